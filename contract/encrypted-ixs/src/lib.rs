@@ -40,6 +40,18 @@ mod circuits {
         pub amount: u64,
     }
 
+    /// Multi-asset transfer request: one amount per asset (USDC, TSLA, SPY, AAPL)
+    #[derive(Copy, Clone)]
+    pub struct MultiTransferRequest {
+        pub amounts: [u64; 4],
+    }
+
+    /// Batch payout request: one amount per recipient slot (up to four)
+    #[derive(Copy, Clone)]
+    pub struct BatchTransferRequest {
+        pub amounts: [u64; 4],
+    }
+
     /// Result of an operation with success flag
     #[derive(Copy, Clone)]
     pub struct BalanceResult {
@@ -67,6 +79,20 @@ mod circuits {
         update_ctxt.owner.from_arcis(balance)
     }
 
+    /// Credit a public amount to another user's balance (deposit_for).
+    /// The depositor cannot encrypt for the recipient's key, so the amount
+    /// is plaintext (it is visible in the token transfer anyway) and the
+    /// result is encrypted for the balance's owner.
+    #[instruction]
+    pub fn credit_balance(
+        balance_ctxt: Enc<Shared, UserBalance>,
+        amount: u64,
+    ) -> Enc<Shared, UserBalance> {
+        let mut balance = balance_ctxt.to_arcis();
+        balance.balance += amount;
+        balance_ctxt.owner.from_arcis(balance)
+    }
+
     /// Subtract from user's balance (withdrawal).
     /// Returns (has_funds, new_balance) so callback can verify success.
     /// If has_funds is false, balance is unchanged and callback should abort.
@@ -139,6 +165,463 @@ mod circuits {
         )
     }
 
+    /// Atomic P2P transfer of all four assets in one computation.
+    /// Either every amount moves or none does: if the sender is short on any
+    /// asset, all eight balances are returned unchanged (re-encrypted).
+    /// Returns the sender's USDC, TSLA, SPY, AAPL balances, then the
+    /// recipient's in the same order.
+    #[instruction]
+    pub fn transfer_multi(
+        request_ctxt: Enc<Shared, MultiTransferRequest>,
+        sender_usdc_ctxt: Enc<Shared, UserBalance>,
+        sender_tsla_ctxt: Enc<Shared, UserBalance>,
+        sender_spy_ctxt: Enc<Shared, UserBalance>,
+        sender_aapl_ctxt: Enc<Shared, UserBalance>,
+        recipient_usdc_ctxt: Enc<Shared, UserBalance>,
+        recipient_tsla_ctxt: Enc<Shared, UserBalance>,
+        recipient_spy_ctxt: Enc<Shared, UserBalance>,
+        recipient_aapl_ctxt: Enc<Shared, UserBalance>,
+    ) -> (
+        Enc<Shared, UserBalance>,
+        Enc<Shared, UserBalance>,
+        Enc<Shared, UserBalance>,
+        Enc<Shared, UserBalance>,
+        Enc<Shared, UserBalance>,
+        Enc<Shared, UserBalance>,
+        Enc<Shared, UserBalance>,
+        Enc<Shared, UserBalance>,
+    ) {
+        let request = request_ctxt.to_arcis();
+        let sender = [
+            sender_usdc_ctxt.to_arcis().balance,
+            sender_tsla_ctxt.to_arcis().balance,
+            sender_spy_ctxt.to_arcis().balance,
+            sender_aapl_ctxt.to_arcis().balance,
+        ];
+        let recipient = [
+            recipient_usdc_ctxt.to_arcis().balance,
+            recipient_tsla_ctxt.to_arcis().balance,
+            recipient_spy_ctxt.to_arcis().balance,
+            recipient_aapl_ctxt.to_arcis().balance,
+        ];
+
+        // All-or-nothing: every asset must be covered
+        let mut has_funds = true;
+        for asset in 0..4 {
+            if sender[asset] < request.amounts[asset] {
+                has_funds = false;
+            }
+        }
+
+        let mut new_sender = sender;
+        let mut new_recipient = recipient;
+        for asset in 0..4 {
+            let amount = if has_funds { request.amounts[asset] } else { 0 };
+            new_sender[asset] = sender[asset] - amount;
+            new_recipient[asset] = recipient[asset] + amount;
+        }
+
+        (
+            sender_usdc_ctxt.owner.from_arcis(UserBalance {
+                balance: new_sender[0],
+            }),
+            sender_tsla_ctxt.owner.from_arcis(UserBalance {
+                balance: new_sender[1],
+            }),
+            sender_spy_ctxt.owner.from_arcis(UserBalance {
+                balance: new_sender[2],
+            }),
+            sender_aapl_ctxt.owner.from_arcis(UserBalance {
+                balance: new_sender[3],
+            }),
+            recipient_usdc_ctxt.owner.from_arcis(UserBalance {
+                balance: new_recipient[0],
+            }),
+            recipient_tsla_ctxt.owner.from_arcis(UserBalance {
+                balance: new_recipient[1],
+            }),
+            recipient_spy_ctxt.owner.from_arcis(UserBalance {
+                balance: new_recipient[2],
+            }),
+            recipient_aapl_ctxt.owner.from_arcis(UserBalance {
+                balance: new_recipient[3],
+            }),
+        )
+    }
+
+    /// Payout of one asset from a sender to up to four recipients in one
+    /// computation. Slots at or beyond `recipient_count` are padding and
+    /// receive nothing. Either every payout moves or none does: if the
+    /// sender cannot cover the total, all balances are returned unchanged
+    /// (re-encrypted). Returns the sender's balance, then each recipient's
+    /// in slot order.
+    #[instruction]
+    pub fn transfer_batch(
+        request_ctxt: Enc<Shared, BatchTransferRequest>,
+        sender_ctxt: Enc<Shared, UserBalance>,
+        recipient_count: u8,
+        recipient_0_ctxt: Enc<Shared, UserBalance>,
+        recipient_1_ctxt: Enc<Shared, UserBalance>,
+        recipient_2_ctxt: Enc<Shared, UserBalance>,
+        recipient_3_ctxt: Enc<Shared, UserBalance>,
+    ) -> (
+        Enc<Shared, UserBalance>,
+        Enc<Shared, UserBalance>,
+        Enc<Shared, UserBalance>,
+        Enc<Shared, UserBalance>,
+        Enc<Shared, UserBalance>,
+    ) {
+        let request = request_ctxt.to_arcis();
+        let sender = sender_ctxt.to_arcis().balance;
+        let recipient = [
+            recipient_0_ctxt.to_arcis().balance,
+            recipient_1_ctxt.to_arcis().balance,
+            recipient_2_ctxt.to_arcis().balance,
+            recipient_3_ctxt.to_arcis().balance,
+        ];
+
+        // Summed in u128 so large amounts cannot wrap past the funds check
+        let mut amounts = request.amounts;
+        let mut total: u128 = 0;
+        for slot in 0..4 {
+            if slot as u8 >= recipient_count {
+                amounts[slot] = 0;
+            }
+            total += amounts[slot] as u128;
+        }
+        let has_funds = total <= sender as u128;
+
+        let new_sender = if has_funds {
+            sender - total as u64
+        } else {
+            sender
+        };
+        let mut new_recipient = recipient;
+        for slot in 0..4 {
+            let amount = if has_funds { amounts[slot] } else { 0 };
+            new_recipient[slot] = recipient[slot] + amount;
+        }
+
+        (
+            sender_ctxt.owner.from_arcis(UserBalance {
+                balance: new_sender,
+            }),
+            recipient_0_ctxt.owner.from_arcis(UserBalance {
+                balance: new_recipient[0],
+            }),
+            recipient_1_ctxt.owner.from_arcis(UserBalance {
+                balance: new_recipient[1],
+            }),
+            recipient_2_ctxt.owner.from_arcis(UserBalance {
+                balance: new_recipient[2],
+            }),
+            recipient_3_ctxt.owner.from_arcis(UserBalance {
+                balance: new_recipient[3],
+            }),
+        )
+    }
+
+    /// Escrow a two-step transfer (request_transfer).
+    /// Deducts the amount from the sender and returns it encrypted for the
+    /// MXE, so neither party can read or move it until it is released.
+    /// If the sender is short of funds the balance is unchanged and the
+    /// escrow holds zero.
+    #[instruction]
+    pub fn escrow_transfer(
+        request_ctxt: Enc<Shared, TransferRequest>,
+        sender_ctxt: Enc<Shared, UserBalance>,
+        mxe: Mxe,
+    ) -> (Enc<Shared, UserBalance>, Enc<Mxe, UserBalance>) {
+        let request = request_ctxt.to_arcis();
+        let sender = sender_ctxt.to_arcis();
+
+        let has_funds = sender.balance >= request.amount;
+        let escrowed = if has_funds { request.amount } else { 0 };
+
+        (
+            sender_ctxt.owner.from_arcis(UserBalance {
+                balance: sender.balance - escrowed,
+            }),
+            mxe.from_arcis(UserBalance { balance: escrowed }),
+        )
+    }
+
+    /// Release an escrowed transfer into a balance (claim_transfer for the
+    /// recipient, reclaim_transfer for the sender).
+    #[instruction]
+    pub fn release_escrow(
+        escrow_ctxt: Enc<Mxe, UserBalance>,
+        balance_ctxt: Enc<Shared, UserBalance>,
+    ) -> Enc<Shared, UserBalance> {
+        let escrow = escrow_ctxt.to_arcis();
+        let balance = balance_ctxt.to_arcis();
+
+        balance_ctxt.owner.from_arcis(UserBalance {
+            balance: balance.balance + escrow.balance,
+        })
+    }
+
+    /// Fund a claim-link voucher (create_voucher).
+    /// Same as escrow_transfer: deducts the amount from the funder and holds
+    /// it encrypted for the MXE; escrows zero if the funder is short.
+    #[instruction]
+    pub fn fund_voucher(
+        request_ctxt: Enc<Shared, TransferRequest>,
+        funder_ctxt: Enc<Shared, UserBalance>,
+        mxe: Mxe,
+    ) -> (Enc<Shared, UserBalance>, Enc<Mxe, UserBalance>) {
+        let request = request_ctxt.to_arcis();
+        let funder = funder_ctxt.to_arcis();
+
+        let has_funds = funder.balance >= request.amount;
+        let escrowed = if has_funds { request.amount } else { 0 };
+
+        (
+            funder_ctxt.owner.from_arcis(UserBalance {
+                balance: funder.balance - escrowed,
+            }),
+            mxe.from_arcis(UserBalance { balance: escrowed }),
+        )
+    }
+
+    /// Credit a voucher's escrow to a balance (claim_voucher for the holder
+    /// of the claim key, reclaim_voucher for the funder).
+    #[instruction]
+    pub fn redeem_voucher(
+        escrow_ctxt: Enc<Mxe, UserBalance>,
+        balance_ctxt: Enc<Shared, UserBalance>,
+    ) -> Enc<Shared, UserBalance> {
+        let escrow = escrow_ctxt.to_arcis();
+        let balance = balance_ctxt.to_arcis();
+
+        balance_ctxt.owner.from_arcis(UserBalance {
+            balance: balance.balance + escrow.balance,
+        })
+    }
+
+    /// Pay out the accrued part of a payment stream (advance_stream).
+    /// `rate_ctxt` is the encrypted per-second rate; `elapsed` (public) is
+    /// the number of seconds being settled. Moves rate * elapsed from payer
+    /// to payee only if the payer has it; returns (has_funds, payer, payee).
+    #[instruction]
+    pub fn stream_transfer(
+        rate_ctxt: Enc<Shared, TransferRequest>,
+        payer_ctxt: Enc<Shared, UserBalance>,
+        payee_ctxt: Enc<Shared, UserBalance>,
+        elapsed: u64,
+    ) -> (bool, Enc<Shared, UserBalance>, Enc<Shared, UserBalance>) {
+        let rate = rate_ctxt.to_arcis();
+        let payer = payer_ctxt.to_arcis();
+        let payee = payee_ctxt.to_arcis();
+
+        let accrued = rate.amount * elapsed;
+        let has_funds = payer.balance >= accrued;
+        let moved = if has_funds { accrued } else { 0 };
+
+        (
+            has_funds.reveal(),
+            payer_ctxt.owner.from_arcis(UserBalance {
+                balance: payer.balance - moved,
+            }),
+            payee_ctxt.owner.from_arcis(UserBalance {
+                balance: payee.balance + moved,
+            }),
+        )
+    }
+
+    /// Move USDC between a user's trading balance and one of their savings
+    /// buckets (move_savings). `direction` is public: 0 moves into the
+    /// bucket, 1 back to the trading balance. Returns (has_funds, new_main,
+    /// new_bucket); if the source is short both balances are unchanged.
+    #[instruction]
+    pub fn move_savings(
+        request_ctxt: Enc<Shared, TransferRequest>,
+        main_ctxt: Enc<Shared, UserBalance>,
+        bucket_ctxt: Enc<Shared, UserBalance>,
+        direction: u8,
+    ) -> (bool, Enc<Shared, UserBalance>, Enc<Shared, UserBalance>) {
+        let request = request_ctxt.to_arcis();
+        let main = main_ctxt.to_arcis();
+        let bucket = bucket_ctxt.to_arcis();
+        let to_bucket = direction == 0;
+
+        let source = if to_bucket {
+            main.balance
+        } else {
+            bucket.balance
+        };
+        let has_funds = source >= request.amount;
+        let moved = if has_funds { request.amount } else { 0 };
+
+        let (new_main, new_bucket) = if to_bucket {
+            (main.balance - moved, bucket.balance + moved)
+        } else {
+            (main.balance + moved, bucket.balance - moved)
+        };
+
+        (
+            has_funds.reveal(),
+            main_ctxt
+                .owner
+                .from_arcis(UserBalance { balance: new_main }),
+            bucket_ctxt.owner.from_arcis(UserBalance {
+                balance: new_bucket,
+            }),
+        )
+    }
+
+    /// Move an owner's entire balance of one asset to a beneficiary.
+    /// Used by claim_inheritance once the owner has been inactive long enough.
+    /// Both balances use Enc<Shared> so each user can decrypt their own result.
+    #[instruction]
+    pub fn sweep_balance(
+        owner_ctxt: Enc<Shared, UserBalance>,
+        beneficiary_ctxt: Enc<Shared, UserBalance>,
+    ) -> (Enc<Shared, UserBalance>, Enc<Shared, UserBalance>) {
+        let owner = owner_ctxt.to_arcis();
+        let beneficiary = beneficiary_ctxt.to_arcis();
+
+        (
+            owner_ctxt.owner.from_arcis(UserBalance { balance: 0 }),
+            beneficiary_ctxt.owner.from_arcis(UserBalance {
+                balance: beneficiary.balance + owner.balance,
+            }),
+        )
+    }
+
+    /// Re-encrypt a user's balance for a viewing key.
+    /// Used by refresh_viewable_balance so frontends and auditors can read a
+    /// balance without the user's key. The stored balance is not modified.
+    #[instruction]
+    pub fn reencrypt_balance(
+        balance_ctxt: Enc<Shared, UserBalance>,
+        viewer: Shared,
+    ) -> Enc<Shared, UserBalance> {
+        let balance = balance_ctxt.to_arcis();
+
+        viewer.from_arcis(balance)
+    }
+
+    /// Re-encrypt all four of a user's balances for an auditor.
+    /// Used by add_viewer for selective disclosure. The stored balances are
+    /// not modified; each output gets its own nonce like rotate_user_key.
+    #[instruction]
+    pub fn disclose_balances(
+        usdc_ctxt: Enc<Shared, UserBalance>,
+        tsla_ctxt: Enc<Shared, UserBalance>,
+        spy_ctxt: Enc<Shared, UserBalance>,
+        aapl_ctxt: Enc<Shared, UserBalance>,
+        usdc_viewer: Shared,
+        tsla_viewer: Shared,
+        spy_viewer: Shared,
+        aapl_viewer: Shared,
+    ) -> (
+        Enc<Shared, UserBalance>,
+        Enc<Shared, UserBalance>,
+        Enc<Shared, UserBalance>,
+        Enc<Shared, UserBalance>,
+    ) {
+        (
+            usdc_viewer.from_arcis(usdc_ctxt.to_arcis()),
+            tsla_viewer.from_arcis(tsla_ctxt.to_arcis()),
+            spy_viewer.from_arcis(spy_ctxt.to_arcis()),
+            aapl_viewer.from_arcis(aapl_ctxt.to_arcis()),
+        )
+    }
+
+    /// Re-encrypt all four of a user's balances under a new key.
+    /// Used by rotate_user_key after the old key is compromised. Each output
+    /// gets its own Shared owner (same key, distinct nonce) so every balance
+    /// keeps an independent nonce on-chain.
+    #[instruction]
+    pub fn rotate_user_key(
+        usdc_ctxt: Enc<Shared, UserBalance>,
+        tsla_ctxt: Enc<Shared, UserBalance>,
+        spy_ctxt: Enc<Shared, UserBalance>,
+        aapl_ctxt: Enc<Shared, UserBalance>,
+        usdc_owner: Shared,
+        tsla_owner: Shared,
+        spy_owner: Shared,
+        aapl_owner: Shared,
+    ) -> (
+        Enc<Shared, UserBalance>,
+        Enc<Shared, UserBalance>,
+        Enc<Shared, UserBalance>,
+        Enc<Shared, UserBalance>,
+    ) {
+        (
+            usdc_owner.from_arcis(usdc_ctxt.to_arcis()),
+            tsla_owner.from_arcis(tsla_ctxt.to_arcis()),
+            spy_owner.from_arcis(spy_ctxt.to_arcis()),
+            aapl_owner.from_arcis(aapl_ctxt.to_arcis()),
+        )
+    }
+
+    /// Move all four balances of a recovered account into the new owner's
+    /// account (guardian recovery, execute_recovery). Each old balance is
+    /// added to the new account's balance of the same asset, encrypted for
+    /// the new owner's key. Returns USDC, TSLA, SPY, AAPL.
+    #[instruction]
+    pub fn recover_account(
+        old_usdc_ctxt: Enc<Shared, UserBalance>,
+        old_tsla_ctxt: Enc<Shared, UserBalance>,
+        old_spy_ctxt: Enc<Shared, UserBalance>,
+        old_aapl_ctxt: Enc<Shared, UserBalance>,
+        new_usdc_ctxt: Enc<Shared, UserBalance>,
+        new_tsla_ctxt: Enc<Shared, UserBalance>,
+        new_spy_ctxt: Enc<Shared, UserBalance>,
+        new_aapl_ctxt: Enc<Shared, UserBalance>,
+    ) -> (
+        Enc<Shared, UserBalance>,
+        Enc<Shared, UserBalance>,
+        Enc<Shared, UserBalance>,
+        Enc<Shared, UserBalance>,
+    ) {
+        let old = [
+            old_usdc_ctxt.to_arcis().balance,
+            old_tsla_ctxt.to_arcis().balance,
+            old_spy_ctxt.to_arcis().balance,
+            old_aapl_ctxt.to_arcis().balance,
+        ];
+        let new = [
+            new_usdc_ctxt.to_arcis().balance,
+            new_tsla_ctxt.to_arcis().balance,
+            new_spy_ctxt.to_arcis().balance,
+            new_aapl_ctxt.to_arcis().balance,
+        ];
+
+        (
+            new_usdc_ctxt.owner.from_arcis(UserBalance {
+                balance: new[0] + old[0],
+            }),
+            new_tsla_ctxt.owner.from_arcis(UserBalance {
+                balance: new[1] + old[1],
+            }),
+            new_spy_ctxt.owner.from_arcis(UserBalance {
+                balance: new[2] + old[2],
+            }),
+            new_aapl_ctxt.owner.from_arcis(UserBalance {
+                balance: new[3] + old[3],
+            }),
+        )
+    }
+
+    /// Whether all four of a user's balances are zero (close_user_account).
+    /// Only the boolean is revealed, never the balances.
+    #[instruction]
+    pub fn check_balances_zero(
+        usdc_ctxt: Enc<Shared, UserBalance>,
+        tsla_ctxt: Enc<Shared, UserBalance>,
+        spy_ctxt: Enc<Shared, UserBalance>,
+        aapl_ctxt: Enc<Shared, UserBalance>,
+    ) -> bool {
+        let all_zero = usdc_ctxt.to_arcis().balance == 0
+            && tsla_ctxt.to_arcis().balance == 0
+            && spy_ctxt.to_arcis().balance == 0
+            && aapl_ctxt.to_arcis().balance == 0;
+        all_zero.reveal()
+    }
+
     // =========================================================================
     // BATCH ACCUMULATOR CIRCUITS (for Omni-Batch)
     // =========================================================================
@@ -182,11 +665,16 @@ mod circuits {
 
     /// Accumulate an order into the batch.
     /// Also deducts from user's balance atomically.
-    /// Returns (has_funds, batch_ready, new_balance, new_batch_state).
+    /// Returns (has_funds, batch_ready, new_balance, new_batch_state, route_ok).
     /// - has_funds: false if user lacks balance, callback should abort
-    /// - batch_ready: true if batch meets requirements (order_count >= 8 AND >= 2 pairs with activity)
+    /// - batch_ready: true if batch meets requirements (order_count >= min_orders
+    ///   AND >= min_pairs pairs with activity)
+    /// - route_ok: false if pair_id > 5 or direction > 1 (e.g. a corrupted
+    ///   ciphertext); balance and batch are then unchanged. Only this bit is
+    ///   revealed, not the offending value.
     ///
     /// NOTE: order_count is passed as plaintext input (tracked on Solana side).
+    /// min_orders / min_pairs are the Pool's batch trigger thresholds.
     /// Active pairs are calculated transiently by checking encrypted pair totals.
     ///
     /// NOTE: User balance uses Enc<Shared,*> so users can decrypt their updated balance.
@@ -197,7 +685,15 @@ mod circuits {
         balance_ctxt: Enc<Shared, UserBalance>,
         batch_ctxt: Enc<Mxe, BatchState>,
         order_count: u8, // Plaintext: current order count (before this order)
-    ) -> (bool, bool, Enc<Shared, UserBalance>, Enc<Mxe, BatchState>) {
+        min_orders: u8,  // Plaintext: Pool.execution_trigger_count
+        min_pairs: u8,   // Plaintext: Pool.min_active_pairs
+    ) -> (
+        bool,
+        bool,
+        Enc<Shared, UserBalance>,
+        Enc<Mxe, BatchState>,
+        bool,
+    ) {
         let order = order_ctxt.to_arcis();
         let balance = balance_ctxt.to_arcis();
         let mut batch = batch_ctxt.to_arcis();
@@ -205,20 +701,25 @@ mod circuits {
         // Check if user has sufficient balance
         let has_funds = balance.balance >= order.amount;
 
-        // Only deduct if has funds
-        let new_balance = if has_funds {
+        // Out-of-range route: would otherwise deduct the balance without
+        // reaching any pair (pair_id) or count as a B-side sell (direction)
+        let route_ok = (order.pair_id as usize) < NUM_PAIRS && order.direction <= 1;
+        let accepted = has_funds && route_ok;
+
+        // Only deduct if accepted
+        let new_balance = if accepted {
             balance.balance - order.amount
         } else {
-            balance.balance // Unchanged if insufficient
+            balance.balance // Unchanged if insufficient or invalid
         };
 
-        // Only accumulate if has_funds
+        // Only accumulate if accepted
         // direction == 0 means selling Token A, direction == 1 means selling Token B
         for i in 0..NUM_PAIRS {
             let is_target = i == order.pair_id as usize;
             let is_a_direction = order.direction == 0;
 
-            if is_target && has_funds {
+            if is_target && accepted {
                 if is_a_direction {
                     batch.pairs[i].total_a_in += order.amount;
                 } else {
@@ -227,8 +728,8 @@ mod circuits {
             }
         }
 
-        // Calculate new order count (increment if has_funds)
-        let new_order_count = if has_funds {
+        // Calculate new order count (increment if accepted)
+        let new_order_count = if accepted {
             order_count + 1
         } else {
             order_count
@@ -243,10 +744,100 @@ mod circuits {
             }
         }
 
-        // Check batch requirements: >= 8 orders AND >= 2 active pairs
-        let batch_ready = new_order_count >= 8 && pair_count >= 2;
+        // Check batch requirements: >= min_orders orders AND >= min_pairs active pairs
+        let batch_ready = new_order_count >= min_orders && pair_count >= min_pairs;
+
+        // Return success flag, batch_ready, updated state and route check
+        (
+            has_funds.reveal(),
+            batch_ready.reveal(),
+            balance_ctxt.owner.from_arcis(UserBalance {
+                balance: new_balance,
+            }),
+            batch_ctxt.owner.from_arcis(batch),
+            route_ok.reveal(),
+        )
+    }
+
+    /// Accumulate an order placed by a delegate against an encrypted allowance.
+    /// Same as accumulate_order, plus the order must not exceed the owner's
+    /// allowance, which is decremented with the balance.
+    /// Returns (has_funds, batch_ready, new_balance, new_batch_state,
+    /// owner_order, route_ok, within_allowance, new_allowance).
+    /// - owner_order: the order re-encrypted for the owner, stored as the
+    ///   OrderTicket so settlement credits the owner's key, not the delegate's
+    /// - within_allowance: false if the order exceeds the allowance; balance,
+    ///   allowance and batch are then unchanged
+    ///
+    /// The order is encrypted by the delegate; balance and allowance are the
+    /// owner's (Enc<Shared> for the account's user_pubkey).
+    #[instruction]
+    pub fn accumulate_order_delegated(
+        order_ctxt: Enc<Shared, OrderInput>,
+        balance_ctxt: Enc<Shared, UserBalance>,
+        allowance_ctxt: Enc<Shared, UserBalance>,
+        batch_ctxt: Enc<Mxe, BatchState>,
+        order_count: u8, // Plaintext: current order count (before this order)
+        min_orders: u8,  // Plaintext: Pool.execution_trigger_count
+        min_pairs: u8,   // Plaintext: Pool.min_active_pairs
+    ) -> (
+        bool,
+        bool,
+        Enc<Shared, UserBalance>,
+        Enc<Mxe, BatchState>,
+        Enc<Shared, OrderInput>,
+        bool,
+        bool,
+        Enc<Shared, UserBalance>,
+    ) {
+        let order = order_ctxt.to_arcis();
+        let balance = balance_ctxt.to_arcis();
+        let allowance = allowance_ctxt.to_arcis();
+        let mut batch = batch_ctxt.to_arcis();
+
+        let has_funds = balance.balance >= order.amount;
+        let within_allowance = allowance.balance >= order.amount;
+        let route_ok = (order.pair_id as usize) < NUM_PAIRS && order.direction <= 1;
+        let accepted = has_funds && within_allowance && route_ok;
+
+        let (new_balance, new_allowance) = if accepted {
+            (
+                balance.balance - order.amount,
+                allowance.balance - order.amount,
+            )
+        } else {
+            (balance.balance, allowance.balance)
+        };
+
+        for i in 0..NUM_PAIRS {
+            let is_target = i == order.pair_id as usize;
+            let is_a_direction = order.direction == 0;
+
+            if is_target && accepted {
+                if is_a_direction {
+                    batch.pairs[i].total_a_in += order.amount;
+                } else {
+                    batch.pairs[i].total_b_in += order.amount;
+                }
+            }
+        }
+
+        let new_order_count = if accepted {
+            order_count + 1
+        } else {
+            order_count
+        };
+
+        let mut pair_count: u8 = 0;
+        for i in 0..NUM_PAIRS {
+            let has_activity = batch.pairs[i].total_a_in > 0 || batch.pairs[i].total_b_in > 0;
+            if has_activity {
+                pair_count += 1;
+            }
+        }
+
+        let batch_ready = new_order_count >= min_orders && pair_count >= min_pairs;
 
-        // Return success flag, batch_ready, and updated state
         (
             has_funds.reveal(),
             batch_ready.reveal(),
@@ -254,6 +845,276 @@ mod circuits {
                 balance: new_balance,
             }),
             batch_ctxt.owner.from_arcis(batch),
+            balance_ctxt.owner.from_arcis(order),
+            route_ok.reveal(),
+            within_allowance.reveal(),
+            allowance_ctxt.owner.from_arcis(UserBalance {
+                balance: new_allowance,
+            }),
+        )
+    }
+
+    /// Withdrawal by a delegate against an encrypted allowance.
+    /// The amount is plaintext (it is visible in the token transfer, and the
+    /// delegate cannot encrypt for the owner's key). Returns
+    /// (has_funds, within_allowance, new_balance, new_allowance); unless both
+    /// flags are true, balance and allowance are unchanged.
+    #[instruction]
+    pub fn sub_balance_delegated(
+        balance_ctxt: Enc<Shared, UserBalance>,
+        allowance_ctxt: Enc<Shared, UserBalance>,
+        amount: u64,
+    ) -> (
+        bool,
+        bool,
+        Enc<Shared, UserBalance>,
+        Enc<Shared, UserBalance>,
+    ) {
+        let balance = balance_ctxt.to_arcis();
+        let allowance = allowance_ctxt.to_arcis();
+
+        let has_funds = balance.balance >= amount;
+        let within_allowance = allowance.balance >= amount;
+        let (new_balance, new_allowance) = if has_funds && within_allowance {
+            (balance.balance - amount, allowance.balance - amount)
+        } else {
+            (balance.balance, allowance.balance)
+        };
+
+        (
+            has_funds.reveal(),
+            within_allowance.reveal(),
+            balance_ctxt.owner.from_arcis(UserBalance {
+                balance: new_balance,
+            }),
+            allowance_ctxt.owner.from_arcis(UserBalance {
+                balance: new_allowance,
+            }),
+        )
+    }
+
+    /// USDC base units per whole USDC (USDC has 6 decimals). The `price` passed
+    /// to accumulate_order_usd is USDC base units per PRICE_SCALE base units of
+    /// the source mint: place_order_usd rescales the per-whole-token oracle
+    /// price as usd_price * PRICE_SCALE / 10^decimals, so
+    /// notional * PRICE_SCALE / price = notional * 10^decimals / usd_price,
+    /// the notional in base units of the source mint whatever its decimals.
+    pub const PRICE_SCALE: u64 = 1_000_000;
+
+    /// Accumulate an order sized in USD.
+    /// The order amount is a USDC notional (6 decimals); it is converted to
+    /// source asset units at the plaintext oracle price before the balance
+    /// check, then accumulated exactly like accumulate_order.
+    /// Returns (has_funds, batch_ready, new_balance, new_batch_state, sized_order, route_ok).
+    /// - sized_order: the order with the converted amount, stored as the
+    ///   OrderTicket so settlement (and the user's UI) see the real size
+    /// - route_ok: same range check as accumulate_order
+    #[instruction]
+    pub fn accumulate_order_usd(
+        order_ctxt: Enc<Shared, OrderInput>,
+        balance_ctxt: Enc<Shared, UserBalance>,
+        batch_ctxt: Enc<Mxe, BatchState>,
+        order_count: u8, // Plaintext: current order count (before this order)
+        min_orders: u8,  // Plaintext: Pool.execution_trigger_count
+        min_pairs: u8,   // Plaintext: Pool.min_active_pairs
+        price: u64,      // Plaintext: source price rescaled by decimals (see PRICE_SCALE)
+    ) -> (
+        bool,
+        bool,
+        Enc<Shared, UserBalance>,
+        Enc<Mxe, BatchState>,
+        Enc<Shared, OrderInput>,
+        bool,
+    ) {
+        let order = order_ctxt.to_arcis();
+        let balance = balance_ctxt.to_arcis();
+        let mut batch = batch_ctxt.to_arcis();
+
+        // notional (USDC units) -> source asset units
+        let amount = ((order.amount as u128 * PRICE_SCALE as u128) / price as u128) as u64;
+
+        // A notional too small to buy one base unit is rejected like short funds
+        let has_funds = amount > 0 && balance.balance >= amount;
+        let route_ok = (order.pair_id as usize) < NUM_PAIRS && order.direction <= 1;
+        let accepted = has_funds && route_ok;
+
+        let new_balance = if accepted {
+            balance.balance - amount
+        } else {
+            balance.balance
+        };
+
+        for i in 0..NUM_PAIRS {
+            let is_target = i == order.pair_id as usize;
+            let is_a_direction = order.direction == 0;
+
+            if is_target && accepted {
+                if is_a_direction {
+                    batch.pairs[i].total_a_in += amount;
+                } else {
+                    batch.pairs[i].total_b_in += amount;
+                }
+            }
+        }
+
+        let new_order_count = if accepted {
+            order_count + 1
+        } else {
+            order_count
+        };
+
+        let mut pair_count: u8 = 0;
+        for i in 0..NUM_PAIRS {
+            let has_activity = batch.pairs[i].total_a_in > 0 || batch.pairs[i].total_b_in > 0;
+            if has_activity {
+                pair_count += 1;
+            }
+        }
+
+        let batch_ready = new_order_count >= min_orders && pair_count >= min_pairs;
+
+        (
+            has_funds.reveal(),
+            batch_ready.reveal(),
+            balance_ctxt.owner.from_arcis(UserBalance {
+                balance: new_balance,
+            }),
+            batch_ctxt.owner.from_arcis(batch),
+            order_ctxt.owner.from_arcis(OrderInput {
+                pair_id: order.pair_id,
+                direction: order.direction,
+                amount,
+            }),
+            route_ok.reveal(),
+        )
+    }
+
+    /// Number of legs in a basket order
+    pub const BASKET_LEGS: usize = 3;
+
+    /// Encrypted basket order: one source amount split across up to 3 pairs.
+    /// Unused legs carry weight 0.
+    #[derive(Copy, Clone)]
+    pub struct BasketInput {
+        /// Pair ID (0-5) of each leg
+        pub pair_ids: [u8; BASKET_LEGS],
+        /// Direction of each leg: 0 = A_to_B, 1 = B_to_A
+        pub directions: [u8; BASKET_LEGS],
+        /// Share of the amount per leg in basis points (must sum to 10_000)
+        pub weights_bps: [u64; BASKET_LEGS],
+        /// Total amount of the source asset
+        pub amount: u64,
+    }
+
+    /// Accumulate a basket order into the batch.
+    /// Deducts the total amount from the user's balance and adds each leg's
+    /// share to its pair accumulator in one computation, so either every leg
+    /// lands in the batch or none does.
+    /// Returns (ok, batch_ready, new_balance, new_batch_state, leg_0, leg_1, leg_2).
+    /// - ok: false if funds are short, weights don't sum to 10_000 or a leg's
+    ///   route is out of range; balance and batch are then unchanged
+    /// - leg_N: the leg as an order (pair_id, direction, leg amount) so each
+    ///   leg can be settled with calculate_payout
+    ///
+    /// The last leg takes amount - leg_0 - leg_1 so rounding dust isn't lost.
+    #[instruction]
+    pub fn accumulate_basket(
+        basket_ctxt: Enc<Shared, BasketInput>,
+        balance_ctxt: Enc<Shared, UserBalance>,
+        batch_ctxt: Enc<Mxe, BatchState>,
+        order_count: u8, // Plaintext: current order count (before this basket)
+        min_orders: u8,  // Plaintext: Pool.execution_trigger_count
+        min_pairs: u8,   // Plaintext: Pool.min_active_pairs
+    ) -> (
+        bool,
+        bool,
+        Enc<Shared, UserBalance>,
+        Enc<Mxe, BatchState>,
+        Enc<Shared, OrderInput>,
+        Enc<Shared, OrderInput>,
+        Enc<Shared, OrderInput>,
+    ) {
+        let basket = basket_ctxt.to_arcis();
+        let balance = balance_ctxt.to_arcis();
+        let mut batch = batch_ctxt.to_arcis();
+
+        let has_funds = balance.balance >= basket.amount;
+
+        let mut weight_sum: u64 = 0;
+        let mut routes_ok = true;
+        for leg in 0..BASKET_LEGS {
+            weight_sum += basket.weights_bps[leg];
+            if basket.pair_ids[leg] as usize >= NUM_PAIRS || basket.directions[leg] > 1 {
+                routes_ok = false;
+            }
+        }
+        let ok = has_funds && routes_ok && weight_sum == BPS_DENOMINATOR;
+
+        // Split the amount (zero legs if the basket is rejected)
+        let mut leg_amounts: [u64; BASKET_LEGS] = [0; BASKET_LEGS];
+        let mut allocated: u64 = 0;
+        for leg in 0..BASKET_LEGS - 1 {
+            let share = ((basket.amount as u128 * basket.weights_bps[leg] as u128)
+                / BPS_DENOMINATOR as u128) as u64;
+            leg_amounts[leg] = if ok { share } else { 0 };
+            allocated += share;
+        }
+        leg_amounts[BASKET_LEGS - 1] = if ok { basket.amount - allocated } else { 0 };
+
+        let new_balance = if ok {
+            balance.balance - basket.amount
+        } else {
+            balance.balance
+        };
+
+        // Add every leg to its pair (zero amounts leave totals unchanged)
+        for i in 0..NUM_PAIRS {
+            for leg in 0..BASKET_LEGS {
+                let is_target = i == basket.pair_ids[leg] as usize;
+                if is_target {
+                    if basket.directions[leg] == 0 {
+                        batch.pairs[i].total_a_in += leg_amounts[leg];
+                    } else {
+                        batch.pairs[i].total_b_in += leg_amounts[leg];
+                    }
+                }
+            }
+        }
+
+        // A basket counts as one order towards the batch trigger
+        let new_order_count = if ok { order_count + 1 } else { order_count };
+
+        let mut pair_count: u8 = 0;
+        for i in 0..NUM_PAIRS {
+            let has_activity = batch.pairs[i].total_a_in > 0 || batch.pairs[i].total_b_in > 0;
+            if has_activity {
+                pair_count += 1;
+            }
+        }
+        let batch_ready = new_order_count >= min_orders && pair_count >= min_pairs;
+
+        (
+            ok.reveal(),
+            batch_ready.reveal(),
+            balance_ctxt.owner.from_arcis(UserBalance {
+                balance: new_balance,
+            }),
+            batch_ctxt.owner.from_arcis(batch),
+            basket_ctxt.owner.from_arcis(OrderInput {
+                pair_id: basket.pair_ids[0],
+                direction: basket.directions[0],
+                amount: leg_amounts[0],
+            }),
+            basket_ctxt.owner.from_arcis(OrderInput {
+                pair_id: basket.pair_ids[1],
+                direction: basket.directions[1],
+                amount: leg_amounts[1],
+            }),
+            basket_ctxt.owner.from_arcis(OrderInput {
+                pair_id: basket.pair_ids[2],
+                direction: basket.directions[2],
+                amount: leg_amounts[2],
+            }),
         )
     }
 
@@ -277,41 +1138,206 @@ mod circuits {
     // SETTLEMENT CIRCUIT (Phase 10)
     // =========================================================================
 
+    /// Fill ratio denominator (must match FILL_RATIO_FULL on-chain)
+    pub const FILL_RATIO_FULL: u64 = 1_000_000;
+
+    /// Basis points denominator (must match BPS_DENOMINATOR on-chain)
+    pub const BPS_DENOMINATOR: u64 = 10_000;
+
+    /// User's encrypted settlement currency preference
+    #[derive(Copy, Clone)]
+    pub struct SettlementPreference {
+        /// 1 = convert non-USDC payouts into USDC in the next batch
+        pub convert_to_usdc: u8,
+    }
+
     /// Calculate pro-rata payout for settlement.
     /// Takes full encrypted order (to preserve struct encryption context),
-    /// plaintext current balance, plus plaintext batch totals,
-    /// and returns updated balance with payout added.
+    /// the user's source and output asset balances, plus plaintext batch
+    /// totals, and returns updated balances.
+    ///
+    /// Partial fills: final_pool_output already reflects only the filled part
+    /// of the user's side, so the pro-rata payout is automatically scaled down.
+    /// The unfilled part of the order (1 - fill_ratio) is refunded to the
+    /// source asset balance.
+    ///
+    /// Route check: pair_id/direction are the plaintext route the caller used
+    /// to pick the batch totals. If they don't match the encrypted order,
+    /// route_ok is false and the callback aborts without touching balances.
     ///
-    /// NOTE: current_balance is plaintext because output asset balances may not have been
-    /// MPC-processed yet (first settlement on that asset).
+    /// Withheld: withheld_bps of the payout is kept from the user's balance
+    /// (forfeit_order withholds the whole payout for the treasury;
+    /// settle_order and force_settle pass 0, force_settle's bounty comes out
+    /// of the execution fee instead).
+    ///
+    /// Execution fee: already withheld before this circuit runs.
+    /// settlement_route (settle_order.rs) subtracts
+    /// batch_log.execution_fee(pair_id, final_pool_output) from the output
+    /// pool, so final_pool_output here is net of the pair's fee and every
+    /// payout is net too. The fee stays in the deposit vault and is accrued
+    /// to Pool.fees_owed by execute_swaps.
+    ///
+    /// Referral split: not implemented yet. There is no referral registry, and
+    /// the fee is withheld pool-wide in plaintext rather than per order, so
+    /// there is no encrypted per-trade fee to split. A referrer's cut would
+    /// need the fee computed per order in this circuit and returned as an
+    /// Enc<Mxe> accumulator for the referrer, converted to a claimable
+    /// plaintext amount periodically, so per-trade earnings stay hidden.
+    ///
+    /// Settlement currency: conversion_pair is the USDC pair that sells the
+    /// output asset (NUM_PAIRS when no conversion applies). The returned
+    /// follow-up order sells the credited payout on that pair if the user's
+    /// preference is set, and has a zero amount otherwise, so staging it
+    /// doesn't reveal the preference.
+    ///
+    /// Output balance: the user's existing balance of the output asset, which
+    /// the payout is added to. The payout alone is returned separately
+    /// (last element) for the order history and SettlementEvent.
     ///
     /// DEBUG: Also returns revealed payout to verify computation is correct
     #[instruction]
     pub fn calculate_payout(
         order_ctxt: Enc<Shared, OrderInput>, // Full order struct (was: Enc<Shared, u64>)
-        current_balance: u64,                // Plaintext - first settlement has zero
+        source_balance_ctxt: Enc<Shared, UserBalance>,
+        output_balance_ctxt: Enc<Shared, UserBalance>,
         total_input: u64,
         final_pool_output: u64,
-    ) -> (Enc<Shared, UserBalance>, u64) {
+        fill_ratio: u64, // Plaintext - executed fraction of the user's side
+        pair_id: u8,     // Plaintext - route claimed by the caller
+        direction: u8,
+        withheld_bps: u64, // Plaintext - share of the payout withheld (forfeiture)
+        preference_ctxt: Enc<Shared, SettlementPreference>,
+        conversion_pair: u8, // Plaintext - USDC pair for the follow-up (NUM_PAIRS = none)
+    ) -> (
+        Enc<Shared, UserBalance>,
+        Enc<Shared, UserBalance>,
+        u64,
+        u64,
+        bool,
+        Enc<Shared, OrderInput>,
+        Enc<Shared, UserBalance>,
+    ) {
         // Extract just the amount from the order struct
         let order = order_ctxt.to_arcis();
+        let source = source_balance_ctxt.to_arcis();
+        let output = output_balance_ctxt.to_arcis();
         let order_amount = order.amount;
 
+        let route_ok = order.pair_id == pair_id && order.direction == direction;
+
         // Pro-rata formula: (order_amount * final_pool_output) / total_input
-        let payout = if total_input > 0 {
+        let payout = if total_input > 0 && route_ok {
             ((order_amount as u128 * final_pool_output as u128) / total_input as u128) as u64
         } else {
-            0 // Zero liquidity case
+            0 // Zero liquidity case (or wrong route)
         };
 
-        let new_balance = current_balance + payout;
+        // Refund the unfilled part of the order
+        let refund = if route_ok {
+            ((order_amount as u128 * (FILL_RATIO_FULL - fill_ratio) as u128)
+                / FILL_RATIO_FULL as u128) as u64
+        } else {
+            0
+        };
+
+        let withheld = ((payout as u128 * withheld_bps as u128) / BPS_DENOMINATOR as u128) as u64;
+
+        let new_balance = output.balance + payout - withheld;
 
-        // Return both encrypted balance AND revealed payout for debugging
+        // Follow-up order selling the credited payout for USDC (token B)
+        let preference = preference_ctxt.to_arcis();
+        let convert = (conversion_pair as usize) < NUM_PAIRS && preference.convert_to_usdc == 1;
+        let follow_up = OrderInput {
+            pair_id: conversion_pair,
+            direction: 0,
+            amount: if convert { payout - withheld } else { 0 },
+        };
+
+        // Return both encrypted balances AND revealed payout for debugging
         (
-            order_ctxt.owner.from_arcis(UserBalance {
+            output_balance_ctxt.owner.from_arcis(UserBalance {
                 balance: new_balance,
             }),
+            source_balance_ctxt.owner.from_arcis(UserBalance {
+                balance: source.balance + refund,
+            }),
             payout.reveal(),
+            withheld.reveal(),
+            route_ok.reveal(),
+            order_ctxt.owner.from_arcis(follow_up),
+            order_ctxt.owner.from_arcis(UserBalance {
+                balance: payout - withheld,
+            }),
+        )
+    }
+
+    // =========================================================================
+    // RESERVE AUCTION CIRCUITS
+    // =========================================================================
+    // Sealed-bid auction of excess reserve inventory. Only the running best bid
+    // is kept (Enc<Mxe>), and only the clearing price + winner index are revealed.
+
+    /// Encrypted bid: total USDC offered for the whole lot
+    #[derive(Copy, Clone)]
+    pub struct AuctionBid {
+        pub price: u64,
+    }
+
+    /// Running auction state (protocol-owned)
+    #[derive(Copy, Clone)]
+    pub struct AuctionState {
+        pub best_price: u64,
+        /// Index into ReserveAuction.bidders
+        pub best_bidder: u64,
+    }
+
+    /// Initialize empty auction state
+    #[instruction]
+    pub fn init_auction_state(mxe: Mxe) -> Enc<Mxe, AuctionState> {
+        mxe.from_arcis(AuctionState {
+            best_price: 0,
+            best_bidder: 0,
+        })
+    }
+
+    /// Fold a bid into the auction state, keeping the highest price.
+    /// Ties go to the earlier bid.
+    #[instruction]
+    pub fn submit_auction_bid(
+        bid_ctxt: Enc<Shared, AuctionBid>,
+        state_ctxt: Enc<Mxe, AuctionState>,
+        bidder_index: u8, // Plaintext: position in ReserveAuction.bidders
+    ) -> Enc<Mxe, AuctionState> {
+        let bid = bid_ctxt.to_arcis();
+        let mut state = state_ctxt.to_arcis();
+
+        let is_better = bid.price > state.best_price;
+        if is_better {
+            state.best_price = bid.price;
+            state.best_bidder = bidder_index as u64;
+        }
+
+        state_ctxt.owner.from_arcis(state)
+    }
+
+    /// Reveal the auction result.
+    /// Returns (cleared, clearing_price, winner_index); price and index are 0
+    /// when the best bid is below the reserve price, so nothing leaks.
+    #[instruction]
+    pub fn reveal_auction(
+        state_ctxt: Enc<Mxe, AuctionState>,
+        reserve_price: u64, // Plaintext: minimum acceptable price
+    ) -> (bool, u64, u64) {
+        let state = state_ctxt.to_arcis();
+
+        let cleared = state.best_price > 0 && state.best_price >= reserve_price;
+        let clearing_price = if cleared { state.best_price } else { 0 };
+        let winner_index = if cleared { state.best_bidder } else { 0 };
+
+        (
+            cleared.reveal(),
+            clearing_price.reveal(),
+            winner_index.reveal(),
         )
     }
 
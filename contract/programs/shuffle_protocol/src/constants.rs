@@ -53,6 +53,208 @@ pub const NUM_PAIRS: u8 = 6;
 /// Default number of orders to trigger batch execution
 pub const BATCH_EXECUTION_TRIGGER: u8 = 8;
 
+/// Default number of active pairs to trigger batch execution
+pub const BATCH_MIN_ACTIVE_PAIRS: u8 = 2;
+
+// =============================================================================
+// DCA CONFIGURATION
+// =============================================================================
+
+/// Minimum interval between DCA executions (1 hour)
+pub const DCA_MIN_INTERVAL_SECS: i64 = 3_600;
+
+// =============================================================================
+// SESSION-KEY DELEGATION
+// =============================================================================
+
+/// Longest a session key may stay valid (7 days)
+pub const MAX_DELEGATE_DURATION_SECS: i64 = 604_800;
+
+// =============================================================================
+// BENEFICIARY (Dead-man's switch)
+// =============================================================================
+
+/// Minimum owner inactivity before a beneficiary can claim (30 days)
+pub const MIN_INACTIVITY_PERIOD_SECS: i64 = 2_592_000;
+
+// =============================================================================
+// GUARDIAN RECOVERY
+// =============================================================================
+
+/// Guardians an owner can register for social recovery
+pub const MAX_GUARDIANS: usize = 5;
+
+/// Shortest challenge period between guardian approval and recovery (3 days)
+pub const MIN_RECOVERY_CHALLENGE_SECS: i64 = 259_200;
+
+// =============================================================================
+// ROLES
+// =============================================================================
+
+/// Wallets that can be granted each role (besides Pool.authority/operator)
+pub const MAX_ROLE_MEMBERS: usize = 4;
+
+// =============================================================================
+// RISK LIMITS
+// =============================================================================
+
+/// Length of the per-wallet withdrawal window (RiskConfig.max_daily_withdrawal)
+pub const WITHDRAWAL_WINDOW_SECS: i64 = 86_400;
+
+// =============================================================================
+// TWO-STEP TRANSFERS
+// =============================================================================
+
+/// Shortest claim window of a transfer request (1 hour)
+pub const MIN_TRANSFER_EXPIRY_SECS: i64 = 3_600;
+
+/// Longest claim window of a transfer request (30 days)
+pub const MAX_TRANSFER_EXPIRY_SECS: i64 = 2_592_000;
+
+/// Time after an escrow, claim or reclaim was queued before a transfer
+/// request (or voucher) whose callback never arrived can be reset
+/// (10 minutes)
+pub const TRANSFER_RESET_TIMEOUT_SECS: i64 = 600;
+
+// =============================================================================
+// TRANSFER MEMOS
+// =============================================================================
+
+/// Shortest encrypted memo accepted by internal_transfer (bytes)
+pub const MIN_TRANSFER_MEMO_LEN: usize = 64;
+
+/// Longest encrypted memo accepted by internal_transfer (bytes)
+pub const MAX_TRANSFER_MEMO_LEN: usize = 128;
+
+// =============================================================================
+// BATCH PAYOUTS
+// =============================================================================
+
+/// Recipients one transfer_batch can pay (slots in the transfer_batch circuit)
+pub const MAX_BATCH_TRANSFER_RECIPIENTS: usize = 4;
+
+// =============================================================================
+// WITHDRAWAL CANCELLATION
+// =============================================================================
+
+/// Time after sub_balance before a withdrawal whose callback never arrived
+/// can be cancelled (10 minutes)
+pub const WITHDRAWAL_CANCEL_TIMEOUT_SECS: i64 = 600;
+
+// =============================================================================
+// BATCH LIFECYCLE
+// =============================================================================
+
+/// Time after execute_batch before a stuck reveal can be recovered (10 minutes)
+pub const BATCH_REVEAL_TIMEOUT_SECS: i64 = 600;
+
+/// Reveals retry_execute_batch may re-queue for one batch before the
+/// authority has to discard it with reset_batch
+pub const MAX_REVEAL_RETRIES: u8 = 3;
+
+// =============================================================================
+// FORCE SETTLEMENT
+// =============================================================================
+
+/// Default time after batch execution before anyone may settle a user's
+/// order (7 days, Pool.force_settle_grace_secs)
+pub const FORCE_SETTLE_GRACE_SECS: i64 = 604_800;
+
+/// Share of the execution fee paid to the force_settle cranker (2000 = 20%)
+/// With execution_fee_bps = 50 the bounty is 10 bps of the payout
+pub const FORCE_SETTLE_BOUNTY_SHARE_BPS: u16 = 2_000;
+
+/// Default time after batch execution before unsettled payouts escheat to
+/// the treasury (90 days)
+pub const DEFAULT_SETTLEMENT_DEADLINE_SECS: i64 = 7_776_000;
+
+/// Lower bound for Pool.settlement_deadline_secs (30 days)
+pub const MIN_SETTLEMENT_DEADLINE_SECS: i64 = 2_592_000;
+
+// =============================================================================
+// MPC CALLBACK PRIORITY
+// =============================================================================
+
+/// Upper bound for Pool.callback_cu_price_micro (1 lamport per CU)
+/// Guards against a fat-fingered fee draining callers on every queued computation
+pub const MAX_CALLBACK_CU_PRICE_MICRO: u64 = 1_000_000;
+
+// =============================================================================
+// BATCH RATE LIMIT
+// =============================================================================
+
+/// Default Pool.batch_window_secs (one day)
+pub const DEFAULT_BATCH_WINDOW_SECS: i64 = 86_400;
+
+/// Upper bound for Pool.batch_window_secs (7 days)
+pub const MAX_BATCH_WINDOW_SECS: i64 = 604_800;
+
+// =============================================================================
+// KEEPER REWARD
+// =============================================================================
+
+/// Upper bound for Pool.keeper_reward_bps (0.5% of a batch's notional; the
+/// payout is also capped at the execution fees the batch accrued)
+pub const MAX_KEEPER_REWARD_BPS: u16 = 50;
+
+// =============================================================================
+// RESERVE-ONLY EXECUTION
+// =============================================================================
+// In reserve-only mode net surpluses are absorbed by protocol reserves at
+// oracle price minus a configurable spread instead of being routed out.
+
+/// Maximum spread the reserves may charge on absorbed surplus (5%)
+pub const MAX_RESERVE_SPREAD_BPS: u16 = 500;
+
+/// Default Pool.max_slippage_bps (1%)
+pub const DEFAULT_MAX_SLIPPAGE_BPS: u16 = 100;
+
+/// Upper bound for Pool.max_slippage_bps, and so for the slippage of any
+/// external swap (3%)
+pub const MAX_EXTERNAL_SWAP_SLIPPAGE_BPS: u16 = 300;
+
+/// Longest time reserves may hold a surplus waiting for an opposite one
+/// from a later batch before it must be flushed externally (1 hour)
+pub const MAX_NETTING_WINDOW_SECS: i64 = 3600;
+
+// =============================================================================
+// EMERGENCY ASSET FREEZE
+// =============================================================================
+
+/// Delay between request_unfreeze_asset and unfreeze_asset (48 hours)
+/// Gives users time to react before a previously compromised asset reopens
+pub const ASSET_UNFREEZE_TIMELOCK_SECS: i64 = 172_800;
+
+// =============================================================================
+// ORACLE PRICES
+// =============================================================================
+// USDC per whole token with 6 decimals, indexed by asset ID:
+// USDC = $1.00, TSLA = $250, SPY = $450, AAPL = $180.
+// Batches are priced from Pyth for every asset with a feed in the
+// OracleConfig (set_oracle_feed), with Switchboard as fallback and
+// cross-check (oracle::read_asset_price); assets without either use these.
+
+pub const MOCK_ORACLE_PRICES: [u64; 4] = [1_000_000, 250_000_000, 450_000_000, 180_000_000];
+
+/// Pyth Solana Receiver program (owner of PriceUpdateV2 accounts)
+pub const PYTH_RECEIVER_PROGRAM_ID: Pubkey = pubkey!("rec5EKMGg6MxZYaMdyBfgwp4d5rB9T1VQH5pJv5LtFJ");
+
+/// Default oldest Pyth price execute_batch accepts (1 minute)
+pub const DEFAULT_MAX_PRICE_AGE_SECS: i64 = 60;
+
+/// Default widest confidence interval accepted, in bps of the price (2%)
+pub const DEFAULT_MAX_PRICE_CONF_BPS: u16 = 200;
+
+/// Switchboard On-Demand program (owner of pull feed accounts)
+pub const SWITCHBOARD_ON_DEMAND_PROGRAM_ID: Pubkey =
+    pubkey!("SBondMDrcV3K4kxZR1HNVT7osZxAHVHgYXL5Ze1oMUv");
+
+/// Default largest Pyth/Switchboard price gap before batches halt (1%)
+pub const DEFAULT_MAX_PRICE_DIVERGENCE_BPS: u16 = 100;
+
+/// Most decimals an asset mint may have (prices are normalized to it)
+pub const MAX_ASSET_DECIMALS: u8 = 9;
+
 // =============================================================================
 // FEE LIMITS
 // =============================================================================
@@ -61,6 +263,9 @@ pub const BATCH_EXECUTION_TRIGGER: u8 = 8;
 /// This prevents the admin from setting unreasonably high fees
 pub const MAX_FEE_BPS: u16 = 1000;
 
+/// Basis points denominator (10_000 = 100%)
+pub const BPS_DENOMINATOR: u16 = 10_000;
+
 // =============================================================================
 // TOKEN MINTS (Devnet)
 // =============================================================================
@@ -95,19 +300,132 @@ pub const SPY_MINT: Pubkey = pubkey!("11111111111111111111111111111111"); // Pla
 // PDA SEEDS
 // =============================================================================
 // PDA (Program Derived Address) seeds are used to derive deterministic addresses.
+// Pool-scoped accounts (pool, vaults, reserves, batch accumulators, batch logs,
+// user accounts) take Pool.pool_id as the seed after their prefix, so several
+// pools can be deployed from one program.
 
-/// Seed for the main pool account
+/// Seed for the main pool account (combined with pool_id)
 pub const POOL_SEED: &[u8] = b"pool";
 
 /// Seed prefix for user accounts
 pub const USER_SEED: &[u8] = b"user";
 
-/// Seed for the batch accumulator account (singleton)
+/// Seed prefix for the batch accumulator accounts (combined with slot)
 pub const BATCH_ACCUMULATOR_SEED: &[u8] = b"batch_accumulator";
 
+/// Number of double-buffered batch accumulator slots
+pub const BATCH_ACCUMULATOR_SLOTS: u8 = 2;
+
 /// Seed prefix for batch log accounts
 pub const BATCH_LOG_SEED: &[u8] = b"batch_log";
 
+/// Seed prefix for per-user order history accounts
+pub const ORDER_HISTORY_SEED: &[u8] = b"order_history";
+
+/// Seed prefix for per-user basket order accounts
+pub const BASKET_ORDER_SEED: &[u8] = b"basket_order";
+
+/// Seed prefix for DCA schedule accounts (combined with owner + dca_id)
+pub const DCA_SEED: &[u8] = b"dca";
+
+/// Seed prefix for session-key delegate accounts (combined with owner + delegate)
+pub const DELEGATE_SEED: &[u8] = b"delegate";
+
+/// Seed prefix for beneficiary (dead-man's switch) accounts (combined with owner)
+pub const BENEFICIARY_SEED: &[u8] = b"beneficiary";
+
+/// Seed prefix for guardian (social recovery) accounts (combined with owner)
+pub const GUARDIAN_SET_SEED: &[u8] = b"guardian_set";
+
+/// Seed prefix for asset transfer policy accounts (combined with mint)
+pub const ASSET_POLICY_SEED: &[u8] = b"asset_policy";
+
+/// Seed for the AssetRegistry PDA (assets listed with register_asset)
+pub const ASSET_REGISTRY_SEED: &[u8] = b"asset_registry";
+
+/// Seed prefix for issuer allowlist entries, derived under the issuer's
+/// allowlist program (combined with mint + wallet)
+pub const ISSUER_ALLOWLIST_SEED: &[u8] = b"allowlist";
+
+/// Seed for the diagnostics (compute profiling) account
+pub const DIAGNOSTICS_SEED: &[u8] = b"diagnostics";
+
+/// Seed for the per-pair execution policy account
+pub const EXECUTION_POLICY_SEED: &[u8] = b"execution_policy";
+
+/// Seed for the PairRegistry PDA (per-pair switches and fees)
+pub const PAIR_REGISTRY_SEED: &[u8] = b"pair_registry";
+
+/// Seed for the MarketSchedule PDA (equity trading hours)
+pub const MARKET_SCHEDULE_SEED: &[u8] = b"market_schedule";
+
+/// Seed for the OracleConfig PDA (price feeds and their limits)
+pub const ORACLE_CONFIG_SEED: &[u8] = b"oracle_config";
+
+/// Seed for a user's SettlementPreference PDA (combined with owner pubkey)
+pub const SETTLEMENT_PREFERENCE_SEED: &[u8] = b"settlement_preference";
+
+/// Seed for a user's ViewableBalance PDA (combined with owner pubkey)
+pub const VIEWABLE_BALANCE_SEED: &[u8] = b"viewable_balance";
+
+/// Seed for a ViewerGrant PDA (combined with owner pubkey and viewer key)
+pub const VIEWER_GRANT_SEED: &[u8] = b"viewer_grant";
+
+/// Seed for a TransferRequest PDA (combined with sender pubkey and transfer_id)
+pub const TRANSFER_REQUEST_SEED: &[u8] = b"transfer_request";
+
+/// Seed for a sender's TransferMemo PDA (combined with sender pubkey)
+pub const TRANSFER_MEMO_SEED: &[u8] = b"transfer_memo";
+
+/// Seed for a user's KeyRotation PDA (combined with owner pubkey)
+pub const KEY_ROTATION_SEED: &[u8] = b"key_rotation";
+
+/// Seed for a user's AccountClosure PDA (combined with owner pubkey)
+pub const ACCOUNT_CLOSURE_SEED: &[u8] = b"account_closure";
+
+/// Seed for an in-flight deposit/withdrawal PendingOperation PDA
+/// (combined with owner pubkey and computation_offset)
+pub const PENDING_OPERATION_SEED: &[u8] = b"pending_operation";
+
+/// Seed for a SavingsBucket PDA (combined with owner pubkey and bucket_id)
+pub const SAVINGS_BUCKET_SEED: &[u8] = b"savings_bucket";
+
+/// Seed for a PaymentStream PDA (combined with payer pubkey and stream_id)
+pub const PAYMENT_STREAM_SEED: &[u8] = b"payment_stream";
+
+/// Seed for a Voucher PDA (combined with the voucher's claim key)
+pub const VOUCHER_SEED: &[u8] = b"voucher";
+
+/// Seed prefix for the RiskConfig PDA (combined with pool_id)
+pub const RISK_CONFIG_SEED: &[u8] = b"risk_config";
+
+/// Seed prefix for per-wallet withdrawal windows (combined with pool_id and owner)
+pub const WITHDRAWAL_WINDOW_SEED: &[u8] = b"withdrawal_window";
+
+/// Seed prefix for the Roles PDA (combined with pool_id)
+pub const ROLES_SEED: &[u8] = b"roles";
+
+/// Seed for the RentSponsor pool PDA
+pub const RENT_SPONSOR_SEED: &[u8] = b"rent_sponsor";
+
+/// Seed for the InvariantMonitor PDA (check_invariants checkpoint)
+pub const INVARIANT_MONITOR_SEED: &[u8] = b"invariant_monitor";
+
+/// Seed for the ExecutorRegistry PDA
+pub const EXECUTOR_REGISTRY_SEED: &[u8] = b"executor_registry";
+
+/// Seed for the CircuitRegistry PDA
+pub const CIRCUIT_REGISTRY_SEED: &[u8] = b"circuit_registry";
+
+/// Seed for the ConfigSnapshot PDA (export_config)
+pub const CONFIG_SNAPSHOT_SEED: &[u8] = b"config_snapshot";
+
+/// Seed for the EventCursor PDA (per-pool event sequence)
+pub const EVENT_CURSOR_SEED: &[u8] = b"event_cursor";
+
+/// Seed for the ProtocolStats PDA (analytics)
+pub const PROTOCOL_STATS_SEED: &[u8] = b"protocol_stats";
+
 /// Seed prefix for vault accounts (user deposits)
 pub const VAULT_SEED: &[u8] = b"vault";
 
@@ -132,6 +450,20 @@ pub const RESERVE_TSLA_SEED: &[u8] = b"tsla";
 pub const RESERVE_SPY_SEED: &[u8] = b"spy";
 pub const RESERVE_AAPL_SEED: &[u8] = b"aapl";
 
+/// Seed prefix for reserve inventory auctions (combined with auction_id)
+pub const RESERVE_AUCTION_SEED: &[u8] = b"reserve_auction";
+
+// =============================================================================
+// TREASURY BUYBACK
+// =============================================================================
+// A share of USDC fees is swapped into a designated token and burned.
+
+/// Seed for the buyback config account (singleton)
+pub const BUYBACK_CONFIG_SEED: &[u8] = b"buyback_config";
+
+/// Seed prefix for the buyback token vault (combined with the buyback mint)
+pub const BUYBACK_VAULT_SEED: &[u8] = b"buyback_vault";
+
 // =============================================================================
 // FAUCET CONFIGURATION (Devnet only)
 // =============================================================================
@@ -5,6 +5,8 @@ use anchor_lang::prelude::*;
 // =============================================================================
 // These are custom errors that our program can return.
 //
+// Each variant is classified below (ErrorCode::info) so failure events can
+// carry a machine-readable ErrorInfo alongside the Anchor error number.
 
 #[error_code]
 pub enum ErrorCode {
@@ -15,6 +17,26 @@ pub enum ErrorCode {
     #[msg("Protocol is paused")]
     ProtocolPaused,
 
+    /// Asset is frozen by admin - deposits, orders and withdrawals blocked
+    #[msg("Asset is frozen")]
+    AssetFrozen,
+
+    /// Asset is not frozen
+    #[msg("Asset is not frozen")]
+    AssetNotFrozen,
+
+    /// Asset is delisted - deposits and new orders blocked, withdrawals allowed
+    #[msg("Asset is delisted")]
+    AssetDelisted,
+
+    /// No unfreeze has been requested for this asset
+    #[msg("Unfreeze not requested")]
+    UnfreezeNotRequested,
+
+    /// Unfreeze timelock has not elapsed yet
+    #[msg("Unfreeze timelock has not elapsed")]
+    UnfreezeTimelockActive,
+
     // =========================================================================
     // AUTHORIZATION ERRORS
     // =========================================================================
@@ -41,6 +63,18 @@ pub enum ErrorCode {
     #[msg("Invalid pair ID (must be 0-5)")]
     InvalidPairId,
 
+    /// register_asset for a built-in or already registered mint
+    #[msg("Asset is already registered")]
+    AssetAlreadyRegistered,
+
+    /// register_asset with MAX_REGISTERED_ASSETS assets listed
+    #[msg("Asset registry is full")]
+    AssetRegistryFull,
+
+    /// Asset symbol empty, too long or not ASCII alphanumeric
+    #[msg("Invalid asset symbol")]
+    InvalidAssetSymbol,
+
     /// Token mint address doesn't match expected (wrong token)
     #[msg("Invalid token mint")]
     InvalidMint,
@@ -49,10 +83,96 @@ pub enum ErrorCode {
     #[msg("Invalid token account owner")]
     InvalidOwner,
 
+    /// Asset transfer policy does not match the mint or asset
+    #[msg("Invalid asset transfer policy")]
+    InvalidAssetPolicy,
+
+    /// Wallet has no entry on the issuer allowlist for a restricted asset
+    #[msg("Wallet is not on the issuer allowlist")]
+    NotAllowlisted,
+
+    /// Protocol token account has a delegate approved
+    #[msg("Vault has a delegate set")]
+    VaultHasDelegate,
+
+    /// Protocol token account has a close authority set
+    #[msg("Vault has a close authority set")]
+    VaultHasCloseAuthority,
+
     /// Execution fee cannot exceed 10% (1000 basis points)
     #[msg("Fee too high (max 10%)")]
     FeeTooHigh,
 
+    /// sweep_fees called with no accrued execution fees for any asset
+    #[msg("No execution fees owed")]
+    NoFeesOwed,
+
+    /// Batch trigger thresholds out of range
+    #[msg("Invalid batch config")]
+    InvalidBatchConfig,
+
+    /// Keeper reward above MAX_KEEPER_REWARD_BPS
+    #[msg("Keeper reward too high")]
+    InvalidKeeperReward,
+
+    /// Co-operator must differ from the operator when a threshold is set
+    #[msg("Invalid batch approval config")]
+    InvalidApprovalConfig,
+
+    /// Operator or treasury set to the default (all-zero) address
+    #[msg("Invalid operator or treasury address")]
+    InvalidPoolAddress,
+
+    /// Unknown role, or member already granted / not granted the role
+    #[msg("Invalid role or role member")]
+    InvalidRole,
+
+    /// Role already has MAX_ROLE_MEMBERS members
+    #[msg("Role has no free member slot")]
+    RoleFull,
+
+    /// Risk limits out of range (or a notional cap without a co-operator)
+    #[msg("Invalid risk config")]
+    InvalidRiskConfig,
+
+    /// Pool.risk_configured is set but the RiskConfig (or the wallet's
+    /// WithdrawalWindow, for a capped asset) was not passed
+    #[msg("Risk config not passed")]
+    RiskConfigMissing,
+
+    /// Withdrawal would exceed RiskConfig.max_daily_withdrawal for the window
+    #[msg("Daily withdrawal cap exceeded")]
+    WithdrawalCapExceeded,
+
+    /// Config blob was exported with a different CONFIG_VERSION
+    #[msg("Unsupported config version")]
+    UnsupportedConfigVersion,
+
+    /// migrate_* called on an account already in the current layout
+    #[msg("Account is already migrated")]
+    AccountAlreadyMigrated,
+
+    /// Account size matches no known version of its layout (a pre-audit
+    /// UserProfile goes through migrate_user_account first)
+    #[msg("Unsupported account layout")]
+    UnsupportedAccountLayout,
+
+    /// Viewing key cannot be all zeroes (or, for a viewer, the owner's key)
+    #[msg("Invalid viewing key")]
+    InvalidViewingKey,
+
+    /// New user key cannot be all zeroes or the current key
+    #[msg("Invalid user key")]
+    InvalidUserKey,
+
+    /// Deposit amount encrypted under the same nonce as the initial balances
+    #[msg("Deposit nonce must differ from the initial balance nonce")]
+    DepositNonceReused,
+
+    /// PendingOperation belongs to another computation or is the wrong kind
+    #[msg("Invalid pending operation")]
+    InvalidPendingOperation,
+
     // =========================================================================
     // ORDER/BATCH STATE ERRORS
     // =========================================================================
@@ -64,10 +184,90 @@ pub enum ErrorCode {
     #[msg("No pending order to settle")]
     NoPendingOrder,
 
+    /// No order staged by settle_and_place_order
+    #[msg("No staged order to place")]
+    NoStagedOrder,
+
+    /// valid_until_batch is before the batch the order would be placed in
+    #[msg("Order validity ends before the current batch")]
+    InvalidOrderValidity,
+
+    /// User already has a basket order queued or awaiting settlement
+    #[msg("A basket order is already in progress")]
+    BasketOrderActive,
+
+    /// No placed basket order to settle
+    #[msg("No basket order to settle")]
+    NoBasketOrder,
+
+    /// Basket leg index out of range or already settled
+    #[msg("Invalid basket leg")]
+    InvalidBasketLeg,
+
+    /// MPC rejected a basket: short balance, weights not summing to 10_000
+    /// or a leg route out of range
+    #[msg("Basket order rejected")]
+    BasketRejected,
+
+    /// Settlement route (pair_id, direction) does not match the encrypted order
+    #[msg("Settlement route does not match the order")]
+    InvalidOrderRoute,
+
+    /// Encrypted order had pair_id > 5 or direction > 1 (checked in MPC)
+    #[msg("Order pair or direction out of range")]
+    OrderRouteOutOfRange,
+
+    /// force_settle called before the grace period after batch execution
+    #[msg("Force settlement grace period has not elapsed")]
+    SettlementGracePeriodActive,
+
+    /// forfeit_order called before Pool.settlement_deadline_secs elapsed
+    #[msg("Settlement deadline has not passed")]
+    SettlementDeadlineNotReached,
+
+    /// Settlement deadline below MIN_SETTLEMENT_DEADLINE_SECS
+    #[msg("Settlement deadline too short")]
+    InvalidSettlementDeadline,
+
+    /// Force settlement grace period not positive or not below the
+    /// settlement deadline
+    #[msg("Invalid force settlement grace period")]
+    InvalidForceSettleGrace,
+
+    /// BatchLog still has unsettled orders or pending swaps
+    #[msg("Batch log still in use")]
+    BatchLogInUse,
+
+    /// Pair's net surplus has not been routed to the external venue yet
+    #[msg("External route pending for this pair")]
+    ExternalRoutePending,
+
+    /// Token account is not the expected protocol deposit vault
+    #[msg("Invalid vault account")]
+    InvalidVault,
+
     /// Trying to settle from a batch that hasn't been executed yet
     #[msg("Batch not yet executed")]
     BatchNotFinalized,
 
+    /// Batch notional is above the approval threshold and the co-operator
+    /// has not approved it yet
+    #[msg("Batch requires co-operator approval")]
+    BatchApprovalRequired,
+
+    /// A pair executed outside its price band and the authority has not
+    /// overridden it yet
+    #[msg("Batch price outside the pair's price band")]
+    PriceBandBreached,
+
+    /// override_price_band on a batch with every pair inside its band
+    #[msg("Batch is within its price bands")]
+    PriceBandNotBreached,
+
+    /// MAX_REVEAL_RETRIES reached for this batch; reset_batch discards it
+    #[msg("Batch reveal retries exhausted")]
+    RevealRetriesExhausted,
+
     /// Batch ID mismatch during settlement
     #[msg("Batch ID mismatch")]
     BatchIdMismatch,
@@ -76,10 +276,111 @@ pub enum ErrorCode {
     #[msg("Invalid batch ID - doesn't match BatchLog")]
     InvalidBatchId,
 
+    /// Batch is locked while its reveal computation is in flight
+    #[msg("Batch reveal in progress - orders are closed")]
+    BatchRevealInProgress,
+
+    /// BatchLog already holds results (batch ID collision)
+    #[msg("Batch already executed")]
+    BatchAlreadyExecuted,
+
+    /// init_batch_state called on a slot that already holds encrypted zeros
+    /// (use reset_batch to re-encrypt it)
+    #[msg("Batch state already initialized")]
+    BatchStateAlreadyInitialized,
+
+    /// recover_batch_reveal called while no reveal is pending
+    #[msg("No batch reveal in progress")]
+    BatchNotRevealing,
+
+    /// recover_batch_reveal called before BATCH_REVEAL_TIMEOUT_SECS elapsed
+    #[msg("Batch reveal is not stale yet")]
+    BatchRevealNotStale,
+
     /// Swaps have already been executed for this batch
     #[msg("Swaps already executed for this batch")]
     SwapsAlreadyExecuted,
 
+    /// Settlement waits for execute_swaps, which may still reduce the fills
+    #[msg("Swaps not yet executed for this batch")]
+    SwapsNotExecuted,
+
+    /// Batch accumulator is not the slot this operation needs
+    /// (e.g. an order sent to the inactive slot)
+    #[msg("Wrong batch accumulator slot")]
+    InvalidBatchSlot,
+
+    /// The other accumulator slot is still revealing or holds a recovered batch
+    #[msg("Next batch accumulator slot is not free yet")]
+    BatchSlotBusy,
+
+    /// execute_batch below Pool.execution_trigger_count orders by someone
+    /// other than the operator
+    #[msg("Batch does not have enough orders to execute")]
+    BatchNotReady,
+
+    /// execute_batch over Pool.max_batches_per_window before the current
+    /// batch reached max_batch_wait_secs
+    #[msg("Batch execution rate limit reached")]
+    BatchRateLimited,
+
+    /// execute_batch with fewer distinct wallets than Pool.min_unique_users
+    #[msg("Batch is below the privacy floor")]
+    PrivacyFloorNotMet,
+
+    /// reset_batch called with an unknown BATCH_RESET_REASON_* code
+    #[msg("Invalid batch reset reason")]
+    InvalidResetReason,
+
+    /// admin_clear_pending called with an unknown CLEAR_PENDING_REASON_* code
+    #[msg("Invalid clear pending reason")]
+    InvalidClearReason,
+
+    /// execute_batch outside trading hours when every pair follows the
+    /// MarketSchedule; the orders wait for the open
+    #[msg("Market is closed")]
+    MarketClosed,
+
+    /// set_market_schedule with open >= close, close past midnight, or too
+    /// many holidays
+    #[msg("Invalid market schedule")]
+    InvalidMarketSchedule,
+
+    /// Pool.market_hours_enforced is set but no MarketSchedule was passed
+    #[msg("Market schedule not passed")]
+    MarketScheduleMissing,
+
+    /// Pool.pair_registry_configured is set but no PairRegistry was passed
+    #[msg("Pair registry not passed")]
+    PairRegistryMissing,
+
+    /// Pool.execution_policy_configured is set but no ExecutionPolicy was passed
+    #[msg("Execution policy not passed")]
+    ExecutionPolicyMissing,
+
+    // =========================================================================
+    // DCA ERRORS
+    // =========================================================================
+    /// DCA interval is shorter than the allowed minimum
+    #[msg("DCA interval too short")]
+    InvalidDcaInterval,
+
+    /// DCA schedule is not due for execution yet
+    #[msg("DCA execution not due yet")]
+    DcaNotDue,
+
+    /// DCA schedule has reached its maximum number of executions
+    #[msg("DCA schedule completed")]
+    DcaCompleted,
+
+    /// DCA schedule is paused
+    #[msg("DCA schedule is paused")]
+    DcaPaused,
+
+    /// DCA schedule is not paused
+    #[msg("DCA schedule is not paused")]
+    DcaNotPaused,
+
     // =========================================================================
     // BALANCE ERRORS
     // =========================================================================
@@ -87,6 +388,22 @@ pub enum ErrorCode {
     #[msg("Insufficient balance")]
     InsufficientBalance,
 
+    /// Rent sponsor pool cannot cover the withdrawal and stay rent-exempt
+    #[msg("Insufficient rent sponsor funds")]
+    InsufficientSponsorFunds,
+
+    /// close_user_account: a balance is not zero
+    #[msg("User account balances are not zero")]
+    UserAccountNotEmpty,
+
+    /// The profile's rent was sponsored but no RentSponsor was passed
+    #[msg("Rent sponsor not passed")]
+    RentSponsorMissing,
+
+    /// Savings bucket missing, or not the one the DCA schedule draws from
+    #[msg("Invalid savings bucket")]
+    InvalidSavingsBucket,
+
     // =========================================================================
     // SWAP EXECUTION ERRORS
     // =========================================================================
@@ -94,10 +411,72 @@ pub enum ErrorCode {
     #[msg("Minimum output not met")]
     MinOutputNotMet,
 
+    /// Jupiter route data is malformed, swaps a different amount, or
+    /// touches a pool token account other than the two route vaults
+    #[msg("Invalid external swap route")]
+    InvalidSwapRoute,
+
+    /// Route slippage above the requested or maximum slippage
+    #[msg("Swap slippage too high")]
+    SlippageTooHigh,
+
     /// Division by zero during settlement calculation
     #[msg("Division by zero in settlement - no input for this pair")]
     DivisionByZero,
 
+    /// Fee valuation or accrual overflowed during swap execution
+    #[msg("Arithmetic overflow")]
+    MathOverflow,
+
+    /// Unknown execution mode, or spread / utilization limit out of range
+    #[msg("Invalid execution mode config")]
+    InvalidExecutionConfig,
+
+    /// Netting buffer can only be flushed once its window has elapsed
+    #[msg("Netting window still open")]
+    NettingWindowOpen,
+
+    /// Netting buffer holds no inventory for this pair
+    #[msg("Netting buffer is empty")]
+    NettingBufferEmpty,
+
+    // =========================================================================
+    // ORACLE ERRORS
+    // =========================================================================
+    /// An asset has a feed configured but its Pyth price update or
+    /// Switchboard feed account was not passed
+    #[msg("Price update account missing")]
+    PriceUpdateMissing,
+
+    /// Pool.oracle_configured is set but no OracleConfig was passed
+    #[msg("Oracle config not passed")]
+    OracleConfigMissing,
+
+    /// Account is not a fully verified Pyth PriceUpdateV2, or its price is not positive
+    #[msg("Invalid price update account")]
+    InvalidPriceUpdate,
+
+    /// Price update is for a different feed than the one configured on Pool
+    #[msg("Price feed mismatch")]
+    PriceFeedMismatch,
+
+    /// Price was published longer ago than OracleConfig.max_price_age_secs
+    #[msg("Oracle price too stale")]
+    PriceTooStale,
+
+    /// Confidence interval is wider than OracleConfig.max_price_conf_bps of the price
+    #[msg("Oracle price confidence too wide")]
+    PriceConfidenceTooWide,
+
+    /// Pyth and Switchboard prices are further apart than
+    /// OracleConfig.max_price_divergence_bps
+    #[msg("Oracle prices diverge")]
+    PriceDivergence,
+
+    /// Price age, confidence or divergence limit out of range
+    #[msg("Invalid oracle config")]
+    InvalidOracleConfig,
+
     // =========================================================================
     // ARCIUM MPC ERRORS
     // =========================================================================
@@ -113,6 +492,48 @@ pub enum ErrorCode {
     #[msg("Cluster not set")]
     ClusterNotSet,
 
+    /// Callback priority fee above MAX_CALLBACK_CU_PRICE_MICRO
+    #[msg("Callback priority fee too high")]
+    InvalidCallbackPriorityFee,
+
+    /// Viewing key was replaced while a re-encryption was queued
+    #[msg("Viewing key changed during refresh")]
+    ViewingKeyChanged,
+
+    /// A balance changed while a key rotation was queued
+    #[msg("Balances changed during key rotation")]
+    KeyRotationStale,
+
+    /// A balance changed while an account closure was queued
+    #[msg("Balances changed during account closure")]
+    AccountClosureStale,
+
+    /// A balance of either profile changed while a recovery was queued
+    #[msg("Balances changed during account recovery")]
+    RecoveryStale,
+
+    /// The balance changed while a deposit, withdrawal, order or settlement
+    /// was queued
+    #[msg("Balance changed while the operation was pending")]
+    PendingOperationStale,
+
+    /// cancel_withdrawal called before WITHDRAWAL_CANCEL_TIMEOUT_SECS elapsed
+    #[msg("Withdrawal cannot be cancelled yet")]
+    WithdrawalCancelTimeoutActive,
+
+    /// upgrade_circuit called with a zero hash or the hash already registered
+    #[msg("Invalid circuit hash")]
+    InvalidCircuitHash,
+
+    /// All MAX_CIRCUITS registry entries are in use
+    #[msg("Circuit registry is full")]
+    CircuitRegistryFull,
+
+    /// bootstrap got a comp def account off the BOOTSTRAP_COMP_DEFS order
+    /// (or past its end)
+    #[msg("Comp def account does not match")]
+    InvalidCompDefAccount,
+
     // =========================================================================
     // P2P TRANSFER ERRORS
     // =========================================================================
@@ -120,6 +541,214 @@ pub enum ErrorCode {
     #[msg("Recipient account not found - they must create a privacy account first")]
     RecipientAccountNotFound,
 
+    /// Claim window outside MIN/MAX_TRANSFER_EXPIRY_SECS, or recipient is the sender
+    #[msg("Invalid transfer request")]
+    InvalidTransferRequest,
+
+    /// Transfer request is not open (escrow pending or already released)
+    #[msg("Transfer request is not open")]
+    TransferRequestNotOpen,
+
+    /// Claim window has passed - only the sender can reclaim
+    #[msg("Transfer request expired")]
+    TransferRequestExpired,
+
+    /// Sender can only reclaim once the claim window has passed
+    #[msg("Transfer request has not expired")]
+    TransferRequestNotExpired,
+
+    /// reset_transfer_request: the request is not ESCROWING or RELEASING
+    #[msg("Transfer request has no computation in flight")]
+    TransferRequestNotPending,
+
+    /// reset_transfer_request: the computation may still complete
+    #[msg("Transfer request cannot be reset yet")]
+    TransferResetTimeoutActive,
+
+    /// Memo outside MIN_TRANSFER_MEMO_LEN..=MAX_TRANSFER_MEMO_LEN bytes
+    #[msg("Invalid transfer memo")]
+    InvalidTransferMemo,
+
+    /// A memo was given but no TransferMemo account was passed
+    #[msg("Transfer memo account not passed")]
+    TransferMemoMissing,
+
+    /// transfer_batch recipients: 1..=MAX_BATCH_TRANSFER_RECIPIENTS distinct,
+    /// writable UserProfiles of the pool, excluding the sender
+    #[msg("Invalid batch transfer recipients")]
+    InvalidBatchRecipients,
+
+    /// Stream window empty or in the past, payee is the payer, or the
+    /// payee's account is not the stream's
+    #[msg("Invalid payment stream")]
+    InvalidPaymentStream,
+
+    /// advance_stream before the start, or with everything up to now paid
+    #[msg("Nothing accrued on the stream yet")]
+    StreamNothingAccrued,
+
+    /// Voucher claim window outside MIN/MAX_TRANSFER_EXPIRY_SECS
+    #[msg("Invalid voucher")]
+    InvalidVoucher,
+
+    /// Voucher is not open (funding pending or already redeemed)
+    #[msg("Voucher is not open")]
+    VoucherNotOpen,
+
+    /// Claim window has passed - only the funder can reclaim
+    #[msg("Voucher expired")]
+    VoucherExpired,
+
+    /// Funder can only reclaim once the claim window has passed
+    #[msg("Voucher has not expired")]
+    VoucherNotExpired,
+
+    /// reset_voucher: the voucher is not FUNDING or REDEEMING
+    #[msg("Voucher has no computation in flight")]
+    VoucherNotPending,
+
+    /// reset_voucher: the computation may still complete
+    #[msg("Voucher cannot be reset yet")]
+    VoucherResetTimeoutActive,
+
+    // =========================================================================
+    // RESERVE AUCTION ERRORS
+    // =========================================================================
+    /// Auction is not in the status required for this operation
+    #[msg("Invalid auction status")]
+    InvalidAuctionStatus,
+
+    /// Bidding window has closed
+    #[msg("Auction bidding has closed")]
+    AuctionBiddingClosed,
+
+    /// Bidding window is still open
+    #[msg("Auction bidding is still open")]
+    AuctionStillOpen,
+
+    /// Auction already holds the maximum number of bids
+    #[msg("Auction bid limit reached")]
+    AuctionFull,
+
+    /// Bidder already submitted a bid to this auction
+    #[msg("Bidder already placed a bid")]
+    DuplicateBid,
+
+    /// Token account is not the expected protocol reserve
+    #[msg("Invalid reserve account")]
+    InvalidReserve,
+
+    // =========================================================================
+    // BENEFICIARY & GUARDIAN RECOVERY ERRORS
+    // =========================================================================
+    /// Inactivity period below MIN_INACTIVITY_PERIOD_SECS
+    #[msg("Inactivity period too short")]
+    InvalidInactivityPeriod,
+
+    /// Beneficiary cannot be the owner
+    #[msg("Invalid beneficiary")]
+    InvalidBeneficiary,
+
+    /// Owner has been active within the inactivity period
+    #[msg("Owner is still active")]
+    OwnerStillActive,
+
+    /// Guardians: 1..=MAX_GUARDIANS distinct wallets other than the owner,
+    /// threshold within 1..=count, challenge >= MIN_RECOVERY_CHALLENGE_SECS
+    #[msg("Invalid guardian configuration")]
+    InvalidGuardians,
+
+    /// Recovery to the owner or default wallet, a zero key, or a new
+    /// profile whose key is not the approved one
+    #[msg("Invalid recovery")]
+    InvalidRecovery,
+
+    /// A different recovery already reached the threshold
+    #[msg("Another recovery is already approved")]
+    RecoveryAlreadyApproved,
+
+    /// Fewer than `threshold` guardians approved the recovery
+    #[msg("Recovery not approved")]
+    RecoveryNotApproved,
+
+    /// Challenge period after approval has not elapsed
+    #[msg("Recovery challenge period active")]
+    RecoveryChallengeActive,
+
+    // =========================================================================
+    // DELEGATION ERRORS
+    // =========================================================================
+    /// Session key cannot be the owner or the default pubkey
+    #[msg("Invalid delegate")]
+    InvalidDelegate,
+
+    /// Expiry must be in the future and within MAX_DELEGATE_DURATION_SECS
+    #[msg("Invalid delegate expiry")]
+    InvalidDelegateExpiry,
+
+    /// Session key delegation has expired
+    #[msg("Delegate has expired")]
+    DelegateExpired,
+
+    /// Session key already placed its maximum orders for this batch
+    #[msg("Delegate order limit reached for this batch")]
+    DelegateOrderLimitReached,
+
+    /// Delegate has an allowance for the source asset and must use
+    /// place_delegated_order
+    #[msg("Delegate must place orders for this asset against its allowance")]
+    DelegateAllowanceRequired,
+
+    /// place_delegated_order or delegated_withdraw for an asset without an allowance
+    #[msg("No delegate allowance for this asset")]
+    NoDelegateAllowance,
+
+    /// Delegated order or withdrawal larger than the remaining allowance
+    #[msg("Amount exceeds the delegate allowance")]
+    DelegateAllowanceExceeded,
+
+    // =========================================================================
+    // BUYBACK ERRORS
+    // =========================================================================
+    /// Buyback module is disabled by governance
+    #[msg("Buyback is disabled")]
+    BuybackDisabled,
+
+    /// Minimum interval since the last buyback has not elapsed
+    #[msg("Buyback interval has not elapsed")]
+    BuybackTooSoon,
+
+    /// Requested USDC exceeds the per-execution cap or the fee budget
+    #[msg("Buyback amount exceeds cap or available fee budget")]
+    BuybackBudgetExceeded,
+
+    /// Swap program does not match the configured adapter
+    #[msg("Invalid swap program")]
+    InvalidSwapProgram,
+
+    // =========================================================================
+    // EXECUTOR REGISTRY ERRORS
+    // =========================================================================
+    /// Execution is registry-gated and the caller has no bond registered
+    #[msg("Executor is not registered")]
+    ExecutorNotRegistered,
+
+    /// Executor already holds a bond in the registry
+    #[msg("Executor already registered")]
+    ExecutorAlreadyRegistered,
+
+    /// Bond below ExecutorRegistry.min_bond_lamports
+    #[msg("Executor bond too low")]
+    ExecutorBondTooLow,
+
+    /// All MAX_EXECUTORS registry entries are in use
+    #[msg("Executor registry is full")]
+    ExecutorRegistryFull,
+
+    /// Config enables gating or a bond but no ExecutorRegistry was passed
+    #[msg("Executor registry not initialized")]
+    ExecutorRegistryMissing,
+
     // =========================================================================
     // FAUCET ERRORS
     // =========================================================================
@@ -127,3 +756,293 @@ pub enum ErrorCode {
     #[msg("Faucet limit exceeded - you can only claim up to 1000 USDC total")]
     FaucetLimitExceeded,
 }
+
+// =============================================================================
+// ERROR TAXONOMY
+// =============================================================================
+// Failure events (OrderRejectedEvent, SettlementRejectedEvent,
+// WithdrawalRejectedEvent, BatchExecutionFailedEvent, CallbackFailedEvent)
+// carry an ErrorInfo so clients can map failures to UI messages without
+// parsing msg! logs:
+// - code: Anchor error number (6000 + variant index), same as the
+//   transaction error
+// - domain: which part of the protocol rejected the request
+// - retryable: whether re-sending the same request later can succeed
+//   (timing, in-flight state, congestion) rather than needing new input
+//
+// The domain match is exhaustive, so new variants must be classified.
+
+/// Protocol area an error belongs to (mirrors the sections above)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ErrorDomain {
+    ProtocolState,
+    Authorization,
+    InputValidation,
+    OrderBatch,
+    Dca,
+    Balance,
+    SwapExecution,
+    Mpc,
+    Transfer,
+    ReserveAuction,
+    Beneficiary,
+    Delegation,
+    Buyback,
+    Faucet,
+    Executor,
+    Oracle,
+}
+
+/// Machine-readable error description included in failure events
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ErrorInfo {
+    /// Anchor error number (6000 + variant index)
+    pub code: u32,
+    pub domain: ErrorDomain,
+    pub retryable: bool,
+}
+
+impl ErrorCode {
+    /// Classification of this error for failure events
+    pub fn info(self) -> ErrorInfo {
+        ErrorInfo {
+            code: u32::from(self),
+            domain: self.domain(),
+            retryable: self.is_retryable(),
+        }
+    }
+
+    /// Protocol area the error belongs to
+    pub fn domain(self) -> ErrorDomain {
+        use ErrorCode::*;
+        match self {
+            ProtocolPaused
+            | AssetFrozen
+            | AssetNotFrozen
+            | AssetDelisted
+            | UnfreezeNotRequested
+            | UnfreezeTimelockActive => ErrorDomain::ProtocolState,
+
+            Unauthorized => ErrorDomain::Authorization,
+
+            InvalidAmount
+            | InvalidAsset
+            | InvalidAssetId
+            | InvalidPairId
+            | AssetAlreadyRegistered
+            | AssetRegistryFull
+            | InvalidAssetSymbol
+            | InvalidMint
+            | InvalidOwner
+            | InvalidAssetPolicy
+            | NotAllowlisted
+            | VaultHasDelegate
+            | VaultHasCloseAuthority
+            | FeeTooHigh
+            | NoFeesOwed
+            | InvalidBatchConfig
+            | InvalidKeeperReward
+            | InvalidApprovalConfig
+            | InvalidPoolAddress
+            | InvalidRole
+            | RoleFull
+            | InvalidRiskConfig
+            | RiskConfigMissing
+            | WithdrawalCapExceeded
+            | UnsupportedConfigVersion
+            | AccountAlreadyMigrated
+            | UnsupportedAccountLayout
+            | InvalidViewingKey
+            | InvalidUserKey
+            | DepositNonceReused
+            | InvalidPendingOperation => ErrorDomain::InputValidation,
+
+            PendingOrderExists
+            | NoPendingOrder
+            | NoStagedOrder
+            | InvalidOrderValidity
+            | BasketOrderActive
+            | NoBasketOrder
+            | InvalidBasketLeg
+            | BasketRejected
+            | InvalidOrderRoute
+            | OrderRouteOutOfRange
+            | SettlementGracePeriodActive
+            | SettlementDeadlineNotReached
+            | InvalidSettlementDeadline
+            | InvalidForceSettleGrace
+            | BatchLogInUse
+            | ExternalRoutePending
+            | InvalidVault
+            | BatchNotFinalized
+            | BatchApprovalRequired
+            | PriceBandBreached
+            | PriceBandNotBreached
+            | RevealRetriesExhausted
+            | BatchIdMismatch
+            | InvalidBatchId
+            | BatchRevealInProgress
+            | BatchAlreadyExecuted
+            | BatchStateAlreadyInitialized
+            | BatchNotRevealing
+            | BatchRevealNotStale
+            | SwapsAlreadyExecuted
+            | SwapsNotExecuted
+            | InvalidBatchSlot
+            | BatchSlotBusy
+            | BatchNotReady
+            | BatchRateLimited
+            | PrivacyFloorNotMet
+            | InvalidResetReason
+            | InvalidClearReason
+            | MarketClosed
+            | InvalidMarketSchedule
+            | MarketScheduleMissing
+            | PairRegistryMissing
+            | ExecutionPolicyMissing => ErrorDomain::OrderBatch,
+
+            InvalidDcaInterval | DcaNotDue | DcaCompleted | DcaPaused | DcaNotPaused => {
+                ErrorDomain::Dca
+            }
+
+            InsufficientBalance
+            | InsufficientSponsorFunds
+            | UserAccountNotEmpty
+            | RentSponsorMissing
+            | InvalidSavingsBucket => ErrorDomain::Balance,
+
+            MinOutputNotMet
+            | InvalidSwapRoute
+            | SlippageTooHigh
+            | DivisionByZero
+            | MathOverflow
+            | InvalidExecutionConfig
+            | NettingWindowOpen
+            | NettingBufferEmpty => ErrorDomain::SwapExecution,
+
+            PriceUpdateMissing
+            | InvalidPriceUpdate
+            | PriceFeedMismatch
+            | PriceTooStale
+            | PriceConfidenceTooWide
+            | PriceDivergence
+            | InvalidOracleConfig
+            | OracleConfigMissing => ErrorDomain::Oracle,
+
+            AbortedComputation
+            | ComputationFailed
+            | ClusterNotSet
+            | InvalidCallbackPriorityFee
+            | ViewingKeyChanged
+            | KeyRotationStale
+            | AccountClosureStale
+            | RecoveryStale
+            | PendingOperationStale
+            | WithdrawalCancelTimeoutActive
+            | InvalidCircuitHash
+            | CircuitRegistryFull
+            | InvalidCompDefAccount => ErrorDomain::Mpc,
+
+            RecipientAccountNotFound
+            | InvalidTransferRequest
+            | TransferRequestNotOpen
+            | TransferRequestExpired
+            | TransferRequestNotExpired
+            | TransferRequestNotPending
+            | TransferResetTimeoutActive
+            | InvalidTransferMemo
+            | TransferMemoMissing
+            | InvalidBatchRecipients
+            | InvalidPaymentStream
+            | StreamNothingAccrued
+            | InvalidVoucher
+            | VoucherNotOpen
+            | VoucherExpired
+            | VoucherNotExpired
+            | VoucherNotPending
+            | VoucherResetTimeoutActive => ErrorDomain::Transfer,
+
+            InvalidAuctionStatus | AuctionBiddingClosed | AuctionStillOpen | AuctionFull
+            | DuplicateBid | InvalidReserve => ErrorDomain::ReserveAuction,
+
+            InvalidInactivityPeriod
+            | InvalidBeneficiary
+            | OwnerStillActive
+            | InvalidGuardians
+            | InvalidRecovery
+            | RecoveryAlreadyApproved
+            | RecoveryNotApproved
+            | RecoveryChallengeActive => ErrorDomain::Beneficiary,
+
+            InvalidDelegate
+            | InvalidDelegateExpiry
+            | DelegateExpired
+            | DelegateOrderLimitReached
+            | DelegateAllowanceRequired
+            | NoDelegateAllowance
+            | DelegateAllowanceExceeded => ErrorDomain::Delegation,
+
+            BuybackDisabled | BuybackTooSoon | BuybackBudgetExceeded | InvalidSwapProgram => {
+                ErrorDomain::Buyback
+            }
+
+            FaucetLimitExceeded => ErrorDomain::Faucet,
+
+            ExecutorNotRegistered
+            | ExecutorAlreadyRegistered
+            | ExecutorBondTooLow
+            | ExecutorRegistryFull
+            | ExecutorRegistryMissing => ErrorDomain::Executor,
+        }
+    }
+
+    /// Whether the same request can succeed if re-sent later unchanged
+    pub fn is_retryable(self) -> bool {
+        use ErrorCode::*;
+        matches!(
+            self,
+            ProtocolPaused
+                | AssetFrozen
+                | UnfreezeTimelockActive
+                | PendingOrderExists
+                | BasketOrderActive
+                | SettlementGracePeriodActive
+                | SettlementDeadlineNotReached
+                | BatchLogInUse
+                | ExternalRoutePending
+                | BatchNotFinalized
+                | SwapsNotExecuted
+                | BatchApprovalRequired
+                | PriceBandBreached
+                | BatchRevealInProgress
+                | BatchRevealNotStale
+                | BatchSlotBusy
+                | BatchNotReady
+                | BatchRateLimited
+                | PrivacyFloorNotMet
+                | MarketClosed
+                | DcaNotDue
+                | StreamNothingAccrued
+                | WithdrawalCapExceeded
+                | RecoveryChallengeActive
+                | MinOutputNotMet
+                | NettingWindowOpen
+                | PriceTooStale
+                | PriceConfidenceTooWide
+                | PriceDivergence
+                | AbortedComputation
+                | ComputationFailed
+                | KeyRotationStale
+                | AccountClosureStale
+                | RecoveryStale
+                | PendingOperationStale
+                | WithdrawalCancelTimeoutActive
+                | TransferResetTimeoutActive
+                | VoucherResetTimeoutActive
+                | AuctionStillOpen
+                | OwnerStillActive
+                | DelegateOrderLimitReached
+                | BuybackTooSoon
+        )
+    }
+}
@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+
+use crate::{AcceptAuthority, AuthorityTransferredEvent};
+
+/// Accept a pending authority transfer. Only callable by the proposed
+/// wallet (checked in the accounts struct).
+pub fn handler(ctx: Context<AcceptAuthority>) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+    let previous_authority = pool.authority;
+    pool.authority = ctx.accounts.pending_authority.key();
+    pool.pending_authority = Pubkey::default();
+
+    emit!(AuthorityTransferredEvent {
+        previous_authority,
+        authority: pool.authority,
+        seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+    });
+
+    msg!(
+        "Authority transferred: {} -> {}",
+        previous_authority,
+        pool.authority
+    );
+    Ok(())
+}
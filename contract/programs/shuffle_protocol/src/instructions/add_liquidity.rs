@@ -1,14 +1,16 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Transfer};
 
 use crate::errors::ErrorCode;
-use crate::AddLiquidity;
+use crate::{transfer_checked_with_hook, AddLiquidity, CheckedTransferAccounts};
 
 // =============================================================================
 // ADD LIQUIDITY - Admin instruction to add tokens to protocol reserves
 // =============================================================================
 // Allows the protocol authority to deposit tokens into reserve vaults.
 // These reserves are used to fulfill net surplus during batch execution.
+// Goes through transfer_checked, so Token-2022 mints work; transfer-hook
+// extra accounts are passed as remaining accounts, and a transfer-fee mint
+// credits the reserve with the amount net of its fee.
 
 /// Add liquidity to protocol reserves.
 /// Only callable by the pool authority (admin).
@@ -16,7 +18,11 @@ use crate::AddLiquidity;
 /// # Arguments
 /// * `asset_id` - Asset to add (0=USDC, 1=TSLA, 2=SPY, 3=AAPL)
 /// * `amount` - Amount to transfer to reserves
-pub fn handler(ctx: Context<AddLiquidity>, asset_id: u8, amount: u64) -> Result<()> {
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, AddLiquidity<'info>>,
+    asset_id: u8,
+    amount: u64,
+) -> Result<()> {
     // Validate asset_id
     require!(asset_id <= 3, ErrorCode::InvalidAssetId);
 
@@ -25,22 +31,26 @@ pub fn handler(ctx: Context<AddLiquidity>, asset_id: u8, amount: u64) -> Result<
         ctx.accounts.authority.key() == ctx.accounts.pool.authority,
         ErrorCode::Unauthorized
     );
+    require!(
+        ctx.accounts.pool.mint_for_asset(asset_id) == Some(ctx.accounts.mint.key()),
+        ErrorCode::InvalidMint
+    );
 
     // Transfer tokens from authority's token account to reserve vault
-    let transfer_ctx = CpiContext::new(
-        ctx.accounts.token_program.to_account_info(),
-        Transfer {
-            from: ctx.accounts.authority_token_account.to_account_info(),
-            to: ctx.accounts.reserve_vault.to_account_info(),
-            authority: ctx.accounts.authority.to_account_info(),
+    transfer_checked_with_hook(
+        CheckedTransferAccounts {
+            token_program: &ctx.accounts.token_program.to_account_info(),
+            from: &ctx.accounts.authority_token_account.to_account_info(),
+            mint: &ctx.accounts.mint.to_account_info(),
+            to: &ctx.accounts.reserve_vault.to_account_info(),
+            authority: &ctx.accounts.authority.to_account_info(),
+            hook_accounts: ctx.remaining_accounts,
         },
-    );
-    token::transfer(transfer_ctx, amount)?;
-
-    msg!(
-        "Added {} units of asset {} to reserves",
         amount,
-        asset_id
-    );
+        ctx.accounts.mint.decimals,
+        &[],
+    )?;
+
+    msg!("Added {} units of asset {} to reserves", amount, asset_id);
     Ok(())
 }
@@ -0,0 +1,108 @@
+use anchor_lang::prelude::*;
+use arcium_anchor::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::{AddViewer, DiscloseBalancesCallback, ViewerAddedEvent};
+
+// =============================================================================
+// ADD VIEWER - Disclose balances to an auditor
+// =============================================================================
+// Creates (or refreshes) the ViewerGrant for viewer_pubkey and queues
+// disclose_balances, which re-encrypts all four balances for the viewer.
+// The owner's balances are left as is.
+//
+// Flow:
+// 1. Owner calls add_viewer with the auditor's key and a fresh nonce
+// 2. Handler queues disclose_balances; balance i is encrypted with nonce + i
+// 3. Callback stores the ciphertexts in the grant
+
+/// Grant a viewer read access to the caller's balances, or refresh the grant.
+///
+/// # Arguments
+/// * `computation_offset` - Unique ID for MPC computation
+/// * `viewer_pubkey` - x25519 public key of the auditor
+/// * `nonce` - Fresh nonce; balance i (0=USDC..3=AAPL) uses nonce + i
+pub fn handler(
+    ctx: Context<AddViewer>,
+    computation_offset: u64,
+    viewer_pubkey: [u8; 32],
+    nonce: u128,
+) -> Result<()> {
+    let user = &ctx.accounts.user_account;
+    require!(
+        viewer_pubkey != [0u8; 32] && viewer_pubkey != user.user_pubkey,
+        ErrorCode::InvalidViewingKey
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let grant = &mut ctx.accounts.viewer_grant;
+    if grant.granted_at == 0 {
+        grant.owner = ctx.accounts.user.key();
+        grant.viewer_pubkey = viewer_pubkey;
+        grant.granted_at = now;
+        grant.bump = ctx.bumps.viewer_grant;
+
+        emit!(ViewerAddedEvent {
+            owner: grant.owner,
+            viewer_pubkey,
+            seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+        });
+    }
+
+    ctx.accounts.user_account.last_active_at = now;
+
+    // Set sign PDA bump
+    ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+    let user = &ctx.accounts.user_account;
+    let args = ArgBuilder::new()
+        // Current balances (Enc<Shared, *> - owner's pubkey)
+        .x25519_pubkey(user.user_pubkey)
+        .plaintext_u128(user.usdc_nonce)
+        .encrypted_u64(user.usdc_credit)
+        .x25519_pubkey(user.user_pubkey)
+        .plaintext_u128(user.tsla_nonce)
+        .encrypted_u64(user.tsla_credit)
+        .x25519_pubkey(user.user_pubkey)
+        .plaintext_u128(user.spy_nonce)
+        .encrypted_u64(user.spy_credit)
+        .x25519_pubkey(user.user_pubkey)
+        .plaintext_u128(user.aapl_nonce)
+        .encrypted_u64(user.aapl_credit)
+        // Viewer (Shared - auditor's key, one nonce per balance)
+        .x25519_pubkey(viewer_pubkey)
+        .plaintext_u128(nonce)
+        .x25519_pubkey(viewer_pubkey)
+        .plaintext_u128(nonce.wrapping_add(1))
+        .x25519_pubkey(viewer_pubkey)
+        .plaintext_u128(nonce.wrapping_add(2))
+        .x25519_pubkey(viewer_pubkey)
+        .plaintext_u128(nonce.wrapping_add(3))
+        .build();
+
+    use arcium_client::idl::arcium::types::CallbackAccount;
+    queue_computation(
+        ctx.accounts,
+        computation_offset,
+        args,
+        vec![DiscloseBalancesCallback::callback_ix(
+            computation_offset,
+            &ctx.accounts.mxe_account,
+            &[
+                CallbackAccount {
+                    pubkey: ctx.accounts.viewer_grant.key(),
+                    is_writable: true,
+                },
+                crate::event_cursor_callback_account(ctx.accounts.pool.pool_id),
+            ],
+        )?],
+        1,
+        0,
+    )?;
+
+    msg!(
+        "Balance disclosure queued for {}",
+        ctx.accounts.user_account.owner
+    );
+    Ok(())
+}
@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::CLEAR_PENDING_REASON_OTHER;
+use crate::{AdminClearPending, PendingStateClearedEvent};
+
+// =============================================================================
+// ADMIN CLEAR PENDING - Unstick a UserProfile left inconsistent
+// =============================================================================
+// A callback that fails after its queue instruction ran can leave
+// pending_order / pending_asset_id pointing at a batch the order never
+// reached (or that was discarded), which blocks every later order of the
+// user. An admin resets the in-flight fields:
+// - pending_order is dropped, pending_asset_id / pending_refund_asset_id
+//   zeroed and the rollover / conversion flags cleared
+// - the staged order and the encrypted balances are left as they are
+//
+// Nothing is refunded: funds debited for the dropped order stay in the
+// vault and are reconciled off-chain from PendingStateClearedEvent. When
+// the order's BatchLog is passed and the batch executed, its
+// unsettled_count is decremented so close_batch_log is not blocked.
+
+/// Clear a user's pending order and in-flight flags. Only callable by an admin.
+///
+/// # Arguments
+/// * `reason` - CLEAR_PENDING_REASON_* code, emitted in PendingStateClearedEvent
+pub fn handler(ctx: Context<AdminClearPending>, reason: u8) -> Result<()> {
+    require!(
+        reason <= CLEAR_PENDING_REASON_OTHER,
+        ErrorCode::InvalidClearReason
+    );
+
+    let user = &mut ctx.accounts.user_account;
+    let cleared_order = user.pending_order.take();
+    let batch_id = cleared_order.map(|order| order.batch_id).unwrap_or(0);
+
+    // The dropped order will never settle against its log
+    if let Some(batch_log) = ctx.accounts.batch_log.as_deref_mut() {
+        require!(
+            cleared_order.is_some() && batch_log.batch_id == batch_id,
+            ErrorCode::InvalidBatchId
+        );
+        if batch_log.executed_at > 0 {
+            batch_log.unsettled_count = batch_log.unsettled_count.saturating_sub(1);
+        }
+    }
+
+    let pending_asset_id = user.pending_asset_id;
+    user.pending_asset_id = 0;
+    user.pending_refund_asset_id = 0;
+    user.pending_rollover = false;
+    user.pending_conversion = false;
+
+    emit!(PendingStateClearedEvent {
+        owner: user.owner,
+        had_order: cleared_order.is_some(),
+        batch_id,
+        pending_asset_id,
+        reason,
+        cleared_by: ctx.accounts.authority.key(),
+        cleared_at: Clock::get()?.unix_timestamp,
+        seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+    });
+
+    msg!(
+        "Pending state cleared for {}: batch {}, reason {}",
+        user.owner,
+        batch_id,
+        reason
+    );
+    Ok(())
+}
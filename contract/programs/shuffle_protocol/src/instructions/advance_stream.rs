@@ -0,0 +1,101 @@
+use anchor_lang::prelude::*;
+use arcium_anchor::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::{AdvanceStream, StreamTransferCallback};
+
+// =============================================================================
+// ADVANCE STREAM - Pay out what a stream has accrued
+// =============================================================================
+// Permissionless crank. Settles the seconds from last_advanced_ts up to
+// min(now, end_ts) through the stream_transfer circuit, which multiplies the
+// encrypted rate by the elapsed time and moves it from payer to payee.
+//
+// The computation and both balance nonces are recorded on the stream; the
+// callback only applies a result that still matches them. Cranking again
+// before the callback lands replaces the in-flight advance.
+
+/// Queue a payout of everything a stream has accrued.
+///
+/// # Arguments
+/// * `computation_offset` - Unique ID for MPC computation
+pub fn handler(ctx: Context<AdvanceStream>, computation_offset: u64) -> Result<()> {
+    require!(!ctx.accounts.pool.paused, ErrorCode::ProtocolPaused);
+    let now = Clock::get()?.unix_timestamp;
+    let asset_id = ctx.accounts.payment_stream.asset_id;
+    require!(
+        !ctx.accounts.pool.is_asset_frozen(asset_id),
+        ErrorCode::AssetFrozen
+    );
+
+    let stream = &mut ctx.accounts.payment_stream;
+    let until = now.min(stream.end_ts);
+    require!(
+        until > stream.last_advanced_ts,
+        ErrorCode::StreamNothingAccrued
+    );
+    let elapsed = (until - stream.last_advanced_ts) as u64;
+
+    stream.pending_computation = ctx.accounts.computation_account.key();
+    stream.pending_until = until;
+    stream.pending_payer_nonce = ctx.accounts.payer_account.get_nonce(asset_id);
+    stream.pending_payee_nonce = ctx.accounts.payee_account.get_nonce(asset_id);
+
+    // Set sign PDA bump
+    ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+    let stream = &ctx.accounts.payment_stream;
+    let args = ArgBuilder::new()
+        // TransferRequest (encrypted by the payer) - amount per second
+        .x25519_pubkey(stream.rate_pubkey)
+        .plaintext_u128(stream.rate_nonce)
+        .encrypted_u64(stream.encrypted_rate)
+        // Payer's current balance (Enc<Shared, *> - payer's pubkey)
+        .x25519_pubkey(ctx.accounts.payer_account.user_pubkey)
+        .plaintext_u128(ctx.accounts.payer_account.get_nonce(asset_id))
+        .encrypted_u64(ctx.accounts.payer_account.get_credit(asset_id))
+        // Payee's current balance (Enc<Shared, *> - payee's pubkey)
+        .x25519_pubkey(ctx.accounts.payee_account.user_pubkey)
+        .plaintext_u128(ctx.accounts.payee_account.get_nonce(asset_id))
+        .encrypted_u64(ctx.accounts.payee_account.get_credit(asset_id))
+        // Seconds being settled (public)
+        .plaintext_u64(elapsed)
+        .build();
+
+    use arcium_client::idl::arcium::types::CallbackAccount;
+    queue_computation(
+        ctx.accounts,
+        computation_offset,
+        args,
+        vec![StreamTransferCallback::callback_ix(
+            computation_offset,
+            &ctx.accounts.mxe_account,
+            &[
+                CallbackAccount {
+                    pubkey: ctx.accounts.payment_stream.key(),
+                    is_writable: true,
+                },
+                CallbackAccount {
+                    pubkey: ctx.accounts.payer_account.key(),
+                    is_writable: true,
+                },
+                CallbackAccount {
+                    pubkey: ctx.accounts.payee_account.key(),
+                    is_writable: true,
+                },
+                crate::event_cursor_callback_account(ctx.accounts.pool.pool_id),
+            ],
+        )?],
+        1,
+        0,
+    )?;
+
+    msg!(
+        "Stream {} of {} advancing {}s, computation {}",
+        ctx.accounts.payment_stream.stream_id,
+        ctx.accounts.payment_stream.payer,
+        elapsed,
+        computation_offset
+    );
+    Ok(())
+}
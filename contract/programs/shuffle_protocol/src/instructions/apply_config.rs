@@ -0,0 +1,105 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::state::{ProtocolConfig, CONFIG_VERSION, EXECUTION_MODE_EXTERNAL_ONLY};
+use crate::{ApplyConfig, ConfigAppliedEvent};
+
+// =============================================================================
+// APPLY CONFIG - Restore or promote a config blob
+// =============================================================================
+// Applies a ProtocolConfig (usually read from a ConfigSnapshot) in one
+// authority-signed transaction. Every parameter is checked against the same
+// bounds as its individual setter, and the whole blob is rejected if any
+// check fails. The emitted config_hash matches the exporting ConfigSnapshot
+// when the blob was applied unchanged.
+//
+// The executor registry is only written if passed; a config that gates
+// execution or sets a bond requires it (configure_executor_registry creates
+// it).
+
+/// Apply a config blob. Only callable by pool authority.
+///
+/// # Arguments
+/// * `version` - CONFIG_VERSION the blob was exported with
+/// * `config` - Parameters to apply
+pub fn handler(ctx: Context<ApplyConfig>, version: u16, config: ProtocolConfig) -> Result<()> {
+    require!(
+        version == CONFIG_VERSION,
+        ErrorCode::UnsupportedConfigVersion
+    );
+
+    // Same bounds as update_batch_config, initialize, set_settlement_deadline,
+    // set_callback_priority_fee, set_execution_mode, set_keeper_reward,
+    // set_batch_approval, set_batch_rate_limit and set_max_slippage
+    // (set_privacy_floor accepts any value)
+    require!(
+        config.execution_trigger_count > 0,
+        ErrorCode::InvalidBatchConfig
+    );
+    require!(
+        config.min_active_pairs > 0 && config.min_active_pairs <= NUM_PAIRS,
+        ErrorCode::InvalidBatchConfig
+    );
+    require!(
+        (1..=MAX_BATCH_WINDOW_SECS).contains(&config.batch_window_secs)
+            && config.max_batch_wait_secs >= 0,
+        ErrorCode::InvalidBatchConfig
+    );
+    require!(
+        config.execution_fee_bps <= MAX_FEE_BPS,
+        ErrorCode::FeeTooHigh
+    );
+    require!(
+        config.settlement_deadline_secs >= MIN_SETTLEMENT_DEADLINE_SECS,
+        ErrorCode::InvalidSettlementDeadline
+    );
+    require!(
+        config.callback_cu_price_micro <= MAX_CALLBACK_CU_PRICE_MICRO,
+        ErrorCode::InvalidCallbackPriorityFee
+    );
+    require!(
+        config.execution_mode <= EXECUTION_MODE_EXTERNAL_ONLY
+            && config.reserve_spread_bps <= MAX_RESERVE_SPREAD_BPS
+            && config.max_reserve_utilization_bps > 0
+            && config.max_reserve_utilization_bps <= BPS_DENOMINATOR
+            && (0..=MAX_NETTING_WINDOW_SECS).contains(&config.netting_window_secs)
+            && config.max_slippage_bps <= MAX_EXTERNAL_SWAP_SLIPPAGE_BPS,
+        ErrorCode::InvalidExecutionConfig
+    );
+    require!(
+        config.execution_mode != EXECUTION_MODE_EXTERNAL_ONLY
+            || config.swap_program != Pubkey::default(),
+        ErrorCode::InvalidSwapProgram
+    );
+    require!(
+        config.keeper_reward_bps <= MAX_KEEPER_REWARD_BPS,
+        ErrorCode::InvalidKeeperReward
+    );
+    let pool = &mut ctx.accounts.pool;
+    require!(
+        config.approval_threshold_usdc == 0
+            || (config.co_operator != Pubkey::default() && config.co_operator != pool.operator),
+        ErrorCode::InvalidApprovalConfig
+    );
+
+    match ctx.accounts.executor_registry.as_mut() {
+        Some(registry) => registry.min_bond_lamports = config.min_executor_bond_lamports,
+        None => require!(
+            !config.executor_gated && config.min_executor_bond_lamports == 0,
+            ErrorCode::ExecutorRegistryMissing
+        ),
+    }
+    config.apply_to(pool);
+
+    let config_hash = config.hash();
+    emit!(ConfigAppliedEvent {
+        version,
+        config_hash,
+        applied_by: ctx.accounts.authority.key(),
+        seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+    });
+
+    msg!("Config applied: version {}", version);
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::{ApproveBatch, BatchApprovedEvent};
+
+// =============================================================================
+// APPROVE BATCH - Co-operator sign-off for large batches
+// =============================================================================
+// The operator alone moves reserve and vault tokens through execute_swaps and
+// route_surplus. For batches whose revealed notional exceeds
+// Pool.approval_threshold_usdc (snapshotted into the BatchLog at
+// execute_batch), those steps also need an approval recorded here by the
+// co-operator, a second key distinct from the operator.
+//
+// The notional is only known once the reveal callback has run, so approval
+// happens between the reveal and the swaps (BatchApprovalRequestedEvent).
+
+/// Approve a revealed batch above the approval threshold.
+/// Only callable by the pool co-operator.
+///
+/// # Arguments
+/// * `batch_id` - The batch ID to approve (for verification)
+pub fn handler(ctx: Context<ApproveBatch>, batch_id: u64) -> Result<()> {
+    let batch_log = &mut ctx.accounts.batch_log;
+    require!(batch_log.batch_id == batch_id, ErrorCode::InvalidBatchId);
+    require!(batch_log.executed_at > 0, ErrorCode::BatchNotFinalized);
+    require!(!batch_log.swaps_executed, ErrorCode::SwapsAlreadyExecuted);
+
+    let co_operator = ctx.accounts.co_operator.key();
+    batch_log.approved_by = co_operator;
+
+    emit!(BatchApprovedEvent {
+        batch_id,
+        co_operator,
+        notional_usdc: batch_log.notional_usdc,
+        seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+    });
+
+    msg!(
+        "Batch {} approved by {} (notional {} USDC units)",
+        batch_id,
+        co_operator,
+        batch_log.notional_usdc
+    );
+    Ok(())
+}
@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::{ApproveRecovery, RecoveryApprovedEvent};
+
+/// Guardian approves moving the owner's account to `new_owner`.
+///
+/// Approving the pending proposal adds the guardian's vote. A different
+/// proposal replaces the pending one (and its votes) until the threshold is
+/// reached; from then on it is fixed until executed or cancelled by the
+/// owner. Reaching the threshold starts the challenge period.
+///
+/// # Arguments
+/// * `new_owner` - Wallet that takes over the account
+/// * `new_user_pubkey` - x25519 key of the new owner's privacy account
+pub fn handler(
+    ctx: Context<ApproveRecovery>,
+    new_owner: Pubkey,
+    new_user_pubkey: [u8; 32],
+) -> Result<()> {
+    let set = &mut ctx.accounts.guardian_set;
+    let index = set
+        .guardian_index(&ctx.accounts.guardian.key())
+        .ok_or(ErrorCode::Unauthorized)?;
+    require!(
+        new_owner != set.owner && new_owner != Pubkey::default() && new_user_pubkey != [0u8; 32],
+        ErrorCode::InvalidRecovery
+    );
+
+    let same_proposal =
+        set.proposed_owner == new_owner && set.proposed_user_pubkey == new_user_pubkey;
+    if !same_proposal {
+        require!(set.approved_at == 0, ErrorCode::RecoveryAlreadyApproved);
+        set.clear_proposal();
+        set.proposed_owner = new_owner;
+        set.proposed_user_pubkey = new_user_pubkey;
+    }
+    set.approvals |= 1 << index;
+
+    let approvals = set.approvals.count_ones() as u8;
+    if set.approved_at == 0 && approvals >= set.threshold {
+        set.approved_at = Clock::get()?.unix_timestamp;
+    }
+
+    emit!(RecoveryApprovedEvent {
+        owner: set.owner,
+        guardian: ctx.accounts.guardian.key(),
+        new_owner,
+        approvals,
+        approved_at: set.approved_at,
+        seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+    });
+
+    msg!(
+        "Recovery of {} to {}: {}/{} approvals",
+        set.owner,
+        new_owner,
+        approvals,
+        set.threshold
+    );
+    Ok(())
+}
@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use crate::errors::ErrorCode;
+use crate::{AuditVaults, VaultAuditEvent};
+
+// =============================================================================
+// AUDIT VAULTS - Attest that protocol token accounts are uncompromised
+// =============================================================================
+// Every vault, reserve and the faucet vault must:
+// - be owned by the Pool PDA
+// - hold the mint recorded on Pool for its asset
+// - have no delegate and no close authority
+//
+// Any violation aborts with a specific error; success emits VaultAuditEvent.
+
+/// Audit all protocol token accounts and emit an attestation event.
+pub fn handler(ctx: Context<AuditVaults>) -> Result<()> {
+    let pool = &ctx.accounts.pool;
+    let pool_key = pool.key();
+
+    let checks: [(&str, &TokenAccount, Pubkey); 9] = [
+        ("vault_usdc", &ctx.accounts.vault_usdc, pool.usdc_mint),
+        ("vault_tsla", &ctx.accounts.vault_tsla, pool.tsla_mint),
+        ("vault_spy", &ctx.accounts.vault_spy, pool.spy_mint),
+        ("vault_aapl", &ctx.accounts.vault_aapl, pool.aapl_mint),
+        ("reserve_usdc", &ctx.accounts.reserve_usdc, pool.usdc_mint),
+        ("reserve_tsla", &ctx.accounts.reserve_tsla, pool.tsla_mint),
+        ("reserve_spy", &ctx.accounts.reserve_spy, pool.spy_mint),
+        ("reserve_aapl", &ctx.accounts.reserve_aapl, pool.aapl_mint),
+        ("faucet_vault", &ctx.accounts.faucet_vault, pool.usdc_mint),
+    ];
+
+    for (name, account, expected_mint) in checks.iter() {
+        if account.owner != pool_key {
+            msg!("Audit failed: {} owner {} != pool", name, account.owner);
+            return Err(ErrorCode::InvalidOwner.into());
+        }
+        if account.mint != *expected_mint {
+            msg!(
+                "Audit failed: {} mint {} != {}",
+                name,
+                account.mint,
+                expected_mint
+            );
+            return Err(ErrorCode::InvalidMint.into());
+        }
+        if account.delegate.is_some() {
+            msg!("Audit failed: {} has a delegate", name);
+            return Err(ErrorCode::VaultHasDelegate.into());
+        }
+        if account.close_authority.is_some() {
+            msg!("Audit failed: {} has a close authority", name);
+            return Err(ErrorCode::VaultHasCloseAuthority.into());
+        }
+    }
+
+    emit!(VaultAuditEvent {
+        auditor: ctx.accounts.auditor.key(),
+        pool: pool_key,
+        accounts_checked: checks.len() as u8,
+        audited_at: Clock::get()?.unix_timestamp,
+        seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+    });
+
+    msg!("Vault audit passed: {} accounts checked", checks.len());
+    Ok(())
+}
@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+use arcium_anchor::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::{BOOTSTRAP_ACCUMULATOR_CREATED, BOOTSTRAP_BATCH_STATE_READY};
+use crate::{Bootstrap, BootstrapProgressEvent, BOOTSTRAP_COMPLETE, BOOTSTRAP_COMP_DEFS};
+
+// =============================================================================
+// BOOTSTRAP - Record how far a deployment got
+// =============================================================================
+// A deployment needs initialize, init_batch_accumulator for both slots,
+// init_batch_state and one init_*_comp_def per circuit. Each comp def init
+// has its own accounts and circuit source, so they stay separate
+// transactions (scripts/init-devnet.js runs the whole sequence and skips
+// what already exists). This instruction checks what is in place and ORs
+// it into Pool.bootstrap_flags:
+// - bit i: the comp def of BOOTSTRAP_COMP_DEFS[i] is initialized
+// - BOOTSTRAP_ACCUMULATOR_CREATED << slot: the accumulator slot exists
+// - BOOTSTRAP_BATCH_STATE_READY << slot: init_batch_state stored its
+//   encrypted zeros (BatchAccumulator.state_initialized)
+//
+// Comp def accounts are passed as remaining accounts in BOOTSTRAP_COMP_DEFS
+// order starting at `first_comp_def`, so a long list can be checked over
+// several calls. Flags are never cleared; Pool::is_bootstrapped tells a
+// partially-initialized deployment from a complete one. Permissionless: it
+// only records what it verifies.
+
+/// Record initialized comp defs and batch slots on the Pool. Permissionless.
+///
+/// # Arguments
+/// * `first_comp_def` - Index into BOOTSTRAP_COMP_DEFS of the first remaining account
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, Bootstrap<'info>>,
+    first_comp_def: u8,
+) -> Result<()> {
+    let first = first_comp_def as usize;
+    require!(
+        first + ctx.remaining_accounts.len() <= BOOTSTRAP_COMP_DEFS.len(),
+        ErrorCode::InvalidCompDefAccount
+    );
+
+    let arcium_program = ctx.accounts.arcium_program.key();
+    let mut flags = ctx.accounts.pool.bootstrap_flags;
+    for (i, info) in ctx.remaining_accounts.iter().enumerate() {
+        let index = first + i;
+        require_keys_eq!(
+            info.key(),
+            derive_comp_def_pda!(BOOTSTRAP_COMP_DEFS[index]),
+            ErrorCode::InvalidCompDefAccount
+        );
+        if *info.owner == arcium_program && !info.data_is_empty() {
+            flags |= 1 << index;
+        }
+    }
+
+    let slots = [
+        ctx.accounts.batch_accumulator_0.as_deref(),
+        ctx.accounts.batch_accumulator_1.as_deref(),
+    ];
+    for (slot, accumulator) in slots.iter().enumerate() {
+        if let Some(accumulator) = accumulator {
+            flags |= BOOTSTRAP_ACCUMULATOR_CREATED << slot;
+            if accumulator.state_initialized {
+                flags |= BOOTSTRAP_BATCH_STATE_READY << slot;
+            }
+        }
+    }
+
+    let pool = &mut ctx.accounts.pool;
+    pool.bootstrap_flags = flags;
+    let complete = pool.is_bootstrapped(BOOTSTRAP_COMPLETE);
+
+    emit!(BootstrapProgressEvent {
+        bootstrap_flags: flags,
+        missing: BOOTSTRAP_COMPLETE & !flags,
+        complete,
+        seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+    });
+
+    msg!("Bootstrap flags {:#x} (complete: {})", flags, complete);
+    Ok(())
+}
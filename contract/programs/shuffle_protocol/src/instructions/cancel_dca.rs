@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+
+use crate::{CancelDca, DcaCancelledEvent};
+
+/// Cancel a DCA schedule and reclaim its rent.
+/// Closing is handled by the `close = user` constraint on CancelDca.
+/// An order already placed by the schedule stays pending and must be settled.
+pub fn handler(ctx: Context<CancelDca>) -> Result<()> {
+    let dca = &ctx.accounts.dca_schedule;
+
+    emit!(DcaCancelledEvent {
+        owner: dca.owner,
+        dca_id: dca.dca_id,
+        executions: dca.executions,
+        seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+    });
+
+    msg!(
+        "DCA {} cancelled for {} after {} executions",
+        dca.dca_id,
+        dca.owner,
+        dca.executions
+    );
+
+    Ok(())
+}
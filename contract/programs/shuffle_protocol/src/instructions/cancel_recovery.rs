@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::{CancelRecovery, RecoveryCancelledEvent};
+
+/// Owner rejects the recovery in progress (e.g. during the challenge
+/// period). Guardians stay registered.
+pub fn handler(ctx: Context<CancelRecovery>) -> Result<()> {
+    let set = &mut ctx.accounts.guardian_set;
+    require!(set.has_proposal(), ErrorCode::InvalidRecovery);
+    let proposed_owner = set.proposed_owner;
+    set.clear_proposal();
+
+    ctx.accounts.user_account.last_active_at = Clock::get()?.unix_timestamp;
+
+    emit!(RecoveryCancelledEvent {
+        owner: set.owner,
+        proposed_owner,
+        seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+    });
+
+    msg!("Recovery of {} to {} cancelled", set.owner, proposed_owner);
+    Ok(())
+}
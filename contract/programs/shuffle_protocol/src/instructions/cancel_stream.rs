@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+use crate::{CancelStream, StreamCancelledEvent};
+
+/// Cancel a payment stream and reclaim its rent.
+/// Closing is handled by the `close = payer` constraint on CancelStream.
+/// Accrual not yet advanced is forfeited, and an in-flight advance's
+/// callback fails once the stream is gone.
+pub fn handler(ctx: Context<CancelStream>) -> Result<()> {
+    let stream = &ctx.accounts.payment_stream;
+
+    emit!(StreamCancelledEvent {
+        payer: stream.payer,
+        payee: stream.payee,
+        stream_id: stream.stream_id,
+        last_advanced_ts: stream.last_advanced_ts,
+        seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+    });
+
+    msg!(
+        "Stream {} of {} cancelled, paid up to {}",
+        stream.stream_id,
+        stream.payer,
+        stream.last_advanced_ts
+    );
+    Ok(())
+}
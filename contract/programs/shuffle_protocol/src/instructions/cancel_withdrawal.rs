@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::WITHDRAWAL_CANCEL_TIMEOUT_SECS;
+use crate::errors::ErrorCode;
+use crate::{CancelWithdrawal, WithdrawalCancelledEvent};
+
+// =============================================================================
+// CANCEL WITHDRAWAL - Drop a withdrawal whose callback never arrived
+// =============================================================================
+// A withdrawal moves no tokens until its sub_balance callback succeeds, so a
+// dropped computation (or a rejected or stale result) leaves only its
+// PendingOperation behind. The owner cancels it to reclaim the rent and
+// withdraw again.
+//
+// The computation account passed must be the one the PendingOperation
+// records. Once it no longer exists no callback can arrive and the
+// withdrawal is cancelled at once; otherwise WITHDRAWAL_CANCEL_TIMEOUT_SECS
+// must have passed since it was queued. A callback landing after the
+// cancellation finds no PendingOperation and fails, so nothing is paid out.
+//
+// Deposits cannot be cancelled; their tokens are already in the vault and
+// retry_deposit credits them.
+
+/// Cancel a withdrawal and return its PendingOperation rent to the owner.
+///
+/// # Arguments
+/// * `pending_offset` - computation_offset the withdrawal was queued with
+pub fn handler(ctx: Context<CancelWithdrawal>, pending_offset: u64) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let pending = &ctx.accounts.pending_operation;
+    let computation_gone = ctx.accounts.computation_account.lamports() == 0;
+    let cancellable_at = pending
+        .queued_at
+        .saturating_add(WITHDRAWAL_CANCEL_TIMEOUT_SECS);
+    require!(
+        computation_gone || now >= cancellable_at,
+        ErrorCode::WithdrawalCancelTimeoutActive
+    );
+
+    emit!(WithdrawalCancelledEvent {
+        user: pending.owner,
+        asset_id: pending.asset_id,
+        amount: pending.amount,
+        computation: pending.computation,
+        seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+    });
+
+    msg!(
+        "Withdrawal {} cancelled: asset {}, amount {}",
+        pending_offset,
+        pending.asset_id,
+        pending.amount
+    );
+    Ok(())
+}
@@ -0,0 +1,154 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::state::{
+    BatchLog, INVARIANT_ACCUMULATOR_PDA, INVARIANT_ACTIVE_SLOT, INVARIANT_BATCH_IDS,
+    INVARIANT_BATCH_LOG, INVARIANT_STATS, INVARIANT_VAULT_MINT,
+};
+use crate::{CheckInvariants, InvariantReportEvent};
+
+// =============================================================================
+// CHECK INVARIANTS - Cross-account consistency report
+// =============================================================================
+// Permissionless. Meant to be run by monitoring after every batch. Unlike
+// audit_vaults it does not abort on the first violation: every check runs and
+// the result is emitted as an INVARIANT_* bitmask in InvariantReportEvent.
+//
+// Checks:
+// - exactly one accumulator slot is active, and its batch_id is above the
+//   other slot's
+// - each accumulator's slot and bump derive its address
+// - batch lifecycle vs BatchLog: the active batch has no log yet, a
+//   revealing or recovered (inactive with orders) batch has an unexecuted one
+// - vault and reserve mints match Pool
+// - accumulator batch IDs and Pool counters never decrease between runs
+//   (compared against the InvariantMonitor checkpoint)
+
+/// Check program-wide invariants and emit an InvariantReportEvent.
+pub fn handler(ctx: Context<CheckInvariants>) -> Result<()> {
+    let mut violations = 0u16;
+    let accumulators = [&ctx.accounts.accumulator_0, &ctx.accounts.accumulator_1];
+    let logs = [
+        ctx.accounts.batch_log_0.to_account_info(),
+        ctx.accounts.batch_log_1.to_account_info(),
+    ];
+
+    // Accumulator slots
+    if accumulators.iter().filter(|a| a.active).count() != 1 {
+        msg!("Invariant violated: active slot count");
+        violations |= INVARIANT_ACTIVE_SLOT;
+    }
+    for accumulator in accumulators.iter() {
+        let derived = Pubkey::create_program_address(
+            &[
+                BATCH_ACCUMULATOR_SEED,
+                &[ctx.accounts.pool.pool_id],
+                &[accumulator.slot],
+                &[accumulator.bump],
+            ],
+            &crate::ID,
+        );
+        if derived.ok() != Some(accumulator.key()) {
+            msg!(
+                "Invariant violated: accumulator {} does not derive from slot {}",
+                accumulator.key(),
+                accumulator.slot
+            );
+            violations |= INVARIANT_ACCUMULATOR_PDA;
+        }
+    }
+    let (active, other) = if accumulators[0].active {
+        (accumulators[0], accumulators[1])
+    } else {
+        (accumulators[1], accumulators[0])
+    };
+    if active.batch_id <= other.batch_id {
+        msg!(
+            "Invariant violated: active batch_id {} <= other slot's {}",
+            active.batch_id,
+            other.batch_id
+        );
+        violations |= INVARIANT_BATCH_IDS;
+    }
+
+    // Batch lifecycle vs BatchLog existence
+    for (accumulator, log) in accumulators.iter().zip(logs.iter()) {
+        let log = load_batch_log(log);
+        let consistent = if accumulator.active {
+            accumulator.is_open() && log.is_none()
+        } else if !accumulator.is_open() || accumulator.order_count > 0 {
+            log.is_some_and(|log| log.executed_at == 0)
+        } else {
+            true
+        };
+        if !consistent {
+            msg!(
+                "Invariant violated: batch {} (slot {}) lifecycle vs BatchLog",
+                accumulator.batch_id,
+                accumulator.slot
+            );
+            violations |= INVARIANT_BATCH_LOG;
+        }
+    }
+
+    // Vault and reserve mints
+    let pool = &ctx.accounts.pool;
+    let mints = [
+        (ctx.accounts.vault_usdc.mint, pool.usdc_mint),
+        (ctx.accounts.vault_tsla.mint, pool.tsla_mint),
+        (ctx.accounts.vault_spy.mint, pool.spy_mint),
+        (ctx.accounts.vault_aapl.mint, pool.aapl_mint),
+        (ctx.accounts.reserve_usdc.mint, pool.usdc_mint),
+        (ctx.accounts.reserve_tsla.mint, pool.tsla_mint),
+        (ctx.accounts.reserve_spy.mint, pool.spy_mint),
+        (ctx.accounts.reserve_aapl.mint, pool.aapl_mint),
+    ];
+    if mints.iter().any(|(mint, expected)| mint != expected) {
+        msg!("Invariant violated: vault mint does not match Pool");
+        violations |= INVARIANT_VAULT_MINT;
+    }
+
+    // Monotonic counters since the last run
+    let monitor = &mut ctx.accounts.monitor;
+    monitor.bump = ctx.bumps.monitor;
+    if active.batch_id < monitor.last_batch_id
+        || pool.total_batches_executed < monitor.last_total_batches_executed
+        || pool.total_fees_collected < monitor.last_total_fees_collected
+    {
+        msg!("Invariant violated: counters decreased since last run");
+        violations |= INVARIANT_STATS;
+    }
+
+    let now = Clock::get()?.unix_timestamp;
+    monitor.last_batch_id = active.batch_id.max(monitor.last_batch_id);
+    monitor.last_total_batches_executed = pool.total_batches_executed;
+    monitor.last_total_fees_collected = pool.total_fees_collected;
+    monitor.last_checked_at = now;
+    if violations != 0 {
+        monitor.failed_runs += 1;
+    }
+
+    emit!(InvariantReportEvent {
+        checker: ctx.accounts.checker.key(),
+        batch_id: active.batch_id,
+        violations,
+        checked_at: now,
+        seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+    });
+
+    msg!(
+        "Invariant check at batch {}: violations={:#06b}",
+        active.batch_id,
+        violations
+    );
+    Ok(())
+}
+
+/// Deserialize a BatchLog PDA, or None if it was never created (or closed)
+fn load_batch_log(info: &AccountInfo) -> Option<BatchLog> {
+    if info.owner != &crate::ID || info.data_is_empty() {
+        return None;
+    }
+    let data = info.try_borrow_data().ok()?;
+    BatchLog::try_deserialize(&mut &data[..]).ok()
+}
@@ -0,0 +1,93 @@
+use anchor_lang::prelude::*;
+use arcium_anchor::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::{ClaimInheritance, SweepBalanceCallback};
+
+// =============================================================================
+// CLAIM INHERITANCE - Beneficiary sweeps an inactive owner's balance
+// =============================================================================
+// One asset per call. The owner's balance is zeroed and added to the
+// beneficiary's balance in MPC; amounts stay encrypted.
+//
+// Flow:
+// 1. Beneficiary calls claim_inheritance once the owner is inactive
+// 2. Handler queues sweep_balance with both encrypted balances
+// 3. Callback re-checks inactivity (owner activity in between cancels the
+//    claim) and writes both balances
+
+/// Queue a sweep of one asset balance from an inactive owner to the beneficiary.
+///
+/// # Arguments
+/// * `computation_offset` - Unique ID for MPC computation
+/// * `asset_id` - Asset to sweep (0=USDC, 1=TSLA, 2=SPY, 3=AAPL)
+pub fn handler(
+    ctx: Context<ClaimInheritance>,
+    computation_offset: u64,
+    asset_id: u8,
+) -> Result<()> {
+    require!(!ctx.accounts.pool.paused, ErrorCode::ProtocolPaused);
+    require!(asset_id <= 3, ErrorCode::InvalidAssetId);
+
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        ctx.accounts
+            .beneficiary_config
+            .is_claimable(ctx.accounts.owner_account.last_active_at, now),
+        ErrorCode::OwnerStillActive
+    );
+
+    ctx.accounts.beneficiary_config.pending_asset_id = asset_id;
+
+    // Set sign PDA bump
+    ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+    let owner = &ctx.accounts.owner_account;
+    let beneficiary = &ctx.accounts.beneficiary_account;
+    let args = ArgBuilder::new()
+        // Owner's balance (Enc<Shared, *> - owner's pubkey)
+        .x25519_pubkey(owner.user_pubkey)
+        .plaintext_u128(owner.get_nonce(asset_id))
+        .encrypted_u64(owner.get_credit(asset_id))
+        // Beneficiary's balance (Enc<Shared, *> - beneficiary's pubkey)
+        .x25519_pubkey(beneficiary.user_pubkey)
+        .plaintext_u128(beneficiary.get_nonce(asset_id))
+        .encrypted_u64(beneficiary.get_credit(asset_id))
+        .build();
+
+    use arcium_client::idl::arcium::types::CallbackAccount;
+    queue_computation(
+        ctx.accounts,
+        computation_offset,
+        args,
+        vec![SweepBalanceCallback::callback_ix(
+            computation_offset,
+            &ctx.accounts.mxe_account,
+            &[
+                CallbackAccount {
+                    pubkey: ctx.accounts.owner_account.key(),
+                    is_writable: true,
+                },
+                CallbackAccount {
+                    pubkey: ctx.accounts.beneficiary_account.key(),
+                    is_writable: true,
+                },
+                CallbackAccount {
+                    pubkey: ctx.accounts.beneficiary_config.key(),
+                    is_writable: false,
+                },
+                crate::event_cursor_callback_account(ctx.accounts.pool.pool_id),
+            ],
+        )?],
+        1,
+        0,
+    )?;
+
+    msg!(
+        "Inheritance sweep queued: {} -> {}, asset={}",
+        ctx.accounts.owner_account.owner,
+        ctx.accounts.beneficiary_account.owner,
+        asset_id
+    );
+    Ok(())
+}
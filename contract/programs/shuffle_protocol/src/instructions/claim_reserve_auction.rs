@@ -0,0 +1,96 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Transfer};
+
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::state::AuctionStatus;
+use crate::{ClaimReserveAuction, ReserveAuctionClaimedEvent};
+
+// =============================================================================
+// CLAIM RESERVE AUCTION - Winner settles the auction
+// =============================================================================
+// Winner pays clearing_price USDC into the USDC reserve and receives the lot
+// from the asset reserve.
+
+/// Settle a cleared reserve auction.
+/// Only callable by the winning bidder.
+pub fn handler(ctx: Context<ClaimReserveAuction>) -> Result<()> {
+    let auction = &ctx.accounts.reserve_auction;
+    require!(
+        auction.status == AuctionStatus::Cleared,
+        ErrorCode::InvalidAuctionStatus
+    );
+
+    // The asset reserve must be the protocol reserve PDA, not just any
+    // Pool-owned account (user deposit vaults share the same mint/authority)
+    let reserve_seed = match auction.asset_id {
+        1 => RESERVE_TSLA_SEED,
+        2 => RESERVE_SPY_SEED,
+        3 => RESERVE_AAPL_SEED,
+        _ => return Err(ErrorCode::InvalidAssetId.into()),
+    };
+    let (expected_reserve, _) = Pubkey::find_program_address(
+        &[RESERVE_SEED, &[ctx.accounts.pool.pool_id], reserve_seed],
+        &crate::ID,
+    );
+    require!(
+        ctx.accounts.reserve_asset.key() == expected_reserve,
+        ErrorCode::InvalidReserve
+    );
+
+    let clearing_price = auction.clearing_price;
+    let lot_amount = auction.lot_amount;
+
+    // Winner pays USDC into reserves
+    let pay_ctx = CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.winner_usdc_account.to_account_info(),
+            to: ctx.accounts.reserve_usdc.to_account_info(),
+            authority: ctx.accounts.winner.to_account_info(),
+        },
+    );
+    token::transfer(pay_ctx, clearing_price)?;
+
+    // Pool PDA releases the lot from reserves
+    let pool_seeds = &[
+        POOL_SEED,
+        &[ctx.accounts.pool.pool_id],
+        &[ctx.accounts.pool.bump],
+    ];
+    let signer_seeds = &[&pool_seeds[..]];
+
+    let lot_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.reserve_asset.to_account_info(),
+            to: ctx.accounts.winner_asset_account.to_account_info(),
+            authority: ctx.accounts.pool.to_account_info(),
+        },
+        signer_seeds,
+    );
+    token::transfer(lot_ctx, lot_amount)?;
+
+    let auction = &mut ctx.accounts.reserve_auction;
+    auction.status = AuctionStatus::Claimed;
+
+    emit!(ReserveAuctionClaimedEvent {
+        auction_id: auction.auction_id,
+        winner: auction.winner,
+        asset_id: auction.asset_id,
+        lot_amount,
+        clearing_price,
+        seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+    });
+
+    msg!(
+        "Reserve auction {} claimed by {}: {} of asset {} for {} USDC",
+        auction.auction_id,
+        auction.winner,
+        lot_amount,
+        auction.asset_id,
+        clearing_price
+    );
+
+    Ok(())
+}
@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+use arcium_anchor::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::{VOUCHER_STATUS_OPEN, VOUCHER_STATUS_REDEEMING};
+use crate::{ClaimVoucher, RedeemVoucherCallback};
+
+/// Holder of the voucher secret claims an open voucher before it expires.
+/// Queues redeem_voucher to add the escrowed amount to the claimer's
+/// balance; the callback closes the voucher.
+///
+/// # Arguments
+/// * `computation_offset` - Unique ID for MPC computation
+pub fn handler(ctx: Context<ClaimVoucher>, computation_offset: u64) -> Result<()> {
+    require!(!ctx.accounts.pool.paused, ErrorCode::ProtocolPaused);
+    let now = Clock::get()?.unix_timestamp;
+    let voucher = &mut ctx.accounts.voucher;
+    require!(
+        voucher.status == VOUCHER_STATUS_OPEN,
+        ErrorCode::VoucherNotOpen
+    );
+    require!(now < voucher.expires_at, ErrorCode::VoucherExpired);
+    voucher.status = VOUCHER_STATUS_REDEEMING;
+    voucher.pending_computation = ctx.accounts.computation_account.key();
+    voucher.queued_at = now;
+    let asset_id = voucher.asset_id;
+
+    ctx.accounts.claimer_account.last_active_at = now;
+
+    // Set sign PDA bump
+    ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+    let voucher = &ctx.accounts.voucher;
+    let claimer = &ctx.accounts.claimer_account;
+    let args = ArgBuilder::new()
+        // Escrowed amount (Enc<Mxe, *>)
+        .plaintext_u128(voucher.escrow_nonce)
+        .encrypted_u64(voucher.escrow_amount)
+        // Claimer's current balance (Enc<Shared, *> - claimer's pubkey)
+        .x25519_pubkey(claimer.user_pubkey)
+        .plaintext_u128(claimer.get_nonce(asset_id))
+        .encrypted_u64(claimer.get_credit(asset_id))
+        .build();
+
+    use arcium_client::idl::arcium::types::CallbackAccount;
+    queue_computation(
+        ctx.accounts,
+        computation_offset,
+        args,
+        vec![RedeemVoucherCallback::callback_ix(
+            computation_offset,
+            &ctx.accounts.mxe_account,
+            &[
+                CallbackAccount {
+                    pubkey: ctx.accounts.claimer_account.key(),
+                    is_writable: true,
+                },
+                CallbackAccount {
+                    pubkey: ctx.accounts.voucher.key(),
+                    is_writable: true,
+                },
+                CallbackAccount {
+                    pubkey: ctx.accounts.voucher.funder,
+                    is_writable: true,
+                },
+                crate::event_cursor_callback_account(ctx.accounts.pool.pool_id),
+            ],
+        )?],
+        1,
+        0,
+    )?;
+
+    msg!(
+        "Voucher {} claim queued by {}",
+        ctx.accounts.voucher.claim_key,
+        ctx.accounts.claimer.key()
+    );
+    Ok(())
+}
@@ -0,0 +1,20 @@
+use anchor_lang::prelude::*;
+
+use crate::{ClearSettlementPreference, SettlementPreferenceUpdatedEvent};
+
+/// Remove the caller's settlement currency preference and reclaim the rent.
+pub fn handler(ctx: Context<ClearSettlementPreference>) -> Result<()> {
+    ctx.accounts.user_account.last_active_at = Clock::get()?.unix_timestamp;
+
+    emit!(SettlementPreferenceUpdatedEvent {
+        user: ctx.accounts.user.key(),
+        enabled: false,
+        seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+    });
+
+    msg!(
+        "Settlement preference cleared for {}",
+        ctx.accounts.user.key()
+    );
+    Ok(())
+}
@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+
+use crate::CloseBatchLog;
+
+// =============================================================================
+// CLOSE BATCH LOG - Garbage-collect a fully settled batch
+// =============================================================================
+// A BatchLog is only needed while orders from its batch remain unsettled
+// (settle_order / force_settle / forfeit_order read it). Once every order is
+// settled or forfeited and the vault<->reserve swaps ran, the operator (or the
+// pool authority) may close it. The account is closed by Anchor's
+// `close = treasury` constraint, with the treasury pinned to Pool.treasury.
+
+/// Close a BatchLog with no unsettled orders, returning its rent to the treasury.
+/// Operator or authority only.
+///
+/// # Arguments
+/// * `batch_id` - Batch whose log is closed
+pub fn handler(ctx: Context<CloseBatchLog>, batch_id: u64) -> Result<()> {
+    msg!(
+        "BatchLog {} closed, rent returned to treasury {}",
+        batch_id,
+        ctx.accounts.treasury.key()
+    );
+    Ok(())
+}
@@ -0,0 +1,19 @@
+use anchor_lang::prelude::*;
+
+use crate::CloseOrderHistory;
+
+// =============================================================================
+// CLOSE ORDER HISTORY - Reclaim rent from the settled-order ring buffer
+// =============================================================================
+// The account itself is closed by Anchor's `close = owner` constraint.
+// settle_order re-creates an empty history on the next settlement.
+
+/// Close the caller's OrderHistory PDA, returning its rent to the owner.
+pub fn handler(ctx: Context<CloseOrderHistory>) -> Result<()> {
+    msg!(
+        "Order history closed for {} ({} settlements recorded)",
+        ctx.accounts.owner.key(),
+        ctx.accounts.order_history.total_settled
+    );
+    Ok(())
+}
@@ -0,0 +1,100 @@
+use anchor_lang::prelude::*;
+use arcium_anchor::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::{CheckBalancesZeroCallback, CloseUserAccount};
+
+// =============================================================================
+// CLOSE USER ACCOUNT - Recover a UserProfile's rent
+// =============================================================================
+// Flow:
+// 1. Owner calls close_user_account; the account may not have a pending or
+//    staged order
+// 2. Handler snapshots the balance nonces and queues check_balances_zero,
+//    which reveals only whether all four balances are zero
+// 3. Callback checks no balance changed in between, then closes the
+//    UserProfile and the AccountClosure PDA
+//
+// Rent of a sponsored profile goes back to the RentSponsor pool, which must
+// then be passed; otherwise it goes to the owner. Side PDAs (viewing keys,
+// delegates, history, ...) are closed by their own instructions.
+
+/// Queue the zero-balance check that closes the caller's privacy account.
+///
+/// # Arguments
+/// * `computation_offset` - Unique ID for MPC computation
+pub fn handler(ctx: Context<CloseUserAccount>, computation_offset: u64) -> Result<()> {
+    let user = &ctx.accounts.user_account;
+    require!(
+        user.pending_order.is_none() && user.staged_order.is_none(),
+        ErrorCode::PendingOrderExists
+    );
+    require!(
+        !user.rent_sponsored || ctx.accounts.rent_sponsor.is_some(),
+        ErrorCode::RentSponsorMissing
+    );
+
+    let source_nonces: [u128; 4] = std::array::from_fn(|asset| user.get_nonce(asset as u8));
+    let closure = &mut ctx.accounts.account_closure;
+    closure.owner = ctx.accounts.user.key();
+    closure.source_nonces = source_nonces;
+    closure.bump = ctx.bumps.account_closure;
+
+    // Set sign PDA bump
+    ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+    let user = &ctx.accounts.user_account;
+    let args = ArgBuilder::new()
+        // Current balances (Enc<Shared, *> - user's key)
+        .x25519_pubkey(user.user_pubkey)
+        .plaintext_u128(user.usdc_nonce)
+        .encrypted_u64(user.usdc_credit)
+        .x25519_pubkey(user.user_pubkey)
+        .plaintext_u128(user.tsla_nonce)
+        .encrypted_u64(user.tsla_credit)
+        .x25519_pubkey(user.user_pubkey)
+        .plaintext_u128(user.spy_nonce)
+        .encrypted_u64(user.spy_credit)
+        .x25519_pubkey(user.user_pubkey)
+        .plaintext_u128(user.aapl_nonce)
+        .encrypted_u64(user.aapl_credit)
+        .build();
+
+    use arcium_client::idl::arcium::types::CallbackAccount;
+    queue_computation(
+        ctx.accounts,
+        computation_offset,
+        args,
+        vec![CheckBalancesZeroCallback::callback_ix(
+            computation_offset,
+            &ctx.accounts.mxe_account,
+            &[
+                CallbackAccount {
+                    pubkey: ctx.accounts.user_account.key(),
+                    is_writable: true,
+                },
+                CallbackAccount {
+                    pubkey: ctx.accounts.account_closure.key(),
+                    is_writable: true,
+                },
+                CallbackAccount {
+                    pubkey: ctx.accounts.user.key(),
+                    is_writable: true,
+                },
+                crate::optional_callback_account(
+                    ctx.accounts.rent_sponsor.as_ref().map(|s| s.key()),
+                    true,
+                ),
+                crate::event_cursor_callback_account(ctx.accounts.pool.pool_id),
+            ],
+        )?],
+        1,
+        0,
+    )?;
+
+    msg!(
+        "Account closure queued for {}",
+        ctx.accounts.user_account.owner
+    );
+    Ok(())
+}
@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::spl_token_2022;
+use anchor_spl::token_2022::spl_token_2022::extension::{
+    transfer_hook::TransferHook, BaseStateWithExtensions, StateWithExtensions,
+};
+
+use crate::errors::ErrorCode;
+use crate::{AssetPolicyConfiguredEvent, ConfigureAssetPolicy};
+
+// =============================================================================
+// CONFIGURE ASSET POLICY - Register transfer restrictions for an asset
+// =============================================================================
+// Reads the mint to detect its token program, decimals and Token-2022
+// transfer hook, and records the issuer allowlist program (if any).
+// Re-running it refreshes the policy after an issuer changes the hook.
+//
+// Once configured, add_balance / sub_balance for the asset require the
+// policy account, so the allowlist cannot be bypassed by omitting it.
+
+/// Create or update the transfer policy for an asset.
+/// Only callable by pool authority.
+///
+/// # Arguments
+/// * `asset_id` - Asset to restrict (0=USDC, 1=TSLA, 2=SPY, 3=AAPL)
+/// * `allowlist_program` - Issuer allowlist program (Pubkey::default() = none)
+pub fn handler(
+    ctx: Context<ConfigureAssetPolicy>,
+    asset_id: u8,
+    allowlist_program: Pubkey,
+) -> Result<()> {
+    require!(asset_id <= 3, ErrorCode::InvalidAssetId);
+
+    let mint_info = ctx.accounts.mint.to_account_info();
+    require!(
+        ctx.accounts.pool.mint_for_asset(asset_id) == Some(mint_info.key()),
+        ErrorCode::InvalidMint
+    );
+
+    // Only Token-2022 mints carry a transfer hook extension
+    let transfer_hook_program = if *mint_info.owner == spl_token_2022::ID {
+        let data = mint_info.try_borrow_data()?;
+        let mint = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&data)?;
+        mint.get_extension::<TransferHook>()
+            .ok()
+            .and_then(|hook| Option::<Pubkey>::from(hook.program_id))
+            .unwrap_or_default()
+    } else {
+        Pubkey::default()
+    };
+
+    let policy = &mut ctx.accounts.asset_policy;
+    policy.mint = mint_info.key();
+    policy.token_program = *mint_info.owner;
+    policy.decimals = ctx.accounts.mint.decimals;
+    policy.transfer_hook_program = transfer_hook_program;
+    policy.allowlist_program = allowlist_program;
+    policy.bump = ctx.bumps.asset_policy;
+
+    ctx.accounts.pool.restricted_assets |= 1 << asset_id;
+
+    emit!(AssetPolicyConfiguredEvent {
+        asset_id,
+        mint: policy.mint,
+        token_program: policy.token_program,
+        transfer_hook_program,
+        allowlist_program,
+        seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+    });
+
+    msg!(
+        "Asset {} policy configured: hook={}, allowlist={}",
+        asset_id,
+        transfer_hook_program,
+        allowlist_program
+    );
+    Ok(())
+}
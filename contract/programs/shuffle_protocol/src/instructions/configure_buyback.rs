@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::{BuybackConfiguredEvent, ConfigureBuyback};
+
+// =============================================================================
+// CONFIGURE BUYBACK - Governance sets the treasury buyback schedule and caps
+// =============================================================================
+// Creates the BuybackConfig and the buyback token vault on first call, then
+// updates the schedule on subsequent calls. Lifetime counters are preserved.
+
+/// Create or update the treasury buyback configuration.
+/// Only callable by the pool authority.
+///
+/// # Arguments
+/// * `enabled` - Whether buybacks may be executed
+/// * `swap_program` - External swap program used to buy the token
+/// * `fee_share_bps` - Share of collected USDC fees allocated to buybacks
+/// * `min_interval_secs` - Minimum seconds between two buybacks
+/// * `max_usdc_per_execution` - Cap on USDC spent per buyback
+pub fn handler(
+    ctx: Context<ConfigureBuyback>,
+    enabled: bool,
+    swap_program: Pubkey,
+    fee_share_bps: u16,
+    min_interval_secs: i64,
+    max_usdc_per_execution: u64,
+) -> Result<()> {
+    require!(fee_share_bps <= BPS_DENOMINATOR, ErrorCode::FeeTooHigh);
+    require!(min_interval_secs >= 0, ErrorCode::InvalidAmount);
+
+    let config = &mut ctx.accounts.buyback_config;
+
+    config.enabled = enabled;
+    config.buyback_mint = ctx.accounts.buyback_mint.key();
+    config.swap_program = swap_program;
+    config.fee_share_bps = fee_share_bps;
+    config.min_interval_secs = min_interval_secs;
+    config.max_usdc_per_execution = max_usdc_per_execution;
+    config.bump = ctx.bumps.buyback_config;
+
+    emit!(BuybackConfiguredEvent {
+        authority: ctx.accounts.authority.key(),
+        enabled,
+        buyback_mint: config.buyback_mint,
+        swap_program,
+        fee_share_bps,
+        min_interval_secs,
+        max_usdc_per_execution,
+        seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+    });
+
+    msg!(
+        "Buyback configured: enabled={}, mint={}, share={} bps, interval={}s, cap={}",
+        enabled,
+        config.buyback_mint,
+        fee_share_bps,
+        min_interval_secs,
+        max_usdc_per_execution
+    );
+
+    Ok(())
+}
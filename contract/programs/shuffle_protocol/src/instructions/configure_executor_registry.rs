@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::{ConfigureExecutorRegistry, ExecutorRegistryConfiguredEvent};
+
+// =============================================================================
+// CONFIGURE EXECUTOR REGISTRY - Toggle bonded execution
+// =============================================================================
+// The registry is created on first call. With gated set, execute_batch and
+// execute_swaps only accept executors bonded in the registry; the operator
+// key alone no longer runs execute_swaps. min_bond_lamports applies to new
+// registrations, existing bonds are kept.
+//
+// Register executors before gating: with no bonded executor, batches cannot
+// execute until the gate is lifted again.
+
+/// Set Pool.executor_gated and the registry's minimum bond.
+/// Only callable by pool authority.
+///
+/// # Arguments
+/// * `gated` - Restrict execution to bonded executors (false = permissionless)
+/// * `min_bond_lamports` - Bond required by register_executor
+pub fn handler(
+    ctx: Context<ConfigureExecutorRegistry>,
+    gated: bool,
+    min_bond_lamports: u64,
+) -> Result<()> {
+    let registry = &mut ctx.accounts.executor_registry;
+    registry.bump = ctx.bumps.executor_registry;
+    registry.min_bond_lamports = min_bond_lamports;
+
+    ctx.accounts.pool.executor_gated = gated;
+
+    emit!(ExecutorRegistryConfiguredEvent {
+        gated,
+        min_bond_lamports,
+        seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+    });
+
+    msg!(
+        "Executor registry configured: gated={}, min bond {} lamports",
+        gated,
+        min_bond_lamports
+    );
+    Ok(())
+}
@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::state::{PairRegistry, PAIR_FEE_POOL_DEFAULT};
+use crate::{ConfigurePair, PairConfiguredEvent};
+
+// =============================================================================
+// CONFIGURE PAIR - Halt/resume a pair and set its fee
+// =============================================================================
+// See state/pair_registry.rs. The registry is created on first call with
+// every pair enabled at the pool's fee, and from then on execute_batch
+// requires it (Pool.pair_registry_configured). Halting a pair does not touch
+// orders already revealed; it applies from the next execute_batch.
+
+/// Enable or disable one pair and set its execution fee.
+/// Only callable by pool authority.
+///
+/// # Arguments
+/// * `pair_id` - Pair to configure (0-5)
+/// * `enabled` - Whether the pair's orders execute
+/// * `fee_bps` - Execution fee (<= MAX_FEE_BPS, or PAIR_FEE_POOL_DEFAULT)
+pub fn handler(
+    ctx: Context<ConfigurePair>,
+    pair_id: u8,
+    enabled: bool,
+    fee_bps: u16,
+) -> Result<()> {
+    require!(pair_id < NUM_PAIRS, ErrorCode::InvalidPairId);
+    require!(
+        fee_bps <= MAX_FEE_BPS || fee_bps == PAIR_FEE_POOL_DEFAULT,
+        ErrorCode::FeeTooHigh
+    );
+
+    ctx.accounts.pool.pair_registry_configured = true;
+    let registry = &mut ctx.accounts.pair_registry;
+    registry.bump = ctx.bumps.pair_registry;
+    if registry.is_unset() {
+        registry.pairs = PairRegistry::default_pairs();
+    }
+    let pair = &mut registry.pairs[pair_id as usize];
+    pair.enabled = enabled;
+    pair.fee_bps = fee_bps;
+
+    emit!(PairConfiguredEvent {
+        pair_id,
+        base_asset: pair.base_asset,
+        quote_asset: pair.quote_asset,
+        enabled,
+        fee_bps,
+        seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+    });
+
+    msg!(
+        "Pair configured: pair={}, enabled={}, fee={} bps",
+        pair_id,
+        enabled,
+        fee_bps
+    );
+    Ok(())
+}
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::ConfigureProfiling;
+
+// =============================================================================
+// CONFIGURE PROFILING - Toggle on-chain compute profiling
+// =============================================================================
+// Creates the Diagnostics account on first call. Operators typically reset
+// the counters right after a program upgrade so samples compare releases.
+
+/// Enable/disable compute profiling and optionally reset the counters.
+/// Only callable by the pool authority.
+///
+/// # Arguments
+/// * `enabled` - Whether profiled instructions should record metrics
+/// * `reset` - Clear all counters and samples
+pub fn handler(ctx: Context<ConfigureProfiling>, enabled: bool, reset: bool) -> Result<()> {
+    let diagnostics = &mut ctx.accounts.diagnostics;
+
+    diagnostics.enabled = enabled;
+    diagnostics.bump = ctx.bumps.diagnostics;
+
+    if reset {
+        diagnostics.reset(Clock::get()?.unix_timestamp);
+    }
+
+    msg!(
+        "Profiling {}: reset={}, since={}",
+        if enabled { "enabled" } else { "disabled" },
+        reset,
+        diagnostics.reset_at
+    );
+
+    Ok(())
+}
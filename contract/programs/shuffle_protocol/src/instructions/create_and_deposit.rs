@@ -0,0 +1,185 @@
+use anchor_lang::prelude::*;
+use arcium_anchor::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::instructions::create_user_account::{init_profile, sponsor_rent};
+use crate::state::PENDING_OP_DEPOSIT;
+use crate::{
+    check_issuer_allowlist, token_transfer_fee, transfer_checked_with_hook, AddBalanceCallback,
+    CheckedTransferAccounts, CreateAndDeposit,
+};
+
+// =============================================================================
+// CREATE AND DEPOSIT - One-transaction onboarding
+// =============================================================================
+// create_user_account followed by add_balance in a single instruction. The
+// profile is initialized exactly as create_user_account does (client-encrypted
+// zeros, optional rent sponsorship), then the first deposit is transferred
+// into the vault and queued through the add_balance circuit against the
+// fresh zero balance. The deposit is completed by add_balance_callback like
+// any other deposit.
+//
+// The deposit amount must be encrypted under a different nonce than the
+// initial balances.
+
+/// Create a privacy account and queue its first deposit.
+///
+/// # Arguments
+/// * `computation_offset` - Unique ID for the add_balance computation
+/// * `user_pubkey` - User's x25519 public key for encryption/decryption
+/// * `initial_balances` - Encrypted zero balances for all 4 assets
+/// * `initial_nonce` - Nonce used to encrypt the initial balances
+/// * `encrypted_amount` - The deposit amount encrypted with user's key
+/// * `nonce` - Nonce of the encrypted amount
+/// * `amount` - Plaintext amount for token transfer (revealed for CPI)
+/// * `asset_id` - Asset identifier (0=USDC, 1=TSLA, 2=SPY, 3=AAPL)
+///
+/// For restricted assets, remaining_accounts carry the Token-2022
+/// transfer-hook extra accounts.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, CreateAndDeposit<'info>>,
+    computation_offset: u64,
+    user_pubkey: [u8; 32],
+    initial_balances: [[u8; 32]; 4],
+    initial_nonce: u128,
+    encrypted_amount: [u8; 32],
+    nonce: u128,
+    amount: u64,
+    asset_id: u8,
+) -> Result<()> {
+    require!(!ctx.accounts.pool.paused, ErrorCode::ProtocolPaused);
+    // Validate asset_id
+    require!(asset_id <= 3, ErrorCode::InvalidAssetId);
+    require!(nonce != initial_nonce, ErrorCode::DepositNonceReused);
+    require!(
+        !ctx.accounts.pool.is_asset_frozen(asset_id),
+        ErrorCode::AssetFrozen
+    );
+    require!(
+        !ctx.accounts.pool.is_asset_delisted(asset_id),
+        ErrorCode::AssetDelisted
+    );
+    require!(
+        ctx.accounts.pool.mint_for_asset(asset_id) == Some(ctx.accounts.mint.key()),
+        ErrorCode::InvalidMint
+    );
+
+    init_profile(
+        &mut ctx.accounts.user_account,
+        ctx.accounts.user.key(),
+        ctx.bumps.user_account,
+        user_pubkey,
+        initial_balances,
+        initial_nonce,
+    )?;
+    sponsor_rent(
+        ctx.accounts.rent_sponsor.as_deref_mut(),
+        &ctx.accounts.payer.to_account_info(),
+        &mut ctx.accounts.user_account,
+        &mut ctx.accounts.event_cursor,
+    )?;
+
+    // Restricted assets: the depositor must be on the issuer allowlist
+    let hook_accounts = match ctx.accounts.asset_policy.as_ref() {
+        Some(policy) => {
+            check_issuer_allowlist(
+                policy,
+                &ctx.accounts.user.key(),
+                ctx.accounts.allowlist_entry.as_ref().map(|e| e.as_ref()),
+            )?;
+            if policy.has_transfer_hook() {
+                ctx.remaining_accounts
+            } else {
+                &[]
+            }
+        }
+        None => {
+            require!(
+                !ctx.accounts.pool.is_asset_restricted(asset_id),
+                ErrorCode::InvalidAssetPolicy
+            );
+            &[]
+        }
+    };
+
+    // A transfer-fee mint delivers amount minus its fee; the encrypted
+    // amount credited must be that net amount (the SDK encrypts it).
+    let transfer_fee = token_transfer_fee(&ctx.accounts.mint.to_account_info(), amount)?;
+    transfer_checked_with_hook(
+        CheckedTransferAccounts {
+            token_program: &ctx.accounts.token_program.to_account_info(),
+            from: &ctx.accounts.user_token_account.to_account_info(),
+            mint: &ctx.accounts.mint.to_account_info(),
+            to: &ctx.accounts.vault.to_account_info(),
+            authority: &ctx.accounts.user.to_account_info(),
+            hook_accounts,
+        },
+        amount,
+        ctx.accounts.mint.decimals,
+        &[],
+    )?;
+
+    // add_balance_callback reads the asset from the PendingOperation
+    let pending = &mut ctx.accounts.pending_operation;
+    pending.owner = ctx.accounts.user.key();
+    pending.computation = ctx.accounts.computation_account.key();
+    pending.kind = PENDING_OP_DEPOSIT;
+    pending.asset_id = asset_id;
+    pending.amount = amount;
+    pending.balance_nonce = initial_nonce;
+    pending.encrypted_amount = encrypted_amount;
+    pending.amount_pubkey = user_pubkey;
+    pending.amount_nonce = nonce;
+    pending.queued_at = Clock::get()?.unix_timestamp;
+    pending.bump = ctx.bumps.pending_operation;
+
+    ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+    let args = ArgBuilder::new()
+        // Shared input 1: BalanceUpdate (first deposit amount)
+        .x25519_pubkey(user_pubkey)
+        .plaintext_u128(nonce)
+        .encrypted_u64(encrypted_amount)
+        // Shared input 2: UserBalance (the encrypted zero just stored)
+        .x25519_pubkey(user_pubkey)
+        .plaintext_u128(initial_nonce)
+        .encrypted_u64(initial_balances[asset_id as usize])
+        .build();
+
+    use arcium_client::idl::arcium::types::CallbackAccount;
+    queue_computation(
+        ctx.accounts,
+        computation_offset,
+        args,
+        vec![AddBalanceCallback::callback_ix(
+            computation_offset,
+            &ctx.accounts.mxe_account,
+            &[
+                CallbackAccount {
+                    pubkey: ctx.accounts.user_account.key(),
+                    is_writable: true,
+                },
+                CallbackAccount {
+                    pubkey: ctx.accounts.pending_operation.key(),
+                    is_writable: true,
+                },
+                CallbackAccount {
+                    pubkey: ctx.accounts.user.key(),
+                    is_writable: true,
+                },
+                crate::event_cursor_callback_account(ctx.accounts.pool.pool_id),
+            ],
+        )?],
+        1,
+        ctx.accounts.pool.callback_cu_price_micro,
+    )?;
+
+    msg!(
+        "Account created, first deposit queued: {} units of asset {} ({} net of transfer fee), computation {}",
+        amount,
+        asset_id,
+        amount - transfer_fee,
+        computation_offset
+    );
+    Ok(())
+}
@@ -0,0 +1,103 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::state::UserProfile;
+use crate::{CreateDca, DcaCreatedEvent};
+
+// =============================================================================
+// CREATE DCA - Register a recurring encrypted order
+// =============================================================================
+// The user encrypts the per-interval order once (same format as place_order).
+// execute_dca re-submits it into the current batch every interval.
+// A USDC schedule may be funded from a savings bucket, which must exist
+// and be passed here.
+
+/// Create a recurring DCA schedule.
+///
+/// # Arguments
+/// * `dca_id` - Schedule identifier (PDA seed, lets a user run several schedules)
+/// * `encrypted_pair_id` - Pair ID (0-5) encrypted with user's key
+/// * `encrypted_direction` - Direction (0=A_to_B, 1=B_to_A) encrypted with user's key
+/// * `encrypted_amount` - Amount per interval encrypted with user's key
+/// * `pubkey` - User's x25519 public key
+/// * `nonce` - Encryption nonce for the order
+/// * `source_asset_id` - Plaintext hint: which asset is being sold
+/// * `interval_secs` - Seconds between executions
+/// * `start_ts` - First execution time (0 = now)
+/// * `max_executions` - Stop after this many executions (0 = unlimited)
+/// * `funding_bucket` - Savings bucket the orders draw from (None = trading balance)
+pub fn handler(
+    ctx: Context<CreateDca>,
+    dca_id: u64,
+    encrypted_pair_id: [u8; 32],
+    encrypted_direction: [u8; 32],
+    encrypted_amount: [u8; 32],
+    pubkey: [u8; 32],
+    nonce: u128,
+    source_asset_id: u8,
+    interval_secs: i64,
+    start_ts: i64,
+    max_executions: u32,
+    funding_bucket: Option<u8>,
+) -> Result<()> {
+    require!(source_asset_id <= 3, ErrorCode::InvalidAssetId);
+    if let Some(bucket_id) = funding_bucket {
+        // Buckets hold USDC only
+        require!(
+            source_asset_id == UserProfile::ASSET_USDC,
+            ErrorCode::InvalidAssetId
+        );
+        require!(
+            ctx.accounts
+                .savings_bucket
+                .as_ref()
+                .is_some_and(|bucket| bucket.bucket_id == bucket_id),
+            ErrorCode::InvalidSavingsBucket
+        );
+    }
+    require!(
+        interval_secs >= DCA_MIN_INTERVAL_SECS,
+        ErrorCode::InvalidDcaInterval
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let dca = &mut ctx.accounts.dca_schedule;
+
+    dca.owner = ctx.accounts.user.key();
+    dca.dca_id = dca_id;
+    dca.pubkey = pubkey;
+    dca.encrypted_pair_id = encrypted_pair_id;
+    dca.encrypted_direction = encrypted_direction;
+    dca.encrypted_amount = encrypted_amount;
+    dca.order_nonce = nonce;
+    dca.source_asset_id = source_asset_id;
+    dca.interval_secs = interval_secs;
+    dca.next_execution_ts = start_ts.max(now);
+    dca.executions = 0;
+    dca.max_executions = max_executions;
+    dca.created_at = now;
+    dca.paused = false;
+    dca.skipped_count = 0;
+    dca.funding_bucket = funding_bucket;
+    dca.bump = ctx.bumps.dca_schedule;
+
+    emit!(DcaCreatedEvent {
+        owner: dca.owner,
+        dca_id,
+        interval_secs,
+        next_execution_ts: dca.next_execution_ts,
+        max_executions,
+        seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+    });
+
+    msg!(
+        "DCA {} created for {}: every {}s starting {}",
+        dca_id,
+        dca.owner,
+        interval_secs,
+        dca.next_execution_ts
+    );
+
+    Ok(())
+}
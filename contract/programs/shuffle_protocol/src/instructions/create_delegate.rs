@@ -0,0 +1,108 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_DELEGATE_DURATION_SECS;
+use crate::errors::ErrorCode;
+use crate::state::Delegate;
+use crate::{CreateDelegate, DelegateCreatedEvent};
+
+// =============================================================================
+// CREATE DELEGATE - Authorize a session key
+// =============================================================================
+// Re-running for the same key refreshes its expiry and limit and resets the
+// per-batch counter; allowances are kept. Revoke with revoke_delegate.
+
+/// Authorize `delegate` to place and settle orders for the caller.
+///
+/// # Arguments
+/// * `delegate` - Session key public key
+/// * `expires_at` - Unix timestamp when the delegation ends (<= now + MAX_DELEGATE_DURATION_SECS)
+/// * `max_orders_per_batch` - Orders the delegate may place per batch (>= 1)
+pub fn handler(
+    ctx: Context<CreateDelegate>,
+    delegate: Pubkey,
+    expires_at: i64,
+    max_orders_per_batch: u8,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        expires_at > now && expires_at <= now + MAX_DELEGATE_DURATION_SECS,
+        ErrorCode::InvalidDelegateExpiry
+    );
+    require!(max_orders_per_batch > 0, ErrorCode::InvalidAmount);
+    require!(
+        delegate != ctx.accounts.user.key() && delegate != Pubkey::default(),
+        ErrorCode::InvalidDelegate
+    );
+
+    let account = &mut ctx.accounts.delegate_account;
+    account.owner = ctx.accounts.user.key();
+    account.delegate = delegate;
+    account.expires_at = expires_at;
+    account.max_orders_per_batch = max_orders_per_batch;
+    account.current_batch_id = 0;
+    account.orders_in_batch = 0;
+    account.bump = ctx.bumps.delegate_account;
+
+    emit!(DelegateCreatedEvent {
+        owner: account.owner,
+        delegate,
+        expires_at,
+        max_orders_per_batch,
+        seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+    });
+
+    msg!(
+        "Delegate {} authorized for {} until {} ({} orders/batch)",
+        delegate,
+        account.owner,
+        expires_at,
+        max_orders_per_batch
+    );
+    Ok(())
+}
+
+/// Check that `signer` may act for `owner`: either the owner itself or an
+/// active session key. When `order_batch_id` is set, the order counts against
+/// the delegate's per-batch limit.
+pub(crate) fn authorize_owner_or_delegate(
+    owner: Pubkey,
+    signer: Pubkey,
+    delegate: Option<&mut Account<Delegate>>,
+    order_batch_id: Option<u64>,
+) -> Result<()> {
+    if signer == owner {
+        return Ok(());
+    }
+
+    // The account constraint already ties the PDA to (owner, signer)
+    let delegate = delegate.ok_or(ErrorCode::InvalidOwner)?;
+    require!(
+        delegate.is_active(Clock::get()?.unix_timestamp),
+        ErrorCode::DelegateExpired
+    );
+    if let Some(batch_id) = order_batch_id {
+        require!(
+            delegate.record_order(batch_id),
+            ErrorCode::DelegateOrderLimitReached
+        );
+    }
+    Ok(())
+}
+
+/// Reject a delegated order selling an asset the owner capped with an
+/// allowance: those orders must go through place_delegated_order.
+pub(crate) fn require_no_allowance(
+    owner: Pubkey,
+    signer: Pubkey,
+    delegate: Option<&Account<Delegate>>,
+    source_asset_id: u8,
+) -> Result<()> {
+    if signer == owner {
+        return Ok(());
+    }
+    require!(
+        !delegate.is_some_and(|delegate| delegate.has_allowance(source_asset_id)),
+        ErrorCode::DelegateAllowanceRequired
+    );
+    Ok(())
+}
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::{CreateSavingsBucket, SavingsBucketCreatedEvent};
+
+// =============================================================================
+// CREATE SAVINGS BUCKET - Open a labeled USDC partition
+// =============================================================================
+// As in create_user_account, the client encrypts a zero balance with the
+// profile's x25519 key so move_savings can decrypt it on the first move.
+
+/// Create a savings bucket for the caller.
+///
+/// # Arguments
+/// * `bucket_id` - Bucket identifier (PDA seed, lets a user keep several buckets)
+/// * `label` - Display label, UTF-8 padded with zeros
+/// * `initial_balance` - `{balance: 0}` encrypted with the profile's user_pubkey
+/// * `initial_nonce` - Nonce used to encrypt the initial balance
+pub fn handler(
+    ctx: Context<CreateSavingsBucket>,
+    bucket_id: u8,
+    label: [u8; 16],
+    initial_balance: [u8; 32],
+    initial_nonce: u128,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let bucket = &mut ctx.accounts.savings_bucket;
+
+    bucket.owner = ctx.accounts.user.key();
+    bucket.bucket_id = bucket_id;
+    bucket.label = label;
+    bucket.encrypted_balance = initial_balance;
+    bucket.nonce = initial_nonce;
+    bucket.created_at = now;
+    bucket.bump = ctx.bumps.savings_bucket;
+
+    emit!(SavingsBucketCreatedEvent {
+        owner: bucket.owner,
+        bucket_id,
+        label,
+        seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+    });
+
+    msg!("Savings bucket {} created for {}", bucket_id, bucket.owner);
+    Ok(())
+}
@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::{CreateStream, StreamCreatedEvent};
+
+// =============================================================================
+// CREATE STREAM - Open a payment stream to another privacy account
+// =============================================================================
+// The rate is encrypted like an internal_transfer amount (TransferRequest
+// with the per-second amount) and stored as-is: every advance_stream feeds
+// the same ciphertext to the stream_transfer circuit.
+
+/// Open a payment stream from the caller to a payee.
+///
+/// # Arguments
+/// * `stream_id` - Payer-chosen ID of the stream (unique per payer)
+/// * `asset_id` - Asset to stream (0=USDC, 1=TSLA, 2=SPY, 3=AAPL)
+/// * `encrypted_rate` - Amount per second encrypted with `pubkey`
+/// * `pubkey` - x25519 public key the rate was encrypted with
+/// * `nonce` - Encryption nonce of the rate
+/// * `start_ts` - Unix timestamp the stream starts accruing
+/// * `end_ts` - Unix timestamp the stream stops accruing (> start_ts, in the future)
+pub fn handler(
+    ctx: Context<CreateStream>,
+    stream_id: u64,
+    asset_id: u8,
+    encrypted_rate: [u8; 32],
+    pubkey: [u8; 32],
+    nonce: u128,
+    start_ts: i64,
+    end_ts: i64,
+) -> Result<()> {
+    require!(!ctx.accounts.pool.paused, ErrorCode::ProtocolPaused);
+    require!(asset_id <= 3, ErrorCode::InvalidAssetId);
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        start_ts < end_ts
+            && end_ts > now
+            && ctx.accounts.payee_account.owner != ctx.accounts.payer.key(),
+        ErrorCode::InvalidPaymentStream
+    );
+
+    let stream = &mut ctx.accounts.payment_stream;
+    stream.payer = ctx.accounts.payer.key();
+    stream.payee = ctx.accounts.payee_account.owner;
+    stream.stream_id = stream_id;
+    stream.pool_id = ctx.accounts.pool.pool_id;
+    stream.asset_id = asset_id;
+    stream.rate_pubkey = pubkey;
+    stream.encrypted_rate = encrypted_rate;
+    stream.rate_nonce = nonce;
+    stream.start_ts = start_ts;
+    stream.end_ts = end_ts;
+    stream.last_advanced_ts = start_ts;
+    stream.pending_computation = Pubkey::default();
+    stream.pending_until = 0;
+    stream.pending_payer_nonce = 0;
+    stream.pending_payee_nonce = 0;
+    stream.advances = 0;
+    stream.bump = ctx.bumps.payment_stream;
+
+    emit!(StreamCreatedEvent {
+        payer: stream.payer,
+        payee: stream.payee,
+        stream_id,
+        asset_id,
+        start_ts,
+        end_ts,
+        seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+    });
+
+    msg!(
+        "Stream {} created: {} -> {}, asset={}, {}..{}",
+        stream_id,
+        stream.payer,
+        stream.payee,
+        asset_id,
+        start_ts,
+        end_ts
+    );
+    Ok(())
+}
@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 
-use crate::CreateUserAccount;
+use crate::state::{EventCursor, RentSponsor, UserProfile};
+use crate::{CreateUserAccount, RentSponsoredEvent};
 
 // =============================================================================
 // CREATE USER ACCOUNT INSTRUCTION HANDLER
@@ -11,6 +12,11 @@ use crate::CreateUserAccount;
 // Key change: User must provide an encrypted zero balance for USDC.
 // Since we use Enc<Shared, *> for all user data, the client encrypts with
 // their shared secret and provides the initial ciphertext and nonce.
+//
+// Rent sponsorship: if the RentSponsor pool is passed and holds enough
+// lamports above its own rent-exempt minimum, the payer is reimbursed the
+// account rent and the profile is flagged rent_sponsored. Otherwise the
+// payer keeps the cost, as without a sponsor.
 
 /// Create a new privacy account (UserProfile) for the user.
 ///
@@ -29,14 +35,38 @@ pub fn handler(
     initial_balances: [[u8; 32]; 4],
     initial_nonce: u128,
 ) -> Result<()> {
-    // Get the user account and initialize its fields
-    let user_account = &mut ctx.accounts.user_account;
+    init_profile(
+        &mut ctx.accounts.user_account,
+        ctx.accounts.owner.key(),
+        ctx.bumps.user_account,
+        user_pubkey,
+        initial_balances,
+        initial_nonce,
+    )?;
+
+    // Reimburse the payer from the sponsorship pool when it can cover the rent
+    sponsor_rent(
+        ctx.accounts.rent_sponsor.as_deref_mut(),
+        &ctx.accounts.payer.to_account_info(),
+        &mut ctx.accounts.user_account,
+        &mut ctx.accounts.event_cursor,
+    )
+}
 
+/// Initialize a freshly created UserProfile (shared with create_and_deposit)
+pub fn init_profile(
+    user_account: &mut UserProfile,
+    owner: Pubkey,
+    bump: u8,
+    user_pubkey: [u8; 32],
+    initial_balances: [[u8; 32]; 4],
+    initial_nonce: u128,
+) -> Result<()> {
     // Store the PDA bump - used for signing in future instructions
-    user_account.bump = ctx.bumps.user_account;
+    user_account.bump = bump;
 
     // Set the owner to the signer's wallet address
-    user_account.owner = ctx.accounts.owner.key();
+    user_account.owner = owner;
 
     // Store the x25519 public key for Arcium encryption
     user_account.user_pubkey = user_pubkey;
@@ -48,15 +78,14 @@ pub fn handler(
     user_account.spy_credit = initial_balances[2];
     user_account.aapl_credit = initial_balances[3];
 
-    // Viewable balances (not used currently - all zeros)
-    user_account.usdc_viewable = [0u8; 32];
-    user_account.tsla_viewable = [0u8; 32];
-    user_account.spy_viewable = [0u8; 32];
-    user_account.aapl_viewable = [0u8; 32];
-
     // No pending order initially
     user_account.pending_order = None;
     user_account.pending_asset_id = 0;
+    user_account.pending_refund_asset_id = 0;
+    user_account.staged_order = None;
+    user_account.staged_asset_id = 0;
+    user_account.pending_rollover = false;
+    user_account.pending_conversion = false;
 
     // Initialize per-asset nonces - all assets use the same initial nonce
     user_account.usdc_nonce = initial_nonce;
@@ -66,12 +95,47 @@ pub fn handler(
 
     user_account.order_count = 0;
     user_account.total_faucet_claimed = 0;
+    user_account.last_active_at = Clock::get()?.unix_timestamp;
+    user_account.rent_sponsored = false;
+    user_account.version = UserProfile::VERSION;
 
     msg!("Privacy account created for user: {}", user_account.owner);
     msg!(
         "All asset balances initialized with nonce: {}",
         initial_nonce
     );
+    Ok(())
+}
+
+/// Reimburse the payer the profile rent if the RentSponsor pool can cover it
+pub fn sponsor_rent(
+    rent_sponsor: Option<&mut Account<RentSponsor>>,
+    payer: &AccountInfo,
+    user_account: &mut UserProfile,
+    event_cursor: &mut EventCursor,
+) -> Result<()> {
+    let Some(sponsor) = rent_sponsor else {
+        return Ok(());
+    };
+    let rent = Rent::get()?;
+    let cost = rent.minimum_balance(UserProfile::SIZE);
+    let available = sponsor
+        .to_account_info()
+        .lamports()
+        .saturating_sub(rent.minimum_balance(RentSponsor::SIZE));
+    if available >= cost {
+        sponsor.to_account_info().sub_lamports(cost)?;
+        payer.add_lamports(cost)?;
+        sponsor.sponsored_accounts += 1;
+        sponsor.total_sponsored = sponsor.total_sponsored.saturating_add(cost);
+        user_account.rent_sponsored = true;
 
+        emit!(RentSponsoredEvent {
+            owner: user_account.owner,
+            amount: cost,
+            seq: event_cursor.next(Clock::get()?.slot),
+        });
+        msg!("Account rent sponsored: {} lamports", cost);
+    }
     Ok(())
 }
@@ -0,0 +1,109 @@
+use anchor_lang::prelude::*;
+use arcium_anchor::prelude::*;
+
+use crate::constants::{MAX_TRANSFER_EXPIRY_SECS, MIN_TRANSFER_EXPIRY_SECS};
+use crate::errors::ErrorCode;
+use crate::state::VOUCHER_STATUS_FUNDING;
+use crate::{CreateVoucher, FundVoucherCallback};
+
+// =============================================================================
+// CREATE VOUCHER - Fund a claim-link deposit
+// =============================================================================
+// Creates the Voucher under the claim key's PDA and queues fund_voucher,
+// which deducts the amount from the funder and escrows it for the MXE. The
+// claim key's secret is shared off-chain (e.g. as a link); whoever holds it
+// has `expiry_secs` to claim_voucher, after which the funder may
+// reclaim_voucher.
+
+/// Escrow an encrypted amount behind a voucher secret.
+///
+/// # Arguments
+/// * `computation_offset` - Unique ID for MPC computation
+/// * `asset_id` - Asset to escrow (0=USDC, 1=TSLA, 2=SPY, 3=AAPL)
+/// * `encrypted_amount` - Amount encrypted for the funder's user_pubkey
+/// * `nonce` - Encryption nonce (fresh; also seeds the escrow's MXE nonce)
+/// * `expiry_secs` - Claim window (MIN_TRANSFER_EXPIRY_SECS..=MAX_TRANSFER_EXPIRY_SECS)
+pub fn handler(
+    ctx: Context<CreateVoucher>,
+    computation_offset: u64,
+    asset_id: u8,
+    encrypted_amount: [u8; 32],
+    nonce: u128,
+    expiry_secs: i64,
+) -> Result<()> {
+    require!(!ctx.accounts.pool.paused, ErrorCode::ProtocolPaused);
+    require!(asset_id <= 3, ErrorCode::InvalidAssetId);
+    require!(
+        !ctx.accounts.pool.is_asset_frozen(asset_id),
+        ErrorCode::AssetFrozen
+    );
+    require!(
+        (MIN_TRANSFER_EXPIRY_SECS..=MAX_TRANSFER_EXPIRY_SECS).contains(&expiry_secs),
+        ErrorCode::InvalidVoucher
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let voucher = &mut ctx.accounts.voucher;
+    voucher.claim_key = ctx.accounts.claim_key.key();
+    voucher.pool_id = ctx.accounts.pool.pool_id;
+    voucher.funder = ctx.accounts.funder.key();
+    voucher.asset_id = asset_id;
+    voucher.created_at = now;
+    voucher.expires_at = now.saturating_add(expiry_secs);
+    voucher.status = VOUCHER_STATUS_FUNDING;
+    voucher.pending_computation = ctx.accounts.computation_account.key();
+    voucher.queued_at = now;
+    voucher.bump = ctx.bumps.voucher;
+
+    // Funder activity resets their beneficiary inactivity timer
+    ctx.accounts.funder_account.last_active_at = now;
+
+    // Set sign PDA bump
+    ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+    let funder = &ctx.accounts.funder_account;
+    let args = ArgBuilder::new()
+        // TransferRequest (encrypted with funder's stored key) - just amount field
+        .x25519_pubkey(funder.user_pubkey)
+        .plaintext_u128(nonce)
+        .encrypted_u64(encrypted_amount)
+        // Funder's current balance (Enc<Shared, *> - funder's pubkey)
+        .x25519_pubkey(funder.user_pubkey)
+        .plaintext_u128(funder.get_nonce(asset_id))
+        .encrypted_u64(funder.get_credit(asset_id))
+        // Mxe owner of the escrow
+        .plaintext_u128(nonce)
+        .build();
+
+    use arcium_client::idl::arcium::types::CallbackAccount;
+    queue_computation(
+        ctx.accounts,
+        computation_offset,
+        args,
+        vec![FundVoucherCallback::callback_ix(
+            computation_offset,
+            &ctx.accounts.mxe_account,
+            &[
+                CallbackAccount {
+                    pubkey: ctx.accounts.funder_account.key(),
+                    is_writable: true,
+                },
+                CallbackAccount {
+                    pubkey: ctx.accounts.voucher.key(),
+                    is_writable: true,
+                },
+                crate::event_cursor_callback_account(ctx.accounts.pool.pool_id),
+            ],
+        )?],
+        1,
+        0,
+    )?;
+
+    msg!(
+        "Voucher {} queued by {}, asset={}",
+        ctx.accounts.voucher.claim_key,
+        ctx.accounts.voucher.funder,
+        asset_id
+    );
+    Ok(())
+}
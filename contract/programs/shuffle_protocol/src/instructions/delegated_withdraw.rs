@@ -0,0 +1,195 @@
+use anchor_lang::prelude::*;
+use arcium_anchor::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::PENDING_OP_WITHDRAWAL;
+use crate::{check_issuer_allowlist, DelegatedWithdraw, SubBalanceDelegatedCallback};
+
+// =============================================================================
+// DELEGATED WITHDRAW - Withdrawal drawn against a delegate allowance
+// =============================================================================
+// Lets a delegate (bot, sub-account) withdraw an asset the owner capped with
+// set_delegate_allowance. The sub_balance_delegated circuit checks the amount
+// against both the owner's balance and the remaining allowance and
+// decrements both, so the delegate can never move more than its budget.
+// Orders (place_delegated_order) and withdrawals draw on the same allowance.
+//
+// As with sub_balance, the token transfer is deferred to the callback and
+// the context lives in a PendingOperation (cancel_withdrawal drops it if the
+// callback never arrives). The callback also rejects a result computed
+// against an allowance the owner has since replaced.
+
+/// Withdraw tokens from the owner's privacy account as a delegate.
+///
+/// # Arguments
+/// * `computation_offset` - Unique ID for MPC computation
+/// * `amount` - Amount of tokens to withdraw
+/// * `asset_id` - Asset to withdraw (0=USDC, 1=TSLA, 2=SPY, 3=AAPL)
+///
+/// For restricted assets, remaining_accounts carry the Token-2022
+/// transfer-hook extra accounts; they are forwarded to the callback.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, DelegatedWithdraw<'info>>,
+    computation_offset: u64,
+    amount: u64,
+    asset_id: u8,
+) -> Result<()> {
+    require!(!ctx.accounts.pool.paused, ErrorCode::ProtocolPaused);
+    require!(asset_id <= 3, ErrorCode::InvalidAssetId);
+    require!(amount > 0, ErrorCode::InvalidAmount);
+    require!(
+        !ctx.accounts.pool.is_asset_frozen(asset_id),
+        ErrorCode::AssetFrozen
+    );
+    require!(
+        ctx.accounts.pool.mint_for_asset(asset_id) == Some(ctx.accounts.mint.key()),
+        ErrorCode::InvalidMint
+    );
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        ctx.accounts.delegate.is_active(now),
+        ErrorCode::DelegateExpired
+    );
+    require!(
+        ctx.accounts.delegate.has_allowance(asset_id),
+        ErrorCode::NoDelegateAllowance
+    );
+
+    // Restricted assets: the recipient must be on the issuer allowlist
+    let hook_accounts = match ctx.accounts.asset_policy.as_ref() {
+        Some(policy) => {
+            check_issuer_allowlist(
+                policy,
+                &ctx.accounts.recipient_token_account.owner,
+                ctx.accounts.allowlist_entry.as_ref().map(|e| e.as_ref()),
+            )?;
+            if policy.has_transfer_hook() {
+                ctx.remaining_accounts
+            } else {
+                &[]
+            }
+        }
+        None => {
+            require!(
+                !ctx.accounts.pool.is_asset_restricted(asset_id),
+                ErrorCode::InvalidAssetPolicy
+            );
+            &[]
+        }
+    };
+
+    // Daily cap counts against the owner, not the delegate
+    let owner = ctx.accounts.user_account.owner;
+    crate::check_withdrawal_cap(
+        &ctx.accounts.pool,
+        ctx.accounts.risk_config.as_deref().map(|c| &**c),
+        ctx.accounts
+            .withdrawal_window
+            .as_deref_mut()
+            .map(|w| &mut **w),
+        &owner,
+        asset_id,
+        amount,
+    )?;
+
+    ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+    // Balance and allowance are encrypted for the owner's stored key
+    let owner_pubkey = ctx.accounts.user_account.user_pubkey;
+    let asset = asset_id as usize;
+    let balance_nonce = ctx.accounts.user_account.get_nonce(asset_id);
+    let allowance_nonce = ctx.accounts.delegate.allowance_nonces[asset];
+
+    let pending = &mut ctx.accounts.pending_operation;
+    pending.owner = ctx.accounts.user_account.owner;
+    pending.computation = ctx.accounts.computation_account.key();
+    pending.kind = PENDING_OP_WITHDRAWAL;
+    pending.asset_id = asset_id;
+    pending.amount = amount;
+    pending.balance_nonce = balance_nonce;
+    pending.queued_at = now;
+    pending.allowance_nonce = allowance_nonce;
+    pending.bump = ctx.bumps.pending_operation;
+
+    let args = ArgBuilder::new()
+        // UserBalance (Enc<Shared>) - owner's balance of the asset
+        .x25519_pubkey(owner_pubkey)
+        .plaintext_u128(balance_nonce)
+        .encrypted_u64(ctx.accounts.user_account.get_credit(asset_id))
+        // Allowance (Enc<Shared>) - owner's remaining allowance for the asset
+        .x25519_pubkey(owner_pubkey)
+        .plaintext_u128(allowance_nonce)
+        .encrypted_u64(ctx.accounts.delegate.allowances[asset])
+        // Withdrawal amount (revealed by the token transfer anyway)
+        .plaintext_u64(amount)
+        .build();
+
+    // Transfer-hook extra accounts follow the named callback accounts
+    use arcium_client::idl::arcium::types::CallbackAccount;
+    let mut callback_accounts = vec![
+        CallbackAccount {
+            pubkey: ctx.accounts.user_account.key(),
+            is_writable: true,
+        },
+        CallbackAccount {
+            pubkey: ctx.accounts.pool.key(),
+            is_writable: false,
+        },
+        CallbackAccount {
+            pubkey: ctx.accounts.vault.key(),
+            is_writable: true,
+        },
+        CallbackAccount {
+            pubkey: ctx.accounts.recipient_token_account.key(),
+            is_writable: true,
+        },
+        CallbackAccount {
+            pubkey: ctx.accounts.token_program.key(),
+            is_writable: false,
+        },
+        CallbackAccount {
+            pubkey: ctx.accounts.mint.key(),
+            is_writable: false,
+        },
+        CallbackAccount {
+            pubkey: ctx.accounts.delegate.key(),
+            is_writable: true,
+        },
+        CallbackAccount {
+            pubkey: ctx.accounts.pending_operation.key(),
+            is_writable: true,
+        },
+        CallbackAccount {
+            pubkey: ctx.accounts.user_account.owner,
+            is_writable: true,
+        },
+        crate::event_cursor_callback_account(ctx.accounts.pool.pool_id),
+    ];
+    callback_accounts.extend(hook_accounts.iter().map(|info| CallbackAccount {
+        pubkey: info.key(),
+        is_writable: info.is_writable,
+    }));
+
+    queue_computation(
+        ctx.accounts,
+        computation_offset,
+        args,
+        vec![SubBalanceDelegatedCallback::callback_ix(
+            computation_offset,
+            &ctx.accounts.mxe_account,
+            &callback_accounts,
+        )?],
+        1,
+        ctx.accounts.pool.callback_cu_price_micro,
+    )?;
+
+    msg!(
+        "Delegated withdrawal queued: user={}, delegate={}, {} units of asset {}, computation {}",
+        ctx.accounts.user_account.owner,
+        ctx.accounts.user.key(),
+        amount,
+        asset_id,
+        computation_offset
+    );
+    Ok(())
+}
@@ -0,0 +1,153 @@
+use anchor_lang::prelude::*;
+use arcium_anchor::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::{
+    check_issuer_allowlist, token_transfer_fee, transfer_checked_with_hook,
+    CheckedTransferAccounts, CreditBalanceCallback, DepositFor,
+};
+
+// =============================================================================
+// DEPOSIT FOR - Fund another user's privacy account
+// =============================================================================
+// Variant of add_balance for sponsored onboarding and gifts: the depositor's
+// tokens go into the vault and the credit lands on the recipient's
+// UserProfile, encrypted under the recipient's key by the credit_balance
+// circuit. The depositor cannot encrypt for that key, so the circuit takes
+// the amount in plaintext; it is the net amount the vault received, which
+// the token transfer reveals anyway.
+//
+// Nothing is written to the recipient's pending fields (a third party must
+// not disturb the recipient's own in-flight operations); the callback
+// derives the asset from the mint.
+
+/// Deposit tokens from the depositor and credit them to a recipient.
+///
+/// # Arguments
+/// * `computation_offset` - Unique ID for MPC computation
+/// * `amount` - Amount of tokens to transfer
+/// * `asset_id` - Asset to deposit (0=USDC, 1=TSLA, 2=SPY, 3=AAPL)
+///
+/// For restricted assets both the depositor and the recipient must be on
+/// the issuer allowlist, and remaining_accounts carry the Token-2022
+/// transfer-hook extra accounts.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, DepositFor<'info>>,
+    computation_offset: u64,
+    amount: u64,
+    asset_id: u8,
+) -> Result<()> {
+    require!(!ctx.accounts.pool.paused, ErrorCode::ProtocolPaused);
+    require!(asset_id <= 3, ErrorCode::InvalidAssetId);
+    require!(amount > 0, ErrorCode::InvalidAmount);
+    require!(
+        !ctx.accounts.pool.is_asset_frozen(asset_id),
+        ErrorCode::AssetFrozen
+    );
+    require!(
+        !ctx.accounts.pool.is_asset_delisted(asset_id),
+        ErrorCode::AssetDelisted
+    );
+    require!(
+        ctx.accounts.pool.mint_for_asset(asset_id) == Some(ctx.accounts.mint.key()),
+        ErrorCode::InvalidMint
+    );
+
+    // Restricted assets: depositor and recipient must be on the issuer allowlist
+    let hook_accounts = match ctx.accounts.asset_policy.as_ref() {
+        Some(policy) => {
+            check_issuer_allowlist(
+                policy,
+                &ctx.accounts.depositor.key(),
+                ctx.accounts.allowlist_entry.as_ref().map(|e| e.as_ref()),
+            )?;
+            check_issuer_allowlist(
+                policy,
+                &ctx.accounts.recipient_account.owner,
+                ctx.accounts
+                    .recipient_allowlist_entry
+                    .as_ref()
+                    .map(|e| e.as_ref()),
+            )?;
+            if policy.has_transfer_hook() {
+                ctx.remaining_accounts
+            } else {
+                &[]
+            }
+        }
+        None => {
+            require!(
+                !ctx.accounts.pool.is_asset_restricted(asset_id),
+                ErrorCode::InvalidAssetPolicy
+            );
+            &[]
+        }
+    };
+
+    // The recipient is credited with what the vault actually receives
+    let credited = amount - token_transfer_fee(&ctx.accounts.mint.to_account_info(), amount)?;
+    transfer_checked_with_hook(
+        CheckedTransferAccounts {
+            token_program: &ctx.accounts.token_program.to_account_info(),
+            from: &ctx.accounts.depositor_token_account.to_account_info(),
+            mint: &ctx.accounts.mint.to_account_info(),
+            to: &ctx.accounts.vault.to_account_info(),
+            authority: &ctx.accounts.depositor.to_account_info(),
+            hook_accounts,
+        },
+        amount,
+        ctx.accounts.mint.decimals,
+        &[],
+    )?;
+
+    // Set sign PDA bump
+    ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+    let recipient = &ctx.accounts.recipient_account;
+    let args = ArgBuilder::new()
+        // Recipient's current balance (Enc<Shared, *> - recipient's pubkey)
+        .x25519_pubkey(recipient.user_pubkey)
+        .plaintext_u128(recipient.get_nonce(asset_id))
+        .encrypted_u64(recipient.get_credit(asset_id))
+        // Net amount received by the vault
+        .plaintext_u64(credited)
+        .build();
+
+    use arcium_client::idl::arcium::types::CallbackAccount;
+    queue_computation(
+        ctx.accounts,
+        computation_offset,
+        args,
+        vec![CreditBalanceCallback::callback_ix(
+            computation_offset,
+            &ctx.accounts.mxe_account,
+            &[
+                CallbackAccount {
+                    pubkey: ctx.accounts.recipient_account.key(),
+                    is_writable: true,
+                },
+                CallbackAccount {
+                    pubkey: ctx.accounts.pool.key(),
+                    is_writable: false,
+                },
+                CallbackAccount {
+                    pubkey: ctx.accounts.mint.key(),
+                    is_writable: false,
+                },
+                crate::event_cursor_callback_account(ctx.accounts.pool.pool_id),
+            ],
+        )?],
+        1,
+        ctx.accounts.pool.callback_cu_price_micro,
+    )?;
+
+    msg!(
+        "Deposit for {} queued: {} units of asset {} ({} net of transfer fee) from {}",
+        ctx.accounts.recipient_account.owner,
+        amount,
+        asset_id,
+        credited,
+        ctx.accounts.depositor.key()
+    );
+    Ok(())
+}
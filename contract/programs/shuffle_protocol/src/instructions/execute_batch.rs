@@ -1,8 +1,14 @@
 use anchor_lang::prelude::*;
 use arcium_anchor::prelude::*;
 
+use crate::constants::*;
 use crate::errors::ErrorCode;
-use crate::{ExecuteBatch, RevealBatchCallback};
+use crate::oracle::{read_asset_price, AssetPrice};
+use crate::state::{
+    BatchLog, BATCH_STATUS_REVEALING, EXECUTION_MODE_RESERVE_ONLY, PROFILE_EXECUTE_BATCH,
+    ROLE_OPERATOR,
+};
+use crate::{ExecuteBatch, PriceDivergenceEvent, RevealBatchCallback};
 
 // =============================================================================
 // EXECUTE BATCH - Queue MPC to Reveal Totals (Phase 9)
@@ -11,13 +17,38 @@ use crate::{ExecuteBatch, RevealBatchCallback};
 // After MPC reveals totals, the callback performs netting and external swaps.
 //
 // Flow:
-// 1. Operator calls execute_batch
-// 2. Handler queues reveal_batch MPC computation
+// 1. Keeper calls execute_batch once the batch holds
+//    Pool.execution_trigger_count orders (the operator may call it earlier),
+//    within Pool.max_batches_per_window unless the batch has waited
+//    max_batch_wait_secs (see set_batch_rate_limit)
+// 2. Handler snapshots oracle prices (Pyth for assets with a feed, with a
+//    Switchboard fallback, see set_oracle_feed) and queues reveal_batch MPC
+//    computation. If the two oracles disagree beyond
+//    OracleConfig.max_price_divergence_bps the handler emits
+//    PriceDivergenceEvent and returns without queuing anything. Equity pairs
+//    are halted outside the MarketSchedule's trading hours (see
+//    set_market_schedule)
 // 3. Callback receives plaintext totals for all 6 pairs
-// 4. Callback performs netting algorithm for each pair
-// 5. Callback CPIs to Jupiter for net surplus swaps
+// 4. Callback performs netting algorithm for each pair, partially filling
+//    any surplus the reserve snapshot cannot absorb and skipping pairs that
+//    touch a frozen asset
+// 5. Net surplus is absorbed by the reserves, or in external-only mode left
+//    for route_surplus to swap on the external venue (see set_execution_mode).
+//    A pair policy can split it between the two (see set_pair_policy)
 // 6. Callback creates BatchLog PDA with results
-// 7. Callback resets BatchAccumulator for next batch
+// 7. Callback resets the revealed BatchAccumulator slot, leaving it idle
+// 8. execute_swaps later pays the caller Pool.keeper_reward_bps of the batch
+//    notional out of the batch's execution fees (see set_keeper_reward), so
+//    anyone running the crank is compensated
+//
+// Batch lifecycle: accumulators are double-buffered. Before queuing the
+// reveal, the active slot hands the active flag and batch_id + 1 to the
+// other (idle) slot, so orders keep landing in the next batch while this one
+// reveals. The revealed slot is marked REVEALING until the callback resets
+// it. If the reveal aborts, the BatchLog for this batch_id already exists;
+// recover_batch_reveal reopens the slot (still inactive, orders kept) and
+// execute_batch on that slot reveals it again, reusing the log. A log that
+// already holds results is a collision and is rejected.
 
 /// Execute the current batch.
 /// Queues MPC to reveal aggregate totals, then callback handles netting and swaps.
@@ -25,9 +56,300 @@ use crate::{ExecuteBatch, RevealBatchCallback};
 /// # Arguments
 /// * `computation_offset` - Unique ID for this MPC computation
 pub fn handler(ctx: Context<ExecuteBatch>, computation_offset: u64) -> Result<()> {
+    require!(!ctx.accounts.pool.paused, ErrorCode::ProtocolPaused);
+
+    let cu_start = crate::profiling_start();
+
+    // Registry-gated mode: only bonded executors run the crank
+    crate::require_executor(
+        &ctx.accounts.pool,
+        ctx.accounts.executor_registry.as_deref().map(|r| &**r),
+        &ctx.accounts.payer.key(),
+    )?;
+
     // Set sign PDA bump
     ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
 
+    let batch_id = ctx.accounts.batch_accumulator.batch_id;
+    require!(
+        ctx.accounts.batch_accumulator.is_open(),
+        ErrorCode::BatchRevealInProgress
+    );
+    require!(
+        ctx.accounts.batch_log.executed_at == 0,
+        ErrorCode::BatchAlreadyExecuted
+    );
+
+    // Oracle prices the callback nets at: Pyth for each asset with a feed,
+    // the Switchboard fallback when the Pyth update is unusable, the mock
+    // price for assets with neither. Read before any state changes, since a
+    // divergence halts the batch without failing the transaction.
+    let now = Clock::get()?.unix_timestamp;
+    let price_updates = [
+        ctx.accounts.price_update_usdc.as_deref(),
+        ctx.accounts.price_update_tsla.as_deref(),
+        ctx.accounts.price_update_spy.as_deref(),
+        ctx.accounts.price_update_aapl.as_deref(),
+    ];
+    let fallback_updates = [
+        ctx.accounts.fallback_feed_usdc.as_deref(),
+        ctx.accounts.fallback_feed_tsla.as_deref(),
+        ctx.accounts.fallback_feed_spy.as_deref(),
+        ctx.accounts.fallback_feed_aapl.as_deref(),
+    ];
+    let mut prices = MOCK_ORACLE_PRICES;
+    let mut confidence = [0u64; 4];
+    if ctx.accounts.pool.oracle_configured {
+        let oracle = ctx
+            .accounts
+            .oracle_config
+            .as_deref()
+            .ok_or(ErrorCode::OracleConfigMissing)?;
+        let feeds = price_updates.into_iter().zip(fallback_updates);
+        for (asset, (price_update, fallback_feed)) in feeds.enumerate() {
+            let price = match read_asset_price(oracle, asset, price_update, fallback_feed, now)? {
+                AssetPrice::Unconfigured => continue,
+                AssetPrice::Price(price) => price,
+                AssetPrice::Diverged {
+                    primary,
+                    fallback,
+                    divergence_bps,
+                } => {
+                    emit!(PriceDivergenceEvent {
+                        batch_id,
+                        asset_id: asset as u8,
+                        primary_price: primary,
+                        fallback_price: fallback,
+                        divergence_bps,
+                        max_divergence_bps: oracle.max_price_divergence_bps,
+                        seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+                    });
+                    msg!(
+                        "Batch halted: asset {} Pyth {} vs Switchboard {} ({} bps apart)",
+                        asset,
+                        primary,
+                        fallback,
+                        divergence_bps
+                    );
+                    return Ok(());
+                }
+            };
+            prices[asset] = price.price;
+            confidence[asset] = price.confidence;
+        }
+    }
+
+    // A batch below the trigger count would reveal totals of too few orders
+    // to hide any one of them. Only an operator may flush it early (e.g. a
+    // quiet market); everyone else waits for the trigger.
+    if ctx.accounts.batch_accumulator.active {
+        let payer = ctx.accounts.payer.key();
+        let is_operator = payer == ctx.accounts.pool.operator
+            || ctx
+                .accounts
+                .roles
+                .as_ref()
+                .is_some_and(|roles| roles.has_role(ROLE_OPERATOR, &payer));
+        require!(
+            ctx.accounts.batch_accumulator.order_count >= ctx.accounts.pool.execution_trigger_count
+                || is_operator,
+            ErrorCode::BatchNotReady
+        );
+
+        // Privacy floor: nobody, not even the operator, reveals totals of
+        // fewer than min_unique_users wallets
+        require!(
+            ctx.accounts.batch_accumulator.min_distinct_wallets()
+                >= ctx.accounts.pool.min_unique_users,
+            ErrorCode::PrivacyFloorNotMet
+        );
+
+        // Rate limit on new batches; a batch that has waited past
+        // max_batch_wait_secs executes regardless
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            ctx.accounts.pool.batch_rate_allows(now),
+            ErrorCode::BatchRateLimited
+        );
+        ctx.accounts.pool.record_batch_queued(now);
+    }
+
+    // Equity pairs outside trading hours settle at a stale close; if that is
+    // every pair the batch waits for the open instead of refunding them all
+    let closed_pairs = if ctx.accounts.pool.market_hours_enforced {
+        let schedule = ctx
+            .accounts
+            .market_schedule
+            .as_ref()
+            .ok_or(ErrorCode::MarketScheduleMissing)?;
+        schedule.closed_pairs(now)
+    } else {
+        0
+    };
+    require!(
+        closed_pairs != (1 << NUM_PAIRS) - 1,
+        ErrorCode::MarketClosed
+    );
+
+    // Hand the active flag to the other slot so new orders land in the next
+    // batch while this one reveals. A slot reopened by recover_batch_reveal
+    // is no longer active and is revealed again without another hand-over.
+    if ctx.accounts.batch_accumulator.active {
+        let next = &mut ctx.accounts.next_accumulator;
+        require!(next.is_idle(), ErrorCode::BatchSlotBusy);
+        next.active = true;
+        next.batch_id = batch_id + 1;
+
+        // The idle slot was revealed last and holds the netting buffers
+        let netting_buffers = next.netting_buffers;
+        ctx.accounts.batch_accumulator.netting_buffers = netting_buffers;
+        ctx.accounts.batch_accumulator.active = false;
+        ctx.accounts.batch_accumulator.reveal_retries = 0;
+    } else {
+        require!(
+            ctx.accounts.batch_accumulator.order_count > 0,
+            ErrorCode::InvalidBatchSlot
+        );
+    }
+
+    // Lock the batch until the reveal callback (or recovery) reopens it
+    let batch = &mut ctx.accounts.batch_accumulator;
+    batch.status = BATCH_STATUS_REVEALING;
+    batch.reveal_queued_at = Clock::get()?.unix_timestamp;
+    batch.reveal_computation_offset = computation_offset;
+    batch.failed_computation_offset = 0;
+
+    // Global risk limits, once configured, cannot be skipped
+    let risk_config = if ctx.accounts.pool.risk_configured {
+        Some(
+            ctx.accounts
+                .risk_config
+                .as_deref()
+                .ok_or(ErrorCode::RiskConfigMissing)?
+                .clone(),
+        )
+    } else {
+        None
+    };
+
+    let batch_log = &mut ctx.accounts.batch_log;
+    batch_log.bump = ctx.bumps.batch_log;
+    batch_log.version = BatchLog::VERSION;
+    batch_log.circuit_version = ctx
+        .accounts
+        .circuit_registry
+        .as_ref()
+        .map_or(0, |registry| {
+            registry.version_of(crate::COMP_DEF_OFFSET_REVEAL_BATCH)
+        });
+    batch_log.batch_id = batch_id;
+    batch_log.executor = ctx.accounts.payer.key();
+    // Snapshot reserve liquidity so the callback can partially fill pairs
+    // whose net surplus exceeds what the reserves can absorb
+    let reserves = [
+        ctx.accounts.reserve_usdc.amount,
+        ctx.accounts.reserve_tsla.amount,
+        ctx.accounts.reserve_spy.amount,
+        ctx.accounts.reserve_aapl.amount,
+    ];
+    batch_log.reserve_snapshot = reserves;
+    batch_log.inventory_headroom = [u64::MAX; 4];
+
+    batch_log.pending_routes = 0;
+    batch_log.route_amounts = [0; 6];
+    batch_log.routed_out = [0; 6];
+    batch_log.route_min_out = [0; 6];
+    batch_log.shortfall_pairs = 0;
+    batch_log.swap_executed = [false; 6];
+
+    // Per-pair overrides of the execution mode (none = follow the pool).
+    // Once the policy exists it cannot be skipped.
+    let execution_policy = ctx.accounts.execution_policy.as_ref();
+    require!(
+        execution_policy.is_some() || !ctx.accounts.pool.execution_policy_configured,
+        ErrorCode::ExecutionPolicyMissing
+    );
+    batch_log.pair_policies = execution_policy
+        .map(|policy| policy.pairs)
+        .unwrap_or_default();
+    batch_log.netting_window_secs = ctx.accounts.pool.netting_window_secs;
+
+    // Large batches need the co-operator's approval before their swaps;
+    // the callback records the notional, approve_batch the approval
+    let pool_threshold = ctx.accounts.pool.approval_threshold_usdc;
+    batch_log.approval_threshold_usdc = risk_config.as_ref().map_or(pool_threshold, |risk| {
+        risk.approval_threshold(pool_threshold)
+    });
+    batch_log.notional_usdc = 0;
+    batch_log.approved_by = Pubkey::default();
+    batch_log.price_band_breaches = 0;
+    batch_log.price_band_overridden_by = Pubkey::default();
+
+    // Reserve-only mode: reserves price the surplus themselves and are
+    // limited per batch (utilization) and in total holdings (inventory)
+    let pool = &ctx.accounts.pool;
+    batch_log.execution_mode = pool.execution_mode;
+    if pool.execution_mode == EXECUTION_MODE_RESERVE_ONLY {
+        batch_log.reserve_spread_bps = pool.reserve_spread_bps;
+        for asset in 0..4 {
+            batch_log.reserve_snapshot[asset] = ((reserves[asset] as u128
+                * pool.max_reserve_utilization_bps as u128)
+                / BPS_DENOMINATOR as u128) as u64;
+            if pool.max_reserve_inventory[asset] > 0 {
+                batch_log.inventory_headroom[asset] =
+                    pool.max_reserve_inventory[asset].saturating_sub(reserves[asset]);
+            }
+        }
+    } else {
+        batch_log.reserve_spread_bps = pool.max_slippage_bps;
+    }
+    // RiskConfig utilization applies in every mode
+    if let Some(risk) = risk_config.as_ref() {
+        for asset in 0..4 {
+            let limit = ((reserves[asset] as u128 * risk.max_reserve_utilization_bps as u128)
+                / BPS_DENOMINATOR as u128) as u64;
+            batch_log.reserve_snapshot[asset] = batch_log.reserve_snapshot[asset].min(limit);
+        }
+    }
+    batch_log.max_slippage_bps = pool.max_slippage_bps;
+
+    // Snapshot frozen assets: the callback refunds pairs that touch them
+    batch_log.frozen_assets = ctx.accounts.pool.frozen_assets;
+
+    // Keeper reward rate, paid to the executor by execute_swaps
+    batch_log.keeper_reward_bps = ctx.accounts.pool.keeper_reward_bps;
+    batch_log.fees_usdc = 0;
+
+    // Fee withheld from the output pools at settlement, and halted pairs
+    // (no registry = every pair enabled at the pool's fee). Pairs touching a
+    // delisted asset are halted too, so no new position opens in it, and so
+    // are equity pairs outside trading hours. Once the registry exists its
+    // halts, fees and price bands cannot be skipped.
+    require!(
+        ctx.accounts.pair_registry.is_some() || !ctx.accounts.pool.pair_registry_configured,
+        ErrorCode::PairRegistryMissing
+    );
+    let pool_fee_bps = ctx.accounts.pool.execution_fee_bps;
+    let delisted_pairs = ctx.accounts.pool.delisted_pairs() | closed_pairs;
+    batch_log.execution_fee_bps = pool_fee_bps;
+    match ctx.accounts.pair_registry.as_ref() {
+        Some(registry) => {
+            batch_log.halted_pairs = registry.halted_pairs() | delisted_pairs;
+            batch_log.pair_fee_bps = registry.fee_bps(pool_fee_bps);
+            batch_log.price_bands = registry.price_bands();
+        }
+        None => {
+            batch_log.halted_pairs = delisted_pairs;
+            batch_log.pair_fee_bps = [pool_fee_bps; 6];
+            batch_log.price_bands = [0; 6];
+        }
+    }
+
+    // Oracle snapshot read above
+    batch_log.oracle_prices = prices;
+    batch_log.oracle_confidence = confidence;
+    batch_log.oracle_slot = Clock::get()?.slot;
+
     // Build MPC arguments: read batch accumulator encrypted state
     // Skip discriminator (8) + batch_id (8) + order_count (1) = 17 bytes
     // Read 12 ciphertexts × 32 bytes = 384 bytes (pairs only)
@@ -58,16 +380,18 @@ pub fn handler(ctx: Context<ExecuteBatch>, computation_offset: u64) -> Result<()
                     pubkey: ctx.accounts.batch_log.key(),
                     is_writable: true,
                 },
-                // TODO: Re-add these accounts after testing callback limit
-                // CallbackAccount {
-                //     pubkey: ctx.accounts.pool.key(),
-                //     is_writable: false,
-                // },
-                // Vault and reserve accounts temporarily removed
+                crate::diagnostics_callback_account(&ctx.accounts.diagnostics),
+                CallbackAccount {
+                    pubkey: ctx.accounts.pool.key(),
+                    is_writable: true,
+                },
+                crate::event_cursor_callback_account(ctx.accounts.pool.pool_id),
+                crate::protocol_stats_callback_account(ctx.accounts.pool.pool_id),
+                // TODO: Re-add vault and reserve accounts after testing callback limit
             ],
         )?],
-        1, // number of callbacks
-        0, // priority
+        1,                                         // number of callbacks
+        ctx.accounts.pool.callback_cu_price_micro, // priority
     )?;
 
     msg!(
@@ -76,5 +400,11 @@ pub fn handler(ctx: Context<ExecuteBatch>, computation_offset: u64) -> Result<()
         computation_offset
     );
 
+    crate::record_profile(
+        ctx.accounts.diagnostics.as_mut(),
+        PROFILE_EXECUTE_BATCH,
+        cu_start,
+    );
+
     Ok(())
 }
@@ -0,0 +1,118 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Burn};
+
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::{BuybackExecutedEvent, ExecuteBuyback};
+
+// =============================================================================
+// EXECUTE BUYBACK - Swap fee USDC into the buyback token and burn it
+// =============================================================================
+// Flow:
+// 1. Enforce schedule (enabled, min interval) and caps (per-execution, fee budget)
+// 2. CPI swap: reserve_usdc → buyback_vault through the configured adapter
+// 3. Burn everything received, signed by the Pool PDA
+
+/// Execute a treasury buyback-and-burn.
+/// Only callable by the pool operator.
+///
+/// # Arguments
+/// * `usdc_amount` - USDC to spend (base units)
+/// * `min_amount_out` - Minimum buyback tokens to receive (slippage protection)
+pub fn handler(ctx: Context<ExecuteBuyback>, usdc_amount: u64, min_amount_out: u64) -> Result<()> {
+    require!(usdc_amount > 0, ErrorCode::InvalidAmount);
+
+    let config = &ctx.accounts.buyback_config;
+    require!(config.enabled, ErrorCode::BuybackDisabled);
+
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        config.last_executed_at == 0 || now >= config.last_executed_at + config.min_interval_secs,
+        ErrorCode::BuybackTooSoon
+    );
+
+    require!(
+        usdc_amount <= config.max_usdc_per_execution
+            && usdc_amount <= config.available_budget(ctx.accounts.pool.total_fees_collected),
+        ErrorCode::BuybackBudgetExceeded
+    );
+
+    let pool_id = ctx.accounts.pool.pool_id;
+    let pool_bump = ctx.accounts.pool.bump;
+    let balance_before = ctx.accounts.buyback_vault.amount;
+
+    // =========================================================================
+    // Step 1: Swap USDC for the buyback token
+    // =========================================================================
+    crate::invoke_swap_adapter(
+        &ctx.accounts.swap_program.to_account_info(),
+        [
+            ctx.accounts.pool.to_account_info(),
+            ctx.accounts.swap_pool.to_account_info(),
+            ctx.accounts.usdc_mint.to_account_info(),
+            ctx.accounts.buyback_mint.to_account_info(),
+            ctx.accounts.reserve_usdc.to_account_info(),
+            ctx.accounts.buyback_vault.to_account_info(),
+            ctx.accounts.swap_source_vault.to_account_info(),
+            ctx.accounts.swap_dest_vault.to_account_info(),
+            ctx.accounts.token_program.to_account_info(),
+        ],
+        usdc_amount,
+        min_amount_out,
+        pool_id,
+        pool_bump,
+    )?;
+
+    ctx.accounts.buyback_vault.reload()?;
+    let bought = ctx
+        .accounts
+        .buyback_vault
+        .amount
+        .saturating_sub(balance_before);
+    require!(bought >= min_amount_out, ErrorCode::MinOutputNotMet);
+
+    // =========================================================================
+    // Step 2: Burn the purchased tokens
+    // =========================================================================
+    let pool_seeds = &[POOL_SEED, &[pool_id], &[pool_bump]];
+    let signer_seeds = &[&pool_seeds[..]];
+
+    let burn_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        Burn {
+            mint: ctx.accounts.buyback_mint.to_account_info(),
+            from: ctx.accounts.buyback_vault.to_account_info(),
+            authority: ctx.accounts.pool.to_account_info(),
+        },
+        signer_seeds,
+    );
+    token::burn(burn_ctx, bought)?;
+
+    // =========================================================================
+    // Step 3: Update counters
+    // =========================================================================
+    let config = &mut ctx.accounts.buyback_config;
+    config.last_executed_at = now;
+    config.total_usdc_spent = config.total_usdc_spent.saturating_add(usdc_amount);
+    config.total_burned = config.total_burned.saturating_add(bought);
+    config.executions += 1;
+
+    emit!(BuybackExecutedEvent {
+        buyback_mint: config.buyback_mint,
+        usdc_spent: usdc_amount,
+        tokens_burned: bought,
+        total_usdc_spent: config.total_usdc_spent,
+        total_burned: config.total_burned,
+        executed_at: now,
+        seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+    });
+
+    msg!(
+        "Buyback #{}: spent {} USDC, burned {} tokens",
+        config.executions,
+        usdc_amount,
+        bought
+    );
+
+    Ok(())
+}
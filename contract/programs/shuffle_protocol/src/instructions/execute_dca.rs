@@ -0,0 +1,166 @@
+use anchor_lang::prelude::*;
+use arcium_anchor::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::OrderTicket;
+use crate::{AccumulateOrderCallback, DcaExecutedEvent, ExecuteDca};
+
+// =============================================================================
+// EXECUTE DCA - Crank a due DCA schedule into the current batch
+// =============================================================================
+// Permissionless. Places the schedule's stored encrypted order exactly like
+// place_order does, reusing the accumulate_order circuit and callback:
+// - Stores the OrderTicket as the user's pending_order
+// - Queues accumulate_order (balance check + batch accumulation in MPC)
+//
+// If the user lacks funds, the callback clears pending_order; the interval is
+// still consumed so a broke schedule cannot be cranked repeatedly.
+//
+// A schedule with a funding_bucket is checked against (and deducted from)
+// that SavingsBucket instead of the trading balance; the bucket is handed to
+// the callback, which stores the new balance there.
+
+/// Execute a due DCA schedule on behalf of its owner.
+///
+/// # Arguments
+/// * `computation_offset` - Unique ID for this MPC computation
+pub fn handler(ctx: Context<ExecuteDca>, computation_offset: u64) -> Result<()> {
+    require!(!ctx.accounts.pool.paused, ErrorCode::ProtocolPaused);
+    let now = Clock::get()?.unix_timestamp;
+    let dca = &mut ctx.accounts.dca_schedule;
+
+    require!(!dca.paused, ErrorCode::DcaPaused);
+    require!(!dca.is_complete(), ErrorCode::DcaCompleted);
+    require!(now >= dca.next_execution_ts, ErrorCode::DcaNotDue);
+    require!(
+        ctx.accounts.batch_accumulator.is_open(),
+        ErrorCode::BatchRevealInProgress
+    );
+    require!(
+        !ctx.accounts.pool.is_asset_frozen(dca.source_asset_id),
+        ErrorCode::AssetFrozen
+    );
+    require!(
+        !ctx.accounts.pool.is_asset_delisted(dca.source_asset_id),
+        ErrorCode::AssetDelisted
+    );
+    let bucket_matches = match (dca.funding_bucket, ctx.accounts.savings_bucket.as_ref()) {
+        (Some(bucket_id), Some(bucket)) => bucket.bucket_id == bucket_id,
+        (None, None) => true,
+        _ => false,
+    };
+    require!(bucket_matches, ErrorCode::InvalidSavingsBucket);
+
+    // Advance schedule; skip missed intervals instead of executing them back-to-back
+    dca.next_execution_ts += dca.interval_secs;
+    if dca.next_execution_ts <= now {
+        dca.next_execution_ts = now + dca.interval_secs;
+    }
+    dca.executions += 1;
+
+    let pubkey = dca.pubkey;
+    let nonce = dca.order_nonce;
+    let source_asset_id = dca.source_asset_id;
+    let encrypted_pair_id = dca.encrypted_pair_id;
+    let encrypted_direction = dca.encrypted_direction;
+    let encrypted_amount = dca.encrypted_amount;
+
+    // Store OrderTicket in user's pending_order (same as place_order)
+    let batch_id = ctx.accounts.batch_accumulator.batch_id;
+    ctx.accounts.user_account.pending_order = Some(OrderTicket {
+        batch_id,
+        pair_id: encrypted_pair_id,
+        direction: encrypted_direction,
+        encrypted_amount,
+        order_nonce: nonce,
+        valid_until_batch: 0,
+    });
+    ctx.accounts.user_account.pending_asset_id = source_asset_id;
+
+    // Set sign PDA bump
+    ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+    let (current_balance, current_nonce) = match ctx.accounts.savings_bucket.as_ref() {
+        Some(bucket) => (bucket.encrypted_balance, bucket.nonce),
+        None => (
+            ctx.accounts.user_account.get_credit(source_asset_id),
+            ctx.accounts.user_account.get_nonce(source_asset_id),
+        ),
+    };
+    ctx.accounts.user_account.pending_order_nonce = current_nonce;
+
+    // Same argument layout as place_order (accumulate_order circuit)
+    let args = ArgBuilder::new()
+        // OrderInput (Enc<Shared>) - encrypted by user at schedule creation
+        .x25519_pubkey(pubkey)
+        .plaintext_u128(nonce)
+        .encrypted_u8(encrypted_pair_id)
+        .encrypted_u8(encrypted_direction)
+        .encrypted_u64(encrypted_amount)
+        // UserBalance (Enc<Shared>) - source asset balance, or the funding bucket
+        .x25519_pubkey(pubkey)
+        .plaintext_u128(current_nonce)
+        .encrypted_u64(current_balance)
+        // BatchState (Enc<Mxe>) - read from batch accumulator account
+        .plaintext_u128(ctx.accounts.batch_accumulator.mxe_nonce)
+        .account(
+            ctx.accounts.batch_accumulator.key(),
+            8 + 8 + 1, // Skip discriminator(8) + batch_id(8) + order_count(1)
+            6 * 64,    // 12 ciphertexts × 32 bytes = 384 bytes (pairs only)
+        )
+        .plaintext_u8(ctx.accounts.batch_accumulator.order_count)
+        // Batch trigger thresholds
+        .plaintext_u8(ctx.accounts.pool.execution_trigger_count)
+        .plaintext_u8(ctx.accounts.pool.min_active_pairs)
+        .build();
+
+    use arcium_client::idl::arcium::types::CallbackAccount;
+    queue_computation(
+        ctx.accounts,
+        computation_offset,
+        args,
+        vec![AccumulateOrderCallback::callback_ix(
+            computation_offset,
+            &ctx.accounts.mxe_account,
+            &[
+                CallbackAccount {
+                    pubkey: ctx.accounts.user_account.key(),
+                    is_writable: true,
+                },
+                CallbackAccount {
+                    pubkey: ctx.accounts.batch_accumulator.key(),
+                    is_writable: true,
+                },
+                crate::optional_callback_account(
+                    ctx.accounts.savings_bucket.as_ref().map(|b| b.key()),
+                    true,
+                ),
+                crate::event_cursor_callback_account(ctx.accounts.pool.pool_id),
+            ],
+        )?],
+        1,                                         // number of callbacks
+        ctx.accounts.pool.callback_cu_price_micro, // priority
+    )?;
+
+    let seq = ctx.accounts.event_cursor.next(Clock::get()?.slot);
+    let dca = &ctx.accounts.dca_schedule;
+    emit!(DcaExecutedEvent {
+        owner: dca.owner,
+        dca_id: dca.dca_id,
+        batch_id,
+        executions: dca.executions,
+        next_execution_ts: dca.next_execution_ts,
+        seq,
+    });
+
+    msg!(
+        "DCA {} executed for {}: batch={}, execution #{}, next at {}",
+        dca.dca_id,
+        dca.owner,
+        batch_id,
+        dca.executions,
+        dca.next_execution_ts
+    );
+
+    Ok(())
+}
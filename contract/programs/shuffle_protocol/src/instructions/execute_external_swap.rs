@@ -0,0 +1,176 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke_signed;
+
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::instructions::route_surplus::{pending_route, record_route_output, vault_address};
+use crate::{ExecuteExternalSwap, SurplusRoutedEvent};
+
+// =============================================================================
+// EXECUTE EXTERNAL SWAP - Fill a pending route through Jupiter v6
+// =============================================================================
+// Production counterpart of route_surplus: instead of the configured swap
+// adapter (mock_jupiter on devnet), the pending surplus of a pair is swapped
+// through a Jupiter v6 route built off-chain by the operator (quote API
+// `route` or `shared_accounts_route` instruction data, with the Pool PDA as
+// user transfer authority). The route's accounts are passed as
+// remaining_accounts in Jupiter's order.
+//
+// The route is only trusted for pricing; the program checks:
+// - it swaps exactly the pending surplus and charges no platform fee
+// - its slippage is within the requested slippage_bps (≤ the batch's
+//   max_slippage_bps)
+// - no pool-owned token account other than the two deposit vaults appears
+//   in it, since the Pool PDA signs for every vault and reserve
+// - the source vault spent at most the surplus and the destination vault
+//   received at least min_amount_out, the quoted output minus slippage and
+//   BatchLog.route_min_out
+//
+// The realized output is recorded exactly as route_surplus does.
+
+/// sha256("global:route")[0..8]
+const JUPITER_ROUTE_DISCRIMINATOR: [u8; 8] = [0xe5, 0x17, 0xcb, 0x97, 0x7a, 0xe3, 0xad, 0x2a];
+
+/// sha256("global:shared_accounts_route")[0..8]
+const JUPITER_SHARED_ROUTE_DISCRIMINATOR: [u8; 8] =
+    [0xc1, 0x20, 0x9b, 0x33, 0x41, 0xd6, 0x9c, 0x81];
+
+/// Trailing route arguments: in_amount (u64), quoted_out_amount (u64),
+/// slippage_bps (u16), platform_fee_bps (u8)
+const ROUTE_TAIL_LEN: usize = 8 + 8 + 2 + 1;
+
+/// Size of an SPL token account
+const TOKEN_ACCOUNT_LEN: usize = 165;
+
+/// Swap one pair's pending surplus through Jupiter v6. Only callable by the operator.
+///
+/// # Arguments
+/// * `batch_id` - Executed batch the surplus belongs to
+/// * `pair_id` - Pair to route (0-5)
+/// * `route_data` - Jupiter route instruction data
+/// * `slippage_bps` - Slippage accepted on the route's quoted output
+/// * `min_amount_out` - Minimum output accepted regardless of the quote
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, ExecuteExternalSwap<'info>>,
+    batch_id: u64,
+    pair_id: u8,
+    route_data: Vec<u8>,
+    slippage_bps: u16,
+    min_amount_out: u64,
+) -> Result<()> {
+    let route = pending_route(&ctx.accounts.batch_log, pair_id)?;
+    require!(
+        slippage_bps <= ctx.accounts.batch_log.max_slippage_bps,
+        ErrorCode::SlippageTooHigh
+    );
+
+    // Swap between the deposit vaults, never the reserves
+    let pool = &ctx.accounts.pool;
+    require!(
+        pool.mint_for_asset(route.source_asset) == Some(ctx.accounts.source_vault.mint)
+            && pool.mint_for_asset(route.output_asset) == Some(ctx.accounts.destination_vault.mint),
+        ErrorCode::InvalidMint
+    );
+    require!(
+        ctx.accounts.source_vault.key() == vault_address(pool.pool_id, route.source_asset)?
+            && ctx.accounts.destination_vault.key()
+                == vault_address(pool.pool_id, route.output_asset)?,
+        ErrorCode::InvalidVault
+    );
+
+    // Route arguments
+    require!(
+        route_data.len() >= 8 + 4 + ROUTE_TAIL_LEN
+            && (route_data[..8] == JUPITER_ROUTE_DISCRIMINATOR
+                || route_data[..8] == JUPITER_SHARED_ROUTE_DISCRIMINATOR),
+        ErrorCode::InvalidSwapRoute
+    );
+    let tail = &route_data[route_data.len() - ROUTE_TAIL_LEN..];
+    let in_amount = u64::from_le_bytes(tail[0..8].try_into().unwrap());
+    let quoted_out = u64::from_le_bytes(tail[8..16].try_into().unwrap());
+    let route_slippage_bps = u16::from_le_bytes(tail[16..18].try_into().unwrap());
+    let platform_fee_bps = tail[18];
+    require!(
+        in_amount == route.amount_in && platform_fee_bps == 0,
+        ErrorCode::InvalidSwapRoute
+    );
+    require!(
+        route_slippage_bps <= slippage_bps,
+        ErrorCode::SlippageTooHigh
+    );
+    let bps = BPS_DENOMINATOR as u128;
+    let quoted_min = (quoted_out as u128 * (bps - slippage_bps as u128) / bps) as u64;
+    let min_out = min_amount_out.max(quoted_min).max(route.min_out);
+
+    // The Pool PDA signs the route: it may not reach any other pool account
+    let pool_key = pool.key();
+    let source_key = ctx.accounts.source_vault.key();
+    let destination_key = ctx.accounts.destination_vault.key();
+    for info in ctx.remaining_accounts.iter() {
+        let foreign_pool_account = info.owner == &anchor_spl::token::ID
+            && info.data_len() == TOKEN_ACCOUNT_LEN
+            && info.try_borrow_data()?[32..64] == pool_key.to_bytes()
+            && info.key() != source_key
+            && info.key() != destination_key;
+        require!(!foreign_pool_account, ErrorCode::InvalidSwapRoute);
+    }
+
+    let metas = ctx
+        .remaining_accounts
+        .iter()
+        .map(|info| AccountMeta {
+            pubkey: info.key(),
+            is_signer: info.is_signer || info.key() == pool_key,
+            is_writable: info.is_writable,
+        })
+        .collect();
+    let ix = Instruction {
+        program_id: JUPITER_PROGRAM_ID,
+        accounts: metas,
+        data: route_data,
+    };
+    let mut infos = ctx.remaining_accounts.to_vec();
+    infos.push(ctx.accounts.jupiter_program.to_account_info());
+
+    let spendable = ctx.accounts.source_vault.amount;
+    let balance_before = ctx.accounts.destination_vault.amount;
+    let pool_seeds = &[POOL_SEED, &[pool.pool_id], &[pool.bump]];
+    invoke_signed(&ix, &infos, &[&pool_seeds[..]])?;
+
+    ctx.accounts.source_vault.reload()?;
+    ctx.accounts.destination_vault.reload()?;
+    let spent = spendable.saturating_sub(ctx.accounts.source_vault.amount);
+    require!(spent <= route.amount_in, ErrorCode::InvalidSwapRoute);
+    let amount_out = ctx
+        .accounts
+        .destination_vault
+        .amount
+        .saturating_sub(balance_before);
+    require!(amount_out >= min_out, ErrorCode::MinOutputNotMet);
+
+    record_route_output(&mut ctx.accounts.batch_log, pair_id, &route, amount_out);
+
+    emit!(SurplusRoutedEvent {
+        batch_id,
+        pair_id,
+        source_asset_id: route.source_asset,
+        amount_in: route.amount_in,
+        amount_out,
+        via_reserves: false,
+        seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+    });
+
+    msg!(
+        "Surplus swapped via Jupiter: batch={}, pair={}, {} of asset {} -> {} of asset {} (min {})",
+        batch_id,
+        pair_id,
+        route.amount_in,
+        route.source_asset,
+        amount_out,
+        route.output_asset,
+        min_out
+    );
+
+    Ok(())
+}
@@ -0,0 +1,138 @@
+use anchor_lang::prelude::*;
+use arcium_anchor::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::{ExecuteRecovery, RecoverAccountCallback};
+
+// =============================================================================
+// EXECUTE RECOVERY - Move a guardian-recovered account to its new owner
+// =============================================================================
+// Flow:
+// 1. Guardians approve a recovery (approve_recovery) and the challenge
+//    period passes without the owner cancelling
+// 2. The new owner creates a privacy account with the approved x25519 key,
+//    then calls execute_recovery
+// 3. Handler snapshots the nonces of both profiles and queues
+//    recover_account, which adds each old balance to the new profile's
+// 4. Callback checks neither profile changed in between, writes the new
+//    balances and closes the old profile and the GuardianSet
+//
+// Orders are encrypted for the old key, so the old account may not have a
+// pending or staged order.
+
+/// Queue the transfer of a recovered account's balances to the new owner.
+///
+/// # Arguments
+/// * `computation_offset` - Unique ID for MPC computation
+pub fn handler(ctx: Context<ExecuteRecovery>, computation_offset: u64) -> Result<()> {
+    require!(!ctx.accounts.pool.paused, ErrorCode::ProtocolPaused);
+    let now = Clock::get()?.unix_timestamp;
+    let set = &ctx.accounts.guardian_set;
+    require!(
+        set.approved_at != 0 && set.approvals.count_ones() as u8 >= set.threshold,
+        ErrorCode::RecoveryNotApproved
+    );
+    require!(
+        now >= set.approved_at.saturating_add(set.challenge_period_secs),
+        ErrorCode::RecoveryChallengeActive
+    );
+    require!(
+        ctx.accounts.new_account.user_pubkey == set.proposed_user_pubkey,
+        ErrorCode::InvalidRecovery
+    );
+    let old = &ctx.accounts.old_account;
+    require!(
+        old.pending_order.is_none() && old.staged_order.is_none(),
+        ErrorCode::PendingOrderExists
+    );
+    require!(
+        !old.rent_sponsored || ctx.accounts.rent_sponsor.is_some(),
+        ErrorCode::RentSponsorMissing
+    );
+
+    let source_nonces: [u128; 4] = std::array::from_fn(|asset| old.get_nonce(asset as u8));
+    let new = &ctx.accounts.new_account;
+    let target_nonces: [u128; 4] = std::array::from_fn(|asset| new.get_nonce(asset as u8));
+    let set = &mut ctx.accounts.guardian_set;
+    set.source_nonces = source_nonces;
+    set.target_nonces = target_nonces;
+
+    ctx.accounts.new_account.last_active_at = now;
+
+    // Set sign PDA bump
+    ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+    let old = &ctx.accounts.old_account;
+    let new = &ctx.accounts.new_account;
+    let args = ArgBuilder::new()
+        // Old profile balances (Enc<Shared, *> - old key)
+        .x25519_pubkey(old.user_pubkey)
+        .plaintext_u128(old.usdc_nonce)
+        .encrypted_u64(old.usdc_credit)
+        .x25519_pubkey(old.user_pubkey)
+        .plaintext_u128(old.tsla_nonce)
+        .encrypted_u64(old.tsla_credit)
+        .x25519_pubkey(old.user_pubkey)
+        .plaintext_u128(old.spy_nonce)
+        .encrypted_u64(old.spy_credit)
+        .x25519_pubkey(old.user_pubkey)
+        .plaintext_u128(old.aapl_nonce)
+        .encrypted_u64(old.aapl_credit)
+        // New profile balances (Enc<Shared, *> - new key)
+        .x25519_pubkey(new.user_pubkey)
+        .plaintext_u128(new.usdc_nonce)
+        .encrypted_u64(new.usdc_credit)
+        .x25519_pubkey(new.user_pubkey)
+        .plaintext_u128(new.tsla_nonce)
+        .encrypted_u64(new.tsla_credit)
+        .x25519_pubkey(new.user_pubkey)
+        .plaintext_u128(new.spy_nonce)
+        .encrypted_u64(new.spy_credit)
+        .x25519_pubkey(new.user_pubkey)
+        .plaintext_u128(new.aapl_nonce)
+        .encrypted_u64(new.aapl_credit)
+        .build();
+
+    use arcium_client::idl::arcium::types::CallbackAccount;
+    queue_computation(
+        ctx.accounts,
+        computation_offset,
+        args,
+        vec![RecoverAccountCallback::callback_ix(
+            computation_offset,
+            &ctx.accounts.mxe_account,
+            &[
+                CallbackAccount {
+                    pubkey: ctx.accounts.guardian_set.key(),
+                    is_writable: true,
+                },
+                CallbackAccount {
+                    pubkey: ctx.accounts.old_account.key(),
+                    is_writable: true,
+                },
+                CallbackAccount {
+                    pubkey: ctx.accounts.new_account.key(),
+                    is_writable: true,
+                },
+                CallbackAccount {
+                    pubkey: ctx.accounts.new_owner.key(),
+                    is_writable: true,
+                },
+                crate::optional_callback_account(
+                    ctx.accounts.rent_sponsor.as_ref().map(|s| s.key()),
+                    true,
+                ),
+                crate::event_cursor_callback_account(ctx.accounts.pool.pool_id),
+            ],
+        )?],
+        1,
+        0,
+    )?;
+
+    msg!(
+        "Recovery of {} to {} queued",
+        ctx.accounts.guardian_set.owner,
+        ctx.accounts.new_owner.key()
+    );
+    Ok(())
+}
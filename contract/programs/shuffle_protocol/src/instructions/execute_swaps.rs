@@ -4,12 +4,12 @@
 //! Reads BatchLog results and executes vault↔reserve token transfers.
 
 use anchor_lang::prelude::*;
-use anchor_spl::token::{Token, TokenAccount};
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
 
 use crate::constants::*;
 use crate::errors::ErrorCode;
-use crate::state::{BatchLog, Pool};
-use crate::ExecuteSwaps;
+use crate::state::{fill_ratio, BatchLog, PairResult, Pool, PAIR_ASSETS, PROFILE_EXECUTE_SWAPS};
+use crate::{ExecuteSwaps, KeeperRewardPaidEvent, ReserveShortfallEvent};
 
 /// Execute vault↔reserve swaps based on BatchLog netting results.
 ///
@@ -23,13 +23,64 @@ use crate::ExecuteSwaps;
 /// - final_pool_a, final_pool_b: The settled amounts after netting
 ///
 /// Transfer logic:
-/// - delta = final_pool - total_in
+/// - delta = final_pool + refund - total_in
+///   (refund is the unfilled part of a partially filled side, which stays
+///   in the vault for settlement to credit back to the sellers)
 /// - If delta > 0: reserve → vault (protocol provides liquidity)
 /// - If delta < 0: vault → reserve (protocol receives surplus)
 ///
+/// The execution fee withheld from each final pool stays in the vault and
+/// is accrued to Pool.fees_owed (and its USDC value to Pool.fees_owed_usdc)
+/// for sweep_fees. Once the last pair completes, the execute_batch caller is
+/// paid its keeper reward out of those fees (see pay_keeper_reward).
+///
+/// Surplus routed externally (external-only mode or a routing pair policy)
+/// already left the vault through route_surplus, and its output already
+/// arrived, so both are netted out of the deltas. Every route must have
+/// completed first.
+///
+/// Reserve pre-check: a reserve that cannot pay its delta in full (it shrank
+/// since execute_batch snapshotted it) pays what it holds, and the pair is
+/// partially filled - its fill ratios and final pool are reduced in the
+/// BatchLog and the pair is flagged in shortfall_pairs. Settlement waits
+/// for this instruction so every order sees the final fills.
+///
+/// Progress is tracked per pair (BatchLog.swap_executed): pairs already
+/// transferred are skipped, so a batch whose transfers fail on one pair can
+/// be completed pair by pair with execute_pair_swaps. swaps_executed is set
+/// once every active pair is done.
+///
 /// # Arguments
 /// * `batch_id` - The batch ID to execute swaps for (for verification)
 pub fn handler(ctx: Context<ExecuteSwaps>, batch_id: u64) -> Result<()> {
+    execute_pairs(ctx, batch_id, u8::MAX)
+}
+
+/// Execute the vault↔reserve transfers of a single pair.
+///
+/// # Arguments
+/// * `batch_id` - The batch ID to execute swaps for (for verification)
+/// * `pair_id` - Pair to transfer (0-5)
+pub fn pair_handler(ctx: Context<ExecuteSwaps>, batch_id: u64, pair_id: u8) -> Result<()> {
+    require!(pair_id <= 5, ErrorCode::InvalidPairId);
+    require!(
+        !ctx.accounts.batch_log.swap_executed[pair_id as usize],
+        ErrorCode::SwapsAlreadyExecuted
+    );
+    execute_pairs(ctx, batch_id, 1 << pair_id)
+}
+
+/// Transfer the pairs in the `pairs` bitmask that are not done yet
+fn execute_pairs(ctx: Context<ExecuteSwaps>, batch_id: u64, pairs: u8) -> Result<()> {
+    let cu_start = crate::profiling_start();
+
+    // Registry-gated mode: any bonded executor instead of the operator
+    crate::require_executor(
+        &ctx.accounts.pool,
+        ctx.accounts.executor_registry.as_deref().map(|r| &**r),
+        &ctx.accounts.operator.key(),
+    )?;
+
     // Verify batch_id matches
     require!(
         ctx.accounts.batch_log.batch_id == batch_id,
@@ -42,41 +93,117 @@ pub fn handler(ctx: Context<ExecuteSwaps>, batch_id: u64) -> Result<()> {
         ErrorCode::SwapsAlreadyExecuted
     );
 
+    // Large batches need the co-operator's approval (approve_batch), and
+    // out-of-band prices the authority's (override_price_band)
+    require!(
+        !ctx.accounts.batch_log.awaiting_approval(),
+        ErrorCode::BatchApprovalRequired
+    );
+    require!(
+        !ctx.accounts.batch_log.awaiting_price_override(),
+        ErrorCode::PriceBandBreached
+    );
+
+    // Routed surplus must be filled before the reserves settle the rest,
+    // and no route may have returned less than the slippage bound
+    let batch_log = &ctx.accounts.batch_log;
+    require!(
+        batch_log.pending_routes == 0,
+        ErrorCode::ExternalRoutePending
+    );
+    require!(
+        batch_log
+            .routed_out
+            .iter()
+            .zip(batch_log.route_min_out.iter())
+            .all(|(out, min_out)| out >= min_out),
+        ErrorCode::MinOutputNotMet
+    );
+
     let pool_bump = ctx.accounts.pool.bump;
-    let pair_results = &ctx.accounts.batch_log.results;
-
-    // Helper: Get asset IDs for a trading pair
-    // Returns (base_asset, quote_asset)
-    fn get_pair_tokens(pair_id: usize) -> (u8, u8) {
-        match pair_id {
-            0 => (1, 0), // TSLA/USDC
-            1 => (2, 0), // SPY/USDC
-            2 => (3, 0), // AAPL/USDC
-            3 => (1, 2), // TSLA/SPY
-            4 => (1, 3), // TSLA/AAPL
-            5 => (2, 3), // SPY/AAPL
-            _ => (0, 0),
-        }
-    }
+    let mut pair_results = batch_log.results;
+    let route_amounts = batch_log.route_amounts;
+    let routed_out = batch_log.routed_out;
+    let mut swap_executed = batch_log.swap_executed;
 
-    // Process each pair using pre-computed results from BatchLog
-    for pair_id in 0..6 {
-        let result = &pair_results[pair_id];
+    // Reserve balances before any transfer, and what this batch moves in
+    // and out of each reserve (ProtocolStats)
+    let reserves_before = [
+        ctx.accounts.reserve_usdc.amount,
+        ctx.accounts.reserve_tsla.amount,
+        ctx.accounts.reserve_spy.amount,
+        ctx.accounts.reserve_aapl.amount,
+    ];
+    let mut reserve_inflow = [0u64; 4];
+    let mut reserve_outflow = [0u64; 4];
+
+    // Execution fees withheld from the final pools, per asset
+    let mut fees = [0u64; 4];
+
+    // Reserve balance left as pairs are processed in order (what earlier
+    // pairs paid into a reserve can fund later pairs), and the pairs a
+    // reserve could not fully pay
+    let mut reserve_available = reserves_before;
+    let mut shortfall_pairs = batch_log.shortfall_pairs;
 
-        // Skip pairs with no activity
+    // Process each pair using pre-computed results from BatchLog
+    for (pair_id, result) in pair_results.iter_mut().enumerate() {
+        // Skip pairs with no activity (nothing to transfer) and pairs that
+        // are done or not requested
         if result.total_a_in == 0 && result.total_b_in == 0 {
+            swap_executed[pair_id] = true;
+            continue;
+        }
+        if swap_executed[pair_id] || pairs & (1 << pair_id) == 0 {
             continue;
         }
 
-        let (base_asset, quote_asset) = get_pair_tokens(pair_id);
+        let (base_asset, quote_asset) = PAIR_ASSETS[pair_id];
+        let (route_in, route_out) = (route_amounts[pair_id], routed_out[pair_id]);
+        let (mut delta_a, mut delta_b) = vault_deltas(result, route_in, route_out);
 
-        // Calculate deltas: what needs to move between vault and reserve
-        // delta = final_pool - total_in
-        // Positive delta = reserve provides to vault
-        // Negative delta = vault provides to reserve
+        // Pre-flight: the reserves were snapshotted at execute_batch and may
+        // have shrunk since (liquidity removed, another batch's swaps). A
+        // reserve that cannot pay its side pays what it holds and the pair
+        // is partially filled: the surplus it no longer absorbs is refunded
+        let available_a = reserve_available[base_asset as usize] as i128;
+        let available_b = reserve_available[quote_asset as usize] as i128;
+        let shortfall = if delta_a > available_a {
+            Some((true, delta_a, available_a, -delta_b))
+        } else if delta_b > available_b {
+            Some((false, delta_b, available_b, -delta_a))
+        } else {
+            None
+        };
+        if let Some((pays_a, required, available, absorbed)) = shortfall {
+            partially_fill(
+                result,
+                pays_a,
+                required as u64,
+                available as u64,
+                absorbed.max(0) as u64,
+            );
+            (delta_a, delta_b) = vault_deltas(result, route_in, route_out);
+            shortfall_pairs |= 1 << pair_id;
+            emit!(ReserveShortfallEvent {
+                batch_id,
+                pair_id: pair_id as u8,
+                asset_id: if pays_a { base_asset } else { quote_asset },
+                required: required as u64,
+                delivered: available as u64,
+                fill_ratio_a: result.fill_ratio_a,
+                fill_ratio_b: result.fill_ratio_b,
+                seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+            });
+        }
+        for (asset, delta) in [(base_asset, delta_a), (quote_asset, delta_b)] {
+            let available = &mut reserve_available[asset as usize];
+            *available = (*available as i128 - delta).max(0) as u64;
+        }
 
-        let delta_a = result.final_pool_a as i128 - result.total_a_in as i128;
-        let delta_b = result.final_pool_b as i128 - result.total_b_in as i128;
+        let batch_log = &ctx.accounts.batch_log;
+        fees[base_asset as usize] += batch_log.execution_fee(pair_id, result.final_pool_a);
+        fees[quote_asset as usize] += batch_log.execution_fee(pair_id, result.final_pool_b);
 
         msg!(
             "ExecuteSwaps: Pair {} - total_a_in={}, final_pool_a={}, delta_a={}",
@@ -104,6 +231,7 @@ pub fn handler(ctx: Context<ExecuteSwaps>, batch_id: u64) -> Result<()> {
                 base_asset
             );
             execute_reserve_to_vault_by_asset(&ctx, base_asset, amount, pool_bump)?;
+            reserve_outflow[base_asset as usize] += amount;
         } else if delta_a < 0 {
             // Protocol receives: vault → reserve
             let amount = (-delta_a) as u64;
@@ -114,6 +242,7 @@ pub fn handler(ctx: Context<ExecuteSwaps>, batch_id: u64) -> Result<()> {
                 base_asset
             );
             execute_vault_to_reserve_by_asset(&ctx, base_asset, amount, pool_bump)?;
+            reserve_inflow[base_asset as usize] += amount;
         }
 
         // Execute transfer for quote asset (B)
@@ -127,6 +256,7 @@ pub fn handler(ctx: Context<ExecuteSwaps>, batch_id: u64) -> Result<()> {
                 quote_asset
             );
             execute_reserve_to_vault_by_asset(&ctx, quote_asset, amount, pool_bump)?;
+            reserve_outflow[quote_asset as usize] += amount;
         } else if delta_b < 0 {
             // Protocol receives: vault → reserve
             let amount = (-delta_b) as u64;
@@ -137,20 +267,204 @@ pub fn handler(ctx: Context<ExecuteSwaps>, batch_id: u64) -> Result<()> {
                 quote_asset
             );
             execute_vault_to_reserve_by_asset(&ctx, quote_asset, amount, pool_bump)?;
+            reserve_inflow[quote_asset as usize] += amount;
         }
+
+        swap_executed[pair_id] = true;
+    }
+
+    // Record progress; once every pair is done settlement can read the
+    // (possibly reduced) fills
+    let completed = swap_executed.iter().all(|&done| done);
+    let batch_log = &mut ctx.accounts.batch_log;
+    batch_log.results = pair_results;
+    batch_log.shortfall_pairs = shortfall_pairs;
+    batch_log.swap_executed = swap_executed;
+    batch_log.swaps_executed = completed;
+
+    // Accrue the execution fees, valued in USDC at the batch's oracle prices.
+    // unit_prices scales each per-whole-token price by 10^(MAX_ASSET_DECIMALS
+    // - decimals), the same normalization the reveal callback nets with, so
+    // fee * unit_price[asset] / unit_price[USDC] is the fee in USDC base units
+    let pool = &mut ctx.accounts.pool;
+    let unit_prices = pool.unit_prices(&ctx.accounts.batch_log.oracle_prices);
+    let mut fees_usdc = 0u128;
+    for (asset, &fee) in fees.iter().enumerate() {
+        pool.fees_owed[asset] = pool.fees_owed[asset]
+            .checked_add(fee)
+            .ok_or(ErrorCode::MathOverflow)?;
+        let value = (fee as u128)
+            .checked_mul(unit_prices[asset])
+            .map(|value| value / unit_prices[0].max(1))
+            .ok_or(ErrorCode::MathOverflow)?;
+        fees_usdc = fees_usdc
+            .checked_add(value)
+            .ok_or(ErrorCode::MathOverflow)?;
+    }
+    let fees_usdc = u64::try_from(fees_usdc).map_err(|_| ErrorCode::MathOverflow)?;
+    pool.fees_owed_usdc = pool
+        .fees_owed_usdc
+        .checked_add(fees_usdc)
+        .ok_or(ErrorCode::MathOverflow)?;
+    let batch_log = &mut ctx.accounts.batch_log;
+    batch_log.fees_usdc = batch_log
+        .fees_usdc
+        .checked_add(fees_usdc)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    if completed {
+        pay_keeper_reward(ctx.accounts, batch_id)?;
+    }
+
+    // Analytics: reserve flows, utilization and time since the reveal
+    let stats = &mut ctx.accounts.protocol_stats;
+    for (asset, &before) in reserves_before.iter().enumerate() {
+        stats.reserve_inflow[asset] =
+            stats.reserve_inflow[asset].saturating_add(reserve_inflow[asset]);
+        stats.reserve_outflow[asset] =
+            stats.reserve_outflow[asset].saturating_add(reserve_outflow[asset]);
+        if before > 0 {
+            let utilization_bps = (reserve_outflow[asset] as u128 * BPS_DENOMINATOR as u128
+                / before as u128)
+                .min(BPS_DENOMINATOR as u128) as u16;
+            stats.peak_reserve_utilization_bps[asset] =
+                stats.peak_reserve_utilization_bps[asset].max(utilization_bps);
+        }
+    }
+    if !completed {
+        // The batch counts as swapped once, when its last pair is done
+        stats.fees_collected_usdc = stats.fees_collected_usdc.saturating_add(fees_usdc);
+        msg!(
+            "Swaps executed for batch {}: pairs pending {:?}",
+            batch_id,
+            swap_executed
+        );
+    } else {
+        let swap_secs = Clock::get()?.unix_timestamp - ctx.accounts.batch_log.executed_at;
+        stats.record_swaps(swap_secs.max(0) as u64, fees_usdc);
+        msg!(
+            "Swaps executed for batch {}: vault↔reserve transfers complete",
+            batch_id
+        );
+    }
+
+    crate::record_profile(
+        ctx.accounts.diagnostics.as_mut(),
+        PROFILE_EXECUTE_SWAPS,
+        cu_start,
+    );
+
+    Ok(())
+}
+
+/// Pay the execute_batch caller its keeper reward once the batch's swaps are
+/// done: BatchLog.keeper_reward_bps of the batch notional, capped at the fees
+/// the batch accrued and at the USDC fees owed, moved from the USDC vault and
+/// deducted from Pool.fees_owed.
+///
+/// Paid here rather than in reveal_batch_callback: the reward is capped at
+/// the batch's fees, which are only accrued as each pair's swaps complete
+/// here, and callback accounts are fixed when execute_batch queues the
+/// reveal, before the keeper's token account needs to exist.
+///
+/// Without the keeper's USDC account the reward is skipped and stays in
+/// Pool.fees_owed, so a missing account cannot hold up settlement.
+fn pay_keeper_reward(accounts: &mut ExecuteSwaps, batch_id: u64) -> Result<()> {
+    let batch_log = &accounts.batch_log;
+    let target = (batch_log.notional_usdc as u128 * batch_log.keeper_reward_bps as u128
+        / BPS_DENOMINATOR as u128) as u64;
+    let reward = target
+        .min(batch_log.fees_usdc)
+        .min(accounts.pool.fees_owed[0]);
+    if reward == 0 {
+        return Ok(());
     }
 
-    // Mark swaps as executed
-    ctx.accounts.batch_log.swaps_executed = true;
+    let Some(keeper_usdc) = accounts.keeper_usdc.as_deref() else {
+        msg!(
+            "Keeper reward of {} USDC base units for batch {} skipped: no keeper account",
+            reward,
+            batch_id
+        );
+        return Ok(());
+    };
+    let pool_seeds = &[POOL_SEED, &[accounts.pool.pool_id], &[accounts.pool.bump]];
+    let signer_seeds = &[&pool_seeds[..]];
+    let transfer_ctx = CpiContext::new_with_signer(
+        accounts.token_program.to_account_info(),
+        Transfer {
+            from: accounts.vault_usdc.to_account_info(),
+            to: keeper_usdc.to_account_info(),
+            authority: accounts.pool.to_account_info(),
+        },
+        signer_seeds,
+    );
+    token::transfer(transfer_ctx, reward)?;
+
+    let pool = &mut accounts.pool;
+    pool.fees_owed[0] -= reward;
+    pool.fees_owed_usdc = pool.fees_owed_usdc.saturating_sub(reward);
 
+    emit!(KeeperRewardPaidEvent {
+        batch_id,
+        executor: accounts.batch_log.executor,
+        amount: reward,
+        seq: accounts.event_cursor.next(Clock::get()?.slot),
+    });
     msg!(
-        "Swaps executed for batch {}: vault↔reserve transfers complete",
+        "Keeper reward of {} USDC base units paid for batch {}",
+        reward,
         batch_id
     );
-
     Ok(())
 }
 
+/// Vault deltas of a pair as (delta_a, delta_b): positive = reserve → vault,
+/// negative = vault → reserve.
+///
+/// delta = final_pool + refund - total_in, net of what route_surplus already
+/// moved: the routed surplus left the surplus side's vault and its output
+/// arrived on the other side.
+fn vault_deltas(result: &PairResult, route_in: u64, route_out: u64) -> (i128, i128) {
+    let mut delta_a =
+        result.final_pool_a as i128 + result.refund_a() as i128 - result.total_a_in as i128;
+    let mut delta_b =
+        result.final_pool_b as i128 + result.refund_b() as i128 - result.total_b_in as i128;
+    if result.final_pool_a < result.total_a_in {
+        delta_a += route_in as i128;
+        delta_b -= route_out as i128;
+    } else {
+        delta_b += route_in as i128;
+        delta_a -= route_out as i128;
+    }
+    (delta_a, delta_b)
+}
+
+/// Scale a pair down to what a short reserve can pay.
+///
+/// The reserve paying side A (`pays_a`) or B delivers `available` instead of
+/// `required`; the other side's surplus it absorbs shrinks in proportion and
+/// the difference is refunded to that side's sellers.
+fn partially_fill(
+    result: &mut PairResult,
+    pays_a: bool,
+    required: u64,
+    available: u64,
+    absorbed: u64,
+) {
+    let still_absorbed = (absorbed as u128 * available as u128 / required as u128) as u64;
+    let unabsorbed = absorbed - still_absorbed;
+    if pays_a {
+        result.final_pool_a -= required - available;
+        let unfilled = result.refund_b() + unabsorbed;
+        result.fill_ratio_b = fill_ratio(result.total_b_in, unfilled.min(result.total_b_in));
+    } else {
+        result.final_pool_b -= required - available;
+        let unfilled = result.refund_a() + unabsorbed;
+        result.fill_ratio_a = fill_ratio(result.total_a_in, unfilled.min(result.total_a_in));
+    }
+}
+
 /// Helper: Execute vault → reserve transfer based on asset ID
 fn execute_vault_to_reserve_by_asset(
     ctx: &Context<ExecuteSwaps>,
@@ -165,6 +479,7 @@ fn execute_vault_to_reserve_by_asset(
             &ctx.accounts.pool.to_account_info(),
             &ctx.accounts.token_program,
             amount,
+            ctx.accounts.pool.pool_id,
             pool_bump,
         ),
         1 => crate::execute_vault_to_reserve_transfer(
@@ -173,6 +488,7 @@ fn execute_vault_to_reserve_by_asset(
             &ctx.accounts.pool.to_account_info(),
             &ctx.accounts.token_program,
             amount,
+            ctx.accounts.pool.pool_id,
             pool_bump,
         ),
         2 => crate::execute_vault_to_reserve_transfer(
@@ -181,6 +497,7 @@ fn execute_vault_to_reserve_by_asset(
             &ctx.accounts.pool.to_account_info(),
             &ctx.accounts.token_program,
             amount,
+            ctx.accounts.pool.pool_id,
             pool_bump,
         ),
         3 => crate::execute_vault_to_reserve_transfer(
@@ -189,6 +506,7 @@ fn execute_vault_to_reserve_by_asset(
             &ctx.accounts.pool.to_account_info(),
             &ctx.accounts.token_program,
             amount,
+            ctx.accounts.pool.pool_id,
             pool_bump,
         ),
         _ => Ok(()),
@@ -209,6 +527,7 @@ fn execute_reserve_to_vault_by_asset(
             &ctx.accounts.pool.to_account_info(),
             &ctx.accounts.token_program,
             amount,
+            ctx.accounts.pool.pool_id,
             pool_bump,
         ),
         1 => crate::execute_reserve_to_vault_transfer(
@@ -217,6 +536,7 @@ fn execute_reserve_to_vault_by_asset(
             &ctx.accounts.pool.to_account_info(),
             &ctx.accounts.token_program,
             amount,
+            ctx.accounts.pool.pool_id,
             pool_bump,
         ),
         2 => crate::execute_reserve_to_vault_transfer(
@@ -225,6 +545,7 @@ fn execute_reserve_to_vault_by_asset(
             &ctx.accounts.pool.to_account_info(),
             &ctx.accounts.token_program,
             amount,
+            ctx.accounts.pool.pool_id,
             pool_bump,
         ),
         3 => crate::execute_reserve_to_vault_transfer(
@@ -233,6 +554,7 @@ fn execute_reserve_to_vault_by_asset(
             &ctx.accounts.pool.to_account_info(),
             &ctx.accounts.token_program,
             amount,
+            ctx.accounts.pool.pool_id,
             pool_bump,
         ),
         _ => Ok(()),
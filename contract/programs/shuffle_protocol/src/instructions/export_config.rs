@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{ProtocolConfig, CONFIG_VERSION};
+use crate::{ConfigExportedEvent, ExportConfig};
+
+// =============================================================================
+// EXPORT CONFIG - Snapshot the tunable parameters
+// =============================================================================
+// Writes the current ProtocolConfig to the ConfigSnapshot PDA (created on
+// first call, overwritten afterwards). Clients read the snapshot and pass
+// its config to apply_config on another cluster, or later on this one.
+
+/// Snapshot the tunable protocol parameters. Only callable by pool authority.
+pub fn handler(ctx: Context<ExportConfig>) -> Result<()> {
+    let config = ProtocolConfig::capture(
+        &ctx.accounts.pool,
+        ctx.accounts.executor_registry.as_deref().map(|r| &**r),
+    );
+    let config_hash = config.hash();
+    let now = Clock::get()?.unix_timestamp;
+
+    let snapshot = &mut ctx.accounts.config_snapshot;
+    snapshot.bump = ctx.bumps.config_snapshot;
+    snapshot.version = CONFIG_VERSION;
+    snapshot.sequence += 1;
+    snapshot.exported_at = now;
+    snapshot.exported_by = ctx.accounts.authority.key();
+    snapshot.config_hash = config_hash;
+    snapshot.config = config;
+
+    emit!(ConfigExportedEvent {
+        version: CONFIG_VERSION,
+        export_sequence: snapshot.sequence,
+        config_hash,
+        exported_at: now,
+        seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+    });
+
+    msg!(
+        "Config exported: version {}, sequence {}",
+        CONFIG_VERSION,
+        snapshot.sequence
+    );
+    Ok(())
+}
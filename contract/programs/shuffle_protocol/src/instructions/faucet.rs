@@ -1,9 +1,7 @@
-use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Transfer};
-
 use crate::constants::*;
 use crate::errors::ErrorCode;
-use crate::Faucet;
+use crate::{transfer_checked_with_hook, CheckedTransferAccounts, Faucet};
+use anchor_lang::prelude::*;
 
 /// Claim USDC from the devnet faucet.
 /// Each user can claim up to FAUCET_MAX_PER_USER (1000 USDC) total.
@@ -28,19 +26,26 @@ pub fn handler(ctx: Context<Faucet>, amount: u64) -> Result<()> {
     );
 
     // Transfer USDC from faucet vault to user's token account
-    let pool_seeds = &[POOL_SEED, &[ctx.accounts.pool.bump]];
+    let pool_seeds = &[
+        POOL_SEED,
+        &[ctx.accounts.pool.pool_id],
+        &[ctx.accounts.pool.bump],
+    ];
     let signer_seeds = &[&pool_seeds[..]];
 
-    let transfer_ctx = CpiContext::new_with_signer(
-        ctx.accounts.token_program.to_account_info(),
-        Transfer {
-            from: ctx.accounts.faucet_vault.to_account_info(),
-            to: ctx.accounts.user_usdc_account.to_account_info(),
-            authority: ctx.accounts.pool.to_account_info(),
+    transfer_checked_with_hook(
+        CheckedTransferAccounts {
+            token_program: &ctx.accounts.token_program.to_account_info(),
+            from: &ctx.accounts.faucet_vault.to_account_info(),
+            mint: &ctx.accounts.usdc_mint.to_account_info(),
+            to: &ctx.accounts.user_usdc_account.to_account_info(),
+            authority: &ctx.accounts.pool.to_account_info(),
+            hook_accounts: &[],
         },
+        amount,
+        ctx.accounts.usdc_mint.decimals,
         signer_seeds,
-    );
-    token::transfer(transfer_ctx, amount)?;
+    )?;
 
     // Update user's total claimed
     user.total_faucet_claimed = new_total;
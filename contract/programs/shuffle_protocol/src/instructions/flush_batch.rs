@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::instructions::reset_batch::discard_batch;
+use crate::state::{BATCH_RESET_REASON_EMERGENCY_FLUSH, BATCH_STATUS_OPEN};
+use crate::ResetBatch;
+
+// =============================================================================
+// FLUSH BATCH - Emergency refund of the open batch
+// =============================================================================
+// Incident response: the authority drops every order accumulated in the
+// active slot without executing any swap, e.g. when an oracle or reserve is
+// compromised and the batch must not run. This is reset_batch on a healthy
+// slot, with the same accounts:
+// 1. The batch's BatchLog is written with empty results, so each order
+//    settles through settle_order, where the calculate_payout circuit credits
+//    its full encrypted amount back to the owner's balance
+// 2. The slot reopens under a new batch_id and init_batch_state re-encrypts
+//    the accumulator
+//
+// The accumulator totals are never revealed: refunds are computed per order
+// from the order ciphertexts, so revealing a discarded batch would only leak
+// its aggregate flow. A batch whose reveal is in flight is handled by
+// recover_batch_reveal / reset_batch instead.
+
+/// Flush the active batch, refunding every order. Only callable by pool authority.
+///
+/// # Arguments
+/// * `computation_offset` - Unique ID for the init_batch_state computation
+pub fn handler(ctx: Context<ResetBatch>, computation_offset: u64) -> Result<()> {
+    let batch = &ctx.accounts.batch_accumulator;
+    require!(batch.active, ErrorCode::InvalidBatchSlot);
+    require!(
+        batch.status == BATCH_STATUS_OPEN,
+        ErrorCode::BatchRevealInProgress
+    );
+
+    discard_batch(ctx, computation_offset, BATCH_RESET_REASON_EMERGENCY_FLUSH)
+}
@@ -0,0 +1,116 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::instructions::route_surplus::reserve_address;
+use crate::state::{NettingBuffer, PAIR_ASSETS};
+use crate::{FlushNettingBuffer, NettingBufferFlushedEvent};
+
+// =============================================================================
+// FLUSH NETTING BUFFER - Sell unnetted surplus once its window elapses
+// =============================================================================
+// With a netting window (Pool.netting_window_secs), the reveal callback lets
+// the reserves absorb a surplus that would otherwise be routed externally
+// and records it in the pair's netting buffer. Opposite surpluses in later
+// batches are paid out of that inventory. Once the window has elapsed
+// without the buffer being netted to zero, the operator sells what is left
+// between the reserves through the swap adapter and the buffer is cleared.
+//
+// An expired buffer is ignored by later batches (their surplus routes as
+// usual) until it is flushed.
+
+/// Sell a pair's expired netting buffer on the external venue. Only callable by the operator.
+///
+/// # Arguments
+/// * `pair_id` - Pair whose buffer is flushed (0-5)
+/// * `min_amount_out` - Minimum output accepted from the venue (slippage protection)
+pub fn handler(ctx: Context<FlushNettingBuffer>, pair_id: u8, min_amount_out: u64) -> Result<()> {
+    require!(pair_id <= 5, ErrorCode::InvalidPairId);
+    // Only the idle slot (last revealed) holds the current buffers
+    require!(
+        !ctx.accounts.batch_accumulator.active,
+        ErrorCode::InvalidBatchSlot
+    );
+    require!(
+        ctx.accounts.batch_accumulator.is_open(),
+        ErrorCode::BatchRevealInProgress
+    );
+
+    let buffer = ctx.accounts.batch_accumulator.netting_buffers[pair_id as usize];
+    require!(buffer.amount > 0, ErrorCode::NettingBufferEmpty);
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        !buffer.is_live(ctx.accounts.pool.netting_window_secs, now),
+        ErrorCode::NettingWindowOpen
+    );
+
+    // The buffer holds one side of the pair; it is sold for the other
+    let (base_asset, quote_asset) = PAIR_ASSETS[pair_id as usize];
+    let source_asset = buffer.asset_id;
+    let output_asset = if source_asset == base_asset {
+        quote_asset
+    } else {
+        base_asset
+    };
+
+    let pool = &ctx.accounts.pool;
+    require!(
+        pool.mint_for_asset(source_asset) == Some(ctx.accounts.source_mint.key())
+            && pool.mint_for_asset(output_asset) == Some(ctx.accounts.destination_mint.key()),
+        ErrorCode::InvalidMint
+    );
+    require!(
+        ctx.accounts.source_reserve.key() == reserve_address(pool.pool_id, source_asset)?
+            && ctx.accounts.destination_reserve.key()
+                == reserve_address(pool.pool_id, output_asset)?,
+        ErrorCode::InvalidReserve
+    );
+
+    let balance_before = ctx.accounts.destination_reserve.amount;
+    crate::invoke_swap_adapter(
+        &ctx.accounts.swap_program.to_account_info(),
+        [
+            ctx.accounts.pool.to_account_info(),
+            ctx.accounts.swap_pool.to_account_info(),
+            ctx.accounts.source_mint.to_account_info(),
+            ctx.accounts.destination_mint.to_account_info(),
+            ctx.accounts.source_reserve.to_account_info(),
+            ctx.accounts.destination_reserve.to_account_info(),
+            ctx.accounts.swap_source_vault.to_account_info(),
+            ctx.accounts.swap_dest_vault.to_account_info(),
+            ctx.accounts.token_program.to_account_info(),
+        ],
+        buffer.amount,
+        min_amount_out,
+        ctx.accounts.pool.pool_id,
+        ctx.accounts.pool.bump,
+    )?;
+
+    ctx.accounts.destination_reserve.reload()?;
+    let amount_out = ctx
+        .accounts
+        .destination_reserve
+        .amount
+        .saturating_sub(balance_before);
+    require!(amount_out >= min_amount_out, ErrorCode::MinOutputNotMet);
+
+    ctx.accounts.batch_accumulator.netting_buffers[pair_id as usize] = NettingBuffer::default();
+
+    emit!(NettingBufferFlushedEvent {
+        pair_id,
+        asset_id: source_asset,
+        amount_in: buffer.amount,
+        amount_out,
+        seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+    });
+
+    msg!(
+        "Netting buffer flushed: pair={}, {} of asset {} -> {} of asset {}",
+        pair_id,
+        buffer.amount,
+        source_asset,
+        amount_out,
+        output_asset
+    );
+
+    Ok(())
+}
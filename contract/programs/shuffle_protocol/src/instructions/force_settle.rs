@@ -0,0 +1,220 @@
+use anchor_lang::prelude::*;
+use arcium_anchor::prelude::*;
+
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::instructions::settle_order::settlement_route;
+use crate::state::{BatchLog, PAIR_ASSETS};
+use crate::{CalculatePayoutCallback, ForceSettle};
+
+// =============================================================================
+// FORCE SETTLE - Crank Stale Orders
+// =============================================================================
+// Users who never settle keep their BatchLog alive forever. After a grace
+// period (Pool.force_settle_grace_secs, see set_force_settle_grace) anyone
+// may settle on their behalf through the same calculate_payout circuit and
+// callback as settle_order.
+//
+// The cranker cannot decrypt the order, so it supplies the route
+// (pair_id, direction). The source asset is public from the place_order
+// arguments, leaving at most three candidate routes; a wrong guess is
+// rejected by MPC without touching the user's balances.
+//
+// Bounty: FORCE_SETTLE_BOUNTY_SHARE_BPS of the execution fee withheld on
+// the payout (the pair's BatchLog.pair_fee_bps). The user is credited the
+// whole payout; the callback pays the bounty from the output asset vault
+// out of Pool.fees_owed, so it comes out of the fee and not the user. It is
+// capped at the fees still owed, so nothing is paid after a sweep_fees.
+
+/// Force-settle a user's pending order after the grace period.
+///
+/// # Arguments
+/// * `computation_offset` - Unique ID for MPC computation
+/// * `pair_id` - Trading pair of the order (0-5)
+/// * `direction` - Order direction (0=A_to_B, 1=B_to_A)
+pub fn handler(
+    ctx: Context<ForceSettle>,
+    computation_offset: u64,
+    pair_id: u8,
+    direction: u8,
+) -> Result<()> {
+    // Batch must have executed, and the owner had the grace period to settle
+    let executed_at = ctx.accounts.batch_log.executed_at;
+    require!(executed_at > 0, ErrorCode::BatchNotFinalized);
+    require!(
+        Clock::get()?.unix_timestamp >= executed_at + ctx.accounts.pool.force_settle_grace_secs,
+        ErrorCode::SettlementGracePeriodActive
+    );
+
+    // Nothing is withheld from the user; the bounty comes out of the fee
+    queue_stale_settlement(ctx, computation_offset, pair_id, direction, 0)
+}
+
+/// force_settle bounty on a revealed `payout` of `output_asset`: its share
+/// of the execution fee the pair from `source_asset` paid in the batch.
+/// The callback caps it at Pool.fees_owed.
+pub(crate) fn fee_bounty(
+    batch_log: &BatchLog,
+    source_asset: u8,
+    output_asset: u8,
+    payout: u64,
+) -> u64 {
+    let fee_bps = PAIR_ASSETS
+        .iter()
+        .position(|&(base, quote)| {
+            (base, quote) == (source_asset, output_asset)
+                || (quote, base) == (source_asset, output_asset)
+        })
+        .map_or(0, |pair_id| batch_log.pair_fee_bps[pair_id]);
+    (payout as u128 * fee_bps as u128 * FORCE_SETTLE_BOUNTY_SHARE_BPS as u128
+        / (BPS_DENOMINATOR as u128 * BPS_DENOMINATOR as u128)) as u64
+}
+
+/// Queue calculate_payout for another user's order, withholding
+/// `withheld_bps` of the payout for `bounty_token_account` (forfeit_order
+/// withholds all of it, force_settle none). Shared with forfeit_order.
+pub(crate) fn queue_stale_settlement(
+    ctx: Context<ForceSettle>,
+    computation_offset: u64,
+    pair_id: u8,
+    direction: u8,
+    withheld_bps: u64,
+) -> Result<()> {
+    let pending = ctx
+        .accounts
+        .user_account
+        .pending_order
+        .ok_or(ErrorCode::NoPendingOrder)?;
+
+    let route = settlement_route(&ctx.accounts.batch_log, pair_id, direction)?;
+
+    // Bounty or forfeiture leaves the vault, so it is blocked like a withdrawal
+    require!(
+        !ctx.accounts.pool.is_asset_frozen(route.output_asset_id),
+        ErrorCode::AssetFrozen
+    );
+
+    // The payout source must be the deposit vault PDA, not just any
+    // Pool-owned account (reserves share the same mint/authority)
+    let vault_seed = match route.output_asset_id {
+        0 => VAULT_USDC_SEED,
+        1 => VAULT_TSLA_SEED,
+        2 => VAULT_SPY_SEED,
+        3 => VAULT_AAPL_SEED,
+        _ => return Err(ErrorCode::InvalidAssetId.into()),
+    };
+    let (expected_vault, _) = Pubkey::find_program_address(
+        &[VAULT_SEED, &[ctx.accounts.pool.pool_id], vault_seed],
+        &crate::ID,
+    );
+    require!(
+        ctx.accounts.payout_vault.key() == expected_vault,
+        ErrorCode::InvalidVault
+    );
+
+    // Store output and refund asset IDs for callback
+    ctx.accounts.user_account.pending_asset_id = route.output_asset_id;
+    ctx.accounts.user_account.pending_refund_asset_id = route.source_asset_id;
+
+    // Stale settlements always refund; they never roll the order over
+    // or convert the payout
+    ctx.accounts.user_account.pending_rollover = false;
+    ctx.accounts.user_account.pending_conversion = false;
+
+    // Bind a freshly created order history to its owner
+    let owner = ctx.accounts.user_account.owner;
+    let order_history = &mut ctx.accounts.order_history;
+    if order_history.owner == Pubkey::default() {
+        order_history.owner = owner;
+        order_history.bump = ctx.bumps.order_history;
+    }
+
+    // Set sign PDA bump
+    ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+    // Same inputs as settle_order, with the owner's stored x25519 key
+    let pubkey = ctx.accounts.user_account.user_pubkey;
+    let source_balance = ctx.accounts.user_account.get_credit(route.source_asset_id);
+    let source_nonce = ctx.accounts.user_account.get_nonce(route.source_asset_id);
+    let output_balance = ctx.accounts.user_account.get_credit(route.output_asset_id);
+    let output_nonce = ctx.accounts.user_account.get_nonce(route.output_asset_id);
+    ctx.accounts.user_account.pending_payout_nonce = output_nonce;
+    ctx.accounts.user_account.pending_refund_nonce = source_nonce;
+
+    let args = ArgBuilder::new()
+        // OrderInput (Enc<Shared, OrderInput>) - all 3 fields from pending_order
+        .x25519_pubkey(pubkey)
+        .plaintext_u128(pending.order_nonce)
+        .encrypted_u8(pending.pair_id)
+        .encrypted_u8(pending.direction)
+        .encrypted_u64(pending.encrypted_amount)
+        // Source asset balance (Enc<Shared, UserBalance>) - credited with refund
+        .x25519_pubkey(pubkey)
+        .plaintext_u128(source_nonce)
+        .encrypted_u64(source_balance)
+        // Output asset balance (Enc<Shared, UserBalance>) - credited with payout
+        .x25519_pubkey(pubkey)
+        .plaintext_u128(output_nonce)
+        .encrypted_u64(output_balance)
+        // Plaintext batch results
+        .plaintext_u64(route.total_input)
+        .plaintext_u64(route.final_pool_output)
+        .plaintext_u64(route.fill_ratio as u64)
+        // Claimed route and the share withheld from the user
+        .plaintext_u8(pair_id)
+        .plaintext_u8(direction)
+        .plaintext_u64(withheld_bps)
+        // No settlement currency conversion
+        .x25519_pubkey(pubkey)
+        .plaintext_u128(0)
+        .encrypted_u8([0u8; 32])
+        .plaintext_u8(NUM_PAIRS)
+        .build();
+
+    use arcium_client::idl::arcium::types::CallbackAccount;
+    queue_computation(
+        ctx.accounts,
+        computation_offset,
+        args,
+        vec![CalculatePayoutCallback::callback_ix(
+            computation_offset,
+            &ctx.accounts.mxe_account,
+            &[
+                CallbackAccount {
+                    pubkey: ctx.accounts.user_account.key(),
+                    is_writable: true,
+                },
+                CallbackAccount {
+                    pubkey: ctx.accounts.order_history.key(),
+                    is_writable: true,
+                },
+                CallbackAccount {
+                    pubkey: ctx.accounts.batch_log.key(),
+                    is_writable: true,
+                },
+                crate::optional_callback_account(None, true), // diagnostics
+                crate::optional_callback_account(Some(ctx.accounts.pool.key()), true),
+                crate::optional_callback_account(Some(ctx.accounts.payout_vault.key()), true),
+                crate::optional_callback_account(
+                    Some(ctx.accounts.bounty_token_account.key()),
+                    true,
+                ),
+                crate::optional_callback_account(Some(ctx.accounts.token_program.key()), false),
+                crate::optional_callback_account(None, true), // basket_order
+                crate::event_cursor_callback_account(ctx.accounts.pool.pool_id),
+            ],
+        )?],
+        1,                                         // number of callbacks
+        ctx.accounts.pool.callback_cu_price_micro, // priority
+    )?;
+
+    msg!(
+        "Stale settlement queued: user={}, batch={}, payer={}, withheld_bps={}",
+        owner,
+        pending.batch_id,
+        ctx.accounts.payer.key(),
+        withheld_bps
+    );
+
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::instructions::force_settle::queue_stale_settlement;
+use crate::ForceSettle;
+
+// =============================================================================
+// FORFEIT ORDER - Escheat Unsettled Payouts to the Treasury
+// =============================================================================
+// Once Pool.settlement_deadline_secs have passed since batch execution, an
+// unsettled payout escheats to Pool.treasury. This is force_settle with the
+// whole payout withheld: MPC credits the user nothing on the output asset
+// (the unfilled refund is still returned) and the callback moves the payout
+// from the output vault to the treasury's token account.
+//
+// Each forfeiture decrements BatchLog.unsettled_count; once it reaches zero
+// the BatchLog can be closed with close_batch_log.
+
+/// Forfeit a user's pending order after the settlement deadline. Permissionless.
+///
+/// # Arguments
+/// * `computation_offset` - Unique ID for MPC computation
+/// * `pair_id` - Trading pair of the order (0-5)
+/// * `direction` - Order direction (0=A_to_B, 1=B_to_A)
+pub fn handler(
+    ctx: Context<ForceSettle>,
+    computation_offset: u64,
+    pair_id: u8,
+    direction: u8,
+) -> Result<()> {
+    let executed_at = ctx.accounts.batch_log.executed_at;
+    require!(executed_at > 0, ErrorCode::BatchNotFinalized);
+    require!(
+        Clock::get()?.unix_timestamp >= executed_at + ctx.accounts.pool.settlement_deadline_secs,
+        ErrorCode::SettlementDeadlineNotReached
+    );
+
+    // The withheld payout may only go to the treasury
+    require!(
+        ctx.accounts.bounty_token_account.owner == ctx.accounts.pool.treasury,
+        ErrorCode::InvalidOwner
+    );
+
+    queue_stale_settlement(
+        ctx,
+        computation_offset,
+        pair_id,
+        direction,
+        BPS_DENOMINATOR as u64,
+    )
+}
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::{AssetFrozenEvent, FreezeAsset};
+
+// =============================================================================
+// EMERGENCY ASSET FREEZE
+// =============================================================================
+// Freezing takes effect immediately; unfreezing goes through
+// request_unfreeze_asset + unfreeze_asset after ASSET_UNFREEZE_TIMELOCK_SECS.
+//
+// While frozen:
+// - add_balance / sub_balance reject the asset
+// - place_order / execute_dca reject it as the source asset
+// - batches snapshot the mask and fully refund pairs that touch the asset
+
+/// Freeze a single asset. Only callable by an admin or risk manager.
+/// Freezing an already frozen asset cancels any pending unfreeze request.
+///
+/// # Arguments
+/// * `asset_id` - Asset to freeze (0=USDC, 1=TSLA, 2=SPY, 3=AAPL)
+pub fn handler(ctx: Context<FreezeAsset>, asset_id: u8) -> Result<()> {
+    require!(asset_id <= 3, ErrorCode::InvalidAssetId);
+
+    let pool = &mut ctx.accounts.pool;
+    pool.frozen_assets |= 1 << asset_id;
+    pool.unfreeze_available_at[asset_id as usize] = 0;
+
+    emit!(AssetFrozenEvent {
+        asset_id,
+        frozen_at: Clock::get()?.unix_timestamp,
+        seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+    });
+
+    msg!("Asset {} frozen", asset_id);
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+
+use crate::errors::ErrorCode;
+use crate::{FundRentSponsor, RentSponsorFundedEvent};
+
+// =============================================================================
+// FUND RENT SPONSOR - Top up the UserProfile rent subsidy
+// =============================================================================
+// The pool is created on first call. Lamports above its own rent-exempt
+// minimum are spent reimbursing UserProfile rent in create_user_account
+// (see state/rent_sponsor.rs); withdraw_rent_sponsor takes them back.
+
+/// Deposit lamports into the rent sponsorship pool. Only callable by pool authority.
+///
+/// # Arguments
+/// * `amount` - Lamports to deposit
+pub fn handler(ctx: Context<FundRentSponsor>, amount: u64) -> Result<()> {
+    require!(amount > 0, ErrorCode::InvalidAmount);
+
+    ctx.accounts.rent_sponsor.bump = ctx.bumps.rent_sponsor;
+
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.authority.to_account_info(),
+                to: ctx.accounts.rent_sponsor.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    let balance = ctx.accounts.rent_sponsor.to_account_info().lamports();
+    emit!(RentSponsorFundedEvent {
+        amount,
+        balance,
+        seq: ctx.accounts.event_cursor.next(Clock::get()?.slot)
+    });
+
+    msg!(
+        "Rent sponsor funded: {} lamports (balance {})",
+        amount,
+        balance
+    );
+    Ok(())
+}
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::ROLE_COUNT;
+use crate::{GrantRole, RoleGrantedEvent};
+
+/// Grant a role to a wallet. Only callable by an admin.
+///
+/// # Arguments
+/// * `role` - ROLE_* constant
+/// * `member` - Wallet to grant the role to
+pub fn handler(ctx: Context<GrantRole>, role: u8, member: Pubkey) -> Result<()> {
+    require!((role as usize) < ROLE_COUNT, ErrorCode::InvalidRole);
+    require!(member != Pubkey::default(), ErrorCode::InvalidRole);
+
+    let roles = &mut ctx.accounts.roles;
+    require!(!roles.has_role(role, &member), ErrorCode::InvalidRole);
+    let slot = roles.members[role as usize]
+        .iter_mut()
+        .find(|slot| **slot == Pubkey::default())
+        .ok_or(ErrorCode::RoleFull)?;
+    *slot = member;
+
+    emit!(RoleGrantedEvent {
+        role,
+        member,
+        granted_by: ctx.accounts.authority.key(),
+        seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+    });
+
+    msg!("Role {} granted to {}", role, member);
+    Ok(())
+}
@@ -1,17 +1,29 @@
 use anchor_lang::prelude::*;
 
-use crate::state::{PairAccumulator, NUM_PAIRS};
+use crate::constants::BATCH_ACCUMULATOR_SLOTS;
+use crate::errors::ErrorCode;
+use crate::state::{
+    BatchAccumulator, NettingBuffer, PairAccumulator, BATCH_STATUS_OPEN, NUM_PAIRS,
+};
 use crate::InitBatchAccumulator;
 
 /// Handler for init_batch_accumulator instruction.
-/// Creates the singleton BatchAccumulator PDA with initial values.
-pub fn handler(ctx: Context<InitBatchAccumulator>) -> Result<()> {
+/// Creates one of the two BatchAccumulator slot PDAs with initial values.
+/// Slot 0 starts active and collects batch 1; slot 1 starts idle and
+/// receives batch 2 when batch 1 is executed. Only callable by pool authority.
+pub fn handler(ctx: Context<InitBatchAccumulator>, slot: u8) -> Result<()> {
+    require!(slot < BATCH_ACCUMULATOR_SLOTS, ErrorCode::InvalidBatchSlot);
+
     let batch = &mut ctx.accounts.batch_accumulator;
 
-    // Initialize with batch_id = 1 (first batch)
-    batch.batch_id = 1;
+    // Slot 0 collects the first batch; slot 1's batch_id is assigned when it
+    // becomes active
+    batch.batch_id = if slot == 0 { 1 } else { 0 };
+    batch.slot = slot;
+    batch.active = slot == 0;
     // Initialize plaintext order_count to 0
     batch.order_count = 0;
+    batch.basket_count = 0;
 
     // Initialize all pair states with zero (encrypted zeros will be set by MPC)
     // For now, use raw zeros as placeholder until first MPC operation
@@ -21,8 +33,20 @@ pub fn handler(ctx: Context<InitBatchAccumulator>) -> Result<()> {
     batch.mxe_nonce = 0;
 
     batch.bump = ctx.bumps.batch_accumulator;
-
-    msg!("BatchAccumulator initialized with batch_id: 1");
+    batch.status = BATCH_STATUS_OPEN;
+    batch.reveal_queued_at = 0;
+    batch.netting_buffers = [NettingBuffer::default(); NUM_PAIRS];
+    batch.reveal_computation_offset = 0;
+    batch.failed_computation_offset = 0;
+    batch.reveal_retries = 0;
+    batch.version = BatchAccumulator::VERSION;
+    batch.state_initialized = false;
+
+    msg!(
+        "BatchAccumulator slot {} initialized with batch_id: {}",
+        slot,
+        batch.batch_id
+    );
 
     Ok(())
 }
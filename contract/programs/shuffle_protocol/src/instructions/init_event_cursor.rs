@@ -0,0 +1,15 @@
+use anchor_lang::prelude::*;
+
+use crate::InitEventCursor;
+
+/// Handler for init_event_cursor instruction.
+/// Creates the EventCursor PDA; the first sequenced event gets seq 1.
+pub fn handler(ctx: Context<InitEventCursor>) -> Result<()> {
+    let cursor = &mut ctx.accounts.event_cursor;
+    cursor.sequence = 0;
+    cursor.last_slot = 0;
+    cursor.bump = ctx.bumps.event_cursor;
+
+    msg!("EventCursor initialized");
+    Ok(())
+}
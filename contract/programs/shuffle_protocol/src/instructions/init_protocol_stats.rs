@@ -0,0 +1,12 @@
+use anchor_lang::prelude::*;
+
+use crate::InitProtocolStats;
+
+/// Handler for init_protocol_stats instruction.
+/// Creates the ProtocolStats PDA with all counters at zero.
+pub fn handler(ctx: Context<InitProtocolStats>) -> Result<()> {
+    ctx.accounts.protocol_stats.bump = ctx.bumps.protocol_stats;
+
+    msg!("ProtocolStats initialized");
+    Ok(())
+}
@@ -0,0 +1,16 @@
+use anchor_lang::prelude::*;
+
+use crate::InitRoles;
+
+/// Create the pool's Roles PDA with no granted members. Only callable by
+/// pool authority. Until it exists, instructions gated by a role cannot be
+/// called.
+pub fn handler(ctx: Context<InitRoles>) -> Result<()> {
+    let roles = &mut ctx.accounts.roles;
+    roles.pool_id = ctx.accounts.pool.pool_id;
+    roles.members = Default::default();
+    roles.bump = ctx.bumps.roles;
+
+    msg!("Roles initialized for pool {}", roles.pool_id);
+    Ok(())
+}
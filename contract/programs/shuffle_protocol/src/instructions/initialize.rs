@@ -2,6 +2,7 @@ use anchor_lang::prelude::*;
 
 use crate::constants::*;
 use crate::errors::ErrorCode;
+use crate::state::{Pool, EXECUTION_MODE_DEFAULT};
 use crate::Initialize;
 
 // =============================================================================
@@ -15,10 +16,12 @@ use crate::Initialize;
 ///
 /// # Arguments
 /// * `ctx` - The validated accounts context
+/// * `pool_id` - Identifier of the pool, seeded into every pool-scoped PDA
 /// * `execution_fee_bps` - Fee charged on swaps in basis points (e.g., 50 = 0.5%)
 /// * `execution_trigger_count` - Number of orders to trigger batch execution (default: 8)
 pub fn handler(
     ctx: Context<Initialize>,
+    pool_id: u8,
     execution_fee_bps: u16,
     execution_trigger_count: u8,
 ) -> Result<()> {
@@ -32,6 +35,7 @@ pub fn handler(
     // Store the bump seed - used later when the Pool PDA needs to sign transactions
     // (e.g., when transferring tokens from vaults during batch execution)
     pool.bump = ctx.bumps.pool;
+    pool.pool_id = pool_id;
 
     // Set the admin authority - this wallet can:
     // - Update fees
@@ -55,9 +59,24 @@ pub fn handler(
     pool.spy_mint = ctx.accounts.spy_mint.key();
     pool.aapl_mint = ctx.accounts.aapl_mint.key();
 
+    // Mint decimals: prices are per whole token, conversions are per base unit
+    pool.asset_decimals = [
+        ctx.accounts.usdc_mint.decimals,
+        ctx.accounts.tsla_mint.decimals,
+        ctx.accounts.spy_mint.decimals,
+        ctx.accounts.aapl_mint.decimals,
+    ];
+    require!(
+        pool.asset_decimals
+            .iter()
+            .all(|&decimals| decimals <= MAX_ASSET_DECIMALS),
+        ErrorCode::InvalidMint
+    );
+
     // Batch configuration
     pool.current_batch_id = 0;
     pool.execution_trigger_count = execution_trigger_count;
+    pool.min_active_pairs = BATCH_MIN_ACTIVE_PAIRS;
 
     // Set fee configuration
     pool.execution_fee_bps = execution_fee_bps;
@@ -66,6 +85,44 @@ pub fn handler(
     pool.paused = false;
     pool.total_fees_collected = 0;
     pool.total_batches_executed = 0;
+    pool.frozen_assets = 0;
+    pool.unfreeze_available_at = [0; 4];
+    pool.delisted_assets = 0;
+    pool.restricted_assets = 0;
+    pool.settlement_deadline_secs = DEFAULT_SETTLEMENT_DEADLINE_SECS;
+    pool.callback_cu_price_micro = 0;
+    pool.execution_mode = EXECUTION_MODE_DEFAULT;
+    pool.reserve_spread_bps = 0;
+    pool.max_reserve_utilization_bps = BPS_DENOMINATOR;
+    pool.max_reserve_inventory = [0; 4];
+    pool.swap_program = JUPITER_PROGRAM_ID;
+    pool.netting_window_secs = 0;
+    pool.keeper_reward_lamports = 0;
+    pool.co_operator = Pubkey::default();
+    pool.approval_threshold_usdc = 0;
+    pool.executor_gated = false;
+    pool.fees_owed = [0; 4];
+    pool.max_batches_per_window = 0;
+    pool.batch_window_secs = DEFAULT_BATCH_WINDOW_SECS;
+    pool.max_batch_wait_secs = 0;
+    pool.batch_window_started_at = 0;
+    pool.batches_in_window = 0;
+    pool.last_batch_queued_at = 0;
+    pool.max_slippage_bps = DEFAULT_MAX_SLIPPAGE_BPS;
+    pool.min_unique_users = 0;
+    pool.oracle_configured = false;
+    pool.market_hours_enforced = false;
+    pool.last_execution_prices = [0; 6];
+    pool.pending_authority = Pubkey::default();
+    pool.risk_configured = false;
+    pool.emergency_withdraw_enabled = false;
+    pool.version = Pool::VERSION;
+    pool.bootstrap_flags = 0;
+    pool.fees_owed_usdc = 0;
+    pool.keeper_reward_bps = 0;
+    pool.pair_registry_configured = false;
+    pool.execution_policy_configured = false;
+    pool.force_settle_grace_secs = FORCE_SETTLE_GRACE_SECS;
 
     msg!("Shuffle Protocol protocol initialized!");
     msg!("Authority: {}", pool.authority);
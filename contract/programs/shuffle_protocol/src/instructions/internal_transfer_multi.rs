@@ -0,0 +1,113 @@
+use anchor_lang::prelude::*;
+use arcium_anchor::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::{InternalTransferMulti, TransferMultiCallback};
+
+// =============================================================================
+// INTERNAL TRANSFER MULTI - Move a whole portfolio in one MPC call
+// =============================================================================
+// Multi-asset counterpart of internal_transfer. The sender encrypts one
+// amount per asset (zero for assets it does not move) as a single
+// MultiTransferRequest; transfer_multi updates all eight balances at once.
+// If the sender is short on any asset nothing moves, and the chain cannot
+// tell which assets were non-zero.
+//
+// Since every asset is touched, no asset may be frozen.
+
+/// Queue a private transfer of all four assets to another privacy account.
+///
+/// # Arguments
+/// * `computation_offset` - Unique ID for MPC computation
+/// * `encrypted_amounts` - USDC, TSLA, SPY, AAPL amounts encrypted with the sender's key
+/// * `pubkey` - Sender's x25519 public key
+/// * `nonce` - Nonce used to encrypt the amounts
+pub fn handler(
+    ctx: Context<InternalTransferMulti>,
+    computation_offset: u64,
+    encrypted_amounts: [[u8; 32]; 4],
+    pubkey: [u8; 32],
+    nonce: u128,
+) -> Result<()> {
+    require!(!ctx.accounts.pool.paused, ErrorCode::ProtocolPaused);
+    require!(
+        !(0..4).any(|asset_id| ctx.accounts.pool.is_asset_frozen(asset_id)),
+        ErrorCode::AssetFrozen
+    );
+
+    // Set sign PDA bump
+    ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+    // Sender activity resets their beneficiary inactivity timer
+    ctx.accounts.sender_account.last_active_at = Clock::get()?.unix_timestamp;
+
+    let sender = &ctx.accounts.sender_account;
+    let recipient = &ctx.accounts.recipient_account;
+    let args = ArgBuilder::new()
+        // MultiTransferRequest (encrypted with sender's key) - four amounts
+        .x25519_pubkey(pubkey)
+        .plaintext_u128(nonce)
+        .encrypted_u64(encrypted_amounts[0])
+        .encrypted_u64(encrypted_amounts[1])
+        .encrypted_u64(encrypted_amounts[2])
+        .encrypted_u64(encrypted_amounts[3])
+        // Sender's balances (Enc<Shared, *> - sender's pubkey)
+        .x25519_pubkey(sender.user_pubkey)
+        .plaintext_u128(sender.usdc_nonce)
+        .encrypted_u64(sender.usdc_credit)
+        .x25519_pubkey(sender.user_pubkey)
+        .plaintext_u128(sender.tsla_nonce)
+        .encrypted_u64(sender.tsla_credit)
+        .x25519_pubkey(sender.user_pubkey)
+        .plaintext_u128(sender.spy_nonce)
+        .encrypted_u64(sender.spy_credit)
+        .x25519_pubkey(sender.user_pubkey)
+        .plaintext_u128(sender.aapl_nonce)
+        .encrypted_u64(sender.aapl_credit)
+        // Recipient's balances (Enc<Shared, *> - recipient's pubkey)
+        .x25519_pubkey(recipient.user_pubkey)
+        .plaintext_u128(recipient.usdc_nonce)
+        .encrypted_u64(recipient.usdc_credit)
+        .x25519_pubkey(recipient.user_pubkey)
+        .plaintext_u128(recipient.tsla_nonce)
+        .encrypted_u64(recipient.tsla_credit)
+        .x25519_pubkey(recipient.user_pubkey)
+        .plaintext_u128(recipient.spy_nonce)
+        .encrypted_u64(recipient.spy_credit)
+        .x25519_pubkey(recipient.user_pubkey)
+        .plaintext_u128(recipient.aapl_nonce)
+        .encrypted_u64(recipient.aapl_credit)
+        .build();
+
+    use arcium_client::idl::arcium::types::CallbackAccount;
+    queue_computation(
+        ctx.accounts,
+        computation_offset,
+        args,
+        vec![TransferMultiCallback::callback_ix(
+            computation_offset,
+            &ctx.accounts.mxe_account,
+            &[
+                CallbackAccount {
+                    pubkey: ctx.accounts.sender_account.key(),
+                    is_writable: true,
+                },
+                CallbackAccount {
+                    pubkey: ctx.accounts.recipient_account.key(),
+                    is_writable: true,
+                },
+                crate::event_cursor_callback_account(ctx.accounts.pool.pool_id),
+            ],
+        )?],
+        1,
+        0,
+    )?;
+
+    msg!(
+        "Multi-asset transfer queued: {} -> {}, computation {}",
+        ctx.accounts.sender_account.owner,
+        ctx.accounts.recipient_account.owner,
+        computation_offset
+    );
+    Ok(())
+}
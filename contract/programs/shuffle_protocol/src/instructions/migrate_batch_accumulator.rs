@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+
+use crate::state::BatchAccumulator;
+use crate::{AccountVersionMigratedEvent, MigrateBatchAccumulator};
+
+// =============================================================================
+// MIGRATE BATCH ACCUMULATOR - Bring an older BatchAccumulator up to the current layout
+// =============================================================================
+// Upgrades an accumulator slot to BatchAccumulator::VERSION. Fields added
+// since are zero-extended, so the slot keeps its orders and encrypted
+// totals and can be migrated mid-batch. Baskets already in a batch migrated
+// to version 3 are not in basket_count, so until it reveals the privacy
+// floor counts them as it did before. Permissionless; the caller pays the
+// extra rent.
+
+/// Upgrade a BatchAccumulator slot to the current layout. Permissionless.
+pub fn handler(ctx: Context<MigrateBatchAccumulator>) -> Result<()> {
+    let info = ctx.accounts.batch_accumulator.to_account_info();
+    let old_size = info.data_len();
+    let from_version = crate::migrate_account_version(
+        &info,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        BatchAccumulator::DISCRIMINATOR,
+        BatchAccumulator::VERSION_OFFSET,
+        BatchAccumulator::SIZE,
+        BatchAccumulator::VERSION,
+    )?;
+
+    // Version 2 added state_initialized: a slot whose zeros were stored
+    // before then already has an MXE nonce
+    if from_version < 2 {
+        let mut batch = Account::<BatchAccumulator>::try_from(&info)?;
+        batch.state_initialized = batch.mxe_nonce != 0;
+        batch.exit(&crate::ID)?;
+    }
+
+    emit!(AccountVersionMigratedEvent {
+        account: info.key(),
+        from_version,
+        to_version: BatchAccumulator::VERSION,
+        old_size: old_size as u32,
+        new_size: BatchAccumulator::SIZE as u32,
+        seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+    });
+
+    msg!(
+        "BatchAccumulator {} migrated: v{} -> v{}, {} -> {} bytes",
+        info.key(),
+        from_version,
+        BatchAccumulator::VERSION,
+        old_size,
+        BatchAccumulator::SIZE
+    );
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+use crate::state::BatchLog;
+use crate::{AccountVersionMigratedEvent, MigrateBatchLog};
+
+// =============================================================================
+// MIGRATE BATCH LOG - Bring an older BatchLog up to the current layout
+// =============================================================================
+// Upgrades a BatchLog that still has unsettled orders to BatchLog::VERSION,
+// so settle_order, execute_swaps and close_batch_log can load it again.
+// Fields added since are zero-extended. Permissionless; the caller pays
+// the extra rent.
+
+/// Upgrade a BatchLog to the current layout. Permissionless.
+pub fn handler(ctx: Context<MigrateBatchLog>) -> Result<()> {
+    let info = ctx.accounts.batch_log.to_account_info();
+    let old_size = info.data_len();
+    let from_version = crate::migrate_account_version(
+        &info,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        BatchLog::DISCRIMINATOR,
+        BatchLog::VERSION_OFFSET,
+        BatchLog::SIZE,
+        BatchLog::VERSION,
+    )?;
+
+    emit!(AccountVersionMigratedEvent {
+        account: info.key(),
+        from_version,
+        to_version: BatchLog::VERSION,
+        old_size: old_size as u32,
+        new_size: BatchLog::SIZE as u32,
+        seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+    });
+
+    msg!(
+        "BatchLog {} migrated: v{} -> v{}, {} -> {} bytes",
+        info.key(),
+        from_version,
+        BatchLog::VERSION,
+        old_size,
+        BatchLog::SIZE
+    );
+    Ok(())
+}
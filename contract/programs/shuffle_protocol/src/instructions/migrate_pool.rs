@@ -0,0 +1,131 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::constants::{FORCE_SETTLE_GRACE_SECS, MAX_ASSET_DECIMALS};
+use crate::errors::ErrorCode;
+use crate::state::{LegacyPool, Pool};
+use crate::{AccountVersionMigratedEvent, MigratePool};
+
+// =============================================================================
+// MIGRATE POOL - Bring an older Pool up to the current layout
+// =============================================================================
+// Pool accounts created before the version field, or at an older version,
+// no longer deserialize once the layout grows. This permissionless crank
+// zero-extends the account to Pool::SIZE (the caller pays the extra rent)
+// and stamps Pool::VERSION. Run it before the other migrate_* instructions,
+// which load the pool. Flags that record whether a config PDA exists are
+// set from the PDAs themselves, so a pool migrated past them keeps
+// requiring the accounts it already had, and new settings that must not be
+// zero get their defaults.
+//
+// A pool still in the original layout (LegacyPool) cannot be zero-extended
+// because fields were inserted before existing ones. It is decoded and
+// rewritten field by field instead, with initialize's defaults for the new
+// settings. Its four mints [USDC, TSLA, SPY, AAPL] must then be passed as
+// remaining accounts so asset_decimals can be read from them.
+
+/// Upgrade the Pool account to the current layout. Permissionless.
+///
+/// # Arguments
+/// * `pool_id` - Pool to migrate, stored on a LegacyPool rewritten here
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, MigratePool<'info>>,
+    pool_id: u8,
+) -> Result<()> {
+    let info = ctx.accounts.pool.to_account_info();
+    let old_size = info.data_len();
+    let from_version = if old_size == LegacyPool::SIZE {
+        migrate_legacy_pool(&ctx, pool_id)?;
+        0
+    } else {
+        crate::migrate_account_version(
+            &info,
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            Pool::DISCRIMINATOR,
+            Pool::VERSION_OFFSET,
+            Pool::SIZE,
+            Pool::VERSION,
+        )?
+    };
+
+    {
+        let exists = |account: AccountInfo| *account.owner == crate::ID && !account.data_is_empty();
+        let mut data = info.try_borrow_mut_data()?;
+        let mut pool = Pool::try_deserialize(&mut &data[..])?;
+        pool.pair_registry_configured |= exists(ctx.accounts.pair_registry.to_account_info());
+        pool.execution_policy_configured |= exists(ctx.accounts.execution_policy.to_account_info());
+        if from_version < 6 {
+            pool.force_settle_grace_secs = FORCE_SETTLE_GRACE_SECS;
+        }
+        pool.try_serialize(&mut &mut data[..])?;
+    }
+
+    let slot = Clock::get()?.slot;
+    let seq = ctx
+        .accounts
+        .event_cursor
+        .as_mut()
+        .map_or(0, |cursor| cursor.next(slot));
+    emit!(AccountVersionMigratedEvent {
+        account: info.key(),
+        from_version,
+        to_version: Pool::VERSION,
+        old_size: old_size as u32,
+        new_size: Pool::SIZE as u32,
+        seq,
+    });
+
+    msg!(
+        "Pool {} migrated: v{} -> v{}, {} -> {} bytes",
+        info.key(),
+        from_version,
+        Pool::VERSION,
+        old_size,
+        Pool::SIZE
+    );
+    Ok(())
+}
+
+/// Rewrite a pool in the original layout (LegacyPool) into the current one
+fn migrate_legacy_pool<'info>(
+    ctx: &Context<'_, '_, '_, 'info, MigratePool<'info>>,
+    pool_id: u8,
+) -> Result<()> {
+    let info = ctx.accounts.pool.to_account_info();
+    require_keys_eq!(*info.owner, crate::ID, ErrorCode::InvalidOwner);
+    let legacy = {
+        let data = info.try_borrow_data()?;
+        require!(data[..8] == *Pool::DISCRIMINATOR, ErrorCode::InvalidOwner);
+        LegacyPool::deserialize(&mut &data[8..])?
+    };
+
+    // Decimals of the legacy mints, passed in asset_id order
+    require!(ctx.remaining_accounts.len() == 4, ErrorCode::InvalidMint);
+    let mut asset_decimals = [0u8; 4];
+    for ((decimals, mint), account) in asset_decimals
+        .iter_mut()
+        .zip(legacy.mints())
+        .zip(ctx.remaining_accounts)
+    {
+        require_keys_eq!(account.key(), mint, ErrorCode::InvalidMint);
+        require!(
+            *account.owner == anchor_spl::token::ID || *account.owner == anchor_spl::token_2022::ID,
+            ErrorCode::InvalidMint
+        );
+        *decimals = Mint::try_deserialize(&mut &account.try_borrow_data()?[..])?.decimals;
+        require!(*decimals <= MAX_ASSET_DECIMALS, ErrorCode::InvalidMint);
+    }
+
+    let pool = legacy.into_current(pool_id, ctx.bumps.pool, asset_decimals);
+    crate::grow_account(
+        &info,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        Pool::SIZE,
+    )?;
+    let mut data = info.try_borrow_mut_data()?;
+    let mut writer: &mut [u8] = &mut data;
+    pool.try_serialize(&mut writer)?;
+    Ok(())
+}
@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::{LegacyUserProfile, UserProfile};
+use crate::{MigrateUserAccount, UserAccountMigratedEvent};
+
+// =============================================================================
+// MIGRATE USER ACCOUNT - Rewrite a UserProfile into the audited layout
+// =============================================================================
+// The size audit dropped the four viewable balances (never written) and
+// moved the settlement preference to its own PDA. Profiles created before
+// that have the LegacyUserProfile layout and no longer deserialize as a
+// UserProfile. This permissionless instruction rewrites one in place,
+// shrinks the account to UserProfile::SIZE and returns the freed rent to
+// the owner.
+//
+// Balances, nonces, pending/staged orders and in-flight flags are kept, so
+// the migration is safe while an order or settlement is pending. An inline
+// settlement preference is not carried over; the owner sets it again with
+// set_settlement_preference.
+
+/// Migrate a legacy UserProfile to the current layout. Permissionless.
+pub fn handler(ctx: Context<MigrateUserAccount>) -> Result<()> {
+    let info = ctx.accounts.user_account.to_account_info();
+    require_keys_eq!(*info.owner, crate::ID, ErrorCode::InvalidOwner);
+
+    let old_size = info.data_len();
+    let legacy = {
+        let data = info.try_borrow_data()?;
+        require!(
+            old_size == LegacyUserProfile::SIZE,
+            ErrorCode::AccountAlreadyMigrated
+        );
+        require!(
+            data[..8] == *UserProfile::DISCRIMINATOR,
+            ErrorCode::InvalidOwner
+        );
+        LegacyUserProfile::deserialize(&mut &data[8..])?
+    };
+    require_keys_eq!(
+        legacy.owner,
+        ctx.accounts.owner.key(),
+        ErrorCode::InvalidOwner
+    );
+    let preference_dropped = legacy.settlement_preference.is_some();
+
+    // Rewrite in the current layout, then shrink the account
+    let profile = legacy.into_current();
+    info.resize(UserProfile::SIZE)?;
+    {
+        let mut data = info.try_borrow_mut_data()?;
+        let mut writer: &mut [u8] = &mut data;
+        profile.try_serialize(&mut writer)?;
+    }
+
+    // Return the rent freed by the smaller layout
+    let required = Rent::get()?.minimum_balance(UserProfile::SIZE);
+    let refunded = info.lamports().saturating_sub(required);
+    if refunded > 0 {
+        info.sub_lamports(refunded)?;
+        ctx.accounts.owner.add_lamports(refunded)?;
+    }
+
+    emit!(UserAccountMigratedEvent {
+        owner: profile.owner,
+        old_size: old_size as u32,
+        new_size: UserProfile::SIZE as u32,
+        refunded,
+        preference_dropped,
+        seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+    });
+
+    msg!(
+        "User account migrated: owner={}, {} -> {} bytes, refunded {} lamports",
+        profile.owner,
+        old_size,
+        UserProfile::SIZE,
+        refunded
+    );
+    Ok(())
+}
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+
+use crate::state::UserProfile;
+use crate::{AccountVersionMigratedEvent, MigrateUserProfile};
+
+// =============================================================================
+// MIGRATE USER PROFILE - Bring an older UserProfile up to the current layout
+// =============================================================================
+// Upgrades a UserProfile from an older versioned layout (version 0 being the
+// layout right before the version field) to UserProfile::VERSION: fields
+// added since are zero-extended and the caller pays the extra rent.
+// Version 2's in-flight balance nonces are seeded from the current balances
+// so an order or settlement already in flight still lands.
+// Pre-audit profiles are rewritten by migrate_user_account instead, which
+// already produces the current version. Permissionless.
+
+/// Upgrade a UserProfile to the current layout. Permissionless.
+pub fn handler(ctx: Context<MigrateUserProfile>) -> Result<()> {
+    let info = ctx.accounts.user_account.to_account_info();
+    let old_size = info.data_len();
+    let from_version = crate::migrate_account_version(
+        &info,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        UserProfile::DISCRIMINATOR,
+        UserProfile::VERSION_OFFSET,
+        UserProfile::SIZE,
+        UserProfile::VERSION,
+    )?;
+
+    if from_version < 2 {
+        let mut data = info.try_borrow_mut_data()?;
+        let mut profile = UserProfile::try_deserialize(&mut &data[..])?;
+        profile.seed_pending_nonces();
+        profile.try_serialize(&mut &mut data[..])?;
+    }
+
+    emit!(AccountVersionMigratedEvent {
+        account: info.key(),
+        from_version,
+        to_version: UserProfile::VERSION,
+        old_size: old_size as u32,
+        new_size: UserProfile::SIZE as u32,
+        seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+    });
+
+    msg!(
+        "UserProfile {} migrated: v{} -> v{}, {} -> {} bytes",
+        info.key(),
+        from_version,
+        UserProfile::VERSION,
+        old_size,
+        UserProfile::SIZE
+    );
+    Ok(())
+}
@@ -4,23 +4,146 @@
 // This module contains all the instruction handlers for the Shuffle Protocol protocol.
 //
 
+pub mod accept_authority;
 pub mod add_liquidity;
+pub mod add_viewer;
+pub mod admin_clear_pending;
+pub mod advance_stream;
+pub mod apply_config;
+pub mod approve_batch;
+pub mod approve_recovery;
+pub mod audit_vaults;
+pub mod bootstrap;
+pub mod cancel_dca;
+pub mod cancel_recovery;
+pub mod cancel_stream;
+pub mod cancel_withdrawal;
+pub mod check_invariants;
+pub mod claim_inheritance;
+pub mod claim_reserve_auction;
+pub mod claim_transfer;
+pub mod claim_voucher;
+pub mod clear_settlement_preference;
+pub mod close_batch_log;
+pub mod close_order_history;
+pub mod close_user_account;
+pub mod configure_asset_policy;
+pub mod configure_buyback;
+pub mod configure_executor_registry;
+pub mod configure_pair;
+pub mod configure_profiling;
+pub mod create_and_deposit;
+pub mod create_dca;
+pub mod create_delegate;
+pub mod create_savings_bucket;
+pub mod create_stream;
 pub mod create_user_account;
+pub mod create_voucher;
+pub mod delegated_withdraw;
+pub mod deposit_for;
 pub mod execute_batch;
+pub mod execute_buyback;
+pub mod execute_dca;
+pub mod execute_external_swap;
+pub mod execute_recovery;
 pub mod execute_swaps;
+pub mod export_config;
 pub mod faucet;
+pub mod flush_batch;
+pub mod flush_netting_buffer;
+pub mod force_settle;
+pub mod forfeit_order;
+pub mod freeze_asset;
+pub mod fund_rent_sponsor;
+pub mod grant_role;
 pub mod init_batch_accumulator;
+pub mod init_event_cursor;
+pub mod init_protocol_stats;
+pub mod init_roles;
 pub mod initialize;
+pub mod internal_transfer_multi;
+pub mod migrate_batch_accumulator;
+pub mod migrate_batch_log;
+pub mod migrate_pool;
+pub mod migrate_user_account;
+pub mod migrate_user_profile;
+pub mod move_savings;
+pub mod open_reserve_auction;
+pub mod override_price_band;
+pub mod pause_dca;
+pub mod pause_protocol;
+pub mod place_basket_order;
+pub mod place_delegated_order;
 pub mod place_order;
+pub mod place_order_usd;
+pub mod place_staged_order;
+pub mod propose_authority;
+pub mod reclaim_transfer;
+pub mod reclaim_voucher;
+pub mod recover_batch_reveal;
+pub mod refresh_viewable_balance;
+pub mod register_asset;
+pub mod register_executor;
+pub mod remove_beneficiary;
 pub mod remove_liquidity;
+pub mod remove_viewer;
+pub mod request_transfer;
+pub mod request_unfreeze_asset;
+pub mod reset_batch;
+pub mod reset_transfer_request;
+pub mod reset_voucher;
+pub mod resume_dca;
+pub mod retry_deposit;
+pub mod retry_execute_batch;
+pub mod reveal_batch;
+pub mod reveal_reserve_auction;
+pub mod revoke_delegate;
+pub mod revoke_role;
+pub mod rotate_user_key;
+pub mod route_surplus;
+pub mod set_asset_delisted;
+pub mod set_batch_approval;
+pub mod set_batch_rate_limit;
+pub mod set_beneficiary;
+pub mod set_callback_priority_fee;
+pub mod set_delegate_allowance;
+pub mod set_emergency_withdraw;
+pub mod set_execution_mode;
+pub mod set_force_settle_grace;
+pub mod set_guardians;
+pub mod set_keeper_reward;
+pub mod set_market_schedule;
+pub mod set_max_slippage;
+pub mod set_operator;
+pub mod set_oracle_feed;
+pub mod set_oracle_params;
+pub mod set_pair_policy;
+pub mod set_price_band;
+pub mod set_privacy_floor;
+pub mod set_risk_config;
+pub mod set_settlement_deadline;
+pub mod set_settlement_preference;
+pub mod set_treasury;
+pub mod set_viewing_key;
+pub mod settle_and_place_order;
+pub mod settle_basket_leg;
 pub mod settle_order;
+pub mod slash_executor;
+pub mod submit_auction_bid;
+pub mod sweep_fees;
 pub mod test_swap;
+pub mod transfer_batch;
+pub mod unfreeze_asset;
+pub mod unpause_protocol;
+pub mod update_batch_config;
+pub mod update_pool_config;
+pub mod upgrade_circuit;
+pub mod withdraw_rent_sponsor;
 // deposit removed in Phase 6 - use add_balance instruction instead (encrypted via Arcium)
 
 // Note: Account structs (like Initialize, CreateUserAccount, Deposit) are defined in lib.rs
 // for Anchor's IDL generation. Only handlers are defined in this module.
 // mod submit_order;         // Phase 7
-// mod create_dca;           // Phase 8
 // mod execute_batch;        // Phase 9
 // mod withdraw;             // Phase 10
 // mod cancel_order;         // Phase 11
@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+use arcium_anchor::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::UserProfile;
+use crate::{MoveSavings, MoveSavingsCallback};
+
+// =============================================================================
+// MOVE SAVINGS - Shift USDC between the trading balance and a bucket
+// =============================================================================
+// The amount is encrypted with the owner's key like an internal_transfer;
+// only the direction is public. The move_savings circuit reveals whether the
+// source side had the funds, and the callback aborts if it did not.
+
+/// Move USDC between the caller's trading balance and a savings bucket.
+///
+/// # Arguments
+/// * `computation_offset` - Unique ID for MPC computation
+/// * `encrypted_amount` - Amount encrypted with the owner's key
+/// * `pubkey` - Owner's x25519 public key
+/// * `nonce` - Encryption nonce
+/// * `to_bucket` - true moves into the bucket, false back to trading
+pub fn handler(
+    ctx: Context<MoveSavings>,
+    computation_offset: u64,
+    encrypted_amount: [u8; 32],
+    pubkey: [u8; 32],
+    nonce: u128,
+    to_bucket: bool,
+) -> Result<()> {
+    require!(!ctx.accounts.pool.paused, ErrorCode::ProtocolPaused);
+    // Set sign PDA bump
+    ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+    let user = &mut ctx.accounts.user_account;
+    user.last_active_at = Clock::get()?.unix_timestamp;
+
+    let bucket = &ctx.accounts.savings_bucket;
+    let args = ArgBuilder::new()
+        // TransferRequest (encrypted with the owner's key) - amount
+        .x25519_pubkey(pubkey)
+        .plaintext_u128(nonce)
+        .encrypted_u64(encrypted_amount)
+        // Trading USDC balance (Enc<Shared, *> - owner's key)
+        .x25519_pubkey(user.user_pubkey)
+        .plaintext_u128(user.get_nonce(UserProfile::ASSET_USDC))
+        .encrypted_u64(user.get_credit(UserProfile::ASSET_USDC))
+        // Bucket balance (Enc<Shared, *> - owner's key)
+        .x25519_pubkey(user.user_pubkey)
+        .plaintext_u128(bucket.nonce)
+        .encrypted_u64(bucket.encrypted_balance)
+        // Direction: 0 = into the bucket, 1 = back to trading
+        .plaintext_u8(if to_bucket { 0 } else { 1 })
+        .build();
+
+    use arcium_client::idl::arcium::types::CallbackAccount;
+    queue_computation(
+        ctx.accounts,
+        computation_offset,
+        args,
+        vec![MoveSavingsCallback::callback_ix(
+            computation_offset,
+            &ctx.accounts.mxe_account,
+            &[
+                CallbackAccount {
+                    pubkey: ctx.accounts.user_account.key(),
+                    is_writable: true,
+                },
+                CallbackAccount {
+                    pubkey: ctx.accounts.savings_bucket.key(),
+                    is_writable: true,
+                },
+                crate::event_cursor_callback_account(ctx.accounts.pool.pool_id),
+            ],
+        )?],
+        1,
+        0,
+    )?;
+
+    msg!(
+        "Savings move queued for {}: bucket {}, to_bucket={}, computation {}",
+        ctx.accounts.user_account.owner,
+        ctx.accounts.savings_bucket.bucket_id,
+        to_bucket,
+        computation_offset
+    );
+    Ok(())
+}
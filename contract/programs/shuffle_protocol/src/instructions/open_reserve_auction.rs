@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+use arcium_anchor::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::AuctionStatus;
+use crate::{InitAuctionStateCallback, OpenReserveAuction};
+
+// =============================================================================
+// OPEN RESERVE AUCTION - Authority lists excess reserve inventory
+// =============================================================================
+// Creates the ReserveAuction PDA and queues init_auction_state so the MPC
+// produces encrypted zeros the first bid can be folded into.
+// The auction becomes Open in the callback.
+
+/// Open a sealed-bid auction for a lot of reserve inventory.
+/// Only callable by the pool authority.
+///
+/// # Arguments
+/// * `computation_offset` - Unique ID for this MPC computation
+/// * `auction_id` - Auction identifier (PDA seed)
+/// * `asset_id` - Asset to sell (1=TSLA, 2=SPY, 3=AAPL); bids are in USDC
+/// * `lot_amount` - Amount of the asset being sold
+/// * `reserve_price` - Minimum total USDC price for the lot
+/// * `bid_deadline` - Unix timestamp after which bids are rejected
+pub fn handler(
+    ctx: Context<OpenReserveAuction>,
+    computation_offset: u64,
+    auction_id: u64,
+    asset_id: u8,
+    lot_amount: u64,
+    reserve_price: u64,
+    bid_deadline: i64,
+) -> Result<()> {
+    require!((1..=3).contains(&asset_id), ErrorCode::InvalidAssetId);
+    require!(lot_amount > 0, ErrorCode::InvalidAmount);
+    require!(
+        bid_deadline > Clock::get()?.unix_timestamp,
+        ErrorCode::AuctionBiddingClosed
+    );
+
+    let auction = &mut ctx.accounts.reserve_auction;
+    auction.auction_id = auction_id;
+    auction.asset_id = asset_id;
+    auction.lot_amount = lot_amount;
+    auction.reserve_price = reserve_price;
+    auction.bid_deadline = bid_deadline;
+    auction.status = AuctionStatus::Initializing;
+    auction.bid_count = 0;
+    auction.clearing_price = 0;
+    auction.winner = Pubkey::default();
+    auction.bump = ctx.bumps.reserve_auction;
+
+    ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+    // init_auction_state takes `mxe: Mxe` argument
+    let args = ArgBuilder::new()
+        .plaintext_u128(0) // Mxe nonce placeholder
+        .build();
+
+    use arcium_client::idl::arcium::types::CallbackAccount;
+    queue_computation(
+        ctx.accounts,
+        computation_offset,
+        args,
+        vec![InitAuctionStateCallback::callback_ix(
+            computation_offset,
+            &ctx.accounts.mxe_account,
+            &[
+                CallbackAccount {
+                    pubkey: ctx.accounts.reserve_auction.key(),
+                    is_writable: true,
+                },
+                crate::event_cursor_callback_account(ctx.accounts.pool.pool_id),
+            ],
+        )?],
+        1, // number of callbacks
+        0, // priority
+    )?;
+
+    msg!(
+        "Reserve auction {} opening: asset={}, lot={}, reserve_price={}, deadline={}",
+        auction_id,
+        asset_id,
+        lot_amount,
+        reserve_price,
+        bid_deadline
+    );
+
+    Ok(())
+}
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::{OverridePriceBand, PriceBandOverriddenEvent};
+
+// =============================================================================
+// OVERRIDE PRICE BAND - Accept a batch netted outside its price bands
+// =============================================================================
+// The reveal callback flags pairs whose execution price moved more than
+// their band from the last accepted price (BatchLog.price_band_breaches).
+// execute_swaps and route_surplus, and so settlement, wait until the
+// authority accepts the prices here. Until then the batch's tokens stay in
+// the vaults and reserves untouched; the callback has already moved the
+// netting buffers, so a revealed batch cannot be refunded instead.
+//
+// Accepting makes the batch's prices the new reference of the breached
+// pairs, so later batches are banded around them.
+
+/// Accept a batch's out-of-band prices. Only callable by an admin or risk manager.
+///
+/// # Arguments
+/// * `batch_id` - The batch ID to accept (for verification)
+pub fn handler(ctx: Context<OverridePriceBand>, batch_id: u64) -> Result<()> {
+    let batch_log = &mut ctx.accounts.batch_log;
+    require!(batch_log.batch_id == batch_id, ErrorCode::InvalidBatchId);
+    require!(batch_log.executed_at > 0, ErrorCode::BatchNotFinalized);
+    require!(!batch_log.swaps_executed, ErrorCode::SwapsAlreadyExecuted);
+    require!(
+        batch_log.awaiting_price_override(),
+        ErrorCode::PriceBandNotBreached
+    );
+
+    let authority = ctx.accounts.authority.key();
+    batch_log.price_band_overridden_by = authority;
+
+    let pool = &mut ctx.accounts.pool;
+    for (pair_id, result) in batch_log.results.iter().enumerate() {
+        if batch_log.price_band_breaches & (1 << pair_id) != 0 {
+            pool.last_execution_prices[pair_id] = result.execution_price;
+        }
+    }
+
+    emit!(PriceBandOverriddenEvent {
+        batch_id,
+        authority,
+        breached_pairs: batch_log.price_band_breaches,
+        seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+    });
+
+    msg!(
+        "Price band overridden: batch {}, pairs {:#08b}",
+        batch_id,
+        batch_log.price_band_breaches
+    );
+    Ok(())
+}
@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::{DcaPausedEvent, PauseDca};
+
+/// Pause a DCA schedule.
+/// The schedule PDA and its encrypted order are kept; execute_dca rejects
+/// the schedule until resume_dca is called.
+pub fn handler(ctx: Context<PauseDca>) -> Result<()> {
+    let dca = &mut ctx.accounts.dca_schedule;
+    require!(!dca.paused, ErrorCode::DcaPaused);
+
+    dca.paused = true;
+
+    emit!(DcaPausedEvent {
+        owner: dca.owner,
+        dca_id: dca.dca_id,
+        paused_at: Clock::get()?.unix_timestamp,
+        seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+    });
+
+    msg!("DCA {} paused for {}", dca.dca_id, dca.owner);
+    Ok(())
+}
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::{PauseProtocol, ProtocolPauseUpdatedEvent};
+
+// =============================================================================
+// PAUSE PROTOCOL - Incident halt
+// =============================================================================
+// While Pool.paused is set, deposits, orders (staged ones and
+// settle_and_place_order included), withdrawals, transfers, transfer and
+// voucher claims, streams, voucher creation, savings moves, recovery and
+// inheritance claims, and execute_batch fail with ProtocolPaused. Batches
+// already executed can still be swapped and settled with settle_order,
+// expired transfer requests and vouchers can still be reclaimed, and
+// callbacks of computations queued before the pause still land. An admin can keep withdrawals open during
+// the pause with set_emergency_withdraw.
+//
+// Pausers (and admins) can pause; only admins can unpause_protocol, so a
+// leaked pauser key can halt the protocol but not resume it.
+
+/// Pause the protocol. Only callable by a pauser or admin.
+pub fn handler(ctx: Context<PauseProtocol>) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+    pool.paused = true;
+
+    emit!(ProtocolPauseUpdatedEvent {
+        paused: true,
+        updated_by: ctx.accounts.authority.key(),
+        seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+    });
+
+    msg!("Protocol paused by {}", ctx.accounts.authority.key());
+    Ok(())
+}
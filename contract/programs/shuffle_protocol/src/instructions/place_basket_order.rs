@@ -0,0 +1,169 @@
+use anchor_lang::prelude::*;
+use arcium_anchor::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::instructions::create_delegate::{authorize_owner_or_delegate, require_no_allowance};
+use crate::state::{BASKET_LEGS, BASKET_STATUS_EMPTY, BASKET_STATUS_QUEUED};
+use crate::{AccumulateBasketCallback, PlaceBasketOrder};
+
+// =============================================================================
+// PLACE BASKET ORDER - One encrypted order split across several pairs
+// =============================================================================
+// The user encrypts a BasketInput (per-leg pair, direction and weight plus
+// the total amount) for the accumulate_basket circuit, which deducts the
+// amount and adds each leg's share to its pair accumulator atomically.
+//
+// Flow:
+// 1. User calls place_basket_order; BasketOrder is marked QUEUED
+// 2. Callback stores the three encrypted legs and marks the basket PLACED
+// 3. After batch execution, each leg is settled with settle_basket_leg
+//
+// Baskets don't use UserProfile.pending_order, so they can coexist with a
+// regular order; BatchAccumulator.basket_count keeps the privacy floor from
+// counting such a wallet twice. force_settle / forfeit_order don't cover basket legs.
+
+/// Place an encrypted basket order in the current batch.
+///
+/// # Arguments
+/// * `computation_offset` - Unique ID for this MPC computation
+/// * `encrypted_pair_ids` - Pair ID (0-5) of each leg, encrypted
+/// * `encrypted_directions` - Direction of each leg, encrypted
+/// * `encrypted_weights_bps` - Weight of each leg in bps (sum 10_000), encrypted
+/// * `encrypted_amount` - Total amount of the source asset, encrypted
+/// * `pubkey` - User's x25519 public key for encryption
+/// * `nonce` - Encryption nonce for the basket input
+/// * `source_asset_id` - Plaintext hint: asset sold by every leg
+pub fn handler(
+    ctx: Context<PlaceBasketOrder>,
+    computation_offset: u64,
+    encrypted_pair_ids: [[u8; 32]; BASKET_LEGS],
+    encrypted_directions: [[u8; 32]; BASKET_LEGS],
+    encrypted_weights_bps: [[u8; 32]; BASKET_LEGS],
+    encrypted_amount: [u8; 32],
+    pubkey: [u8; 32],
+    nonce: u128,
+    source_asset_id: u8,
+) -> Result<()> {
+    require!(!ctx.accounts.pool.paused, ErrorCode::ProtocolPaused);
+    require!(source_asset_id <= 3, ErrorCode::InvalidAssetId);
+    require!(
+        !ctx.accounts.pool.is_asset_frozen(source_asset_id),
+        ErrorCode::AssetFrozen
+    );
+    require!(
+        !ctx.accounts.pool.is_asset_delisted(source_asset_id),
+        ErrorCode::AssetDelisted
+    );
+    require!(
+        ctx.accounts.batch_accumulator.is_open(),
+        ErrorCode::BatchRevealInProgress
+    );
+
+    let batch_id = ctx.accounts.batch_accumulator.batch_id;
+    authorize_owner_or_delegate(
+        ctx.accounts.user_account.owner,
+        ctx.accounts.user.key(),
+        ctx.accounts.delegate.as_deref_mut(),
+        Some(batch_id),
+    )?;
+    require_no_allowance(
+        ctx.accounts.user_account.owner,
+        ctx.accounts.user.key(),
+        ctx.accounts.delegate.as_deref(),
+        source_asset_id,
+    )?;
+
+    let basket = &mut ctx.accounts.basket_order;
+    require!(
+        basket.status == BASKET_STATUS_EMPTY,
+        ErrorCode::BasketOrderActive
+    );
+    basket.owner = ctx.accounts.user_account.owner;
+    basket.batch_id = batch_id;
+    basket.source_asset_id = source_asset_id;
+    basket.status = BASKET_STATUS_QUEUED;
+    basket.legs = Default::default();
+    basket.settled_legs = 0;
+    basket.settling_leg = 0;
+    basket.bump = ctx.bumps.basket_order;
+
+    ctx.accounts.user_account.last_active_at = Clock::get()?.unix_timestamp;
+
+    // Set sign PDA bump
+    ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+    let current_balance = ctx.accounts.user_account.get_credit(source_asset_id);
+    let current_nonce = ctx.accounts.user_account.get_nonce(source_asset_id);
+    ctx.accounts.user_account.pending_basket_nonce = current_nonce;
+
+    // BasketInput fields are encrypted together, in struct order:
+    // pair_ids[0..3], directions[0..3], weights_bps[0..3], amount
+    let mut builder = ArgBuilder::new()
+        .x25519_pubkey(pubkey)
+        .plaintext_u128(nonce);
+    for pair_id in encrypted_pair_ids {
+        builder = builder.encrypted_u8(pair_id);
+    }
+    for direction in encrypted_directions {
+        builder = builder.encrypted_u8(direction);
+    }
+    for weight in encrypted_weights_bps {
+        builder = builder.encrypted_u64(weight);
+    }
+    let args = builder
+        .encrypted_u64(encrypted_amount)
+        // UserBalance (Enc<Shared>) - current balance of source asset
+        .x25519_pubkey(pubkey)
+        .plaintext_u128(current_nonce)
+        .encrypted_u64(current_balance)
+        // BatchState (Enc<Mxe>) - read from batch accumulator account
+        .plaintext_u128(ctx.accounts.batch_accumulator.mxe_nonce)
+        .account(
+            ctx.accounts.batch_accumulator.key(),
+            8 + 8 + 1, // Skip discriminator(8) + batch_id(8) + order_count(1)
+            6 * 64,    // 12 ciphertexts × 32 bytes = 384 bytes (pairs only)
+        )
+        .plaintext_u8(ctx.accounts.batch_accumulator.order_count)
+        // Batch trigger thresholds
+        .plaintext_u8(ctx.accounts.pool.execution_trigger_count)
+        .plaintext_u8(ctx.accounts.pool.min_active_pairs)
+        .build();
+
+    use arcium_client::idl::arcium::types::CallbackAccount;
+    queue_computation(
+        ctx.accounts,
+        computation_offset,
+        args,
+        vec![AccumulateBasketCallback::callback_ix(
+            computation_offset,
+            &ctx.accounts.mxe_account,
+            &[
+                CallbackAccount {
+                    pubkey: ctx.accounts.user_account.key(),
+                    is_writable: true,
+                },
+                CallbackAccount {
+                    pubkey: ctx.accounts.batch_accumulator.key(),
+                    is_writable: true,
+                },
+                CallbackAccount {
+                    pubkey: ctx.accounts.basket_order.key(),
+                    is_writable: true,
+                },
+                crate::event_cursor_callback_account(ctx.accounts.pool.pool_id),
+            ],
+        )?],
+        1,                                         // number of callbacks
+        ctx.accounts.pool.callback_cu_price_micro, // priority
+    )?;
+
+    msg!(
+        "Basket order placed: user={}, batch={}, asset={}, computation={}",
+        ctx.accounts.user_account.owner,
+        batch_id,
+        source_asset_id,
+        computation_offset
+    );
+
+    Ok(())
+}
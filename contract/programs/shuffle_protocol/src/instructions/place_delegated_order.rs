@@ -0,0 +1,159 @@
+use anchor_lang::prelude::*;
+use arcium_anchor::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::instructions::create_delegate::authorize_owner_or_delegate;
+use crate::state::OrderTicket;
+use crate::{AccumulateOrderDelegatedCallback, PlaceDelegatedOrder};
+
+// =============================================================================
+// PLACE DELEGATED ORDER - Order drawn against a delegate allowance
+// =============================================================================
+// Placed by a delegate (bot, third-party program) for an asset the owner
+// capped with set_delegate_allowance. The delegate encrypts the order with
+// its own x25519 key; the accumulate_order_delegated circuit checks it
+// against the owner's balance and remaining allowance, decrements both and
+// returns the order re-encrypted for the owner.
+//
+// The callback replaces the pending OrderTicket with that re-encrypted order,
+// so settlement (by the owner or the delegate, passing the owner's
+// user_pubkey) credits a balance only the owner can decrypt. An order above
+// the allowance is released without touching balance, allowance or batch.
+
+/// Place an encrypted order as a delegate, drawing on its allowance.
+///
+/// # Arguments
+/// * `computation_offset` - Unique ID for this MPC computation
+/// * `encrypted_pair_id` - Pair ID (0-5) encrypted with the delegate's key
+/// * `encrypted_direction` - Direction (0=A_to_B, 1=B_to_A) encrypted with the delegate's key
+/// * `encrypted_amount` - Order amount encrypted with the delegate's key
+/// * `pubkey` - Delegate's x25519 public key for the order
+/// * `nonce` - Encryption nonce for the order input
+/// * `source_asset_id` - Plaintext hint: which asset is being sold (0=USDC, 1=TSLA, 2=SPY, 3=AAPL)
+/// * `valid_until_batch` - Last batch the order may roll over into (0 = this batch only)
+pub fn handler(
+    ctx: Context<PlaceDelegatedOrder>,
+    computation_offset: u64,
+    encrypted_pair_id: [u8; 32],
+    encrypted_direction: [u8; 32],
+    encrypted_amount: [u8; 32],
+    pubkey: [u8; 32],
+    nonce: u128,
+    source_asset_id: u8,
+    valid_until_batch: u64,
+) -> Result<()> {
+    require!(!ctx.accounts.pool.paused, ErrorCode::ProtocolPaused);
+    require!(source_asset_id <= 3, ErrorCode::InvalidAssetId);
+    require!(
+        !ctx.accounts.pool.is_asset_frozen(source_asset_id),
+        ErrorCode::AssetFrozen
+    );
+    require!(
+        !ctx.accounts.pool.is_asset_delisted(source_asset_id),
+        ErrorCode::AssetDelisted
+    );
+    require!(
+        ctx.accounts.batch_accumulator.is_open(),
+        ErrorCode::BatchRevealInProgress
+    );
+    let batch_id = ctx.accounts.batch_accumulator.batch_id;
+    require!(
+        valid_until_batch == 0 || valid_until_batch >= batch_id,
+        ErrorCode::InvalidOrderValidity
+    );
+    require!(
+        ctx.accounts.delegate.has_allowance(source_asset_id),
+        ErrorCode::NoDelegateAllowance
+    );
+    authorize_owner_or_delegate(
+        ctx.accounts.user_account.owner,
+        ctx.accounts.user.key(),
+        Some(&mut *ctx.accounts.delegate),
+        Some(batch_id),
+    )?;
+
+    // Placeholder ticket until the callback stores the owner's copy
+    ctx.accounts.user_account.pending_order = Some(OrderTicket {
+        batch_id,
+        pair_id: encrypted_pair_id,
+        direction: encrypted_direction,
+        encrypted_amount,
+        order_nonce: nonce,
+        valid_until_batch,
+    });
+    ctx.accounts.user_account.pending_asset_id = source_asset_id;
+    ctx.accounts.user_account.staged_order = None;
+
+    ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+    // Balance and allowance are encrypted for the owner's stored key
+    let owner_pubkey = ctx.accounts.user_account.user_pubkey;
+    let asset = source_asset_id as usize;
+    let current_nonce = ctx.accounts.user_account.get_nonce(source_asset_id);
+    ctx.accounts.user_account.pending_order_nonce = current_nonce;
+    let args = ArgBuilder::new()
+        // OrderInput (Enc<Shared>) - encrypted by the delegate
+        .x25519_pubkey(pubkey)
+        .plaintext_u128(nonce)
+        .encrypted_u8(encrypted_pair_id)
+        .encrypted_u8(encrypted_direction)
+        .encrypted_u64(encrypted_amount)
+        // UserBalance (Enc<Shared>) - owner's balance of the source asset
+        .x25519_pubkey(owner_pubkey)
+        .plaintext_u128(current_nonce)
+        .encrypted_u64(ctx.accounts.user_account.get_credit(source_asset_id))
+        // Allowance (Enc<Shared>) - owner's remaining allowance for the asset
+        .x25519_pubkey(owner_pubkey)
+        .plaintext_u128(ctx.accounts.delegate.allowance_nonces[asset])
+        .encrypted_u64(ctx.accounts.delegate.allowances[asset])
+        // BatchState (Enc<Mxe>)
+        .plaintext_u128(ctx.accounts.batch_accumulator.mxe_nonce)
+        .account(
+            ctx.accounts.batch_accumulator.key(),
+            8 + 8 + 1, // Skip discriminator(8) + batch_id(8) + order_count(1)
+            6 * 64,    // 12 ciphertexts × 32 bytes = 384 bytes (pairs only)
+        )
+        .plaintext_u8(ctx.accounts.batch_accumulator.order_count)
+        .plaintext_u8(ctx.accounts.pool.execution_trigger_count)
+        .plaintext_u8(ctx.accounts.pool.min_active_pairs)
+        .build();
+
+    use arcium_client::idl::arcium::types::CallbackAccount;
+    queue_computation(
+        ctx.accounts,
+        computation_offset,
+        args,
+        vec![AccumulateOrderDelegatedCallback::callback_ix(
+            computation_offset,
+            &ctx.accounts.mxe_account,
+            &[
+                CallbackAccount {
+                    pubkey: ctx.accounts.user_account.key(),
+                    is_writable: true,
+                },
+                CallbackAccount {
+                    pubkey: ctx.accounts.batch_accumulator.key(),
+                    is_writable: true,
+                },
+                crate::event_cursor_callback_account(ctx.accounts.pool.pool_id),
+                CallbackAccount {
+                    pubkey: ctx.accounts.delegate.key(),
+                    is_writable: true,
+                },
+            ],
+        )?],
+        1,
+        ctx.accounts.pool.callback_cu_price_micro,
+    )?;
+
+    msg!(
+        "Delegated order placed: user={}, delegate={}, batch={}, asset={}, computation={}",
+        ctx.accounts.user_account.owner,
+        ctx.accounts.user.key(),
+        batch_id,
+        source_asset_id,
+        computation_offset
+    );
+
+    Ok(())
+}
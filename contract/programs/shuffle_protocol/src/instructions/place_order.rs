@@ -2,6 +2,7 @@ use anchor_lang::prelude::*;
 use arcium_anchor::prelude::*;
 
 use crate::errors::ErrorCode;
+use crate::instructions::create_delegate::{authorize_owner_or_delegate, require_no_allowance};
 use crate::{AccumulateOrderCallback, PlaceOrder};
 
 // =============================================================================
@@ -18,6 +19,9 @@ use crate::{AccumulateOrderCallback, PlaceOrder};
 // 4. Callback receives updated balance + batch state from MPC
 // 5. Callback updates batch accumulator and checks auto-trigger conditions
 //
+// The signer may be the owner or a session key with an active Delegate;
+// delegated orders count against the delegate's per-batch limit. A delegate
+// with an allowance for the source asset must use place_delegated_order.
 
 /// Place an encrypted order in the current batch.
 /// Stores OrderTicket and queues MPC computation.
@@ -30,6 +34,7 @@ use crate::{AccumulateOrderCallback, PlaceOrder};
 /// * `pubkey` - User's x25519 public key for encryption
 /// * `nonce` - Encryption nonce for the order input
 /// * `source_asset_id` - Plaintext hint: which asset is being sold (0=USDC, 1=TSLA, 2=SPY, 3=AAPL)
+/// * `valid_until_batch` - Last batch the order may roll over into (0 = this batch only)
 pub fn handler(
     ctx: Context<PlaceOrder>,
     computation_offset: u64,
@@ -39,9 +44,19 @@ pub fn handler(
     pubkey: [u8; 32],
     nonce: u128,
     source_asset_id: u8,
+    valid_until_batch: u64,
 ) -> Result<()> {
+    require!(!ctx.accounts.pool.paused, ErrorCode::ProtocolPaused);
     // Validate asset_id
     require!(source_asset_id <= 3, ErrorCode::InvalidAssetId);
+    require!(
+        !ctx.accounts.pool.is_asset_frozen(source_asset_id),
+        ErrorCode::AssetFrozen
+    );
+    require!(
+        !ctx.accounts.pool.is_asset_delisted(source_asset_id),
+        ErrorCode::AssetDelisted
+    );
 
     // Validate no pending order exists (ensured by account constraint, but double-check)
     require!(
@@ -49,19 +64,45 @@ pub fn handler(
         ErrorCode::PendingOrderExists
     );
 
+    require!(
+        ctx.accounts.batch_accumulator.is_open(),
+        ErrorCode::BatchRevealInProgress
+    );
+    let batch_id = ctx.accounts.batch_accumulator.batch_id;
+    require!(
+        valid_until_batch == 0 || valid_until_batch >= batch_id,
+        ErrorCode::InvalidOrderValidity
+    );
+    authorize_owner_or_delegate(
+        ctx.accounts.user_account.owner,
+        ctx.accounts.user.key(),
+        ctx.accounts.delegate.as_deref_mut(),
+        Some(batch_id),
+    )?;
+    require_no_allowance(
+        ctx.accounts.user_account.owner,
+        ctx.accounts.user.key(),
+        ctx.accounts.delegate.as_deref(),
+        source_asset_id,
+    )?;
+
     // Store OrderTicket in user's pending_order
     use crate::state::OrderTicket;
-    let batch_id = ctx.accounts.batch_accumulator.batch_id;
     ctx.accounts.user_account.pending_order = Some(OrderTicket {
         batch_id,
         pair_id: encrypted_pair_id,
         direction: encrypted_direction,
         encrypted_amount,
         order_nonce: nonce,
+        valid_until_batch,
     });
 
     // Store source_asset_id for callback to know which balance to update
     ctx.accounts.user_account.pending_asset_id = source_asset_id;
+    ctx.accounts.user_account.last_active_at = Clock::get()?.unix_timestamp;
+
+    // An explicit order supersedes one staged by settle_and_place_order
+    ctx.accounts.user_account.staged_order = None;
 
     // Set sign PDA bump
     ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
@@ -73,6 +114,7 @@ pub fn handler(
 
     let current_balance = ctx.accounts.user_account.get_credit(source_asset_id);
     let current_nonce = ctx.accounts.user_account.get_nonce(source_asset_id);
+    ctx.accounts.user_account.pending_order_nonce = current_nonce;
 
     let args = ArgBuilder::new()
         // OrderInput (Enc<Shared>) - encrypted by user
@@ -94,6 +136,9 @@ pub fn handler(
         )
         // order_count passed as plaintext input for batch_ready calculation
         .plaintext_u8(ctx.accounts.batch_accumulator.order_count)
+        // Batch trigger thresholds
+        .plaintext_u8(ctx.accounts.pool.execution_trigger_count)
+        .plaintext_u8(ctx.accounts.pool.min_active_pairs)
         .build();
 
     // Queue MPC computation with callback
@@ -114,15 +159,17 @@ pub fn handler(
                     pubkey: ctx.accounts.batch_accumulator.key(),
                     is_writable: true,
                 },
+                crate::optional_callback_account(None, true), // savings_bucket
+                crate::event_cursor_callback_account(ctx.accounts.pool.pool_id),
             ],
         )?],
-        1, // number of callbacks
-        0, // priority
+        1,                                         // number of callbacks
+        ctx.accounts.pool.callback_cu_price_micro, // priority
     )?;
 
     msg!(
         "Order placed: user={}, batch={}, asset={}, computation={}",
-        ctx.accounts.user.key(),
+        ctx.accounts.user_account.owner,
         batch_id,
         source_asset_id,
         computation_offset
@@ -0,0 +1,203 @@
+use anchor_lang::prelude::*;
+use arcium_anchor::prelude::*;
+
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::instructions::create_delegate::{authorize_owner_or_delegate, require_no_allowance};
+use crate::oracle::{read_asset_price, AssetPrice};
+use crate::state::OrderTicket;
+use crate::{AccumulateOrderUsdCallback, PlaceOrderUsd};
+
+// =============================================================================
+// PLACE ORDER USD - Order sized by USD notional
+// =============================================================================
+// Same as place_order, except the encrypted amount is a USDC notional
+// (6 decimals). The accumulate_order_usd circuit converts it to source asset
+// units at the plaintext oracle price, deducts that from the balance and
+// returns the sized order re-encrypted for the user. The price is read the
+// way execute_batch reads it (oracle::read_asset_price), so the order is
+// sized at the source the batch nets at; the mock price applies only when
+// no oracle is configured for the asset.
+//
+// The callback replaces the pending OrderTicket with the sized order, so
+// settlement and the user's decrypted order both show the real asset amount.
+// Selling USDC converts 1:1.
+
+/// Place an encrypted order sized in USD in the current batch.
+///
+/// # Arguments
+/// * `computation_offset` - Unique ID for this MPC computation
+/// * `encrypted_pair_id` - Pair ID (0-5) encrypted with user's key
+/// * `encrypted_direction` - Direction (0=A_to_B, 1=B_to_A) encrypted with user's key
+/// * `encrypted_notional` - USDC notional (6 decimals) encrypted with user's key
+/// * `pubkey` - User's x25519 public key for encryption
+/// * `nonce` - Encryption nonce for the order input
+/// * `source_asset_id` - Plaintext hint: which asset is being sold (0=USDC, 1=TSLA, 2=SPY, 3=AAPL)
+/// * `valid_until_batch` - Last batch the order may roll over into (0 = this batch only)
+pub fn handler(
+    ctx: Context<PlaceOrderUsd>,
+    computation_offset: u64,
+    encrypted_pair_id: [u8; 32],
+    encrypted_direction: [u8; 32],
+    encrypted_notional: [u8; 32],
+    pubkey: [u8; 32],
+    nonce: u128,
+    source_asset_id: u8,
+    valid_until_batch: u64,
+) -> Result<()> {
+    require!(!ctx.accounts.pool.paused, ErrorCode::ProtocolPaused);
+    require!(source_asset_id <= 3, ErrorCode::InvalidAssetId);
+    require!(
+        !ctx.accounts.pool.is_asset_frozen(source_asset_id),
+        ErrorCode::AssetFrozen
+    );
+    require!(
+        !ctx.accounts.pool.is_asset_delisted(source_asset_id),
+        ErrorCode::AssetDelisted
+    );
+    require!(
+        ctx.accounts.user_account.pending_order.is_none(),
+        ErrorCode::PendingOrderExists
+    );
+    require!(
+        ctx.accounts.batch_accumulator.is_open(),
+        ErrorCode::BatchRevealInProgress
+    );
+    let batch_id = ctx.accounts.batch_accumulator.batch_id;
+    require!(
+        valid_until_batch == 0 || valid_until_batch >= batch_id,
+        ErrorCode::InvalidOrderValidity
+    );
+    authorize_owner_or_delegate(
+        ctx.accounts.user_account.owner,
+        ctx.accounts.user.key(),
+        ctx.accounts.delegate.as_deref_mut(),
+        Some(batch_id),
+    )?;
+    require_no_allowance(
+        ctx.accounts.user_account.owner,
+        ctx.accounts.user.key(),
+        ctx.accounts.delegate.as_deref(),
+        source_asset_id,
+    )?;
+
+    // Price used for the conversion, passed to MPC in plaintext. The circuit
+    // computes notional * PRICE_SCALE / price (PRICE_SCALE = 10^6), so the
+    // per-whole-token price is rescaled by 10^decimals to yield base units of
+    // the source mint. For mints above 6 decimals the rescaled price is
+    // truncated, a relative error under 1 / price.
+    let usd_price = source_price(&ctx, source_asset_id)?;
+    let decimals = ctx.accounts.pool.asset_decimals[source_asset_id as usize] as u32;
+    let price = (usd_price as u128 * 1_000_000 / 10u128.pow(decimals)).max(1) as u64;
+
+    // Placeholder ticket until the callback stores the sized order
+    ctx.accounts.user_account.pending_order = Some(OrderTicket {
+        batch_id,
+        pair_id: encrypted_pair_id,
+        direction: encrypted_direction,
+        encrypted_amount: encrypted_notional,
+        order_nonce: nonce,
+        valid_until_batch,
+    });
+    ctx.accounts.user_account.pending_asset_id = source_asset_id;
+    ctx.accounts.user_account.last_active_at = Clock::get()?.unix_timestamp;
+
+    // An explicit order supersedes one staged by settle_and_place_order
+    ctx.accounts.user_account.staged_order = None;
+
+    // Set sign PDA bump
+    ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+    let current_balance = ctx.accounts.user_account.get_credit(source_asset_id);
+    let current_nonce = ctx.accounts.user_account.get_nonce(source_asset_id);
+    ctx.accounts.user_account.pending_order_nonce = current_nonce;
+
+    let args = ArgBuilder::new()
+        // OrderInput (Enc<Shared>) - amount is the USDC notional
+        .x25519_pubkey(pubkey)
+        .plaintext_u128(nonce)
+        .encrypted_u8(encrypted_pair_id)
+        .encrypted_u8(encrypted_direction)
+        .encrypted_u64(encrypted_notional)
+        // UserBalance (Enc<Shared>) - current balance of source asset
+        .x25519_pubkey(pubkey)
+        .plaintext_u128(current_nonce)
+        .encrypted_u64(current_balance)
+        // BatchState (Enc<Mxe>) - read from batch accumulator account
+        .plaintext_u128(ctx.accounts.batch_accumulator.mxe_nonce)
+        .account(
+            ctx.accounts.batch_accumulator.key(),
+            8 + 8 + 1, // Skip discriminator(8) + batch_id(8) + order_count(1)
+            6 * 64,    // 12 ciphertexts × 32 bytes = 384 bytes (pairs only)
+        )
+        .plaintext_u8(ctx.accounts.batch_accumulator.order_count)
+        // Batch trigger thresholds
+        .plaintext_u8(ctx.accounts.pool.execution_trigger_count)
+        .plaintext_u8(ctx.accounts.pool.min_active_pairs)
+        // Oracle price of the source asset
+        .plaintext_u64(price)
+        .build();
+
+    use arcium_client::idl::arcium::types::CallbackAccount;
+    queue_computation(
+        ctx.accounts,
+        computation_offset,
+        args,
+        vec![AccumulateOrderUsdCallback::callback_ix(
+            computation_offset,
+            &ctx.accounts.mxe_account,
+            &[
+                CallbackAccount {
+                    pubkey: ctx.accounts.user_account.key(),
+                    is_writable: true,
+                },
+                CallbackAccount {
+                    pubkey: ctx.accounts.batch_accumulator.key(),
+                    is_writable: true,
+                },
+                crate::event_cursor_callback_account(ctx.accounts.pool.pool_id),
+            ],
+        )?],
+        1,                                         // number of callbacks
+        ctx.accounts.pool.callback_cu_price_micro, // priority
+    )?;
+
+    msg!(
+        "USD order placed: user={}, batch={}, asset={}, price={}, computation={}",
+        ctx.accounts.user_account.owner,
+        batch_id,
+        source_asset_id,
+        price,
+        computation_offset
+    );
+
+    Ok(())
+}
+
+/// USDC per whole token of the source asset (6 decimals).
+///
+/// Fails rather than falling back to the mock price when the configured
+/// sources are stale, too uncertain or diverge: execute_batch would halt on
+/// the same prices.
+fn source_price(ctx: &Context<PlaceOrderUsd>, source_asset_id: u8) -> Result<u64> {
+    let asset = source_asset_id as usize;
+    if !ctx.accounts.pool.oracle_configured {
+        return Ok(MOCK_ORACLE_PRICES[asset]);
+    }
+    let oracle = ctx
+        .accounts
+        .oracle_config
+        .as_deref()
+        .ok_or(ErrorCode::OracleConfigMissing)?;
+    match read_asset_price(
+        oracle,
+        asset,
+        ctx.accounts.price_update.as_deref(),
+        ctx.accounts.fallback_feed.as_deref(),
+        Clock::get()?.unix_timestamp,
+    )? {
+        AssetPrice::Unconfigured => Ok(MOCK_ORACLE_PRICES[asset]),
+        AssetPrice::Price(price) => Ok(price.price),
+        AssetPrice::Diverged { .. } => err!(ErrorCode::PriceDivergence),
+    }
+}
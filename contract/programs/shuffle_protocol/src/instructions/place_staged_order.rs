@@ -0,0 +1,136 @@
+use anchor_lang::prelude::*;
+use arcium_anchor::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::OrderTicket;
+use crate::{AccumulateOrderCallback, OrderExpiredEvent, PlaceStagedOrder};
+
+// =============================================================================
+// PLACE STAGED ORDER - Crank an order staged by settle_and_place_order
+// =============================================================================
+// Permissionless. Runs once the previous order has settled (pending_order is
+// None) and places the staged order into the current batch exactly like
+// place_order, reusing the accumulate_order circuit and callback.
+//
+// Also places orders rolled over by settle_order (good-til-batch orders),
+// dropping any whose valid_until_batch has passed.
+
+/// Place the user's staged order into the current batch.
+///
+/// # Arguments
+/// * `computation_offset` - Unique ID for this MPC computation
+pub fn handler(ctx: Context<PlaceStagedOrder>, computation_offset: u64) -> Result<()> {
+    require!(!ctx.accounts.pool.paused, ErrorCode::ProtocolPaused);
+    let staged = ctx
+        .accounts
+        .user_account
+        .staged_order
+        .ok_or(ErrorCode::NoStagedOrder)?;
+    let source_asset_id = ctx.accounts.user_account.staged_asset_id;
+
+    require!(
+        ctx.accounts.batch_accumulator.is_open(),
+        ErrorCode::BatchRevealInProgress
+    );
+    let batch_id = ctx.accounts.batch_accumulator.batch_id;
+
+    // Past its validity the order is dropped; its amount was already
+    // refunded to the source balance when the previous order settled
+    if !staged.is_valid_for(batch_id) {
+        ctx.accounts.user_account.staged_order = None;
+        emit!(OrderExpiredEvent {
+            user: ctx.accounts.user_account.owner,
+            batch_id,
+            valid_until_batch: staged.valid_until_batch,
+            seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+        });
+        msg!(
+            "Staged order expired: user={}, valid_until_batch={}",
+            ctx.accounts.user_account.owner,
+            staged.valid_until_batch
+        );
+        return Ok(());
+    }
+
+    require!(
+        !ctx.accounts.pool.is_asset_frozen(source_asset_id),
+        ErrorCode::AssetFrozen
+    );
+    require!(
+        !ctx.accounts.pool.is_asset_delisted(source_asset_id),
+        ErrorCode::AssetDelisted
+    );
+
+    // Move the staged order into pending_order for the current batch
+    ctx.accounts.user_account.pending_order = Some(OrderTicket { batch_id, ..staged });
+    ctx.accounts.user_account.pending_asset_id = source_asset_id;
+    ctx.accounts.user_account.staged_order = None;
+
+    // Set sign PDA bump
+    ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+    let pubkey = ctx.accounts.user_account.user_pubkey;
+    let current_balance = ctx.accounts.user_account.get_credit(source_asset_id);
+    let current_nonce = ctx.accounts.user_account.get_nonce(source_asset_id);
+    ctx.accounts.user_account.pending_order_nonce = current_nonce;
+
+    // Same argument layout as place_order (accumulate_order circuit)
+    let args = ArgBuilder::new()
+        // OrderInput (Enc<Shared>) - encrypted by user when staging
+        .x25519_pubkey(pubkey)
+        .plaintext_u128(staged.order_nonce)
+        .encrypted_u8(staged.pair_id)
+        .encrypted_u8(staged.direction)
+        .encrypted_u64(staged.encrypted_amount)
+        // UserBalance (Enc<Shared>) - current balance of source asset
+        .x25519_pubkey(pubkey)
+        .plaintext_u128(current_nonce)
+        .encrypted_u64(current_balance)
+        // BatchState (Enc<Mxe>) - read from batch accumulator account
+        .plaintext_u128(ctx.accounts.batch_accumulator.mxe_nonce)
+        .account(
+            ctx.accounts.batch_accumulator.key(),
+            8 + 8 + 1, // Skip discriminator(8) + batch_id(8) + order_count(1)
+            6 * 64,    // 12 ciphertexts × 32 bytes = 384 bytes (pairs only)
+        )
+        .plaintext_u8(ctx.accounts.batch_accumulator.order_count)
+        // Batch trigger thresholds
+        .plaintext_u8(ctx.accounts.pool.execution_trigger_count)
+        .plaintext_u8(ctx.accounts.pool.min_active_pairs)
+        .build();
+
+    use arcium_client::idl::arcium::types::CallbackAccount;
+    queue_computation(
+        ctx.accounts,
+        computation_offset,
+        args,
+        vec![AccumulateOrderCallback::callback_ix(
+            computation_offset,
+            &ctx.accounts.mxe_account,
+            &[
+                CallbackAccount {
+                    pubkey: ctx.accounts.user_account.key(),
+                    is_writable: true,
+                },
+                CallbackAccount {
+                    pubkey: ctx.accounts.batch_accumulator.key(),
+                    is_writable: true,
+                },
+                crate::optional_callback_account(None, true), // savings_bucket
+                crate::event_cursor_callback_account(ctx.accounts.pool.pool_id),
+            ],
+        )?],
+        1,                                         // number of callbacks
+        ctx.accounts.pool.callback_cu_price_micro, // priority
+    )?;
+
+    msg!(
+        "Staged order placed: user={}, batch={}, asset={}, computation={}",
+        ctx.accounts.user_account.owner,
+        batch_id,
+        source_asset_id,
+        computation_offset
+    );
+
+    Ok(())
+}
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::{AuthorityProposedEvent, ProposeAuthority};
+
+// =============================================================================
+// PROPOSE AUTHORITY - First step of an authority handover
+// =============================================================================
+// The authority only changes once the proposed wallet signs accept_authority,
+// so a mistyped key leaves the current authority in place instead of
+// locking every admin instruction. A new proposal replaces the pending one;
+// proposing the default address cancels it.
+
+/// Propose a new pool authority. Only callable by pool authority.
+///
+/// # Arguments
+/// * `new_authority` - Wallet that must accept (default = cancel)
+pub fn handler(ctx: Context<ProposeAuthority>, new_authority: Pubkey) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+    pool.pending_authority = new_authority;
+
+    emit!(AuthorityProposedEvent {
+        authority: pool.authority,
+        pending_authority: new_authority,
+        seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+    });
+
+    msg!(
+        "Authority transfer proposed: {} -> {}",
+        pool.authority,
+        new_authority
+    );
+    Ok(())
+}
@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+use arcium_anchor::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::{TRANSFER_STATUS_OPEN, TRANSFER_STATUS_RELEASING};
+use crate::{ReclaimTransfer, ReleaseEscrowCallback};
+
+/// Sender takes back an open transfer request once it has expired
+/// unclaimed. Queues release_escrow to return the escrowed amount to the
+/// sender's balance; the callback closes the request.
+///
+/// # Arguments
+/// * `computation_offset` - Unique ID for MPC computation
+pub fn handler(ctx: Context<ReclaimTransfer>, computation_offset: u64) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let request = &mut ctx.accounts.transfer_request;
+    require!(
+        request.status == TRANSFER_STATUS_OPEN,
+        ErrorCode::TransferRequestNotOpen
+    );
+    require!(
+        now >= request.expires_at,
+        ErrorCode::TransferRequestNotExpired
+    );
+    request.status = TRANSFER_STATUS_RELEASING;
+    request.pending_computation = ctx.accounts.computation_account.key();
+    request.queued_at = now;
+    let asset_id = request.asset_id;
+
+    ctx.accounts.sender_account.last_active_at = now;
+
+    // Set sign PDA bump
+    ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+    let request = &ctx.accounts.transfer_request;
+    let sender = &ctx.accounts.sender_account;
+    let args = ArgBuilder::new()
+        // Escrowed amount (Enc<Mxe, *>)
+        .plaintext_u128(request.escrow_nonce)
+        .encrypted_u64(request.escrow_amount)
+        // Sender's current balance (Enc<Shared, *> - sender's pubkey)
+        .x25519_pubkey(sender.user_pubkey)
+        .plaintext_u128(sender.get_nonce(asset_id))
+        .encrypted_u64(sender.get_credit(asset_id))
+        .build();
+
+    use arcium_client::idl::arcium::types::CallbackAccount;
+    queue_computation(
+        ctx.accounts,
+        computation_offset,
+        args,
+        vec![ReleaseEscrowCallback::callback_ix(
+            computation_offset,
+            &ctx.accounts.mxe_account,
+            &[
+                CallbackAccount {
+                    pubkey: ctx.accounts.sender_account.key(),
+                    is_writable: true,
+                },
+                CallbackAccount {
+                    pubkey: ctx.accounts.transfer_request.key(),
+                    is_writable: true,
+                },
+                CallbackAccount {
+                    pubkey: ctx.accounts.transfer_request.sender,
+                    is_writable: true,
+                },
+                crate::event_cursor_callback_account(ctx.accounts.pool.pool_id),
+            ],
+        )?],
+        1,
+        0,
+    )?;
+
+    msg!(
+        "Transfer request {} reclaim queued by {}",
+        ctx.accounts.transfer_request.transfer_id,
+        ctx.accounts.transfer_request.sender
+    );
+    Ok(())
+}
@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+use arcium_anchor::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::{VOUCHER_STATUS_OPEN, VOUCHER_STATUS_REDEEMING};
+use crate::{ReclaimVoucher, RedeemVoucherCallback};
+
+/// Funder takes back an open voucher once it has expired unclaimed.
+/// Queues redeem_voucher to return the escrowed amount to the funder's
+/// balance; the callback closes the voucher.
+///
+/// # Arguments
+/// * `computation_offset` - Unique ID for MPC computation
+pub fn handler(ctx: Context<ReclaimVoucher>, computation_offset: u64) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let voucher = &mut ctx.accounts.voucher;
+    require!(
+        voucher.status == VOUCHER_STATUS_OPEN,
+        ErrorCode::VoucherNotOpen
+    );
+    require!(now >= voucher.expires_at, ErrorCode::VoucherNotExpired);
+    voucher.status = VOUCHER_STATUS_REDEEMING;
+    voucher.pending_computation = ctx.accounts.computation_account.key();
+    voucher.queued_at = now;
+    let asset_id = voucher.asset_id;
+
+    ctx.accounts.funder_account.last_active_at = now;
+
+    // Set sign PDA bump
+    ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+    let voucher = &ctx.accounts.voucher;
+    let funder = &ctx.accounts.funder_account;
+    let args = ArgBuilder::new()
+        // Escrowed amount (Enc<Mxe, *>)
+        .plaintext_u128(voucher.escrow_nonce)
+        .encrypted_u64(voucher.escrow_amount)
+        // Funder's current balance (Enc<Shared, *> - funder's pubkey)
+        .x25519_pubkey(funder.user_pubkey)
+        .plaintext_u128(funder.get_nonce(asset_id))
+        .encrypted_u64(funder.get_credit(asset_id))
+        .build();
+
+    use arcium_client::idl::arcium::types::CallbackAccount;
+    queue_computation(
+        ctx.accounts,
+        computation_offset,
+        args,
+        vec![RedeemVoucherCallback::callback_ix(
+            computation_offset,
+            &ctx.accounts.mxe_account,
+            &[
+                CallbackAccount {
+                    pubkey: ctx.accounts.funder_account.key(),
+                    is_writable: true,
+                },
+                CallbackAccount {
+                    pubkey: ctx.accounts.voucher.key(),
+                    is_writable: true,
+                },
+                CallbackAccount {
+                    pubkey: ctx.accounts.voucher.funder,
+                    is_writable: true,
+                },
+                crate::event_cursor_callback_account(ctx.accounts.pool.pool_id),
+            ],
+        )?],
+        1,
+        0,
+    )?;
+
+    msg!(
+        "Voucher {} reclaim queued by {}",
+        ctx.accounts.voucher.claim_key,
+        ctx.accounts.voucher.funder
+    );
+    Ok(())
+}
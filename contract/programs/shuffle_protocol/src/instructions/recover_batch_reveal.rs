@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::BATCH_REVEAL_TIMEOUT_SECS;
+use crate::errors::ErrorCode;
+use crate::state::BATCH_STATUS_OPEN;
+use crate::{BatchRevealRecoveredEvent, RecoverBatchReveal};
+
+// =============================================================================
+// RECOVER BATCH REVEAL - Reopen a batch after an aborted reveal
+// =============================================================================
+// If reveal_batch aborts, the accumulator slot stays REVEALING with its
+// orders. After BATCH_REVEAL_TIMEOUT_SECS the operator or authority can
+// reopen it; the slot stays inactive (the other slot keeps collecting the
+// next batch) and execute_batch on it reveals the batch again, reusing the
+// BatchLog already created for this batch_id.
+//
+// A late callback from the abandoned computation is harmless: once the retry
+// has executed, the log holds results and batch_id has moved on, so the
+// callback fails the gap/collision checks.
+
+/// Reopen a batch slot after a stale reveal. Operator or authority only.
+pub fn handler(ctx: Context<RecoverBatchReveal>) -> Result<()> {
+    let batch = &mut ctx.accounts.batch_accumulator;
+    require!(!batch.is_open(), ErrorCode::BatchNotRevealing);
+
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        now >= batch.reveal_queued_at + BATCH_REVEAL_TIMEOUT_SECS,
+        ErrorCode::BatchRevealNotStale
+    );
+
+    let queued_at = batch.reveal_queued_at;
+    batch.status = BATCH_STATUS_OPEN;
+    batch.reveal_queued_at = 0;
+
+    emit!(BatchRevealRecoveredEvent {
+        batch_id: batch.batch_id,
+        reveal_queued_at: queued_at,
+        recovered_at: now,
+        seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+    });
+
+    msg!(
+        "Batch {} reopened after stale reveal queued at {}",
+        batch.batch_id,
+        queued_at
+    );
+    Ok(())
+}
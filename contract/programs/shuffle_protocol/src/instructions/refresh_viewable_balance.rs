@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+use arcium_anchor::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::{ReencryptBalanceCallback, RefreshViewableBalance};
+
+// =============================================================================
+// REFRESH VIEWABLE BALANCE - Re-encrypt a balance for the viewing key
+// =============================================================================
+// One asset per call. The owner's encrypted credit is re-encrypted in MPC
+// under the ViewableBalance viewing key; the credit itself is left as is.
+//
+// Flow:
+// 1. Owner calls refresh_viewable_balance with a fresh nonce for the viewer
+// 2. Handler queues reencrypt_balance with the credit and the viewing key
+// 3. Callback stores the re-encrypted balance unless the viewing key was
+//    replaced in between
+
+/// Queue a re-encryption of one asset balance under the caller's viewing key.
+///
+/// # Arguments
+/// * `computation_offset` - Unique ID for MPC computation
+/// * `asset_id` - Asset to refresh (0=USDC, 1=TSLA, 2=SPY, 3=AAPL)
+/// * `viewer_nonce` - Fresh nonce for the viewing key's shared secret
+pub fn handler(
+    ctx: Context<RefreshViewableBalance>,
+    computation_offset: u64,
+    asset_id: u8,
+    viewer_nonce: u128,
+) -> Result<()> {
+    require!(asset_id <= 3, ErrorCode::InvalidAssetId);
+
+    let viewable = &mut ctx.accounts.viewable_balance;
+    viewable.pending_asset_id = asset_id;
+    viewable.pending_viewing_pubkey = viewable.viewing_pubkey;
+
+    // Set sign PDA bump
+    ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+    let user = &ctx.accounts.user_account;
+    let args = ArgBuilder::new()
+        // Owner's balance (Enc<Shared, *> - owner's pubkey)
+        .x25519_pubkey(user.user_pubkey)
+        .plaintext_u128(user.get_nonce(asset_id))
+        .encrypted_u64(user.get_credit(asset_id))
+        // Viewer (Shared - viewing key)
+        .x25519_pubkey(ctx.accounts.viewable_balance.viewing_pubkey)
+        .plaintext_u128(viewer_nonce)
+        .build();
+
+    use arcium_client::idl::arcium::types::CallbackAccount;
+    queue_computation(
+        ctx.accounts,
+        computation_offset,
+        args,
+        vec![ReencryptBalanceCallback::callback_ix(
+            computation_offset,
+            &ctx.accounts.mxe_account,
+            &[
+                CallbackAccount {
+                    pubkey: ctx.accounts.viewable_balance.key(),
+                    is_writable: true,
+                },
+                crate::event_cursor_callback_account(ctx.accounts.pool.pool_id),
+            ],
+        )?],
+        1,
+        0,
+    )?;
+
+    msg!(
+        "Viewable balance refresh queued: owner={}, asset={}",
+        ctx.accounts.user_account.owner,
+        asset_id
+    );
+    Ok(())
+}
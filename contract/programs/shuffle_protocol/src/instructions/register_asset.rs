@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::{AssetEntry, MAX_ASSET_SYMBOL_LEN, MAX_REGISTERED_ASSETS, NUM_BUILTIN_ASSETS};
+use crate::{AssetRegisteredEvent, RegisterAsset};
+
+// =============================================================================
+// REGISTER ASSET - List a tokenized stock without a program upgrade
+// =============================================================================
+// Adds the mint to the AssetRegistry (created on first call) and creates its
+// Pool-owned deposit vault with seeds ["vault", pool_id, mint]. The built-in
+// vaults use named seeds ("usdc", "tsla", ...) of a different length, so the
+// two schemes cannot collide.
+//
+// decimals is passed explicitly and must match the mint, so a listing
+// script that assumed the wrong precision fails here rather than when the
+// first deposit is priced. Registration is append-only: asset IDs are
+// handed out in order and never reused.
+
+/// Register a new asset. Only callable by pool authority.
+///
+/// # Arguments
+/// * `decimals` - Expected mint decimals
+/// * `symbol` - Ticker, 1-8 ASCII letters or digits
+pub fn handler(ctx: Context<RegisterAsset>, decimals: u8, symbol: String) -> Result<()> {
+    let mint = ctx.accounts.mint.key();
+    require!(
+        ctx.accounts.mint.decimals == decimals,
+        ErrorCode::InvalidMint
+    );
+    require!(
+        !symbol.is_empty()
+            && symbol.len() <= MAX_ASSET_SYMBOL_LEN
+            && symbol.bytes().all(|b| b.is_ascii_alphanumeric()),
+        ErrorCode::InvalidAssetSymbol
+    );
+
+    let pool = &ctx.accounts.pool;
+    let builtin =
+        (0..NUM_BUILTIN_ASSETS).any(|asset_id| pool.mint_for_asset(asset_id) == Some(mint));
+    let registry = &mut ctx.accounts.asset_registry;
+    require!(
+        !builtin && registry.asset_id(&mint).is_none(),
+        ErrorCode::AssetAlreadyRegistered
+    );
+    let index = registry.asset_count as usize;
+    require!(index < MAX_REGISTERED_ASSETS, ErrorCode::AssetRegistryFull);
+
+    let mut symbol_bytes = [0u8; MAX_ASSET_SYMBOL_LEN];
+    symbol_bytes[..symbol.len()].copy_from_slice(symbol.as_bytes());
+    let vault = ctx.accounts.asset_vault.key();
+    registry.assets[index] = AssetEntry {
+        mint,
+        vault,
+        decimals,
+        symbol: symbol_bytes,
+        registered_at: Clock::get()?.unix_timestamp,
+    };
+    registry.asset_count += 1;
+    registry.bump = ctx.bumps.asset_registry;
+
+    let asset_id = NUM_BUILTIN_ASSETS + index as u8;
+    emit!(AssetRegisteredEvent {
+        asset_id,
+        mint,
+        vault,
+        decimals,
+        symbol: symbol.clone(),
+        seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+    });
+
+    msg!("Asset {} registered: {} ({})", asset_id, symbol, mint);
+    Ok(())
+}
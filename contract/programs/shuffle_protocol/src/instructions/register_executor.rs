@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+
+use crate::errors::ErrorCode;
+use crate::state::ExecutorEntry;
+use crate::{ExecutorRegisteredEvent, RegisterExecutor};
+
+// =============================================================================
+// REGISTER EXECUTOR - Post a bond to join the executor set
+// =============================================================================
+// The bond is transferred into the ExecutorRegistry PDA and held there until
+// slash_executor sends it to the treasury.
+
+/// Register the signer as a bonded executor.
+///
+/// # Arguments
+/// * `bond_lamports` - Bond to post (>= ExecutorRegistry.min_bond_lamports)
+pub fn handler(ctx: Context<RegisterExecutor>, bond_lamports: u64) -> Result<()> {
+    let executor = ctx.accounts.executor.key();
+    let registry = &ctx.accounts.executor_registry;
+    require!(
+        bond_lamports > 0 && bond_lamports >= registry.min_bond_lamports,
+        ErrorCode::ExecutorBondTooLow
+    );
+    require!(
+        !registry.is_registered(&executor),
+        ErrorCode::ExecutorAlreadyRegistered
+    );
+    let index = registry
+        .free_entry()
+        .ok_or(ErrorCode::ExecutorRegistryFull)?;
+
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.executor.to_account_info(),
+                to: ctx.accounts.executor_registry.to_account_info(),
+            },
+        ),
+        bond_lamports,
+    )?;
+
+    let registered_at = Clock::get()?.unix_timestamp;
+    ctx.accounts.executor_registry.executors[index] = ExecutorEntry {
+        executor,
+        bond_lamports,
+        registered_at,
+    };
+
+    emit!(ExecutorRegisteredEvent {
+        executor,
+        bond_lamports,
+        registered_at,
+        seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+    });
+
+    msg!(
+        "Executor {} registered with a bond of {} lamports",
+        executor,
+        bond_lamports
+    );
+    Ok(())
+}
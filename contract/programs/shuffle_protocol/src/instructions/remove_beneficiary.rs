@@ -0,0 +1,17 @@
+use anchor_lang::prelude::*;
+
+use crate::{BeneficiaryRemovedEvent, RemoveBeneficiary};
+
+/// Remove the caller's beneficiary and reclaim the account rent.
+pub fn handler(ctx: Context<RemoveBeneficiary>) -> Result<()> {
+    ctx.accounts.user_account.last_active_at = Clock::get()?.unix_timestamp;
+
+    emit!(BeneficiaryRemovedEvent {
+        owner: ctx.accounts.user.key(),
+        beneficiary: ctx.accounts.beneficiary_config.beneficiary,
+        seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+    });
+
+    msg!("Beneficiary removed for {}", ctx.accounts.user.key());
+    Ok(())
+}
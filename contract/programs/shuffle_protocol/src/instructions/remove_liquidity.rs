@@ -1,14 +1,15 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Transfer};
 
 use crate::constants::POOL_SEED;
 use crate::errors::ErrorCode;
-use crate::RemoveLiquidity;
+use crate::{transfer_checked_with_hook, CheckedTransferAccounts, RemoveLiquidity};
 
 // =============================================================================
 // REMOVE LIQUIDITY - Admin instruction to withdraw tokens from protocol reserves
 // =============================================================================
 // Allows the protocol authority to withdraw tokens from reserve vaults.
+// Like add_liquidity it uses transfer_checked; a transfer-fee mint's fee is
+// borne by the receiving account.
 
 /// Remove liquidity from protocol reserves.
 /// Only callable by the pool authority (admin).
@@ -16,7 +17,11 @@ use crate::RemoveLiquidity;
 /// # Arguments
 /// * `asset_id` - Asset to remove (0=USDC, 1=TSLA, 2=SPY, 3=AAPL)
 /// * `amount` - Amount to transfer from reserves
-pub fn handler(ctx: Context<RemoveLiquidity>, asset_id: u8, amount: u64) -> Result<()> {
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, RemoveLiquidity<'info>>,
+    asset_id: u8,
+    amount: u64,
+) -> Result<()> {
     // Validate asset_id
     require!(asset_id <= 3, ErrorCode::InvalidAssetId);
 
@@ -25,21 +30,32 @@ pub fn handler(ctx: Context<RemoveLiquidity>, asset_id: u8, amount: u64) -> Resu
         ctx.accounts.authority.key() == ctx.accounts.pool.authority,
         ErrorCode::Unauthorized
     );
+    require!(
+        ctx.accounts.pool.mint_for_asset(asset_id) == Some(ctx.accounts.mint.key()),
+        ErrorCode::InvalidMint
+    );
 
     // Pool PDA signs the transfer from reserve vault
-    let pool_seeds = &[POOL_SEED, &[ctx.accounts.pool.bump]];
+    let pool_seeds = &[
+        POOL_SEED,
+        &[ctx.accounts.pool.pool_id],
+        &[ctx.accounts.pool.bump],
+    ];
     let signer_seeds = &[&pool_seeds[..]];
 
-    let transfer_ctx = CpiContext::new_with_signer(
-        ctx.accounts.token_program.to_account_info(),
-        Transfer {
-            from: ctx.accounts.reserve_vault.to_account_info(),
-            to: ctx.accounts.authority_token_account.to_account_info(),
-            authority: ctx.accounts.pool.to_account_info(),
+    transfer_checked_with_hook(
+        CheckedTransferAccounts {
+            token_program: &ctx.accounts.token_program.to_account_info(),
+            from: &ctx.accounts.reserve_vault.to_account_info(),
+            mint: &ctx.accounts.mint.to_account_info(),
+            to: &ctx.accounts.authority_token_account.to_account_info(),
+            authority: &ctx.accounts.pool.to_account_info(),
+            hook_accounts: ctx.remaining_accounts,
         },
+        amount,
+        ctx.accounts.mint.decimals,
         signer_seeds,
-    );
-    token::transfer(transfer_ctx, amount)?;
+    )?;
 
     msg!(
         "Removed {} units of asset {} from reserves",
@@ -0,0 +1,17 @@
+use anchor_lang::prelude::*;
+
+use crate::{RemoveViewer, ViewerRemovedEvent};
+
+/// Revoke a viewer grant and reclaim the account rent.
+pub fn handler(ctx: Context<RemoveViewer>, viewer_pubkey: [u8; 32]) -> Result<()> {
+    ctx.accounts.user_account.last_active_at = Clock::get()?.unix_timestamp;
+
+    emit!(ViewerRemovedEvent {
+        owner: ctx.accounts.user.key(),
+        viewer_pubkey,
+        seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+    });
+
+    msg!("Viewer removed for {}", ctx.accounts.user.key());
+    Ok(())
+}
@@ -0,0 +1,113 @@
+use anchor_lang::prelude::*;
+use arcium_anchor::prelude::*;
+
+use crate::constants::{MAX_TRANSFER_EXPIRY_SECS, MIN_TRANSFER_EXPIRY_SECS};
+use crate::errors::ErrorCode;
+use crate::state::TRANSFER_STATUS_ESCROWING;
+use crate::{EscrowTransferCallback, RequestTransfer};
+
+// =============================================================================
+// REQUEST TRANSFER - First step of a two-step transfer
+// =============================================================================
+// Creates the TransferRequest and queues escrow_transfer, which deducts the
+// amount from the sender and escrows it for the MXE. The recipient then has
+// `expiry_secs` to claim_transfer; after that the sender may
+// reclaim_transfer.
+
+/// Escrow an encrypted amount for a recipient to claim.
+///
+/// # Arguments
+/// * `computation_offset` - Unique ID for MPC computation
+/// * `transfer_id` - Sender-chosen ID of the request (unique per sender)
+/// * `asset_id` - Asset to transfer (0=USDC, 1=TSLA, 2=SPY, 3=AAPL)
+/// * `encrypted_amount` - Amount encrypted for the sender's user_pubkey
+/// * `nonce` - Encryption nonce (fresh; also seeds the escrow's MXE nonce)
+/// * `expiry_secs` - Claim window (MIN_TRANSFER_EXPIRY_SECS..=MAX_TRANSFER_EXPIRY_SECS)
+pub fn handler(
+    ctx: Context<RequestTransfer>,
+    computation_offset: u64,
+    transfer_id: u64,
+    asset_id: u8,
+    encrypted_amount: [u8; 32],
+    nonce: u128,
+    expiry_secs: i64,
+) -> Result<()> {
+    require!(!ctx.accounts.pool.paused, ErrorCode::ProtocolPaused);
+    require!(asset_id <= 3, ErrorCode::InvalidAssetId);
+    require!(
+        !ctx.accounts.pool.is_asset_frozen(asset_id),
+        ErrorCode::AssetFrozen
+    );
+    require!(
+        (MIN_TRANSFER_EXPIRY_SECS..=MAX_TRANSFER_EXPIRY_SECS).contains(&expiry_secs)
+            && ctx.accounts.recipient_account.owner != ctx.accounts.sender.key(),
+        ErrorCode::InvalidTransferRequest
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let request = &mut ctx.accounts.transfer_request;
+    request.transfer_id = transfer_id;
+    request.pool_id = ctx.accounts.pool.pool_id;
+    request.sender = ctx.accounts.sender.key();
+    request.recipient = ctx.accounts.recipient_account.owner;
+    request.asset_id = asset_id;
+    request.created_at = now;
+    request.expires_at = now.saturating_add(expiry_secs);
+    request.status = TRANSFER_STATUS_ESCROWING;
+    request.pending_computation = ctx.accounts.computation_account.key();
+    request.queued_at = now;
+    request.bump = ctx.bumps.transfer_request;
+
+    // Sender activity resets their beneficiary inactivity timer
+    ctx.accounts.sender_account.last_active_at = now;
+
+    // Set sign PDA bump
+    ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+    let sender = &ctx.accounts.sender_account;
+    let args = ArgBuilder::new()
+        // TransferRequest (encrypted with sender's stored key) - just amount field
+        .x25519_pubkey(sender.user_pubkey)
+        .plaintext_u128(nonce)
+        .encrypted_u64(encrypted_amount)
+        // Sender's current balance (Enc<Shared, *> - sender's pubkey)
+        .x25519_pubkey(sender.user_pubkey)
+        .plaintext_u128(sender.get_nonce(asset_id))
+        .encrypted_u64(sender.get_credit(asset_id))
+        // Mxe owner of the escrow
+        .plaintext_u128(nonce)
+        .build();
+
+    use arcium_client::idl::arcium::types::CallbackAccount;
+    queue_computation(
+        ctx.accounts,
+        computation_offset,
+        args,
+        vec![EscrowTransferCallback::callback_ix(
+            computation_offset,
+            &ctx.accounts.mxe_account,
+            &[
+                CallbackAccount {
+                    pubkey: ctx.accounts.sender_account.key(),
+                    is_writable: true,
+                },
+                CallbackAccount {
+                    pubkey: ctx.accounts.transfer_request.key(),
+                    is_writable: true,
+                },
+                crate::event_cursor_callback_account(ctx.accounts.pool.pool_id),
+            ],
+        )?],
+        1,
+        0,
+    )?;
+
+    msg!(
+        "Transfer request {} queued: {} -> {}, asset={}",
+        transfer_id,
+        ctx.accounts.transfer_request.sender,
+        ctx.accounts.transfer_request.recipient,
+        asset_id
+    );
+    Ok(())
+}
@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::ASSET_UNFREEZE_TIMELOCK_SECS;
+use crate::errors::ErrorCode;
+use crate::{AssetUnfreezeRequestedEvent, RequestUnfreezeAsset};
+
+/// Start the unfreeze timelock for a frozen asset.
+/// Only callable by pool authority.
+///
+/// # Arguments
+/// * `asset_id` - Frozen asset to reopen (0=USDC, 1=TSLA, 2=SPY, 3=AAPL)
+pub fn handler(ctx: Context<RequestUnfreezeAsset>, asset_id: u8) -> Result<()> {
+    require!(asset_id <= 3, ErrorCode::InvalidAssetId);
+
+    let pool = &mut ctx.accounts.pool;
+    require!(pool.is_asset_frozen(asset_id), ErrorCode::AssetNotFrozen);
+
+    let available_at = Clock::get()?.unix_timestamp + ASSET_UNFREEZE_TIMELOCK_SECS;
+    pool.unfreeze_available_at[asset_id as usize] = available_at;
+
+    emit!(AssetUnfreezeRequestedEvent {
+        asset_id,
+        available_at,
+        seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+    });
+
+    msg!("Asset {} unfreeze available at {}", asset_id, available_at);
+    Ok(())
+}
@@ -0,0 +1,130 @@
+use anchor_lang::prelude::*;
+use arcium_anchor::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::{
+    BatchLog, PairAccumulator, PairResult, BATCH_RESET_REASON_OTHER, BATCH_STATUS_OPEN, NUM_PAIRS,
+};
+use crate::{BatchResetEvent, InitBatchStateCallback, ResetBatch};
+
+// =============================================================================
+// RESET BATCH - Discard a stuck batch and re-encrypt the accumulator
+// =============================================================================
+// If an MPC callback fails mid-batch, the accumulator slot can be left with a
+// stale mxe_nonce or partially updated ciphertexts, which no retry can fix.
+// The authority discards the slot's batch:
+// 1. The BatchLog of the discarded batch is written with empty results and
+//    marked executed, so its orders settle through settle_order with no fill
+//    and a full refund (good-til-batch orders roll over as usual)
+// 2. The slot is reopened empty; an active slot moves on to a new batch_id
+//    so in-flight placements for the discarded batch are rejected
+// 3. init_batch_state is queued to replace the ciphertexts with fresh
+//    encrypted zeros
+//
+// A late reveal callback for the discarded batch fails the collision check
+// since its BatchLog already holds (empty) results. A batch that already
+// revealed keeps its log untouched.
+
+/// Discard the batch held by an accumulator slot and re-initialize it.
+/// Only callable by pool authority.
+///
+/// # Arguments
+/// * `computation_offset` - Unique ID for the init_batch_state computation
+/// * `reason` - BATCH_RESET_REASON_* code, emitted in BatchResetEvent
+pub fn handler(ctx: Context<ResetBatch>, computation_offset: u64, reason: u8) -> Result<()> {
+    require!(
+        reason <= BATCH_RESET_REASON_OTHER,
+        ErrorCode::InvalidResetReason
+    );
+    discard_batch(ctx, computation_offset, reason)
+}
+
+/// Refund-only discard shared by reset_batch and flush_batch
+pub(crate) fn discard_batch(
+    ctx: Context<ResetBatch>,
+    computation_offset: u64,
+    reason: u8,
+) -> Result<()> {
+    ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+    let now = Clock::get()?.unix_timestamp;
+
+    let batch_id = ctx.accounts.batch_accumulator.batch_id;
+    let discarded_orders = ctx.accounts.batch_accumulator.order_count;
+
+    // Nothing of the discarded batch executes: every order is refunded
+    let batch_log = &mut ctx.accounts.batch_log;
+    if batch_log.executed_at == 0 {
+        batch_log.bump = ctx.bumps.batch_log;
+        batch_log.version = BatchLog::VERSION;
+        batch_log.batch_id = batch_id;
+        batch_log.results = [PairResult::default(); NUM_PAIRS];
+        batch_log.executed_at = now;
+        batch_log.swaps_executed = true;
+        batch_log.frozen_assets = ctx.accounts.pool.frozen_assets;
+        batch_log.unsettled_count = discarded_orders;
+        batch_log.execution_mode = ctx.accounts.pool.execution_mode;
+        batch_log.pending_routes = 0;
+        batch_log.route_amounts = [0; NUM_PAIRS];
+        batch_log.routed_out = [0; NUM_PAIRS];
+        batch_log.route_min_out = [0; NUM_PAIRS];
+        batch_log.shortfall_pairs = 0;
+        batch_log.swap_executed = [true; NUM_PAIRS];
+    }
+
+    // Reopen the slot empty until init_batch_state_callback stores fresh zeros
+    let batch = &mut ctx.accounts.batch_accumulator;
+    if batch.active {
+        batch.batch_id = batch_id + 1;
+    }
+    batch.order_count = 0;
+    batch.basket_count = 0;
+    batch.status = BATCH_STATUS_OPEN;
+    batch.reveal_queued_at = 0;
+    batch.pair_states = [PairAccumulator::default(); NUM_PAIRS];
+    batch.mxe_nonce = 0;
+    batch.state_initialized = false;
+    batch.reveal_computation_offset = 0;
+    batch.failed_computation_offset = 0;
+    batch.reveal_retries = 0;
+
+    // Same arguments as init_batch_state
+    let args = ArgBuilder::new()
+        .plaintext_u128(0) // Mxe nonce placeholder
+        .build();
+
+    use arcium_client::idl::arcium::types::CallbackAccount;
+    queue_computation(
+        ctx.accounts,
+        computation_offset,
+        args,
+        vec![InitBatchStateCallback::callback_ix(
+            computation_offset,
+            &ctx.accounts.mxe_account,
+            &[CallbackAccount {
+                pubkey: ctx.accounts.batch_accumulator.key(),
+                is_writable: true,
+            }],
+        )?],
+        1,                                         // number of callbacks
+        ctx.accounts.pool.callback_cu_price_micro, // priority
+    )?;
+
+    emit!(BatchResetEvent {
+        slot: ctx.accounts.batch_accumulator.slot,
+        batch_id,
+        new_batch_id: ctx.accounts.batch_accumulator.batch_id,
+        discarded_orders,
+        reason,
+        reset_at: now,
+        seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+    });
+
+    msg!(
+        "Batch {} reset (slot {}, reason {}): {} orders refunded at settlement",
+        batch_id,
+        ctx.accounts.batch_accumulator.slot,
+        reason,
+        discarded_orders
+    );
+    Ok(())
+}
@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::TRANSFER_RESET_TIMEOUT_SECS;
+use crate::errors::ErrorCode;
+use crate::state::{TRANSFER_STATUS_ESCROWING, TRANSFER_STATUS_OPEN, TRANSFER_STATUS_RELEASING};
+use crate::{ResetTransferRequest, TransferRequestResetEvent};
+
+// =============================================================================
+// RESET TRANSFER REQUEST - Recover a request whose callback never arrived
+// =============================================================================
+// An aborted escrow_transfer or release_escrow computation leaves the request
+// ESCROWING or RELEASING with nothing to move it on. Neither step changes a
+// balance until its callback succeeds, so the request can be recovered:
+// - ESCROWING: nothing was deducted from the sender; the request is closed
+//   and its rent returned to the sender.
+// - RELEASING: the escrow is untouched; the request is OPEN again so the
+//   recipient can claim (or the sender reclaim after expiry) once more.
+//
+// As with cancel_withdrawal, the computation account passed must be the one
+// the request waits on. Once it no longer exists the request is reset at
+// once; otherwise TRANSFER_RESET_TIMEOUT_SECS must have passed since it was
+// queued. A callback landing after the reset no longer matches the request's
+// pending computation and fails. Permissionless.
+
+/// Reset a transfer request stuck in ESCROWING or RELEASING.
+pub fn handler(ctx: Context<ResetTransferRequest>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let request = &ctx.accounts.transfer_request;
+    let status = request.status;
+    require!(
+        status == TRANSFER_STATUS_ESCROWING || status == TRANSFER_STATUS_RELEASING,
+        ErrorCode::TransferRequestNotPending
+    );
+    let computation_gone = ctx.accounts.computation_account.lamports() == 0;
+    let resettable_at = request
+        .queued_at
+        .saturating_add(TRANSFER_RESET_TIMEOUT_SECS);
+    require!(
+        computation_gone || now >= resettable_at,
+        ErrorCode::TransferResetTimeoutActive
+    );
+
+    let closed = status == TRANSFER_STATUS_ESCROWING;
+    emit!(TransferRequestResetEvent {
+        transfer_id: request.transfer_id,
+        sender: request.sender,
+        status,
+        closed,
+        seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+    });
+    msg!(
+        "Transfer request {} reset: {}",
+        request.transfer_id,
+        if closed { "closed" } else { "open" }
+    );
+
+    if closed {
+        ctx.accounts
+            .transfer_request
+            .close(ctx.accounts.sender.to_account_info())?;
+    } else {
+        let request = &mut ctx.accounts.transfer_request;
+        request.status = TRANSFER_STATUS_OPEN;
+        request.pending_computation = Pubkey::default();
+    }
+    Ok(())
+}
@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::TRANSFER_RESET_TIMEOUT_SECS;
+use crate::errors::ErrorCode;
+use crate::state::{VOUCHER_STATUS_FUNDING, VOUCHER_STATUS_OPEN, VOUCHER_STATUS_REDEEMING};
+use crate::{ResetVoucher, VoucherResetEvent};
+
+// =============================================================================
+// RESET VOUCHER - Recover a voucher whose callback never arrived
+// =============================================================================
+// Same recovery as reset_transfer_request. An aborted fund_voucher or
+// redeem_voucher computation leaves the voucher FUNDING or REDEEMING:
+// - FUNDING: nothing was deducted from the funder; the voucher is closed and
+//   its rent returned to the funder.
+// - REDEEMING: the escrow is untouched; the voucher is OPEN again so the
+//   secret holder can claim (or the funder reclaim after expiry) once more.
+//
+// The reset is allowed once the voucher's computation account no longer
+// exists or TRANSFER_RESET_TIMEOUT_SECS have passed since it was queued. A
+// callback landing after the reset fails. Permissionless.
+
+/// Reset a voucher stuck in FUNDING or REDEEMING.
+pub fn handler(ctx: Context<ResetVoucher>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let voucher = &ctx.accounts.voucher;
+    let status = voucher.status;
+    require!(
+        status == VOUCHER_STATUS_FUNDING || status == VOUCHER_STATUS_REDEEMING,
+        ErrorCode::VoucherNotPending
+    );
+    let computation_gone = ctx.accounts.computation_account.lamports() == 0;
+    let resettable_at = voucher
+        .queued_at
+        .saturating_add(TRANSFER_RESET_TIMEOUT_SECS);
+    require!(
+        computation_gone || now >= resettable_at,
+        ErrorCode::VoucherResetTimeoutActive
+    );
+
+    let closed = status == VOUCHER_STATUS_FUNDING;
+    emit!(VoucherResetEvent {
+        claim_key: voucher.claim_key,
+        funder: voucher.funder,
+        status,
+        closed,
+        seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+    });
+    msg!(
+        "Voucher {} reset: {}",
+        voucher.claim_key,
+        if closed { "closed" } else { "open" }
+    );
+
+    if closed {
+        ctx.accounts
+            .voucher
+            .close(ctx.accounts.funder.to_account_info())?;
+    } else {
+        let voucher = &mut ctx.accounts.voucher;
+        voucher.status = VOUCHER_STATUS_OPEN;
+        voucher.pending_computation = Pubkey::default();
+    }
+    Ok(())
+}
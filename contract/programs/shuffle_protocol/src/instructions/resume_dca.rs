@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::{DcaResumedEvent, ResumeDca};
+
+/// Resume a paused DCA schedule.
+/// Intervals that fell due while paused are skipped (not executed
+/// back-to-back) and added to skipped_count; the schedule keeps its
+/// original cadence.
+pub fn handler(ctx: Context<ResumeDca>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let dca = &mut ctx.accounts.dca_schedule;
+    require!(dca.paused, ErrorCode::DcaNotPaused);
+
+    let skipped = dca.missed_intervals(now);
+    dca.next_execution_ts += skipped as i64 * dca.interval_secs;
+    dca.skipped_count = dca.skipped_count.saturating_add(skipped);
+    dca.paused = false;
+
+    emit!(DcaResumedEvent {
+        owner: dca.owner,
+        dca_id: dca.dca_id,
+        skipped,
+        skipped_count: dca.skipped_count,
+        next_execution_ts: dca.next_execution_ts,
+        seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+    });
+
+    msg!(
+        "DCA {} resumed for {}: skipped {} intervals, next at {}",
+        dca.dca_id,
+        dca.owner,
+        skipped,
+        dca.next_execution_ts
+    );
+    Ok(())
+}
@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+use arcium_anchor::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::PENDING_OP_DEPOSIT;
+use crate::{AddBalanceCallback, RetryDeposit};
+
+// =============================================================================
+// RETRY DEPOSIT - Re-queue a deposit that was never credited
+// =============================================================================
+// A deposit's tokens are in the vault as soon as add_balance lands, so a
+// callback that fails (aborted computation, or PendingOperationStale because
+// another operation on the same asset landed first) would leave them
+// uncredited. The PendingOperation keeps the encrypted amount; this
+// re-queues add_balance with it against the current balance.
+//
+// The PendingOperation is pointed at the new computation, so if the original
+// computation is still in flight its callback is rejected and the deposit
+// is credited once.
+
+/// Re-queue add_balance for an uncredited deposit.
+///
+/// # Arguments
+/// * `computation_offset` - Unique ID for the new MPC computation
+/// * `pending_offset` - computation_offset the deposit was first queued with
+pub fn handler(
+    ctx: Context<RetryDeposit>,
+    computation_offset: u64,
+    pending_offset: u64,
+) -> Result<()> {
+    require!(
+        ctx.accounts.pending_operation.kind == PENDING_OP_DEPOSIT,
+        ErrorCode::InvalidPendingOperation
+    );
+
+    ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+    let pending = &mut ctx.accounts.pending_operation;
+    let asset_id = pending.asset_id;
+    let current_balance = ctx.accounts.user_account.get_credit(asset_id);
+    let current_nonce = ctx.accounts.user_account.get_nonce(asset_id);
+    pending.computation = ctx.accounts.computation_account.key();
+    pending.balance_nonce = current_nonce;
+    pending.queued_at = Clock::get()?.unix_timestamp;
+
+    let args = ArgBuilder::new()
+        // Shared input 1: BalanceUpdate (the original deposit amount)
+        .x25519_pubkey(pending.amount_pubkey)
+        .plaintext_u128(pending.amount_nonce)
+        .encrypted_u64(pending.encrypted_amount)
+        // Shared input 2: UserBalance (current balance from account)
+        .x25519_pubkey(ctx.accounts.user_account.user_pubkey)
+        .plaintext_u128(current_nonce)
+        .encrypted_u64(current_balance)
+        .build();
+
+    use arcium_client::idl::arcium::types::CallbackAccount;
+    queue_computation(
+        ctx.accounts,
+        computation_offset,
+        args,
+        vec![AddBalanceCallback::callback_ix(
+            computation_offset,
+            &ctx.accounts.mxe_account,
+            &[
+                CallbackAccount {
+                    pubkey: ctx.accounts.user_account.key(),
+                    is_writable: true,
+                },
+                CallbackAccount {
+                    pubkey: ctx.accounts.pending_operation.key(),
+                    is_writable: true,
+                },
+                CallbackAccount {
+                    pubkey: ctx.accounts.user.key(),
+                    is_writable: true,
+                },
+                crate::event_cursor_callback_account(ctx.accounts.pool.pool_id),
+            ],
+        )?],
+        1,
+        ctx.accounts.pool.callback_cu_price_micro,
+    )?;
+
+    msg!(
+        "Deposit {} re-queued: asset {}, computation {}",
+        pending_offset,
+        asset_id,
+        computation_offset
+    );
+    Ok(())
+}
@@ -0,0 +1,120 @@
+use anchor_lang::prelude::*;
+use arcium_anchor::prelude::*;
+
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::{BatchRevealRetriedEvent, RetryExecuteBatch, RevealBatchCallback};
+
+// =============================================================================
+// RETRY EXECUTE BATCH - Re-queue a failed reveal
+// =============================================================================
+// When the reveal_batch callback fails verification, it records the failed
+// computation_offset on the accumulator (and emits BatchExecutionFailedEvent)
+// instead of leaving the batch stuck. This instruction re-queues the reveal
+// for the same batch and BatchLog, either right after such a failure or once
+// the reveal has been pending for BATCH_REVEAL_TIMEOUT_SECS with no callback.
+//
+// The reveal reads the same accumulator ciphertexts and reserve snapshot as
+// the first attempt, so the retry produces the same results. The caller
+// replaces the original executor and receives the keeper reward.
+// After MAX_REVEAL_RETRIES the authority discards the batch with reset_batch.
+
+/// Re-queue reveal_batch for a failed or stale reveal.
+///
+/// # Arguments
+/// * `computation_offset` - Unique ID for the new MPC computation
+pub fn handler(ctx: Context<RetryExecuteBatch>, computation_offset: u64) -> Result<()> {
+    crate::require_executor(
+        &ctx.accounts.pool,
+        ctx.accounts.executor_registry.as_deref().map(|r| &**r),
+        &ctx.accounts.payer.key(),
+    )?;
+
+    ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+    let batch = &ctx.accounts.batch_accumulator;
+    require!(!batch.is_open(), ErrorCode::BatchNotRevealing);
+    require!(
+        ctx.accounts.batch_log.executed_at == 0,
+        ErrorCode::BatchAlreadyExecuted
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let failed = batch.failed_computation_offset != 0
+        && batch.failed_computation_offset == batch.reveal_computation_offset;
+    require!(
+        failed || now >= batch.reveal_queued_at + BATCH_REVEAL_TIMEOUT_SECS,
+        ErrorCode::BatchRevealNotStale
+    );
+    require!(
+        batch.reveal_retries < MAX_REVEAL_RETRIES,
+        ErrorCode::RevealRetriesExhausted
+    );
+
+    let failed_computation_offset = batch.reveal_computation_offset;
+    let batch = &mut ctx.accounts.batch_accumulator;
+    batch.reveal_retries += 1;
+    batch.reveal_computation_offset = computation_offset;
+    batch.failed_computation_offset = 0;
+    batch.reveal_queued_at = now;
+    ctx.accounts.batch_log.executor = ctx.accounts.payer.key();
+
+    // Same arguments as execute_batch
+    let args = ArgBuilder::new()
+        .plaintext_u128(ctx.accounts.batch_accumulator.mxe_nonce) // Use stored MXE nonce
+        .account(
+            ctx.accounts.batch_accumulator.key(),
+            8 + 8 + 1, // Skip discriminator + batch_id + order_count
+            6 * 64,    // 12 ciphertexts × 32 bytes = 384 bytes
+        )
+        .build();
+
+    use arcium_client::idl::arcium::types::CallbackAccount;
+    queue_computation(
+        ctx.accounts,
+        computation_offset,
+        args,
+        vec![RevealBatchCallback::callback_ix(
+            computation_offset,
+            &ctx.accounts.mxe_account,
+            &[
+                CallbackAccount {
+                    pubkey: ctx.accounts.batch_accumulator.key(),
+                    is_writable: true,
+                },
+                CallbackAccount {
+                    pubkey: ctx.accounts.batch_log.key(),
+                    is_writable: true,
+                },
+                crate::diagnostics_callback_account(&ctx.accounts.diagnostics),
+                CallbackAccount {
+                    pubkey: ctx.accounts.pool.key(),
+                    is_writable: true,
+                },
+                crate::event_cursor_callback_account(ctx.accounts.pool.pool_id),
+                crate::protocol_stats_callback_account(ctx.accounts.pool.pool_id),
+            ],
+        )?],
+        1,                                         // number of callbacks
+        ctx.accounts.pool.callback_cu_price_micro, // priority
+    )?;
+
+    let batch = &ctx.accounts.batch_accumulator;
+    emit!(BatchRevealRetriedEvent {
+        batch_id: batch.batch_id,
+        slot: batch.slot,
+        failed_computation_offset,
+        computation_offset,
+        retry_count: batch.reveal_retries,
+        seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+    });
+
+    msg!(
+        "Batch {} reveal retried ({}/{}): computation={}",
+        batch.batch_id,
+        batch.reveal_retries,
+        MAX_REVEAL_RETRIES,
+        computation_offset
+    );
+    Ok(())
+}
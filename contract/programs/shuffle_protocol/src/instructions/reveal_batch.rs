@@ -0,0 +1,595 @@
+use anchor_lang::prelude::*;
+use arcium_anchor::prelude::*;
+
+use crate::constants::BPS_DENOMINATOR;
+use crate::errors::ErrorCode;
+use crate::state::{
+    self, fill_ratio, NettingBuffer, PairResult, EXECUTION_PRICE_SCALE, FILL_RATIO_FULL, NUM_PAIRS,
+    PAIR_ASSETS,
+};
+use crate::{
+    BatchApprovalRequestedEvent, BatchExecutedEvent, BatchExecutionFailedEvent,
+    PriceBandBreachedEvent, RevealBatchCallback,
+};
+
+// =============================================================================
+// REVEAL BATCH CALLBACK - Netting the revealed batch totals
+// =============================================================================
+// reveal_batch_callback receives the plaintext per-pair totals of a batch and
+// runs these steps in order:
+//   1. record_failed_reveal - a failed computation is recorded for
+//      retry_execute_batch instead of aborting
+//   2. check_batch_log      - the BatchLog must belong to the batch and be empty
+//   3. net_pairs            - nets each pair against the oracle prices, the
+//      reserves, the external venue and the cross-batch netting buffers
+//   4. record_results       - writes the BatchLog, pool and ProtocolStats
+//   5. reset_revealed_slot  - frees the accumulator slot and emits
+//      BatchExecutedEvent
+// No tokens move here: execute_swaps performs the transfers from the BatchLog.
+
+/// Outcome of netting one revealed batch, written by record_results
+pub struct Netting {
+    /// Per-pair results for settlement
+    pub pair_results: [PairResult; 6],
+    /// Bitmask of pairs whose surplus waits for route_surplus
+    pub pending_routes: u8,
+    /// Surplus routed per pair, in surplus units
+    pub route_amounts: [u64; 6],
+    /// Minimum output of each routed surplus
+    pub route_min_out: [u64; 6],
+    /// USDC value of what the executing pairs trade
+    pub notional_usdc: u128,
+    /// USDC value of the spread the reserves keep
+    pub fees_usdc: u128,
+    /// Cross-batch netting buffers after this batch
+    pub netting_buffers: [NettingBuffer; NUM_PAIRS],
+    /// Price band references after this batch
+    pub last_prices: [u64; 6],
+    /// Bitmask of pairs outside their price band
+    pub price_band_breaches: u8,
+}
+
+/// verify_output failed. Record the failure for retry_execute_batch when it
+/// is the reveal currently queued for the batch; otherwise abort.
+pub fn record_failed_reveal(accounts: &mut RevealBatchCallback) -> Result<()> {
+    // The reveal currently queued for the batch failed: record it
+    // so retry_execute_batch can re-queue without waiting for the
+    // stale timeout. Returning Ok keeps the record; the batch
+    // stays REVEALING with its orders.
+    let batch = &mut accounts.batch_accumulator;
+    let queued = batch.reveal_computation_offset;
+    let expected = derive_comp_pda!(queued, accounts.mxe_account, ErrorCode::ClusterNotSet);
+    if batch.is_open() || queued == 0 || accounts.computation_account.key() != expected {
+        return Err(ErrorCode::AbortedComputation.into());
+    }
+    batch.failed_computation_offset = queued;
+    emit!(BatchExecutionFailedEvent {
+        batch_id: batch.batch_id,
+        error: ErrorCode::AbortedComputation.info(),
+        slot: batch.slot,
+        computation_offset: queued,
+        retry_count: batch.reveal_retries,
+        seq: accounts.event_cursor.next(Clock::get()?.slot),
+    });
+    Ok(())
+}
+
+/// Reject a late or duplicate callback before anything is written.
+pub fn check_batch_log(accounts: &RevealBatchCallback) -> Result<()> {
+    // Gap/collision detection: the log must belong to the batch being
+    // revealed and must not hold results yet (late or duplicate callback)
+    let batch_id = accounts.batch_accumulator.batch_id;
+    let collision = if accounts.batch_log.batch_id != batch_id {
+        Some(ErrorCode::BatchIdMismatch)
+    } else if accounts.batch_log.executed_at != 0 {
+        Some(ErrorCode::BatchAlreadyExecuted)
+    } else {
+        None
+    };
+    if let Some(error) = collision {
+        emit!(BatchExecutionFailedEvent {
+            batch_id,
+            error: error.info(),
+            slot: accounts.batch_accumulator.slot,
+            computation_offset: accounts.batch_accumulator.reveal_computation_offset,
+            retry_count: accounts.batch_accumulator.reveal_retries,
+            seq: 0, // not sequenced: the transaction fails
+        });
+        return Err(error.into());
+    }
+    Ok(())
+}
+
+/// Net every pair of the revealed `totals` ([u64; 12], a_in and b_in per pair).
+pub fn net_pairs(accounts: &RevealBatchCallback, totals: &[u64; 12], now: i64) -> Netting {
+    msg!(
+        "DEBUG reveal_batch: batch_id={}, mxe_nonce={}",
+        accounts.batch_accumulator.batch_id,
+        accounts.batch_accumulator.mxe_nonce
+    );
+
+    // Oracle prices (in USDC, 6 decimals) snapshotted by execute_batch.
+    // Netting works in base units, so prices are normalized per base unit
+    // by each mint's decimals. Both sides net at the mid price; the
+    // reserves value what they take at the low end of the confidence
+    // interval and what they pay out at the high end.
+    let prices = accounts.batch_log.oracle_prices;
+    let confidence = accounts.batch_log.oracle_confidence;
+    let pool = &accounts.pool;
+    let unit_prices = pool.unit_prices(&prices);
+    let unit_bids = pool.unit_prices(&std::array::from_fn(|asset| {
+        prices[asset].saturating_sub(confidence[asset]).max(1)
+    }));
+    let unit_asks = pool.unit_prices(&std::array::from_fn(|asset| {
+        prices[asset].saturating_add(confidence[asset])
+    }));
+
+    // Reserve liquidity still available to absorb net surpluses.
+    // Snapshotted in execute_batch; decremented as pairs draw on it so two
+    // pairs can never promise the same reserve tokens.
+    let mut reserve_available = accounts.batch_log.reserve_snapshot;
+
+    // Reserve-only mode: how much more of each asset the reserves may
+    // take in (unlimited otherwise), and the spread kept on the surplus
+    let mut inventory_headroom = accounts.batch_log.inventory_headroom;
+    let spread_bps = accounts.batch_log.reserve_spread_bps as u128;
+    let bps = BPS_DENOMINATOR as u128;
+
+    // External-only mode: reserves are not used, surpluses wait for route_surplus
+    let external_only = accounts.batch_log.execution_mode == state::EXECUTION_MODE_EXTERNAL_ONLY;
+    let mut pending_routes = 0u8;
+    let mut route_amounts = [0u64; 6];
+
+    // Minimum output of each routed surplus: oracle value minus the
+    // worst accepted slippage
+    let mut route_min_out = [0u64; 6];
+    let slippage_bps = accounts.batch_log.max_slippage_bps as u128;
+
+    // USDC value of what the executing pairs trade, for the approval
+    // threshold (frozen pairs move nothing and are left out)
+    let mut notional_usdc = 0u128;
+
+    // USDC value of the spread the reserves keep on absorbed surplus
+    // (ProtocolStats.fees_collected_usdc)
+    let mut fees_usdc = 0u128;
+    let spread_value =
+        |filled: u128, price_in: u128| filled * price_in * spread_bps / bps / unit_prices[0];
+
+    // Cross-batch netting: surplus held by the reserves from earlier
+    // batches, waiting for an opposite surplus on the same pair
+    let mut netting_buffers = accounts.batch_accumulator.netting_buffers;
+    let netting_window = accounts.batch_log.netting_window_secs;
+
+    // Price bands: a pair netted too far from its last accepted price
+    // holds the batch for override_price_band; the pairs inside their
+    // band become the new reference
+    let mut last_prices = accounts.pool.last_execution_prices;
+    let mut price_band_breaches = 0u8;
+
+    // Split a net surplus between the reserves and the external venue.
+    // The reserves are offered `share_bps` of it, capped by what they can
+    // pay (`available`) and hold (`headroom`); with `spill` the part they
+    // cannot take is routed too, otherwise it stays unfilled.
+    // Returns (reserve_filled, reserve_out, routed); all in surplus units
+    // except reserve_out.
+    let split_surplus = |surplus: u128,
+                         price_in: u128,
+                         price_out: u128,
+                         share_bps: u128,
+                         spill: bool,
+                         available: u128,
+                         headroom: u128| {
+        let offered = surplus * share_bps / bps;
+        let amount_out = ((offered * price_in / price_out) * (bps - spread_bps)) / bps;
+        let (mut filled, mut out) = if amount_out > available {
+            (offered * available / amount_out, available)
+        } else {
+            (offered, amount_out)
+        };
+        if filled > headroom {
+            out = out * headroom / filled;
+            filled = headroom;
+        }
+        let routed = if spill {
+            surplus - filled
+        } else {
+            surplus - offered
+        };
+        (filled, out, routed)
+    };
+
+    // Inactive pairs count as fully filled so they never produce refunds
+    let mut pair_results = [PairResult {
+        fill_ratio_a: FILL_RATIO_FULL,
+        fill_ratio_b: FILL_RATIO_FULL,
+        ..PairResult::default()
+    }; 6];
+
+    // Process each pair with netting algorithm
+    // reveal() returns [u64; 12] - the array is the output directly
+    // totals is type [u64; 12] from the MPC output
+    for pair_id in 0..6 {
+        let total_a_in = totals[pair_id * 2];
+        let total_b_in = totals[pair_id * 2 + 1];
+
+        // Skip inactive pairs
+        if total_a_in == 0 && total_b_in == 0 {
+            continue;
+        }
+
+        let (base_asset, quote_asset) = PAIR_ASSETS[pair_id];
+
+        // Pairs touching a frozen asset and halted pairs do not execute:
+        // zero fill on both sides so settlement refunds every order in full
+        let frozen_mask = (1u8 << base_asset) | (1u8 << quote_asset);
+        let frozen = accounts.batch_log.frozen_assets & frozen_mask != 0;
+        if frozen || accounts.batch_log.halted_pairs & (1 << pair_id) != 0 {
+            pair_results[pair_id] = PairResult {
+                total_a_in,
+                total_b_in,
+                final_pool_a: 0,
+                final_pool_b: 0,
+                fill_ratio_a: 0,
+                fill_ratio_b: 0,
+                execution_price: 0,
+            };
+            msg!(
+                "Pair {}: {}, orders refunded",
+                pair_id,
+                if frozen {
+                    "asset frozen"
+                } else {
+                    "pair halted"
+                }
+            );
+            continue;
+        }
+
+        let base_price = unit_prices[base_asset as usize];
+        let quote_price = unit_prices[quote_asset as usize];
+        // Both sides net at the oracle cross rate
+        let execution_price = (base_price * EXECUTION_PRICE_SCALE as u128 / quote_price) as u64;
+        let band = accounts.batch_log.price_bands[pair_id] as u128;
+        let reference = last_prices[pair_id] as u128;
+        if band > 0
+            && reference > 0
+            && (execution_price as u128).abs_diff(reference) * bps > reference * band
+        {
+            price_band_breaches |= 1 << pair_id;
+        } else {
+            last_prices[pair_id] = execution_price;
+        }
+        notional_usdc +=
+            (total_a_in as u128 * base_price + total_b_in as u128 * quote_price) / unit_prices[0];
+
+        // Convert both sides to common unit (quote asset value) for comparison
+        let a_value_in_quote = (total_a_in as u128 * base_price) / quote_price;
+        let b_value = total_b_in as u128;
+
+        // Share of the net surplus offered to the reserves, and whether
+        // what they cannot take is routed to the external venue. The pair
+        // policy overrides the pool's execution mode.
+        let policy = accounts.batch_log.pair_policies[pair_id];
+        let (share_bps, spill) = match policy.strategy {
+            state::POLICY_RESERVE_FIRST => (policy.reserve_share_bps as u128, true),
+            state::POLICY_BEST_QUOTE => (0, false),
+            _ if external_only => (0, false),
+            _ => (bps, false),
+        };
+
+        let mut fill_ratio_a = FILL_RATIO_FULL;
+        let mut fill_ratio_b = FILL_RATIO_FULL;
+        let mut routed = 0u128;
+
+        let (mut final_pool_a, mut final_pool_b) = if a_value_in_quote > b_value {
+            // Net surplus on A side: users deposited more base_asset than needed
+            // Transfer surplus from vault_A → reserve_A
+            // Transfer equivalent from reserve_B → vault_B
+            let surplus_in_a =
+                (((a_value_in_quote - b_value) * quote_price) / base_price).min(total_a_in as u128);
+
+            // Reserves pay the quote owed minus the spread (reserve-only)
+            // or the simulated routing slippage, as far as reserve_B can
+            // pay and reserve_A may still hold
+            let (filled_surplus, filled_out, to_route) = split_surplus(
+                surplus_in_a,
+                unit_bids[base_asset as usize],
+                unit_asks[quote_asset as usize],
+                share_bps,
+                spill,
+                reserve_available[quote_asset as usize] as u128,
+                inventory_headroom[base_asset as usize] as u128,
+            );
+            routed = to_route;
+            let unfilled = (surplus_in_a - filled_surplus - routed) as u64;
+            fill_ratio_a = fill_ratio(total_a_in, unfilled);
+            reserve_available[quote_asset as usize] -= filled_out as u64;
+            inventory_headroom[base_asset as usize] -= filled_surplus as u64;
+            fees_usdc += spread_value(filled_surplus, base_price);
+
+            msg!(
+                "Pair {}: Net surplus {} units of asset {} → swap {} for {} units of asset {}, route {} (fill_ratio_a={})",
+                pair_id,
+                surplus_in_a,
+                base_asset,
+                filled_surplus,
+                filled_out,
+                quote_asset,
+                routed,
+                fill_ratio_a
+            );
+
+            // No transfers here: execute_swaps moves the surplus
+            // vault_base → reserve_base and the output
+            // reserve_quote → vault_quote from the BatchLog
+
+            (
+                total_a_in.saturating_sub(surplus_in_a as u64),
+                total_b_in.saturating_add(filled_out as u64),
+            )
+        } else if b_value > a_value_in_quote {
+            // Net surplus on B side: users deposited more quote_asset than needed
+            let surplus_in_b = (b_value - a_value_in_quote).min(total_b_in as u128);
+
+            // Reserves pay the base owed minus the spread (reserve-only)
+            // or the simulated routing slippage, as far as reserve_A can
+            // pay and reserve_B may still hold
+            let (filled_surplus, filled_out, to_route) = split_surplus(
+                surplus_in_b,
+                unit_bids[quote_asset as usize],
+                unit_asks[base_asset as usize],
+                share_bps,
+                spill,
+                reserve_available[base_asset as usize] as u128,
+                inventory_headroom[quote_asset as usize] as u128,
+            );
+            routed = to_route;
+            let unfilled = (surplus_in_b - filled_surplus - routed) as u64;
+            fill_ratio_b = fill_ratio(total_b_in, unfilled);
+            reserve_available[base_asset as usize] -= filled_out as u64;
+            inventory_headroom[quote_asset as usize] -= filled_surplus as u64;
+            fees_usdc += spread_value(filled_surplus, quote_price);
+
+            msg!(
+                "Pair {}: Net surplus {} units of asset {} → swap {} for {} units of asset {}, route {} (fill_ratio_b={})",
+                pair_id,
+                surplus_in_b,
+                quote_asset,
+                filled_surplus,
+                filled_out,
+                base_asset,
+                routed,
+                fill_ratio_b
+            );
+
+            // No transfers here: execute_swaps moves the surplus
+            // vault_quote → reserve_quote and the output
+            // reserve_base → vault_base from the BatchLog
+
+            (
+                total_a_in.saturating_add(filled_out as u64),
+                total_b_in.saturating_sub(surplus_in_b as u64),
+            )
+        } else {
+            // Perfect internal match - no external swap needed
+            msg!("Pair {}: Perfect internal match, no external swap", pair_id);
+            (total_a_in, total_b_in)
+        };
+
+        // Cross-batch netting: rather than routing, the reserves pay the
+        // surplus out of an opposite one held from an earlier batch, and
+        // hold what is left for a later batch to net against
+        if routed > 0 && netting_window > 0 {
+            let (surplus_asset, output_asset, price_in) = if a_value_in_quote > b_value {
+                (base_asset, quote_asset, base_price)
+            } else {
+                (quote_asset, base_asset, quote_price)
+            };
+            let bid_in = unit_bids[surplus_asset as usize];
+            let ask_out = unit_asks[output_asset as usize];
+            let buffer = &mut netting_buffers[pair_id];
+            let live = buffer.is_live(netting_window, now);
+            let mut netted_out = 0u128;
+
+            // Opposite inventory: pay the surplus out of the buffer
+            if live && buffer.asset_id == output_asset {
+                let available = reserve_available[output_asset as usize].min(buffer.amount);
+                let (filled, out, rest) = split_surplus(
+                    routed,
+                    bid_in,
+                    ask_out,
+                    bps,
+                    true,
+                    available as u128,
+                    inventory_headroom[surplus_asset as usize] as u128,
+                );
+                buffer.amount -= out as u64;
+                reserve_available[output_asset as usize] -= out as u64;
+                inventory_headroom[surplus_asset as usize] -= filled as u64;
+                fees_usdc += spread_value(filled, price_in);
+                netted_out += out;
+                routed = rest;
+                msg!(
+                    "Pair {}: netted {} units of asset {} against buffered asset {}",
+                    pair_id,
+                    filled,
+                    surplus_asset,
+                    output_asset
+                );
+            }
+
+            // Empty or same-side buffer: the reserves hold the rest until
+            // the window elapses (flush_netting_buffer)
+            if routed > 0 && (buffer.amount == 0 || (live && buffer.asset_id == surplus_asset)) {
+                let (filled, out, rest) = split_surplus(
+                    routed,
+                    bid_in,
+                    ask_out,
+                    bps,
+                    true,
+                    reserve_available[output_asset as usize] as u128,
+                    inventory_headroom[surplus_asset as usize] as u128,
+                );
+                if filled > 0 {
+                    if buffer.amount == 0 {
+                        buffer.asset_id = surplus_asset;
+                        buffer.opened_at = now;
+                    }
+                    buffer.amount += filled as u64;
+                }
+                reserve_available[output_asset as usize] -= out as u64;
+                inventory_headroom[surplus_asset as usize] -= filled as u64;
+                fees_usdc += spread_value(filled, price_in);
+                netted_out += out;
+                routed = rest;
+                msg!(
+                    "Pair {}: buffered {} units of asset {} for netting",
+                    pair_id,
+                    filled,
+                    surplus_asset
+                );
+            }
+
+            if a_value_in_quote > b_value {
+                final_pool_b = final_pool_b.saturating_add(netted_out as u64);
+            } else {
+                final_pool_a = final_pool_a.saturating_add(netted_out as u64);
+            }
+        }
+
+        // The routed part stays in the deposit vault for route_surplus,
+        // which adds the realized output to the other side's pool;
+        // settlement of the pair waits for it
+        if routed > 0 {
+            let (price_in, price_out) = if a_value_in_quote > b_value {
+                (base_price, quote_price)
+            } else {
+                (quote_price, base_price)
+            };
+            pending_routes |= 1 << pair_id;
+            route_amounts[pair_id] = routed as u64;
+            route_min_out[pair_id] =
+                (routed * price_in / price_out * (bps - slippage_bps) / bps) as u64;
+        }
+
+        pair_results[pair_id] = PairResult {
+            total_a_in,
+            total_b_in,
+            final_pool_a,
+            final_pool_b,
+            fill_ratio_a,
+            fill_ratio_b,
+            execution_price,
+        };
+
+        msg!(
+            "Pair {}: total_a_in={}, total_b_in={}, final_pool_a={}, final_pool_b={}",
+            pair_id,
+            total_a_in,
+            total_b_in,
+            final_pool_a,
+            final_pool_b
+        );
+    }
+
+    Netting {
+        pair_results,
+        pending_routes,
+        route_amounts,
+        route_min_out,
+        notional_usdc,
+        fees_usdc,
+        netting_buffers,
+        last_prices,
+        price_band_breaches,
+    }
+}
+
+/// Write the netting results to the BatchLog, pool and ProtocolStats.
+pub fn record_results(
+    accounts: &mut RevealBatchCallback,
+    netting: &Netting,
+    now: i64,
+) -> Result<()> {
+    // Update BatchLog (already initialized in execute_batch)
+    let batch_log = &mut accounts.batch_log;
+    batch_log.batch_id = accounts.batch_accumulator.batch_id;
+    batch_log.results = netting.pair_results;
+    batch_log.pending_routes = netting.pending_routes;
+    batch_log.route_amounts = netting.route_amounts;
+    batch_log.route_min_out = netting.route_min_out;
+    batch_log.executed_at = Clock::get()?.unix_timestamp;
+    batch_log.unsettled_count = accounts.batch_accumulator.order_count;
+    batch_log.notional_usdc = netting.notional_usdc.min(u64::MAX as u128) as u64;
+    batch_log.price_band_breaches = netting.price_band_breaches;
+    for (pair_id, result) in netting.pair_results.iter().enumerate() {
+        if netting.price_band_breaches & (1 << pair_id) != 0 {
+            emit!(PriceBandBreachedEvent {
+                batch_id: batch_log.batch_id,
+                pair_id: pair_id as u8,
+                execution_price: result.execution_price,
+                reference_price: netting.last_prices[pair_id],
+                max_deviation_bps: batch_log.price_bands[pair_id],
+                seq: accounts.event_cursor.next(Clock::get()?.slot),
+            });
+        }
+    }
+    if batch_log.awaiting_approval() {
+        emit!(BatchApprovalRequestedEvent {
+            batch_id: batch_log.batch_id,
+            notional_usdc: batch_log.notional_usdc,
+            threshold_usdc: batch_log.approval_threshold_usdc,
+            seq: accounts.event_cursor.next(Clock::get()?.slot),
+        });
+    }
+
+    accounts.pool.total_batches_executed += 1;
+    accounts.pool.last_execution_prices = netting.last_prices;
+
+    // Analytics: executed volume (refunded parts excluded), revenue and
+    // how long the reveal took
+    let stats = &mut accounts.protocol_stats;
+    for (pair_id, result) in netting.pair_results.iter().enumerate() {
+        stats.volume_a[pair_id] =
+            stats.volume_a[pair_id].saturating_add(result.total_a_in - result.refund_a());
+        stats.volume_b[pair_id] =
+            stats.volume_b[pair_id].saturating_add(result.total_b_in - result.refund_b());
+    }
+    let reveal_secs = now.saturating_sub(accounts.batch_accumulator.reveal_queued_at);
+    stats.record_reveal(
+        reveal_secs.max(0) as u64,
+        accounts.batch_log.notional_usdc,
+        netting.fees_usdc.min(u64::MAX as u128) as u64,
+    );
+
+    Ok(())
+}
+
+/// Free the revealed accumulator slot and signal execute_swaps.
+pub fn reset_revealed_slot(accounts: &mut RevealBatchCallback, netting: &Netting) -> Result<()> {
+    // Reset the revealed slot: it stays inactive (the other slot already
+    // collects the next batch) and is idle until execute_batch hands the
+    // active flag back to it
+    let batch = &mut accounts.batch_accumulator;
+    let old_batch_id = batch.batch_id;
+    // Reset plaintext order_count for next batch
+    batch.order_count = 0;
+    batch.basket_count = 0;
+    batch.status = state::BATCH_STATUS_OPEN;
+    batch.reveal_queued_at = 0;
+    batch.netting_buffers = netting.netting_buffers;
+    batch.reveal_computation_offset = 0;
+    batch.failed_computation_offset = 0;
+    batch.reveal_retries = 0;
+
+    msg!("Batch {} executed", old_batch_id);
+
+    // Emit event for backend to trigger execute_swaps
+    emit!(BatchExecutedEvent {
+        batch_id: old_batch_id,
+        batch_log: accounts.batch_log.key(),
+        seq: accounts.event_cursor.next(Clock::get()?.slot),
+    });
+
+    Ok(())
+}
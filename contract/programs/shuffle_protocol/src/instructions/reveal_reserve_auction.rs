@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+use arcium_anchor::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::AuctionStatus;
+use crate::{RevealAuctionCallback, RevealReserveAuction};
+
+// =============================================================================
+// REVEAL RESERVE AUCTION - Reveal clearing price after the deadline
+// =============================================================================
+// Permissionless. The status only changes in the callback, so a failed
+// computation can simply be re-queued.
+
+/// Queue MPC to reveal the auction's clearing price and winner.
+///
+/// # Arguments
+/// * `computation_offset` - Unique ID for this MPC computation
+pub fn handler(ctx: Context<RevealReserveAuction>, computation_offset: u64) -> Result<()> {
+    let auction = &ctx.accounts.reserve_auction;
+
+    require!(
+        auction.status == AuctionStatus::Open,
+        ErrorCode::InvalidAuctionStatus
+    );
+    require!(
+        Clock::get()?.unix_timestamp >= auction.bid_deadline,
+        ErrorCode::AuctionStillOpen
+    );
+
+    ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+    let args = ArgBuilder::new()
+        .plaintext_u128(auction.mxe_nonce)
+        .account(
+            auction.key(),
+            8,      // Skip discriminator
+            2 * 32, // best_price + best_bidder ciphertexts
+        )
+        .plaintext_u64(auction.reserve_price)
+        .build();
+
+    use arcium_client::idl::arcium::types::CallbackAccount;
+    queue_computation(
+        ctx.accounts,
+        computation_offset,
+        args,
+        vec![RevealAuctionCallback::callback_ix(
+            computation_offset,
+            &ctx.accounts.mxe_account,
+            &[
+                CallbackAccount {
+                    pubkey: ctx.accounts.reserve_auction.key(),
+                    is_writable: true,
+                },
+                crate::event_cursor_callback_account(ctx.accounts.pool.pool_id),
+            ],
+        )?],
+        1, // number of callbacks
+        0, // priority
+    )?;
+
+    msg!(
+        "Auction reveal queued: auction={}, bids={}",
+        ctx.accounts.reserve_auction.auction_id,
+        ctx.accounts.reserve_auction.bid_count
+    );
+
+    Ok(())
+}
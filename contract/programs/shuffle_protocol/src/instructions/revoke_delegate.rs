@@ -0,0 +1,19 @@
+use anchor_lang::prelude::*;
+
+use crate::{DelegateRevokedEvent, RevokeDelegate};
+
+/// Revoke a session key and reclaim the account rent.
+pub fn handler(ctx: Context<RevokeDelegate>) -> Result<()> {
+    emit!(DelegateRevokedEvent {
+        owner: ctx.accounts.user.key(),
+        delegate: ctx.accounts.delegate_account.delegate,
+        seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+    });
+
+    msg!(
+        "Delegate {} revoked for {}",
+        ctx.accounts.delegate_account.delegate,
+        ctx.accounts.user.key()
+    );
+    Ok(())
+}
@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::ROLE_COUNT;
+use crate::{RevokeRole, RoleRevokedEvent};
+
+/// Revoke a granted role. Only callable by an admin. Pool.authority and
+/// Pool.operator keep their implicit roles.
+///
+/// # Arguments
+/// * `role` - ROLE_* constant
+/// * `member` - Wallet to revoke the role from
+pub fn handler(ctx: Context<RevokeRole>, role: u8, member: Pubkey) -> Result<()> {
+    require!((role as usize) < ROLE_COUNT, ErrorCode::InvalidRole);
+
+    let roles = &mut ctx.accounts.roles;
+    let slot = roles.members[role as usize]
+        .iter_mut()
+        .find(|slot| **slot == member && member != Pubkey::default())
+        .ok_or(ErrorCode::InvalidRole)?;
+    *slot = Pubkey::default();
+
+    emit!(RoleRevokedEvent {
+        role,
+        member,
+        revoked_by: ctx.accounts.authority.key(),
+        seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+    });
+
+    msg!("Role {} revoked from {}", role, member);
+    Ok(())
+}
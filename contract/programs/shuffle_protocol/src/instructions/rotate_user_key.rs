@@ -0,0 +1,114 @@
+use anchor_lang::prelude::*;
+use arcium_anchor::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::{RotateUserKey, RotateUserKeyCallback};
+
+// =============================================================================
+// ROTATE USER KEY - Move all balances to a new x25519 key
+// =============================================================================
+// Recovery path for a compromised encryption key. The wallet signs, so a
+// leaked x25519 secret alone cannot rotate the key away from the owner.
+//
+// Flow:
+// 1. Owner calls rotate_user_key with the new public key and a fresh nonce
+// 2. Handler snapshots the balance nonces and queues rotate_user_key with
+//    all four balances; balance i is re-encrypted with nonce + i
+// 3. Callback checks no balance changed in between, then writes the new
+//    key, credits and nonces together
+//
+// Orders are encrypted for the old key, so the account may not have a
+// pending or staged order. Side PDAs encrypted for user_pubkey
+// (SettlementPreference, delegate allowances) keep their old ciphertexts and
+// should be set again after rotating.
+
+/// Queue a re-encryption of all balances under a new user key.
+///
+/// # Arguments
+/// * `computation_offset` - Unique ID for MPC computation
+/// * `new_user_pubkey` - New x25519 public key
+/// * `nonce` - Fresh nonce; balance i (0=USDC..3=AAPL) uses nonce + i
+pub fn handler(
+    ctx: Context<RotateUserKey>,
+    computation_offset: u64,
+    new_user_pubkey: [u8; 32],
+    nonce: u128,
+) -> Result<()> {
+    let user = &ctx.accounts.user_account;
+    require!(
+        new_user_pubkey != [0u8; 32] && new_user_pubkey != user.user_pubkey,
+        ErrorCode::InvalidUserKey
+    );
+    require!(
+        user.pending_order.is_none() && user.staged_order.is_none(),
+        ErrorCode::PendingOrderExists
+    );
+
+    let source_nonces: [u128; 4] = std::array::from_fn(|asset| user.get_nonce(asset as u8));
+    let rotation = &mut ctx.accounts.key_rotation;
+    rotation.owner = ctx.accounts.user.key();
+    rotation.pending_user_pubkey = new_user_pubkey;
+    rotation.source_nonces = source_nonces;
+    rotation.bump = ctx.bumps.key_rotation;
+
+    ctx.accounts.user_account.last_active_at = Clock::get()?.unix_timestamp;
+
+    // Set sign PDA bump
+    ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+    let user = &ctx.accounts.user_account;
+    let args = ArgBuilder::new()
+        // Current balances (Enc<Shared, *> - old key)
+        .x25519_pubkey(user.user_pubkey)
+        .plaintext_u128(user.usdc_nonce)
+        .encrypted_u64(user.usdc_credit)
+        .x25519_pubkey(user.user_pubkey)
+        .plaintext_u128(user.tsla_nonce)
+        .encrypted_u64(user.tsla_credit)
+        .x25519_pubkey(user.user_pubkey)
+        .plaintext_u128(user.spy_nonce)
+        .encrypted_u64(user.spy_credit)
+        .x25519_pubkey(user.user_pubkey)
+        .plaintext_u128(user.aapl_nonce)
+        .encrypted_u64(user.aapl_credit)
+        // New owners (Shared - new key, one nonce per balance)
+        .x25519_pubkey(new_user_pubkey)
+        .plaintext_u128(nonce)
+        .x25519_pubkey(new_user_pubkey)
+        .plaintext_u128(nonce.wrapping_add(1))
+        .x25519_pubkey(new_user_pubkey)
+        .plaintext_u128(nonce.wrapping_add(2))
+        .x25519_pubkey(new_user_pubkey)
+        .plaintext_u128(nonce.wrapping_add(3))
+        .build();
+
+    use arcium_client::idl::arcium::types::CallbackAccount;
+    queue_computation(
+        ctx.accounts,
+        computation_offset,
+        args,
+        vec![RotateUserKeyCallback::callback_ix(
+            computation_offset,
+            &ctx.accounts.mxe_account,
+            &[
+                CallbackAccount {
+                    pubkey: ctx.accounts.user_account.key(),
+                    is_writable: true,
+                },
+                CallbackAccount {
+                    pubkey: ctx.accounts.key_rotation.key(),
+                    is_writable: true,
+                },
+                crate::event_cursor_callback_account(ctx.accounts.pool.pool_id),
+            ],
+        )?],
+        1,
+        0,
+    )?;
+
+    msg!(
+        "Key rotation queued for {}",
+        ctx.accounts.user_account.owner
+    );
+    Ok(())
+}
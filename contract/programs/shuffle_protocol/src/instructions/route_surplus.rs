@@ -0,0 +1,256 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::state::{BatchLog, PAIR_ASSETS, POLICY_BEST_QUOTE};
+use crate::{RouteSurplus, SurplusRoutedEvent};
+
+// =============================================================================
+// ROUTE SURPLUS - Fill a pair's net surplus on the external venue
+// =============================================================================
+// In external-only mode, or under a routing pair policy, reveal_batch leaves
+// (part of) a pair's net surplus in the deposit vault, records the amount in
+// BatchLog.route_amounts and flags the pair in BatchLog.pending_routes.
+// The operator swaps it out of the deposit vault through the configured
+// swap adapter; the output lands in the other asset's vault and the
+// realized amount is added to that side's final pool, so settlement pays
+// out exactly what the venue returned.
+//
+// The output must reach BatchLog.route_min_out (oracle value minus the
+// batch's max slippage) whatever min_amount_out the operator passes.
+//
+// POLICY_BEST_QUOTE pairs: min_amount_out is the operator's venue quote.
+// If the reserves pay more for the surplus (oracle price minus the pool's
+// reserve spread) and hold enough, they fill it instead of the venue.
+//
+// Settlement of the pair is blocked until its route completes. If the venue
+// cannot fill the surplus the swap reverts and the route can be retried.
+
+/// Route one pair's net surplus to the external venue. Only callable by the operator.
+///
+/// # Arguments
+/// * `batch_id` - Executed batch the surplus belongs to
+/// * `pair_id` - Pair to route (0-5)
+/// * `min_amount_out` - Minimum output accepted from the venue (slippage protection,
+///   and the quote reserves must beat under POLICY_BEST_QUOTE)
+pub fn handler(
+    ctx: Context<RouteSurplus>,
+    batch_id: u64,
+    pair_id: u8,
+    min_amount_out: u64,
+) -> Result<()> {
+    let batch_log = &ctx.accounts.batch_log;
+    let route = pending_route(batch_log, pair_id)?;
+    let (source_asset, output_asset, amount_in) =
+        (route.source_asset, route.output_asset, route.amount_in);
+    let min_amount_out = min_amount_out.max(route.min_out);
+    let best_quote = batch_log.pair_policies[pair_id as usize].strategy == POLICY_BEST_QUOTE;
+
+    // Swap between the deposit vaults, never the reserves (same mint/authority)
+    let pool = &ctx.accounts.pool;
+    require!(
+        pool.mint_for_asset(source_asset) == Some(ctx.accounts.source_mint.key())
+            && pool.mint_for_asset(output_asset) == Some(ctx.accounts.destination_mint.key()),
+        ErrorCode::InvalidMint
+    );
+    require!(
+        ctx.accounts.source_vault.key() == vault_address(pool.pool_id, source_asset)?
+            && ctx.accounts.destination_vault.key() == vault_address(pool.pool_id, output_asset)?,
+        ErrorCode::InvalidVault
+    );
+
+    // Reserve price for the surplus: the batch's oracle snapshot minus the
+    // reserve spread
+    let bps = BPS_DENOMINATOR as u128;
+    let unit_prices = pool.unit_prices(&ctx.accounts.batch_log.oracle_prices);
+    let reserve_out = ((amount_in as u128 * unit_prices[source_asset as usize]
+        / unit_prices[output_asset as usize])
+        * (bps - pool.reserve_spread_bps as u128)
+        / bps) as u64;
+    let reserves = match (
+        ctx.accounts.source_reserve.as_ref(),
+        ctx.accounts.destination_reserve.as_ref(),
+    ) {
+        (Some(source_reserve), Some(destination_reserve)) if best_quote => {
+            require!(
+                source_reserve.key() == reserve_address(pool.pool_id, source_asset)?
+                    && destination_reserve.key() == reserve_address(pool.pool_id, output_asset)?,
+                ErrorCode::InvalidReserve
+            );
+            Some((source_reserve, destination_reserve))
+        }
+        _ => None,
+    };
+
+    let (amount_out, via_reserves) = match reserves {
+        // Reserves beat the venue quote: they take the surplus and pay out
+        Some((source_reserve, destination_reserve))
+            if reserve_out > min_amount_out && destination_reserve.amount >= reserve_out =>
+        {
+            crate::execute_vault_to_reserve_transfer(
+                &ctx.accounts.source_vault,
+                source_reserve,
+                &ctx.accounts.pool.to_account_info(),
+                &ctx.accounts.token_program,
+                amount_in,
+                ctx.accounts.pool.pool_id,
+                ctx.accounts.pool.bump,
+            )?;
+            crate::execute_reserve_to_vault_transfer(
+                destination_reserve,
+                &ctx.accounts.destination_vault,
+                &ctx.accounts.pool.to_account_info(),
+                &ctx.accounts.token_program,
+                reserve_out,
+                ctx.accounts.pool.pool_id,
+                ctx.accounts.pool.bump,
+            )?;
+            (reserve_out, true)
+        }
+        _ => {
+            let balance_before = ctx.accounts.destination_vault.amount;
+            crate::invoke_swap_adapter(
+                &ctx.accounts.swap_program.to_account_info(),
+                [
+                    ctx.accounts.pool.to_account_info(),
+                    ctx.accounts.swap_pool.to_account_info(),
+                    ctx.accounts.source_mint.to_account_info(),
+                    ctx.accounts.destination_mint.to_account_info(),
+                    ctx.accounts.source_vault.to_account_info(),
+                    ctx.accounts.destination_vault.to_account_info(),
+                    ctx.accounts.swap_source_vault.to_account_info(),
+                    ctx.accounts.swap_dest_vault.to_account_info(),
+                    ctx.accounts.token_program.to_account_info(),
+                ],
+                amount_in,
+                min_amount_out,
+                ctx.accounts.pool.pool_id,
+                ctx.accounts.pool.bump,
+            )?;
+
+            ctx.accounts.destination_vault.reload()?;
+            let amount_out = ctx
+                .accounts
+                .destination_vault
+                .amount
+                .saturating_sub(balance_before);
+            require!(amount_out >= min_amount_out, ErrorCode::MinOutputNotMet);
+            (amount_out, false)
+        }
+    };
+
+    record_route_output(&mut ctx.accounts.batch_log, pair_id, &route, amount_out);
+
+    emit!(SurplusRoutedEvent {
+        batch_id,
+        pair_id,
+        source_asset_id: source_asset,
+        amount_in,
+        amount_out,
+        via_reserves,
+        seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+    });
+
+    msg!(
+        "Surplus routed: batch={}, pair={}, {} of asset {} -> {} of asset {}, via_reserves={}",
+        batch_id,
+        pair_id,
+        amount_in,
+        source_asset,
+        amount_out,
+        output_asset,
+        via_reserves
+    );
+
+    Ok(())
+}
+
+/// A pair's pending route, as recorded by reveal_batch
+pub(crate) struct PendingRoute {
+    /// Surplus is on the A side (the base asset is sold)
+    pub sells_a: bool,
+    pub source_asset: u8,
+    pub output_asset: u8,
+    /// Surplus to swap out of the source vault
+    pub amount_in: u64,
+    /// Least output the route may return (BatchLog.route_min_out)
+    pub min_out: u64,
+}
+
+/// Look up a pair's pending route; the batch must be executed, approved and
+/// within its price bands (or overridden)
+pub(crate) fn pending_route(batch_log: &BatchLog, pair_id: u8) -> Result<PendingRoute> {
+    require!(pair_id <= 5, ErrorCode::InvalidPairId);
+    require!(batch_log.executed_at > 0, ErrorCode::BatchNotFinalized);
+    require!(
+        !batch_log.awaiting_approval(),
+        ErrorCode::BatchApprovalRequired
+    );
+    require!(
+        !batch_log.awaiting_price_override(),
+        ErrorCode::PriceBandBreached
+    );
+    require!(
+        batch_log.pending_routes & (1 << pair_id) != 0,
+        ErrorCode::InvalidOrderRoute
+    );
+
+    // The surplus side is the one whose final pool is below its deposits
+    let result = batch_log.results[pair_id as usize];
+    let (base_asset, quote_asset) = PAIR_ASSETS[pair_id as usize];
+    let sells_a = result.final_pool_a < result.total_a_in;
+    let (source_asset, output_asset) = if sells_a {
+        (base_asset, quote_asset)
+    } else {
+        (quote_asset, base_asset)
+    };
+    Ok(PendingRoute {
+        sells_a,
+        source_asset,
+        output_asset,
+        amount_in: batch_log.route_amounts[pair_id as usize],
+        min_out: batch_log.route_min_out[pair_id as usize],
+    })
+}
+
+/// Credit a completed route's realized output to the other side's pool for
+/// settlement and clear the pending flag
+pub(crate) fn record_route_output(
+    batch_log: &mut BatchLog,
+    pair_id: u8,
+    route: &PendingRoute,
+    amount_out: u64,
+) {
+    let result = &mut batch_log.results[pair_id as usize];
+    if route.sells_a {
+        result.final_pool_b = result.final_pool_b.saturating_add(amount_out);
+    } else {
+        result.final_pool_a = result.final_pool_a.saturating_add(amount_out);
+    }
+    batch_log.routed_out[pair_id as usize] = amount_out;
+    batch_log.pending_routes &= !(1 << pair_id);
+}
+
+/// Deposit vault PDA of a pool for an asset
+pub(crate) fn vault_address(pool_id: u8, asset_id: u8) -> Result<Pubkey> {
+    let vault_seed = match asset_id {
+        0 => VAULT_USDC_SEED,
+        1 => VAULT_TSLA_SEED,
+        2 => VAULT_SPY_SEED,
+        3 => VAULT_AAPL_SEED,
+        _ => return Err(ErrorCode::InvalidAssetId.into()),
+    };
+    Ok(Pubkey::find_program_address(&[VAULT_SEED, &[pool_id], vault_seed], &crate::ID).0)
+}
+
+/// Reserve PDA of a pool for an asset
+pub(crate) fn reserve_address(pool_id: u8, asset_id: u8) -> Result<Pubkey> {
+    let reserve_seed = match asset_id {
+        0 => RESERVE_USDC_SEED,
+        1 => RESERVE_TSLA_SEED,
+        2 => RESERVE_SPY_SEED,
+        3 => RESERVE_AAPL_SEED,
+        _ => return Err(ErrorCode::InvalidAssetId.into()),
+    };
+    Ok(Pubkey::find_program_address(&[RESERVE_SEED, &[pool_id], reserve_seed], &crate::ID).0)
+}
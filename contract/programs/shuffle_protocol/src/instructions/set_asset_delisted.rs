@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::{AssetDelistingUpdatedEvent, SetAssetDelisted};
+
+// =============================================================================
+// ASSET DELISTING
+// =============================================================================
+// Winds an asset down without trapping funds. Unlike freeze_asset, existing
+// positions stay reachable, and relisting takes effect immediately.
+//
+// While delisted:
+// - add_balance rejects the asset; sub_balance (withdrawals) still works
+// - place_order / execute_dca reject it as the source asset
+// - execute_batch halts every pair that touches the asset, so orders buying
+//   it are refunded in full; batches already revealed settle normally
+
+/// Delist or relist an asset. Only callable by pool authority.
+///
+/// # Arguments
+/// * `asset_id` - Asset to update (0=USDC, 1=TSLA, 2=SPY, 3=AAPL)
+/// * `delisted` - true to block new deposits and orders, false to relist
+pub fn handler(ctx: Context<SetAssetDelisted>, asset_id: u8, delisted: bool) -> Result<()> {
+    require!(asset_id <= 3, ErrorCode::InvalidAssetId);
+
+    let pool = &mut ctx.accounts.pool;
+    if delisted {
+        pool.delisted_assets |= 1 << asset_id;
+    } else {
+        pool.delisted_assets &= !(1 << asset_id);
+    }
+
+    emit!(AssetDelistingUpdatedEvent {
+        asset_id,
+        delisted,
+        seq: ctx.accounts.event_cursor.next(Clock::get()?.slot)
+    });
+
+    msg!(
+        "Asset {} {}",
+        asset_id,
+        if delisted { "delisted" } else { "relisted" }
+    );
+    Ok(())
+}
@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::{BatchApprovalConfigUpdatedEvent, SetBatchApproval};
+
+// =============================================================================
+// SET BATCH APPROVAL - Co-operator and large batch threshold
+// =============================================================================
+// Batches whose revealed notional exceeds threshold_usdc need approve_batch
+// from the co-operator before execute_swaps or route_surplus. The threshold
+// is snapshotted into the BatchLog at execute_batch, so a change applies
+// from the next batch executed.
+//
+// The co-operator must be a different key than the operator, otherwise a
+// single key could still move a large batch on its own.
+
+/// Update Pool.co_operator and Pool.approval_threshold_usdc.
+/// Only callable by pool authority.
+///
+/// # Arguments
+/// * `co_operator` - Second operator that approves large batches
+/// * `threshold_usdc` - Notional in USDC base units above which approval is required (0 = disabled)
+pub fn handler(
+    ctx: Context<SetBatchApproval>,
+    co_operator: Pubkey,
+    threshold_usdc: u64,
+) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+    if threshold_usdc > 0 {
+        require!(
+            co_operator != Pubkey::default() && co_operator != pool.operator,
+            ErrorCode::InvalidApprovalConfig
+        );
+    }
+
+    pool.co_operator = co_operator;
+    pool.approval_threshold_usdc = threshold_usdc;
+
+    emit!(BatchApprovalConfigUpdatedEvent {
+        co_operator,
+        threshold_usdc,
+        seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+    });
+
+    msg!(
+        "Batch approval updated: co-operator {}, threshold {} USDC units",
+        co_operator,
+        threshold_usdc
+    );
+    Ok(())
+}
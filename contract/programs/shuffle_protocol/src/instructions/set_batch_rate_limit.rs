@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::{BatchRateLimitUpdatedEvent, SetBatchRateLimit};
+
+// =============================================================================
+// SET BATCH RATE LIMIT - Cap batch executions per window
+// =============================================================================
+// Every batch costs a reveal, its callback and a BatchLog, and a batch flushed
+// with few orders is a small privacy set. execute_batch therefore queues at
+// most max_batches_per_window new batches per window_secs. A window starts
+// with the first batch queued after the previous window elapsed.
+//
+// The deadline takes precedence: once the current batch has been open for
+// max_batch_wait_secs (measured from the previous execute_batch, when its
+// slot became active) it may execute even if the window is full, so orders
+// never wait indefinitely. Re-revealing a recovered batch is not limited.
+
+/// Update the batch rate limit. Only callable by an admin or risk manager.
+///
+/// # Arguments
+/// * `max_batches_per_window` - New batches allowed per window (0 = unlimited)
+/// * `window_secs` - Window length (1..=MAX_BATCH_WINDOW_SECS)
+/// * `max_batch_wait_secs` - Deadline after which a batch ignores the limit (0 = none)
+pub fn handler(
+    ctx: Context<SetBatchRateLimit>,
+    max_batches_per_window: u16,
+    window_secs: i64,
+    max_batch_wait_secs: i64,
+) -> Result<()> {
+    require!(
+        (1..=MAX_BATCH_WINDOW_SECS).contains(&window_secs) && max_batch_wait_secs >= 0,
+        ErrorCode::InvalidBatchConfig
+    );
+
+    let pool = &mut ctx.accounts.pool;
+    pool.max_batches_per_window = max_batches_per_window;
+    pool.batch_window_secs = window_secs;
+    pool.max_batch_wait_secs = max_batch_wait_secs;
+
+    emit!(BatchRateLimitUpdatedEvent {
+        max_batches_per_window,
+        window_secs,
+        max_batch_wait_secs,
+        seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+    });
+
+    msg!(
+        "Batch rate limit updated: {} batches per {}s, deadline {}s",
+        max_batches_per_window,
+        window_secs,
+        max_batch_wait_secs
+    );
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MIN_INACTIVITY_PERIOD_SECS;
+use crate::errors::ErrorCode;
+use crate::{BeneficiarySetEvent, SetBeneficiary};
+
+/// Register or replace the beneficiary that inherits the caller's balances
+/// after `inactivity_period_secs` without owner activity.
+///
+/// # Arguments
+/// * `beneficiary` - Beneficiary wallet (must already have a privacy account to claim)
+/// * `inactivity_period_secs` - Inactivity before a claim is allowed (>= 30 days)
+pub fn handler(
+    ctx: Context<SetBeneficiary>,
+    beneficiary: Pubkey,
+    inactivity_period_secs: i64,
+) -> Result<()> {
+    require!(
+        inactivity_period_secs >= MIN_INACTIVITY_PERIOD_SECS,
+        ErrorCode::InvalidInactivityPeriod
+    );
+    require!(
+        beneficiary != ctx.accounts.user.key() && beneficiary != Pubkey::default(),
+        ErrorCode::InvalidBeneficiary
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+
+    let config = &mut ctx.accounts.beneficiary_config;
+    config.owner = ctx.accounts.user.key();
+    config.beneficiary = beneficiary;
+    config.inactivity_period_secs = inactivity_period_secs;
+    config.registered_at = now;
+    config.bump = ctx.bumps.beneficiary_config;
+
+    ctx.accounts.user_account.last_active_at = now;
+
+    emit!(BeneficiarySetEvent {
+        owner: config.owner,
+        beneficiary,
+        inactivity_period_secs,
+        seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+    });
+
+    msg!(
+        "Beneficiary {} set for {} after {}s inactivity",
+        beneficiary,
+        config.owner,
+        inactivity_period_secs
+    );
+    Ok(())
+}
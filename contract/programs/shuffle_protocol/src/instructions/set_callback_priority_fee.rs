@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::{CallbackPriorityFeeUpdatedEvent, SetCallbackPriorityFee};
+
+// =============================================================================
+// SET CALLBACK PRIORITY FEE
+// =============================================================================
+// MPC callbacks are submitted by the Arcium cluster and can be dropped when
+// the network is congested. The priority fee passed to queue_computation is
+// paid on the callback transaction, so raising it during congestion makes
+// callbacks land more reliably.
+//
+// Only the price is configurable: callback_ix does not take a compute unit
+// limit, the cluster sizes the callback transaction itself.
+
+/// Update Pool.callback_cu_price_micro. Only callable by pool authority.
+///
+/// # Arguments
+/// * `cu_price_micro` - Priority fee in micro-lamports per CU (0 = none)
+pub fn handler(ctx: Context<SetCallbackPriorityFee>, cu_price_micro: u64) -> Result<()> {
+    require!(
+        cu_price_micro <= MAX_CALLBACK_CU_PRICE_MICRO,
+        ErrorCode::InvalidCallbackPriorityFee
+    );
+
+    let pool = &mut ctx.accounts.pool;
+    let previous_cu_price_micro = pool.callback_cu_price_micro;
+    pool.callback_cu_price_micro = cu_price_micro;
+
+    emit!(CallbackPriorityFeeUpdatedEvent {
+        previous_cu_price_micro,
+        cu_price_micro,
+        seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+    });
+
+    msg!(
+        "Callback priority fee updated: {} -> {} micro-lamports/CU",
+        previous_cu_price_micro,
+        cu_price_micro
+    );
+    Ok(())
+}
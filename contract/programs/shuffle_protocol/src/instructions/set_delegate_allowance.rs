@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::{DelegateAllowanceSetEvent, SetDelegateAllowance};
+
+// =============================================================================
+// SET DELEGATE ALLOWANCE - Cap what a delegate may sell or withdraw
+// =============================================================================
+// The owner encrypts the allowance as a UserBalance for the MXE with the
+// x25519 key stored in their UserProfile (user_pubkey), so the remaining
+// allowance stays readable by the owner as the delegate draws it down.
+// Setting it again replaces the remaining amount; 0 blocks the delegate
+// from selling or withdrawing the asset. The allowance lasts until revoke_delegate.
+
+/// Set or replace a delegate's encrypted allowance for an asset.
+///
+/// # Arguments
+/// * `asset_id` - Asset the allowance applies to (0=USDC, 1=TSLA, 2=SPY, 3=AAPL)
+/// * `encrypted_allowance` - Allowance amount encrypted with the owner's key
+/// * `nonce` - Encryption nonce of the allowance
+pub fn handler(
+    ctx: Context<SetDelegateAllowance>,
+    asset_id: u8,
+    encrypted_allowance: [u8; 32],
+    nonce: u128,
+) -> Result<()> {
+    require!(asset_id <= 3, ErrorCode::InvalidAssetId);
+
+    let delegate = &mut ctx.accounts.delegate_account;
+    delegate.allowances[asset_id as usize] = encrypted_allowance;
+    delegate.allowance_nonces[asset_id as usize] = nonce;
+    delegate.allowance_mask |= 1 << asset_id;
+
+    emit!(DelegateAllowanceSetEvent {
+        owner: delegate.owner,
+        delegate: delegate.delegate,
+        asset_id,
+        seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+    });
+
+    msg!(
+        "Delegate {} allowance set for asset {}",
+        delegate.delegate,
+        asset_id
+    );
+    Ok(())
+}
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::{EmergencyWithdrawUpdatedEvent, SetEmergencyWithdraw};
+
+// =============================================================================
+// SET EMERGENCY WITHDRAW - Let users exit a paused protocol
+// =============================================================================
+// With Pool.emergency_withdraw_enabled set, sub_balance keeps working while
+// Pool.paused is set. It is the only exit the flag opens: everything else
+// the pause halts (see pause_protocol), delegated withdrawals included,
+// stays halted. The flag has no effect on an unpaused protocol and
+// survives unpause_protocol, so a later pause starts with exits already
+// open unless an admin clears it.
+
+/// Open or close the emergency withdrawal path. Only callable by an admin.
+///
+/// # Arguments
+/// * `enabled` - Whether sub_balance stays available while paused
+pub fn handler(ctx: Context<SetEmergencyWithdraw>, enabled: bool) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+    pool.emergency_withdraw_enabled = enabled;
+
+    emit!(EmergencyWithdrawUpdatedEvent {
+        enabled,
+        paused: pool.paused,
+        updated_by: ctx.accounts.authority.key(),
+        seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+    });
+
+    msg!(
+        "Emergency withdraw {} by {}",
+        if enabled { "enabled" } else { "disabled" },
+        ctx.accounts.authority.key()
+    );
+    Ok(())
+}
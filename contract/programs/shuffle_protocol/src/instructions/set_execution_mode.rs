@@ -0,0 +1,98 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::state::EXECUTION_MODE_EXTERNAL_ONLY;
+use crate::{ExecutionModeUpdatedEvent, SetExecutionMode};
+
+// =============================================================================
+// SET EXECUTION MODE - How net surpluses are filled
+// =============================================================================
+// Reserve-only (no external venue): net surpluses are absorbed by the
+// protocol reserves only, priced at the oracle price minus the configured
+// spread. Two limits bound the reserves' exposure:
+// - utilization: share of each reserve a single batch may pay out
+// - inventory: max balance each reserve may hold after absorbing surplus
+// Surplus beyond either limit is left unfilled and refunded at settlement.
+//
+// External-only (no inventory): reserves are never used. Each pair's net
+// surplus is swapped through swap_program by route_surplus and the realized
+// output is what that pair's orders settle against.
+//
+// Individual pairs can override the mode with set_pair_policy.
+//
+// Cross-batch netting: with a netting window, a surplus that would be routed
+// externally is absorbed by the reserves instead and held in the pair's
+// netting buffer. An opposite surplus on the same pair in a later batch is
+// paid out of that inventory; whatever is left when the window elapses is
+// sold on the venue with flush_netting_buffer.
+//
+// Settings are snapshotted into the BatchLog by execute_batch, so a change
+// applies from the next batch executed.
+
+/// Configure the execution mode. Only callable by pool authority.
+///
+/// # Arguments
+/// * `execution_mode` - EXECUTION_MODE_DEFAULT, _RESERVE_ONLY or _EXTERNAL_ONLY
+/// * `spread_bps` - Spread kept by the reserves on absorbed surplus (reserve-only)
+/// * `max_utilization_bps` - Max share of each reserve one batch may pay out (1-10_000)
+/// * `max_inventory` - Max balance per reserve [USDC, TSLA, SPY, AAPL] (0 = no limit)
+/// * `swap_program` - Swap adapter surpluses are routed through (external-only)
+/// * `netting_window_secs` - Cross-batch netting window (0 = disabled)
+pub fn handler(
+    ctx: Context<SetExecutionMode>,
+    execution_mode: u8,
+    spread_bps: u16,
+    max_utilization_bps: u16,
+    max_inventory: [u64; 4],
+    swap_program: Pubkey,
+    netting_window_secs: i64,
+) -> Result<()> {
+    require!(
+        execution_mode <= EXECUTION_MODE_EXTERNAL_ONLY,
+        ErrorCode::InvalidExecutionConfig
+    );
+    require!(
+        spread_bps <= MAX_RESERVE_SPREAD_BPS,
+        ErrorCode::InvalidExecutionConfig
+    );
+    require!(
+        max_utilization_bps > 0 && max_utilization_bps <= BPS_DENOMINATOR,
+        ErrorCode::InvalidExecutionConfig
+    );
+    require!(
+        (0..=MAX_NETTING_WINDOW_SECS).contains(&netting_window_secs),
+        ErrorCode::InvalidExecutionConfig
+    );
+    require!(
+        execution_mode != EXECUTION_MODE_EXTERNAL_ONLY || swap_program != Pubkey::default(),
+        ErrorCode::InvalidSwapProgram
+    );
+
+    let pool = &mut ctx.accounts.pool;
+    pool.execution_mode = execution_mode;
+    pool.reserve_spread_bps = spread_bps;
+    pool.max_reserve_utilization_bps = max_utilization_bps;
+    pool.max_reserve_inventory = max_inventory;
+    pool.swap_program = swap_program;
+    pool.netting_window_secs = netting_window_secs;
+
+    emit!(ExecutionModeUpdatedEvent {
+        execution_mode,
+        spread_bps,
+        max_utilization_bps,
+        max_inventory,
+        swap_program,
+        netting_window_secs,
+        seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+    });
+
+    msg!(
+        "Execution mode updated: mode={}, spread={} bps, utilization={} bps, netting window={}s",
+        execution_mode,
+        spread_bps,
+        max_utilization_bps,
+        netting_window_secs
+    );
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::{ForceSettleGraceUpdatedEvent, SetSettlementDeadline};
+
+// =============================================================================
+// SET FORCE SETTLE GRACE
+// =============================================================================
+// Controls how long users have to settle their own orders before anyone may
+// force_settle them. Like the settlement deadline it is measured from each
+// batch's executed_at, so a change also applies to batches that already
+// executed. It stays below the deadline so stale orders can be cranked
+// before their payouts are forfeited.
+
+/// Update Pool.force_settle_grace_secs. Only callable by pool authority.
+/// Uses the SetSettlementDeadline accounts.
+///
+/// # Arguments
+/// * `grace_secs` - Seconds after batch execution before force_settle is allowed
+pub fn handler(ctx: Context<SetSettlementDeadline>, grace_secs: i64) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+    require!(
+        grace_secs > 0 && grace_secs < pool.settlement_deadline_secs,
+        ErrorCode::InvalidForceSettleGrace
+    );
+
+    let previous_secs = pool.force_settle_grace_secs;
+    pool.force_settle_grace_secs = grace_secs;
+
+    emit!(ForceSettleGraceUpdatedEvent {
+        previous_secs,
+        grace_secs,
+        seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+    });
+
+    msg!(
+        "Force settle grace updated: {}s -> {}s",
+        previous_secs,
+        grace_secs
+    );
+    Ok(())
+}
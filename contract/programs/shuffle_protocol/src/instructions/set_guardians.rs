@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{MAX_GUARDIANS, MIN_RECOVERY_CHALLENGE_SECS};
+use crate::errors::ErrorCode;
+use crate::{GuardiansSetEvent, SetGuardians};
+
+/// Register or replace the guardians that can recover the caller's
+/// account. Replacing them discards any recovery in progress.
+///
+/// # Arguments
+/// * `guardians` - Guardian wallets (1..=MAX_GUARDIANS, distinct, not the owner)
+/// * `threshold` - Approvals needed for a recovery (1..=guardians.len())
+/// * `challenge_period_secs` - Delay after approval (>= MIN_RECOVERY_CHALLENGE_SECS)
+pub fn handler(
+    ctx: Context<SetGuardians>,
+    guardians: Vec<Pubkey>,
+    threshold: u8,
+    challenge_period_secs: i64,
+) -> Result<()> {
+    let owner = ctx.accounts.user.key();
+    let count = guardians.len();
+    let distinct = guardians
+        .iter()
+        .enumerate()
+        .all(|(i, g)| *g != owner && *g != Pubkey::default() && !guardians[..i].contains(g));
+    require!(
+        (1..=MAX_GUARDIANS).contains(&count)
+            && distinct
+            && threshold >= 1
+            && threshold as usize <= count
+            && challenge_period_secs >= MIN_RECOVERY_CHALLENGE_SECS,
+        ErrorCode::InvalidGuardians
+    );
+
+    let set = &mut ctx.accounts.guardian_set;
+    set.owner = owner;
+    set.pool_id = ctx.accounts.pool.pool_id;
+    set.guardians = [Pubkey::default(); MAX_GUARDIANS];
+    set.guardians[..count].copy_from_slice(&guardians);
+    set.guardian_count = count as u8;
+    set.threshold = threshold;
+    set.challenge_period_secs = challenge_period_secs;
+    set.clear_proposal();
+    set.bump = ctx.bumps.guardian_set;
+
+    ctx.accounts.user_account.last_active_at = Clock::get()?.unix_timestamp;
+
+    emit!(GuardiansSetEvent {
+        owner,
+        guardians,
+        threshold,
+        challenge_period_secs,
+        seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+    });
+
+    msg!(
+        "{} guardians set for {} (threshold {})",
+        count,
+        owner,
+        threshold
+    );
+    Ok(())
+}
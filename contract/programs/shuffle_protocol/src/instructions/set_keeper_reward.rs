@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::{KeeperRewardUpdatedEvent, SetKeeperReward};
+
+// =============================================================================
+// SET KEEPER REWARD
+// =============================================================================
+// execute_batch is permissionless, but the caller pays the transaction and
+// the BatchLog rent. The keeper reward pays that caller so running the crank
+// is worth it.
+//
+// The reward is keeper_reward_bps of the batch's revealed notional, paid in
+// USDC out of Pool.fees_owed when execute_swaps completes the batch. It is
+// capped at the execution fees that batch accrued (and at the USDC owed), so
+// it never draws on user deposits or other batches' fees. execute_batch
+// snapshots the rate into the BatchLog, so changing it here only affects
+// batches queued afterwards.
+
+/// Update Pool.keeper_reward_bps. Only callable by pool authority.
+///
+/// # Arguments
+/// * `reward_bps` - Basis points of batch notional (0 = no reward)
+pub fn handler(ctx: Context<SetKeeperReward>, reward_bps: u16) -> Result<()> {
+    require!(
+        reward_bps <= MAX_KEEPER_REWARD_BPS,
+        ErrorCode::InvalidKeeperReward
+    );
+
+    let pool = &mut ctx.accounts.pool;
+    let previous_reward_bps = pool.keeper_reward_bps;
+    pool.keeper_reward_bps = reward_bps;
+
+    emit!(KeeperRewardUpdatedEvent {
+        previous_reward_bps,
+        reward_bps,
+        seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+    });
+
+    msg!(
+        "Keeper reward updated: {} -> {} bps of batch notional",
+        previous_reward_bps,
+        reward_bps
+    );
+    Ok(())
+}
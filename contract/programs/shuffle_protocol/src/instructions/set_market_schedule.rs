@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::{MAX_MARKET_HOLIDAYS, SECONDS_PER_DAY};
+use crate::{MarketScheduleUpdatedEvent, SetMarketSchedule};
+
+// =============================================================================
+// SET MARKET SCHEDULE - Equity trading hours
+// =============================================================================
+// Writes the MarketSchedule (see state/market_schedule.rs) and switches its
+// enforcement. The schedule account is created on first call. While
+// enforced, execute_batch halts the pairs of the equity assets outside
+// trading hours, or rejects the batch with MarketClosed when that is every
+// pair, so the orders wait for the open instead of being refunded.
+//
+// Holidays replace the previous list; past days can be dropped whenever the
+// list is refreshed.
+
+/// Update the market schedule. Only callable by pool authority.
+///
+/// # Arguments
+/// * `enforced` - Whether execute_batch follows the schedule
+/// * `open_secs` - Market open, seconds after UTC midnight
+/// * `close_secs` - Market close, seconds after UTC midnight
+/// * `trading_days` - Days the market opens (bit 0 = Monday)
+/// * `holidays` - Closed UTC days (unix_timestamp / 86400), at most MAX_MARKET_HOLIDAYS
+/// * `equity_assets` - Assets that follow the schedule (bit N = asset_id N)
+pub fn handler(
+    ctx: Context<SetMarketSchedule>,
+    enforced: bool,
+    open_secs: u32,
+    close_secs: u32,
+    trading_days: u8,
+    holidays: Vec<u32>,
+    equity_assets: u8,
+) -> Result<()> {
+    require!(
+        open_secs < close_secs
+            && close_secs as i64 <= SECONDS_PER_DAY
+            && trading_days < 1 << 7
+            && holidays.len() <= MAX_MARKET_HOLIDAYS
+            && equity_assets < 1 << 4,
+        ErrorCode::InvalidMarketSchedule
+    );
+
+    let schedule = &mut ctx.accounts.market_schedule;
+    schedule.bump = ctx.bumps.market_schedule;
+    schedule.open_secs = open_secs;
+    schedule.close_secs = close_secs;
+    schedule.trading_days = trading_days;
+    schedule.holidays = [0; MAX_MARKET_HOLIDAYS];
+    schedule.holidays[..holidays.len()].copy_from_slice(&holidays);
+    schedule.equity_assets = equity_assets;
+    ctx.accounts.pool.market_hours_enforced = enforced;
+
+    emit!(MarketScheduleUpdatedEvent {
+        enforced,
+        open_secs,
+        close_secs,
+        trading_days,
+        holiday_count: holidays.len() as u8,
+        equity_assets,
+        seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+    });
+
+    msg!(
+        "Market schedule updated: enforced={}, {}s-{}s UTC, days={:#09b}, {} holidays",
+        enforced,
+        open_secs,
+        close_secs,
+        trading_days,
+        holidays.len()
+    );
+    Ok(())
+}
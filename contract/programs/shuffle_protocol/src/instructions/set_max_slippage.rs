@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::{MaxSlippageUpdatedEvent, SetMaxSlippage};
+
+// =============================================================================
+// SET MAX SLIPPAGE
+// =============================================================================
+// Pool.max_slippage_bps bounds every external fill. execute_batch snapshots
+// it into the BatchLog; the reveal callback prices default-mode reserve
+// fills at it and records, per routed pair, the minimum output (oracle value
+// minus the slippage). route_surplus and execute_external_swap revert below
+// that minimum, so the route is retried rather than settled at a worse rate.
+
+/// Update Pool.max_slippage_bps. Only callable by an admin or risk manager.
+///
+/// # Arguments
+/// * `max_slippage_bps` - Worst accepted slippage (<= MAX_EXTERNAL_SWAP_SLIPPAGE_BPS)
+pub fn handler(ctx: Context<SetMaxSlippage>, max_slippage_bps: u16) -> Result<()> {
+    require!(
+        max_slippage_bps <= MAX_EXTERNAL_SWAP_SLIPPAGE_BPS,
+        ErrorCode::SlippageTooHigh
+    );
+
+    let pool = &mut ctx.accounts.pool;
+    let previous_slippage_bps = pool.max_slippage_bps;
+    pool.max_slippage_bps = max_slippage_bps;
+
+    emit!(MaxSlippageUpdatedEvent {
+        previous_slippage_bps,
+        max_slippage_bps,
+        seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+    });
+
+    msg!(
+        "Max slippage updated: {} -> {} bps",
+        previous_slippage_bps,
+        max_slippage_bps
+    );
+    Ok(())
+}
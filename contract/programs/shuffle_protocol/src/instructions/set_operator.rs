@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::{OperatorUpdatedEvent, SetOperator};
+
+/// Replace the operator wallet. Only callable by pool authority.
+///
+/// With batch approval enabled the new operator may not be the co-operator,
+/// or one wallet could both execute and approve a batch.
+///
+/// # Arguments
+/// * `operator` - New operator wallet
+pub fn handler(ctx: Context<SetOperator>, operator: Pubkey) -> Result<()> {
+    require!(operator != Pubkey::default(), ErrorCode::InvalidPoolAddress);
+
+    let pool = &mut ctx.accounts.pool;
+    require!(
+        pool.approval_threshold_usdc == 0 || operator != pool.co_operator,
+        ErrorCode::InvalidApprovalConfig
+    );
+
+    let previous_operator = pool.operator;
+    pool.operator = operator;
+
+    emit!(OperatorUpdatedEvent {
+        previous_operator,
+        operator,
+        seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+    });
+
+    msg!("Operator updated: {} -> {}", previous_operator, operator);
+    Ok(())
+}
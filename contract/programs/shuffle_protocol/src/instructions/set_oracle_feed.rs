@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::{OracleFeedUpdatedEvent, SetOracleFeed};
+
+// =============================================================================
+// SET ORACLE FEED - Rotate an asset's price feeds
+// =============================================================================
+// Points one asset at a Pyth feed ID and, optionally, a Switchboard pull feed
+// (see state/oracle_config.rs). The OracleConfig is created on first call
+// with no feeds and the default limits, and from then on execute_batch
+// requires it (Pool.oracle_configured).
+//
+// A default key clears the feed; an asset with neither feed goes back to the
+// mock price. The change applies from the next execute_batch.
+
+/// Set the price feeds of one asset. Only callable by pool authority.
+///
+/// # Arguments
+/// * `asset_id` - Asset to configure (0=USDC, 1=TSLA, 2=SPY, 3=AAPL)
+/// * `feed_pubkey` - Pyth feed ID (default = none)
+/// * `fallback_feed` - Switchboard pull feed account (default = none)
+pub fn handler(
+    ctx: Context<SetOracleFeed>,
+    asset_id: u8,
+    feed_pubkey: Pubkey,
+    fallback_feed: Pubkey,
+) -> Result<()> {
+    require!(asset_id < 4, ErrorCode::InvalidAssetId);
+
+    let oracle_config = &mut ctx.accounts.oracle_config;
+    oracle_config.bump = ctx.bumps.oracle_config;
+    if oracle_config.is_unset() {
+        oracle_config.set_default_params();
+    }
+    let previous_feed = oracle_config.feeds[asset_id as usize];
+    oracle_config.feeds[asset_id as usize] = feed_pubkey;
+    oracle_config.fallback_feeds[asset_id as usize] = fallback_feed;
+    ctx.accounts.pool.oracle_configured = true;
+
+    emit!(OracleFeedUpdatedEvent {
+        asset_id,
+        previous_feed,
+        feed: feed_pubkey,
+        fallback_feed,
+        seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+    });
+
+    msg!(
+        "Oracle feed updated: asset={}, pyth={}, switchboard={}",
+        asset_id,
+        feed_pubkey,
+        fallback_feed
+    );
+    Ok(())
+}
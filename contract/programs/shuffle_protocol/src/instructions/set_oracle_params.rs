@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::{OracleParamsUpdatedEvent, SetOracleParams};
+
+// =============================================================================
+// SET ORACLE PARAMS - Staleness, confidence and divergence limits
+// =============================================================================
+// The limits execute_batch applies to every feed in the OracleConfig:
+// - max_price_age_secs: older updates are rejected (PriceTooStale)
+// - max_price_conf_bps: wider confidence intervals are rejected
+//   (PriceConfidenceTooWide)
+// - max_price_divergence_bps: a larger Pyth/Switchboard gap halts the batch
+//   (PriceDivergenceEvent)
+//
+// Creates the OracleConfig if set_oracle_feed has not yet.
+
+/// Update the oracle limits. Only callable by an admin or risk manager.
+///
+/// # Arguments
+/// * `max_price_age_secs` - Maximum age of a price update (> 0)
+/// * `max_price_conf_bps` - Maximum confidence interval in bps of the price
+/// * `max_price_divergence_bps` - Maximum Pyth/Switchboard gap in bps
+pub fn handler(
+    ctx: Context<SetOracleParams>,
+    max_price_age_secs: i64,
+    max_price_conf_bps: u16,
+    max_price_divergence_bps: u16,
+) -> Result<()> {
+    require!(
+        max_price_age_secs > 0
+            && (1..=BPS_DENOMINATOR).contains(&max_price_conf_bps)
+            && (1..=BPS_DENOMINATOR).contains(&max_price_divergence_bps),
+        ErrorCode::InvalidOracleConfig
+    );
+
+    let oracle_config = &mut ctx.accounts.oracle_config;
+    oracle_config.bump = ctx.bumps.oracle_config;
+    oracle_config.max_price_age_secs = max_price_age_secs;
+    oracle_config.max_price_conf_bps = max_price_conf_bps;
+    oracle_config.max_price_divergence_bps = max_price_divergence_bps;
+    ctx.accounts.pool.oracle_configured = true;
+
+    emit!(OracleParamsUpdatedEvent {
+        max_price_age_secs,
+        max_price_conf_bps,
+        max_price_divergence_bps,
+        seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+    });
+
+    msg!(
+        "Oracle params updated: max age {}s, max confidence {} bps, max divergence {} bps",
+        max_price_age_secs,
+        max_price_conf_bps,
+        max_price_divergence_bps
+    );
+    Ok(())
+}
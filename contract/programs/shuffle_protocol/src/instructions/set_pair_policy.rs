@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::state::{PairPolicy, POLICY_BEST_QUOTE, POLICY_RESERVE_FIRST};
+use crate::{PairPolicyUpdatedEvent, SetPairPolicy};
+
+// =============================================================================
+// SET PAIR POLICY - Per-pair surplus routing
+// =============================================================================
+// Overrides Pool.execution_mode for one pair (see state/execution_policy.rs).
+// The policy account is created on first call with every pair set to
+// POLICY_POOL_MODE, and from then on execute_batch requires it
+// (Pool.execution_policy_configured). Routing strategies need
+// Pool.swap_program to be set, since any surplus the reserves don't take
+// goes through route_surplus.
+
+/// Set the surplus routing policy of one pair. Only callable by pool authority.
+///
+/// # Arguments
+/// * `pair_id` - Pair to configure (0-5)
+/// * `strategy` - POLICY_POOL_MODE, POLICY_RESERVE_FIRST or POLICY_BEST_QUOTE
+/// * `reserve_share_bps` - Share of the surplus offered to reserves (RESERVE_FIRST only)
+pub fn handler(
+    ctx: Context<SetPairPolicy>,
+    pair_id: u8,
+    strategy: u8,
+    reserve_share_bps: u16,
+) -> Result<()> {
+    require!(pair_id < NUM_PAIRS, ErrorCode::InvalidPairId);
+    require!(
+        strategy <= POLICY_BEST_QUOTE,
+        ErrorCode::InvalidExecutionConfig
+    );
+    require!(
+        reserve_share_bps <= BPS_DENOMINATOR,
+        ErrorCode::InvalidExecutionConfig
+    );
+    require!(
+        strategy < POLICY_RESERVE_FIRST || ctx.accounts.pool.swap_program != Pubkey::default(),
+        ErrorCode::InvalidSwapProgram
+    );
+
+    // The share only applies to RESERVE_FIRST
+    let reserve_share_bps = if strategy == POLICY_RESERVE_FIRST {
+        reserve_share_bps
+    } else {
+        0
+    };
+
+    let policy = &mut ctx.accounts.execution_policy;
+    policy.bump = ctx.bumps.execution_policy;
+    policy.pairs[pair_id as usize] = PairPolicy {
+        strategy,
+        reserve_share_bps,
+    };
+    ctx.accounts.pool.execution_policy_configured = true;
+
+    emit!(PairPolicyUpdatedEvent {
+        pair_id,
+        strategy,
+        reserve_share_bps,
+        seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+    });
+
+    msg!(
+        "Pair policy updated: pair={}, strategy={}, reserve_share={} bps",
+        pair_id,
+        strategy,
+        reserve_share_bps
+    );
+    Ok(())
+}
@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::state::PairRegistry;
+use crate::{PriceBandUpdatedEvent, SetPriceBand};
+
+// =============================================================================
+// SET PRICE BAND - Per-pair price circuit breaker
+// =============================================================================
+// Sets how far a pair's execution price may move from its last accepted one
+// (Pool.last_execution_prices) before a batch needs override_price_band.
+// Like the rest of the pair registry it is snapshotted by execute_batch, so
+// a change applies from the next batch executed. Once the registry exists
+// execute_batch requires it, so a band cannot be skipped by leaving it out.
+
+/// Set the price band of one pair. Only callable by an admin or risk manager.
+///
+/// # Arguments
+/// * `pair_id` - Pair to configure (0-5)
+/// * `max_deviation_bps` - Largest move in bps (0 = no band)
+pub fn handler(ctx: Context<SetPriceBand>, pair_id: u8, max_deviation_bps: u16) -> Result<()> {
+    require!(pair_id < NUM_PAIRS, ErrorCode::InvalidPairId);
+    require!(
+        max_deviation_bps <= BPS_DENOMINATOR,
+        ErrorCode::InvalidExecutionConfig
+    );
+
+    let registry = &mut ctx.accounts.pair_registry;
+    registry.bump = ctx.bumps.pair_registry;
+    if registry.is_unset() {
+        registry.pairs = PairRegistry::default_pairs();
+    }
+    registry.pairs[pair_id as usize].max_price_deviation_bps = max_deviation_bps;
+    ctx.accounts.pool.pair_registry_configured = true;
+
+    emit!(PriceBandUpdatedEvent {
+        pair_id,
+        max_deviation_bps,
+        seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+    });
+
+    msg!(
+        "Price band updated: pair={}, max deviation={} bps",
+        pair_id,
+        max_deviation_bps
+    );
+    Ok(())
+}
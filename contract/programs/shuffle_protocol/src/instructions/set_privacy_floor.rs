@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::{PrivacyFloorUpdatedEvent, SetPrivacyFloor};
+
+// =============================================================================
+// SET PRIVACY FLOOR - Minimum distinct wallets per revealed batch
+// =============================================================================
+// The reveal publishes per-pair totals. With a single participant those
+// totals are that wallet's order, so execute_batch refuses to queue a reveal
+// until the batch holds at least min_unique_users distinct wallets.
+// Wallets are counted conservatively by BatchAccumulator::min_distinct_wallets:
+// a wallet has at most one regular order and one basket per batch, and may
+// have both, so the larger of the two counts is used.
+//
+// The floor holds for the operator too and is not lifted by
+// max_batch_wait_secs. Orders stuck in a batch that never reaches it are
+// refunded with flush_batch, which discards the batch without revealing it.
+
+/// Update Pool.min_unique_users. Only callable by an admin or risk manager.
+///
+/// # Arguments
+/// * `min_unique_users` - Distinct wallets required to reveal a batch (0 = off)
+pub fn handler(ctx: Context<SetPrivacyFloor>, min_unique_users: u8) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+    let previous_min_unique_users = pool.min_unique_users;
+    pool.min_unique_users = min_unique_users;
+
+    emit!(PrivacyFloorUpdatedEvent {
+        previous_min_unique_users,
+        min_unique_users,
+        seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+    });
+
+    msg!(
+        "Privacy floor updated: {} -> {} wallets per batch",
+        previous_min_unique_users,
+        min_unique_users
+    );
+    Ok(())
+}
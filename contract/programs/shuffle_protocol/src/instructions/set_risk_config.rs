@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::{RiskConfigUpdatedEvent, SetRiskConfig};
+
+// =============================================================================
+// SET RISK CONFIG - Global limits
+// =============================================================================
+// Creates the RiskConfig on first call (see state/risk_config.rs) and sets
+// Pool.risk_configured, after which execute_batch, sub_balance and
+// delegated_withdraw require it. Changes apply from the next batch or
+// withdrawal; withdrawals already counted in a window stay counted.
+//
+// A notional cap is enforced through approve_batch, so it needs a
+// co-operator (set_batch_approval) to approve batches above it.
+
+/// Update the global risk limits. Only callable by an admin or risk manager.
+///
+/// # Arguments
+/// * `max_batch_notional_usdc` - Batch notional needing approval (0 = no limit)
+/// * `max_daily_withdrawal` - Per-wallet cap per asset and window (0 = no cap)
+/// * `max_reserve_utilization_bps` - Reserve share paid out per batch (1-10000)
+pub fn handler(
+    ctx: Context<SetRiskConfig>,
+    max_batch_notional_usdc: u64,
+    max_daily_withdrawal: [u64; 4],
+    max_reserve_utilization_bps: u16,
+) -> Result<()> {
+    require!(
+        max_reserve_utilization_bps > 0 && max_reserve_utilization_bps <= BPS_DENOMINATOR,
+        ErrorCode::InvalidRiskConfig
+    );
+    require!(
+        max_batch_notional_usdc == 0 || ctx.accounts.pool.co_operator != Pubkey::default(),
+        ErrorCode::InvalidRiskConfig
+    );
+
+    let risk_config = &mut ctx.accounts.risk_config;
+    risk_config.max_batch_notional_usdc = max_batch_notional_usdc;
+    risk_config.max_daily_withdrawal = max_daily_withdrawal;
+    risk_config.max_reserve_utilization_bps = max_reserve_utilization_bps;
+    risk_config.bump = ctx.bumps.risk_config;
+    ctx.accounts.pool.risk_configured = true;
+
+    emit!(RiskConfigUpdatedEvent {
+        max_batch_notional_usdc,
+        max_daily_withdrawal,
+        max_reserve_utilization_bps,
+        updated_by: ctx.accounts.authority.key(),
+        seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+    });
+
+    msg!(
+        "Risk config updated: max notional {}, withdrawal caps {:?}, utilization {} bps",
+        max_batch_notional_usdc,
+        max_daily_withdrawal,
+        max_reserve_utilization_bps
+    );
+    Ok(())
+}
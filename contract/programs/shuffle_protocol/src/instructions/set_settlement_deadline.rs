@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::{SetSettlementDeadline, SettlementDeadlineUpdatedEvent};
+
+// =============================================================================
+// SET SETTLEMENT DEADLINE
+// =============================================================================
+// Controls when unsettled payouts escheat to the treasury via forfeit_order.
+// The deadline is measured from each batch's executed_at, so a change also
+// applies to batches that already executed; the lower bound keeps users a
+// reasonable window to settle.
+
+/// Update Pool.settlement_deadline_secs. Only callable by pool authority.
+///
+/// # Arguments
+/// * `deadline_secs` - Seconds after batch execution before payouts can be forfeited
+pub fn handler(ctx: Context<SetSettlementDeadline>, deadline_secs: i64) -> Result<()> {
+    require!(
+        deadline_secs >= MIN_SETTLEMENT_DEADLINE_SECS,
+        ErrorCode::InvalidSettlementDeadline
+    );
+
+    let pool = &mut ctx.accounts.pool;
+    let previous_secs = pool.settlement_deadline_secs;
+    pool.settlement_deadline_secs = deadline_secs;
+
+    emit!(SettlementDeadlineUpdatedEvent {
+        previous_secs,
+        deadline_secs,
+        seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+    });
+
+    msg!(
+        "Settlement deadline updated: {}s -> {}s",
+        previous_secs,
+        deadline_secs
+    );
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+use crate::{SetSettlementPreference, SettlementPreferenceUpdatedEvent};
+
+// =============================================================================
+// SET SETTLEMENT PREFERENCE - Auto-convert payouts into USDC
+// =============================================================================
+// The preference is an encrypted flag (SettlementPreference.convert_to_usdc)
+// for the account's stored user_pubkey, kept in a side PDA so only users who
+// opt in pay its rent. While the PDA exists and is passed to settle_order,
+// settling an order whose output is not USDC stages a follow-up order
+// selling the payout on the asset's USDC pair; place_staged_order places it
+// in the next batch. With the flag off the follow-up has a zero amount, so
+// the chain only shows that a preference exists, not whether payouts are
+// converted.
+//
+// No conversion is staged when another order is already staged
+// (settle_and_place_order, rollovers) or for force_settle / forfeit_order.
+
+/// Set or replace the caller's settlement currency preference.
+///
+/// # Arguments
+/// * `convert_to_usdc` - Encrypted flag (1 = convert) for the account's user_pubkey
+/// * `nonce` - Nonce used to encrypt the flag
+pub fn handler(
+    ctx: Context<SetSettlementPreference>,
+    convert_to_usdc: [u8; 32],
+    nonce: u128,
+) -> Result<()> {
+    let preference = &mut ctx.accounts.settlement_preference;
+    preference.owner = ctx.accounts.user.key();
+    preference.convert_to_usdc = convert_to_usdc;
+    preference.nonce = nonce;
+    preference.bump = ctx.bumps.settlement_preference;
+
+    ctx.accounts.user_account.last_active_at = Clock::get()?.unix_timestamp;
+
+    emit!(SettlementPreferenceUpdatedEvent {
+        user: ctx.accounts.user.key(),
+        enabled: true,
+        seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+    });
+
+    msg!("Settlement preference set for {}", ctx.accounts.user.key());
+    Ok(())
+}
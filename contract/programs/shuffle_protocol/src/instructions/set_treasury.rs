@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::{SetTreasury, TreasuryUpdatedEvent};
+
+/// Replace the treasury wallet. Only callable by pool authority.
+///
+/// sweep_fees, forfeit_order, close_batch_log and slash_executor pay the
+/// wallet (or token accounts it owns) current at the time they run; fees not
+/// yet collected go to the new treasury.
+///
+/// # Arguments
+/// * `treasury` - New treasury wallet
+pub fn handler(ctx: Context<SetTreasury>, treasury: Pubkey) -> Result<()> {
+    require!(treasury != Pubkey::default(), ErrorCode::InvalidPoolAddress);
+
+    let pool = &mut ctx.accounts.pool;
+    let previous_treasury = pool.treasury;
+    pool.treasury = treasury;
+
+    emit!(TreasuryUpdatedEvent {
+        previous_treasury,
+        treasury,
+        seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+    });
+
+    msg!("Treasury updated: {} -> {}", previous_treasury, treasury);
+    Ok(())
+}
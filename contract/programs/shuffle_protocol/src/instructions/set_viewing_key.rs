@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::{SetViewingKey, ViewingKeySetEvent};
+
+/// Designate or replace the x25519 key the caller's balances are
+/// re-encrypted for by refresh_viewable_balance. A new key zeroes every
+/// previously re-encrypted balance.
+///
+/// # Arguments
+/// * `viewing_pubkey` - x25519 public key of the frontend or auditor
+pub fn handler(ctx: Context<SetViewingKey>, viewing_pubkey: [u8; 32]) -> Result<()> {
+    require!(viewing_pubkey != [0u8; 32], ErrorCode::InvalidViewingKey);
+
+    let now = Clock::get()?.unix_timestamp;
+
+    let viewable = &mut ctx.accounts.viewable_balance;
+    if viewable.viewing_pubkey != viewing_pubkey {
+        viewable.balances = [[0u8; 32]; 4];
+        viewable.nonces = [0; 4];
+        viewable.refreshed_at = [0; 4];
+    }
+    viewable.owner = ctx.accounts.user.key();
+    viewable.viewing_pubkey = viewing_pubkey;
+    viewable.bump = ctx.bumps.viewable_balance;
+
+    ctx.accounts.user_account.last_active_at = now;
+
+    emit!(ViewingKeySetEvent {
+        owner: viewable.owner,
+        viewing_pubkey,
+        seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+    });
+
+    msg!("Viewing key set for {}", viewable.owner);
+    Ok(())
+}
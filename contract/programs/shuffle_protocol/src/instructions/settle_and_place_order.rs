@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::OrderTicket;
+use crate::SettleOrder;
+
+// =============================================================================
+// SETTLE AND PLACE ORDER - One signature for settle + next order
+// =============================================================================
+// A callback cannot queue another computation (the Arcium program would be
+// re-entered and there is no fee payer), so the next order is staged:
+// 1. User calls settle_and_place_order: the next order is stored in
+//    user_account.staged_order and calculate_payout is queued as in settle_order
+// 2. calculate_payout_callback clears pending_order and emits StagedOrderReadyEvent
+// 3. Anyone calls place_staged_order, which queues accumulate_order for the
+//    staged order in the then-current batch (same flow as execute_dca)
+//
+// The staged order is encrypted for the account's stored user_pubkey, since
+// place_staged_order has no user-supplied key.
+
+/// Settle the pending order and stage the next one.
+///
+/// # Arguments
+/// * `computation_offset` - Unique ID for the calculate_payout computation
+/// * `pubkey` - User's x25519 public key
+/// * `nonce` - Encryption nonce
+/// * `pair_id` - Trading pair of the order being settled (0-5)
+/// * `direction` - Direction of the order being settled (0=A_to_B, 1=B_to_A)
+/// * `next_order` - Encrypted next order (batch_id is ignored)
+/// * `next_source_asset_id` - Plaintext hint: asset sold by the next order
+pub fn handler(
+    ctx: Context<SettleOrder>,
+    computation_offset: u64,
+    pubkey: [u8; 32],
+    nonce: u128,
+    pair_id: u8,
+    direction: u8,
+    next_order: OrderTicket,
+    next_source_asset_id: u8,
+) -> Result<()> {
+    require!(!ctx.accounts.pool.paused, ErrorCode::ProtocolPaused);
+    require!(next_source_asset_id <= 3, ErrorCode::InvalidAssetId);
+
+    ctx.accounts.user_account.staged_order = Some(OrderTicket {
+        batch_id: 0,
+        ..next_order
+    });
+    ctx.accounts.user_account.staged_asset_id = next_source_asset_id;
+
+    // Authorization, route resolution and queueing are shared with settle_order
+    crate::instructions::settle_order::handler(
+        ctx,
+        computation_offset,
+        pubkey,
+        nonce,
+        pair_id,
+        direction,
+    )
+}
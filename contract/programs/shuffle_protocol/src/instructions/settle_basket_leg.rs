@@ -0,0 +1,162 @@
+use anchor_lang::prelude::*;
+use arcium_anchor::prelude::*;
+
+use crate::constants::NUM_PAIRS;
+use crate::errors::ErrorCode;
+use crate::instructions::create_delegate::authorize_owner_or_delegate;
+use crate::instructions::settle_order::settlement_route;
+use crate::state::BASKET_LEGS;
+use crate::{CalculatePayoutCallback, SettleBasketLeg};
+
+// =============================================================================
+// SETTLE BASKET LEG - Settle one leg of a basket order
+// =============================================================================
+// Each basket leg is an encrypted OrderInput for the owner, so it settles with
+// the regular calculate_payout circuit. The callback recognises a basket
+// settlement by the BasketOrder callback account, marks the leg settled and
+// frees the basket once every leg is done.
+//
+// Zero-weight legs settle to a zero payout; they still have to be settled so
+// the basket (and its BatchLog) can be released.
+
+/// Settle one leg of the user's basket order.
+///
+/// # Arguments
+/// * `computation_offset` - Unique ID for MPC computation
+/// * `pubkey` - User's x25519 public key
+/// * `nonce` - Encryption nonce
+/// * `leg` - Basket leg to settle (0-2)
+/// * `pair_id` - Trading pair of the leg (0-5)
+/// * `direction` - Direction of the leg (0=A_to_B, 1=B_to_A)
+pub fn handler(
+    ctx: Context<SettleBasketLeg>,
+    computation_offset: u64,
+    pubkey: [u8; 32],
+    nonce: u128,
+    leg: u8,
+    pair_id: u8,
+    direction: u8,
+) -> Result<()> {
+    authorize_owner_or_delegate(
+        ctx.accounts.user_account.owner,
+        ctx.accounts.user.key(),
+        ctx.accounts.delegate.as_deref_mut(),
+        None,
+    )?;
+
+    let basket = &mut ctx.accounts.basket_order;
+    require!(
+        (leg as usize) < BASKET_LEGS && !basket.is_leg_settled(leg),
+        ErrorCode::InvalidBasketLeg
+    );
+    basket.settling_leg = leg;
+    let ticket = basket.legs[leg as usize];
+
+    let route = settlement_route(&ctx.accounts.batch_log, pair_id, direction)?;
+    require!(
+        route.source_asset_id == basket.source_asset_id,
+        ErrorCode::InvalidOrderRoute
+    );
+
+    // Store output and refund asset IDs for callback; legs never roll over
+    // or convert
+    ctx.accounts.user_account.pending_asset_id = route.output_asset_id;
+    ctx.accounts.user_account.pending_refund_asset_id = route.source_asset_id;
+    ctx.accounts.user_account.pending_rollover = false;
+    ctx.accounts.user_account.pending_conversion = false;
+    ctx.accounts.user_account.last_active_at = Clock::get()?.unix_timestamp;
+
+    // Bind a freshly created order history to its owner
+    let order_history = &mut ctx.accounts.order_history;
+    if order_history.owner == Pubkey::default() {
+        order_history.owner = ctx.accounts.user_account.owner;
+        order_history.bump = ctx.bumps.order_history;
+    }
+
+    // Set sign PDA bump
+    ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+    // Same inputs as settle_order, with the leg in place of pending_order
+    let source_balance = ctx.accounts.user_account.get_credit(route.source_asset_id);
+    let source_nonce = ctx.accounts.user_account.get_nonce(route.source_asset_id);
+    let output_balance = ctx.accounts.user_account.get_credit(route.output_asset_id);
+    let output_nonce = ctx.accounts.user_account.get_nonce(route.output_asset_id);
+    ctx.accounts.user_account.pending_payout_nonce = output_nonce;
+    ctx.accounts.user_account.pending_refund_nonce = source_nonce;
+
+    let args = ArgBuilder::new()
+        // OrderInput (Enc<Shared, OrderInput>) - leg returned by accumulate_basket
+        .x25519_pubkey(pubkey)
+        .plaintext_u128(ticket.order_nonce)
+        .encrypted_u8(ticket.pair_id)
+        .encrypted_u8(ticket.direction)
+        .encrypted_u64(ticket.encrypted_amount)
+        // Source asset balance (Enc<Shared, UserBalance>) - credited with refund
+        .x25519_pubkey(pubkey)
+        .plaintext_u128(source_nonce)
+        .encrypted_u64(source_balance)
+        // Output asset balance (Enc<Shared, UserBalance>) - credited with payout
+        .x25519_pubkey(pubkey)
+        .plaintext_u128(output_nonce)
+        .encrypted_u64(output_balance)
+        // Plaintext batch results
+        .plaintext_u64(route.total_input)
+        .plaintext_u64(route.final_pool_output)
+        .plaintext_u64(route.fill_ratio as u64)
+        // Claimed route and no bounty
+        .plaintext_u8(pair_id)
+        .plaintext_u8(direction)
+        .plaintext_u64(0)
+        // No settlement currency conversion
+        .x25519_pubkey(pubkey)
+        .plaintext_u128(0)
+        .encrypted_u8([0u8; 32])
+        .plaintext_u8(NUM_PAIRS)
+        .build();
+
+    use arcium_client::idl::arcium::types::CallbackAccount;
+    queue_computation(
+        ctx.accounts,
+        computation_offset,
+        args,
+        vec![CalculatePayoutCallback::callback_ix(
+            computation_offset,
+            &ctx.accounts.mxe_account,
+            &[
+                CallbackAccount {
+                    pubkey: ctx.accounts.user_account.key(),
+                    is_writable: true,
+                },
+                CallbackAccount {
+                    pubkey: ctx.accounts.order_history.key(),
+                    is_writable: true,
+                },
+                CallbackAccount {
+                    pubkey: ctx.accounts.batch_log.key(),
+                    is_writable: true,
+                },
+                crate::optional_callback_account(None, true), // diagnostics
+                // No crank bounty: pool, payout vault, bounty account, token program
+                crate::optional_callback_account(None, false),
+                crate::optional_callback_account(None, false),
+                crate::optional_callback_account(None, false),
+                crate::optional_callback_account(None, false),
+                crate::optional_callback_account(Some(ctx.accounts.basket_order.key()), true),
+                crate::event_cursor_callback_account(ctx.accounts.pool.pool_id),
+            ],
+        )?],
+        1,                                         // number of callbacks
+        ctx.accounts.pool.callback_cu_price_micro, // priority
+    )?;
+
+    msg!(
+        "Basket leg settlement queued: user={}, batch={}, leg={}, pair={}, direction={}",
+        ctx.accounts.user_account.owner,
+        ctx.accounts.basket_order.batch_id,
+        leg,
+        pair_id,
+        direction
+    );
+
+    Ok(())
+}
@@ -1,7 +1,10 @@
 use anchor_lang::prelude::*;
 use arcium_anchor::prelude::*;
 
+use crate::constants::NUM_PAIRS;
 use crate::errors::ErrorCode;
+use crate::instructions::create_delegate::authorize_owner_or_delegate;
+use crate::state::{pair_assets, BatchLog, PairResult, PROFILE_SETTLE_ORDER};
 use crate::{CalculatePayoutCallback, SettleOrder};
 
 // =============================================================================
@@ -15,10 +18,31 @@ use crate::{CalculatePayoutCallback, SettleOrder};
 // 2. Handler loads BatchLog results for the executed batch
 // 3. Handler queues calculate_payout MPC computation
 // 4. Callback receives updated balance with payout added
-// 5. Callback clears pending_order
+// 5. Callback records the fill in OrderHistory and clears pending_order
+//
+// Good-til-batch orders (OrderTicket.valid_until_batch): if the order's side
+// had a zero fill (e.g. its pair was skipped for a frozen asset), the refund
+// is credited as usual and the callback stages the same ticket again, so
+// place_staged_order re-enters it into the next batch. Like
+// settle_and_place_order, this assumes the order was encrypted for the
+// account's stored user_pubkey.
+//
+// Settlement currency preference (SettlementPreference side PDA): if the
+// output asset is not USDC and nothing else is staged, calculate_payout also
+// returns a follow-up order selling the payout on the asset's USDC pair, and
+// the callback stages it for place_staged_order.
+//
+// Multi-order settlement (settle_all):
+// Not implemented yet. UserProfile holds a single pending_order (place_order
+// and execute_dca both reject a second one), so there is never more than one
+// order to settle per user. Once pending orders become a list, settle_all
+// should take the matching BatchLogs via remaining_accounts and queue one
+// multi-payout circuit instead of one calculate_payout per order.
 
 /// Settle a pending order.
 /// Calculates pro-rata payout and updates user balance.
+/// If the user's side was partially filled, the unfilled part of the order
+/// is refunded to the source asset balance in the same computation.
 ///
 /// # Arguments
 /// * `computation_offset` - Unique ID for MPC computation
@@ -34,9 +58,15 @@ pub fn handler(
     pair_id: u8,
     direction: u8,
 ) -> Result<()> {
-    // Validate inputs
-    require!(pair_id <= 5, ErrorCode::InvalidPairId);
-    require!(direction <= 1, ErrorCode::InvalidAmount); // 0 or 1
+    let cu_start = crate::profiling_start();
+
+    // Owner or an active session key
+    authorize_owner_or_delegate(
+        ctx.accounts.user_account.owner,
+        ctx.accounts.user.key(),
+        ctx.accounts.delegate.as_deref_mut(),
+        None,
+    )?;
 
     // Verify pending_order exists
     let pending = ctx
@@ -45,48 +75,53 @@ pub fn handler(
         .pending_order
         .ok_or(ErrorCode::NoPendingOrder)?;
 
-    // Load PairResult from batch_log
-    use crate::state::PairResult;
-    let pair_result: PairResult = ctx.accounts.batch_log.results[pair_id as usize];
+    // Resolve batch totals for the claimed route (checked against the order in MPC)
+    let route = settlement_route(&ctx.accounts.batch_log, pair_id, direction)?;
 
-    // Determine which totals to use based on direction
-    let (total_input, final_pool_output) = if direction == 0 {
-        // A_to_B: user sold A, gets B
-        (pair_result.total_a_in, pair_result.final_pool_b)
-    } else {
-        // B_to_A: user sold B, gets A
-        (pair_result.total_b_in, pair_result.final_pool_a)
-    };
+    // Store output and refund asset IDs for callback
+    ctx.accounts.user_account.pending_asset_id = route.output_asset_id;
+    ctx.accounts.user_account.pending_refund_asset_id = route.source_asset_id;
+    ctx.accounts.user_account.last_active_at = Clock::get()?.unix_timestamp;
 
-    // Determine output asset ID based on pair and direction
-    // Per constants.rs: PAIR_TSLA_USDC=0, PAIR_SPY_USDC=1, etc.
-    // Token A is first in pair name, Token B is second
-    // Direction: 0=A_to_B (sell A, get B), 1=B_to_A (sell B, get A)
-    let (token_a_asset, token_b_asset) = match pair_id {
-        0 => (1_u8, 0_u8), // TSLA/USDC - A=TSLA(1), B=USDC(0)
-        1 => (2_u8, 0_u8), // SPY/USDC - A=SPY(2), B=USDC(0)
-        2 => (3_u8, 0_u8), // AAPL/USDC - A=AAPL(3), B=USDC(0)
-        3 => (1_u8, 2_u8), // TSLA/SPY - A=TSLA(1), B=SPY(2)
-        4 => (1_u8, 3_u8), // TSLA/AAPL - A=TSLA(1), B=AAPL(3)
-        5 => (2_u8, 3_u8), // SPY/AAPL - A=SPY(2), B=AAPL(3)
-        _ => return Err(ErrorCode::InvalidPairId.into()),
-    };
-    let output_asset_id = if direction == 0 {
-        token_b_asset // A_to_B: sell A, get B
-    } else {
-        token_a_asset // B_to_A: sell B, get A
-    };
+    // An order whose side didn't execute at all rolls over into the next
+    // batch while still valid; an explicitly staged order takes precedence
+    ctx.accounts.user_account.pending_rollover = route.fill_ratio == 0
+        && pending.valid_until_batch > pending.batch_id
+        && ctx.accounts.user_account.staged_order.is_none();
+
+    // Non-USDC payouts convert in the next batch under a settlement preference
+    let conversion_pair = usdc_conversion_pair(route.output_asset_id).filter(|_| {
+        ctx.accounts.settlement_preference.is_some()
+            && !ctx.accounts.user_account.pending_rollover
+            && ctx.accounts.user_account.staged_order.is_none()
+    });
+    ctx.accounts.user_account.pending_conversion = conversion_pair.is_some();
+    let (preference_flag, preference_nonce) = ctx
+        .accounts
+        .settlement_preference
+        .as_ref()
+        .map(|preference| (preference.convert_to_usdc, preference.nonce))
+        .unwrap_or_default();
 
-    // Store output_asset_id for callback
-    ctx.accounts.user_account.pending_asset_id = output_asset_id;
+    // Bind a freshly created order history to its owner
+    let order_history = &mut ctx.accounts.order_history;
+    if order_history.owner == Pubkey::default() {
+        order_history.owner = ctx.accounts.user_account.owner;
+        order_history.bump = ctx.bumps.order_history;
+    }
 
     // Set sign PDA bump
     ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
 
-    // Get current balance for output asset (plaintext - for first settlement this is 0)
-    // Note: We read the plaintext value because output assets haven't been MPC-processed yet
-    // In a full implementation, we'd track which balances have been MPC-initialized
-    let current_balance: u64 = 0; // First settlement on output asset always starts at 0
+    // Source asset balance receives the refund for any unfilled portion
+    let source_balance = ctx.accounts.user_account.get_credit(route.source_asset_id);
+    let source_nonce = ctx.accounts.user_account.get_nonce(route.source_asset_id);
+
+    // Output asset balance receives the payout
+    let output_balance = ctx.accounts.user_account.get_credit(route.output_asset_id);
+    let output_nonce = ctx.accounts.user_account.get_nonce(route.output_asset_id);
+    ctx.accounts.user_account.pending_payout_nonce = output_nonce;
+    ctx.accounts.user_account.pending_refund_nonce = source_nonce;
 
     // Build MPC arguments - pass FULL OrderInput struct to preserve encryption context
     // The order was encrypted as a struct (pair_id, direction, amount) with order_nonce
@@ -97,11 +132,27 @@ pub fn handler(
         .encrypted_u8(pending.pair_id) // Struct field 0
         .encrypted_u8(pending.direction) // Struct field 1
         .encrypted_u64(pending.encrypted_amount) // Struct field 2
-        // Plaintext current balance (0 for first settlement)
-        .plaintext_u64(current_balance)
+        // Source asset balance (Enc<Shared, UserBalance>) - credited with refund
+        .x25519_pubkey(pubkey)
+        .plaintext_u128(source_nonce)
+        .encrypted_u64(source_balance)
+        // Output asset balance (Enc<Shared, UserBalance>) - credited with payout
+        .x25519_pubkey(pubkey)
+        .plaintext_u128(output_nonce)
+        .encrypted_u64(output_balance)
         // Plaintext batch results
-        .plaintext_u64(total_input)
-        .plaintext_u64(final_pool_output)
+        .plaintext_u64(route.total_input)
+        .plaintext_u64(route.final_pool_output)
+        .plaintext_u64(route.fill_ratio as u64)
+        // Claimed route (checked against the encrypted order) and no bounty
+        .plaintext_u8(pair_id)
+        .plaintext_u8(direction)
+        .plaintext_u64(0)
+        // Settlement preference (Enc<Shared, SettlementPreference>) and its USDC pair
+        .x25519_pubkey(pubkey)
+        .plaintext_u128(preference_nonce)
+        .encrypted_u8(preference_flag)
+        .plaintext_u8(conversion_pair.unwrap_or(NUM_PAIRS))
         .build();
 
     // Queue MPC computation
@@ -113,22 +164,127 @@ pub fn handler(
         vec![CalculatePayoutCallback::callback_ix(
             computation_offset,
             &ctx.accounts.mxe_account,
-            &[CallbackAccount {
-                pubkey: ctx.accounts.user_account.key(),
-                is_writable: true,
-            }],
+            &[
+                CallbackAccount {
+                    pubkey: ctx.accounts.user_account.key(),
+                    is_writable: true,
+                },
+                CallbackAccount {
+                    pubkey: ctx.accounts.order_history.key(),
+                    is_writable: true,
+                },
+                CallbackAccount {
+                    pubkey: ctx.accounts.batch_log.key(),
+                    is_writable: true,
+                },
+                crate::diagnostics_callback_account(&ctx.accounts.diagnostics),
+                // No crank bounty: pool, payout vault, bounty account, token program
+                crate::optional_callback_account(None, false),
+                crate::optional_callback_account(None, false),
+                crate::optional_callback_account(None, false),
+                crate::optional_callback_account(None, false),
+                crate::optional_callback_account(None, true), // basket_order
+                crate::event_cursor_callback_account(ctx.accounts.pool.pool_id),
+            ],
         )?],
-        1,
-        0,
+        1,                                         // number of callbacks
+        ctx.accounts.pool.callback_cu_price_micro, // priority
     )?;
 
     msg!(
         "Settlement queued: user={}, batch={}, pair={}, direction={}",
-        ctx.accounts.user.key(),
+        ctx.accounts.user_account.owner,
         pending.batch_id,
         pair_id,
         direction
     );
 
+    crate::record_profile(
+        ctx.accounts.diagnostics.as_mut(),
+        PROFILE_SETTLE_ORDER,
+        cu_start,
+    );
+
     Ok(())
 }
+
+/// Batch totals and asset IDs a settlement reads for a given route.
+pub(crate) struct SettlementRoute {
+    pub total_input: u64,
+    pub final_pool_output: u64,
+    pub fill_ratio: u32,
+    pub output_asset_id: u8,
+    pub source_asset_id: u8,
+}
+
+/// USDC pair selling `output_asset_id` as token A, or None for USDC itself.
+pub(crate) fn usdc_conversion_pair(output_asset_id: u8) -> Option<u8> {
+    match output_asset_id {
+        1 => Some(0), // TSLA/USDC
+        2 => Some(1), // SPY/USDC
+        3 => Some(2), // AAPL/USDC
+        _ => None,
+    }
+}
+
+/// Resolve the BatchLog totals and asset IDs for a (pair_id, direction) route.
+/// The route is a plaintext claim; calculate_payout checks it against the
+/// encrypted order.
+pub(crate) fn settlement_route(
+    batch_log: &BatchLog,
+    pair_id: u8,
+    direction: u8,
+) -> Result<SettlementRoute> {
+    require!(pair_id <= 5, ErrorCode::InvalidPairId);
+    require!(direction <= 1, ErrorCode::InvalidAmount); // 0 or 1
+    require!(
+        batch_log.pending_routes & (1 << pair_id) == 0,
+        ErrorCode::ExternalRoutePending
+    );
+    // execute_swaps partially fills pairs whose reserve fell short
+    require!(batch_log.swaps_executed, ErrorCode::SwapsNotExecuted);
+
+    let pair_result: PairResult = batch_log.results[pair_id as usize];
+
+    // Determine which totals to use based on direction
+    // fill_ratio is the executed fraction of the user's side; the rest is refunded
+    let (total_input, final_pool_output, fill_ratio) = if direction == 0 {
+        // A_to_B: user sold A, gets B
+        (
+            pair_result.total_a_in,
+            pair_result.final_pool_b,
+            pair_result.fill_ratio_a,
+        )
+    } else {
+        // B_to_A: user sold B, gets A
+        (
+            pair_result.total_b_in,
+            pair_result.final_pool_a,
+            pair_result.fill_ratio_b,
+        )
+    };
+
+    // Determine output asset ID based on pair and direction
+    // Per constants.rs: PAIR_TSLA_USDC=0, PAIR_SPY_USDC=1, etc.
+    // Token A is first in pair name, Token B is second
+    // Direction: 0=A_to_B (sell A, get B), 1=B_to_A (sell B, get A)
+    let (token_a_asset, token_b_asset) = pair_assets(pair_id).ok_or(ErrorCode::InvalidPairId)?;
+
+    // Users share what is left of the output pool after the execution fee
+    let final_pool_output =
+        final_pool_output - batch_log.execution_fee(pair_id as usize, final_pool_output);
+
+    let (output_asset_id, source_asset_id) = if direction == 0 {
+        (token_b_asset, token_a_asset) // A_to_B: sell A, get B
+    } else {
+        (token_a_asset, token_b_asset) // B_to_A: sell B, get A
+    };
+
+    Ok(SettlementRoute {
+        total_input,
+        final_pool_output,
+        fill_ratio,
+        output_asset_id,
+        source_asset_id,
+    })
+}
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::ExecutorEntry;
+use crate::{ExecutorSlashedEvent, SlashExecutor};
+
+// =============================================================================
+// SLASH EXECUTOR - Confiscate a misbehaving executor's bond
+// =============================================================================
+// The executor is removed from the registry and its whole bond moves from the
+// registry PDA to the treasury. It may register again with a new bond.
+
+/// Slash a registered executor. Only callable by pool authority.
+///
+/// # Arguments
+/// * `executor` - Executor to slash
+pub fn handler(ctx: Context<SlashExecutor>, executor: Pubkey) -> Result<()> {
+    let registry = &mut ctx.accounts.executor_registry;
+    let index = registry
+        .find(&executor)
+        .ok_or(ErrorCode::ExecutorNotRegistered)?;
+    let bond_lamports = registry.executors[index].bond_lamports;
+
+    registry.executors[index] = ExecutorEntry::default();
+    registry.total_slashed = registry.total_slashed.saturating_add(bond_lamports);
+
+    registry.to_account_info().sub_lamports(bond_lamports)?;
+    ctx.accounts.treasury.add_lamports(bond_lamports)?;
+
+    emit!(ExecutorSlashedEvent {
+        executor,
+        bond_lamports,
+        seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+    });
+
+    msg!(
+        "Executor {} slashed: {} lamports sent to the treasury",
+        executor,
+        bond_lamports
+    );
+    Ok(())
+}
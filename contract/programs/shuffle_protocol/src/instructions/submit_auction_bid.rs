@@ -0,0 +1,103 @@
+use anchor_lang::prelude::*;
+use arcium_anchor::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::{AuctionStatus, MAX_AUCTION_BIDS};
+use crate::{SubmitAuctionBid, SubmitAuctionBidCallback};
+
+// =============================================================================
+// SUBMIT AUCTION BID - Encrypted bid on a reserve auction
+// =============================================================================
+// The bid price is encrypted with the bidder's key; the MPC folds it into the
+// running best bid without ever revealing it. The bidder's wallet is recorded
+// in plaintext so the winner index can be mapped back after reveal.
+//
+// NOTE: Bids are folded sequentially. The bidder is registered before the
+// MPC runs so each bid gets a unique index even when callbacks interleave.
+
+/// Submit an encrypted bid to an open reserve auction.
+///
+/// # Arguments
+/// * `computation_offset` - Unique ID for this MPC computation
+/// * `encrypted_price` - Total USDC offered for the lot, encrypted with bidder's key
+/// * `pubkey` - Bidder's x25519 public key
+/// * `nonce` - Encryption nonce
+pub fn handler(
+    ctx: Context<SubmitAuctionBid>,
+    computation_offset: u64,
+    encrypted_price: [u8; 32],
+    pubkey: [u8; 32],
+    nonce: u128,
+) -> Result<()> {
+    let bidder = ctx.accounts.bidder.key();
+    let auction = &mut ctx.accounts.reserve_auction;
+
+    require!(
+        auction.status == AuctionStatus::Open,
+        ErrorCode::InvalidAuctionStatus
+    );
+    require!(
+        Clock::get()?.unix_timestamp < auction.bid_deadline,
+        ErrorCode::AuctionBiddingClosed
+    );
+
+    let bid_count = auction.bid_count as usize;
+    require!(bid_count < MAX_AUCTION_BIDS, ErrorCode::AuctionFull);
+    require!(
+        !auction.bidders[..bid_count].contains(&bidder),
+        ErrorCode::DuplicateBid
+    );
+
+    // Register bidder at the next index
+    let bidder_index = auction.bid_count;
+    auction.bidders[bid_count] = bidder;
+    auction.bid_count += 1;
+
+    ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+    // Build MPC arguments:
+    // 1. AuctionBid (Enc<Shared>) - bidder encrypts
+    // 2. AuctionState (Enc<Mxe>) - read from auction account
+    // 3. bidder_index (plaintext)
+    let args = ArgBuilder::new()
+        .x25519_pubkey(pubkey)
+        .plaintext_u128(nonce)
+        .encrypted_u64(encrypted_price)
+        .plaintext_u128(ctx.accounts.reserve_auction.mxe_nonce)
+        .account(
+            ctx.accounts.reserve_auction.key(),
+            8,      // Skip discriminator
+            2 * 32, // best_price + best_bidder ciphertexts
+        )
+        .plaintext_u8(bidder_index)
+        .build();
+
+    use arcium_client::idl::arcium::types::CallbackAccount;
+    queue_computation(
+        ctx.accounts,
+        computation_offset,
+        args,
+        vec![SubmitAuctionBidCallback::callback_ix(
+            computation_offset,
+            &ctx.accounts.mxe_account,
+            &[
+                CallbackAccount {
+                    pubkey: ctx.accounts.reserve_auction.key(),
+                    is_writable: true,
+                },
+                crate::event_cursor_callback_account(ctx.accounts.pool.pool_id),
+            ],
+        )?],
+        1, // number of callbacks
+        0, // priority
+    )?;
+
+    msg!(
+        "Auction bid queued: auction={}, bidder={}, index={}",
+        ctx.accounts.reserve_auction.auction_id,
+        bidder,
+        bidder_index
+    );
+
+    Ok(())
+}
@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, TokenAccount, Transfer};
+
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::{FeesCollectedEvent, SweepFees};
+
+// =============================================================================
+// SWEEP FEES - Send accrued execution fees to the treasury
+// =============================================================================
+// execute_swaps accrues each batch's execution fee to Pool.fees_owed; the
+// tokens stay in the deposit vaults meanwhile. This moves what is owed for
+// every asset to the treasury's token account for that asset, skipping assets
+// with nothing owed, and emits one FeesCollectedEvent per asset moved.
+// Permissionless: the destinations are fixed, so anyone may crank it.
+//
+// Every owed balance is swept in full, so the USDC value accrued alongside
+// them (Pool.fees_owed_usdc) moves into Pool.total_fees_collected as a whole.
+// The buyback budget and the invariant monitor read that total.
+
+/// Transfer the execution fees owed for every asset to the treasury.
+pub fn handler(ctx: Context<SweepFees>) -> Result<()> {
+    let pool_id = ctx.accounts.pool.pool_id;
+    let bump = ctx.accounts.pool.bump;
+    let pool_seeds = &[POOL_SEED, &[pool_id], &[bump]];
+    let signer_seeds = &[&pool_seeds[..]];
+
+    let accounts = &ctx.accounts;
+    let legs: [(&Account<TokenAccount>, &Account<TokenAccount>); 4] = [
+        (&accounts.vault_usdc, &accounts.treasury_usdc),
+        (&accounts.vault_tsla, &accounts.treasury_tsla),
+        (&accounts.vault_spy, &accounts.treasury_spy),
+        (&accounts.vault_aapl, &accounts.treasury_aapl),
+    ];
+
+    let mut swept = [0u64; 4];
+    let mut destinations = [Pubkey::default(); 4];
+    for (asset_id, (vault, treasury_token_account)) in legs.into_iter().enumerate() {
+        let amount = accounts.pool.fees_owed[asset_id];
+        if amount == 0 {
+            continue;
+        }
+        let transfer_ctx = CpiContext::new_with_signer(
+            accounts.token_program.to_account_info(),
+            Transfer {
+                from: vault.to_account_info(),
+                to: treasury_token_account.to_account_info(),
+                authority: accounts.pool.to_account_info(),
+            },
+            signer_seeds,
+        );
+        token::transfer(transfer_ctx, amount)?;
+        swept[asset_id] = amount;
+        destinations[asset_id] = treasury_token_account.key();
+    }
+    require!(
+        swept.iter().any(|&amount| amount > 0),
+        ErrorCode::NoFeesOwed
+    );
+
+    let slot = Clock::get()?.slot;
+    for (asset_id, (amount, treasury_token_account)) in
+        swept.into_iter().zip(destinations).enumerate()
+    {
+        if amount == 0 {
+            continue;
+        }
+        emit!(FeesCollectedEvent {
+            asset_id: asset_id as u8,
+            amount,
+            treasury_token_account,
+            seq: ctx.accounts.event_cursor.next(slot),
+        });
+    }
+
+    let pool = &mut ctx.accounts.pool;
+    for (owed, amount) in pool.fees_owed.iter_mut().zip(swept) {
+        *owed -= amount;
+    }
+    pool.total_fees_collected = pool
+        .total_fees_collected
+        .saturating_add(pool.fees_owed_usdc);
+    pool.fees_owed_usdc = 0;
+
+    msg!(
+        "Swept execution fees to the treasury: USDC {}, TSLA {}, SPY {}, AAPL {} (total collected {})",
+        swept[0],
+        swept[1],
+        swept[2],
+        swept[3],
+        pool.total_fees_collected
+    );
+    Ok(())
+}
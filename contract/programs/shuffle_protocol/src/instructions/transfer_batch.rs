@@ -0,0 +1,179 @@
+use anchor_lang::prelude::*;
+use arcium_anchor::prelude::*;
+
+use crate::constants::{MAX_BATCH_TRANSFER_RECIPIENTS, USER_SEED};
+use crate::errors::ErrorCode;
+use crate::state::UserProfile;
+use crate::{TransferBatch, TransferBatchCallback};
+
+// =============================================================================
+// TRANSFER BATCH - Payroll-style payouts in one MPC call
+// =============================================================================
+// One sender pays one asset to up to MAX_BATCH_TRANSFER_RECIPIENTS privacy
+// accounts, passed as writable remaining_accounts. The sender encrypts one
+// amount per recipient slot as a single BatchTransferRequest; transfer_batch
+// debits the total and credits every recipient at once, or moves nothing if
+// the sender cannot cover it.
+//
+// The circuit always has four slots. Unused slots are padded with the first
+// recipient's balance and zeroed by the circuit (recipient_count), and the
+// callback only writes back the slots that were passed.
+
+/// Queue a private payout from the sender to several privacy accounts.
+///
+/// # Arguments
+/// * `computation_offset` - Unique ID for MPC computation
+/// * `asset_id` - Asset to pay out (0=USDC, 1=TSLA, 2=SPY, 3=AAPL)
+/// * `encrypted_amounts` - Amount per recipient slot, encrypted with the sender's key
+/// * `pubkey` - Sender's x25519 public key
+/// * `nonce` - Nonce used to encrypt the amounts
+pub fn handler(
+    ctx: Context<TransferBatch>,
+    computation_offset: u64,
+    asset_id: u8,
+    encrypted_amounts: [[u8; 32]; 4],
+    pubkey: [u8; 32],
+    nonce: u128,
+) -> Result<()> {
+    require!(!ctx.accounts.pool.paused, ErrorCode::ProtocolPaused);
+    require!(asset_id <= 3, ErrorCode::InvalidAssetId);
+    require!(
+        !ctx.accounts.pool.is_asset_frozen(asset_id),
+        ErrorCode::AssetFrozen
+    );
+
+    // Recipients: distinct UserProfiles of this pool other than the sender
+    let recipient_infos = ctx.remaining_accounts;
+    require!(
+        (1..=MAX_BATCH_TRANSFER_RECIPIENTS).contains(&recipient_infos.len()),
+        ErrorCode::InvalidBatchRecipients
+    );
+    let mut recipients = Vec::with_capacity(recipient_infos.len());
+    for (slot, info) in recipient_infos.iter().enumerate() {
+        require!(
+            info.key() != ctx.accounts.sender_account.key()
+                && !recipient_infos[..slot]
+                    .iter()
+                    .any(|prev| prev.key() == info.key()),
+            ErrorCode::InvalidBatchRecipients
+        );
+        recipients.push(load_recipient(info, ctx.accounts.pool.pool_id)?);
+    }
+
+    // Callback reads the asset from the sender's account
+    ctx.accounts.sender_account.pending_asset_id = asset_id;
+
+    // Set sign PDA bump
+    ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+    // Sender activity resets their beneficiary inactivity timer
+    ctx.accounts.sender_account.last_active_at = Clock::get()?.unix_timestamp;
+
+    // Unused slots repeat the first recipient; the circuit pays them nothing
+    let slot = |i: usize| recipients.get(i).unwrap_or(&recipients[0]);
+    let sender = &ctx.accounts.sender_account;
+    let args = ArgBuilder::new()
+        // BatchTransferRequest (encrypted with sender's key) - four amounts
+        .x25519_pubkey(pubkey)
+        .plaintext_u128(nonce)
+        .encrypted_u64(encrypted_amounts[0])
+        .encrypted_u64(encrypted_amounts[1])
+        .encrypted_u64(encrypted_amounts[2])
+        .encrypted_u64(encrypted_amounts[3])
+        // Sender's balance (Enc<Shared, *> - sender's pubkey)
+        .x25519_pubkey(sender.user_pubkey)
+        .plaintext_u128(sender.get_nonce(asset_id))
+        .encrypted_u64(sender.get_credit(asset_id))
+        // Number of real recipient slots
+        .plaintext_u8(recipients.len() as u8)
+        // Recipient balances (Enc<Shared, *> - each recipient's pubkey)
+        .x25519_pubkey(slot(0).user_pubkey)
+        .plaintext_u128(slot(0).get_nonce(asset_id))
+        .encrypted_u64(slot(0).get_credit(asset_id))
+        .x25519_pubkey(slot(1).user_pubkey)
+        .plaintext_u128(slot(1).get_nonce(asset_id))
+        .encrypted_u64(slot(1).get_credit(asset_id))
+        .x25519_pubkey(slot(2).user_pubkey)
+        .plaintext_u128(slot(2).get_nonce(asset_id))
+        .encrypted_u64(slot(2).get_credit(asset_id))
+        .x25519_pubkey(slot(3).user_pubkey)
+        .plaintext_u128(slot(3).get_nonce(asset_id))
+        .encrypted_u64(slot(3).get_credit(asset_id))
+        .build();
+
+    // Recipients follow the named callback accounts, in slot order
+    use arcium_client::idl::arcium::types::CallbackAccount;
+    let mut callback_accounts = vec![
+        CallbackAccount {
+            pubkey: ctx.accounts.sender_account.key(),
+            is_writable: true,
+        },
+        crate::event_cursor_callback_account(ctx.accounts.pool.pool_id),
+    ];
+    callback_accounts.extend(recipient_infos.iter().map(|info| CallbackAccount {
+        pubkey: info.key(),
+        is_writable: true,
+    }));
+
+    queue_computation(
+        ctx.accounts,
+        computation_offset,
+        args,
+        vec![TransferBatchCallback::callback_ix(
+            computation_offset,
+            &ctx.accounts.mxe_account,
+            &callback_accounts,
+        )?],
+        1,
+        0,
+    )?;
+
+    msg!(
+        "Batch transfer queued: {} -> {} recipients, asset={}",
+        ctx.accounts.sender_account.owner,
+        recipients.len(),
+        asset_id
+    );
+    Ok(())
+}
+
+/// Read a recipient UserProfile passed as a remaining account, checking it
+/// is writable and is the pool's PDA for its owner
+fn load_recipient(info: &AccountInfo, pool_id: u8) -> Result<UserProfile> {
+    require!(
+        info.owner == &crate::ID && info.is_writable,
+        ErrorCode::InvalidBatchRecipients
+    );
+    let data = info.try_borrow_data()?;
+    let profile = UserProfile::try_deserialize(&mut &data[..])?;
+    let derived = Pubkey::create_program_address(
+        &[
+            USER_SEED,
+            &[pool_id],
+            profile.owner.as_ref(),
+            &[profile.bump],
+        ],
+        &crate::ID,
+    );
+    require!(
+        derived.ok() == Some(info.key()),
+        ErrorCode::InvalidBatchRecipients
+    );
+    Ok(profile)
+}
+
+/// Write a recipient's new balance of `asset_id` from the transfer_batch
+/// callback. Returns the recipient's wallet.
+pub fn credit_recipient(
+    info: &AccountInfo,
+    asset_id: u8,
+    balance: [u8; 32],
+    nonce: u128,
+) -> Result<Pubkey> {
+    let mut data = info.try_borrow_mut_data()?;
+    let mut profile = UserProfile::try_deserialize(&mut &data[..])?;
+    profile.set_credit(asset_id, balance);
+    profile.set_nonce(asset_id, nonce);
+    profile.try_serialize(&mut &mut data[..])?;
+    Ok(profile.owner)
+}
@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::{AssetUnfrozenEvent, UnfreezeAsset};
+
+/// Unfreeze an asset once its unfreeze timelock has elapsed.
+/// Only callable by pool authority.
+///
+/// # Arguments
+/// * `asset_id` - Frozen asset to reopen (0=USDC, 1=TSLA, 2=SPY, 3=AAPL)
+pub fn handler(ctx: Context<UnfreezeAsset>, asset_id: u8) -> Result<()> {
+    require!(asset_id <= 3, ErrorCode::InvalidAssetId);
+
+    let pool = &mut ctx.accounts.pool;
+    require!(pool.is_asset_frozen(asset_id), ErrorCode::AssetNotFrozen);
+
+    let available_at = pool.unfreeze_available_at[asset_id as usize];
+    require!(available_at != 0, ErrorCode::UnfreezeNotRequested);
+
+    let now = Clock::get()?.unix_timestamp;
+    require!(now >= available_at, ErrorCode::UnfreezeTimelockActive);
+
+    pool.frozen_assets &= !(1 << asset_id);
+    pool.unfreeze_available_at[asset_id as usize] = 0;
+
+    emit!(AssetUnfrozenEvent {
+        asset_id,
+        unfrozen_at: now,
+        seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+    });
+
+    msg!("Asset {} unfrozen", asset_id);
+    Ok(())
+}
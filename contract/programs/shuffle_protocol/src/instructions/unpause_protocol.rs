@@ -0,0 +1,18 @@
+use anchor_lang::prelude::*;
+
+use crate::{ProtocolPauseUpdatedEvent, UnpauseProtocol};
+
+/// Resume a paused protocol. Only callable by an admin.
+pub fn handler(ctx: Context<UnpauseProtocol>) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+    pool.paused = false;
+
+    emit!(ProtocolPauseUpdatedEvent {
+        paused: false,
+        updated_by: ctx.accounts.authority.key(),
+        seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+    });
+
+    msg!("Protocol unpaused by {}", ctx.accounts.authority.key());
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::{BatchConfigUpdatedEvent, UpdateBatchConfig};
+
+// =============================================================================
+// UPDATE BATCH CONFIG - Batch trigger thresholds
+// =============================================================================
+// The accumulate circuits report batch_ready once the batch holds at least
+// execution_trigger_count orders across at least min_active_pairs pairs.
+// Both are passed to MPC as plaintext on every order, so a change applies
+// from the next order placed.
+
+/// Update the batch trigger thresholds. Only callable by pool authority.
+///
+/// # Arguments
+/// * `execution_trigger_count` - Orders required for batch_ready (>= 1)
+/// * `min_active_pairs` - Pairs with activity required for batch_ready (1-6)
+pub fn handler(
+    ctx: Context<UpdateBatchConfig>,
+    execution_trigger_count: u8,
+    min_active_pairs: u8,
+) -> Result<()> {
+    require!(execution_trigger_count > 0, ErrorCode::InvalidBatchConfig);
+    require!(
+        min_active_pairs > 0 && min_active_pairs <= NUM_PAIRS,
+        ErrorCode::InvalidBatchConfig
+    );
+
+    let pool = &mut ctx.accounts.pool;
+    pool.execution_trigger_count = execution_trigger_count;
+    pool.min_active_pairs = min_active_pairs;
+
+    emit!(BatchConfigUpdatedEvent {
+        execution_trigger_count,
+        min_active_pairs,
+        seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+    });
+
+    msg!(
+        "Batch config updated: trigger at {} orders across {} pairs",
+        execution_trigger_count,
+        min_active_pairs
+    );
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::{PoolConfigUpdatedEvent, UpdatePoolConfig};
+
+// =============================================================================
+// UPDATE POOL CONFIG - Fee and batch trigger set at initialize
+// =============================================================================
+// execute_batch snapshots execution_fee_bps into the BatchLog, so a new fee
+// applies from the next batch executed; batches already queued settle at
+// the fee they were executed with. execution_trigger_count is passed to MPC
+// on every order and applies from the next order placed.
+
+/// Update the execution fee and batch trigger count. Only callable by pool
+/// authority.
+///
+/// # Arguments
+/// * `execution_fee_bps` - Fee on swaps in basis points (<= MAX_FEE_BPS)
+/// * `execution_trigger_count` - Orders required for batch_ready (>= 1)
+pub fn handler(
+    ctx: Context<UpdatePoolConfig>,
+    execution_fee_bps: u16,
+    execution_trigger_count: u8,
+) -> Result<()> {
+    // Same bounds as initialize and update_batch_config
+    require!(execution_fee_bps <= MAX_FEE_BPS, ErrorCode::FeeTooHigh);
+    require!(execution_trigger_count > 0, ErrorCode::InvalidBatchConfig);
+
+    let pool = &mut ctx.accounts.pool;
+    let previous_fee_bps = pool.execution_fee_bps;
+    let previous_trigger_count = pool.execution_trigger_count;
+    pool.execution_fee_bps = execution_fee_bps;
+    pool.execution_trigger_count = execution_trigger_count;
+
+    emit!(PoolConfigUpdatedEvent {
+        previous_fee_bps,
+        execution_fee_bps,
+        previous_trigger_count,
+        execution_trigger_count,
+        seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+    });
+
+    msg!(
+        "Pool config updated: fee {} -> {} bps, trigger {} -> {} orders",
+        previous_fee_bps,
+        execution_fee_bps,
+        previous_trigger_count,
+        execution_trigger_count
+    );
+    Ok(())
+}
@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::CircuitEntry;
+use crate::{CircuitUpgradedEvent, UpgradeCircuit};
+
+// =============================================================================
+// UPGRADE CIRCUIT - Record a computation definition's circuit hash
+// =============================================================================
+// The registry is created on first call. The first hash recorded for a
+// comp_def_offset gets version 1; every later upgrade bumps the version.
+// Recording the hash already registered is rejected so versions only move
+// when the circuit does.
+
+/// Record a new circuit hash for a computation definition.
+/// Only callable by an admin.
+///
+/// # Arguments
+/// * `comp_def_offset` - comp_def_offset of the computation definition
+/// * `hash` - Hash of the new circuit (as in circuit_hash!)
+pub fn handler(ctx: Context<UpgradeCircuit>, comp_def_offset: u32, hash: [u8; 32]) -> Result<()> {
+    require!(hash != [0u8; 32], ErrorCode::InvalidCircuitHash);
+
+    let registry = &mut ctx.accounts.circuit_registry;
+    registry.bump = ctx.bumps.circuit_registry;
+
+    let index = match registry.find(comp_def_offset) {
+        Some(i) => i,
+        None => registry
+            .free_entry()
+            .ok_or(ErrorCode::CircuitRegistryFull)?,
+    };
+    let previous = registry.circuits[index];
+    require!(previous.hash != hash, ErrorCode::InvalidCircuitHash);
+
+    let version = previous.version + 1;
+    registry.circuits[index] = CircuitEntry {
+        comp_def_offset,
+        hash,
+        version,
+        updated_at: Clock::get()?.unix_timestamp,
+    };
+
+    emit!(CircuitUpgradedEvent {
+        comp_def_offset,
+        previous_hash: previous.hash,
+        hash,
+        version,
+        upgraded_by: ctx.accounts.authority.key(),
+        seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+    });
+
+    msg!(
+        "Circuit {} upgraded to version {}",
+        comp_def_offset,
+        version
+    );
+    Ok(())
+}
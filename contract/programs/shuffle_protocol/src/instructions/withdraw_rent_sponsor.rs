@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::RentSponsor;
+use crate::{RentSponsorWithdrawnEvent, WithdrawRentSponsor};
+
+/// Withdraw unspent lamports from the rent sponsorship pool, keeping the
+/// pool rent-exempt. Only callable by pool authority.
+///
+/// # Arguments
+/// * `amount` - Lamports to withdraw to the authority
+pub fn handler(ctx: Context<WithdrawRentSponsor>, amount: u64) -> Result<()> {
+    require!(amount > 0, ErrorCode::InvalidAmount);
+
+    let sponsor = ctx.accounts.rent_sponsor.to_account_info();
+    let reserved = Rent::get()?.minimum_balance(RentSponsor::SIZE);
+    require!(
+        sponsor.lamports().saturating_sub(reserved) >= amount,
+        ErrorCode::InsufficientSponsorFunds
+    );
+
+    sponsor.sub_lamports(amount)?;
+    ctx.accounts.authority.add_lamports(amount)?;
+
+    let balance = sponsor.lamports();
+    emit!(RentSponsorWithdrawnEvent {
+        amount,
+        balance,
+        seq: ctx.accounts.event_cursor.next(Clock::get()?.slot)
+    });
+
+    msg!(
+        "Rent sponsor withdrawn: {} lamports (balance {})",
+        amount,
+        balance
+    );
+    Ok(())
+}
@@ -18,11 +18,14 @@ pub mod errors;
 /// Instruction handlers: initialize, deposit, withdraw, etc.
 pub mod instructions;
 
+/// Pyth price reads for batch pricing
+pub mod oracle;
+
 /// Account state structures: Pool, UserProfile, BatchAccumulator, BatchLog
 pub mod state;
 
 // Re-export errors for easier access
-pub use errors::ErrorCode;
+pub use errors::{ErrorCode, ErrorInfo};
 
 // =============================================================================
 // ARCIUM COMPUTATION DEFINITION OFFSETS
@@ -32,12 +35,76 @@ pub use errors::ErrorCode;
 
 const COMP_DEF_OFFSET_ADD_TOGETHER: u32 = comp_def_offset("add_together");
 const COMP_DEF_OFFSET_ADD_BALANCE: u32 = comp_def_offset("add_balance");
+const COMP_DEF_OFFSET_CREDIT_BALANCE: u32 = comp_def_offset("credit_balance");
 const COMP_DEF_OFFSET_SUB_BALANCE: u32 = comp_def_offset("sub_balance");
 const COMP_DEF_OFFSET_TRANSFER: u32 = comp_def_offset("transfer");
+const COMP_DEF_OFFSET_TRANSFER_MULTI: u32 = comp_def_offset("transfer_multi");
+const COMP_DEF_OFFSET_TRANSFER_BATCH: u32 = comp_def_offset("transfer_batch");
+const COMP_DEF_OFFSET_ESCROW_TRANSFER: u32 = comp_def_offset("escrow_transfer");
+const COMP_DEF_OFFSET_RELEASE_ESCROW: u32 = comp_def_offset("release_escrow");
 const COMP_DEF_OFFSET_ACCUMULATE_ORDER: u32 = comp_def_offset("accumulate_order");
 const COMP_DEF_OFFSET_INIT_BATCH_STATE: u32 = comp_def_offset("init_batch_state");
 const COMP_DEF_OFFSET_REVEAL_BATCH: u32 = comp_def_offset("reveal_batch");
 const COMP_DEF_OFFSET_CALCULATE_PAYOUT: u32 = comp_def_offset("calculate_payout");
+const COMP_DEF_OFFSET_INIT_AUCTION_STATE: u32 = comp_def_offset("init_auction_state");
+const COMP_DEF_OFFSET_SUBMIT_AUCTION_BID: u32 = comp_def_offset("submit_auction_bid");
+const COMP_DEF_OFFSET_REVEAL_AUCTION: u32 = comp_def_offset("reveal_auction");
+const COMP_DEF_OFFSET_SWEEP_BALANCE: u32 = comp_def_offset("sweep_balance");
+const COMP_DEF_OFFSET_REENCRYPT_BALANCE: u32 = comp_def_offset("reencrypt_balance");
+const COMP_DEF_OFFSET_ROTATE_USER_KEY: u32 = comp_def_offset("rotate_user_key");
+const COMP_DEF_OFFSET_CHECK_BALANCES_ZERO: u32 = comp_def_offset("check_balances_zero");
+const COMP_DEF_OFFSET_DISCLOSE_BALANCES: u32 = comp_def_offset("disclose_balances");
+const COMP_DEF_OFFSET_ACCUMULATE_BASKET: u32 = comp_def_offset("accumulate_basket");
+const COMP_DEF_OFFSET_ACCUMULATE_ORDER_USD: u32 = comp_def_offset("accumulate_order_usd");
+const COMP_DEF_OFFSET_ACCUMULATE_ORDER_DELEGATED: u32 =
+    comp_def_offset("accumulate_order_delegated");
+const COMP_DEF_OFFSET_SUB_BALANCE_DELEGATED: u32 = comp_def_offset("sub_balance_delegated");
+const COMP_DEF_OFFSET_MOVE_SAVINGS: u32 = comp_def_offset("move_savings");
+const COMP_DEF_OFFSET_STREAM_TRANSFER: u32 = comp_def_offset("stream_transfer");
+const COMP_DEF_OFFSET_FUND_VOUCHER: u32 = comp_def_offset("fund_voucher");
+const COMP_DEF_OFFSET_REDEEM_VOUCHER: u32 = comp_def_offset("redeem_voucher");
+const COMP_DEF_OFFSET_RECOVER_ACCOUNT: u32 = comp_def_offset("recover_account");
+
+/// Computation definitions a deployment needs, in Pool.bootstrap_flags bit
+/// order (the add_together demo is left out). Append new circuits at the end
+/// so existing bits keep their meaning.
+const BOOTSTRAP_COMP_DEFS: [u32; 29] = [
+    COMP_DEF_OFFSET_ADD_BALANCE,
+    COMP_DEF_OFFSET_CREDIT_BALANCE,
+    COMP_DEF_OFFSET_SUB_BALANCE,
+    COMP_DEF_OFFSET_TRANSFER,
+    COMP_DEF_OFFSET_TRANSFER_MULTI,
+    COMP_DEF_OFFSET_TRANSFER_BATCH,
+    COMP_DEF_OFFSET_ESCROW_TRANSFER,
+    COMP_DEF_OFFSET_RELEASE_ESCROW,
+    COMP_DEF_OFFSET_ACCUMULATE_ORDER,
+    COMP_DEF_OFFSET_INIT_BATCH_STATE,
+    COMP_DEF_OFFSET_REVEAL_BATCH,
+    COMP_DEF_OFFSET_CALCULATE_PAYOUT,
+    COMP_DEF_OFFSET_INIT_AUCTION_STATE,
+    COMP_DEF_OFFSET_SUBMIT_AUCTION_BID,
+    COMP_DEF_OFFSET_REVEAL_AUCTION,
+    COMP_DEF_OFFSET_SWEEP_BALANCE,
+    COMP_DEF_OFFSET_REENCRYPT_BALANCE,
+    COMP_DEF_OFFSET_ROTATE_USER_KEY,
+    COMP_DEF_OFFSET_CHECK_BALANCES_ZERO,
+    COMP_DEF_OFFSET_DISCLOSE_BALANCES,
+    COMP_DEF_OFFSET_ACCUMULATE_BASKET,
+    COMP_DEF_OFFSET_ACCUMULATE_ORDER_USD,
+    COMP_DEF_OFFSET_ACCUMULATE_ORDER_DELEGATED,
+    COMP_DEF_OFFSET_SUB_BALANCE_DELEGATED,
+    COMP_DEF_OFFSET_MOVE_SAVINGS,
+    COMP_DEF_OFFSET_STREAM_TRANSFER,
+    COMP_DEF_OFFSET_FUND_VOUCHER,
+    COMP_DEF_OFFSET_REDEEM_VOUCHER,
+    COMP_DEF_OFFSET_RECOVER_ACCOUNT,
+];
+
+/// Pool.bootstrap_flags of a fully initialized deployment: every comp def
+/// plus both accumulator slots created and holding encrypted zeros
+const BOOTSTRAP_COMPLETE: u64 = ((1 << BOOTSTRAP_COMP_DEFS.len()) - 1)
+    | (0b11 * state::BOOTSTRAP_ACCUMULATOR_CREATED)
+    | (0b11 * state::BOOTSTRAP_BATCH_STATE_READY);
 
 // =============================================================================
 // PROGRAM ID
@@ -75,6 +142,7 @@ use anchor_spl::token::{self, Token, TokenAccount, Transfer};
 /// * `pool` - Pool PDA (authority for vaults)
 /// * `token_program` - SPL Token program
 /// * `amount` - Amount to transfer
+/// * `pool_id` - Pool.pool_id, for signing
 /// * `pool_bump` - PDA bump for signing
 pub fn execute_vault_to_reserve_transfer<'info>(
     from_vault: &Account<'info, TokenAccount>,
@@ -82,13 +150,14 @@ pub fn execute_vault_to_reserve_transfer<'info>(
     pool: &AccountInfo<'info>,
     token_program: &Program<'info, Token>,
     amount: u64,
+    pool_id: u8,
     pool_bump: u8,
 ) -> Result<()> {
     if amount == 0 {
         return Ok(());
     }
 
-    let pool_seeds = &[constants::POOL_SEED, &[pool_bump]];
+    let pool_seeds = &[constants::POOL_SEED, &[pool_id], &[pool_bump]];
     let signer_seeds = &[&pool_seeds[..]];
 
     let transfer_ctx = CpiContext::new_with_signer(
@@ -113,13 +182,14 @@ pub fn execute_reserve_to_vault_transfer<'info>(
     pool: &AccountInfo<'info>,
     token_program: &Program<'info, Token>,
     amount: u64,
+    pool_id: u8,
     pool_bump: u8,
 ) -> Result<()> {
     if amount == 0 {
         return Ok(());
     }
 
-    let pool_seeds = &[constants::POOL_SEED, &[pool_bump]];
+    let pool_seeds = &[constants::POOL_SEED, &[pool_id], &[pool_bump]];
     let signer_seeds = &[&pool_seeds[..]];
 
     let transfer_ctx = CpiContext::new_with_signer(
@@ -137,6 +207,381 @@ pub fn execute_reserve_to_vault_transfer<'info>(
     Ok(())
 }
 
+/// Anchor instruction discriminator for the swap adapter's "swap" instruction.
+/// sha256("global:swap")[0..8] = f8c69e91e17587c8
+/// Pre-computed to avoid runtime hash dependency.
+const SWAP_ADAPTER_DISCRIMINATOR: [u8; 8] = [0xf8, 0xc6, 0x9e, 0x91, 0xe1, 0x75, 0x87, 0xc8];
+
+/// CPI into the external swap adapter (mock_jupiter's `swap` instruction).
+/// The Pool PDA signs as the "user_authority" since it owns the source and
+/// destination token accounts.
+///
+/// # Arguments
+/// * `swap_program` - Swap adapter program to invoke
+/// * `accounts` - Accounts in the adapter's Swap order:
+///   pool (authority), swap_pool, source_mint, destination_mint,
+///   pool source token, pool destination token, adapter source vault,
+///   adapter destination vault, token_program
+/// * `amount_in` - Amount of source tokens to swap
+/// * `min_amount_out` - Minimum acceptable output (slippage protection)
+/// * `pool_id` - Pool.pool_id, for signing
+/// * `pool_bump` - PDA bump for signing
+pub fn invoke_swap_adapter<'info>(
+    swap_program: &AccountInfo<'info>,
+    accounts: [AccountInfo<'info>; 9],
+    amount_in: u64,
+    min_amount_out: u64,
+    pool_id: u8,
+    pool_bump: u8,
+) -> Result<()> {
+    use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+    use anchor_lang::solana_program::program::invoke_signed;
+
+    // Layout: [8-byte discriminator][8-byte amount_in LE][8-byte min_amount_out LE]
+    let mut data = Vec::with_capacity(8 + 8 + 8);
+    data.extend_from_slice(&SWAP_ADAPTER_DISCRIMINATOR);
+    data.extend_from_slice(&amount_in.to_le_bytes());
+    data.extend_from_slice(&min_amount_out.to_le_bytes());
+
+    // Pool PDA signs; mints and token program are read-only, the rest writable
+    let metas = accounts
+        .iter()
+        .enumerate()
+        .map(|(i, info)| match i {
+            0 => AccountMeta::new(*info.key, true),
+            2 | 3 | 8 => AccountMeta::new_readonly(*info.key, false),
+            _ => AccountMeta::new(*info.key, false),
+        })
+        .collect();
+
+    let ix = Instruction {
+        program_id: *swap_program.key,
+        accounts: metas,
+        data,
+    };
+
+    let pool_seeds = &[constants::POOL_SEED, &[pool_id], &[pool_bump]];
+    let signer_seeds = &[&pool_seeds[..]];
+
+    invoke_signed(&ix, &accounts, signer_seeds)?;
+    Ok(())
+}
+
+// =============================================================================
+// RESTRICTED ASSET TRANSFER HELPERS
+// =============================================================================
+// Deposits, withdrawals, liquidity and the faucet go through transfer_checked
+// on the token interface so Token-2022 mints work. For mints with a transfer
+// hook, the hook's extra accounts (validation PDA, hook program, extra metas)
+// are forwarded and resolved by spl_token_2022's on-chain helper. Mints with
+// a transfer fee deliver amount minus the fee (see token_transfer_fee).
+
+/// Fail unless `wallet` has an entry on the issuer allowlist required by `policy`.
+/// The entry must be the ["allowlist", mint, wallet] PDA of the allowlist
+/// program and be owned by it.
+pub fn check_issuer_allowlist(
+    policy: &state::AssetTransferPolicy,
+    wallet: &Pubkey,
+    allowlist_entry: Option<&AccountInfo>,
+) -> Result<()> {
+    if !policy.requires_allowlist() {
+        return Ok(());
+    }
+
+    let entry = allowlist_entry.ok_or(errors::ErrorCode::NotAllowlisted)?;
+    let (expected, _) = Pubkey::find_program_address(
+        &[
+            constants::ISSUER_ALLOWLIST_SEED,
+            policy.mint.as_ref(),
+            wallet.as_ref(),
+        ],
+        &policy.allowlist_program,
+    );
+    require!(
+        entry.key() == expected
+            && *entry.owner == policy.allowlist_program
+            && !entry.data_is_empty(),
+        errors::ErrorCode::NotAllowlisted
+    );
+    Ok(())
+}
+
+/// Accounts for a checked transfer that may invoke a Token-2022 transfer hook.
+pub struct CheckedTransferAccounts<'a, 'info> {
+    pub token_program: &'a AccountInfo<'info>,
+    pub from: &'a AccountInfo<'info>,
+    pub mint: &'a AccountInfo<'info>,
+    pub to: &'a AccountInfo<'info>,
+    pub authority: &'a AccountInfo<'info>,
+    /// Transfer-hook extra accounts (empty for mints without a hook)
+    pub hook_accounts: &'a [AccountInfo<'info>],
+}
+
+/// transfer_checked through the token interface, forwarding transfer-hook
+/// extra accounts when present.
+pub fn transfer_checked_with_hook<'info>(
+    accounts: CheckedTransferAccounts<'_, 'info>,
+    amount: u64,
+    decimals: u8,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    if accounts.hook_accounts.is_empty() {
+        let transfer_ctx = CpiContext::new_with_signer(
+            accounts.token_program.clone(),
+            anchor_spl::token_interface::TransferChecked {
+                from: accounts.from.clone(),
+                mint: accounts.mint.clone(),
+                to: accounts.to.clone(),
+                authority: accounts.authority.clone(),
+            },
+            signer_seeds,
+        );
+        return anchor_spl::token_interface::transfer_checked(transfer_ctx, amount, decimals);
+    }
+
+    anchor_spl::token_2022::spl_token_2022::onchain::invoke_transfer_checked(
+        accounts.token_program.key,
+        accounts.from.clone(),
+        accounts.mint.clone(),
+        accounts.to.clone(),
+        accounts.authority.clone(),
+        accounts.hook_accounts,
+        amount,
+        decimals,
+        signer_seeds,
+    )?;
+    Ok(())
+}
+
+/// Fee a Token-2022 transfer-fee mint withholds from a transfer of `amount`
+/// in the current epoch (0 for SPL Token mints and mints without the
+/// extension).
+pub fn token_transfer_fee(mint: &AccountInfo, amount: u64) -> Result<u64> {
+    use anchor_spl::token_2022::spl_token_2022::{
+        self,
+        extension::{
+            transfer_fee::TransferFeeConfig, BaseStateWithExtensions, StateWithExtensions,
+        },
+    };
+
+    if *mint.owner != spl_token_2022::ID {
+        return Ok(0);
+    }
+    let data = mint.try_borrow_data()?;
+    let state = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&data)?;
+    match state.get_extension::<TransferFeeConfig>() {
+        Ok(config) => config
+            .calculate_epoch_fee(Clock::get()?.epoch, amount)
+            .ok_or_else(|| errors::ErrorCode::InvalidAmount.into()),
+        Err(_) => Ok(0),
+    }
+}
+
+// =============================================================================
+// PROFILING HELPERS
+// =============================================================================
+// Compute unit accounting for the optional Diagnostics account.
+// Measurements cover the handler body only (account deserialization and
+// Anchor constraint checks happen before the start marker).
+
+/// Remaining compute units - take this at the top of a profiled handler.
+pub fn profiling_start() -> u64 {
+    anchor_lang::solana_program::compute_units::sol_remaining_compute_units()
+}
+
+/// Record the CUs used since `cu_start` if a Diagnostics account was passed
+/// and profiling is enabled.
+pub fn record_profile(
+    diagnostics: Option<&mut Account<'_, state::Diagnostics>>,
+    ix: u8,
+    cu_start: u64,
+) {
+    if let Some(diagnostics) = diagnostics {
+        if diagnostics.enabled {
+            let cu_used = cu_start.saturating_sub(profiling_start());
+            diagnostics.record(ix, cu_used);
+        }
+    }
+}
+
+/// CallbackAccount entry for an optional Diagnostics account.
+pub fn diagnostics_callback_account(
+    diagnostics: &Option<Account<'_, state::Diagnostics>>,
+) -> arcium_client::idl::arcium::types::CallbackAccount {
+    optional_callback_account(diagnostics.as_ref().map(|d| d.key()), true)
+}
+
+/// CallbackAccount entry for the pool's EventCursor, appended last by every
+/// queue site whose callback emits business events.
+pub fn event_cursor_callback_account(
+    pool_id: u8,
+) -> arcium_client::idl::arcium::types::CallbackAccount {
+    arcium_client::idl::arcium::types::CallbackAccount {
+        pubkey: Pubkey::find_program_address(&[EVENT_CURSOR_SEED, &[pool_id]], &crate::ID).0,
+        is_writable: true,
+    }
+}
+
+/// CallbackAccount entry for the ProtocolStats account (reveal_batch callback).
+pub fn protocol_stats_callback_account(
+    pool_id: u8,
+) -> arcium_client::idl::arcium::types::CallbackAccount {
+    arcium_client::idl::arcium::types::CallbackAccount {
+        pubkey: Pubkey::find_program_address(&[PROTOCOL_STATS_SEED, &[pool_id]], &crate::ID).0,
+        is_writable: true,
+    }
+}
+
+/// CallbackAccount entry for an optional callback account.
+/// Anchor treats the program ID as "None" for optional accounts.
+pub fn optional_callback_account(
+    key: Option<Pubkey>,
+    is_writable: bool,
+) -> arcium_client::idl::arcium::types::CallbackAccount {
+    arcium_client::idl::arcium::types::CallbackAccount {
+        pubkey: key.unwrap_or(crate::ID),
+        is_writable: key.is_some() && is_writable,
+    }
+}
+
+// =============================================================================
+// EXECUTOR GATING
+// =============================================================================
+
+/// With Pool.executor_gated set, require `caller` to hold a bond in the
+/// ExecutorRegistry (execute_batch, execute_swaps). No-op otherwise.
+pub fn require_executor(
+    pool: &state::Pool,
+    registry: Option<&state::ExecutorRegistry>,
+    caller: &Pubkey,
+) -> Result<()> {
+    if pool.executor_gated {
+        require!(
+            registry.is_some_and(|registry| registry.is_registered(caller)),
+            ErrorCode::ExecutorNotRegistered
+        );
+    }
+    Ok(())
+}
+
+// =============================================================================
+// ACCOUNT VERSIONING
+// =============================================================================
+
+/// Upgrade a versioned account (Pool, UserProfile, BatchAccumulator,
+/// BatchLog) in place: zero-extend it to `size`, top up its rent from
+/// `payer` and stamp `version` at `version_offset`. An account that ends at
+/// `version_offset` predates the version field (version 0); the Pool's
+/// original layout is handled separately (LegacyPool). Returns the version
+/// it was migrated from.
+pub fn migrate_account_version<'info>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    discriminator: &[u8],
+    version_offset: usize,
+    size: usize,
+    version: u8,
+) -> Result<u8> {
+    require_keys_eq!(*account.owner, crate::ID, ErrorCode::InvalidOwner);
+    let old_size = account.data_len();
+    let from_version = {
+        let data = account.try_borrow_data()?;
+        require!(
+            data.len() >= 8 && data[..8] == *discriminator,
+            ErrorCode::InvalidOwner
+        );
+        match old_size.cmp(&version_offset) {
+            std::cmp::Ordering::Equal => 0,
+            std::cmp::Ordering::Greater if old_size <= size => data[version_offset],
+            _ => return err!(ErrorCode::UnsupportedAccountLayout),
+        }
+    };
+    require!(from_version < version, ErrorCode::AccountAlreadyMigrated);
+
+    grow_account(account, payer, system_program, size)?;
+    account.try_borrow_mut_data()?[version_offset] = version;
+    Ok(from_version)
+}
+
+/// Zero-extend a program account to `size`, topping up its rent from
+/// `payer` (migrate_account_version, migrate_pool for LegacyPool)
+pub fn grow_account<'info>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    size: usize,
+) -> Result<()> {
+    // Rent for the larger layout comes from the caller
+    let required = Rent::get()?.minimum_balance(size);
+    let top_up = required.saturating_sub(account.lamports());
+    if top_up > 0 {
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                anchor_lang::system_program::Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            top_up,
+        )?;
+    }
+
+    let old_size = account.data_len();
+    account.resize(size)?;
+    account.try_borrow_mut_data()?[old_size..].fill(0);
+    Ok(())
+}
+
+// =============================================================================
+// RISK LIMITS
+// =============================================================================
+
+/// With Pool.risk_configured set, count a withdrawal against the owner's
+/// RiskConfig.max_daily_withdrawal for the asset (sub_balance,
+/// delegated_withdraw). No-op otherwise and for uncapped assets.
+pub fn check_withdrawal_cap(
+    pool: &state::Pool,
+    risk_config: Option<&state::RiskConfig>,
+    window: Option<&mut state::WithdrawalWindow>,
+    owner: &Pubkey,
+    asset_id: u8,
+    amount: u64,
+) -> Result<()> {
+    if !pool.risk_configured {
+        return Ok(());
+    }
+    let risk_config = risk_config.ok_or(ErrorCode::RiskConfigMissing)?;
+    let cap = risk_config.max_daily_withdrawal[asset_id as usize];
+    if cap == 0 {
+        return Ok(());
+    }
+    let window = window.ok_or(ErrorCode::RiskConfigMissing)?;
+    window.owner = *owner;
+    require!(
+        window.record(asset_id, amount, cap, Clock::get()?.unix_timestamp),
+        ErrorCode::WithdrawalCapExceeded
+    );
+    Ok(())
+}
+
+// =============================================================================
+// CALLBACK FAILURE HELPERS
+// =============================================================================
+
+/// Emit CallbackFailedEvent for a callback whose output failed verification.
+/// Call right before returning AbortedComputation.
+pub fn emit_callback_failed(encrypted_ix: &str, computation: Pubkey) {
+    emit!(CallbackFailedEvent {
+        encrypted_ix: encrypted_ix.to_string(),
+        computation,
+        error: ErrorCode::AbortedComputation.info(),
+        failed_at: Clock::get().map(|c| c.unix_timestamp).unwrap_or_default(),
+        seq: 0, // not sequenced: the transaction fails
+    });
+}
+
 #[arcium_program]
 pub mod shuffle_protocol {
     use super::*;
@@ -146,19 +591,22 @@ pub mod shuffle_protocol {
     // PROTOCOL INITIALIZATION (Phase 3)
     // =========================================================================
 
-    /// Initialize the Shuffle Protocol protocol.
+    /// Initialize a Shuffle Protocol pool.
     /// Creates the Pool account and all token vaults.
-    /// Should only be called once when deploying the protocol.
+    /// Called once per pool; independent pools (e.g. a devnet test pool and
+    /// an equities pool) use different pool_ids.
     ///
     /// # Arguments
+    /// * `pool_id` - Identifier of the pool, seeded into every pool-scoped PDA
     /// * `execution_fee_bps` - Fee on swaps in basis points (e.g., 50 = 0.5%)
     /// * `execution_trigger_count` - Number of orders to trigger batch execution
     pub fn initialize(
         ctx: Context<Initialize>,
+        pool_id: u8,
         execution_fee_bps: u16,
         execution_trigger_count: u8,
     ) -> Result<()> {
-        instructions::initialize::handler(ctx, execution_fee_bps, execution_trigger_count)
+        instructions::initialize::handler(ctx, pool_id, execution_fee_bps, execution_trigger_count)
     }
 
     // =========================================================================
@@ -186,6 +634,107 @@ pub mod shuffle_protocol {
         )
     }
 
+    /// Create a privacy account and deposit into it in one transaction.
+    /// The deposit completes through add_balance_callback.
+    ///
+    /// # Arguments
+    /// * `computation_offset` - Unique ID for the add_balance computation
+    /// * `user_pubkey` - User's x25519 public key for Arcium encryption
+    /// * `initial_balances` - Encrypted zero balances for all 4 assets
+    /// * `initial_nonce` - Nonce used to encrypt the initial balances
+    /// * `encrypted_amount` - The deposit amount encrypted with user's key
+    /// * `nonce` - Nonce of the encrypted amount (must differ from `initial_nonce`)
+    /// * `amount` - Plaintext amount for token transfer
+    /// * `asset_id` - Asset identifier (0=USDC, 1=TSLA, 2=SPY, 3=AAPL)
+    pub fn create_and_deposit<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreateAndDeposit<'info>>,
+        computation_offset: u64,
+        user_pubkey: [u8; 32],
+        initial_balances: [[u8; 32]; 4],
+        initial_nonce: u128,
+        encrypted_amount: [u8; 32],
+        nonce: u128,
+        amount: u64,
+        asset_id: u8,
+    ) -> Result<()> {
+        instructions::create_and_deposit::handler(
+            ctx,
+            computation_offset,
+            user_pubkey,
+            initial_balances,
+            initial_nonce,
+            encrypted_amount,
+            nonce,
+            amount,
+            asset_id,
+        )
+    }
+
+    /// Rewrite a UserProfile created with the pre-audit layout into the
+    /// current one and return the freed rent to the owner. Permissionless.
+    pub fn migrate_user_account(ctx: Context<MigrateUserAccount>) -> Result<()> {
+        instructions::migrate_user_account::handler(ctx)
+    }
+
+    /// Upgrade the Pool account to the current layout version, paying the
+    /// extra rent. Permissionless; run before the other migrations. A pool
+    /// in the original layout also takes its four mints as remaining
+    /// accounts.
+    ///
+    /// # Arguments
+    /// * `pool_id` - Pool to migrate
+    pub fn migrate_pool<'info>(
+        ctx: Context<'_, '_, '_, 'info, MigratePool<'info>>,
+        pool_id: u8,
+    ) -> Result<()> {
+        instructions::migrate_pool::handler(ctx, pool_id)
+    }
+
+    /// Upgrade a UserProfile to the current layout version, paying the
+    /// extra rent. Permissionless.
+    pub fn migrate_user_profile(ctx: Context<MigrateUserProfile>) -> Result<()> {
+        instructions::migrate_user_profile::handler(ctx)
+    }
+
+    /// Upgrade a BatchAccumulator slot to the current layout version,
+    /// paying the extra rent. Permissionless.
+    ///
+    /// # Arguments
+    /// * `slot` - Accumulator slot to migrate (0 or 1)
+    pub fn migrate_batch_accumulator(
+        ctx: Context<MigrateBatchAccumulator>,
+        _slot: u8,
+    ) -> Result<()> {
+        instructions::migrate_batch_accumulator::handler(ctx)
+    }
+
+    /// Upgrade a BatchLog to the current layout version, paying the extra
+    /// rent. Permissionless.
+    ///
+    /// # Arguments
+    /// * `batch_id` - Batch whose log to migrate
+    pub fn migrate_batch_log(ctx: Context<MigrateBatchLog>, _batch_id: u64) -> Result<()> {
+        instructions::migrate_batch_log::handler(ctx)
+    }
+
+    /// Deposit lamports into the UserProfile rent sponsorship pool.
+    /// Only callable by pool authority.
+    ///
+    /// # Arguments
+    /// * `amount` - Lamports to deposit
+    pub fn fund_rent_sponsor(ctx: Context<FundRentSponsor>, amount: u64) -> Result<()> {
+        instructions::fund_rent_sponsor::handler(ctx, amount)
+    }
+
+    /// Withdraw unspent lamports from the rent sponsorship pool.
+    /// Only callable by pool authority.
+    ///
+    /// # Arguments
+    /// * `amount` - Lamports to withdraw
+    pub fn withdraw_rent_sponsor(ctx: Context<WithdrawRentSponsor>, amount: u64) -> Result<()> {
+        instructions::withdraw_rent_sponsor::handler(ctx, amount)
+    }
+
     // =========================================================================
     // DEPOSIT (Phase 5 - REMOVED)
     // =========================================================================
@@ -196,12 +745,33 @@ pub mod shuffle_protocol {
     // BATCH ACCUMULATOR INITIALIZATION (Phase 8)
     // =========================================================================
 
-    /// Initialize the BatchAccumulator singleton account.
-    /// This must be called once after pool initialization before orders can be placed.
+    /// Initialize one of the two double-buffered BatchAccumulator slots.
+    /// Must be called for slot 0 and slot 1 after pool initialization before
+    /// orders can be placed. Slot 0 starts active with batch_id 1.
     /// The BatchAccumulator tracks all orders across the 6 trading pairs.
-    /// It auto-triggers batch execution when order_count >= 8 AND active_pairs >= 2.
-    pub fn init_batch_accumulator(ctx: Context<InitBatchAccumulator>) -> Result<()> {
-        instructions::init_batch_accumulator::handler(ctx)
+    /// It auto-triggers batch execution when order_count >= Pool.execution_trigger_count
+    /// AND active_pairs >= Pool.min_active_pairs (default 8 and 2).
+    /// Only callable by pool authority.
+    ///
+    /// # Arguments
+    /// * `slot` - Accumulator slot to create (0 or 1)
+    pub fn init_batch_accumulator(ctx: Context<InitBatchAccumulator>, slot: u8) -> Result<()> {
+        instructions::init_batch_accumulator::handler(ctx, slot)
+    }
+
+    /// Initialize a pool's EventCursor.
+    /// Must be called once after pool initialization: every callback that
+    /// emits user, batch or settlement events stamps them with the next
+    /// sequence number from this account.
+    pub fn init_event_cursor(ctx: Context<InitEventCursor>) -> Result<()> {
+        instructions::init_event_cursor::handler(ctx)
+    }
+
+    /// Initialize the ProtocolStats analytics account.
+    /// Must be called once after pool initialization: reveal_batch_callback
+    /// and execute_swaps update it for every batch.
+    pub fn init_protocol_stats(ctx: Context<InitProtocolStats>) -> Result<()> {
+        instructions::init_protocol_stats::handler(ctx)
     }
 
     // =========================================================================
@@ -211,6 +781,7 @@ pub mod shuffle_protocol {
     /// Place an encrypted order in the current batch.
     /// Order details (pair_id, direction, amount) are encrypted on-chain.
     /// Only batch aggregates are revealed during execution.
+    /// May be signed by the owner or an active session key (Delegate).
     ///
     /// # Arguments
     /// * `computation_offset` - Unique ID for MPC computation
@@ -220,6 +791,7 @@ pub mod shuffle_protocol {
     /// * `pubkey` - User's x25519 public key
     /// * `nonce` - Encryption nonce
     /// * `source_asset_id` - Plaintext hint for which asset is sold
+    /// * `valid_until_batch` - Last batch an unexecuted order rolls over into (0 = none)
     pub fn place_order(
         ctx: Context<PlaceOrder>,
         computation_offset: u64,
@@ -229,6 +801,7 @@ pub mod shuffle_protocol {
         pubkey: [u8; 32],
         nonce: u128,
         source_asset_id: u8,
+        valid_until_batch: u64,
     ) -> Result<()> {
         instructions::place_order::handler(
             ctx,
@@ -239,6 +812,7 @@ pub mod shuffle_protocol {
             pubkey,
             nonce,
             source_asset_id,
+            valid_until_batch,
         )
     }
 
@@ -270,29 +844,95 @@ pub mod shuffle_protocol {
                 // Clear pending_order so user can retry if MPC computation fails
                 msg!("MPC computation failed, clearing pending_order");
                 ctx.accounts.user_account.pending_order = None;
+                emit_callback_failed("accumulate_order", ctx.accounts.computation_account.key());
                 return Err(ErrorCode::AbortedComputation.into());
             }
         };
 
-        // MPC output is a 4-tuple: (has_funds, batch_ready, new_balance, new_batch_state)
-        // Wrapped as: o.field_0 = tuple containing all four
+        // MPC output is a 5-tuple: (has_funds, batch_ready, new_balance, new_batch_state, route_ok)
+        // Wrapped as: o.field_0 = tuple containing all five
         // o.field_0.field_0 = bool (has_funds, revealed)
         // o.field_0.field_1 = bool (batch_ready, revealed)
         // o.field_0.field_2 = UserBalance (SharedEncryptedStruct<1>)
         // o.field_0.field_3 = BatchState (MXEEncryptedStruct - now includes order_count + active_pairs)
+        // o.field_0.field_4 = bool (route_ok, revealed)
 
         let has_funds: bool = o.field_0.field_0;
         let batch_ready: bool = o.field_0.field_1;
 
+        // The slot was handed over (batch executing) while this computation
+        // was in flight: discard the outputs so neither the balance nor the
+        // revealed batch changes, and release the order
+        let order_batch_id = ctx
+            .accounts
+            .user_account
+            .pending_order
+            .map(|order| order.batch_id)
+            .unwrap_or_default();
+        if !ctx.accounts.batch_accumulator.accepts(order_batch_id) {
+            emit!(OrderRejectedEvent {
+                user: ctx.accounts.user_account.owner,
+                batch_id: order_batch_id,
+                error: ErrorCode::InvalidBatchSlot.info(),
+                seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+            });
+            ctx.accounts.user_account.pending_order = None;
+            msg!("Order rejected: batch {} is no longer open", order_batch_id);
+            return Ok(());
+        }
+
+        // pair_id or direction out of range (only the check result is
+        // revealed): nothing was deducted or accumulated, release the order
+        if !o.field_0.field_4 {
+            emit!(OrderRejectedEvent {
+                user: ctx.accounts.user_account.owner,
+                batch_id: order_batch_id,
+                error: ErrorCode::OrderRouteOutOfRange.info(),
+                seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+            });
+            ctx.accounts.user_account.pending_order = None;
+            msg!("Order rejected: pair or direction out of range");
+            return Ok(());
+        }
+
+        // A deposit or withdrawal replaced the balance while this computation
+        // was in flight: the result would undo it, release the order
+        let asset_id = ctx.accounts.user_account.pending_asset_id;
+        let current_nonce = match ctx.accounts.savings_bucket.as_ref() {
+            Some(bucket) => bucket.nonce,
+            None => ctx.accounts.user_account.get_nonce(asset_id),
+        };
+        if current_nonce != ctx.accounts.user_account.pending_order_nonce {
+            emit!(OrderRejectedEvent {
+                user: ctx.accounts.user_account.owner,
+                batch_id: order_batch_id,
+                error: ErrorCode::PendingOperationStale.info(),
+                seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+            });
+            ctx.accounts.user_account.pending_order = None;
+            msg!("Order rejected: balance changed while in flight");
+            return Ok(());
+        }
+
         // If user doesn't have sufficient funds, clear pending_order and abort
         if !has_funds {
             msg!("Order rejected: insufficient balance");
+            emit!(OrderRejectedEvent {
+                user: ctx.accounts.user_account.owner,
+                batch_id: ctx
+                    .accounts
+                    .user_account
+                    .pending_order
+                    .map(|order| order.batch_id)
+                    .unwrap_or_default(),
+                error: ErrorCode::InsufficientBalance.info(),
+                seq: 0, // not sequenced: the transaction fails
+            });
             ctx.accounts.user_account.pending_order = None;
             return Err(ErrorCode::InsufficientBalance.into());
         }
 
         // Update user's balance for the source asset
-        let asset_id = ctx.accounts.user_account.pending_asset_id;
         let old_nonce = ctx.accounts.user_account.get_nonce(asset_id);
         let new_nonce = o.field_0.field_2.nonce;
         let new_ciphertext = o.field_0.field_2.ciphertexts[0];
@@ -305,10 +945,16 @@ pub mod shuffle_protocol {
             &new_ciphertext[0..4]
         );
 
-        ctx.accounts
-            .user_account
-            .set_credit(asset_id, new_ciphertext);
-        ctx.accounts.user_account.set_nonce(asset_id, new_nonce);
+        // Bucket-funded DCA orders were checked against the bucket balance
+        if let Some(bucket) = ctx.accounts.savings_bucket.as_mut() {
+            bucket.encrypted_balance = new_ciphertext;
+            bucket.nonce = new_nonce;
+        } else {
+            ctx.accounts
+                .user_account
+                .set_credit(asset_id, new_ciphertext);
+            ctx.accounts.user_account.set_nonce(asset_id, new_nonce);
+        }
 
         // Update batch accumulator with new encrypted batch state from MPC
         // Ciphertext layout: 12 values (6 pairs × 2 totals each)
@@ -343,7 +989,7 @@ pub mod shuffle_protocol {
             batch.order_count
         );
 
-        // Check batch_ready flag from MPC (requirements: >= 8 orders AND >= 2 pairs)
+        // Check batch_ready flag from MPC (requirements: Pool batch trigger thresholds)
         if batch_ready {
             msg!("Batch ready for execution: MPC confirmed requirements met");
 
@@ -351,12 +997,14 @@ pub mod shuffle_protocol {
             emit!(BatchReadyEvent {
                 batch_id: batch.batch_id,
                 batch_accumulator: batch_accumulator_key,
+                seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
             });
         }
 
         emit!(OrderPlacedEvent {
             user: ctx.accounts.user_account.owner,
             batch_id: batch.batch_id,
+            seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
         });
 
         msg!(
@@ -370,251 +1018,244 @@ pub mod shuffle_protocol {
     }
 
     // =========================================================================
-    // EXECUTE BATCH (Phase 9)
+    // USD-SIZED ORDERS
     // =========================================================================
 
-    /// Execute the current batch.
-    /// Reveals aggregate totals via MPC, then performs netting and swaps in callback.
+    /// Place an encrypted order sized by USD notional instead of asset units.
+    /// MPC converts the notional at the oracle price of the source asset.
+    /// May be signed by the owner or an active session key (Delegate).
     ///
     /// # Arguments
     /// * `computation_offset` - Unique ID for MPC computation
-    pub fn execute_batch(ctx: Context<ExecuteBatch>, computation_offset: u64) -> Result<()> {
-        instructions::execute_batch::handler(ctx, computation_offset)
-    }
-
-    /// Execute vault↔reserve swaps based on BatchLog netting results.
-    /// Called by backend after MPC callback completes.
-    ///
-    /// # Arguments
-    /// * `batch_id` - The batch ID to execute swaps for
-    pub fn execute_swaps(ctx: Context<ExecuteSwaps>, batch_id: u64) -> Result<()> {
-        instructions::execute_swaps::handler(ctx, batch_id)
+    /// * `encrypted_pair_id` - Trading pair (0-5) encrypted with user's key
+    /// * `encrypted_direction` - Order direction (0=A_to_B, 1=B_to_A) encrypted
+    /// * `encrypted_notional` - USDC notional (6 decimals) encrypted
+    /// * `pubkey` - User's x25519 public key
+    /// * `nonce` - Encryption nonce
+    /// * `source_asset_id` - Plaintext hint for which asset is sold
+    /// * `valid_until_batch` - Last batch an unexecuted order rolls over into (0 = none)
+    pub fn place_order_usd(
+        ctx: Context<PlaceOrderUsd>,
+        computation_offset: u64,
+        encrypted_pair_id: [u8; 32],
+        encrypted_direction: [u8; 32],
+        encrypted_notional: [u8; 32],
+        pubkey: [u8; 32],
+        nonce: u128,
+        source_asset_id: u8,
+        valid_until_batch: u64,
+    ) -> Result<()> {
+        instructions::place_order_usd::handler(
+            ctx,
+            computation_offset,
+            encrypted_pair_id,
+            encrypted_direction,
+            encrypted_notional,
+            pubkey,
+            nonce,
+            source_asset_id,
+            valid_until_batch,
+        )
     }
 
-    /// Callback handler for reveal_batch computation.
-    /// Receives plaintext totals and performs netting + swaps.
-    #[arcium_callback(encrypted_ix = "reveal_batch")]
-    pub fn reveal_batch_callback(
-        ctx: Context<RevealBatchCallback>,
-        output: SignedComputationOutputs<RevealBatchOutput>,
+    /// Callback handler for accumulate_order_usd computation.
+    /// MPC output is a 6-tuple:
+    /// (has_funds, batch_ready, new_balance, new_batch_state, sized_order, route_ok)
+    /// Same as accumulate_order_callback, plus the pending OrderTicket is
+    /// replaced by the sized order (amount in source asset units).
+    #[arcium_callback(encrypted_ix = "accumulate_order_usd")]
+    pub fn accumulate_order_usd_callback(
+        ctx: Context<AccumulateOrderUsdCallback>,
+        output: SignedComputationOutputs<AccumulateOrderUsdOutput>,
     ) -> Result<()> {
-        // For reveal() outputs, access the array via the output struct
-        let totals: [u64; 12] = match output.verify_output(
+        let o = match output.verify_output(
             &ctx.accounts.cluster_account,
             &ctx.accounts.computation_account,
         ) {
-            Ok(RevealBatchOutput { field_0 }) => field_0,
+            Ok(output) => output,
             Err(err) => {
                 msg!(
-                    "reveal_batch_callback verify_output failed: {:?}, computation={}",
+                    "accumulate_order_usd_callback verify_output failed: {:?}, computation={}",
                     err,
                     ctx.accounts.computation_account.key()
                 );
+                ctx.accounts.user_account.pending_order = None;
+                emit_callback_failed(
+                    "accumulate_order_usd",
+                    ctx.accounts.computation_account.key(),
+                );
                 return Err(ErrorCode::AbortedComputation.into());
             }
         };
 
-        // DEBUG: Log the raw totals from MPC
-        msg!(
-            "DEBUG reveal_batch: totals = [{}, {}, {}, {}, {}, {}, {}, {}, {}, {}, {}, {}]",
-            totals[0],
-            totals[1],
-            totals[2],
-            totals[3],
-            totals[4],
-            totals[5],
-            totals[6],
-            totals[7],
-            totals[8],
-            totals[9],
-            totals[10],
-            totals[11]
-        );
-        msg!(
-            "DEBUG reveal_batch: batch_id={}, mxe_nonce={}",
-            ctx.accounts.batch_accumulator.batch_id,
-            ctx.accounts.batch_accumulator.mxe_nonce
-        );
-
-        // totals is [u64; 12] - 6 pairs × 2 values (a_in, b_in)
-        use crate::state::PairResult;
-
-        // Helper: Get asset IDs for a trading pair
-        fn get_pair_tokens(pair_id: u8) -> (u8, u8) {
-            match pair_id {
-                0 => (1, 0), // TSLA/USDC
-                1 => (2, 0), // SPY/USDC
-                2 => (3, 0), // AAPL/USDC
-                3 => (1, 2), // TSLA/SPY
-                4 => (1, 3), // TSLA/AAPL
-                5 => (2, 3), // SPY/AAPL
-                _ => (0, 0),
-            }
-        }
+        let has_funds: bool = o.field_0.field_0;
+        let batch_ready: bool = o.field_0.field_1;
 
-        // Mock prices (in USDC, 6 decimals). Real implementation would use oracle.
-        // USDC = $1.00, TSLA = $250, SPY = $450, AAPL = $180
-        let prices = [1_000_000u64, 250_000_000u64, 450_000_000u64, 180_000_000u64];
+        let Some(mut ticket) = ctx.accounts.user_account.pending_order else {
+            return Err(ErrorCode::NoPendingOrder.into());
+        };
 
-        let mut pair_results = [PairResult::default(); 6];
+        // Slot handed over while in flight: discard outputs, release the order
+        if !ctx.accounts.batch_accumulator.accepts(ticket.batch_id) {
+            emit!(OrderRejectedEvent {
+                user: ctx.accounts.user_account.owner,
+                batch_id: ticket.batch_id,
+                error: ErrorCode::InvalidBatchSlot.info(),
+                seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+            });
+            ctx.accounts.user_account.pending_order = None;
+            msg!(
+                "USD order rejected: batch {} is no longer open",
+                ticket.batch_id
+            );
+            return Ok(());
+        }
 
-        // Process each pair with netting algorithm
-        // reveal() returns [u64; 12] - the array is the output directly
-        // totals is type [u64; 12] from the MPC output
-        for pair_id in 0..6 {
-            let total_a_in = totals[pair_id * 2];
-            let total_b_in = totals[pair_id * 2 + 1];
+        // Out-of-range route: balance and batch unchanged, release the order
+        if !o.field_0.field_5 {
+            emit!(OrderRejectedEvent {
+                user: ctx.accounts.user_account.owner,
+                batch_id: ticket.batch_id,
+                error: ErrorCode::OrderRouteOutOfRange.info(),
+                seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+            });
+            ctx.accounts.user_account.pending_order = None;
+            msg!("USD order rejected: pair or direction out of range");
+            return Ok(());
+        }
 
-            // Skip inactive pairs
-            if total_a_in == 0 && total_b_in == 0 {
-                continue;
-            }
+        // Balance replaced by a deposit or withdrawal while in flight:
+        // balance and batch unchanged, release the order
+        let asset_id = ctx.accounts.user_account.pending_asset_id;
+        if ctx.accounts.user_account.get_nonce(asset_id)
+            != ctx.accounts.user_account.pending_order_nonce
+        {
+            emit!(OrderRejectedEvent {
+                user: ctx.accounts.user_account.owner,
+                batch_id: ticket.batch_id,
+                error: ErrorCode::PendingOperationStale.info(),
+                seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+            });
+            ctx.accounts.user_account.pending_order = None;
+            msg!("USD order rejected: balance changed while in flight");
+            return Ok(());
+        }
 
-            let (base_asset, quote_asset) = get_pair_tokens(pair_id as u8);
-
-            // Convert both sides to common unit (quote asset value) for comparison
-            let a_value_in_quote = (total_a_in as u128 * prices[base_asset as usize] as u128)
-                / prices[quote_asset as usize] as u128;
-            let b_value = total_b_in as u128;
-
-            let (final_pool_a, final_pool_b) = if a_value_in_quote > b_value {
-                // Net surplus on A side: users deposited more base_asset than needed
-                // Transfer surplus from vault_A → reserve_A
-                // Transfer equivalent from reserve_B → vault_B
-                let surplus_in_a = ((a_value_in_quote - b_value)
-                    * prices[quote_asset as usize] as u128)
-                    / prices[base_asset as usize] as u128;
-
-                // Calculate output (1% slippage for simulation)
-                let amount_out = (surplus_in_a * 99) / 100;
-                let surplus_capped = surplus_in_a.min(total_a_in as u128) as u64;
-
-                msg!(
-                    "Pair {}: Net surplus {} units of asset {} → swap for {} units of asset {}",
-                    pair_id,
-                    surplus_capped,
-                    base_asset,
-                    amount_out,
-                    quote_asset
-                );
-
-                // TODO: Token transfers disabled for callback account limit testing
-                // When re-enabled:
-                // - Transfer surplus from vault_base → reserve_base
-                // - Transfer output from reserve_quote → vault_quote
-
-                (
-                    total_a_in.saturating_sub(surplus_capped),
-                    total_b_in.saturating_add(amount_out as u64),
-                )
-            } else if b_value > a_value_in_quote {
-                // Net surplus on B side: users deposited more quote_asset than needed
-                let surplus_in_b = b_value - a_value_in_quote;
-                let amount_out = (surplus_in_b * 99) / 100;
-                let surplus_capped = surplus_in_b.min(total_b_in as u128) as u64;
-
-                msg!(
-                    "Pair {}: Net surplus {} units of asset {} → swap for {} units of asset {}",
-                    pair_id,
-                    surplus_capped,
-                    quote_asset,
-                    amount_out,
-                    base_asset
-                );
-
-                // TODO: Token transfers disabled for callback account limit testing
-                // When re-enabled:
-                // - Transfer surplus from vault_quote → reserve_quote
-                // - Transfer output from reserve_base → vault_base
-
-                (
-                    total_a_in.saturating_add(amount_out as u64),
-                    total_b_in.saturating_sub(surplus_capped),
-                )
-            } else {
-                // Perfect internal match - no external swap needed
-                msg!("Pair {}: Perfect internal match, no external swap", pair_id);
-                (total_a_in, total_b_in)
-            };
-
-            pair_results[pair_id] = PairResult {
-                total_a_in,
-                total_b_in,
-                final_pool_a,
-                final_pool_b,
-            };
-
-            msg!(
-                "Pair {}: total_a_in={}, total_b_in={}, final_pool_a={}, final_pool_b={}",
-                pair_id,
-                total_a_in,
-                total_b_in,
-                final_pool_a,
-                final_pool_b
-            );
+        // Short funds (or a notional below one base unit): clear and abort
+        if !has_funds {
+            msg!("USD order rejected: insufficient balance");
+            emit!(OrderRejectedEvent {
+                user: ctx.accounts.user_account.owner,
+                batch_id: ticket.batch_id,
+                error: ErrorCode::InsufficientBalance.info(),
+                seq: 0, // not sequenced: the transaction fails
+            });
+            ctx.accounts.user_account.pending_order = None;
+            return Err(ErrorCode::InsufficientBalance.into());
         }
 
-        // Update BatchLog (already initialized in execute_batch)
-        let batch_log = &mut ctx.accounts.batch_log;
-        batch_log.batch_id = ctx.accounts.batch_accumulator.batch_id;
-        batch_log.results = pair_results;
-        batch_log.executed_at = Clock::get()?.unix_timestamp;
+        // Replace the notional ticket with the sized order
+        let sized = &o.field_0.field_4;
+        ticket.pair_id = sized.ciphertexts[0];
+        ticket.direction = sized.ciphertexts[1];
+        ticket.encrypted_amount = sized.ciphertexts[2];
+        ticket.order_nonce = sized.nonce;
+        ctx.accounts.user_account.pending_order = Some(ticket);
+
+        // Update the source asset balance
+        ctx.accounts
+            .user_account
+            .set_credit(asset_id, o.field_0.field_2.ciphertexts[0]);
+        ctx.accounts
+            .user_account
+            .set_nonce(asset_id, o.field_0.field_2.nonce);
 
-        // Reset BatchAccumulator for next batch
+        // Update batch accumulator (same layout as accumulate_order)
+        let batch_accumulator_key = ctx.accounts.batch_accumulator.key();
         let batch = &mut ctx.accounts.batch_accumulator;
-        let old_batch_id = batch.batch_id;
-        batch.batch_id += 1;
-        // Reset plaintext order_count for next batch
-        batch.order_count = 0;
+        for pair_id in 0..6 {
+            batch.pair_states[pair_id].encrypted_token_a_in =
+                o.field_0.field_3.ciphertexts[pair_id * 2];
+            batch.pair_states[pair_id].encrypted_token_b_in =
+                o.field_0.field_3.ciphertexts[pair_id * 2 + 1];
+        }
+        batch.order_count += 1;
+        batch.mxe_nonce = o.field_0.field_3.nonce;
 
-        msg!("Batch {} executed", old_batch_id);
+        if batch_ready {
+            emit!(BatchReadyEvent {
+                batch_id: batch.batch_id,
+                batch_accumulator: batch_accumulator_key,
+                seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+            });
+        }
 
-        // Emit event for backend to trigger execute_swaps
-        emit!(BatchExecutedEvent {
-            batch_id: old_batch_id,
-            batch_log: ctx.accounts.batch_log.key(),
+        emit!(OrderPlacedEvent {
+            user: ctx.accounts.user_account.owner,
+            batch_id: batch.batch_id,
+            seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
         });
 
+        msg!(
+            "USD order callback: user={}, batch={}, batch_ready={}",
+            ctx.accounts.user_account.owner,
+            batch.batch_id,
+            batch_ready
+        );
+
         Ok(())
     }
 
     // =========================================================================
-    // SETTLE ORDER (Phase 10)
+    // BASKET ORDERS
     // =========================================================================
 
-    /// Settle a pending order.
-    /// Calculates pro-rata payout based on batch results and user's order size.
+    /// Place one encrypted order split across up to 3 pairs by encrypted
+    /// weights (e.g. 50% TSLA, 30% SPY, 20% AAPL from USDC).
+    /// May be signed by the owner or an active session key (Delegate).
     ///
     /// # Arguments
     /// * `computation_offset` - Unique ID for MPC computation
+    /// * `encrypted_pair_ids` - Pair ID (0-5) of each leg, encrypted
+    /// * `encrypted_directions` - Direction of each leg, encrypted
+    /// * `encrypted_weights_bps` - Weight of each leg in bps (sum 10_000), encrypted
+    /// * `encrypted_amount` - Total amount sold, encrypted
     /// * `pubkey` - User's x25519 public key
     /// * `nonce` - Encryption nonce
-    /// * `pair_id` - Trading pair (0-5)
-    /// * `direction` - Order direction (0=A_to_B, 1=B_to_A)
-    pub fn settle_order(
-        ctx: Context<SettleOrder>,
+    /// * `source_asset_id` - Plaintext hint for which asset is sold
+    pub fn place_basket_order(
+        ctx: Context<PlaceBasketOrder>,
         computation_offset: u64,
+        encrypted_pair_ids: [[u8; 32]; 3],
+        encrypted_directions: [[u8; 32]; 3],
+        encrypted_weights_bps: [[u8; 32]; 3],
+        encrypted_amount: [u8; 32],
         pubkey: [u8; 32],
         nonce: u128,
-        pair_id: u8,
-        direction: u8,
+        source_asset_id: u8,
     ) -> Result<()> {
-        instructions::settle_order::handler(
+        instructions::place_basket_order::handler(
             ctx,
             computation_offset,
+            encrypted_pair_ids,
+            encrypted_directions,
+            encrypted_weights_bps,
+            encrypted_amount,
             pubkey,
             nonce,
-            pair_id,
-            direction,
+            source_asset_id,
         )
     }
 
-    /// Callback handler for calculate_payout computation.
-    /// Updates user balance with payout and clears pending_order.
-    #[arcium_callback(encrypted_ix = "calculate_payout")]
-    pub fn calculate_payout_callback(
-        ctx: Context<CalculatePayoutCallback>,
-        output: SignedComputationOutputs<CalculatePayoutOutput>,
+    /// Callback handler for accumulate_basket computation.
+    /// MPC output is a 7-tuple:
+    /// (ok, batch_ready, new_balance, new_batch_state, leg_0, leg_1, leg_2)
+    /// A rejected basket (short funds, bad weights or route) frees the
+    /// BasketOrder without touching the balance or the batch.
+    #[arcium_callback(encrypted_ix = "accumulate_basket")]
+    pub fn accumulate_basket_callback(
+        ctx: Context<AccumulateBasketCallback>,
+        output: SignedComputationOutputs<AccumulateBasketOutput>,
     ) -> Result<()> {
         let o = match output.verify_output(
             &ctx.accounts.cluster_account,
@@ -623,235 +1264,409 @@ pub mod shuffle_protocol {
             Ok(output) => output,
             Err(err) => {
                 msg!(
-                    "calculate_payout_callback verify_output failed: {:?}, computation={}",
+                    "accumulate_basket_callback verify_output failed: {:?}, computation={}",
                     err,
                     ctx.accounts.computation_account.key()
                 );
+                emit_callback_failed("accumulate_basket", ctx.accounts.computation_account.key());
                 return Err(ErrorCode::AbortedComputation.into());
             }
         };
 
-        // For tuple output (Enc<Shared, UserBalance>, u64):
-        // o.field_0 = wrapper for first tuple element
-        // o.field_0.field_0 = the actual Enc<Shared, UserBalance> with .ciphertexts and .nonce
-        // o.field_1 = the revealed u64 payout (if accessible)
+        let ok: bool = o.field_0.field_0;
+        let batch_ready: bool = o.field_0.field_1;
+        let basket = &mut ctx.accounts.basket_order;
+
+        // Rejected (or the slot was handed over, or a deposit or withdrawal
+        // replaced the source balance, while in flight): release the basket,
+        // balance and batch are unchanged
+        let slot_open = ctx.accounts.batch_accumulator.accepts(basket.batch_id);
+        let balance_current = ctx.accounts.user_account.get_nonce(basket.source_asset_id)
+            == ctx.accounts.user_account.pending_basket_nonce;
+        if !ok || !slot_open || !balance_current {
+            basket.status = state::BASKET_STATUS_EMPTY;
+            let error = if !slot_open {
+                ErrorCode::InvalidBatchSlot
+            } else if !balance_current {
+                ErrorCode::PendingOperationStale
+            } else {
+                ErrorCode::BasketRejected
+            };
+            emit!(OrderRejectedEvent {
+                user: basket.owner,
+                batch_id: basket.batch_id,
+                error: error.info(),
+                seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+            });
+            msg!("Basket order rejected: user={}", basket.owner);
+            return Ok(());
+        }
 
-        // DEBUG: Try to log the revealed payout value
-        // Note: If this doesn't compile, comment it out
-        msg!(
-            "DEBUG calculate_payout: revealed payout = {}",
-            o.field_0.field_1
-        );
+        // Store the legs; each settles later with calculate_payout
+        let batch_id = basket.batch_id;
+        let legs = [&o.field_0.field_4, &o.field_0.field_5, &o.field_0.field_6];
+        for (ticket, leg) in basket.legs.iter_mut().zip(legs) {
+            *ticket = OrderTicket {
+                batch_id,
+                pair_id: leg.ciphertexts[0],
+                direction: leg.ciphertexts[1],
+                encrypted_amount: leg.ciphertexts[2],
+                order_nonce: leg.nonce,
+                valid_until_batch: 0,
+            };
+        }
+        basket.status = state::BASKET_STATUS_PLACED;
 
-        // Update output asset balance using o.field_0.field_0 (the encrypted UserBalance)
-        let output_asset_id = ctx.accounts.user_account.pending_asset_id;
+        // Update the source asset balance
+        let asset_id = basket.source_asset_id;
         ctx.accounts
             .user_account
-            .set_credit(output_asset_id, o.field_0.field_0.ciphertexts[0]);
+            .set_credit(asset_id, o.field_0.field_2.ciphertexts[0]);
         ctx.accounts
             .user_account
-            .set_nonce(output_asset_id, o.field_0.field_0.nonce);
+            .set_nonce(asset_id, o.field_0.field_2.nonce);
+
+        // Update batch accumulator (same layout as accumulate_order)
+        let batch_accumulator_key = ctx.accounts.batch_accumulator.key();
+        let batch = &mut ctx.accounts.batch_accumulator;
+        for pair_id in 0..6 {
+            batch.pair_states[pair_id].encrypted_token_a_in =
+                o.field_0.field_3.ciphertexts[pair_id * 2];
+            batch.pair_states[pair_id].encrypted_token_b_in =
+                o.field_0.field_3.ciphertexts[pair_id * 2 + 1];
+        }
+        batch.order_count += 1;
+        batch.basket_count += 1;
+        batch.mxe_nonce = o.field_0.field_3.nonce;
 
-        // Clear pending_order
-        let batch_id = ctx.accounts.user_account.pending_order.unwrap().batch_id;
-        ctx.accounts.user_account.pending_order = None;
+        if batch_ready {
+            emit!(BatchReadyEvent {
+                batch_id: batch.batch_id,
+                batch_accumulator: batch_accumulator_key,
+                seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+            });
+        }
 
-        emit!(SettlementEvent {
+        emit!(BasketOrderPlacedEvent {
             user: ctx.accounts.user_account.owner,
-            batch_id,
-            encrypted_payout: o.field_0.field_0.ciphertexts[0],
-            nonce: o.field_0.field_0.nonce.to_le_bytes(),
-            revealed_payout: o.field_0.field_1,
+            batch_id: batch.batch_id,
+            seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
         });
 
         msg!(
-            "Settlement callback: user={}, batch={}, payout={}",
+            "Basket callback: user={}, batch={}, batch_ready={}",
             ctx.accounts.user_account.owner,
-            batch_id,
-            o.field_0.field_1
+            batch.batch_id,
+            batch_ready
         );
 
         Ok(())
     }
 
+    /// Settle one leg of a basket order after batch execution.
+    /// Uses the calculate_payout circuit; the basket is released once all
+    /// legs have settled.
+    ///
+    /// # Arguments
+    /// * `computation_offset` - Unique ID for MPC computation
+    /// * `pubkey` - User's x25519 public key
+    /// * `nonce` - Encryption nonce
+    /// * `leg` - Basket leg (0-2)
+    /// * `pair_id` - Trading pair of the leg (0-5)
+    /// * `direction` - Direction of the leg (0=A_to_B, 1=B_to_A)
+    pub fn settle_basket_leg(
+        ctx: Context<SettleBasketLeg>,
+        computation_offset: u64,
+        pubkey: [u8; 32],
+        nonce: u128,
+        leg: u8,
+        pair_id: u8,
+        direction: u8,
+    ) -> Result<()> {
+        instructions::settle_basket_leg::handler(
+            ctx,
+            computation_offset,
+            pubkey,
+            nonce,
+            leg,
+            pair_id,
+            direction,
+        )
+    }
+
     // =========================================================================
-    // LIQUIDITY MANAGEMENT (Protocol Reserves)
+    // DCA SCHEDULES
     // =========================================================================
 
-    /// Add liquidity to protocol reserves.
-    /// Only callable by pool authority.
+    /// Create a recurring DCA schedule.
+    /// The per-interval order is encrypted once and re-submitted by execute_dca.
     ///
     /// # Arguments
-    /// * `asset_id` - Asset to add (0=USDC, 1=TSLA, 2=SPY, 3=AAPL)
-    /// * `amount` - Amount to transfer to reserves
-    pub fn add_liquidity(ctx: Context<AddLiquidity>, asset_id: u8, amount: u64) -> Result<()> {
-        instructions::add_liquidity::handler(ctx, asset_id, amount)
+    /// * `dca_id` - Schedule identifier (lets a user run several schedules)
+    /// * `encrypted_pair_id` - Trading pair (0-5) encrypted with user's key
+    /// * `encrypted_direction` - Order direction (0=A_to_B, 1=B_to_A) encrypted
+    /// * `encrypted_amount` - Amount per interval encrypted
+    /// * `pubkey` - User's x25519 public key
+    /// * `nonce` - Encryption nonce
+    /// * `source_asset_id` - Plaintext hint for which asset is sold
+    /// * `interval_secs` - Seconds between executions
+    /// * `start_ts` - First execution time (0 = now)
+    /// * `max_executions` - Stop after this many executions (0 = unlimited)
+    /// * `funding_bucket` - Savings bucket the orders draw from (USDC only,
+    ///   None = trading balance)
+    pub fn create_dca(
+        ctx: Context<CreateDca>,
+        dca_id: u64,
+        encrypted_pair_id: [u8; 32],
+        encrypted_direction: [u8; 32],
+        encrypted_amount: [u8; 32],
+        pubkey: [u8; 32],
+        nonce: u128,
+        source_asset_id: u8,
+        interval_secs: i64,
+        start_ts: i64,
+        max_executions: u32,
+        funding_bucket: Option<u8>,
+    ) -> Result<()> {
+        instructions::create_dca::handler(
+            ctx,
+            dca_id,
+            encrypted_pair_id,
+            encrypted_direction,
+            encrypted_amount,
+            pubkey,
+            nonce,
+            source_asset_id,
+            interval_secs,
+            start_ts,
+            max_executions,
+            funding_bucket,
+        )
     }
 
-    /// Remove liquidity from protocol reserves.
-    /// Only callable by pool authority.
+    /// Cancel a DCA schedule and reclaim its rent.
+    pub fn cancel_dca(ctx: Context<CancelDca>) -> Result<()> {
+        instructions::cancel_dca::handler(ctx)
+    }
+
+    /// Pause a DCA schedule without losing its PDA or encrypted order.
+    pub fn pause_dca(ctx: Context<PauseDca>) -> Result<()> {
+        instructions::pause_dca::handler(ctx)
+    }
+
+    /// Resume a paused DCA schedule. Intervals missed while paused are
+    /// skipped and counted in skipped_count.
+    pub fn resume_dca(ctx: Context<ResumeDca>) -> Result<()> {
+        instructions::resume_dca::handler(ctx)
+    }
+
+    /// Crank a due DCA schedule: places its encrypted order into the
+    /// current batch on behalf of the owner. Permissionless.
+    /// Result is delivered to accumulate_order_callback like a manual order.
     ///
     /// # Arguments
-    /// * `asset_id` - Asset to remove (0=USDC, 1=TSLA, 2=SPY, 3=AAPL)
-    /// * `amount` - Amount to transfer from reserves
-    pub fn remove_liquidity(
-        ctx: Context<RemoveLiquidity>,
-        asset_id: u8,
-        amount: u64,
-    ) -> Result<()> {
-        instructions::remove_liquidity::handler(ctx, asset_id, amount)
+    /// * `computation_offset` - Unique ID for MPC computation
+    pub fn execute_dca(ctx: Context<ExecuteDca>, computation_offset: u64) -> Result<()> {
+        instructions::execute_dca::handler(ctx, computation_offset)
     }
 
     // =========================================================================
-    // FAUCET (Devnet only)
+    // SAVINGS BUCKETS
     // =========================================================================
 
-    /// Claim USDC from the devnet faucet.
-    /// Each user can claim up to 1000 USDC total.
+    /// Create a labeled savings bucket for the caller's USDC.
     ///
     /// # Arguments
-    /// * `amount` - Amount of USDC to claim (in base units, 6 decimals)
-    pub fn faucet(ctx: Context<Faucet>, amount: u64) -> Result<()> {
-        instructions::faucet::handler(ctx, amount)
+    /// * `bucket_id` - Bucket identifier (lets a user keep several buckets)
+    /// * `label` - Display label, UTF-8 padded with zeros
+    /// * `initial_balance` - Encrypted zero balance (profile's user_pubkey)
+    /// * `initial_nonce` - Nonce used to encrypt the initial balance
+    pub fn create_savings_bucket(
+        ctx: Context<CreateSavingsBucket>,
+        bucket_id: u8,
+        label: [u8; 16],
+        initial_balance: [u8; 32],
+        initial_nonce: u128,
+    ) -> Result<()> {
+        instructions::create_savings_bucket::handler(
+            ctx,
+            bucket_id,
+            label,
+            initial_balance,
+            initial_nonce,
+        )
     }
 
-    // =========================================================================
-    // ARCIUM MPC SETUP (Demo - from scaffolding)
-    // =========================================================================
+    /// Move USDC between the caller's trading balance and a savings bucket
+    /// via the move_savings circuit. The amount stays encrypted.
+    ///
+    /// # Arguments
+    /// * `computation_offset` - Unique ID for MPC computation
+    /// * `encrypted_amount` - Amount encrypted with the owner's key
+    /// * `pubkey` - Owner's x25519 public key
+    /// * `nonce` - Encryption nonce
+    /// * `to_bucket` - true moves into the bucket, false back to trading
+    pub fn move_savings(
+        ctx: Context<MoveSavings>,
+        computation_offset: u64,
+        encrypted_amount: [u8; 32],
+        pubkey: [u8; 32],
+        nonce: u128,
+        to_bucket: bool,
+    ) -> Result<()> {
+        instructions::move_savings::handler(
+            ctx,
+            computation_offset,
+            encrypted_amount,
+            pubkey,
+            nonce,
+            to_bucket,
+        )
+    }
 
-    pub fn init_add_together_comp_def(ctx: Context<InitAddTogetherCompDef>) -> Result<()> {
-        init_comp_def(
-            ctx.accounts,
-            Some(CircuitSource::OffChain(OffChainCircuitSource {
-                source: "https://gateway.pinata.cloud/ipfs/QmQ4Jd2KEQZXPzE5xgXGQTz8BjtF4BHemSsjXWaE3QTuGT".to_string(),
-                hash: circuit_hash!("add_together"),
-            })),
-            None,
-        )?;
+    /// Callback handler for move_savings computation.
+    /// MPC output is (has_funds, new_main, new_bucket); aborts if the
+    /// source side was short, otherwise stores both balances.
+    #[arcium_callback(encrypted_ix = "move_savings")]
+    pub fn move_savings_callback(
+        ctx: Context<MoveSavingsCallback>,
+        output: SignedComputationOutputs<MoveSavingsOutput>,
+    ) -> Result<()> {
+        let o = match output.verify_output(
+            &ctx.accounts.cluster_account,
+            &ctx.accounts.computation_account,
+        ) {
+            Ok(output) => output,
+            Err(err) => {
+                msg!(
+                    "move_savings_callback verify_output failed: {:?}, computation={}",
+                    err,
+                    ctx.accounts.computation_account.key()
+                );
+                emit_callback_failed("move_savings", ctx.accounts.computation_account.key());
+                return Err(ErrorCode::AbortedComputation.into());
+            }
+        };
+
+        let has_funds: bool = o.field_0.field_0;
+        let new_main = &o.field_0.field_1;
+        let new_bucket = &o.field_0.field_2;
+        require!(has_funds, ErrorCode::InsufficientBalance);
+
+        ctx.accounts
+            .user_account
+            .set_credit(UserProfile::ASSET_USDC, new_main.ciphertexts[0]);
+        ctx.accounts
+            .user_account
+            .set_nonce(UserProfile::ASSET_USDC, new_main.nonce);
+        let bucket = &mut ctx.accounts.savings_bucket;
+        bucket.encrypted_balance = new_bucket.ciphertexts[0];
+        bucket.nonce = new_bucket.nonce;
+
+        emit!(SavingsMovedEvent {
+            owner: bucket.owner,
+            bucket_id: bucket.bucket_id,
+            bucket_nonce: bucket.nonce.to_le_bytes(),
+            seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+        });
+
+        msg!(
+            "Savings bucket {} of {} updated",
+            bucket.bucket_id,
+            bucket.owner
+        );
         Ok(())
     }
 
     // =========================================================================
-    // ARCIUM MPC SETUP - Add Balance (Phase 6)
+    // SESSION-KEY DELEGATION
     // =========================================================================
 
-    /// Initialize the add_balance computation definition.
-    /// This must be called once before any encrypted deposits can be processed.
-    pub fn init_add_balance_comp_def(ctx: Context<InitAddBalanceCompDef>) -> Result<()> {
-        init_comp_def(
-            ctx.accounts,
-            Some(CircuitSource::OffChain(OffChainCircuitSource {
-                source: "https://gateway.pinata.cloud/ipfs/QmdbkwigmEYcXPaDGdFJYhVKGC2c1WDfznBBxt8Rc1vZmM".to_string(),
-                hash: circuit_hash!("add_balance"),
-            })),
-            None,
-        )?;
-        Ok(())
+    /// Authorize a session key to call place_order and settle_order on the
+    /// caller's behalf, until `expires_at` and up to `max_orders_per_batch`
+    /// orders per batch. Calling again for the same key refreshes it.
+    ///
+    /// # Arguments
+    /// * `delegate` - Session key public key
+    /// * `expires_at` - Expiry (<= now + MAX_DELEGATE_DURATION_SECS)
+    /// * `max_orders_per_batch` - Per-batch order limit
+    pub fn create_delegate(
+        ctx: Context<CreateDelegate>,
+        delegate: Pubkey,
+        expires_at: i64,
+        max_orders_per_batch: u8,
+    ) -> Result<()> {
+        instructions::create_delegate::handler(ctx, delegate, expires_at, max_orders_per_batch)
     }
 
-    /// Initialize the accumulate_order computation definition (Phase 8).
-    /// This must be called once before orders can be placed.
-    pub fn init_accumulate_order_comp_def(ctx: Context<InitAccumulateOrderCompDef>) -> Result<()> {
-        init_comp_def(
-            ctx.accounts,
-            Some(CircuitSource::OffChain(OffChainCircuitSource {
-                source: "https://gateway.pinata.cloud/ipfs/QmbgiSK9qUxVB9SWK21wQxNyMF9bhMzCM9CJLbVsGRAhWx".to_string(),
-                hash: circuit_hash!("accumulate_order"),
-            })),
-            None,
-        )?;
-        Ok(())
-    }
-
-    /// Initialize the init_batch_state computation definition (Phase 8).
-    /// This must be called once for batch initialization.
-    pub fn init_init_batch_state_comp_def(ctx: Context<InitInitBatchStateCompDef>) -> Result<()> {
-        init_comp_def(
-            ctx.accounts,
-            Some(CircuitSource::OffChain(OffChainCircuitSource {
-                source: "https://gateway.pinata.cloud/ipfs/QmbBzp7G3o2KqGPFdzjB5Y7ioujpvR5TT54bpLsoo7QZv7".to_string(),
-                hash: circuit_hash!("init_batch_state"),
-            })),
-            None,
-        )?;
-        Ok(())
-    }
-
-    /// Initialize the reveal_batch computation definition (Phase 9).
-    /// This must be called once before batch execution.
-    pub fn init_reveal_batch_comp_def(ctx: Context<InitRevealBatchCompDef>) -> Result<()> {
-        init_comp_def(
-            ctx.accounts,
-            Some(CircuitSource::OffChain(OffChainCircuitSource {
-                source: "https://gateway.pinata.cloud/ipfs/Qmc311AdUo1eE7Pm8F8ctDEfX5FJ2SQ4ATDvJi4YXMjmQ8".to_string(),
-                hash: circuit_hash!("reveal_batch"),
-            })),
-            None,
-        )?;
-        Ok(())
+    /// Revoke a session key and reclaim rent.
+    pub fn revoke_delegate(ctx: Context<RevokeDelegate>) -> Result<()> {
+        instructions::revoke_delegate::handler(ctx)
     }
 
-    /// Initialize the calculate_payout computation definition (Phase 10).
-    /// This must be called once before settlements can be processed.
-    pub fn init_calculate_payout_comp_def(ctx: Context<InitCalculatePayoutCompDef>) -> Result<()> {
-        init_comp_def(
-            ctx.accounts,
-            Some(CircuitSource::OffChain(OffChainCircuitSource {
-                source: "https://gateway.pinata.cloud/ipfs/QmT8bDc6mba5H3bpAJrtDFBYnSTKLKoMFxhm6TmnMNHSnA".to_string(),
-                hash: circuit_hash!("calculate_payout"),
-            })),
-            None,
-        )?;
-        Ok(())
+    /// Cap what a delegate may sell of an asset with an encrypted allowance.
+    /// The delegate must then sell the asset through place_delegated_order.
+    ///
+    /// # Arguments
+    /// * `asset_id` - Asset the allowance applies to
+    /// * `encrypted_allowance` - Allowance encrypted with the owner's key
+    /// * `nonce` - Encryption nonce
+    pub fn set_delegate_allowance(
+        ctx: Context<SetDelegateAllowance>,
+        asset_id: u8,
+        encrypted_allowance: [u8; 32],
+        nonce: u128,
+    ) -> Result<()> {
+        instructions::set_delegate_allowance::handler(ctx, asset_id, encrypted_allowance, nonce)
     }
 
-    // =========================================================================
-    // INIT_BATCH_STATE - Initialize batch accumulator with encrypted zeros
-    // =========================================================================
-    // This MUST be called after initBatchAccumulator and before any orders.
-    // The MPC generates properly encrypted zeros that can be decrypted later.
-
-    /// Queue MPC to generate encrypted zeros for the batch accumulator.
-    /// This must be called once after batch accumulator creation and after each batch reset.
-    pub fn init_batch_state(ctx: Context<InitBatchState>, computation_offset: u64) -> Result<()> {
-        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
-
-        // init_batch_state takes `mxe: Mxe` argument
-        // The Mxe type compiles to a struct with a u128 nonce field
-        let args = ArgBuilder::new()
-            .plaintext_u128(0) // Mxe nonce placeholder
-            .build();
-
-        use arcium_client::idl::arcium::types::CallbackAccount;
-        queue_computation(
-            ctx.accounts,
+    /// Place an order as a delegate against its encrypted allowance.
+    /// MPC checks the order against the owner's balance and allowance and
+    /// decrements both.
+    ///
+    /// # Arguments
+    /// * `computation_offset` - Unique ID for MPC computation
+    /// * `encrypted_pair_id` - Trading pair (0-5) encrypted with the delegate's key
+    /// * `encrypted_direction` - Order direction (0=A_to_B, 1=B_to_A) encrypted
+    /// * `encrypted_amount` - Order amount encrypted
+    /// * `pubkey` - Delegate's x25519 public key
+    /// * `nonce` - Encryption nonce
+    /// * `source_asset_id` - Plaintext hint for which asset is sold
+    /// * `valid_until_batch` - Last batch an unexecuted order rolls over into (0 = none)
+    pub fn place_delegated_order(
+        ctx: Context<PlaceDelegatedOrder>,
+        computation_offset: u64,
+        encrypted_pair_id: [u8; 32],
+        encrypted_direction: [u8; 32],
+        encrypted_amount: [u8; 32],
+        pubkey: [u8; 32],
+        nonce: u128,
+        source_asset_id: u8,
+        valid_until_batch: u64,
+    ) -> Result<()> {
+        instructions::place_delegated_order::handler(
+            ctx,
             computation_offset,
-            args,
-            vec![InitBatchStateCallback::callback_ix(
-                computation_offset,
-                &ctx.accounts.mxe_account,
-                &[CallbackAccount {
-                    pubkey: ctx.accounts.batch_accumulator.key(),
-                    is_writable: true,
-                }],
-            )?],
-            1,
-            0,
-        )?;
-
-        msg!("init_batch_state queued for MPC");
-        Ok(())
+            encrypted_pair_id,
+            encrypted_direction,
+            encrypted_amount,
+            pubkey,
+            nonce,
+            source_asset_id,
+            valid_until_batch,
+        )
     }
 
-    /// Callback: Receive encrypted zeros from MPC and store in batch accumulator.
-    /// BatchState has 19 encrypted u64 values:
-    /// - pairs[6]: 12 u64 values (pair[i].total_a_in, pair[i].total_b_in) - indices 0-11
-    /// - order_count: 1 u64 value - index 12
-    /// - active_pairs[6]: 6 bool values (as u64s in MPC) - indices 13-18
-    #[arcium_callback(encrypted_ix = "init_batch_state")]
-    pub fn init_batch_state_callback(
-        ctx: Context<InitBatchStateCallback>,
-        output: SignedComputationOutputs<InitBatchStateOutput>,
+    /// Callback handler for accumulate_order_delegated computation.
+    /// MPC output is an 8-tuple:
+    /// (has_funds, batch_ready, new_balance, new_batch_state, owner_order,
+    /// route_ok, within_allowance, new_allowance)
+    /// Same as accumulate_order_usd_callback, plus the delegate's allowance
+    /// is replaced by the decremented one.
+    #[arcium_callback(encrypted_ix = "accumulate_order_delegated")]
+    pub fn accumulate_order_delegated_callback(
+        ctx: Context<AccumulateOrderDelegatedCallback>,
+        output: SignedComputationOutputs<AccumulateOrderDelegatedOutput>,
     ) -> Result<()> {
         let o = match output.verify_output(
             &ctx.accounts.cluster_account,
@@ -860,338 +1675,427 @@ pub mod shuffle_protocol {
             Ok(output) => output,
             Err(err) => {
                 msg!(
-                    "init_batch_state_callback verify_output failed: {:?}, computation={}",
+                    "accumulate_order_delegated_callback verify_output failed: {:?}, computation={}",
                     err,
                     ctx.accounts.computation_account.key()
                 );
+                ctx.accounts.user_account.pending_order = None;
+                emit_callback_failed(
+                    "accumulate_order_delegated",
+                    ctx.accounts.computation_account.key(),
+                );
                 return Err(ErrorCode::AbortedComputation.into());
             }
         };
 
-        // MPC output is MXEEncryptedStruct with 12 ciphertexts (6 pairs × 2 values)
-        let batch = &mut ctx.accounts.batch_accumulator;
+        let has_funds: bool = o.field_0.field_0;
+        let batch_ready: bool = o.field_0.field_1;
 
-        // Store pair totals (12 ciphertexts)
+        let Some(mut ticket) = ctx.accounts.user_account.pending_order else {
+            return Err(ErrorCode::NoPendingOrder.into());
+        };
+
+        // Slot handed over while in flight: discard outputs, release the order
+        if !ctx.accounts.batch_accumulator.accepts(ticket.batch_id) {
+            emit!(OrderRejectedEvent {
+                user: ctx.accounts.user_account.owner,
+                batch_id: ticket.batch_id,
+                error: ErrorCode::InvalidBatchSlot.info(),
+                seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+            });
+            ctx.accounts.user_account.pending_order = None;
+            msg!(
+                "Delegated order rejected: batch {} is no longer open",
+                ticket.batch_id
+            );
+            return Ok(());
+        }
+
+        // Out-of-range route, over the allowance, or computed against a
+        // balance a deposit or withdrawal has since replaced: nothing is
+        // deducted or accumulated, release the order
+        let asset_id = ctx.accounts.user_account.pending_asset_id;
+        let rejection = if !o.field_0.field_5 {
+            Some(ErrorCode::OrderRouteOutOfRange)
+        } else if !o.field_0.field_6 {
+            Some(ErrorCode::DelegateAllowanceExceeded)
+        } else if ctx.accounts.user_account.get_nonce(asset_id)
+            != ctx.accounts.user_account.pending_order_nonce
+        {
+            Some(ErrorCode::PendingOperationStale)
+        } else {
+            None
+        };
+        if let Some(error) = rejection {
+            emit!(OrderRejectedEvent {
+                user: ctx.accounts.user_account.owner,
+                batch_id: ticket.batch_id,
+                error: error.info(),
+                seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+            });
+            ctx.accounts.user_account.pending_order = None;
+            msg!("Delegated order rejected: {:?}", error);
+            return Ok(());
+        }
+
+        if !has_funds {
+            msg!("Delegated order rejected: insufficient balance");
+            emit!(OrderRejectedEvent {
+                user: ctx.accounts.user_account.owner,
+                batch_id: ticket.batch_id,
+                error: ErrorCode::InsufficientBalance.info(),
+                seq: 0, // not sequenced: the transaction fails
+            });
+            ctx.accounts.user_account.pending_order = None;
+            return Err(ErrorCode::InsufficientBalance.into());
+        }
+
+        // Replace the delegate's ticket with the owner's copy of the order
+        let owner_order = &o.field_0.field_4;
+        ticket.pair_id = owner_order.ciphertexts[0];
+        ticket.direction = owner_order.ciphertexts[1];
+        ticket.encrypted_amount = owner_order.ciphertexts[2];
+        ticket.order_nonce = owner_order.nonce;
+        ctx.accounts.user_account.pending_order = Some(ticket);
+
+        // Update the source asset balance and the remaining allowance
+        ctx.accounts
+            .user_account
+            .set_credit(asset_id, o.field_0.field_2.ciphertexts[0]);
+        ctx.accounts
+            .user_account
+            .set_nonce(asset_id, o.field_0.field_2.nonce);
+        let delegate = &mut ctx.accounts.delegate;
+        delegate.allowances[asset_id as usize] = o.field_0.field_7.ciphertexts[0];
+        delegate.allowance_nonces[asset_id as usize] = o.field_0.field_7.nonce;
+
+        // Update batch accumulator (same layout as accumulate_order)
+        let batch_accumulator_key = ctx.accounts.batch_accumulator.key();
+        let batch = &mut ctx.accounts.batch_accumulator;
         for pair_id in 0..6 {
-            batch.pair_states[pair_id].encrypted_token_a_in = o.field_0.ciphertexts[pair_id * 2];
+            batch.pair_states[pair_id].encrypted_token_a_in =
+                o.field_0.field_3.ciphertexts[pair_id * 2];
             batch.pair_states[pair_id].encrypted_token_b_in =
-                o.field_0.ciphertexts[pair_id * 2 + 1];
+                o.field_0.field_3.ciphertexts[pair_id * 2 + 1];
         }
+        batch.order_count += 1;
+        batch.mxe_nonce = o.field_0.field_3.nonce;
 
-        // Store MXE output nonce for subsequent reads
-        batch.mxe_nonce = o.field_0.nonce;
+        if batch_ready {
+            emit!(BatchReadyEvent {
+                batch_id: batch.batch_id,
+                batch_accumulator: batch_accumulator_key,
+                seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+            });
+        }
+
+        emit!(OrderPlacedEvent {
+            user: ctx.accounts.user_account.owner,
+            batch_id: batch.batch_id,
+            seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+        });
 
         msg!(
-            "DEBUG init_batch_state: initial_mxe_nonce={}",
-            batch.mxe_nonce
+            "Delegated order callback: user={}, batch={}, batch_ready={}",
+            ctx.accounts.user_account.owner,
+            batch.batch_id,
+            batch_ready
         );
 
         Ok(())
     }
 
-    pub fn add_together(
-        ctx: Context<AddTogether>,
+    /// Withdraw tokens from the owner's privacy account as a delegate,
+    /// drawing on its allowance for the asset. The token transfer happens
+    /// in the callback once MPC confirms balance and allowance.
+    ///
+    /// # Arguments
+    /// * `computation_offset` - Unique ID for MPC computation
+    /// * `amount` - Amount of tokens to withdraw
+    /// * `asset_id` - Asset to withdraw (0=USDC, 1=TSLA, 2=SPY, 3=AAPL)
+    pub fn delegated_withdraw<'info>(
+        ctx: Context<'_, '_, '_, 'info, DelegatedWithdraw<'info>>,
         computation_offset: u64,
-        ciphertext_0: [u8; 32],
-        ciphertext_1: [u8; 32],
-        pubkey: [u8; 32],
-        nonce: u128,
+        amount: u64,
+        asset_id: u8,
     ) -> Result<()> {
-        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
-        let args = ArgBuilder::new()
-            .x25519_pubkey(pubkey)
-            .plaintext_u128(nonce)
-            .encrypted_u8(ciphertext_0)
-            .encrypted_u8(ciphertext_1)
-            .build();
-
-        queue_computation(
-            ctx.accounts,
-            computation_offset,
-            args,
-            vec![AddTogetherCallback::callback_ix(
-                computation_offset,
-                &ctx.accounts.mxe_account,
-                &[],
-            )?],
-            1,
-            0,
-        )?;
-        Ok(())
+        instructions::delegated_withdraw::handler(ctx, computation_offset, amount, asset_id)
     }
 
-    #[arcium_callback(encrypted_ix = "add_together")]
-    pub fn add_together_callback(
-        ctx: Context<AddTogetherCallback>,
-        output: SignedComputationOutputs<AddTogetherOutput>,
+    /// Callback handler for sub_balance_delegated computation.
+    /// MPC output is (has_funds, within_allowance, new_balance, new_allowance).
+    /// Aborts unless both flags are set; otherwise transfers the tokens and
+    /// stores the decremented balance and allowance.
+    #[arcium_callback(encrypted_ix = "sub_balance_delegated")]
+    pub fn sub_balance_delegated_callback<'info>(
+        ctx: Context<'_, '_, '_, 'info, SubBalanceDelegatedCallback<'info>>,
+        output: SignedComputationOutputs<SubBalanceDelegatedOutput>,
     ) -> Result<()> {
         let o = match output.verify_output(
             &ctx.accounts.cluster_account,
             &ctx.accounts.computation_account,
         ) {
-            Ok(AddTogetherOutput { field_0 }) => field_0,
+            Ok(output) => output,
             Err(err) => {
                 msg!(
-                    "add_together_callback verify_output failed: {:?}, computation={}",
+                    "sub_balance_delegated_callback verify_output failed: {:?}, computation={}",
                     err,
                     ctx.accounts.computation_account.key()
                 );
+                emit_callback_failed(
+                    "sub_balance_delegated",
+                    ctx.accounts.computation_account.key(),
+                );
                 return Err(ErrorCode::AbortedComputation.into());
             }
         };
 
-        emit!(SumEvent {
-            sum: o.ciphertexts[0],
-            nonce: o.nonce.to_le_bytes(),
+        let has_funds: bool = o.field_0.field_0;
+        let within_allowance: bool = o.field_0.field_1;
+        let new_balance = &o.field_0.field_2;
+        let new_allowance = &o.field_0.field_3;
+
+        let pending = &ctx.accounts.pending_operation;
+        require!(
+            pending.kind == PENDING_OP_WITHDRAWAL
+                && pending.computation == ctx.accounts.computation_account.key(),
+            ErrorCode::InvalidPendingOperation
+        );
+        let asset_id = pending.asset_id;
+        let amount = pending.amount;
+
+        // Short balance or allowance, or a result computed against a balance
+        // or allowance that has since been replaced: abort the transaction
+        // (the owner cancels the PendingOperation)
+        let rejection = if !has_funds {
+            Some(ErrorCode::InsufficientBalance)
+        } else if !within_allowance {
+            Some(ErrorCode::DelegateAllowanceExceeded)
+        } else if ctx.accounts.user_account.get_nonce(asset_id) != pending.balance_nonce
+            || ctx.accounts.delegate.allowance_nonces[asset_id as usize] != pending.allowance_nonce
+        {
+            Some(ErrorCode::PendingOperationStale)
+        } else {
+            None
+        };
+        if let Some(error) = rejection {
+            emit!(WithdrawalRejectedEvent {
+                user: ctx.accounts.user_account.owner,
+                asset_id,
+                amount,
+                error: error.info(),
+                seq: 0, // not sequenced: the transaction fails
+            });
+            return Err(error.into());
+        }
+
+        let pool_seeds = &[
+            POOL_SEED,
+            &[ctx.accounts.pool.pool_id],
+            &[ctx.accounts.pool.bump],
+        ];
+        let signer_seeds = &[&pool_seeds[..]];
+        transfer_checked_with_hook(
+            CheckedTransferAccounts {
+                token_program: &ctx.accounts.token_program,
+                from: &ctx.accounts.vault,
+                mint: &ctx.accounts.mint.to_account_info(),
+                to: &ctx.accounts.recipient_token_account,
+                authority: &ctx.accounts.pool.to_account_info(),
+                hook_accounts: ctx.remaining_accounts,
+            },
+            amount,
+            ctx.accounts.mint.decimals,
+            signer_seeds,
+        )?;
+
+        ctx.accounts
+            .user_account
+            .set_credit(asset_id, new_balance.ciphertexts[0]);
+        ctx.accounts
+            .user_account
+            .set_nonce(asset_id, new_balance.nonce);
+        let delegate = &mut ctx.accounts.delegate;
+        delegate.allowances[asset_id as usize] = new_allowance.ciphertexts[0];
+        delegate.allowance_nonces[asset_id as usize] = new_allowance.nonce;
+        ctx.accounts
+            .pending_operation
+            .close(ctx.accounts.owner.to_account_info())?;
+
+        emit!(WithdrawEvent {
+            user: ctx.accounts.user_account.owner,
+            encrypted_balance: new_balance.ciphertexts[0],
+            nonce: new_balance.nonce.to_le_bytes(),
+            seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
         });
+
+        msg!(
+            "Delegated withdrawal callback: {} units of asset {} transferred",
+            amount,
+            asset_id
+        );
         Ok(())
     }
 
     // =========================================================================
-    // ADD BALANCE - Queue Encrypted Deposit (Phase 6)
+    // BENEFICIARY (Dead-man's switch)
     // =========================================================================
 
-    /// Queue an encrypted balance update for a deposit.
-    /// This performs the token transfer and queues the MPC computation.
-    /// The actual balance update happens in the callback.
+    /// Register or replace a beneficiary that can inherit the caller's
+    /// encrypted balances after `inactivity_period_secs` of owner inactivity.
     ///
     /// # Arguments
-    /// * `computation_offset` - Unique ID for this computation
-    /// * `encrypted_amount` - The deposit amount encrypted with user's key
-    /// * `pubkey` - User's x25519 public key
-    /// * `nonce` - Encryption nonce
-    /// * `amount` - Plaintext amount for token transfer (revealed for CPI)
-    /// * `asset_id` - Asset identifier (0=USDC, 1=TSLA, 2=SPY, 3=AAPL)
-    pub fn add_balance(
-        ctx: Context<AddBalance>,
-        computation_offset: u64,
-        encrypted_amount: [u8; 32],
-        pubkey: [u8; 32],
-        nonce: u128,
-        amount: u64,
-        asset_id: u8,
+    /// * `beneficiary` - Beneficiary wallet
+    /// * `inactivity_period_secs` - Inactivity before a claim (>= MIN_INACTIVITY_PERIOD_SECS)
+    pub fn set_beneficiary(
+        ctx: Context<SetBeneficiary>,
+        beneficiary: Pubkey,
+        inactivity_period_secs: i64,
     ) -> Result<()> {
-        // Validate asset_id
-        require!(asset_id <= 3, ErrorCode::InvalidAssetId);
-
-        // Transfer tokens first (this is visible on-chain, but private in aggregate)
-        let transfer_ctx = CpiContext::new(
-            ctx.accounts.token_program.to_account_info(),
-            anchor_spl::token::Transfer {
-                from: ctx.accounts.user_token_account.to_account_info(),
-                to: ctx.accounts.vault.to_account_info(),
-                authority: ctx.accounts.user.to_account_info(),
-            },
-        );
-        anchor_spl::token::transfer(transfer_ctx, amount)?;
-
-        // Store pending asset_id for callback to know which balance to update
-        ctx.accounts.user_account.pending_asset_id = asset_id;
-
-        // Set sign PDA bump
-        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
-
-        // Build MPC arguments using the correct balance and nonce for this asset
-        let current_balance = ctx.accounts.user_account.get_credit(asset_id);
-        let current_nonce = ctx.accounts.user_account.get_nonce(asset_id);
-        let args = ArgBuilder::new()
-            // Shared input 1: BalanceUpdate (new deposit amount)
-            .x25519_pubkey(pubkey)
-            .plaintext_u128(nonce)
-            .encrypted_u64(encrypted_amount)
-            // Shared input 2: UserBalance (current balance from account)
-            .x25519_pubkey(pubkey)
-            .plaintext_u128(current_nonce)
-            .encrypted_u64(current_balance)
-            .build();
+        instructions::set_beneficiary::handler(ctx, beneficiary, inactivity_period_secs)
+    }
 
-        // Register callback that will receive the new encrypted balance
-        use arcium_client::idl::arcium::types::CallbackAccount;
-        queue_computation(
-            ctx.accounts,
-            computation_offset,
-            args,
-            vec![AddBalanceCallback::callback_ix(
-                computation_offset,
-                &ctx.accounts.mxe_account,
-                &[CallbackAccount {
-                    pubkey: ctx.accounts.user_account.key(),
-                    is_writable: true,
-                }],
-            )?],
-            1, // number of callbacks
-            0, // priority
-        )?;
+    /// Remove the caller's beneficiary and reclaim rent.
+    pub fn remove_beneficiary(ctx: Context<RemoveBeneficiary>) -> Result<()> {
+        instructions::remove_beneficiary::handler(ctx)
+    }
 
-        msg!(
-            "Deposit queued: {} units of asset {}, computation {}",
-            amount,
-            asset_id,
-            computation_offset
-        );
-        Ok(())
+    /// Beneficiary sweeps one asset balance of an inactive owner into their
+    /// own privacy account via the sweep_balance circuit.
+    ///
+    /// # Arguments
+    /// * `computation_offset` - Unique ID for MPC computation
+    /// * `asset_id` - Asset to sweep (0=USDC, 1=TSLA, 2=SPY, 3=AAPL)
+    pub fn claim_inheritance(
+        ctx: Context<ClaimInheritance>,
+        computation_offset: u64,
+        asset_id: u8,
+    ) -> Result<()> {
+        instructions::claim_inheritance::handler(ctx, computation_offset, asset_id)
     }
 
-    /// Callback handler for add_balance computation.
-    /// Receives the new encrypted balance from MPC and updates user account.
-    #[arcium_callback(encrypted_ix = "add_balance")]
-    pub fn add_balance_callback(
-        ctx: Context<AddBalanceCallback>,
-        output: SignedComputationOutputs<AddBalanceOutput>,
+    /// Callback handler for sweep_balance computation.
+    /// Aborts if the owner became active after the claim was queued.
+    #[arcium_callback(encrypted_ix = "sweep_balance")]
+    pub fn sweep_balance_callback(
+        ctx: Context<SweepBalanceCallback>,
+        output: SignedComputationOutputs<SweepBalanceOutput>,
     ) -> Result<()> {
         let o = match output.verify_output(
             &ctx.accounts.cluster_account,
             &ctx.accounts.computation_account,
         ) {
-            Ok(AddBalanceOutput { field_0 }) => field_0,
+            Ok(output) => output,
             Err(err) => {
                 msg!(
-                    "add_balance_callback verify_output failed: {:?}, computation={}",
+                    "sweep_balance_callback verify_output failed: {:?}, computation={}",
                     err,
                     ctx.accounts.computation_account.key()
                 );
+                emit_callback_failed("sweep_balance", ctx.accounts.computation_account.key());
                 return Err(ErrorCode::AbortedComputation.into());
             }
         };
 
-        // Update the correct asset balance and nonce using pending_asset_id set during add_balance
-        let asset_id = ctx.accounts.user_account.pending_asset_id;
+        // Owner activity since the claim was queued cancels it
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            ctx.accounts
+                .beneficiary_config
+                .is_claimable(ctx.accounts.owner_account.last_active_at, now),
+            ErrorCode::OwnerStillActive
+        );
 
+        // o.field_0.field_0 = owner's balance (zeroed)
+        // o.field_0.field_1 = beneficiary's balance with the owner's added
+        let asset_id = ctx.accounts.beneficiary_config.pending_asset_id;
         ctx.accounts
-            .user_account
-            .set_credit(asset_id, o.ciphertexts[0]);
-        ctx.accounts.user_account.set_nonce(asset_id, o.nonce);
+            .owner_account
+            .set_credit(asset_id, o.field_0.field_0.ciphertexts[0]);
+        ctx.accounts
+            .owner_account
+            .set_nonce(asset_id, o.field_0.field_0.nonce);
+        ctx.accounts
+            .beneficiary_account
+            .set_credit(asset_id, o.field_0.field_1.ciphertexts[0]);
+        ctx.accounts
+            .beneficiary_account
+            .set_nonce(asset_id, o.field_0.field_1.nonce);
 
-        emit!(DepositEvent {
-            user: ctx.accounts.user_account.owner,
-            encrypted_balance: o.ciphertexts[0],
-            nonce: o.nonce.to_le_bytes(),
+        emit!(InheritanceClaimedEvent {
+            owner: ctx.accounts.owner_account.owner,
+            beneficiary: ctx.accounts.beneficiary_account.owner,
+            asset_id,
+            claimed_at: now,
+            seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
         });
 
-        msg!("Deposit callback: asset {} balance updated", asset_id);
+        msg!(
+            "Inheritance sweep: {} -> {}, asset={}",
+            ctx.accounts.owner_account.owner,
+            ctx.accounts.beneficiary_account.owner,
+            asset_id
+        );
         Ok(())
     }
 
     // =========================================================================
-    // ARCIUM MPC SETUP - Sub Balance (Phase 6.5)
+    // GUARDIAN RECOVERY (Social recovery)
     // =========================================================================
 
-    /// Initialize the sub_balance computation definition.
-    /// This must be called once before any encrypted withdrawals can be processed.
-    pub fn init_sub_balance_comp_def(ctx: Context<InitSubBalanceCompDef>) -> Result<()> {
-        init_comp_def(
-            ctx.accounts,
-            Some(CircuitSource::OffChain(OffChainCircuitSource {
-                source: "https://gateway.pinata.cloud/ipfs/QmSfQjsdRAiXEU9b8qH2d1fgmyn1P7wcRCd28DE1e5Y3nC".to_string(),
-                hash: circuit_hash!("sub_balance"),
-            })),
-            None,
-        )?;
-        Ok(())
+    /// Register or replace the guardians that can move the caller's account
+    /// to a new wallet. Discards any recovery in progress.
+    ///
+    /// # Arguments
+    /// * `guardians` - Guardian wallets (1..=MAX_GUARDIANS)
+    /// * `threshold` - Approvals needed for a recovery
+    /// * `challenge_period_secs` - Delay after approval (>= MIN_RECOVERY_CHALLENGE_SECS)
+    pub fn set_guardians(
+        ctx: Context<SetGuardians>,
+        guardians: Vec<Pubkey>,
+        threshold: u8,
+        challenge_period_secs: i64,
+    ) -> Result<()> {
+        instructions::set_guardians::handler(ctx, guardians, threshold, challenge_period_secs)
     }
 
-    // =========================================================================
-    // SUB BALANCE - Queue Encrypted Withdrawal (Phase 6.5)
-    // =========================================================================
-
-    /// Queue an encrypted balance update for a withdrawal.
-    /// This performs the token transfer and queues the MPC computation.
-    /// The encrypted balance update happens in the callback.
+    /// Guardian approves moving an owner's account to `new_owner`, whose
+    /// privacy account must use `new_user_pubkey`.
     ///
     /// # Arguments
-    /// * `computation_offset` - Unique ID for this computation
-    /// * `encrypted_amount` - The withdrawal amount encrypted with user's key
-    /// * `pubkey` - User's x25519 public key
-    /// * `nonce` - Encryption nonce
-    /// * `amount` - Plaintext amount for token transfer (deferred to callback)
-    /// * `asset_id` - Asset identifier (0=USDC, 1=TSLA, 2=SPY, 3=AAPL)
-    pub fn sub_balance(
-        ctx: Context<SubBalance>,
-        computation_offset: u64,
-        encrypted_amount: [u8; 32],
-        pubkey: [u8; 32],
-        nonce: u128,
-        amount: u64,
-        asset_id: u8,
+    /// * `new_owner` - Wallet that takes over the account
+    /// * `new_user_pubkey` - x25519 key of the new owner's privacy account
+    pub fn approve_recovery(
+        ctx: Context<ApproveRecovery>,
+        new_owner: Pubkey,
+        new_user_pubkey: [u8; 32],
     ) -> Result<()> {
-        // Validate asset_id
-        require!(asset_id <= 3, ErrorCode::InvalidAssetId);
-
-        // Store pending info for callback to use
-        // Token transfer is DEFERRED to callback (after MPC confirms sufficient balance)
-        ctx.accounts.user_account.pending_asset_id = asset_id;
-        ctx.accounts.user_account.pending_withdrawal_amount = amount;
-
-        // Set sign PDA bump
-        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
-
-        // Build MPC arguments using the correct balance and nonce for this asset
-        let current_balance = ctx.accounts.user_account.get_credit(asset_id);
-        let current_nonce = ctx.accounts.user_account.get_nonce(asset_id);
-        let args = ArgBuilder::new()
-            // Shared input 1: BalanceUpdate (withdrawal amount)
-            .x25519_pubkey(pubkey)
-            .plaintext_u128(nonce)
-            .encrypted_u64(encrypted_amount)
-            // Shared input 2: UserBalance (current balance from account)
-            .x25519_pubkey(pubkey)
-            .plaintext_u128(current_nonce)
-            .encrypted_u64(current_balance)
-            .build();
+        instructions::approve_recovery::handler(ctx, new_owner, new_user_pubkey)
+    }
 
-        // Register callback that will verify has_funds and perform token transfer
-        use arcium_client::idl::arcium::types::CallbackAccount;
-        queue_computation(
-            ctx.accounts,
-            computation_offset,
-            args,
-            vec![SubBalanceCallback::callback_ix(
-                computation_offset,
-                &ctx.accounts.mxe_account,
-                &[
-                    CallbackAccount {
-                        pubkey: ctx.accounts.user_account.key(),
-                        is_writable: true,
-                    },
-                    CallbackAccount {
-                        pubkey: ctx.accounts.pool.key(),
-                        is_writable: false,
-                    },
-                    CallbackAccount {
-                        pubkey: ctx.accounts.vault.key(),
-                        is_writable: true,
-                    },
-                    CallbackAccount {
-                        pubkey: ctx.accounts.recipient_token_account.key(),
-                        is_writable: true,
-                    },
-                    CallbackAccount {
-                        pubkey: ctx.accounts.token_program.key(),
-                        is_writable: false,
-                    },
-                ],
-            )?],
-            1, // number of callbacks
-            0, // priority
-        )?;
+    /// Owner rejects the recovery in progress.
+    pub fn cancel_recovery(ctx: Context<CancelRecovery>) -> Result<()> {
+        instructions::cancel_recovery::handler(ctx)
+    }
 
-        msg!(
-            "Withdrawal queued: {} units of asset {}, computation {} (transfer deferred to callback)",
-            amount,
-            asset_id,
-            computation_offset
-        );
-        Ok(())
+    /// New owner moves an approved account's balances into their privacy
+    /// account via the recover_account circuit once the challenge period
+    /// has passed.
+    ///
+    /// # Arguments
+    /// * `computation_offset` - Unique ID for MPC computation
+    pub fn execute_recovery(ctx: Context<ExecuteRecovery>, computation_offset: u64) -> Result<()> {
+        instructions::execute_recovery::handler(ctx, computation_offset)
     }
 
-    /// Callback handler for sub_balance computation.
-    /// Receives (has_funds, new_balance) from MPC.
-    /// If has_funds is false, aborts the transaction.
-    /// If has_funds is true, performs the token transfer and updates balance.
-    #[arcium_callback(encrypted_ix = "sub_balance")]
-    pub fn sub_balance_callback(
-        ctx: Context<SubBalanceCallback>,
-        output: SignedComputationOutputs<SubBalanceOutput>,
+    /// Callback handler for recover_account computation.
+    /// Writes the merged balances to the new profile and closes the old
+    /// profile (rent to the new owner, or back to the RentSponsor pool if it
+    /// was sponsored) and the GuardianSet.
+    #[arcium_callback(encrypted_ix = "recover_account")]
+    pub fn recover_account_callback(
+        ctx: Context<RecoverAccountCallback>,
+        output: SignedComputationOutputs<RecoverAccountOutput>,
     ) -> Result<()> {
         let o = match output.verify_output(
             &ctx.accounts.cluster_account,
@@ -1200,281 +2104,14080 @@ pub mod shuffle_protocol {
             Ok(output) => output,
             Err(err) => {
                 msg!(
-                    "sub_balance_callback verify_output failed: {:?}, computation={}",
+                    "recover_account_callback verify_output failed: {:?}, computation={}",
                     err,
                     ctx.accounts.computation_account.key()
                 );
+                emit_callback_failed("recover_account", ctx.accounts.computation_account.key());
                 return Err(ErrorCode::AbortedComputation.into());
             }
         };
 
-        // Extract has_funds flag from MPC output
-        // Circuit returns (bool, Enc<Shared, UserBalance>) wrapped in field_0
-        // o.field_0.field_0 = bool (has_funds, revealed)
-        // o.field_0.field_1 = UserBalance (SharedEncryptedStruct<1>)
-        let has_funds: bool = o.field_0.field_0;
-        let new_balance = &o.field_0.field_1;
-
-        // If user doesn't have sufficient funds, abort the transaction
-        if !has_funds {
-            return Err(ErrorCode::InsufficientBalance.into());
-        }
-
-        // Perform the deferred token transfer now that MPC confirmed sufficient balance
-        let pool_seeds = &[POOL_SEED, &[ctx.accounts.pool.bump]];
-        let signer_seeds = &[&pool_seeds[..]];
-
-        let transfer_ctx = CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
-            anchor_spl::token::Transfer {
-                from: ctx.accounts.vault.to_account_info(),
-                to: ctx.accounts.recipient_token_account.to_account_info(),
-                authority: ctx.accounts.pool.to_account_info(),
-            },
-            signer_seeds,
+        // Any balance update on either profile since queueing replaced its nonce
+        let set = &ctx.accounts.guardian_set;
+        let old = &ctx.accounts.old_account;
+        let new = &ctx.accounts.new_account;
+        let old_nonces: [u128; 4] = std::array::from_fn(|asset| old.get_nonce(asset as u8));
+        let new_nonces: [u128; 4] = std::array::from_fn(|asset| new.get_nonce(asset as u8));
+        require!(
+            old_nonces == set.source_nonces && new_nonces == set.target_nonces,
+            ErrorCode::RecoveryStale
+        );
+        require!(
+            old.pending_order.is_none() && old.staged_order.is_none(),
+            ErrorCode::PendingOrderExists
         );
 
-        let amount = ctx.accounts.user_account.pending_withdrawal_amount;
-        anchor_spl::token::transfer(transfer_ctx, amount)?;
+        // o.field_0.field_0..field_3 = USDC, TSLA, SPY, AAPL of the new profile
+        let balances = [
+            o.field_0.field_0,
+            o.field_0.field_1,
+            o.field_0.field_2,
+            o.field_0.field_3,
+        ];
+        let new = &mut ctx.accounts.new_account;
+        for (asset_id, balance) in balances.iter().enumerate() {
+            new.set_credit(asset_id as u8, balance.ciphertexts[0]);
+            new.set_nonce(asset_id as u8, balance.nonce);
+        }
 
-        // Update the correct asset balance and nonce
-        let asset_id = ctx.accounts.user_account.pending_asset_id;
-        ctx.accounts
-            .user_account
-            .set_credit(asset_id, new_balance.ciphertexts[0]);
+        let previous_owner = ctx.accounts.old_account.owner;
+        let destination = if ctx.accounts.old_account.rent_sponsored {
+            ctx.accounts
+                .rent_sponsor
+                .as_ref()
+                .ok_or(ErrorCode::RentSponsorMissing)?
+                .to_account_info()
+        } else {
+            ctx.accounts.new_owner.to_account_info()
+        };
+        ctx.accounts.old_account.close(destination)?;
         ctx.accounts
-            .user_account
-            .set_nonce(asset_id, new_balance.nonce);
-
-        // Clear pending withdrawal
-        ctx.accounts.user_account.pending_withdrawal_amount = 0;
-
-        emit!(WithdrawEvent {
-            user: ctx.accounts.user_account.owner,
-            encrypted_balance: new_balance.ciphertexts[0],
-            nonce: new_balance.nonce.to_le_bytes(),
+            .guardian_set
+            .close(ctx.accounts.new_owner.to_account_info())?;
+
+        emit!(AccountRecoveredEvent {
+            previous_owner,
+            new_owner: ctx.accounts.new_account.owner,
+            new_pubkey: ctx.accounts.new_account.user_pubkey,
+            seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
         });
 
         msg!(
-            "Withdrawal callback: {} units of asset {} transferred, balance updated",
-            amount,
-            asset_id
+            "Account of {} recovered to {}",
+            previous_owner,
+            ctx.accounts.new_account.owner
         );
         Ok(())
     }
 
     // =========================================================================
-    // P2P INTERNAL TRANSFER (Phase 6.75)
+    // VIEWABLE BALANCES (View keys)
     // =========================================================================
 
-    /// Check if a wallet has a privacy account.
-    /// This is a view function for clients to check before attempting transfers.
+    /// Designate or replace the x25519 viewing key the caller's balances are
+    /// re-encrypted for (side PDA). Replacing the key zeroes stored copies.
     ///
-    /// # Returns
-    /// * `true` if the account exists
-    /// * `false` if the account doesn't exist
-    pub fn check_privacy_account_exists(ctx: Context<CheckPrivacyAccountExists>) -> Result<bool> {
-        // If we get here, the account exists (Anchor validates it)
-        // So we just return true
-        msg!(
-            "Privacy account exists for wallet: {}",
-            ctx.accounts.user_account.owner
-        );
-        Ok(true)
+    /// # Arguments
+    /// * `viewing_pubkey` - x25519 public key of the frontend or auditor
+    pub fn set_viewing_key(ctx: Context<SetViewingKey>, viewing_pubkey: [u8; 32]) -> Result<()> {
+        instructions::set_viewing_key::handler(ctx, viewing_pubkey)
     }
 
-    // =========================================================================
-    // ARCIUM MPC SETUP - Transfer (Phase 6.75)
-    // =========================================================================
+    /// Re-encrypt one asset balance under the caller's viewing key via the
+    /// reencrypt_balance circuit. The balance itself is unchanged.
+    ///
+    /// # Arguments
+    /// * `computation_offset` - Unique ID for MPC computation
+    /// * `asset_id` - Asset to refresh (0=USDC, 1=TSLA, 2=SPY, 3=AAPL)
+    /// * `viewer_nonce` - Fresh nonce for the viewing key's shared secret
+    pub fn refresh_viewable_balance(
+        ctx: Context<RefreshViewableBalance>,
+        computation_offset: u64,
+        asset_id: u8,
+        viewer_nonce: u128,
+    ) -> Result<()> {
+        instructions::refresh_viewable_balance::handler(
+            ctx,
+            computation_offset,
+            asset_id,
+            viewer_nonce,
+        )
+    }
 
-    /// Initialize the transfer computation definition.
-    /// This must be called once before any P2P transfers can be processed.
-    pub fn init_transfer_comp_def(ctx: Context<InitTransferCompDef>) -> Result<()> {
-        init_comp_def(
-            ctx.accounts,
-            Some(CircuitSource::OffChain(OffChainCircuitSource {
-                source: "https://gateway.pinata.cloud/ipfs/QmQAK9JvndSP3YePGq9ciSeuCk8boHfQy5xi3RZTHS9iDW".to_string(),
-                hash: circuit_hash!("transfer"),
-            })),
-            None,
-        )?;
+    /// Callback handler for reencrypt_balance computation.
+    /// Drops the result if the viewing key was replaced after queueing.
+    #[arcium_callback(encrypted_ix = "reencrypt_balance")]
+    pub fn reencrypt_balance_callback(
+        ctx: Context<ReencryptBalanceCallback>,
+        output: SignedComputationOutputs<ReencryptBalanceOutput>,
+    ) -> Result<()> {
+        let o = match output.verify_output(
+            &ctx.accounts.cluster_account,
+            &ctx.accounts.computation_account,
+        ) {
+            Ok(ReencryptBalanceOutput { field_0 }) => field_0,
+            Err(err) => {
+                msg!(
+                    "reencrypt_balance_callback verify_output failed: {:?}, computation={}",
+                    err,
+                    ctx.accounts.computation_account.key()
+                );
+                emit_callback_failed("reencrypt_balance", ctx.accounts.computation_account.key());
+                return Err(ErrorCode::AbortedComputation.into());
+            }
+        };
+
+        let viewable = &mut ctx.accounts.viewable_balance;
+        require!(
+            viewable.pending_viewing_pubkey == viewable.viewing_pubkey,
+            ErrorCode::ViewingKeyChanged
+        );
+
+        let now = Clock::get()?.unix_timestamp;
+        let asset_id = viewable.pending_asset_id;
+        let index = asset_id as usize;
+        viewable.balances[index] = o.ciphertexts[0];
+        viewable.nonces[index] = o.nonce;
+        viewable.refreshed_at[index] = now;
+
+        emit!(ViewableBalanceRefreshedEvent {
+            owner: viewable.owner,
+            asset_id,
+            viewing_pubkey: viewable.viewing_pubkey,
+            encrypted_balance: o.ciphertexts[0],
+            nonce: o.nonce.to_le_bytes(),
+            refreshed_at: now,
+            seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+        });
+
+        msg!(
+            "Viewable balance refreshed: owner={}, asset={}",
+            viewable.owner,
+            asset_id
+        );
         Ok(())
     }
 
-    // =========================================================================
-    // P2P INTERNAL TRANSFER (Phase 6.75)
-    // =========================================================================
+    /// Disclose all four balances to an auditor's x25519 key via the
+    /// disclose_balances circuit, stored in a ViewerGrant. Calling it again
+    /// for the same viewer refreshes the grant.
+    ///
+    /// # Arguments
+    /// * `computation_offset` - Unique ID for MPC computation
+    /// * `viewer_pubkey` - x25519 public key of the auditor
+    /// * `nonce` - Fresh nonce; balance i (0=USDC..3=AAPL) uses nonce + i
+    pub fn add_viewer(
+        ctx: Context<AddViewer>,
+        computation_offset: u64,
+        viewer_pubkey: [u8; 32],
+        nonce: u128,
+    ) -> Result<()> {
+        instructions::add_viewer::handler(ctx, computation_offset, viewer_pubkey, nonce)
+    }
 
-    // =========================================================================
-    // TEST SWAP CPI (Phase 8 - Cross-Program Invocation to mock_jupiter)
-    // =========================================================================
+    /// Callback handler for disclose_balances computation.
+    #[arcium_callback(encrypted_ix = "disclose_balances")]
+    pub fn disclose_balances_callback(
+        ctx: Context<DiscloseBalancesCallback>,
+        output: SignedComputationOutputs<DiscloseBalancesOutput>,
+    ) -> Result<()> {
+        let o = match output.verify_output(
+            &ctx.accounts.cluster_account,
+            &ctx.accounts.computation_account,
+        ) {
+            Ok(output) => output,
+            Err(err) => {
+                msg!(
+                    "disclose_balances_callback verify_output failed: {:?}, computation={}",
+                    err,
+                    ctx.accounts.computation_account.key()
+                );
+                emit_callback_failed("disclose_balances", ctx.accounts.computation_account.key());
+                return Err(ErrorCode::AbortedComputation.into());
+            }
+        };
 
-    /// Test CPI swap through mock_jupiter.
-    /// The Pool PDA signs the CPI as the "user_authority" since it owns the vaults.
-    /// This proves cross-program invocation works before building full batch execution.
+        // o.field_0.field_0..field_3 = USDC, TSLA, SPY, AAPL for the viewer
+        let balances = [
+            o.field_0.field_0,
+            o.field_0.field_1,
+            o.field_0.field_2,
+            o.field_0.field_3,
+        ];
+        let now = Clock::get()?.unix_timestamp;
+        let grant = &mut ctx.accounts.viewer_grant;
+        for (index, balance) in balances.iter().enumerate() {
+            grant.balances[index] = balance.ciphertexts[0];
+            grant.nonces[index] = balance.nonce;
+        }
+        grant.disclosed_at = now;
+
+        emit!(BalancesDisclosedEvent {
+            owner: grant.owner,
+            viewer_pubkey: grant.viewer_pubkey,
+            disclosed_at: now,
+            seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+        });
+
+        msg!("Balances disclosed: owner={}", grant.owner);
+        Ok(())
+    }
+
+    /// Revoke a viewer grant and reclaim rent.
     ///
     /// # Arguments
-    /// * `amount_in` - Amount of source tokens to swap
-    /// * `min_amount_out` - Minimum acceptable output (slippage protection)
-    pub fn test_swap(ctx: Context<TestSwap>, amount_in: u64, min_amount_out: u64) -> Result<()> {
-        instructions::test_swap::handler(ctx, amount_in, min_amount_out)
+    /// * `viewer_pubkey` - x25519 public key of the auditor
+    pub fn remove_viewer(ctx: Context<RemoveViewer>, viewer_pubkey: [u8; 32]) -> Result<()> {
+        instructions::remove_viewer::handler(ctx, viewer_pubkey)
     }
 
     // =========================================================================
-    // P2P INTERNAL TRANSFER (Phase 6.75)
+    // KEY ROTATION
     // =========================================================================
 
-    /// Internal transfer between two privacy accounts.
-    /// Atomically deducts from sender's and adds to recipient's encrypted balance.
-    ///
-    /// Both balances are updated in a single MPC computation using the `transfer` circuit.
+    /// Re-encrypt all four balances under a new x25519 key via the
+    /// rotate_user_key circuit, then switch UserProfile.user_pubkey.
+    /// Requires no pending or staged order.
     ///
     /// # Arguments
     /// * `computation_offset` - Unique ID for MPC computation
-    /// * `encrypted_amount` - Amount encrypted with sender's key
-    /// * `pubkey` - Sender's x25519 public key
-    /// * `nonce` - Encryption nonce
-    pub fn internal_transfer(
-        ctx: Context<InternalTransfer>,
+    /// * `new_user_pubkey` - New x25519 public key
+    /// * `nonce` - Fresh nonce; balance i (0=USDC..3=AAPL) uses nonce + i
+    pub fn rotate_user_key(
+        ctx: Context<RotateUserKey>,
         computation_offset: u64,
-        encrypted_amount: [u8; 32],
-        pubkey: [u8; 32],
+        new_user_pubkey: [u8; 32],
         nonce: u128,
     ) -> Result<()> {
-        // Set sign PDA bump
-        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
-
-        // Build MPC arguments for transfer circuit
-        // Transfer circuit takes: TransferRequest { amount }, sender_balance, recipient_balance
-        // All use Enc<Shared, *> pattern with x25519 pubkey + nonce + encrypted value
-        let args = ArgBuilder::new()
-            // TransferRequest (encrypted with sender's key) - just amount field
-            .x25519_pubkey(pubkey)
-            .plaintext_u128(nonce)
-            .encrypted_u64(encrypted_amount)
-            // Sender's current balance (Enc<Shared, *> - using sender's pubkey)
-            .x25519_pubkey(ctx.accounts.sender_account.user_pubkey)
-            .plaintext_u128(ctx.accounts.sender_account.usdc_nonce)
-            .encrypted_u64(ctx.accounts.sender_account.usdc_credit)
-            // Recipient's current balance (Enc<Shared, *> - using recipient's pubkey)
-            .x25519_pubkey(ctx.accounts.recipient_account.user_pubkey)
-            .plaintext_u128(ctx.accounts.recipient_account.usdc_nonce)
-            .encrypted_u64(ctx.accounts.recipient_account.usdc_credit)
-            .build();
+        instructions::rotate_user_key::handler(ctx, computation_offset, new_user_pubkey, nonce)
+    }
 
-        // Queue MPC - callback receives BOTH updated balances
-        use arcium_client::idl::arcium::types::CallbackAccount;
-        queue_computation(
-            ctx.accounts,
-            computation_offset,
-            args,
-            vec![TransferCallback::callback_ix(
-                computation_offset,
-                &ctx.accounts.mxe_account,
-                &[
-                    CallbackAccount {
-                        pubkey: ctx.accounts.sender_account.key(),
-                        is_writable: true,
-                    },
-                    CallbackAccount {
-                        pubkey: ctx.accounts.recipient_account.key(),
-                        is_writable: true,
-                    },
-                ],
-            )?],
-            1,
-            0,
-        )?;
+    /// Callback handler for rotate_user_key computation.
+    /// Aborts if any balance changed after the rotation was queued.
+    #[arcium_callback(encrypted_ix = "rotate_user_key")]
+    pub fn rotate_user_key_callback(
+        ctx: Context<RotateUserKeyCallback>,
+        output: SignedComputationOutputs<RotateUserKeyOutput>,
+    ) -> Result<()> {
+        let o = match output.verify_output(
+            &ctx.accounts.cluster_account,
+            &ctx.accounts.computation_account,
+        ) {
+            Ok(output) => output,
+            Err(err) => {
+                msg!(
+                    "rotate_user_key_callback verify_output failed: {:?}, computation={}",
+                    err,
+                    ctx.accounts.computation_account.key()
+                );
+                emit_callback_failed("rotate_user_key", ctx.accounts.computation_account.key());
+                return Err(ErrorCode::AbortedComputation.into());
+            }
+        };
 
-        msg!(
-            "Transfer queued: {} -> {}, computation {}",
-            ctx.accounts.sender_account.owner,
-            ctx.accounts.recipient_account.owner,
-            computation_offset
+        // Any balance update since queueing replaced its nonce
+        let user = &mut ctx.accounts.user_account;
+        let rotation = &mut ctx.accounts.key_rotation;
+        let current_nonces: [u128; 4] = std::array::from_fn(|asset| user.get_nonce(asset as u8));
+        require!(
+            current_nonces == rotation.source_nonces,
+            ErrorCode::KeyRotationStale
         );
+
+        // o.field_0.field_0..field_3 = USDC, TSLA, SPY, AAPL under the new key
+        let balances = [
+            o.field_0.field_0,
+            o.field_0.field_1,
+            o.field_0.field_2,
+            o.field_0.field_3,
+        ];
+        for (asset_id, balance) in balances.iter().enumerate() {
+            user.set_credit(asset_id as u8, balance.ciphertexts[0]);
+            user.set_nonce(asset_id as u8, balance.nonce);
+        }
+        let previous_pubkey = user.user_pubkey;
+        user.user_pubkey = rotation.pending_user_pubkey;
+
+        let now = Clock::get()?.unix_timestamp;
+        rotation.rotation_count = rotation.rotation_count.saturating_add(1);
+        rotation.rotated_at = now;
+
+        emit!(UserKeyRotatedEvent {
+            owner: user.owner,
+            previous_pubkey,
+            new_pubkey: user.user_pubkey,
+            rotated_at: now,
+            seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+        });
+
+        msg!("User key rotated for {}", user.owner);
         Ok(())
     }
 
-    /// Callback handler for transfer computation.
-    /// Receives both updated balances and writes them atomically.
-    #[arcium_callback(encrypted_ix = "transfer")]
-    pub fn transfer_callback(
-        ctx: Context<TransferCallback>,
-        output: SignedComputationOutputs<TransferOutput>,
+    // =========================================================================
+    // CLOSE USER ACCOUNT
+    // =========================================================================
+
+    /// Close the caller's privacy account and recover its rent once the
+    /// check_balances_zero circuit confirms all balances are zero.
+    /// Requires no pending or staged order.
+    ///
+    /// # Arguments
+    /// * `computation_offset` - Unique ID for MPC computation
+    pub fn close_user_account(
+        ctx: Context<CloseUserAccount>,
+        computation_offset: u64,
     ) -> Result<()> {
-        let o = match output.verify_output(
+        instructions::close_user_account::handler(ctx, computation_offset)
+    }
+
+    /// Callback handler for check_balances_zero computation.
+    /// Closes the UserProfile (rent to the owner, or back to the RentSponsor
+    /// pool if it was sponsored) and the AccountClosure PDA.
+    #[arcium_callback(encrypted_ix = "check_balances_zero")]
+    pub fn check_balances_zero_callback(
+        ctx: Context<CheckBalancesZeroCallback>,
+        output: SignedComputationOutputs<CheckBalancesZeroOutput>,
+    ) -> Result<()> {
+        let all_zero = match output.verify_output(
             &ctx.accounts.cluster_account,
             &ctx.accounts.computation_account,
         ) {
-            Ok(output) => output,
+            Ok(CheckBalancesZeroOutput { field_0 }) => field_0,
             Err(err) => {
                 msg!(
-                    "transfer_callback verify_output failed: {:?}, computation={}",
+                    "check_balances_zero_callback verify_output failed: {:?}, computation={}",
                     err,
                     ctx.accounts.computation_account.key()
                 );
+                emit_callback_failed(
+                    "check_balances_zero",
+                    ctx.accounts.computation_account.key(),
+                );
                 return Err(ErrorCode::AbortedComputation.into());
             }
         };
 
-        // Tuple return creates nested struct:
-        // o.field_0.field_0 = sender's new balance (Enc<Shared, UserBalance>)
-        // o.field_0.field_1 = recipient's new balance (Enc<Shared, UserBalance>)
-
-        // Log old values for debugging
-        msg!(
-            "DEBUG transfer_callback: sender old nonce={}, old credit[0..4]={:?}",
-            ctx.accounts.sender_account.usdc_nonce,
-            &ctx.accounts.sender_account.usdc_credit[0..4]
+        // Any balance update since queueing replaced its nonce
+        let user = &ctx.accounts.user_account;
+        let current_nonces: [u128; 4] = std::array::from_fn(|asset| user.get_nonce(asset as u8));
+        require!(
+            current_nonces == ctx.accounts.account_closure.source_nonces,
+            ErrorCode::AccountClosureStale
         );
-        msg!(
-            "DEBUG transfer_callback: recipient old nonce={}, old credit[0..4]={:?}",
-            ctx.accounts.recipient_account.usdc_nonce,
-            &ctx.accounts.recipient_account.usdc_credit[0..4]
+        require!(
+            user.pending_order.is_none() && user.staged_order.is_none(),
+            ErrorCode::PendingOrderExists
         );
+        require!(all_zero, ErrorCode::UserAccountNotEmpty);
+
+        let owner = user.owner;
+        let sponsored = user.rent_sponsored;
+        let refunded = user.to_account_info().lamports();
+        let destination = if sponsored {
+            ctx.accounts
+                .rent_sponsor
+                .as_ref()
+                .ok_or(ErrorCode::RentSponsorMissing)?
+                .to_account_info()
+        } else {
+            ctx.accounts.owner.to_account_info()
+        };
+        ctx.accounts.user_account.close(destination)?;
+        ctx.accounts
+            .account_closure
+            .close(ctx.accounts.owner.to_account_info())?;
+
+        emit!(UserAccountClosedEvent {
+            owner,
+            refunded,
+            rent_sponsored: sponsored,
+            seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+        });
 
-        // Log new values from MPC
-        msg!(
-            "DEBUG transfer_callback: sender new nonce={}, new credit[0..4]={:?}",
-            o.field_0.field_0.nonce,
-            &o.field_0.field_0.ciphertexts[0][0..4]
-        );
-        msg!(
-            "DEBUG transfer_callback: recipient new nonce={}, new credit[0..4]={:?}",
-            o.field_0.field_1.nonce,
-            &o.field_0.field_1.ciphertexts[0][0..4]
-        );
+        msg!("User account closed for {}", owner);
+        Ok(())
+    }
+
+    // =========================================================================
+    // EXECUTE BATCH (Phase 9)
+    // =========================================================================
+
+    /// Execute the current batch.
+    /// Reveals aggregate totals via MPC, then performs netting and swaps in callback.
+    /// Requires Pool.execution_trigger_count orders unless called by the operator,
+    /// and is limited to Pool.max_batches_per_window new batches per window.
+    ///
+    /// # Arguments
+    /// * `computation_offset` - Unique ID for MPC computation
+    pub fn execute_batch(ctx: Context<ExecuteBatch>, computation_offset: u64) -> Result<()> {
+        instructions::execute_batch::handler(ctx, computation_offset)
+    }
+
+    /// Reopen a batch slot after its reveal has been pending for
+    /// BATCH_REVEAL_TIMEOUT_SECS (aborted computation). execute_batch can then
+    /// be retried and reuses the existing BatchLog. Operator or authority only.
+    pub fn recover_batch_reveal(ctx: Context<RecoverBatchReveal>) -> Result<()> {
+        instructions::recover_batch_reveal::handler(ctx)
+    }
+
+    /// Re-queue reveal_batch for a batch whose reveal callback failed
+    /// verification, or whose reveal has been pending for
+    /// BATCH_REVEAL_TIMEOUT_SECS. Up to MAX_REVEAL_RETRIES per batch.
+    /// Same access as execute_batch.
+    ///
+    /// # Arguments
+    /// * `computation_offset` - Unique ID for the new MPC computation
+    pub fn retry_execute_batch(
+        ctx: Context<RetryExecuteBatch>,
+        computation_offset: u64,
+    ) -> Result<()> {
+        instructions::retry_execute_batch::handler(ctx, computation_offset)
+    }
+
+    /// Discard the batch of an accumulator slot left inconsistent by a failed
+    /// callback: its orders settle with a full refund, the slot reopens empty
+    /// (an active slot under a new batch_id) and init_batch_state is queued.
+    /// Only callable by pool authority.
+    ///
+    /// # Arguments
+    /// * `computation_offset` - Unique ID for the init_batch_state computation
+    /// * `reason` - BATCH_RESET_REASON_* code
+    pub fn reset_batch(
+        ctx: Context<ResetBatch>,
+        computation_offset: u64,
+        reason: u8,
+    ) -> Result<()> {
+        instructions::reset_batch::handler(ctx, computation_offset, reason)
+    }
+
+    /// Clear a user's pending order and in-flight flags left inconsistent by
+    /// a failed callback, without refunding. Only callable by an admin.
+    ///
+    /// # Arguments
+    /// * `reason` - CLEAR_PENDING_REASON_* code
+    pub fn admin_clear_pending(ctx: Context<AdminClearPending>, reason: u8) -> Result<()> {
+        instructions::admin_clear_pending::handler(ctx, reason)
+    }
+
+    /// Emergency flush of the active batch for incident response: no swaps
+    /// are executed and every order settles with a full refund through the
+    /// calculate_payout circuit. Uses the ResetBatch accounts; the slot
+    /// reopens under a new batch_id. Only callable by pool authority.
+    ///
+    /// # Arguments
+    /// * `computation_offset` - Unique ID for the init_batch_state computation
+    pub fn flush_batch(ctx: Context<ResetBatch>, computation_offset: u64) -> Result<()> {
+        instructions::flush_batch::handler(ctx, computation_offset)
+    }
+
+    /// Execute vault↔reserve swaps based on BatchLog netting results.
+    /// Called by backend after MPC callback completes.
+    ///
+    /// # Arguments
+    /// * `batch_id` - The batch ID to execute swaps for
+    pub fn execute_swaps(ctx: Context<ExecuteSwaps>, batch_id: u64) -> Result<()> {
+        instructions::execute_swaps::handler(ctx, batch_id)
+    }
+
+    /// Execute the vault↔reserve transfers of one pair, to resume a batch
+    /// whose execute_swaps failed on another pair. Uses the ExecuteSwaps
+    /// accounts; the batch counts as swapped once every pair is done.
+    ///
+    /// # Arguments
+    /// * `batch_id` - The batch ID to execute swaps for
+    /// * `pair_id` - Pair to transfer (0-5)
+    pub fn execute_pair_swaps(
+        ctx: Context<ExecuteSwaps>,
+        batch_id: u64,
+        pair_id: u8,
+    ) -> Result<()> {
+        instructions::execute_swaps::pair_handler(ctx, batch_id, pair_id)
+    }
+
+    /// Approve a revealed batch whose notional exceeds the approval
+    /// threshold, unblocking execute_swaps and route_surplus.
+    /// Only callable by the pool co-operator.
+    ///
+    /// # Arguments
+    /// * `batch_id` - The batch ID to approve
+    pub fn approve_batch(ctx: Context<ApproveBatch>, batch_id: u64) -> Result<()> {
+        instructions::approve_batch::handler(ctx, batch_id)
+    }
+
+    /// Accept a revealed batch whose execution prices moved outside their
+    /// pairs' price bands, unblocking execute_swaps and route_surplus.
+    /// Only callable by an admin or risk manager.
+    ///
+    /// # Arguments
+    /// * `batch_id` - The batch ID to accept
+    pub fn override_price_band(ctx: Context<OverridePriceBand>, batch_id: u64) -> Result<()> {
+        instructions::override_price_band::handler(ctx, batch_id)
+    }
+
+    /// Callback handler for reveal_batch computation.
+    /// Receives plaintext totals, nets them and writes the BatchLog; the
+    /// vault↔reserve transfers are left to execute_swaps, which keeps this
+    /// callback within its account limit. The steps live in
+    /// instructions/reveal_batch.rs.
+    #[arcium_callback(encrypted_ix = "reveal_batch")]
+    pub fn reveal_batch_callback(
+        ctx: Context<RevealBatchCallback>,
+        output: SignedComputationOutputs<RevealBatchOutput>,
+    ) -> Result<()> {
+        let cu_start = crate::profiling_start();
+
+        // For reveal() outputs, access the array via the output struct
+        let totals: [u64; 12] = match output.verify_output(
+            &ctx.accounts.cluster_account,
+            &ctx.accounts.computation_account,
+        ) {
+            Ok(RevealBatchOutput { field_0 }) => field_0,
+            Err(err) => {
+                msg!(
+                    "reveal_batch_callback verify_output failed: {:?}, computation={}",
+                    err,
+                    ctx.accounts.computation_account.key()
+                );
+                emit_callback_failed("reveal_batch", ctx.accounts.computation_account.key());
+
+                return instructions::reveal_batch::record_failed_reveal(ctx.accounts);
+            }
+        };
+
+        instructions::reveal_batch::check_batch_log(ctx.accounts)?;
+
+        let now = Clock::get()?.unix_timestamp;
+        let netting = instructions::reveal_batch::net_pairs(ctx.accounts, &totals, now);
+        instructions::reveal_batch::record_results(ctx.accounts, &netting, now)?;
+        instructions::reveal_batch::reset_revealed_slot(ctx.accounts, &netting)?;
+
+        crate::record_profile(
+            ctx.accounts.diagnostics.as_mut(),
+            crate::state::PROFILE_REVEAL_BATCH_CALLBACK,
+            cu_start,
+        );
+
+        Ok(())
+    }
+
+    // =========================================================================
+    // SETTLE ORDER (Phase 10)
+    // =========================================================================
+
+    /// Settle a pending order.
+    /// Calculates pro-rata payout based on batch results and user's order size.
+    /// May be signed by the owner or an active session key (Delegate).
+    ///
+    /// # Arguments
+    /// * `computation_offset` - Unique ID for MPC computation
+    /// * `pubkey` - User's x25519 public key
+    /// * `nonce` - Encryption nonce
+    /// * `pair_id` - Trading pair (0-5)
+    /// * `direction` - Order direction (0=A_to_B, 1=B_to_A)
+    pub fn settle_order(
+        ctx: Context<SettleOrder>,
+        computation_offset: u64,
+        pubkey: [u8; 32],
+        nonce: u128,
+        pair_id: u8,
+        direction: u8,
+    ) -> Result<()> {
+        instructions::settle_order::handler(
+            ctx,
+            computation_offset,
+            pubkey,
+            nonce,
+            pair_id,
+            direction,
+        )
+    }
+
+    /// Settle the pending order and stage the next one in a single signature.
+    /// The staged order is placed by place_staged_order after the settlement
+    /// callback has cleared pending_order. It must be encrypted for the
+    /// account's stored user_pubkey.
+    ///
+    /// # Arguments
+    /// * `computation_offset` - Unique ID for MPC computation
+    /// * `pubkey` - User's x25519 public key
+    /// * `nonce` - Encryption nonce
+    /// * `pair_id` - Trading pair of the settled order (0-5)
+    /// * `direction` - Direction of the settled order (0=A_to_B, 1=B_to_A)
+    /// * `next_order` - Encrypted next order (batch_id ignored)
+    /// * `next_source_asset_id` - Plaintext hint for which asset the next order sells
+    pub fn settle_and_place_order(
+        ctx: Context<SettleOrder>,
+        computation_offset: u64,
+        pubkey: [u8; 32],
+        nonce: u128,
+        pair_id: u8,
+        direction: u8,
+        next_order: OrderTicket,
+        next_source_asset_id: u8,
+    ) -> Result<()> {
+        instructions::settle_and_place_order::handler(
+            ctx,
+            computation_offset,
+            pubkey,
+            nonce,
+            pair_id,
+            direction,
+            next_order,
+            next_source_asset_id,
+        )
+    }
+
+    /// Place an order staged by settle_and_place_order into the current
+    /// batch. Permissionless crank; requires the previous order to be settled.
+    ///
+    /// # Arguments
+    /// * `computation_offset` - Unique ID for MPC computation
+    pub fn place_staged_order(
+        ctx: Context<PlaceStagedOrder>,
+        computation_offset: u64,
+    ) -> Result<()> {
+        instructions::place_staged_order::handler(ctx, computation_offset)
+    }
+
+    /// Set the caller's settlement currency preference (side PDA). While it
+    /// exists, settle_order stages a follow-up order converting a non-USDC
+    /// payout into USDC, placed by place_staged_order in the next batch.
+    ///
+    /// # Arguments
+    /// * `convert_to_usdc` - Encrypted flag for the account's user_pubkey
+    /// * `nonce` - Nonce used to encrypt the flag
+    pub fn set_settlement_preference(
+        ctx: Context<SetSettlementPreference>,
+        convert_to_usdc: [u8; 32],
+        nonce: u128,
+    ) -> Result<()> {
+        instructions::set_settlement_preference::handler(ctx, convert_to_usdc, nonce)
+    }
+
+    /// Remove the caller's settlement currency preference and reclaim rent.
+    pub fn clear_settlement_preference(ctx: Context<ClearSettlementPreference>) -> Result<()> {
+        instructions::clear_settlement_preference::handler(ctx)
+    }
+
+    /// Callback handler for calculate_payout computation.
+    /// Updates user balance with payout (and any partial-fill refund) and clears pending_order
+    /// (or, for settle_basket_leg, marks the basket leg settled).
+    #[arcium_callback(encrypted_ix = "calculate_payout")]
+    pub fn calculate_payout_callback(
+        ctx: Context<CalculatePayoutCallback>,
+        output: SignedComputationOutputs<CalculatePayoutOutput>,
+    ) -> Result<()> {
+        let cu_start = crate::profiling_start();
+
+        let o = match output.verify_output(
+            &ctx.accounts.cluster_account,
+            &ctx.accounts.computation_account,
+        ) {
+            Ok(output) => output,
+            Err(err) => {
+                msg!(
+                    "calculate_payout_callback verify_output failed: {:?}, computation={}",
+                    err,
+                    ctx.accounts.computation_account.key()
+                );
+                emit_callback_failed("calculate_payout", ctx.accounts.computation_account.key());
+                return Err(ErrorCode::AbortedComputation.into());
+            }
+        };
+
+        // For tuple output (Enc<Shared, UserBalance>, Enc<Shared, UserBalance>, u64, u64, bool,
+        // Enc<Shared, OrderInput>, Enc<Shared, UserBalance>):
+        // o.field_0 = wrapper for the tuple
+        // o.field_0.field_0 = output asset balance with payout (minus bounty) added
+        // o.field_0.field_1 = source asset balance with unfilled refund added
+        // o.field_0.field_2 = the revealed u64 payout
+        // o.field_0.field_3 = the revealed u64 withheld from the payout (forfeit_order)
+        // o.field_0.field_4 = whether the claimed route matched the encrypted order
+        // o.field_0.field_5 = follow-up order converting the payout into USDC
+        // o.field_0.field_6 = the credited payout (minus bounty) on its own
+
+        // Wrong route: leave balances and pending_order untouched
+        let route_ok: bool = o.field_0.field_4;
+        if !route_ok {
+            msg!("Settlement rejected: route does not match the order");
+            emit!(SettlementRejectedEvent {
+                user: ctx.accounts.user_account.owner,
+                batch_id: ctx.accounts.batch_log.batch_id,
+                error: ErrorCode::InvalidOrderRoute.info(),
+                seq: 0, // not sequenced: the transaction fails
+            });
+            return Err(ErrorCode::InvalidOrderRoute.into());
+        }
+
+        // A deposit or withdrawal replaced either balance while in flight:
+        // leave everything untouched so the order can be settled again
+        let output_asset_id = ctx.accounts.user_account.pending_asset_id;
+        let refund_asset_id = ctx.accounts.user_account.pending_refund_asset_id;
+        if ctx.accounts.user_account.get_nonce(output_asset_id)
+            != ctx.accounts.user_account.pending_payout_nonce
+            || ctx.accounts.user_account.get_nonce(refund_asset_id)
+                != ctx.accounts.user_account.pending_refund_nonce
+        {
+            msg!("Settlement rejected: balance changed while in flight");
+            emit!(SettlementRejectedEvent {
+                user: ctx.accounts.user_account.owner,
+                batch_id: ctx.accounts.batch_log.batch_id,
+                error: ErrorCode::PendingOperationStale.info(),
+                seq: 0, // not sequenced: the transaction fails
+            });
+            return Err(ErrorCode::PendingOperationStale.into());
+        }
+
+        // DEBUG: Try to log the revealed payout value
+        // Note: If this doesn't compile, comment it out
+        msg!(
+            "DEBUG calculate_payout: revealed payout = {}",
+            o.field_0.field_2
+        );
+
+        // Update output asset balance using o.field_0.field_0 (the encrypted UserBalance)
+        ctx.accounts
+            .user_account
+            .set_credit(output_asset_id, o.field_0.field_0.ciphertexts[0]);
+        ctx.accounts
+            .user_account
+            .set_nonce(output_asset_id, o.field_0.field_0.nonce);
+
+        // Update source asset balance (refund of any unfilled portion)
+        ctx.accounts
+            .user_account
+            .set_credit(refund_asset_id, o.field_0.field_1.ciphertexts[0]);
+        ctx.accounts
+            .user_account
+            .set_nonce(refund_asset_id, o.field_0.field_1.nonce);
+
+        // Record the fill in the user's order history before clearing it.
+        // Basket legs come from the BasketOrder instead of pending_order.
+        let ticket = match ctx.accounts.basket_order.as_ref() {
+            Some(basket) => basket.legs[basket.settling_leg as usize],
+            None => ctx
+                .accounts
+                .user_account
+                .pending_order
+                .ok_or(ErrorCode::NoPendingOrder)?,
+        };
+        ctx.accounts
+            .order_history
+            .push(crate::state::OrderHistoryEntry {
+                ticket,
+                encrypted_payout: o.field_0.field_6.ciphertexts[0],
+                payout_nonce: o.field_0.field_6.nonce,
+                settled_at: Clock::get()?.unix_timestamp,
+            });
+
+        // Clear pending_order (or the settled basket leg). A basket counts as
+        // one order in the BatchLog, released with its last leg.
+        let batch_id = ticket.batch_id;
+        let order_done = match ctx.accounts.basket_order.as_mut() {
+            Some(basket) => {
+                let leg = basket.settling_leg;
+                basket.settled_legs |= 1 << leg;
+                let done = basket.is_fully_settled();
+                if done {
+                    basket.status = state::BASKET_STATUS_EMPTY;
+                }
+                done
+            }
+            None => {
+                ctx.accounts.user_account.pending_order = None;
+                true
+            }
+        };
+        if order_done {
+            ctx.accounts.batch_log.unsettled_count =
+                ctx.accounts.batch_log.unsettled_count.saturating_sub(1);
+        }
+
+        // Good-til-batch order that didn't execute: the refund above restores
+        // its funds, and place_staged_order deducts them again next batch
+        if ctx.accounts.user_account.pending_rollover {
+            ctx.accounts.user_account.pending_rollover = false;
+            ctx.accounts.user_account.staged_order = Some(OrderTicket {
+                batch_id: 0,
+                ..ticket
+            });
+            ctx.accounts.user_account.staged_asset_id = refund_asset_id;
+            emit!(OrderRolledOverEvent {
+                user: ctx.accounts.user_account.owner,
+                batch_id,
+                valid_until_batch: ticket.valid_until_batch,
+                seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+            });
+        }
+
+        // Settlement currency preference: stage the USDC conversion of the
+        // payout (zero amount when the encrypted flag is off)
+        if ctx.accounts.user_account.pending_conversion {
+            ctx.accounts.user_account.pending_conversion = false;
+            let follow_up = &o.field_0.field_5;
+            ctx.accounts.user_account.staged_order = Some(OrderTicket {
+                batch_id: 0,
+                pair_id: follow_up.ciphertexts[0],
+                direction: follow_up.ciphertexts[1],
+                encrypted_amount: follow_up.ciphertexts[2],
+                order_nonce: follow_up.nonce,
+                valid_until_batch: 0,
+            });
+            ctx.accounts.user_account.staged_asset_id = output_asset_id;
+        }
+
+        // forfeit_order: move the withheld payout from the output vault to the
+        // treasury. force_settle: nothing is withheld; pay the cranker its
+        // share of the execution fee on the payout, out of Pool.fees_owed.
+        let withheld = o.field_0.field_3;
+        if let (Some(pool), Some(vault), Some(bounty_account), Some(token_program)) = (
+            ctx.accounts.pool.as_mut(),
+            ctx.accounts.payout_vault.as_ref(),
+            ctx.accounts.bounty_token_account.as_ref(),
+            ctx.accounts.token_program.as_ref(),
+        ) {
+            let output_asset = output_asset_id as usize;
+            let amount = if withheld > 0 {
+                withheld
+            } else {
+                instructions::force_settle::fee_bounty(
+                    &ctx.accounts.batch_log,
+                    refund_asset_id,
+                    output_asset_id,
+                    o.field_0.field_2,
+                )
+                .min(pool.fees_owed[output_asset])
+            };
+            if amount > 0 {
+                let pool_seeds = &[constants::POOL_SEED, &[pool.pool_id], &[pool.bump]];
+                let signer_seeds = &[&pool_seeds[..]];
+                let transfer_ctx = CpiContext::new_with_signer(
+                    token_program.to_account_info(),
+                    Transfer {
+                        from: vault.to_account_info(),
+                        to: bounty_account.to_account_info(),
+                        authority: pool.to_account_info(),
+                    },
+                    signer_seeds,
+                );
+                token::transfer(transfer_ctx, amount)?;
+
+                if withheld > 0 {
+                    emit!(PayoutForfeitedEvent {
+                        user: ctx.accounts.user_account.owner,
+                        batch_id,
+                        asset_id: output_asset_id,
+                        amount,
+                        seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+                    });
+                } else {
+                    // Same USDC valuation the fee was accrued at
+                    let unit_prices = pool.unit_prices(&ctx.accounts.batch_log.oracle_prices);
+                    let value = amount as u128 * unit_prices[output_asset] / unit_prices[0].max(1);
+                    pool.fees_owed[output_asset] -= amount;
+                    pool.fees_owed_usdc = pool
+                        .fees_owed_usdc
+                        .saturating_sub(value.min(u64::MAX as u128) as u64);
+                    emit!(SettlementBountyPaidEvent {
+                        user: ctx.accounts.user_account.owner,
+                        batch_id,
+                        recipient: bounty_account.owner,
+                        asset_id: output_asset_id,
+                        amount,
+                        seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+                    });
+                }
+            }
+        }
+
+        // settle_and_place_order, rollovers and conversions: signal the crank
+        // that the next order can go in
+        if let Some(staged) = ctx.accounts.user_account.staged_order {
+            emit!(StagedOrderReadyEvent {
+                user: ctx.accounts.user_account.owner,
+                source_asset_id: ctx.accounts.user_account.staged_asset_id,
+                order_nonce: staged.order_nonce.to_le_bytes(),
+                seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+            });
+        }
+
+        emit!(SettlementEvent {
+            user: ctx.accounts.user_account.owner,
+            batch_id,
+            encrypted_payout: o.field_0.field_6.ciphertexts[0],
+            nonce: o.field_0.field_6.nonce.to_le_bytes(),
+            revealed_payout: o.field_0.field_2,
+            seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+        });
+
+        msg!(
+            "Settlement callback: user={}, batch={}, payout={}",
+            ctx.accounts.user_account.owner,
+            batch_id,
+            o.field_0.field_2
+        );
+
+        crate::record_profile(
+            ctx.accounts.diagnostics.as_mut(),
+            crate::state::PROFILE_CALCULATE_PAYOUT_CALLBACK,
+            cu_start,
+        );
+
+        Ok(())
+    }
+
+    /// Settle another user's stale order after Pool.force_settle_grace_secs
+    /// have passed since batch execution. Permissionless.
+    /// The cranker supplies the route (pair_id, direction); MPC rejects it if
+    /// it doesn't match the encrypted order. On success the user is credited
+    /// the whole payout and the cranker receives FORCE_SETTLE_BOUNTY_SHARE_BPS
+    /// of the execution fee on it, paid out of Pool.fees_owed.
+    ///
+    /// # Arguments
+    /// * `computation_offset` - Unique ID for MPC computation
+    /// * `pair_id` - Trading pair of the order (0-5)
+    /// * `direction` - Order direction (0=A_to_B, 1=B_to_A)
+    pub fn force_settle(
+        ctx: Context<ForceSettle>,
+        computation_offset: u64,
+        pair_id: u8,
+        direction: u8,
+    ) -> Result<()> {
+        instructions::force_settle::handler(ctx, computation_offset, pair_id, direction)
+    }
+
+    /// Forfeit a user's unsettled payout to the treasury once
+    /// Pool.settlement_deadline_secs have passed since batch execution.
+    /// Permissionless. Uses the ForceSettle accounts with the treasury's
+    /// token account as bounty_token_account; the whole payout is withheld
+    /// while any unfilled refund is still credited to the user.
+    ///
+    /// # Arguments
+    /// * `computation_offset` - Unique ID for MPC computation
+    /// * `pair_id` - Trading pair of the order (0-5)
+    /// * `direction` - Order direction (0=A_to_B, 1=B_to_A)
+    pub fn forfeit_order(
+        ctx: Context<ForceSettle>,
+        computation_offset: u64,
+        pair_id: u8,
+        direction: u8,
+    ) -> Result<()> {
+        instructions::forfeit_order::handler(ctx, computation_offset, pair_id, direction)
+    }
+
+    /// Update the settlement deadline used by forfeit_order.
+    /// Only callable by pool authority.
+    ///
+    /// # Arguments
+    /// * `deadline_secs` - Seconds after batch execution (>= MIN_SETTLEMENT_DEADLINE_SECS)
+    pub fn set_settlement_deadline(
+        ctx: Context<SetSettlementDeadline>,
+        deadline_secs: i64,
+    ) -> Result<()> {
+        instructions::set_settlement_deadline::handler(ctx, deadline_secs)
+    }
+
+    /// Update the grace period before force_settle may settle a user's order.
+    /// Only callable by pool authority.
+    ///
+    /// # Arguments
+    /// * `grace_secs` - Seconds after batch execution (> 0, < settlement deadline)
+    pub fn set_force_settle_grace(
+        ctx: Context<SetSettlementDeadline>,
+        grace_secs: i64,
+    ) -> Result<()> {
+        instructions::set_force_settle_grace::handler(ctx, grace_secs)
+    }
+
+    /// Close a BatchLog whose orders are all settled or forfeited and whose
+    /// swaps have executed. Rent goes to the treasury. Operator or authority only.
+    ///
+    /// # Arguments
+    /// * `batch_id` - Batch whose log is closed
+    pub fn close_batch_log(ctx: Context<CloseBatchLog>, batch_id: u64) -> Result<()> {
+        instructions::close_batch_log::handler(ctx, batch_id)
+    }
+
+    /// Send the execution fees accrued for every asset to the treasury's
+    /// token accounts in one transaction. Permissionless.
+    pub fn sweep_fees(ctx: Context<SweepFees>) -> Result<()> {
+        instructions::sweep_fees::handler(ctx)
+    }
+
+    /// Close the caller's order history and reclaim its rent.
+    /// A fresh history is created automatically on the next settlement.
+    pub fn close_order_history(ctx: Context<CloseOrderHistory>) -> Result<()> {
+        instructions::close_order_history::handler(ctx)
+    }
+
+    // =========================================================================
+    // LIQUIDITY MANAGEMENT (Protocol Reserves)
+    // =========================================================================
+
+    /// Add liquidity to protocol reserves.
+    /// Only callable by pool authority.
+    ///
+    /// # Arguments
+    /// * `asset_id` - Asset to add (0=USDC, 1=TSLA, 2=SPY, 3=AAPL)
+    /// * `amount` - Amount to transfer to reserves
+    pub fn add_liquidity<'info>(
+        ctx: Context<'_, '_, '_, 'info, AddLiquidity<'info>>,
+        asset_id: u8,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::add_liquidity::handler(ctx, asset_id, amount)
+    }
+
+    /// Remove liquidity from protocol reserves.
+    /// Only callable by pool authority.
+    ///
+    /// # Arguments
+    /// * `asset_id` - Asset to remove (0=USDC, 1=TSLA, 2=SPY, 3=AAPL)
+    /// * `amount` - Amount to transfer from reserves
+    pub fn remove_liquidity<'info>(
+        ctx: Context<'_, '_, '_, 'info, RemoveLiquidity<'info>>,
+        asset_id: u8,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::remove_liquidity::handler(ctx, asset_id, amount)
+    }
+
+    // =========================================================================
+    // EMERGENCY ASSET FREEZE
+    // =========================================================================
+
+    /// Freeze a single asset (e.g. a compromised tokenized stock mint).
+    /// Deposits, orders and withdrawals involving the asset are blocked
+    /// while other assets keep working. Takes effect immediately.
+    /// Only callable by an admin or risk manager.
+    ///
+    /// # Arguments
+    /// * `asset_id` - Asset to freeze (0=USDC, 1=TSLA, 2=SPY, 3=AAPL)
+    pub fn freeze_asset(ctx: Context<FreezeAsset>, asset_id: u8) -> Result<()> {
+        instructions::freeze_asset::handler(ctx, asset_id)
+    }
+
+    /// Start the unfreeze timelock (ASSET_UNFREEZE_TIMELOCK_SECS) for a frozen asset.
+    /// Only callable by pool authority.
+    ///
+    /// # Arguments
+    /// * `asset_id` - Frozen asset to reopen
+    pub fn request_unfreeze_asset(ctx: Context<RequestUnfreezeAsset>, asset_id: u8) -> Result<()> {
+        instructions::request_unfreeze_asset::handler(ctx, asset_id)
+    }
+
+    /// Unfreeze an asset after its unfreeze timelock has elapsed.
+    /// Only callable by pool authority.
+    ///
+    /// # Arguments
+    /// * `asset_id` - Frozen asset to reopen
+    pub fn unfreeze_asset(ctx: Context<UnfreezeAsset>, asset_id: u8) -> Result<()> {
+        instructions::unfreeze_asset::handler(ctx, asset_id)
+    }
+
+    // =========================================================================
+    // ASSET DELISTING
+    // =========================================================================
+
+    /// Delist or relist an asset. A delisted asset accepts no new deposits
+    /// or orders and every pair touching it is halted, while withdrawals and
+    /// settlement of existing positions keep working. Takes effect immediately.
+    /// Only callable by pool authority.
+    ///
+    /// # Arguments
+    /// * `asset_id` - Asset to update (0=USDC, 1=TSLA, 2=SPY, 3=AAPL)
+    /// * `delisted` - true to delist, false to relist
+    pub fn set_asset_delisted(
+        ctx: Context<SetAssetDelisted>,
+        asset_id: u8,
+        delisted: bool,
+    ) -> Result<()> {
+        instructions::set_asset_delisted::handler(ctx, asset_id, delisted)
+    }
+
+    // =========================================================================
+    // RESTRICTED ASSETS
+    // =========================================================================
+
+    /// Register transfer restrictions for an asset backed by a restricted mint
+    /// (Token-2022 transfer hook and/or issuer allowlist). Afterwards
+    /// add_balance / sub_balance for the asset must pass the policy account.
+    /// Only callable by pool authority.
+    ///
+    /// # Arguments
+    /// * `asset_id` - Asset to restrict (0=USDC, 1=TSLA, 2=SPY, 3=AAPL)
+    /// * `allowlist_program` - Issuer allowlist program (Pubkey::default() = none)
+    pub fn configure_asset_policy(
+        ctx: Context<ConfigureAssetPolicy>,
+        asset_id: u8,
+        allowlist_program: Pubkey,
+    ) -> Result<()> {
+        instructions::configure_asset_policy::handler(ctx, asset_id, allowlist_program)
+    }
+
+    // =========================================================================
+    // ASSET REGISTRY
+    // =========================================================================
+
+    /// List a new tokenized stock in the AssetRegistry and create its deposit
+    /// vault (seeds ["vault", pool_id, mint]). Only callable by pool authority.
+    ///
+    /// # Arguments
+    /// * `decimals` - Expected mint decimals
+    /// * `symbol` - Ticker, 1-8 ASCII letters or digits
+    pub fn register_asset(ctx: Context<RegisterAsset>, decimals: u8, symbol: String) -> Result<()> {
+        instructions::register_asset::handler(ctx, decimals, symbol)
+    }
+
+    // =========================================================================
+    // VAULT AUDIT
+    // =========================================================================
+
+    /// Verify that every protocol token account (vaults, reserves, faucet) is
+    /// owned by the Pool PDA, holds the expected mint, and has no delegate or
+    /// close authority set. Emits a VaultAuditEvent attestation on success.
+    /// Permissionless - intended to be run after upgrades or key incidents.
+    pub fn audit_vaults(ctx: Context<AuditVaults>) -> Result<()> {
+        instructions::audit_vaults::handler(ctx)
+    }
+
+    /// Check cross-account invariants (accumulator slots and seeds, batch
+    /// lifecycle vs BatchLog, vault mints, monotonic counters) and emit an
+    /// InvariantReportEvent with a bitmask of violations. Does not fail on a
+    /// violation. Permissionless - intended to be run by monitoring after
+    /// every batch.
+    pub fn check_invariants(ctx: Context<CheckInvariants>) -> Result<()> {
+        instructions::check_invariants::handler(ctx)
+    }
+
+    // =========================================================================
+    // RESERVE AUCTION (Sealed-bid auction of excess reserve inventory)
+    // =========================================================================
+
+    /// Open a sealed-bid auction for a lot of reserve inventory.
+    /// Only callable by pool authority. Bids are accepted once the
+    /// init_auction_state callback marks the auction Open.
+    ///
+    /// # Arguments
+    /// * `computation_offset` - Unique ID for MPC computation
+    /// * `auction_id` - Auction identifier (PDA seed)
+    /// * `asset_id` - Asset to sell (1=TSLA, 2=SPY, 3=AAPL); bids are in USDC
+    /// * `lot_amount` - Amount of the asset being sold
+    /// * `reserve_price` - Minimum total USDC price for the lot
+    /// * `bid_deadline` - Unix timestamp after which bids are rejected
+    pub fn open_reserve_auction(
+        ctx: Context<OpenReserveAuction>,
+        computation_offset: u64,
+        auction_id: u64,
+        asset_id: u8,
+        lot_amount: u64,
+        reserve_price: u64,
+        bid_deadline: i64,
+    ) -> Result<()> {
+        instructions::open_reserve_auction::handler(
+            ctx,
+            computation_offset,
+            auction_id,
+            asset_id,
+            lot_amount,
+            reserve_price,
+            bid_deadline,
+        )
+    }
+
+    /// Callback: store encrypted zero auction state and open bidding.
+    #[arcium_callback(encrypted_ix = "init_auction_state")]
+    pub fn init_auction_state_callback(
+        ctx: Context<InitAuctionStateCallback>,
+        output: SignedComputationOutputs<InitAuctionStateOutput>,
+    ) -> Result<()> {
+        let o = match output.verify_output(
+            &ctx.accounts.cluster_account,
+            &ctx.accounts.computation_account,
+        ) {
+            Ok(output) => output,
+            Err(err) => {
+                msg!(
+                    "init_auction_state_callback verify_output failed: {:?}, computation={}",
+                    err,
+                    ctx.accounts.computation_account.key()
+                );
+                emit_callback_failed("init_auction_state", ctx.accounts.computation_account.key());
+                return Err(ErrorCode::AbortedComputation.into());
+            }
+        };
+
+        // MPC output is MXEEncryptedStruct with 2 ciphertexts (best_price, best_bidder)
+        let auction = &mut ctx.accounts.reserve_auction;
+        auction.encrypted_state = o.field_0.ciphertexts;
+        auction.mxe_nonce = o.field_0.nonce;
+        auction.status = crate::state::AuctionStatus::Open;
+
+        emit!(ReserveAuctionOpenedEvent {
+            auction_id: auction.auction_id,
+            asset_id: auction.asset_id,
+            lot_amount: auction.lot_amount,
+            reserve_price: auction.reserve_price,
+            bid_deadline: auction.bid_deadline,
+            seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+        });
+
+        msg!("Reserve auction {} open for bids", auction.auction_id);
+        Ok(())
+    }
+
+    /// Submit an encrypted bid to an open reserve auction.
+    /// The bid price is never revealed unless it wins.
+    ///
+    /// # Arguments
+    /// * `computation_offset` - Unique ID for MPC computation
+    /// * `encrypted_price` - Total USDC offered for the lot, encrypted
+    /// * `pubkey` - Bidder's x25519 public key
+    /// * `nonce` - Encryption nonce
+    pub fn submit_auction_bid(
+        ctx: Context<SubmitAuctionBid>,
+        computation_offset: u64,
+        encrypted_price: [u8; 32],
+        pubkey: [u8; 32],
+        nonce: u128,
+    ) -> Result<()> {
+        instructions::submit_auction_bid::handler(
+            ctx,
+            computation_offset,
+            encrypted_price,
+            pubkey,
+            nonce,
+        )
+    }
+
+    /// Callback: store the updated best-bid state.
+    #[arcium_callback(encrypted_ix = "submit_auction_bid")]
+    pub fn submit_auction_bid_callback(
+        ctx: Context<SubmitAuctionBidCallback>,
+        output: SignedComputationOutputs<SubmitAuctionBidOutput>,
+    ) -> Result<()> {
+        let o = match output.verify_output(
+            &ctx.accounts.cluster_account,
+            &ctx.accounts.computation_account,
+        ) {
+            Ok(output) => output,
+            Err(err) => {
+                msg!(
+                    "submit_auction_bid_callback verify_output failed: {:?}, computation={}",
+                    err,
+                    ctx.accounts.computation_account.key()
+                );
+                emit_callback_failed("submit_auction_bid", ctx.accounts.computation_account.key());
+                return Err(ErrorCode::AbortedComputation.into());
+            }
+        };
+
+        let auction = &mut ctx.accounts.reserve_auction;
+
+        // Ignore late results once the auction has been revealed
+        require!(
+            auction.status == crate::state::AuctionStatus::Open,
+            ErrorCode::InvalidAuctionStatus
+        );
+
+        auction.encrypted_state = o.field_0.ciphertexts;
+        auction.mxe_nonce = o.field_0.nonce;
+
+        emit!(AuctionBidPlacedEvent {
+            auction_id: auction.auction_id,
+            bid_count: auction.bid_count,
+            seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+        });
+
+        msg!(
+            "Auction bid folded: auction={}, bids={}",
+            auction.auction_id,
+            auction.bid_count
+        );
+        Ok(())
+    }
+
+    /// Reveal the clearing price and winner of a reserve auction.
+    /// Permissionless - callable once the bid deadline has passed.
+    ///
+    /// # Arguments
+    /// * `computation_offset` - Unique ID for MPC computation
+    pub fn reveal_reserve_auction(
+        ctx: Context<RevealReserveAuction>,
+        computation_offset: u64,
+    ) -> Result<()> {
+        instructions::reveal_reserve_auction::handler(ctx, computation_offset)
+    }
+
+    /// Callback: record the clearing price and winner (or NoWinner).
+    /// MPC output is (cleared, clearing_price, winner_index).
+    #[arcium_callback(encrypted_ix = "reveal_auction")]
+    pub fn reveal_auction_callback(
+        ctx: Context<RevealAuctionCallback>,
+        output: SignedComputationOutputs<RevealAuctionOutput>,
+    ) -> Result<()> {
+        let o = match output.verify_output(
+            &ctx.accounts.cluster_account,
+            &ctx.accounts.computation_account,
+        ) {
+            Ok(output) => output,
+            Err(err) => {
+                msg!(
+                    "reveal_auction_callback verify_output failed: {:?}, computation={}",
+                    err,
+                    ctx.accounts.computation_account.key()
+                );
+                emit_callback_failed("reveal_auction", ctx.accounts.computation_account.key());
+                return Err(ErrorCode::AbortedComputation.into());
+            }
+        };
+
+        let cleared: bool = o.field_0.field_0;
+        let clearing_price: u64 = o.field_0.field_1;
+        let winner_index: u64 = o.field_0.field_2;
+
+        let auction = &mut ctx.accounts.reserve_auction;
+
+        // A duplicate reveal may land after the first one
+        require!(
+            auction.status == crate::state::AuctionStatus::Open,
+            ErrorCode::InvalidAuctionStatus
+        );
+
+        if cleared && (winner_index as usize) < auction.bid_count as usize {
+            auction.status = crate::state::AuctionStatus::Cleared;
+            auction.clearing_price = clearing_price;
+            auction.winner = auction.bidders[winner_index as usize];
+        } else {
+            auction.status = crate::state::AuctionStatus::NoWinner;
+        }
+
+        emit!(ReserveAuctionClearedEvent {
+            auction_id: auction.auction_id,
+            cleared: auction.status == crate::state::AuctionStatus::Cleared,
+            clearing_price: auction.clearing_price,
+            winner: auction.winner,
+            seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+        });
+
+        msg!(
+            "Reserve auction {} revealed: cleared={}, price={}, winner={}",
+            auction.auction_id,
+            cleared,
+            auction.clearing_price,
+            auction.winner
+        );
+        Ok(())
+    }
+
+    /// Settle a cleared reserve auction: winner pays the clearing price
+    /// into the USDC reserve and receives the lot.
+    pub fn claim_reserve_auction(ctx: Context<ClaimReserveAuction>) -> Result<()> {
+        instructions::claim_reserve_auction::handler(ctx)
+    }
+
+    // =========================================================================
+    // DIAGNOSTICS (Compute profiling)
+    // =========================================================================
+
+    /// Enable/disable on-chain compute profiling and optionally reset counters.
+    /// When enabled, execute_batch, reveal_batch_callback, execute_swaps,
+    /// settle_order and calculate_payout_callback record invocation counts and
+    /// recent CU samples into the Diagnostics account (if passed).
+    /// Only callable by pool authority.
+    ///
+    /// # Arguments
+    /// * `enabled` - Whether profiled instructions should record metrics
+    /// * `reset` - Clear all counters and samples
+    pub fn configure_profiling(
+        ctx: Context<ConfigureProfiling>,
+        enabled: bool,
+        reset: bool,
+    ) -> Result<()> {
+        instructions::configure_profiling::handler(ctx, enabled, reset)
+    }
+
+    // =========================================================================
+    // MPC CALLBACK PRIORITY
+    // =========================================================================
+
+    /// Set the priority fee requested for MPC callback transactions.
+    /// Applied by place_order, execute_dca, place_staged_order, execute_batch,
+    /// settle_order, force_settle, forfeit_order, add_balance and sub_balance.
+    /// Only callable by pool authority.
+    ///
+    /// # Arguments
+    /// * `cu_price_micro` - Micro-lamports per CU (<= MAX_CALLBACK_CU_PRICE_MICRO)
+    pub fn set_callback_priority_fee(
+        ctx: Context<SetCallbackPriorityFee>,
+        cu_price_micro: u64,
+    ) -> Result<()> {
+        instructions::set_callback_priority_fee::handler(ctx, cu_price_micro)
+    }
+
+    // =========================================================================
+    // BATCH APPROVAL
+    // =========================================================================
+
+    /// Set the co-operator and the revealed notional (USDC base units) above
+    /// which a batch needs its approval before swaps. Only callable by pool
+    /// authority.
+    ///
+    /// # Arguments
+    /// * `co_operator` - Second operator (must differ from the operator)
+    /// * `threshold_usdc` - Notional threshold (0 = approval disabled)
+    pub fn set_batch_approval(
+        ctx: Context<SetBatchApproval>,
+        co_operator: Pubkey,
+        threshold_usdc: u64,
+    ) -> Result<()> {
+        instructions::set_batch_approval::handler(ctx, co_operator, threshold_usdc)
+    }
+
+    // =========================================================================
+    // EXECUTOR REGISTRY
+    // =========================================================================
+
+    /// Toggle registry-gated execution and set the minimum executor bond.
+    /// When gated, only bonded executors may call execute_batch and
+    /// execute_swaps. Only callable by pool authority.
+    ///
+    /// # Arguments
+    /// * `gated` - Restrict execution to bonded executors
+    /// * `min_bond_lamports` - Bond required to register
+    pub fn configure_executor_registry(
+        ctx: Context<ConfigureExecutorRegistry>,
+        gated: bool,
+        min_bond_lamports: u64,
+    ) -> Result<()> {
+        instructions::configure_executor_registry::handler(ctx, gated, min_bond_lamports)
+    }
+
+    /// Register the signer as an executor by posting a lamport bond.
+    ///
+    /// # Arguments
+    /// * `bond_lamports` - Bond to post (>= min_bond_lamports)
+    pub fn register_executor(ctx: Context<RegisterExecutor>, bond_lamports: u64) -> Result<()> {
+        instructions::register_executor::handler(ctx, bond_lamports)
+    }
+
+    /// Remove an executor and send its bond to the treasury.
+    /// Only callable by pool authority.
+    ///
+    /// # Arguments
+    /// * `executor` - Executor to slash
+    pub fn slash_executor(ctx: Context<SlashExecutor>, executor: Pubkey) -> Result<()> {
+        instructions::slash_executor::handler(ctx, executor)
+    }
+
+    // =========================================================================
+    // CIRCUIT REGISTRY
+    // =========================================================================
+
+    /// Record the circuit hash a computation definition now runs and bump its
+    /// version in the CircuitRegistry. Only callable by an admin.
+    ///
+    /// # Arguments
+    /// * `comp_def_offset` - comp_def_offset of the computation definition
+    /// * `hash` - Hash of the new circuit
+    pub fn upgrade_circuit(
+        ctx: Context<UpgradeCircuit>,
+        comp_def_offset: u32,
+        hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::upgrade_circuit::handler(ctx, comp_def_offset, hash)
+    }
+
+    // =========================================================================
+    // CONFIG SNAPSHOT
+    // =========================================================================
+
+    /// Snapshot every tunable Pool/registry parameter into the versioned
+    /// ConfigSnapshot PDA. Only callable by pool authority.
+    pub fn export_config(ctx: Context<ExportConfig>) -> Result<()> {
+        instructions::export_config::handler(ctx)
+    }
+
+    /// Validate and apply a config blob (e.g. a ConfigSnapshot from another
+    /// cluster). Only callable by pool authority.
+    ///
+    /// # Arguments
+    /// * `version` - CONFIG_VERSION the blob was exported with
+    /// * `config` - Parameters to apply
+    pub fn apply_config(
+        ctx: Context<ApplyConfig>,
+        version: u16,
+        config: ProtocolConfig,
+    ) -> Result<()> {
+        instructions::apply_config::handler(ctx, version, config)
+    }
+
+    // =========================================================================
+    // KEEPER REWARD
+    // =========================================================================
+
+    /// Set the keeper reward paid to the execute_batch caller, in basis
+    /// points of the batch notional. Paid in USDC from the execution fees
+    /// once the batch's swaps execute, capped at the fees the batch accrued.
+    /// Only callable by pool authority.
+    ///
+    /// # Arguments
+    /// * `reward_bps` - Basis points of batch notional (<= MAX_KEEPER_REWARD_BPS)
+    pub fn set_keeper_reward(ctx: Context<SetKeeperReward>, reward_bps: u16) -> Result<()> {
+        instructions::set_keeper_reward::handler(ctx, reward_bps)
+    }
+
+    // =========================================================================
+    // ORACLE CONFIG
+    // =========================================================================
+
+    /// Point one asset at a Pyth feed and, optionally, a Switchboard
+    /// fallback feed. Creates the OracleConfig on first call.
+    /// Only callable by pool authority.
+    ///
+    /// # Arguments
+    /// * `asset_id` - Asset to configure (0-3)
+    /// * `feed_pubkey` - Pyth feed ID (default = none)
+    /// * `fallback_feed` - Switchboard pull feed account (default = none)
+    pub fn set_oracle_feed(
+        ctx: Context<SetOracleFeed>,
+        asset_id: u8,
+        feed_pubkey: Pubkey,
+        fallback_feed: Pubkey,
+    ) -> Result<()> {
+        instructions::set_oracle_feed::handler(ctx, asset_id, feed_pubkey, fallback_feed)
+    }
+
+    /// Set the maximum age, confidence interval and Pyth/Switchboard
+    /// divergence execute_batch accepts. Only callable by an admin or risk manager.
+    ///
+    /// # Arguments
+    /// * `max_price_age_secs` - Maximum update age in seconds
+    /// * `max_price_conf_bps` - Maximum confidence interval in bps of the price
+    /// * `max_price_divergence_bps` - Maximum Pyth/Switchboard gap in bps
+    pub fn set_oracle_params(
+        ctx: Context<SetOracleParams>,
+        max_price_age_secs: i64,
+        max_price_conf_bps: u16,
+        max_price_divergence_bps: u16,
+    ) -> Result<()> {
+        instructions::set_oracle_params::handler(
+            ctx,
+            max_price_age_secs,
+            max_price_conf_bps,
+            max_price_divergence_bps,
+        )
+    }
+
+    // =========================================================================
+    // POOL ADMINISTRATION
+    // =========================================================================
+
+    /// Update the execution fee and batch trigger count set at initialize.
+    /// Only callable by pool authority.
+    ///
+    /// # Arguments
+    /// * `execution_fee_bps` - Fee on swaps in basis points (<= MAX_FEE_BPS)
+    /// * `execution_trigger_count` - Orders required for batch_ready (>= 1)
+    pub fn update_pool_config(
+        ctx: Context<UpdatePoolConfig>,
+        execution_fee_bps: u16,
+        execution_trigger_count: u8,
+    ) -> Result<()> {
+        instructions::update_pool_config::handler(ctx, execution_fee_bps, execution_trigger_count)
+    }
+
+    /// Replace the operator wallet that executes batches.
+    /// Only callable by pool authority.
+    ///
+    /// # Arguments
+    /// * `operator` - New operator wallet
+    pub fn set_operator(ctx: Context<SetOperator>, operator: Pubkey) -> Result<()> {
+        instructions::set_operator::handler(ctx, operator)
+    }
+
+    /// Replace the treasury wallet that receives fees.
+    /// Only callable by pool authority.
+    ///
+    /// # Arguments
+    /// * `treasury` - New treasury wallet
+    pub fn set_treasury(ctx: Context<SetTreasury>, treasury: Pubkey) -> Result<()> {
+        instructions::set_treasury::handler(ctx, treasury)
+    }
+
+    /// Propose a new pool authority. The transfer only takes effect once
+    /// the proposed wallet calls accept_authority.
+    /// Only callable by pool authority.
+    ///
+    /// # Arguments
+    /// * `new_authority` - Wallet that must accept (default = cancel)
+    pub fn propose_authority(ctx: Context<ProposeAuthority>, new_authority: Pubkey) -> Result<()> {
+        instructions::propose_authority::handler(ctx, new_authority)
+    }
+
+    /// Become the pool authority. Only callable by the wallet proposed with
+    /// propose_authority.
+    pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+        instructions::accept_authority::handler(ctx)
+    }
+
+    // =========================================================================
+    // ROLES & PAUSE
+    // =========================================================================
+
+    /// Create the Roles PDA that lets several wallets hold the admin,
+    /// operator, pauser and risk roles. Only callable by pool authority.
+    pub fn init_roles(ctx: Context<InitRoles>) -> Result<()> {
+        instructions::init_roles::handler(ctx)
+    }
+
+    /// Grant a role to a wallet. Only callable by an admin.
+    ///
+    /// # Arguments
+    /// * `role` - ROLE_ADMIN, ROLE_OPERATOR, ROLE_PAUSER or ROLE_RISK
+    /// * `member` - Wallet to grant the role to
+    pub fn grant_role(ctx: Context<GrantRole>, role: u8, member: Pubkey) -> Result<()> {
+        instructions::grant_role::handler(ctx, role, member)
+    }
+
+    /// Revoke a granted role. Only callable by an admin.
+    ///
+    /// # Arguments
+    /// * `role` - ROLE_ADMIN, ROLE_OPERATOR, ROLE_PAUSER or ROLE_RISK
+    /// * `member` - Wallet to revoke the role from
+    pub fn revoke_role(ctx: Context<RevokeRole>, role: u8, member: Pubkey) -> Result<()> {
+        instructions::revoke_role::handler(ctx, role, member)
+    }
+
+    /// Halt deposits, orders, withdrawals, transfers, streams, vouchers and
+    /// batch execution (see instructions/pause_protocol.rs for the full
+    /// list). Only callable by a pauser or admin.
+    pub fn pause_protocol(ctx: Context<PauseProtocol>) -> Result<()> {
+        instructions::pause_protocol::handler(ctx)
+    }
+
+    /// Resume a paused protocol. Only callable by an admin.
+    pub fn unpause_protocol(ctx: Context<UnpauseProtocol>) -> Result<()> {
+        instructions::unpause_protocol::handler(ctx)
+    }
+
+    /// Keep withdrawals (sub_balance) open while the protocol is paused.
+    /// Only callable by an admin.
+    ///
+    /// # Arguments
+    /// * `enabled` - Whether sub_balance stays available while paused
+    pub fn set_emergency_withdraw(ctx: Context<SetEmergencyWithdraw>, enabled: bool) -> Result<()> {
+        instructions::set_emergency_withdraw::handler(ctx, enabled)
+    }
+
+    // =========================================================================
+    // BATCH CONFIGURATION
+    // =========================================================================
+
+    /// Update the batch trigger thresholds used by the accumulate circuits.
+    /// Only callable by pool authority.
+    ///
+    /// # Arguments
+    /// * `execution_trigger_count` - Orders required for batch_ready (>= 1)
+    /// * `min_active_pairs` - Active pairs required for batch_ready (1-6)
+    pub fn update_batch_config(
+        ctx: Context<UpdateBatchConfig>,
+        execution_trigger_count: u8,
+        min_active_pairs: u8,
+    ) -> Result<()> {
+        instructions::update_batch_config::handler(ctx, execution_trigger_count, min_active_pairs)
+    }
+
+    /// Limit how many new batches execute_batch may queue per window. A batch
+    /// open for max_batch_wait_secs may execute regardless.
+    /// Only callable by an admin or risk manager.
+    ///
+    /// # Arguments
+    /// * `max_batches_per_window` - Batches per window (0 = unlimited)
+    /// * `window_secs` - Window length (<= MAX_BATCH_WINDOW_SECS)
+    /// * `max_batch_wait_secs` - Deadline override (0 = none)
+    pub fn set_batch_rate_limit(
+        ctx: Context<SetBatchRateLimit>,
+        max_batches_per_window: u16,
+        window_secs: i64,
+        max_batch_wait_secs: i64,
+    ) -> Result<()> {
+        instructions::set_batch_rate_limit::handler(
+            ctx,
+            max_batches_per_window,
+            window_secs,
+            max_batch_wait_secs,
+        )
+    }
+
+    /// Require at least min_unique_users distinct wallets in a batch before
+    /// execute_batch reveals it. Applies to the operator as well.
+    /// Only callable by an admin or risk manager.
+    ///
+    /// # Arguments
+    /// * `min_unique_users` - Distinct wallets per batch (0 = off)
+    pub fn set_privacy_floor(ctx: Context<SetPrivacyFloor>, min_unique_users: u8) -> Result<()> {
+        instructions::set_privacy_floor::handler(ctx, min_unique_users)
+    }
+
+    /// Set the global risk limits: batch notional needing approval,
+    /// per-wallet daily withdrawal caps and reserve utilization per batch.
+    /// Only callable by an admin or risk manager.
+    ///
+    /// # Arguments
+    /// * `max_batch_notional_usdc` - Batch notional needing approval (0 = no limit)
+    /// * `max_daily_withdrawal` - Per-wallet cap per asset and window (0 = no cap)
+    /// * `max_reserve_utilization_bps` - Reserve share paid out per batch (1-10000)
+    pub fn set_risk_config(
+        ctx: Context<SetRiskConfig>,
+        max_batch_notional_usdc: u64,
+        max_daily_withdrawal: [u64; 4],
+        max_reserve_utilization_bps: u16,
+    ) -> Result<()> {
+        instructions::set_risk_config::handler(
+            ctx,
+            max_batch_notional_usdc,
+            max_daily_withdrawal,
+            max_reserve_utilization_bps,
+        )
+    }
+
+    // =========================================================================
+    // EXECUTION MODE
+    // =========================================================================
+
+    /// Configure how net surpluses are filled: default, reserve-only (oracle
+    /// price minus a spread, never routed externally) or external-only
+    /// (reserves never used, surplus routed with route_surplus).
+    /// Only callable by pool authority.
+    ///
+    /// # Arguments
+    /// * `execution_mode` - EXECUTION_MODE_* constant
+    /// * `spread_bps` - Spread on absorbed surplus (<= MAX_RESERVE_SPREAD_BPS)
+    /// * `max_utilization_bps` - Max share of each reserve paid out per batch
+    /// * `max_inventory` - Max balance per reserve (0 = no limit)
+    /// * `swap_program` - Swap adapter used by route_surplus
+    /// * `netting_window_secs` - Cross-batch netting window (0 = disabled)
+    pub fn set_execution_mode(
+        ctx: Context<SetExecutionMode>,
+        execution_mode: u8,
+        spread_bps: u16,
+        max_utilization_bps: u16,
+        max_inventory: [u64; 4],
+        swap_program: Pubkey,
+        netting_window_secs: i64,
+    ) -> Result<()> {
+        instructions::set_execution_mode::handler(
+            ctx,
+            execution_mode,
+            spread_bps,
+            max_utilization_bps,
+            max_inventory,
+            swap_program,
+            netting_window_secs,
+        )
+    }
+
+    /// Set the worst slippage accepted on external swaps. Default-mode
+    /// reserve fills are priced at it and routed surplus must return at
+    /// least its oracle value minus it. Only callable by an admin or risk manager.
+    ///
+    /// # Arguments
+    /// * `max_slippage_bps` - Slippage in basis points (<= MAX_EXTERNAL_SWAP_SLIPPAGE_BPS)
+    pub fn set_max_slippage(ctx: Context<SetMaxSlippage>, max_slippage_bps: u16) -> Result<()> {
+        instructions::set_max_slippage::handler(ctx, max_slippage_bps)
+    }
+
+    /// Swap one pair's net surplus on the external venue and record the
+    /// realized output for settlement (external-only mode).
+    /// Only callable by the operator.
+    ///
+    /// # Arguments
+    /// * `batch_id` - Executed batch
+    /// * `pair_id` - Pair to route (0-5)
+    /// * `min_amount_out` - Minimum venue output (slippage protection)
+    pub fn route_surplus(
+        ctx: Context<RouteSurplus>,
+        batch_id: u64,
+        pair_id: u8,
+        min_amount_out: u64,
+    ) -> Result<()> {
+        instructions::route_surplus::handler(ctx, batch_id, pair_id, min_amount_out)
+    }
+
+    /// Swap one pair's pending surplus through a Jupiter v6 route built
+    /// off-chain, enforcing the route amount, slippage and minimum output.
+    /// Route accounts are passed as remaining_accounts.
+    /// Only callable by the operator.
+    ///
+    /// # Arguments
+    /// * `batch_id` - Executed batch
+    /// * `pair_id` - Pair to route (0-5)
+    /// * `route_data` - Jupiter `route` / `shared_accounts_route` instruction data
+    /// * `slippage_bps` - Slippage accepted on the quoted output (≤ the batch's max_slippage_bps)
+    /// * `min_amount_out` - Minimum output (slippage protection)
+    pub fn execute_external_swap<'info>(
+        ctx: Context<'_, '_, '_, 'info, ExecuteExternalSwap<'info>>,
+        batch_id: u64,
+        pair_id: u8,
+        route_data: Vec<u8>,
+        slippage_bps: u16,
+        min_amount_out: u64,
+    ) -> Result<()> {
+        instructions::execute_external_swap::handler(
+            ctx,
+            batch_id,
+            pair_id,
+            route_data,
+            slippage_bps,
+            min_amount_out,
+        )
+    }
+
+    /// Set the trading hours of the equity assets and whether execute_batch
+    /// enforces them. Only callable by pool authority.
+    ///
+    /// # Arguments
+    /// * `enforced` - Whether execute_batch follows the schedule
+    /// * `open_secs` - Market open, seconds after UTC midnight
+    /// * `close_secs` - Market close, seconds after UTC midnight
+    /// * `trading_days` - Days the market opens (bit 0 = Monday)
+    /// * `holidays` - Closed UTC days (unix_timestamp / 86400)
+    /// * `equity_assets` - Assets that follow the schedule (bit N = asset_id N)
+    pub fn set_market_schedule(
+        ctx: Context<SetMarketSchedule>,
+        enforced: bool,
+        open_secs: u32,
+        close_secs: u32,
+        trading_days: u8,
+        holidays: Vec<u32>,
+        equity_assets: u8,
+    ) -> Result<()> {
+        instructions::set_market_schedule::handler(
+            ctx,
+            enforced,
+            open_secs,
+            close_secs,
+            trading_days,
+            holidays,
+            equity_assets,
+        )
+    }
+
+    /// Override the execution mode for one pair: reserves first up to a
+    /// share of the surplus then external, or best of reserve price and
+    /// venue quote. Only callable by pool authority.
+    ///
+    /// # Arguments
+    /// * `pair_id` - Pair to configure (0-5)
+    /// * `strategy` - POLICY_* constant
+    /// * `reserve_share_bps` - Share of the surplus offered to reserves
+    pub fn set_pair_policy(
+        ctx: Context<SetPairPolicy>,
+        pair_id: u8,
+        strategy: u8,
+        reserve_share_bps: u16,
+    ) -> Result<()> {
+        instructions::set_pair_policy::handler(ctx, pair_id, strategy, reserve_share_bps)
+    }
+
+    /// Halt or resume one pair and set its execution fee. A halted pair's
+    /// orders are refunded in full from the next batch executed.
+    /// Only callable by pool authority.
+    ///
+    /// # Arguments
+    /// * `pair_id` - Pair to configure (0-5)
+    /// * `enabled` - Whether the pair's orders execute
+    /// * `fee_bps` - Execution fee (<= MAX_FEE_BPS, or u16::MAX for the pool's)
+    pub fn configure_pair(
+        ctx: Context<ConfigurePair>,
+        pair_id: u8,
+        enabled: bool,
+        fee_bps: u16,
+    ) -> Result<()> {
+        instructions::configure_pair::handler(ctx, pair_id, enabled, fee_bps)
+    }
+
+    /// Set how far one pair's execution price may move from its last
+    /// accepted price before a batch needs override_price_band.
+    /// Only callable by an admin or risk manager.
+    ///
+    /// # Arguments
+    /// * `pair_id` - Pair to configure (0-5)
+    /// * `max_deviation_bps` - Largest move in bps (0 = no band)
+    pub fn set_price_band(
+        ctx: Context<SetPriceBand>,
+        pair_id: u8,
+        max_deviation_bps: u16,
+    ) -> Result<()> {
+        instructions::set_price_band::handler(ctx, pair_id, max_deviation_bps)
+    }
+
+    /// Sell what is left in a pair's netting buffer once the netting window
+    /// has elapsed. Only callable by the operator.
+    ///
+    /// # Arguments
+    /// * `pair_id` - Pair whose buffer is flushed (0-5)
+    /// * `min_amount_out` - Minimum venue output (slippage protection)
+    pub fn flush_netting_buffer(
+        ctx: Context<FlushNettingBuffer>,
+        pair_id: u8,
+        min_amount_out: u64,
+    ) -> Result<()> {
+        instructions::flush_netting_buffer::handler(ctx, pair_id, min_amount_out)
+    }
+
+    // =========================================================================
+    // TREASURY BUYBACK
+    // =========================================================================
+
+    /// Create or update the buyback-and-burn configuration.
+    /// Only callable by pool authority.
+    ///
+    /// # Arguments
+    /// * `enabled` - Whether buybacks may be executed
+    /// * `swap_program` - External swap program used to buy the token
+    /// * `fee_share_bps` - Share of collected USDC fees allocated to buybacks
+    /// * `min_interval_secs` - Minimum seconds between two buybacks
+    /// * `max_usdc_per_execution` - Cap on USDC spent per buyback
+    pub fn configure_buyback(
+        ctx: Context<ConfigureBuyback>,
+        enabled: bool,
+        swap_program: Pubkey,
+        fee_share_bps: u16,
+        min_interval_secs: i64,
+        max_usdc_per_execution: u64,
+    ) -> Result<()> {
+        instructions::configure_buyback::handler(
+            ctx,
+            enabled,
+            swap_program,
+            fee_share_bps,
+            min_interval_secs,
+            max_usdc_per_execution,
+        )
+    }
+
+    /// Swap a share of fee USDC into the buyback token and burn it.
+    /// Only callable by pool operator, subject to the configured schedule and caps.
+    ///
+    /// # Arguments
+    /// * `usdc_amount` - USDC to spend (base units)
+    /// * `min_amount_out` - Minimum buyback tokens to receive (slippage protection)
+    pub fn execute_buyback(
+        ctx: Context<ExecuteBuyback>,
+        usdc_amount: u64,
+        min_amount_out: u64,
+    ) -> Result<()> {
+        instructions::execute_buyback::handler(ctx, usdc_amount, min_amount_out)
+    }
+
+    // =========================================================================
+    // FAUCET (Devnet only)
+    // =========================================================================
+
+    /// Claim USDC from the devnet faucet.
+    /// Each user can claim up to 1000 USDC total.
+    ///
+    /// # Arguments
+    /// * `amount` - Amount of USDC to claim (in base units, 6 decimals)
+    pub fn faucet(ctx: Context<Faucet>, amount: u64) -> Result<()> {
+        instructions::faucet::handler(ctx, amount)
+    }
+
+    // =========================================================================
+    // ARCIUM MPC SETUP (Demo - from scaffolding)
+    // =========================================================================
+
+    pub fn init_add_together_comp_def(ctx: Context<InitAddTogetherCompDef>) -> Result<()> {
+        init_comp_def(
+            ctx.accounts,
+            Some(CircuitSource::OffChain(OffChainCircuitSource {
+                source: "https://gateway.pinata.cloud/ipfs/QmQ4Jd2KEQZXPzE5xgXGQTz8BjtF4BHemSsjXWaE3QTuGT".to_string(),
+                hash: circuit_hash!("add_together"),
+            })),
+            None,
+        )?;
+        Ok(())
+    }
+
+    // =========================================================================
+    // ARCIUM MPC SETUP - Add Balance (Phase 6)
+    // =========================================================================
+
+    /// Initialize the add_balance computation definition.
+    /// This must be called once before any encrypted deposits can be processed.
+    pub fn init_add_balance_comp_def(ctx: Context<InitAddBalanceCompDef>) -> Result<()> {
+        init_comp_def(
+            ctx.accounts,
+            Some(CircuitSource::OffChain(OffChainCircuitSource {
+                source: "https://gateway.pinata.cloud/ipfs/QmdbkwigmEYcXPaDGdFJYhVKGC2c1WDfznBBxt8Rc1vZmM".to_string(),
+                hash: circuit_hash!("add_balance"),
+            })),
+            None,
+        )?;
+        Ok(())
+    }
+
+    /// Initialize the accumulate_order computation definition (Phase 8).
+    /// This must be called once before orders can be placed.
+    pub fn init_accumulate_order_comp_def(ctx: Context<InitAccumulateOrderCompDef>) -> Result<()> {
+        init_comp_def(
+            ctx.accounts,
+            Some(CircuitSource::OffChain(OffChainCircuitSource {
+                source: "https://gateway.pinata.cloud/ipfs/QmbgiSK9qUxVB9SWK21wQxNyMF9bhMzCM9CJLbVsGRAhWx".to_string(),
+                hash: circuit_hash!("accumulate_order"),
+            })),
+            None,
+        )?;
+        Ok(())
+    }
+
+    /// Initialize the init_batch_state computation definition (Phase 8).
+    /// This must be called once for batch initialization.
+    pub fn init_init_batch_state_comp_def(ctx: Context<InitInitBatchStateCompDef>) -> Result<()> {
+        init_comp_def(
+            ctx.accounts,
+            Some(CircuitSource::OffChain(OffChainCircuitSource {
+                source: "https://gateway.pinata.cloud/ipfs/QmbBzp7G3o2KqGPFdzjB5Y7ioujpvR5TT54bpLsoo7QZv7".to_string(),
+                hash: circuit_hash!("init_batch_state"),
+            })),
+            None,
+        )?;
+        Ok(())
+    }
+
+    /// Initialize the reveal_batch computation definition (Phase 9).
+    /// This must be called once before batch execution.
+    pub fn init_reveal_batch_comp_def(ctx: Context<InitRevealBatchCompDef>) -> Result<()> {
+        init_comp_def(
+            ctx.accounts,
+            Some(CircuitSource::OffChain(OffChainCircuitSource {
+                source: "https://gateway.pinata.cloud/ipfs/Qmc311AdUo1eE7Pm8F8ctDEfX5FJ2SQ4ATDvJi4YXMjmQ8".to_string(),
+                hash: circuit_hash!("reveal_batch"),
+            })),
+            None,
+        )?;
+        Ok(())
+    }
+
+    /// Initialize the calculate_payout computation definition (Phase 10).
+    /// This must be called once before settlements can be processed.
+    pub fn init_calculate_payout_comp_def(ctx: Context<InitCalculatePayoutCompDef>) -> Result<()> {
+        init_comp_def(
+            ctx.accounts,
+            Some(CircuitSource::OffChain(OffChainCircuitSource {
+                source: "https://gateway.pinata.cloud/ipfs/QmT8bDc6mba5H3bpAJrtDFBYnSTKLKoMFxhm6TmnMNHSnA".to_string(),
+                hash: circuit_hash!("calculate_payout"),
+            })),
+            None,
+        )?;
+        Ok(())
+    }
+
+    /// Initialize the init_auction_state computation definition.
+    /// This must be called once before reserve auctions can be opened.
+    pub fn init_init_auction_state_comp_def(
+        ctx: Context<InitInitAuctionStateCompDef>,
+    ) -> Result<()> {
+        init_comp_def(ctx.accounts, None, None)?;
+        Ok(())
+    }
+
+    /// Initialize the submit_auction_bid computation definition.
+    /// This must be called once before auction bids can be submitted.
+    pub fn init_submit_auction_bid_comp_def(
+        ctx: Context<InitSubmitAuctionBidCompDef>,
+    ) -> Result<()> {
+        init_comp_def(ctx.accounts, None, None)?;
+        Ok(())
+    }
+
+    /// Initialize the reveal_auction computation definition.
+    /// This must be called once before auctions can be revealed.
+    pub fn init_reveal_auction_comp_def(ctx: Context<InitRevealAuctionCompDef>) -> Result<()> {
+        init_comp_def(ctx.accounts, None, None)?;
+        Ok(())
+    }
+
+    /// Initialize the sweep_balance computation definition.
+    /// This must be called once before beneficiaries can claim.
+    pub fn init_sweep_balance_comp_def(ctx: Context<InitSweepBalanceCompDef>) -> Result<()> {
+        init_comp_def(ctx.accounts, None, None)?;
+        Ok(())
+    }
+
+    /// Initialize the reencrypt_balance computation definition.
+    /// This must be called once before viewable balances can be refreshed.
+    pub fn init_reencrypt_balance_comp_def(
+        ctx: Context<InitReencryptBalanceCompDef>,
+    ) -> Result<()> {
+        init_comp_def(ctx.accounts, None, None)?;
+        Ok(())
+    }
+
+    /// Initialize the rotate_user_key computation definition.
+    /// This must be called once before user keys can be rotated.
+    pub fn init_rotate_user_key_comp_def(ctx: Context<InitRotateUserKeyCompDef>) -> Result<()> {
+        init_comp_def(ctx.accounts, None, None)?;
+        Ok(())
+    }
+
+    /// Initialize the check_balances_zero computation definition.
+    /// This must be called once before user accounts can be closed.
+    pub fn init_check_balances_zero_comp_def(
+        ctx: Context<InitCheckBalancesZeroCompDef>,
+    ) -> Result<()> {
+        init_comp_def(ctx.accounts, None, None)?;
+        Ok(())
+    }
+
+    /// Initialize the disclose_balances computation definition.
+    /// This must be called once before viewers can be added.
+    pub fn init_disclose_balances_comp_def(
+        ctx: Context<InitDiscloseBalancesCompDef>,
+    ) -> Result<()> {
+        init_comp_def(ctx.accounts, None, None)?;
+        Ok(())
+    }
+
+    /// Initialize the accumulate_basket computation definition.
+    /// This must be called once before basket orders can be placed.
+    pub fn init_accumulate_basket_comp_def(
+        ctx: Context<InitAccumulateBasketCompDef>,
+    ) -> Result<()> {
+        init_comp_def(ctx.accounts, None, None)?;
+        Ok(())
+    }
+
+    /// Initialize the accumulate_order_usd computation definition.
+    /// This must be called once before USD-sized orders can be placed.
+    pub fn init_accumulate_order_usd_comp_def(
+        ctx: Context<InitAccumulateOrderUsdCompDef>,
+    ) -> Result<()> {
+        init_comp_def(ctx.accounts, None, None)?;
+        Ok(())
+    }
+
+    /// Initialize the accumulate_order_delegated computation definition.
+    /// This must be called once before delegates can place orders against
+    /// an allowance.
+    pub fn init_accumulate_order_delegated_comp_def(
+        ctx: Context<InitAccumulateOrderDelegatedCompDef>,
+    ) -> Result<()> {
+        init_comp_def(ctx.accounts, None, None)?;
+        Ok(())
+    }
+
+    /// Initialize the sub_balance_delegated computation definition.
+    /// This must be called once before delegates can withdraw against an
+    /// allowance.
+    pub fn init_sub_balance_delegated_comp_def(
+        ctx: Context<InitSubBalanceDelegatedCompDef>,
+    ) -> Result<()> {
+        init_comp_def(ctx.accounts, None, None)?;
+        Ok(())
+    }
+
+    /// Initialize the move_savings computation definition.
+    /// This must be called once before users can move USDC into or out of
+    /// savings buckets.
+    pub fn init_move_savings_comp_def(ctx: Context<InitMoveSavingsCompDef>) -> Result<()> {
+        init_comp_def(ctx.accounts, None, None)?;
+        Ok(())
+    }
+
+    pub fn init_stream_transfer_comp_def(ctx: Context<InitStreamTransferCompDef>) -> Result<()> {
+        init_comp_def(ctx.accounts, None, None)?;
+        Ok(())
+    }
+
+    pub fn init_fund_voucher_comp_def(ctx: Context<InitFundVoucherCompDef>) -> Result<()> {
+        init_comp_def(ctx.accounts, None, None)?;
+        Ok(())
+    }
+
+    pub fn init_redeem_voucher_comp_def(ctx: Context<InitRedeemVoucherCompDef>) -> Result<()> {
+        init_comp_def(ctx.accounts, None, None)?;
+        Ok(())
+    }
+
+    pub fn init_recover_account_comp_def(ctx: Context<InitRecoverAccountCompDef>) -> Result<()> {
+        init_comp_def(ctx.accounts, None, None)?;
+        Ok(())
+    }
+
+    // =========================================================================
+    // BOOTSTRAP - Deployment progress
+    // =========================================================================
+
+    /// Verify which comp defs and batch slots are initialized and record them
+    /// in Pool.bootstrap_flags. Comp def accounts are passed as remaining
+    /// accounts in BOOTSTRAP_COMP_DEFS order. Permissionless.
+    ///
+    /// # Arguments
+    /// * `first_comp_def` - Index into BOOTSTRAP_COMP_DEFS of the first remaining account
+    pub fn bootstrap<'info>(
+        ctx: Context<'_, '_, '_, 'info, Bootstrap<'info>>,
+        first_comp_def: u8,
+    ) -> Result<()> {
+        instructions::bootstrap::handler(ctx, first_comp_def)
+    }
+
+    // =========================================================================
+    // INIT_BATCH_STATE - Initialize batch accumulator with encrypted zeros
+    // =========================================================================
+    // This MUST be called after initBatchAccumulator and before any orders.
+    // The MPC generates properly encrypted zeros that can be decrypted later.
+
+    /// Queue MPC to generate encrypted zeros for the batch accumulator.
+    /// Called once after batch accumulator creation, or after a reset_batch
+    /// whose re-encryption never landed (state_initialized unset).
+    /// Only callable by pool authority.
+    pub fn init_batch_state(ctx: Context<InitBatchState>, computation_offset: u64) -> Result<()> {
+        // Re-encrypting a live slot goes through reset_batch
+        require!(
+            !ctx.accounts.batch_accumulator.state_initialized,
+            ErrorCode::BatchStateAlreadyInitialized
+        );
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+        // init_batch_state takes `mxe: Mxe` argument
+        // The Mxe type compiles to a struct with a u128 nonce field
+        let args = ArgBuilder::new()
+            .plaintext_u128(0) // Mxe nonce placeholder
+            .build();
+
+        use arcium_client::idl::arcium::types::CallbackAccount;
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            vec![InitBatchStateCallback::callback_ix(
+                computation_offset,
+                &ctx.accounts.mxe_account,
+                &[
+                    CallbackAccount {
+                        pubkey: ctx.accounts.pool.key(),
+                        is_writable: false,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.batch_accumulator.key(),
+                        is_writable: true,
+                    },
+                ],
+            )?],
+            1,
+            0,
+        )?;
+
+        msg!("init_batch_state queued for MPC");
+        Ok(())
+    }
+
+    /// Callback: Receive encrypted zeros from MPC and store in batch accumulator.
+    /// BatchState has 19 encrypted u64 values:
+    /// - pairs[6]: 12 u64 values (pair[i].total_a_in, pair[i].total_b_in) - indices 0-11
+    /// - order_count: 1 u64 value - index 12
+    /// - active_pairs[6]: 6 bool values (as u64s in MPC) - indices 13-18
+    #[arcium_callback(encrypted_ix = "init_batch_state")]
+    pub fn init_batch_state_callback(
+        ctx: Context<InitBatchStateCallback>,
+        output: SignedComputationOutputs<InitBatchStateOutput>,
+    ) -> Result<()> {
+        let o = match output.verify_output(
+            &ctx.accounts.cluster_account,
+            &ctx.accounts.computation_account,
+        ) {
+            Ok(output) => output,
+            Err(err) => {
+                msg!(
+                    "init_batch_state_callback verify_output failed: {:?}, computation={}",
+                    err,
+                    ctx.accounts.computation_account.key()
+                );
+                emit_callback_failed("init_batch_state", ctx.accounts.computation_account.key());
+                return Err(ErrorCode::AbortedComputation.into());
+            }
+        };
+
+        // MPC output is MXEEncryptedStruct with 12 ciphertexts (6 pairs × 2 values)
+        let batch = &mut ctx.accounts.batch_accumulator;
+
+        // Store pair totals (12 ciphertexts)
+        for pair_id in 0..6 {
+            batch.pair_states[pair_id].encrypted_token_a_in = o.field_0.ciphertexts[pair_id * 2];
+            batch.pair_states[pair_id].encrypted_token_b_in =
+                o.field_0.ciphertexts[pair_id * 2 + 1];
+        }
+
+        // Store MXE output nonce for subsequent reads
+        batch.mxe_nonce = o.field_0.nonce;
+        batch.state_initialized = true;
+
+        msg!(
+            "DEBUG init_batch_state: initial_mxe_nonce={}",
+            batch.mxe_nonce
+        );
+
+        Ok(())
+    }
+
+    pub fn add_together(
+        ctx: Context<AddTogether>,
+        computation_offset: u64,
+        ciphertext_0: [u8; 32],
+        ciphertext_1: [u8; 32],
+        pubkey: [u8; 32],
+        nonce: u128,
+    ) -> Result<()> {
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+        let args = ArgBuilder::new()
+            .x25519_pubkey(pubkey)
+            .plaintext_u128(nonce)
+            .encrypted_u8(ciphertext_0)
+            .encrypted_u8(ciphertext_1)
+            .build();
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            vec![AddTogetherCallback::callback_ix(
+                computation_offset,
+                &ctx.accounts.mxe_account,
+                &[],
+            )?],
+            1,
+            0,
+        )?;
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "add_together")]
+    pub fn add_together_callback(
+        ctx: Context<AddTogetherCallback>,
+        output: SignedComputationOutputs<AddTogetherOutput>,
+    ) -> Result<()> {
+        let o = match output.verify_output(
+            &ctx.accounts.cluster_account,
+            &ctx.accounts.computation_account,
+        ) {
+            Ok(AddTogetherOutput { field_0 }) => field_0,
+            Err(err) => {
+                msg!(
+                    "add_together_callback verify_output failed: {:?}, computation={}",
+                    err,
+                    ctx.accounts.computation_account.key()
+                );
+                emit_callback_failed("add_together", ctx.accounts.computation_account.key());
+                return Err(ErrorCode::AbortedComputation.into());
+            }
+        };
+
+        emit!(SumEvent {
+            sum: o.ciphertexts[0],
+            nonce: o.nonce.to_le_bytes(),
+            seq: 0, // not sequenced: no pool
+        });
+        Ok(())
+    }
+
+    // =========================================================================
+    // ADD BALANCE - Queue Encrypted Deposit (Phase 6)
+    // =========================================================================
+
+    /// Queue an encrypted balance update for a deposit.
+    /// This performs the token transfer and queues the MPC computation.
+    /// The actual balance update happens in the callback.
+    ///
+    /// # Arguments
+    /// * `computation_offset` - Unique ID for this computation
+    /// * `encrypted_amount` - The deposit amount encrypted with user's key
+    /// * `pubkey` - User's x25519 public key
+    /// * `nonce` - Encryption nonce
+    /// * `amount` - Plaintext amount for token transfer (revealed for CPI)
+    /// * `asset_id` - Asset identifier (0=USDC, 1=TSLA, 2=SPY, 3=AAPL)
+    ///
+    /// For restricted assets, remaining_accounts carry the Token-2022
+    /// transfer-hook extra accounts.
+    ///
+    /// `amount` stays public: Token-2022 confidential transfers into the
+    /// vault are not accepted. Nothing proves the ElGamal-encrypted transfer
+    /// amount equals `encrypted_amount`, so a depositor could credit more
+    /// than they sent, and the vault PDA has no ElGamal secret to apply its
+    /// own pending balance.
+    pub fn add_balance<'info>(
+        ctx: Context<'_, '_, '_, 'info, AddBalance<'info>>,
+        computation_offset: u64,
+        encrypted_amount: [u8; 32],
+        pubkey: [u8; 32],
+        nonce: u128,
+        amount: u64,
+        asset_id: u8,
+    ) -> Result<()> {
+        require!(!ctx.accounts.pool.paused, ErrorCode::ProtocolPaused);
+        // Validate asset_id
+        require!(asset_id <= 3, ErrorCode::InvalidAssetId);
+        require!(
+            !ctx.accounts.pool.is_asset_frozen(asset_id),
+            ErrorCode::AssetFrozen
+        );
+        require!(
+            !ctx.accounts.pool.is_asset_delisted(asset_id),
+            ErrorCode::AssetDelisted
+        );
+
+        require!(
+            ctx.accounts.pool.mint_for_asset(asset_id) == Some(ctx.accounts.mint.key()),
+            ErrorCode::InvalidMint
+        );
+
+        // Restricted assets: the depositor must be on the issuer allowlist
+        let hook_accounts = match ctx.accounts.asset_policy.as_ref() {
+            Some(policy) => {
+                check_issuer_allowlist(
+                    policy,
+                    &ctx.accounts.user.key(),
+                    ctx.accounts.allowlist_entry.as_ref().map(|e| e.as_ref()),
+                )?;
+                if policy.has_transfer_hook() {
+                    ctx.remaining_accounts
+                } else {
+                    &[]
+                }
+            }
+            None => {
+                require!(
+                    !ctx.accounts.pool.is_asset_restricted(asset_id),
+                    ErrorCode::InvalidAssetPolicy
+                );
+                &[]
+            }
+        };
+
+        // Transfer tokens first (this is visible on-chain, but private in aggregate).
+        // A transfer-fee mint delivers amount minus its fee; the encrypted
+        // amount credited must be that net amount (the SDK encrypts it).
+        let transfer_fee = token_transfer_fee(&ctx.accounts.mint.to_account_info(), amount)?;
+        transfer_checked_with_hook(
+            CheckedTransferAccounts {
+                token_program: &ctx.accounts.token_program.to_account_info(),
+                from: &ctx.accounts.user_token_account.to_account_info(),
+                mint: &ctx.accounts.mint.to_account_info(),
+                to: &ctx.accounts.vault.to_account_info(),
+                authority: &ctx.accounts.user.to_account_info(),
+                hook_accounts,
+            },
+            amount,
+            ctx.accounts.mint.decimals,
+            &[],
+        )?;
+
+        ctx.accounts.user_account.last_active_at = Clock::get()?.unix_timestamp;
+
+        // Set sign PDA bump
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+        // Build MPC arguments using the correct balance and nonce for this asset
+        let current_balance = ctx.accounts.user_account.get_credit(asset_id);
+        let current_nonce = ctx.accounts.user_account.get_nonce(asset_id);
+
+        // Per-computation context for the callback (see PendingOperation)
+        let pending = &mut ctx.accounts.pending_operation;
+        pending.owner = ctx.accounts.user.key();
+        pending.computation = ctx.accounts.computation_account.key();
+        pending.kind = PENDING_OP_DEPOSIT;
+        pending.asset_id = asset_id;
+        pending.amount = amount;
+        pending.balance_nonce = current_nonce;
+        pending.encrypted_amount = encrypted_amount;
+        pending.amount_pubkey = pubkey;
+        pending.amount_nonce = nonce;
+        pending.queued_at = Clock::get()?.unix_timestamp;
+        pending.bump = ctx.bumps.pending_operation;
+
+        let args = ArgBuilder::new()
+            // Shared input 1: BalanceUpdate (new deposit amount)
+            .x25519_pubkey(pubkey)
+            .plaintext_u128(nonce)
+            .encrypted_u64(encrypted_amount)
+            // Shared input 2: UserBalance (current balance from account)
+            .x25519_pubkey(pubkey)
+            .plaintext_u128(current_nonce)
+            .encrypted_u64(current_balance)
+            .build();
+
+        // Register callback that will receive the new encrypted balance
+        use arcium_client::idl::arcium::types::CallbackAccount;
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            vec![AddBalanceCallback::callback_ix(
+                computation_offset,
+                &ctx.accounts.mxe_account,
+                &[
+                    CallbackAccount {
+                        pubkey: ctx.accounts.user_account.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.pending_operation.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.user.key(),
+                        is_writable: true,
+                    },
+                    crate::event_cursor_callback_account(ctx.accounts.pool.pool_id),
+                ],
+            )?],
+            1,                                         // number of callbacks
+            ctx.accounts.pool.callback_cu_price_micro, // priority
+        )?;
+
+        msg!(
+            "Deposit queued: {} units of asset {} ({} net of transfer fee), computation {}",
+            amount,
+            asset_id,
+            amount - transfer_fee,
+            computation_offset
+        );
+        Ok(())
+    }
+
+    /// Callback handler for add_balance computation.
+    /// Receives the new encrypted balance from MPC and updates user account.
+    #[arcium_callback(encrypted_ix = "add_balance")]
+    pub fn add_balance_callback(
+        ctx: Context<AddBalanceCallback>,
+        output: SignedComputationOutputs<AddBalanceOutput>,
+    ) -> Result<()> {
+        let o = match output.verify_output(
+            &ctx.accounts.cluster_account,
+            &ctx.accounts.computation_account,
+        ) {
+            Ok(AddBalanceOutput { field_0 }) => field_0,
+            Err(err) => {
+                msg!(
+                    "add_balance_callback verify_output failed: {:?}, computation={}",
+                    err,
+                    ctx.accounts.computation_account.key()
+                );
+                emit_callback_failed("add_balance", ctx.accounts.computation_account.key());
+                return Err(ErrorCode::AbortedComputation.into());
+            }
+        };
+
+        // The PendingOperation names the asset; a result computed against a
+        // balance another operation has since replaced is rejected
+        let pending = &ctx.accounts.pending_operation;
+        require!(
+            pending.kind == PENDING_OP_DEPOSIT
+                && pending.computation == ctx.accounts.computation_account.key(),
+            ErrorCode::InvalidPendingOperation
+        );
+        let asset_id = pending.asset_id;
+        require!(
+            ctx.accounts.user_account.get_nonce(asset_id) == pending.balance_nonce,
+            ErrorCode::PendingOperationStale
+        );
+
+        ctx.accounts
+            .user_account
+            .set_credit(asset_id, o.ciphertexts[0]);
+        ctx.accounts.user_account.set_nonce(asset_id, o.nonce);
+        ctx.accounts
+            .pending_operation
+            .close(ctx.accounts.owner.to_account_info())?;
+
+        emit!(DepositEvent {
+            user: ctx.accounts.user_account.owner,
+            encrypted_balance: o.ciphertexts[0],
+            nonce: o.nonce.to_le_bytes(),
+            seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+        });
+
+        msg!("Deposit callback: asset {} balance updated", asset_id);
+        Ok(())
+    }
+
+    // =========================================================================
+    // DEPOSIT FOR - Credit another user's privacy account
+    // =========================================================================
+
+    /// Initialize the credit_balance computation definition.
+    /// This must be called once before deposit_for can be used.
+    pub fn init_credit_balance_comp_def(ctx: Context<InitCreditBalanceCompDef>) -> Result<()> {
+        init_comp_def(ctx.accounts, None, None)?;
+        Ok(())
+    }
+
+    /// Deposit tokens from the signer and credit them to another user's
+    /// privacy account (sponsored onboarding, gifts).
+    ///
+    /// # Arguments
+    /// * `computation_offset` - Unique ID for MPC computation
+    /// * `amount` - Amount of tokens to transfer
+    /// * `asset_id` - Asset to deposit (0=USDC, 1=TSLA, 2=SPY, 3=AAPL)
+    pub fn deposit_for<'info>(
+        ctx: Context<'_, '_, '_, 'info, DepositFor<'info>>,
+        computation_offset: u64,
+        amount: u64,
+        asset_id: u8,
+    ) -> Result<()> {
+        instructions::deposit_for::handler(ctx, computation_offset, amount, asset_id)
+    }
+
+    /// Callback handler for credit_balance computation.
+    /// Writes the recipient's new balance of the deposited asset.
+    #[arcium_callback(encrypted_ix = "credit_balance")]
+    pub fn credit_balance_callback(
+        ctx: Context<CreditBalanceCallback>,
+        output: SignedComputationOutputs<CreditBalanceOutput>,
+    ) -> Result<()> {
+        let o = match output.verify_output(
+            &ctx.accounts.cluster_account,
+            &ctx.accounts.computation_account,
+        ) {
+            Ok(CreditBalanceOutput { field_0 }) => field_0,
+            Err(err) => {
+                msg!(
+                    "credit_balance_callback verify_output failed: {:?}, computation={}",
+                    err,
+                    ctx.accounts.computation_account.key()
+                );
+                emit_callback_failed("credit_balance", ctx.accounts.computation_account.key());
+                return Err(ErrorCode::AbortedComputation.into());
+            }
+        };
+
+        // The asset comes from the mint, not the recipient's pending fields
+        let asset_id = ctx
+            .accounts
+            .pool
+            .asset_for_mint(&ctx.accounts.mint.key())
+            .ok_or(ErrorCode::InvalidMint)?;
+        ctx.accounts
+            .user_account
+            .set_credit(asset_id, o.ciphertexts[0]);
+        ctx.accounts.user_account.set_nonce(asset_id, o.nonce);
+
+        emit!(DepositEvent {
+            user: ctx.accounts.user_account.owner,
+            encrypted_balance: o.ciphertexts[0],
+            nonce: o.nonce.to_le_bytes(),
+            seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+        });
+
+        msg!(
+            "Deposit for callback: {} asset {} balance updated",
+            ctx.accounts.user_account.owner,
+            asset_id
+        );
+        Ok(())
+    }
+
+    // =========================================================================
+    // ARCIUM MPC SETUP - Sub Balance (Phase 6.5)
+    // =========================================================================
+
+    /// Initialize the sub_balance computation definition.
+    /// This must be called once before any encrypted withdrawals can be processed.
+    pub fn init_sub_balance_comp_def(ctx: Context<InitSubBalanceCompDef>) -> Result<()> {
+        init_comp_def(
+            ctx.accounts,
+            Some(CircuitSource::OffChain(OffChainCircuitSource {
+                source: "https://gateway.pinata.cloud/ipfs/QmSfQjsdRAiXEU9b8qH2d1fgmyn1P7wcRCd28DE1e5Y3nC".to_string(),
+                hash: circuit_hash!("sub_balance"),
+            })),
+            None,
+        )?;
+        Ok(())
+    }
+
+    // =========================================================================
+    // SUB BALANCE - Queue Encrypted Withdrawal (Phase 6.5)
+    // =========================================================================
+
+    /// Queue an encrypted balance update for a withdrawal.
+    /// This performs the token transfer and queues the MPC computation.
+    /// The encrypted balance update happens in the callback.
+    ///
+    /// # Arguments
+    /// * `computation_offset` - Unique ID for this computation
+    /// * `encrypted_amount` - The withdrawal amount encrypted with user's key
+    /// * `pubkey` - User's x25519 public key
+    /// * `nonce` - Encryption nonce
+    /// * `amount` - Plaintext amount for token transfer (deferred to callback)
+    /// * `asset_id` - Asset identifier (0=USDC, 1=TSLA, 2=SPY, 3=AAPL)
+    ///
+    /// For restricted assets, remaining_accounts carry the Token-2022
+    /// transfer-hook extra accounts; they are forwarded to the callback.
+    pub fn sub_balance<'info>(
+        ctx: Context<'_, '_, '_, 'info, SubBalance<'info>>,
+        computation_offset: u64,
+        encrypted_amount: [u8; 32],
+        pubkey: [u8; 32],
+        nonce: u128,
+        amount: u64,
+        asset_id: u8,
+    ) -> Result<()> {
+        // Paused: only exits, and only once an admin opened them
+        require!(
+            !ctx.accounts.pool.paused || ctx.accounts.pool.emergency_withdraw_enabled,
+            ErrorCode::ProtocolPaused
+        );
+        // Validate asset_id
+        require!(asset_id <= 3, ErrorCode::InvalidAssetId);
+        require!(
+            !ctx.accounts.pool.is_asset_frozen(asset_id),
+            ErrorCode::AssetFrozen
+        );
+
+        require!(
+            ctx.accounts.pool.mint_for_asset(asset_id) == Some(ctx.accounts.mint.key()),
+            ErrorCode::InvalidMint
+        );
+
+        // Restricted assets: the recipient must be on the issuer allowlist
+        let hook_accounts = match ctx.accounts.asset_policy.as_ref() {
+            Some(policy) => {
+                check_issuer_allowlist(
+                    policy,
+                    &ctx.accounts.recipient_token_account.owner,
+                    ctx.accounts.allowlist_entry.as_ref().map(|e| e.as_ref()),
+                )?;
+                if policy.has_transfer_hook() {
+                    ctx.remaining_accounts
+                } else {
+                    &[]
+                }
+            }
+            None => {
+                require!(
+                    !ctx.accounts.pool.is_asset_restricted(asset_id),
+                    ErrorCode::InvalidAssetPolicy
+                );
+                &[]
+            }
+        };
+
+        crate::check_withdrawal_cap(
+            &ctx.accounts.pool,
+            ctx.accounts.risk_config.as_deref().map(|c| &**c),
+            ctx.accounts
+                .withdrawal_window
+                .as_deref_mut()
+                .map(|w| &mut **w),
+            &ctx.accounts.user.key(),
+            asset_id,
+            amount,
+        )?;
+
+        ctx.accounts.user_account.last_active_at = Clock::get()?.unix_timestamp;
+
+        // Set sign PDA bump
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+        // Build MPC arguments using the correct balance and nonce for this asset
+        let current_balance = ctx.accounts.user_account.get_credit(asset_id);
+        let current_nonce = ctx.accounts.user_account.get_nonce(asset_id);
+
+        // Store pending info for the callback (see PendingOperation)
+        // Token transfer is DEFERRED to callback (after MPC confirms sufficient balance)
+        let pending = &mut ctx.accounts.pending_operation;
+        pending.owner = ctx.accounts.user.key();
+        pending.computation = ctx.accounts.computation_account.key();
+        pending.kind = PENDING_OP_WITHDRAWAL;
+        pending.asset_id = asset_id;
+        pending.amount = amount;
+        pending.balance_nonce = current_nonce;
+        pending.encrypted_amount = encrypted_amount;
+        pending.amount_pubkey = pubkey;
+        pending.amount_nonce = nonce;
+        pending.queued_at = Clock::get()?.unix_timestamp;
+        pending.bump = ctx.bumps.pending_operation;
+        let args = ArgBuilder::new()
+            // Shared input 1: BalanceUpdate (withdrawal amount)
+            .x25519_pubkey(pubkey)
+            .plaintext_u128(nonce)
+            .encrypted_u64(encrypted_amount)
+            // Shared input 2: UserBalance (current balance from account)
+            .x25519_pubkey(pubkey)
+            .plaintext_u128(current_nonce)
+            .encrypted_u64(current_balance)
+            .build();
+
+        // Register callback that will verify has_funds and perform token transfer
+        // Transfer-hook extra accounts follow the named callback accounts
+        use arcium_client::idl::arcium::types::CallbackAccount;
+        let mut callback_accounts = vec![
+            CallbackAccount {
+                pubkey: ctx.accounts.user_account.key(),
+                is_writable: true,
+            },
+            CallbackAccount {
+                pubkey: ctx.accounts.pool.key(),
+                is_writable: false,
+            },
+            CallbackAccount {
+                pubkey: ctx.accounts.vault.key(),
+                is_writable: true,
+            },
+            CallbackAccount {
+                pubkey: ctx.accounts.recipient_token_account.key(),
+                is_writable: true,
+            },
+            CallbackAccount {
+                pubkey: ctx.accounts.token_program.key(),
+                is_writable: false,
+            },
+            CallbackAccount {
+                pubkey: ctx.accounts.mint.key(),
+                is_writable: false,
+            },
+            CallbackAccount {
+                pubkey: ctx.accounts.pending_operation.key(),
+                is_writable: true,
+            },
+            CallbackAccount {
+                pubkey: ctx.accounts.user.key(),
+                is_writable: true,
+            },
+            crate::event_cursor_callback_account(ctx.accounts.pool.pool_id),
+        ];
+        callback_accounts.extend(hook_accounts.iter().map(|info| CallbackAccount {
+            pubkey: info.key(),
+            is_writable: info.is_writable,
+        }));
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            vec![SubBalanceCallback::callback_ix(
+                computation_offset,
+                &ctx.accounts.mxe_account,
+                &callback_accounts,
+            )?],
+            1,                                         // number of callbacks
+            ctx.accounts.pool.callback_cu_price_micro, // priority
+        )?;
+
+        msg!(
+            "Withdrawal queued: {} units of asset {}, computation {} (transfer deferred to callback)",
+            amount,
+            asset_id,
+            computation_offset
+        );
+        Ok(())
+    }
+
+    /// Callback handler for sub_balance computation.
+    /// Receives (has_funds, new_balance) from MPC.
+    /// If has_funds is false, aborts the transaction.
+    /// If has_funds is true, performs the token transfer and updates balance.
+    #[arcium_callback(encrypted_ix = "sub_balance")]
+    pub fn sub_balance_callback<'info>(
+        ctx: Context<'_, '_, '_, 'info, SubBalanceCallback<'info>>,
+        output: SignedComputationOutputs<SubBalanceOutput>,
+    ) -> Result<()> {
+        let o = match output.verify_output(
+            &ctx.accounts.cluster_account,
+            &ctx.accounts.computation_account,
+        ) {
+            Ok(output) => output,
+            Err(err) => {
+                msg!(
+                    "sub_balance_callback verify_output failed: {:?}, computation={}",
+                    err,
+                    ctx.accounts.computation_account.key()
+                );
+                emit_callback_failed("sub_balance", ctx.accounts.computation_account.key());
+                return Err(ErrorCode::AbortedComputation.into());
+            }
+        };
+
+        // Extract has_funds flag from MPC output
+        // Circuit returns (bool, Enc<Shared, UserBalance>) wrapped in field_0
+        // o.field_0.field_0 = bool (has_funds, revealed)
+        // o.field_0.field_1 = UserBalance (SharedEncryptedStruct<1>)
+        let has_funds: bool = o.field_0.field_0;
+        let new_balance = &o.field_0.field_1;
+
+        let pending = &ctx.accounts.pending_operation;
+        require!(
+            pending.kind == PENDING_OP_WITHDRAWAL
+                && pending.computation == ctx.accounts.computation_account.key(),
+            ErrorCode::InvalidPendingOperation
+        );
+        let asset_id = pending.asset_id;
+        let amount = pending.amount;
+
+        // If user doesn't have sufficient funds, or the result was computed
+        // against a balance another operation has since replaced, abort the
+        // transaction (the owner closes the PendingOperation)
+        let rejection = if !has_funds {
+            Some(ErrorCode::InsufficientBalance)
+        } else if ctx.accounts.user_account.get_nonce(asset_id) != pending.balance_nonce {
+            Some(ErrorCode::PendingOperationStale)
+        } else {
+            None
+        };
+        if let Some(error) = rejection {
+            emit!(WithdrawalRejectedEvent {
+                user: ctx.accounts.user_account.owner,
+                asset_id,
+                amount,
+                error: error.info(),
+                seq: 0, // not sequenced: the transaction fails
+            });
+            return Err(error.into());
+        }
+
+        // Perform the deferred token transfer now that MPC confirmed sufficient balance
+        let pool_seeds = &[
+            POOL_SEED,
+            &[ctx.accounts.pool.pool_id],
+            &[ctx.accounts.pool.bump],
+        ];
+        let signer_seeds = &[&pool_seeds[..]];
+
+        transfer_checked_with_hook(
+            CheckedTransferAccounts {
+                token_program: &ctx.accounts.token_program,
+                from: &ctx.accounts.vault,
+                mint: &ctx.accounts.mint.to_account_info(),
+                to: &ctx.accounts.recipient_token_account,
+                authority: &ctx.accounts.pool.to_account_info(),
+                hook_accounts: ctx.remaining_accounts,
+            },
+            amount,
+            ctx.accounts.mint.decimals,
+            signer_seeds,
+        )?;
+
+        // Update the correct asset balance and nonce
+        ctx.accounts
+            .user_account
+            .set_credit(asset_id, new_balance.ciphertexts[0]);
+        ctx.accounts
+            .user_account
+            .set_nonce(asset_id, new_balance.nonce);
+
+        // Clear pending withdrawal
+        ctx.accounts
+            .pending_operation
+            .close(ctx.accounts.owner.to_account_info())?;
+
+        emit!(WithdrawEvent {
+            user: ctx.accounts.user_account.owner,
+            encrypted_balance: new_balance.ciphertexts[0],
+            nonce: new_balance.nonce.to_le_bytes(),
+            seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+        });
+
+        msg!(
+            "Withdrawal callback: {} units of asset {} transferred, balance updated",
+            amount,
+            asset_id
+        );
+        Ok(())
+    }
+
+    /// Re-queue a deposit whose add_balance callback failed or was
+    /// rejected as stale. Only callable by the account owner.
+    ///
+    /// # Arguments
+    /// * `computation_offset` - Unique ID for the new MPC computation
+    /// * `pending_offset` - computation_offset the deposit was first queued with
+    pub fn retry_deposit(
+        ctx: Context<RetryDeposit>,
+        computation_offset: u64,
+        pending_offset: u64,
+    ) -> Result<()> {
+        instructions::retry_deposit::handler(ctx, computation_offset, pending_offset)
+    }
+
+    /// Cancel a withdrawal whose callback never arrived (or failed) and
+    /// reclaim its PendingOperation rent. Allowed once the computation
+    /// account is gone or WITHDRAWAL_CANCEL_TIMEOUT_SECS after queueing.
+    ///
+    /// # Arguments
+    /// * `pending_offset` - computation_offset the withdrawal was queued with
+    pub fn cancel_withdrawal(ctx: Context<CancelWithdrawal>, pending_offset: u64) -> Result<()> {
+        instructions::cancel_withdrawal::handler(ctx, pending_offset)
+    }
+
+    // =========================================================================
+    // P2P INTERNAL TRANSFER (Phase 6.75)
+    // =========================================================================
+
+    /// Check if a wallet has a privacy account.
+    /// This is a view function for clients to check before attempting transfers.
+    ///
+    /// # Returns
+    /// * `true` if the account exists
+    /// * `false` if the account doesn't exist
+    pub fn check_privacy_account_exists(ctx: Context<CheckPrivacyAccountExists>) -> Result<bool> {
+        // If we get here, the account exists (Anchor validates it)
+        // So we just return true
+        msg!(
+            "Privacy account exists for wallet: {}",
+            ctx.accounts.user_account.owner
+        );
+        Ok(true)
+    }
+
+    // =========================================================================
+    // ARCIUM MPC SETUP - Transfer (Phase 6.75)
+    // =========================================================================
+
+    /// Initialize the transfer computation definition.
+    /// This must be called once before any P2P transfers can be processed.
+    pub fn init_transfer_comp_def(ctx: Context<InitTransferCompDef>) -> Result<()> {
+        init_comp_def(
+            ctx.accounts,
+            Some(CircuitSource::OffChain(OffChainCircuitSource {
+                source: "https://gateway.pinata.cloud/ipfs/QmQAK9JvndSP3YePGq9ciSeuCk8boHfQy5xi3RZTHS9iDW".to_string(),
+                hash: circuit_hash!("transfer"),
+            })),
+            None,
+        )?;
+        Ok(())
+    }
+
+    /// Initialize the transfer_multi computation definition.
+    /// This must be called once before multi-asset transfers can be processed.
+    pub fn init_transfer_multi_comp_def(ctx: Context<InitTransferMultiCompDef>) -> Result<()> {
+        init_comp_def(ctx.accounts, None, None)?;
+        Ok(())
+    }
+
+    /// Initialize the transfer_batch computation definition.
+    /// This must be called once before batch payouts can be processed.
+    pub fn init_transfer_batch_comp_def(ctx: Context<InitTransferBatchCompDef>) -> Result<()> {
+        init_comp_def(ctx.accounts, None, None)?;
+        Ok(())
+    }
+
+    /// Initialize the escrow_transfer computation definition.
+    /// This must be called once before transfer requests can be created.
+    pub fn init_escrow_transfer_comp_def(ctx: Context<InitEscrowTransferCompDef>) -> Result<()> {
+        init_comp_def(ctx.accounts, None, None)?;
+        Ok(())
+    }
+
+    /// Initialize the release_escrow computation definition.
+    /// This must be called once before transfer requests can be claimed or reclaimed.
+    pub fn init_release_escrow_comp_def(ctx: Context<InitReleaseEscrowCompDef>) -> Result<()> {
+        init_comp_def(ctx.accounts, None, None)?;
+        Ok(())
+    }
+
+    // =========================================================================
+    // P2P INTERNAL TRANSFER (Phase 6.75)
+    // =========================================================================
+
+    // =========================================================================
+    // TEST SWAP CPI (Phase 8 - Cross-Program Invocation to mock_jupiter)
+    // =========================================================================
+
+    /// Test CPI swap through mock_jupiter.
+    /// The Pool PDA signs the CPI as the "user_authority" since it owns the vaults.
+    /// This proves cross-program invocation works before building full batch execution.
+    ///
+    /// # Arguments
+    /// * `amount_in` - Amount of source tokens to swap
+    /// * `min_amount_out` - Minimum acceptable output (slippage protection)
+    pub fn test_swap(ctx: Context<TestSwap>, amount_in: u64, min_amount_out: u64) -> Result<()> {
+        instructions::test_swap::handler(ctx, amount_in, min_amount_out)
+    }
+
+    // =========================================================================
+    // P2P INTERNAL TRANSFER (Phase 6.75)
+    // =========================================================================
+
+    /// Internal transfer between two privacy accounts.
+    /// Atomically deducts from sender's and adds to recipient's encrypted balance
+    /// of the same asset.
+    ///
+    /// Both balances are updated in a single MPC computation using the `transfer` circuit.
+    ///
+    /// # Arguments
+    /// * `computation_offset` - Unique ID for MPC computation
+    /// * `encrypted_amount` - Amount encrypted with sender's key
+    /// * `pubkey` - Sender's x25519 public key
+    /// * `nonce` - Encryption nonce
+    /// * `asset_id` - Asset to transfer (0=USDC, 1=TSLA, 2=SPY, 3=AAPL)
+    /// * `memo` - Optional memo encrypted to the recipient's x25519 key
+    ///   (MIN_TRANSFER_MEMO_LEN..=MAX_TRANSFER_MEMO_LEN bytes), requires transfer_memo
+    pub fn internal_transfer(
+        ctx: Context<InternalTransfer>,
+        computation_offset: u64,
+        encrypted_amount: [u8; 32],
+        pubkey: [u8; 32],
+        nonce: u128,
+        asset_id: u8,
+        memo: Option<Vec<u8>>,
+    ) -> Result<()> {
+        require!(!ctx.accounts.pool.paused, ErrorCode::ProtocolPaused);
+        require!(asset_id <= 3, ErrorCode::InvalidAssetId);
+        require!(
+            !ctx.accounts.pool.is_asset_frozen(asset_id),
+            ErrorCode::AssetFrozen
+        );
+
+        // Stage the memo for the callback, tagged with this computation
+        let memo_account = match memo {
+            Some(memo) => {
+                require!(
+                    (MIN_TRANSFER_MEMO_LEN..=MAX_TRANSFER_MEMO_LEN).contains(&memo.len()),
+                    ErrorCode::InvalidTransferMemo
+                );
+                let transfer_memo = ctx
+                    .accounts
+                    .transfer_memo
+                    .as_mut()
+                    .ok_or(ErrorCode::TransferMemoMissing)?;
+                transfer_memo.owner = ctx.accounts.sender.key();
+                transfer_memo.computation = ctx.accounts.computation_account.key();
+                transfer_memo.memo = memo;
+                Some(transfer_memo.key())
+            }
+            None => None,
+        };
+
+        // Callback reads the asset from the sender's account
+        ctx.accounts.sender_account.pending_asset_id = asset_id;
+
+        // Set sign PDA bump
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+        // Sender activity resets their beneficiary inactivity timer
+        ctx.accounts.sender_account.last_active_at = Clock::get()?.unix_timestamp;
+
+        // Build MPC arguments for transfer circuit
+        // Transfer circuit takes: TransferRequest { amount }, sender_balance, recipient_balance
+        // All use Enc<Shared, *> pattern with x25519 pubkey + nonce + encrypted value
+        let args = ArgBuilder::new()
+            // TransferRequest (encrypted with sender's key) - just amount field
+            .x25519_pubkey(pubkey)
+            .plaintext_u128(nonce)
+            .encrypted_u64(encrypted_amount)
+            // Sender's current balance (Enc<Shared, *> - using sender's pubkey)
+            .x25519_pubkey(ctx.accounts.sender_account.user_pubkey)
+            .plaintext_u128(ctx.accounts.sender_account.get_nonce(asset_id))
+            .encrypted_u64(ctx.accounts.sender_account.get_credit(asset_id))
+            // Recipient's current balance (Enc<Shared, *> - using recipient's pubkey)
+            .x25519_pubkey(ctx.accounts.recipient_account.user_pubkey)
+            .plaintext_u128(ctx.accounts.recipient_account.get_nonce(asset_id))
+            .encrypted_u64(ctx.accounts.recipient_account.get_credit(asset_id))
+            .build();
+
+        // Queue MPC - callback receives BOTH updated balances
+        use arcium_client::idl::arcium::types::CallbackAccount;
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            vec![TransferCallback::callback_ix(
+                computation_offset,
+                &ctx.accounts.mxe_account,
+                &[
+                    CallbackAccount {
+                        pubkey: ctx.accounts.sender_account.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.recipient_account.key(),
+                        is_writable: true,
+                    },
+                    crate::optional_callback_account(memo_account, true),
+                    crate::event_cursor_callback_account(ctx.accounts.pool.pool_id),
+                ],
+            )?],
+            1,
+            0,
+        )?;
+
+        msg!(
+            "Transfer queued: {} -> {}, asset={}, computation {}",
+            ctx.accounts.sender_account.owner,
+            ctx.accounts.recipient_account.owner,
+            asset_id,
+            computation_offset
+        );
+        Ok(())
+    }
+
+    /// Callback handler for transfer computation.
+    /// Receives both updated balances and writes them atomically.
+    #[arcium_callback(encrypted_ix = "transfer")]
+    pub fn transfer_callback(
+        ctx: Context<TransferCallback>,
+        output: SignedComputationOutputs<TransferOutput>,
+    ) -> Result<()> {
+        let o = match output.verify_output(
+            &ctx.accounts.cluster_account,
+            &ctx.accounts.computation_account,
+        ) {
+            Ok(output) => output,
+            Err(err) => {
+                msg!(
+                    "transfer_callback verify_output failed: {:?}, computation={}",
+                    err,
+                    ctx.accounts.computation_account.key()
+                );
+                emit_callback_failed("transfer", ctx.accounts.computation_account.key());
+                return Err(ErrorCode::AbortedComputation.into());
+            }
+        };
+
+        // Tuple return creates nested struct:
+        // o.field_0.field_0 = sender's new balance (Enc<Shared, UserBalance>)
+        // o.field_0.field_1 = recipient's new balance (Enc<Shared, UserBalance>)
+
+        let asset_id = ctx.accounts.sender_account.pending_asset_id;
+
+        // Log old values for debugging
+        msg!(
+            "DEBUG transfer_callback: sender old nonce={}, old credit[0..4]={:?}",
+            ctx.accounts.sender_account.get_nonce(asset_id),
+            &ctx.accounts.sender_account.get_credit(asset_id)[0..4]
+        );
+        msg!(
+            "DEBUG transfer_callback: recipient old nonce={}, old credit[0..4]={:?}",
+            ctx.accounts.recipient_account.get_nonce(asset_id),
+            &ctx.accounts.recipient_account.get_credit(asset_id)[0..4]
+        );
+
+        // Log new values from MPC
+        msg!(
+            "DEBUG transfer_callback: sender new nonce={}, new credit[0..4]={:?}",
+            o.field_0.field_0.nonce,
+            &o.field_0.field_0.ciphertexts[0][0..4]
+        );
+        msg!(
+            "DEBUG transfer_callback: recipient new nonce={}, new credit[0..4]={:?}",
+            o.field_0.field_1.nonce,
+            &o.field_0.field_1.ciphertexts[0][0..4]
+        );
+
+        // Update sender's encrypted balance and nonce of the transferred asset
+        ctx.accounts
+            .sender_account
+            .set_credit(asset_id, o.field_0.field_0.ciphertexts[0]);
+        ctx.accounts
+            .sender_account
+            .set_nonce(asset_id, o.field_0.field_0.nonce);
+
+        // Update recipient's encrypted balance and nonce of the transferred asset
+        ctx.accounts
+            .recipient_account
+            .set_credit(asset_id, o.field_0.field_1.ciphertexts[0]);
+        ctx.accounts
+            .recipient_account
+            .set_nonce(asset_id, o.field_0.field_1.nonce);
+
+        // Memo staged by this transfer (a later transfer may have replaced it)
+        let computation = ctx.accounts.computation_account.key();
+        let memo = match ctx.accounts.transfer_memo.as_mut() {
+            Some(transfer_memo) if transfer_memo.computation == computation => {
+                std::mem::take(&mut transfer_memo.memo)
+            }
+            _ => Vec::new(),
+        };
+
+        emit!(TransferEvent {
+            from: ctx.accounts.sender_account.owner,
+            to: ctx.accounts.recipient_account.owner,
+            asset_id,
+            amount: 0, // Amount not revealed in callback
+            sender_nonce: o.field_0.field_0.nonce.to_le_bytes(),
+            memo,
+            seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+        });
+
+        msg!(
+            "Transfer callback: {} -> {} asset {} balances updated",
+            ctx.accounts.sender_account.owner,
+            ctx.accounts.recipient_account.owner,
+            asset_id
+        );
+        Ok(())
+    }
+
+    /// Internal transfer of all four assets in one MPC computation.
+    /// Either every amount moves or none does.
+    ///
+    /// # Arguments
+    /// * `computation_offset` - Unique ID for MPC computation
+    /// * `encrypted_amounts` - USDC, TSLA, SPY, AAPL amounts encrypted with sender's key
+    /// * `pubkey` - Sender's x25519 public key
+    /// * `nonce` - Encryption nonce
+    pub fn internal_transfer_multi(
+        ctx: Context<InternalTransferMulti>,
+        computation_offset: u64,
+        encrypted_amounts: [[u8; 32]; 4],
+        pubkey: [u8; 32],
+        nonce: u128,
+    ) -> Result<()> {
+        instructions::internal_transfer_multi::handler(
+            ctx,
+            computation_offset,
+            encrypted_amounts,
+            pubkey,
+            nonce,
+        )
+    }
+
+    /// Callback handler for transfer_multi computation.
+    /// Writes all eight balances atomically.
+    #[arcium_callback(encrypted_ix = "transfer_multi")]
+    pub fn transfer_multi_callback(
+        ctx: Context<TransferMultiCallback>,
+        output: SignedComputationOutputs<TransferMultiOutput>,
+    ) -> Result<()> {
+        let o = match output.verify_output(
+            &ctx.accounts.cluster_account,
+            &ctx.accounts.computation_account,
+        ) {
+            Ok(output) => output,
+            Err(err) => {
+                msg!(
+                    "transfer_multi_callback verify_output failed: {:?}, computation={}",
+                    err,
+                    ctx.accounts.computation_account.key()
+                );
+                emit_callback_failed("transfer_multi", ctx.accounts.computation_account.key());
+                return Err(ErrorCode::AbortedComputation.into());
+            }
+        };
+
+        // o.field_0.field_0..field_3 = sender's USDC, TSLA, SPY, AAPL
+        // o.field_0.field_4..field_7 = recipient's USDC, TSLA, SPY, AAPL
+        let sender_balances = [
+            o.field_0.field_0,
+            o.field_0.field_1,
+            o.field_0.field_2,
+            o.field_0.field_3,
+        ];
+        let recipient_balances = [
+            o.field_0.field_4,
+            o.field_0.field_5,
+            o.field_0.field_6,
+            o.field_0.field_7,
+        ];
+        for (asset_id, balance) in sender_balances.iter().enumerate() {
+            ctx.accounts
+                .sender_account
+                .set_credit(asset_id as u8, balance.ciphertexts[0]);
+            ctx.accounts
+                .sender_account
+                .set_nonce(asset_id as u8, balance.nonce);
+        }
+        for (asset_id, balance) in recipient_balances.iter().enumerate() {
+            ctx.accounts
+                .recipient_account
+                .set_credit(asset_id as u8, balance.ciphertexts[0]);
+            ctx.accounts
+                .recipient_account
+                .set_nonce(asset_id as u8, balance.nonce);
+        }
+
+        emit!(MultiTransferEvent {
+            from: ctx.accounts.sender_account.owner,
+            to: ctx.accounts.recipient_account.owner,
+            sender_nonces: sender_balances.map(|balance| balance.nonce.to_le_bytes()),
+            seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+        });
+
+        msg!(
+            "Multi-asset transfer callback: {} -> {} balances updated",
+            ctx.accounts.sender_account.owner,
+            ctx.accounts.recipient_account.owner
+        );
+        Ok(())
+    }
+
+    /// Payout of one asset to up to MAX_BATCH_TRANSFER_RECIPIENTS privacy
+    /// accounts (writable remaining_accounts) in one MPC computation.
+    /// Either every payout moves or none does.
+    ///
+    /// # Arguments
+    /// * `computation_offset` - Unique ID for MPC computation
+    /// * `asset_id` - Asset to pay out (0=USDC, 1=TSLA, 2=SPY, 3=AAPL)
+    /// * `encrypted_amounts` - Amount per recipient slot encrypted with sender's key
+    /// * `pubkey` - Sender's x25519 public key
+    /// * `nonce` - Encryption nonce
+    pub fn transfer_batch(
+        ctx: Context<TransferBatch>,
+        computation_offset: u64,
+        asset_id: u8,
+        encrypted_amounts: [[u8; 32]; 4],
+        pubkey: [u8; 32],
+        nonce: u128,
+    ) -> Result<()> {
+        instructions::transfer_batch::handler(
+            ctx,
+            computation_offset,
+            asset_id,
+            encrypted_amounts,
+            pubkey,
+            nonce,
+        )
+    }
+
+    /// Callback handler for transfer_batch computation.
+    /// Writes the sender's and every passed recipient's balance.
+    #[arcium_callback(encrypted_ix = "transfer_batch")]
+    pub fn transfer_batch_callback(
+        ctx: Context<TransferBatchCallback>,
+        output: SignedComputationOutputs<TransferBatchOutput>,
+    ) -> Result<()> {
+        let o = match output.verify_output(
+            &ctx.accounts.cluster_account,
+            &ctx.accounts.computation_account,
+        ) {
+            Ok(output) => output,
+            Err(err) => {
+                msg!(
+                    "transfer_batch_callback verify_output failed: {:?}, computation={}",
+                    err,
+                    ctx.accounts.computation_account.key()
+                );
+                emit_callback_failed("transfer_batch", ctx.accounts.computation_account.key());
+                return Err(ErrorCode::AbortedComputation.into());
+            }
+        };
+
+        // o.field_0.field_0 = sender's new balance
+        // o.field_0.field_1..field_4 = recipient slots (padding slots ignored)
+        let asset_id = ctx.accounts.sender_account.pending_asset_id;
+        ctx.accounts
+            .sender_account
+            .set_credit(asset_id, o.field_0.field_0.ciphertexts[0]);
+        ctx.accounts
+            .sender_account
+            .set_nonce(asset_id, o.field_0.field_0.nonce);
+
+        let slots = [
+            o.field_0.field_1,
+            o.field_0.field_2,
+            o.field_0.field_3,
+            o.field_0.field_4,
+        ];
+        let mut recipients = Vec::with_capacity(ctx.remaining_accounts.len());
+        for (info, balance) in ctx.remaining_accounts.iter().zip(slots.iter()) {
+            recipients.push(instructions::transfer_batch::credit_recipient(
+                info,
+                asset_id,
+                balance.ciphertexts[0],
+                balance.nonce,
+            )?);
+        }
+
+        emit!(TransferBatchEvent {
+            from: ctx.accounts.sender_account.owner,
+            recipients,
+            asset_id,
+            sender_nonce: o.field_0.field_0.nonce.to_le_bytes(),
+            seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+        });
+
+        msg!(
+            "Batch transfer callback: {} paid {} recipients in asset {}",
+            ctx.accounts.sender_account.owner,
+            ctx.remaining_accounts.len(),
+            asset_id
+        );
+        Ok(())
+    }
+
+    // =========================================================================
+    // TWO-STEP TRANSFERS
+    // =========================================================================
+
+    /// Escrow an encrypted amount for a recipient to claim before
+    /// `expiry_secs` elapse; the sender can reclaim it afterwards.
+    ///
+    /// # Arguments
+    /// * `computation_offset` - Unique ID for MPC computation
+    /// * `transfer_id` - Sender-chosen ID of the request (unique per sender)
+    /// * `asset_id` - Asset to transfer (0=USDC, 1=TSLA, 2=SPY, 3=AAPL)
+    /// * `encrypted_amount` - Amount encrypted for the sender's user_pubkey
+    /// * `nonce` - Encryption nonce
+    /// * `expiry_secs` - Claim window
+    pub fn request_transfer(
+        ctx: Context<RequestTransfer>,
+        computation_offset: u64,
+        transfer_id: u64,
+        asset_id: u8,
+        encrypted_amount: [u8; 32],
+        nonce: u128,
+        expiry_secs: i64,
+    ) -> Result<()> {
+        instructions::request_transfer::handler(
+            ctx,
+            computation_offset,
+            transfer_id,
+            asset_id,
+            encrypted_amount,
+            nonce,
+            expiry_secs,
+        )
+    }
+
+    /// Callback handler for escrow_transfer computation.
+    /// Debits the sender and opens the request for the recipient.
+    #[arcium_callback(encrypted_ix = "escrow_transfer")]
+    pub fn escrow_transfer_callback(
+        ctx: Context<EscrowTransferCallback>,
+        output: SignedComputationOutputs<EscrowTransferOutput>,
+    ) -> Result<()> {
+        let o = match output.verify_output(
+            &ctx.accounts.cluster_account,
+            &ctx.accounts.computation_account,
+        ) {
+            Ok(output) => output,
+            Err(err) => {
+                msg!(
+                    "escrow_transfer_callback verify_output failed: {:?}, computation={}",
+                    err,
+                    ctx.accounts.computation_account.key()
+                );
+                emit_callback_failed("escrow_transfer", ctx.accounts.computation_account.key());
+                return Err(ErrorCode::AbortedComputation.into());
+            }
+        };
+
+        // A request reset by reset_transfer_request no longer waits on this
+        // computation
+        let request = &mut ctx.accounts.transfer_request;
+        require!(
+            request.status == TRANSFER_STATUS_ESCROWING
+                && request.pending_computation == ctx.accounts.computation_account.key(),
+            ErrorCode::InvalidPendingOperation
+        );
+
+        // o.field_0.field_0 = sender's new balance, o.field_0.field_1 = escrow
+        ctx.accounts
+            .sender_account
+            .set_credit(request.asset_id, o.field_0.field_0.ciphertexts[0]);
+        ctx.accounts
+            .sender_account
+            .set_nonce(request.asset_id, o.field_0.field_0.nonce);
+        request.escrow_amount = o.field_0.field_1.ciphertexts[0];
+        request.escrow_nonce = o.field_0.field_1.nonce;
+        request.status = TRANSFER_STATUS_OPEN;
+
+        emit!(TransferRequestedEvent {
+            transfer_id: request.transfer_id,
+            sender: request.sender,
+            recipient: request.recipient,
+            asset_id: request.asset_id,
+            expires_at: request.expires_at,
+            seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+        });
+
+        msg!(
+            "Transfer request {} open until {}",
+            request.transfer_id,
+            request.expires_at
+        );
+        Ok(())
+    }
+
+    /// Recipient claims an open transfer request before it expires.
+    ///
+    /// # Arguments
+    /// * `computation_offset` - Unique ID for MPC computation
+    pub fn claim_transfer(ctx: Context<ClaimTransfer>, computation_offset: u64) -> Result<()> {
+        instructions::claim_transfer::handler(ctx, computation_offset)
+    }
+
+    /// Sender takes back an expired, unclaimed transfer request.
+    ///
+    /// # Arguments
+    /// * `computation_offset` - Unique ID for MPC computation
+    pub fn reclaim_transfer(ctx: Context<ReclaimTransfer>, computation_offset: u64) -> Result<()> {
+        instructions::reclaim_transfer::handler(ctx, computation_offset)
+    }
+
+    /// Recover a transfer request whose escrow, claim or reclaim callback
+    /// never arrived. Permissionless.
+    pub fn reset_transfer_request(ctx: Context<ResetTransferRequest>) -> Result<()> {
+        instructions::reset_transfer_request::handler(ctx)
+    }
+
+    /// Callback handler for release_escrow computation.
+    /// Credits the claimer and closes the request (rent back to the sender).
+    #[arcium_callback(encrypted_ix = "release_escrow")]
+    pub fn release_escrow_callback(
+        ctx: Context<ReleaseEscrowCallback>,
+        output: SignedComputationOutputs<ReleaseEscrowOutput>,
+    ) -> Result<()> {
+        let balance = match output.verify_output(
+            &ctx.accounts.cluster_account,
+            &ctx.accounts.computation_account,
+        ) {
+            Ok(ReleaseEscrowOutput { field_0 }) => field_0,
+            Err(err) => {
+                msg!(
+                    "release_escrow_callback verify_output failed: {:?}, computation={}",
+                    err,
+                    ctx.accounts.computation_account.key()
+                );
+                emit_callback_failed("release_escrow", ctx.accounts.computation_account.key());
+                return Err(ErrorCode::AbortedComputation.into());
+            }
+        };
+
+        // A request reset by reset_transfer_request no longer waits on this
+        // computation
+        let request = &ctx.accounts.transfer_request;
+        require!(
+            request.status == TRANSFER_STATUS_RELEASING
+                && request.pending_computation == ctx.accounts.computation_account.key(),
+            ErrorCode::InvalidPendingOperation
+        );
+        ctx.accounts
+            .user_account
+            .set_credit(request.asset_id, balance.ciphertexts[0]);
+        ctx.accounts
+            .user_account
+            .set_nonce(request.asset_id, balance.nonce);
+        let claimed = ctx.accounts.user_account.owner == request.recipient;
+
+        emit!(TransferReleasedEvent {
+            transfer_id: request.transfer_id,
+            sender: request.sender,
+            recipient: request.recipient,
+            asset_id: request.asset_id,
+            claimed,
+            seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+        });
+
+        msg!(
+            "Transfer request {} {}",
+            request.transfer_id,
+            if claimed { "claimed" } else { "reclaimed" }
+        );
+        Ok(())
+    }
+
+    // =========================================================================
+    // PAYMENT STREAMS
+    // =========================================================================
+
+    /// Open a payment stream paying an encrypted per-second rate to another
+    /// privacy account between `start_ts` and `end_ts`.
+    ///
+    /// # Arguments
+    /// * `stream_id` - Payer-chosen ID of the stream (unique per payer)
+    /// * `asset_id` - Asset to stream (0=USDC, 1=TSLA, 2=SPY, 3=AAPL)
+    /// * `encrypted_rate` - Amount per second encrypted with `pubkey`
+    /// * `pubkey` - x25519 public key the rate was encrypted with
+    /// * `nonce` - Encryption nonce of the rate
+    /// * `start_ts` - Unix timestamp the stream starts accruing
+    /// * `end_ts` - Unix timestamp the stream stops accruing
+    pub fn create_stream(
+        ctx: Context<CreateStream>,
+        stream_id: u64,
+        asset_id: u8,
+        encrypted_rate: [u8; 32],
+        pubkey: [u8; 32],
+        nonce: u128,
+        start_ts: i64,
+        end_ts: i64,
+    ) -> Result<()> {
+        instructions::create_stream::handler(
+            ctx,
+            stream_id,
+            asset_id,
+            encrypted_rate,
+            pubkey,
+            nonce,
+            start_ts,
+            end_ts,
+        )
+    }
+
+    /// Pay out everything a stream has accrued since its last advance via
+    /// the stream_transfer circuit. Permissionless.
+    ///
+    /// # Arguments
+    /// * `computation_offset` - Unique ID for MPC computation
+    pub fn advance_stream(ctx: Context<AdvanceStream>, computation_offset: u64) -> Result<()> {
+        instructions::advance_stream::handler(ctx, computation_offset)
+    }
+
+    /// Callback handler for stream_transfer computation.
+    /// MPC output is (has_funds, new_payer, new_payee). Stores both balances
+    /// and moves last_advanced_ts forward if the payer had the funds.
+    #[arcium_callback(encrypted_ix = "stream_transfer")]
+    pub fn stream_transfer_callback(
+        ctx: Context<StreamTransferCallback>,
+        output: SignedComputationOutputs<StreamTransferOutput>,
+    ) -> Result<()> {
+        let o = match output.verify_output(
+            &ctx.accounts.cluster_account,
+            &ctx.accounts.computation_account,
+        ) {
+            Ok(output) => output,
+            Err(err) => {
+                msg!(
+                    "stream_transfer_callback verify_output failed: {:?}, computation={}",
+                    err,
+                    ctx.accounts.computation_account.key()
+                );
+                emit_callback_failed("stream_transfer", ctx.accounts.computation_account.key());
+                return Err(ErrorCode::AbortedComputation.into());
+            }
+        };
+
+        let has_funds: bool = o.field_0.field_0;
+        let new_payer = &o.field_0.field_1;
+        let new_payee = &o.field_0.field_2;
+
+        // Replaced by a later advance, or computed against a balance that
+        // has since changed: the next advance covers these seconds again
+        let stream = &ctx.accounts.payment_stream;
+        let asset_id = stream.asset_id;
+        require!(
+            stream.pending_computation == ctx.accounts.computation_account.key(),
+            ErrorCode::InvalidPendingOperation
+        );
+        require!(
+            ctx.accounts.payer_account.get_nonce(asset_id) == stream.pending_payer_nonce
+                && ctx.accounts.payee_account.get_nonce(asset_id) == stream.pending_payee_nonce,
+            ErrorCode::PendingOperationStale
+        );
+
+        let stream = &mut ctx.accounts.payment_stream;
+        let until = stream.pending_until;
+        stream.pending_computation = Pubkey::default();
+        if has_funds {
+            ctx.accounts
+                .payer_account
+                .set_credit(asset_id, new_payer.ciphertexts[0]);
+            ctx.accounts
+                .payer_account
+                .set_nonce(asset_id, new_payer.nonce);
+            ctx.accounts
+                .payee_account
+                .set_credit(asset_id, new_payee.ciphertexts[0]);
+            ctx.accounts
+                .payee_account
+                .set_nonce(asset_id, new_payee.nonce);
+            stream.last_advanced_ts = until;
+            stream.advances += 1;
+        }
+
+        emit!(StreamAdvancedEvent {
+            payer: stream.payer,
+            payee: stream.payee,
+            stream_id: stream.stream_id,
+            paid: has_funds,
+            last_advanced_ts: stream.last_advanced_ts,
+            seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+        });
+
+        msg!(
+            "Stream {} of {} {} up to {}",
+            stream.stream_id,
+            stream.payer,
+            if has_funds { "advanced" } else { "underfunded" },
+            until
+        );
+        Ok(())
+    }
+
+    /// Cancel a payment stream and reclaim its rent (payer only).
+    pub fn cancel_stream(ctx: Context<CancelStream>) -> Result<()> {
+        instructions::cancel_stream::handler(ctx)
+    }
+
+    // =========================================================================
+    // CLAIM-LINK VOUCHERS
+    // =========================================================================
+
+    /// Escrow an encrypted amount behind a voucher secret that any wallet
+    /// can claim before `expiry_secs` elapse; the funder can reclaim it
+    /// afterwards.
+    ///
+    /// # Arguments
+    /// * `computation_offset` - Unique ID for MPC computation
+    /// * `asset_id` - Asset to escrow (0=USDC, 1=TSLA, 2=SPY, 3=AAPL)
+    /// * `encrypted_amount` - Amount encrypted for the funder's user_pubkey
+    /// * `nonce` - Encryption nonce
+    /// * `expiry_secs` - Claim window
+    pub fn create_voucher(
+        ctx: Context<CreateVoucher>,
+        computation_offset: u64,
+        asset_id: u8,
+        encrypted_amount: [u8; 32],
+        nonce: u128,
+        expiry_secs: i64,
+    ) -> Result<()> {
+        instructions::create_voucher::handler(
+            ctx,
+            computation_offset,
+            asset_id,
+            encrypted_amount,
+            nonce,
+            expiry_secs,
+        )
+    }
+
+    /// Callback handler for fund_voucher computation.
+    /// Debits the funder and opens the voucher for claiming.
+    #[arcium_callback(encrypted_ix = "fund_voucher")]
+    pub fn fund_voucher_callback(
+        ctx: Context<FundVoucherCallback>,
+        output: SignedComputationOutputs<FundVoucherOutput>,
+    ) -> Result<()> {
+        let o = match output.verify_output(
+            &ctx.accounts.cluster_account,
+            &ctx.accounts.computation_account,
+        ) {
+            Ok(output) => output,
+            Err(err) => {
+                msg!(
+                    "fund_voucher_callback verify_output failed: {:?}, computation={}",
+                    err,
+                    ctx.accounts.computation_account.key()
+                );
+                emit_callback_failed("fund_voucher", ctx.accounts.computation_account.key());
+                return Err(ErrorCode::AbortedComputation.into());
+            }
+        };
+
+        // o.field_0.field_0 = funder's new balance, o.field_0.field_1 = escrow
+        // A voucher reset by reset_voucher no longer waits on this computation
+        let voucher = &mut ctx.accounts.voucher;
+        require!(
+            voucher.status == VOUCHER_STATUS_FUNDING
+                && voucher.pending_computation == ctx.accounts.computation_account.key(),
+            ErrorCode::InvalidPendingOperation
+        );
+        ctx.accounts
+            .funder_account
+            .set_credit(voucher.asset_id, o.field_0.field_0.ciphertexts[0]);
+        ctx.accounts
+            .funder_account
+            .set_nonce(voucher.asset_id, o.field_0.field_0.nonce);
+        voucher.escrow_amount = o.field_0.field_1.ciphertexts[0];
+        voucher.escrow_nonce = o.field_0.field_1.nonce;
+        voucher.status = VOUCHER_STATUS_OPEN;
+
+        emit!(VoucherCreatedEvent {
+            claim_key: voucher.claim_key,
+            funder: voucher.funder,
+            asset_id: voucher.asset_id,
+            expires_at: voucher.expires_at,
+            seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+        });
+
+        msg!(
+            "Voucher {} open until {}",
+            voucher.claim_key,
+            voucher.expires_at
+        );
+        Ok(())
+    }
+
+    /// Claim an open voucher into the caller's privacy account. Must be
+    /// signed by the voucher's claim key as well as the claimer.
+    ///
+    /// # Arguments
+    /// * `computation_offset` - Unique ID for MPC computation
+    pub fn claim_voucher(ctx: Context<ClaimVoucher>, computation_offset: u64) -> Result<()> {
+        instructions::claim_voucher::handler(ctx, computation_offset)
+    }
+
+    /// Funder takes back an expired, unclaimed voucher.
+    ///
+    /// # Arguments
+    /// * `computation_offset` - Unique ID for MPC computation
+    pub fn reclaim_voucher(ctx: Context<ReclaimVoucher>, computation_offset: u64) -> Result<()> {
+        instructions::reclaim_voucher::handler(ctx, computation_offset)
+    }
+
+    /// Recover a voucher whose funding, claim or reclaim callback never
+    /// arrived. Permissionless.
+    pub fn reset_voucher(ctx: Context<ResetVoucher>) -> Result<()> {
+        instructions::reset_voucher::handler(ctx)
+    }
+
+    /// Callback handler for redeem_voucher computation.
+    /// Credits the claimer and closes the voucher (rent back to the funder).
+    #[arcium_callback(encrypted_ix = "redeem_voucher")]
+    pub fn redeem_voucher_callback(
+        ctx: Context<RedeemVoucherCallback>,
+        output: SignedComputationOutputs<RedeemVoucherOutput>,
+    ) -> Result<()> {
+        let balance = match output.verify_output(
+            &ctx.accounts.cluster_account,
+            &ctx.accounts.computation_account,
+        ) {
+            Ok(RedeemVoucherOutput { field_0 }) => field_0,
+            Err(err) => {
+                msg!(
+                    "redeem_voucher_callback verify_output failed: {:?}, computation={}",
+                    err,
+                    ctx.accounts.computation_account.key()
+                );
+                emit_callback_failed("redeem_voucher", ctx.accounts.computation_account.key());
+                return Err(ErrorCode::AbortedComputation.into());
+            }
+        };
+
+        // A voucher reset by reset_voucher no longer waits on this computation
+        let voucher = &ctx.accounts.voucher;
+        require!(
+            voucher.status == VOUCHER_STATUS_REDEEMING
+                && voucher.pending_computation == ctx.accounts.computation_account.key(),
+            ErrorCode::InvalidPendingOperation
+        );
+        ctx.accounts
+            .user_account
+            .set_credit(voucher.asset_id, balance.ciphertexts[0]);
+        ctx.accounts
+            .user_account
+            .set_nonce(voucher.asset_id, balance.nonce);
+        let claimer = ctx.accounts.user_account.owner;
+
+        emit!(VoucherRedeemedEvent {
+            claim_key: voucher.claim_key,
+            funder: voucher.funder,
+            claimer,
+            asset_id: voucher.asset_id,
+            claimed: claimer != voucher.funder,
+            seq: ctx.accounts.event_cursor.next(Clock::get()?.slot),
+        });
+
+        msg!("Voucher {} redeemed by {}", voucher.claim_key, claimer);
+        Ok(())
+    }
+}
+
+#[queue_computation_accounts("add_together", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct AddTogether<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Box<Account<'info, ArciumSignerAccount>>,
+    #[account(
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_ADD_TOGETHER)
+    )]
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    pub cluster_account: Box<Account<'info, Cluster>>,
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Box<Account<'info, FeePool>>,
+    #[account(
+        mut,
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+    )]
+    pub clock_account: Box<Account<'info, ClockAccount>>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+}
+
+#[callback_accounts("add_together")]
+#[derive(Accounts)]
+pub struct AddTogetherCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_ADD_TOGETHER)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Account<'info, MXEAccount>,
+    /// CHECK: computation_account, checked by arcium program via constraints in the callback context.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+}
+
+#[init_computation_definition_accounts("add_together", payer)]
+#[derive(Accounts)]
+pub struct InitAddTogetherCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program.
+    /// Can't check it here as it's not initialized yet.
+    pub comp_def_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_mxe_lut_pda!(mxe_account.lut_offset_slot))]
+    /// CHECK: address_lookup_table, checked by arcium program.
+    pub address_lookup_table: UncheckedAccount<'info>,
+    #[account(address = LUT_PROGRAM_ID)]
+    /// CHECK: lut_program is the Address Lookup Table program.
+    pub lut_program: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+// =============================================================================
+// INIT ADD_BALANCE COMPUTATION DEFINITION (Phase 6)
+// =============================================================================
+
+#[init_computation_definition_accounts("add_balance", payer)]
+#[derive(Accounts)]
+pub struct InitAddBalanceCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program.
+    /// Can't check it here as it's not initialized yet.
+    pub comp_def_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_mxe_lut_pda!(mxe_account.lut_offset_slot))]
+    /// CHECK: address_lookup_table, checked by arcium program.
+    pub address_lookup_table: UncheckedAccount<'info>,
+    #[account(address = LUT_PROGRAM_ID)]
+    /// CHECK: lut_program is the Address Lookup Table program.
+    pub lut_program: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+// =============================================================================
+// ADD BALANCE QUEUE COMPUTATION ACCOUNTS (Phase 6)
+// =============================================================================
+// These accounts are needed when calling add_balance instruction.
+// Combines token transfer + MPC queue in single instruction.
+
+#[queue_computation_accounts("add_balance", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct AddBalance<'info> {
+    // =========================================================================
+    // PAYER & USER
+    // =========================================================================
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The user making the deposit (must sign for token transfer)
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    // =========================================================================
+    // TOKEN ACCOUNTS
+    // =========================================================================
+    /// The pool account (for vault authority)
+    #[account(
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// User's privacy account (will have encrypted balance updated via callback)
+    #[account(
+        mut,
+        seeds = [USER_SEED, &[pool.pool_id], user.key().as_ref()],
+        bump = user_account.bump,
+    )]
+    pub user_account: Box<Account<'info, UserProfile>>,
+
+    /// Context of this computation for the callback (see PendingOperation)
+    #[account(
+        init,
+        payer = payer,
+        space = PendingOperation::SIZE,
+        seeds = [
+            PENDING_OPERATION_SEED,
+            &[pool.pool_id],
+            user.key().as_ref(),
+            &computation_offset.to_le_bytes(),
+        ],
+        bump,
+    )]
+    pub pending_operation: Box<Account<'info, PendingOperation>>,
+
+    /// Mint of the asset being deposited (must match pool mint for asset_id)
+    #[account(mint::token_program = token_program)]
+    pub mint: Box<InterfaceAccount<'info, anchor_spl::token_interface::Mint>>,
+
+    /// User's token account for the asset being deposited (source of funds)
+    /// Caller must provide the correct token account matching the asset_id
+    #[account(
+        mut,
+        constraint = user_token_account.owner == user.key() @ ErrorCode::InvalidOwner,
+        constraint = user_token_account.mint == mint.key() @ ErrorCode::InvalidMint,
+    )]
+    pub user_token_account: Box<InterfaceAccount<'info, anchor_spl::token_interface::TokenAccount>>,
+
+    /// Protocol's vault for the asset being deposited (destination of funds)
+    /// Caller must provide the correct vault matching the asset_id
+    #[account(
+        mut,
+        constraint = vault.mint == mint.key() @ ErrorCode::InvalidMint,
+    )]
+    pub vault: Box<InterfaceAccount<'info, anchor_spl::token_interface::TokenAccount>>,
+
+    pub token_program: Interface<'info, anchor_spl::token_interface::TokenInterface>,
+
+    /// Transfer policy - required once the asset is restricted
+    #[account(
+        seeds = [ASSET_POLICY_SEED, &[pool.pool_id], mint.key().as_ref()],
+        bump = asset_policy.bump,
+        constraint = asset_policy.token_program == token_program.key() @ ErrorCode::InvalidAssetPolicy,
+    )]
+    pub asset_policy: Option<Box<Account<'info, AssetTransferPolicy>>>,
+
+    /// Issuer allowlist entry for the user (restricted assets only)
+    /// CHECK: Verified against asset_policy by check_issuer_allowlist
+    pub allowlist_entry: Option<UncheckedAccount<'info>>,
+
+    // =========================================================================
+    // ARCIUM MPC ACCOUNTS
+    // =========================================================================
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Box<Account<'info, ArciumSignerAccount>>,
+
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+
+    #[account(
+        mut,
+        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_ADD_BALANCE))]
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    pub cluster_account: Box<Account<'info, Cluster>>,
+
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Box<Account<'info, FeePool>>,
+
+    #[account(
+        mut,
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+    )]
+    pub clock_account: Box<Account<'info, ClockAccount>>,
+
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+}
+
+// =============================================================================
+// ADD BALANCE CALLBACK ACCOUNTS (Phase 6)
+// =============================================================================
+
+#[callback_accounts("add_balance")]
+#[derive(Accounts)]
+pub struct AddBalanceCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_ADD_BALANCE))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Account<'info, MXEAccount>,
+
+    /// CHECK: computation_account, checked by arcium program via constraints in the callback context.
+    pub computation_account: UncheckedAccount<'info>,
+
+    #[account(address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    pub cluster_account: Account<'info, Cluster>,
+
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+
+    /// User's privacy account - receives the updated encrypted balance
+    #[account(mut)]
+    pub user_account: Box<Account<'info, UserProfile>>,
+
+    /// Context of this computation, closed on success
+    #[account(
+        mut,
+        constraint = pending_operation.owner == user_account.owner @ ErrorCode::InvalidPendingOperation,
+    )]
+    pub pending_operation: Box<Account<'info, PendingOperation>>,
+
+    /// CHECK: rent destination, must be the profile's owner
+    #[account(mut, address = user_account.owner)]
+    pub owner: UncheckedAccount<'info>,
+
+    /// Pool event sequence (stamps `seq` into emitted events); the queue
+    /// site passes the pool's cursor
+    #[account(mut)]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+}
+
+// =============================================================================
+// DEPOSIT FOR ACCOUNTS
+// =============================================================================
+// Tokens come from the depositor; the credit_balance computation credits the
+// recipient's UserProfile under the recipient's key.
+
+#[init_computation_definition_accounts("credit_balance", payer)]
+#[derive(Accounts)]
+pub struct InitCreditBalanceCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program.
+    /// Can't check it here as it's not initialized yet.
+    pub comp_def_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_mxe_lut_pda!(mxe_account.lut_offset_slot))]
+    /// CHECK: address_lookup_table, checked by arcium program.
+    pub address_lookup_table: UncheckedAccount<'info>,
+    #[account(address = LUT_PROGRAM_ID)]
+    /// CHECK: lut_program is the Address Lookup Table program.
+    pub lut_program: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[queue_computation_accounts("credit_balance", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct DepositFor<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Wallet funding the deposit (must sign for token transfer)
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+
+    /// The pool account (for vault authority)
+    #[account(
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// Recipient's privacy account (credited via callback, must exist)
+    #[account(
+        mut,
+        seeds = [USER_SEED, &[pool.pool_id], recipient_account.owner.as_ref()],
+        bump = recipient_account.bump,
+    )]
+    pub recipient_account: Box<Account<'info, UserProfile>>,
+
+    /// Mint of the asset being deposited (must match pool mint for asset_id)
+    #[account(mint::token_program = token_program)]
+    pub mint: Box<InterfaceAccount<'info, anchor_spl::token_interface::Mint>>,
+
+    /// Depositor's token account for the asset (source of funds)
+    #[account(
+        mut,
+        constraint = depositor_token_account.owner == depositor.key() @ ErrorCode::InvalidOwner,
+        constraint = depositor_token_account.mint == mint.key() @ ErrorCode::InvalidMint,
+    )]
+    pub depositor_token_account:
+        Box<InterfaceAccount<'info, anchor_spl::token_interface::TokenAccount>>,
+
+    /// Protocol's vault for the asset being deposited (destination of funds)
+    #[account(
+        mut,
+        constraint = vault.mint == mint.key() @ ErrorCode::InvalidMint,
+    )]
+    pub vault: Box<InterfaceAccount<'info, anchor_spl::token_interface::TokenAccount>>,
+
+    pub token_program: Interface<'info, anchor_spl::token_interface::TokenInterface>,
+
+    /// Transfer policy - required once the asset is restricted
+    #[account(
+        seeds = [ASSET_POLICY_SEED, &[pool.pool_id], mint.key().as_ref()],
+        bump = asset_policy.bump,
+        constraint = asset_policy.token_program == token_program.key() @ ErrorCode::InvalidAssetPolicy,
+    )]
+    pub asset_policy: Option<Box<Account<'info, AssetTransferPolicy>>>,
+
+    /// Issuer allowlist entry for the depositor (restricted assets only)
+    /// CHECK: Verified against asset_policy by check_issuer_allowlist
+    pub allowlist_entry: Option<UncheckedAccount<'info>>,
+
+    /// Issuer allowlist entry for the recipient (restricted assets only)
+    /// CHECK: Verified against asset_policy by check_issuer_allowlist
+    pub recipient_allowlist_entry: Option<UncheckedAccount<'info>>,
+
+    // =========================================================================
+    // ARCIUM MPC ACCOUNTS
+    // =========================================================================
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Box<Account<'info, ArciumSignerAccount>>,
+
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+
+    #[account(
+        mut,
+        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_CREDIT_BALANCE))]
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    pub cluster_account: Box<Account<'info, Cluster>>,
+
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Box<Account<'info, FeePool>>,
+
+    #[account(
+        mut,
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+    )]
+    pub clock_account: Box<Account<'info, ClockAccount>>,
+
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+}
+
+#[callback_accounts("credit_balance")]
+#[derive(Accounts)]
+pub struct CreditBalanceCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_CREDIT_BALANCE))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Account<'info, MXEAccount>,
+
+    /// CHECK: computation_account, checked by arcium program via constraints in the callback context.
+    pub computation_account: UncheckedAccount<'info>,
+
+    #[account(address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    pub cluster_account: Account<'info, Cluster>,
+
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+
+    /// Recipient's privacy account - receives the updated encrypted balance
+    #[account(mut)]
+    pub user_account: Box<Account<'info, UserProfile>>,
+
+    /// Pool whose mints identify the deposited asset
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// Mint of the deposited asset
+    pub mint: Box<InterfaceAccount<'info, anchor_spl::token_interface::Mint>>,
+
+    /// Pool event sequence (stamps `seq` into emitted events)
+    #[account(
+        mut,
+        seeds = [EVENT_CURSOR_SEED, &[pool.pool_id]],
+        bump = event_cursor.bump,
+    )]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+}
+
+#[event]
+pub struct SumEvent {
+    pub sum: [u8; 32],
+    pub nonce: [u8; 16],
+    /// Always 0: the demo computation belongs to no pool
+    pub seq: u64,
+}
+
+/// Emitted when the rent sponsor pool reimburses a new UserProfile's rent
+#[event]
+pub struct RentSponsoredEvent {
+    pub owner: Pubkey,
+    pub amount: u64,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when the authority funds the rent sponsor pool
+#[event]
+pub struct RentSponsorFundedEvent {
+    pub amount: u64,
+    /// Pool lamports after the deposit
+    pub balance: u64,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when the authority withdraws from the rent sponsor pool
+#[event]
+pub struct RentSponsorWithdrawnEvent {
+    pub amount: u64,
+    /// Pool lamports after the withdrawal
+    pub balance: u64,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when a legacy UserProfile is rewritten in the current layout
+#[event]
+pub struct UserAccountMigratedEvent {
+    pub owner: Pubkey,
+    pub old_size: u32,
+    pub new_size: u32,
+    /// Rent returned to the owner
+    pub refunded: u64,
+    /// Whether an inline settlement preference had to be dropped
+    pub preference_dropped: bool,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when a versioned account is upgraded by a migrate_* instruction
+#[event]
+pub struct AccountVersionMigratedEvent {
+    pub account: Pubkey,
+    pub from_version: u8,
+    pub to_version: u8,
+    pub old_size: u32,
+    pub new_size: u32,
+    /// EventCursor sequence number (0 if the transaction failed, or for a
+    /// pool migrated before its cursor exists)
+    pub seq: u64,
+}
+
+#[event]
+pub struct DepositEvent {
+    pub user: Pubkey,
+    pub encrypted_balance: [u8; 32],
+    pub nonce: [u8; 16],
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+#[event]
+pub struct WithdrawEvent {
+    pub user: Pubkey,
+    pub encrypted_balance: [u8; 32],
+    pub nonce: [u8; 16],
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+#[event]
+pub struct TransferEvent {
+    pub from: Pubkey,
+    pub to: Pubkey,
+    pub asset_id: u8,
+    pub amount: u64,
+    pub sender_nonce: [u8; 16],
+    /// Memo encrypted to the recipient's x25519 key (empty if none)
+    pub memo: Vec<u8>,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when a multi-asset transfer settles (amounts stay encrypted)
+#[event]
+pub struct MultiTransferEvent {
+    pub from: Pubkey,
+    pub to: Pubkey,
+    /// Sender's new balance nonces (USDC, TSLA, SPY, AAPL)
+    pub sender_nonces: [[u8; 16]; 4],
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when a batch payout settles (amounts stay encrypted)
+#[event]
+pub struct TransferBatchEvent {
+    pub from: Pubkey,
+    /// Recipient wallets in slot order
+    pub recipients: Vec<Pubkey>,
+    pub asset_id: u8,
+    pub sender_nonce: [u8; 16],
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when a transfer request's amount is escrowed and it can be claimed
+#[event]
+pub struct TransferRequestedEvent {
+    pub transfer_id: u64,
+    pub sender: Pubkey,
+    pub recipient: Pubkey,
+    pub asset_id: u8,
+    /// Unix timestamp after which the sender may reclaim
+    pub expires_at: i64,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when a transfer request is claimed or reclaimed and closed
+#[event]
+pub struct TransferReleasedEvent {
+    pub transfer_id: u64,
+    pub sender: Pubkey,
+    pub recipient: Pubkey,
+    pub asset_id: u8,
+    /// True if the recipient claimed, false if the sender reclaimed
+    pub claimed: bool,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when reset_transfer_request recovers a request whose callback
+/// never arrived
+#[event]
+pub struct TransferRequestResetEvent {
+    pub transfer_id: u64,
+    pub sender: Pubkey,
+    /// TRANSFER_STATUS_* the request was stuck in
+    pub status: u8,
+    /// True if the never-funded request was closed, false if it was opened
+    /// again for claim or reclaim
+    pub closed: bool,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when a voucher's amount is escrowed and it can be claimed
+#[event]
+pub struct VoucherCreatedEvent {
+    pub claim_key: Pubkey,
+    pub funder: Pubkey,
+    pub asset_id: u8,
+    /// Unix timestamp after which the funder may reclaim
+    pub expires_at: i64,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when reset_voucher recovers a voucher whose callback never
+/// arrived
+#[event]
+pub struct VoucherResetEvent {
+    pub claim_key: Pubkey,
+    pub funder: Pubkey,
+    /// VOUCHER_STATUS_* the voucher was stuck in
+    pub status: u8,
+    /// True if the never-funded voucher was closed, false if it was opened
+    /// again for claim or reclaim
+    pub closed: bool,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when a voucher is claimed or reclaimed and closed
+#[event]
+pub struct VoucherRedeemedEvent {
+    pub claim_key: Pubkey,
+    pub funder: Pubkey,
+    /// Wallet credited with the escrow
+    pub claimer: Pubkey,
+    pub asset_id: u8,
+    /// False if the funder took the escrow back
+    pub claimed: bool,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+#[event]
+pub struct OrderPlacedEvent {
+    pub user: Pubkey,
+    pub batch_id: u64,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+#[event]
+pub struct SettlementEvent {
+    pub user: Pubkey,
+    pub batch_id: u64,
+    pub encrypted_payout: [u8; 32],
+    pub nonce: [u8; 16],
+    /// DEBUG: Revealed payout value from MPC for verification
+    pub revealed_payout: u64,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when a basket order's legs are accumulated into the batch
+#[event]
+pub struct BasketOrderPlacedEvent {
+    pub user: Pubkey,
+    pub batch_id: u64,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when a settlement leaves a staged order ready for place_staged_order
+#[event]
+pub struct StagedOrderReadyEvent {
+    pub user: Pubkey,
+    pub source_asset_id: u8,
+    pub order_nonce: [u8; 16],
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when a user sets or clears their settlement currency preference
+#[event]
+pub struct SettlementPreferenceUpdatedEvent {
+    pub user: Pubkey,
+    pub enabled: bool,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when settlement stages an unexecuted good-til-batch order again
+#[event]
+pub struct OrderRolledOverEvent {
+    pub user: Pubkey,
+    /// Batch the order failed to execute in
+    pub batch_id: u64,
+    pub valid_until_batch: u64,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when a staged order is dropped because its validity has passed
+#[event]
+pub struct OrderExpiredEvent {
+    pub user: Pubkey,
+    pub batch_id: u64,
+    pub valid_until_batch: u64,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when force_settle pays its bounty to the cranker
+#[event]
+pub struct SettlementBountyPaidEvent {
+    pub user: Pubkey,
+    pub batch_id: u64,
+    pub recipient: Pubkey,
+    pub asset_id: u8,
+    pub amount: u64,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when an unsettled payout is forfeited to the treasury
+#[event]
+pub struct PayoutForfeitedEvent {
+    pub user: Pubkey,
+    pub batch_id: u64,
+    pub asset_id: u8,
+    pub amount: u64,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when accrued execution fees are sent to the treasury
+#[event]
+pub struct FeesCollectedEvent {
+    pub asset_id: u8,
+    pub amount: u64,
+    pub treasury_token_account: Pubkey,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when the authority changes the settlement deadline
+#[event]
+pub struct SettlementDeadlineUpdatedEvent {
+    pub previous_secs: i64,
+    pub deadline_secs: i64,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when the authority changes the force_settle grace period
+#[event]
+pub struct ForceSettleGraceUpdatedEvent {
+    pub previous_secs: i64,
+    pub grace_secs: i64,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when the authority changes the MPC callback priority fee
+#[event]
+pub struct CallbackPriorityFeeUpdatedEvent {
+    pub previous_cu_price_micro: u64,
+    pub cu_price_micro: u64,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when the authority changes the co-operator or approval threshold
+#[event]
+pub struct BatchApprovalConfigUpdatedEvent {
+    pub co_operator: Pubkey,
+    pub threshold_usdc: u64,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when the authority configures registry-gated execution
+#[event]
+pub struct ExecutorRegistryConfiguredEvent {
+    pub gated: bool,
+    pub min_bond_lamports: u64,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when an admin records a new circuit for a computation definition
+#[event]
+pub struct CircuitUpgradedEvent {
+    pub comp_def_offset: u32,
+    /// Hash recorded before (zero on first registration)
+    pub previous_hash: [u8; 32],
+    pub hash: [u8; 32],
+    pub version: u16,
+    pub upgraded_by: Pubkey,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted by bootstrap with the deployment steps verified so far
+#[event]
+pub struct BootstrapProgressEvent {
+    pub bootstrap_flags: u64,
+    /// Steps of BOOTSTRAP_COMPLETE still missing
+    pub missing: u64,
+    pub complete: bool,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when an executor posts its bond
+#[event]
+pub struct ExecutorRegisteredEvent {
+    pub executor: Pubkey,
+    pub bond_lamports: u64,
+    pub registered_at: i64,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when the authority slashes an executor's bond to the treasury
+#[event]
+pub struct ExecutorSlashedEvent {
+    pub executor: Pubkey,
+    pub bond_lamports: u64,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when the authority snapshots the protocol config
+#[event]
+pub struct ConfigExportedEvent {
+    pub version: u16,
+    /// ConfigSnapshot export counter (not an EventCursor seq)
+    pub export_sequence: u64,
+    pub config_hash: [u8; 32],
+    pub exported_at: i64,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when the authority applies a config blob
+#[event]
+pub struct ConfigAppliedEvent {
+    pub version: u16,
+    pub config_hash: [u8; 32],
+    pub applied_by: Pubkey,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when the authority changes the keeper reward
+#[event]
+pub struct KeeperRewardUpdatedEvent {
+    pub previous_reward_bps: u16,
+    pub reward_bps: u16,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when the authority rotates an asset's price feeds
+#[event]
+pub struct OracleFeedUpdatedEvent {
+    pub asset_id: u8,
+    pub previous_feed: Pubkey,
+    pub feed: Pubkey,
+    pub fallback_feed: Pubkey,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when the authority changes the oracle limits
+#[event]
+pub struct OracleParamsUpdatedEvent {
+    pub max_price_age_secs: i64,
+    pub max_price_conf_bps: u16,
+    pub max_price_divergence_bps: u16,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when the authority changes the execution fee or trigger count
+#[event]
+pub struct PoolConfigUpdatedEvent {
+    pub previous_fee_bps: u16,
+    pub execution_fee_bps: u16,
+    pub previous_trigger_count: u8,
+    pub execution_trigger_count: u8,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when the authority replaces the operator
+#[event]
+pub struct OperatorUpdatedEvent {
+    pub previous_operator: Pubkey,
+    pub operator: Pubkey,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when the authority replaces the treasury
+#[event]
+pub struct TreasuryUpdatedEvent {
+    pub previous_treasury: Pubkey,
+    pub treasury: Pubkey,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when the authority proposes (or cancels) an authority transfer
+#[event]
+pub struct AuthorityProposedEvent {
+    pub authority: Pubkey,
+    /// Default address when the pending transfer was cancelled
+    pub pending_authority: Pubkey,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when the proposed wallet accepts the authority
+#[event]
+pub struct AuthorityTransferredEvent {
+    pub previous_authority: Pubkey,
+    pub authority: Pubkey,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when an admin grants a role
+#[event]
+pub struct RoleGrantedEvent {
+    pub role: u8,
+    pub member: Pubkey,
+    pub granted_by: Pubkey,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when an admin revokes a role
+#[event]
+pub struct RoleRevokedEvent {
+    pub role: u8,
+    pub member: Pubkey,
+    pub revoked_by: Pubkey,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when the protocol is paused or unpaused
+#[event]
+pub struct ProtocolPauseUpdatedEvent {
+    pub paused: bool,
+    pub updated_by: Pubkey,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when an admin opens or closes withdrawals during a pause
+#[event]
+pub struct EmergencyWithdrawUpdatedEvent {
+    pub enabled: bool,
+    pub paused: bool,
+    pub updated_by: Pubkey,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when the authority changes the batch trigger thresholds
+#[event]
+pub struct BatchConfigUpdatedEvent {
+    pub execution_trigger_count: u8,
+    pub min_active_pairs: u8,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when the authority changes the batch rate limit
+#[event]
+pub struct BatchRateLimitUpdatedEvent {
+    pub max_batches_per_window: u16,
+    pub window_secs: i64,
+    pub max_batch_wait_secs: i64,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when the authority changes the privacy floor
+#[event]
+pub struct PrivacyFloorUpdatedEvent {
+    pub previous_min_unique_users: u8,
+    pub min_unique_users: u8,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when the risk limits change
+#[event]
+pub struct RiskConfigUpdatedEvent {
+    pub max_batch_notional_usdc: u64,
+    pub max_daily_withdrawal: [u64; 4],
+    pub max_reserve_utilization_bps: u16,
+    pub updated_by: Pubkey,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when the authority changes the execution mode settings
+#[event]
+pub struct ExecutionModeUpdatedEvent {
+    pub execution_mode: u8,
+    pub spread_bps: u16,
+    pub max_utilization_bps: u16,
+    pub max_inventory: [u64; 4],
+    pub swap_program: Pubkey,
+    pub netting_window_secs: i64,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when the authority changes the max slippage
+#[event]
+pub struct MaxSlippageUpdatedEvent {
+    pub previous_slippage_bps: u16,
+    pub max_slippage_bps: u16,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when an expired netting buffer is sold on the external venue
+#[event]
+pub struct NettingBufferFlushedEvent {
+    pub pair_id: u8,
+    pub asset_id: u8,
+    pub amount_in: u64,
+    /// Realized output received by the reserves
+    pub amount_out: u64,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when the authority changes the equity market schedule
+#[event]
+pub struct MarketScheduleUpdatedEvent {
+    pub enforced: bool,
+    pub open_secs: u32,
+    pub close_secs: u32,
+    pub trading_days: u8,
+    pub holiday_count: u8,
+    pub equity_assets: u8,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when the authority changes a pair's execution policy
+#[event]
+pub struct PairPolicyUpdatedEvent {
+    pub pair_id: u8,
+    pub strategy: u8,
+    pub reserve_share_bps: u16,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when the authority halts/resumes a pair or changes its fee
+#[event]
+pub struct PairConfiguredEvent {
+    pub pair_id: u8,
+    pub base_asset: u8,
+    pub quote_asset: u8,
+    pub enabled: bool,
+    pub fee_bps: u16,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when the authority changes a pair's price band
+#[event]
+pub struct PriceBandUpdatedEvent {
+    pub pair_id: u8,
+    pub max_deviation_bps: u16,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted by the reveal callback for each pair netted outside its price
+/// band; the batch waits for override_price_band
+#[event]
+pub struct PriceBandBreachedEvent {
+    pub batch_id: u64,
+    pub pair_id: u8,
+    pub execution_price: u64,
+    pub reference_price: u64,
+    pub max_deviation_bps: u16,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when the authority accepts a batch's out-of-band prices
+#[event]
+pub struct PriceBandOverriddenEvent {
+    pub batch_id: u64,
+    pub authority: Pubkey,
+    pub breached_pairs: u8,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when a pair's net surplus is filled on the external venue
+/// (or by the reserves, when they beat the venue quote)
+#[event]
+pub struct SurplusRoutedEvent {
+    pub batch_id: u64,
+    pub pair_id: u8,
+    pub source_asset_id: u8,
+    pub amount_in: u64,
+    /// Realized output credited to the other side's pool
+    pub amount_out: u64,
+    /// Filled by the reserves instead of the venue (POLICY_BEST_QUOTE)
+    pub via_reserves: bool,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when execute_swaps partially fills a pair because a reserve can
+/// no longer pay what the reveal callback netted against it
+#[event]
+pub struct ReserveShortfallEvent {
+    pub batch_id: u64,
+    pub pair_id: u8,
+    /// Reserve asset that fell short
+    pub asset_id: u8,
+    /// Amount the reveal callback expected the reserve to pay
+    pub required: u64,
+    /// Amount it paid
+    pub delivered: u64,
+    /// Reduced fill ratios settlement uses
+    pub fill_ratio_a: u32,
+    pub fill_ratio_b: u32,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when an MPC callback fails output verification.
+/// The callback transaction reverts, but its logs (and this event) remain
+/// readable from the failed transaction so keepers can re-queue the work.
+#[event]
+pub struct CallbackFailedEvent {
+    /// Circuit name (e.g. "accumulate_order")
+    pub encrypted_ix: String,
+    pub computation: Pubkey,
+    pub failed_at: i64,
+    pub error: ErrorInfo,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+// Rejection events below carry the same ErrorInfo as the returned error, so
+// clients read the reason from the event instead of parsing msg! logs. Events
+// emitted before an Err remain in the failed transaction's logs.
+
+/// Emitted when MPC rejects an order (accumulate_order or accumulate_basket)
+#[event]
+pub struct OrderRejectedEvent {
+    pub user: Pubkey,
+    pub batch_id: u64,
+    pub error: ErrorInfo,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when MPC rejects a settlement (e.g. route doesn't match the order)
+#[event]
+pub struct SettlementRejectedEvent {
+    pub user: Pubkey,
+    pub batch_id: u64,
+    pub error: ErrorInfo,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when MPC rejects a withdrawal (insufficient encrypted balance)
+#[event]
+pub struct WithdrawalRejectedEvent {
+    pub user: Pubkey,
+    pub asset_id: u8,
+    pub amount: u64,
+    pub error: ErrorInfo,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when a withdrawal whose callback never arrived is cancelled
+#[event]
+pub struct WithdrawalCancelledEvent {
+    pub user: Pubkey,
+    pub asset_id: u8,
+    pub amount: u64,
+    /// Computation account of the cancelled withdrawal
+    pub computation: Pubkey,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when a batch reveal fails: the callback output failed
+/// verification (retryable with retry_execute_batch), or its result can't be
+/// recorded (gap or collision)
+#[event]
+pub struct BatchExecutionFailedEvent {
+    pub batch_id: u64,
+    pub error: ErrorInfo,
+    /// Accumulator slot holding the batch
+    pub slot: u8,
+    /// computation_offset of the failed reveal
+    pub computation_offset: u64,
+    /// Retries already used (MAX_REVEAL_RETRIES allowed)
+    pub retry_count: u8,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when retry_execute_batch re-queues a failed or stale reveal
+#[event]
+pub struct BatchRevealRetriedEvent {
+    pub batch_id: u64,
+    pub slot: u8,
+    pub failed_computation_offset: u64,
+    pub computation_offset: u64,
+    pub retry_count: u8,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when an owner authorizes or refreshes a session key
+#[event]
+pub struct DelegateCreatedEvent {
+    pub owner: Pubkey,
+    pub delegate: Pubkey,
+    pub expires_at: i64,
+    pub max_orders_per_batch: u8,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when an owner revokes a session key
+#[event]
+pub struct DelegateRevokedEvent {
+    pub owner: Pubkey,
+    pub delegate: Pubkey,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when an owner sets a delegate's encrypted allowance for an asset
+#[event]
+pub struct DelegateAllowanceSetEvent {
+    pub owner: Pubkey,
+    pub delegate: Pubkey,
+    pub asset_id: u8,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when a user registers or replaces their beneficiary
+#[event]
+pub struct BeneficiarySetEvent {
+    pub owner: Pubkey,
+    pub beneficiary: Pubkey,
+    pub inactivity_period_secs: i64,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when a user removes their beneficiary
+#[event]
+pub struct BeneficiaryRemovedEvent {
+    pub owner: Pubkey,
+    pub beneficiary: Pubkey,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when a beneficiary sweeps an inactive owner's asset balance
+#[event]
+pub struct InheritanceClaimedEvent {
+    pub owner: Pubkey,
+    pub beneficiary: Pubkey,
+    pub asset_id: u8,
+    pub claimed_at: i64,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when a user registers or replaces their recovery guardians
+#[event]
+pub struct GuardiansSetEvent {
+    pub owner: Pubkey,
+    pub guardians: Vec<Pubkey>,
+    pub threshold: u8,
+    pub challenge_period_secs: i64,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when a guardian approves a recovery
+#[event]
+pub struct RecoveryApprovedEvent {
+    pub owner: Pubkey,
+    pub guardian: Pubkey,
+    pub new_owner: Pubkey,
+    /// Approvals of the proposal so far
+    pub approvals: u8,
+    /// Unix timestamp the threshold was reached (0 = not yet)
+    pub approved_at: i64,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when the owner cancels a recovery in progress
+#[event]
+pub struct RecoveryCancelledEvent {
+    pub owner: Pubkey,
+    pub proposed_owner: Pubkey,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when a recovered account's balances move to the new owner
+#[event]
+pub struct AccountRecoveredEvent {
+    pub previous_owner: Pubkey,
+    pub new_owner: Pubkey,
+    pub new_pubkey: [u8; 32],
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when a user designates or replaces their viewing key
+#[event]
+pub struct ViewingKeySetEvent {
+    pub owner: Pubkey,
+    pub viewing_pubkey: [u8; 32],
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when a user grants a new viewer access to their balances
+#[event]
+pub struct ViewerAddedEvent {
+    pub owner: Pubkey,
+    pub viewer_pubkey: [u8; 32],
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when a user's balances are re-encrypted for a viewer
+#[event]
+pub struct BalancesDisclosedEvent {
+    pub owner: Pubkey,
+    pub viewer_pubkey: [u8; 32],
+    pub disclosed_at: i64,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when a user revokes a viewer grant
+#[event]
+pub struct ViewerRemovedEvent {
+    pub owner: Pubkey,
+    pub viewer_pubkey: [u8; 32],
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when a user's balances move to a new x25519 key
+#[event]
+pub struct UserKeyRotatedEvent {
+    pub owner: Pubkey,
+    pub previous_pubkey: [u8; 32],
+    pub new_pubkey: [u8; 32],
+    pub rotated_at: i64,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when a UserProfile with zero balances is closed
+#[event]
+pub struct UserAccountClosedEvent {
+    pub owner: Pubkey,
+    /// Rent lamports freed by the profile
+    pub refunded: u64,
+    /// Whether the rent went back to the RentSponsor pool instead of the owner
+    pub rent_sponsored: bool,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when a balance is re-encrypted under the owner's viewing key
+#[event]
+pub struct ViewableBalanceRefreshedEvent {
+    pub owner: Pubkey,
+    pub asset_id: u8,
+    pub viewing_pubkey: [u8; 32],
+    pub encrypted_balance: [u8; 32],
+    pub nonce: [u8; 16],
+    pub refreshed_at: i64,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when a user creates a DCA schedule
+#[event]
+pub struct DcaCreatedEvent {
+    pub owner: Pubkey,
+    pub dca_id: u64,
+    pub interval_secs: i64,
+    pub next_execution_ts: i64,
+    pub max_executions: u32,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when the crank places a DCA order into a batch
+#[event]
+pub struct DcaExecutedEvent {
+    pub owner: Pubkey,
+    pub dca_id: u64,
+    pub batch_id: u64,
+    pub executions: u32,
+    pub next_execution_ts: i64,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when a user creates a savings bucket
+#[event]
+pub struct SavingsBucketCreatedEvent {
+    pub owner: Pubkey,
+    pub bucket_id: u8,
+    pub label: [u8; 16],
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when USDC moves between the trading balance and a savings bucket
+/// (the amount stays private)
+#[event]
+pub struct SavingsMovedEvent {
+    pub owner: Pubkey,
+    pub bucket_id: u8,
+    /// New nonce of the bucket balance
+    pub bucket_nonce: [u8; 16],
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when a payer opens a payment stream (the rate stays encrypted)
+#[event]
+pub struct StreamCreatedEvent {
+    pub payer: Pubkey,
+    pub payee: Pubkey,
+    pub stream_id: u64,
+    pub asset_id: u8,
+    pub start_ts: i64,
+    pub end_ts: i64,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when an advance_stream callback lands
+#[event]
+pub struct StreamAdvancedEvent {
+    pub payer: Pubkey,
+    pub payee: Pubkey,
+    pub stream_id: u64,
+    /// False if the payer was short and nothing moved
+    pub paid: bool,
+    /// Accrual is paid out up to this timestamp
+    pub last_advanced_ts: i64,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when a payer cancels a payment stream
+#[event]
+pub struct StreamCancelledEvent {
+    pub payer: Pubkey,
+    pub payee: Pubkey,
+    pub stream_id: u64,
+    pub last_advanced_ts: i64,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when the authority freezes an asset
+#[event]
+pub struct AssetFrozenEvent {
+    pub asset_id: u8,
+    pub frozen_at: i64,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when the authority starts the unfreeze timelock for an asset
+#[event]
+pub struct AssetUnfreezeRequestedEvent {
+    pub asset_id: u8,
+    pub available_at: i64,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when a frozen asset is reopened
+#[event]
+pub struct AssetUnfrozenEvent {
+    pub asset_id: u8,
+    pub unfrozen_at: i64,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when the authority delists or relists an asset
+#[event]
+pub struct AssetDelistingUpdatedEvent {
+    pub asset_id: u8,
+    pub delisted: bool,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when the authority registers or refreshes an asset transfer policy
+#[event]
+pub struct AssetPolicyConfiguredEvent {
+    pub asset_id: u8,
+    pub mint: Pubkey,
+    pub token_program: Pubkey,
+    pub transfer_hook_program: Pubkey,
+    pub allowlist_program: Pubkey,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when the authority lists a new asset
+#[event]
+pub struct AssetRegisteredEvent {
+    pub asset_id: u8,
+    pub mint: Pubkey,
+    pub vault: Pubkey,
+    pub decimals: u8,
+    pub symbol: String,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when a user pauses a DCA schedule
+#[event]
+pub struct DcaPausedEvent {
+    pub owner: Pubkey,
+    pub dca_id: u64,
+    pub paused_at: i64,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when a user resumes a DCA schedule
+#[event]
+pub struct DcaResumedEvent {
+    pub owner: Pubkey,
+    pub dca_id: u64,
+    pub skipped: u32,
+    pub skipped_count: u32,
+    pub next_execution_ts: i64,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when a user cancels a DCA schedule
+#[event]
+pub struct DcaCancelledEvent {
+    pub owner: Pubkey,
+    pub dca_id: u64,
+    pub executions: u32,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when batch meets execution criteria (8+ orders, 2+ pairs)
+/// MPC computes requirements check and reveals batch_ready boolean
+/// Can be used by external services (webhooks) to trigger batch execution
+#[event]
+pub struct BatchReadyEvent {
+    pub batch_id: u64,
+    pub batch_accumulator: Pubkey,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Attestation that all protocol token accounts passed the vault audit
+#[event]
+pub struct VaultAuditEvent {
+    pub auditor: Pubkey,
+    pub pool: Pubkey,
+    pub accounts_checked: u8,
+    pub audited_at: i64,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted by check_invariants; violations is a bitmask of INVARIANT_* flags
+/// (0 = all invariants hold)
+#[event]
+pub struct InvariantReportEvent {
+    pub checker: Pubkey,
+    pub batch_id: u64,
+    pub violations: u16,
+    pub checked_at: i64,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when a reserve auction starts accepting bids
+#[event]
+pub struct ReserveAuctionOpenedEvent {
+    pub auction_id: u64,
+    pub asset_id: u8,
+    pub lot_amount: u64,
+    pub reserve_price: u64,
+    pub bid_deadline: i64,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when an encrypted bid is folded into the auction state.
+/// Bid amounts are never emitted.
+#[event]
+pub struct AuctionBidPlacedEvent {
+    pub auction_id: u64,
+    pub bid_count: u8,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when the MPC reveals the auction result
+#[event]
+pub struct ReserveAuctionClearedEvent {
+    pub auction_id: u64,
+    pub cleared: bool,
+    pub clearing_price: u64,
+    pub winner: Pubkey,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when the winner pays and receives the lot
+#[event]
+pub struct ReserveAuctionClaimedEvent {
+    pub auction_id: u64,
+    pub winner: Pubkey,
+    pub asset_id: u8,
+    pub lot_amount: u64,
+    pub clearing_price: u64,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when governance creates or updates the buyback configuration
+#[event]
+pub struct BuybackConfiguredEvent {
+    pub authority: Pubkey,
+    pub enabled: bool,
+    pub buyback_mint: Pubkey,
+    pub swap_program: Pubkey,
+    pub fee_share_bps: u16,
+    pub min_interval_secs: i64,
+    pub max_usdc_per_execution: u64,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted after each buyback-and-burn for transparency
+#[event]
+pub struct BuybackExecutedEvent {
+    pub buyback_mint: Pubkey,
+    pub usdc_spent: u64,
+    pub tokens_burned: u64,
+    pub total_usdc_spent: u64,
+    pub total_burned: u64,
+    pub executed_at: i64,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when batch MPC completes and BatchLog is created
+/// Backend listens for this to call execute_swaps
+#[event]
+pub struct BatchExecutedEvent {
+    pub batch_id: u64,
+    pub batch_log: Pubkey,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when execute_swaps pays the execute_batch caller
+#[event]
+pub struct KeeperRewardPaidEvent {
+    pub batch_id: u64,
+    pub executor: Pubkey,
+    /// USDC base units, taken from Pool.fees_owed
+    pub amount: u64,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted by the reveal callback when a batch exceeds the approval
+/// threshold; the co-operator approves it with approve_batch
+#[event]
+pub struct BatchApprovalRequestedEvent {
+    pub batch_id: u64,
+    pub notional_usdc: u64,
+    pub threshold_usdc: u64,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when the co-operator approves a large batch
+#[event]
+pub struct BatchApprovedEvent {
+    pub batch_id: u64,
+    pub co_operator: Pubkey,
+    pub notional_usdc: u64,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when execute_batch halts because the Pyth and Switchboard prices
+/// of an asset are further apart than OracleConfig.max_price_divergence_bps
+#[event]
+pub struct PriceDivergenceEvent {
+    pub batch_id: u64,
+    pub asset_id: u8,
+    pub primary_price: u64,
+    pub fallback_price: u64,
+    pub divergence_bps: u64,
+    pub max_divergence_bps: u16,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when a stale batch reveal is abandoned and the batch reopened
+#[event]
+pub struct BatchRevealRecoveredEvent {
+    pub batch_id: u64,
+    pub reveal_queued_at: i64,
+    pub recovered_at: i64,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when the authority discards a stuck batch with reset_batch
+#[event]
+pub struct BatchResetEvent {
+    pub slot: u8,
+    /// Discarded batch; its orders settle with a full refund
+    pub batch_id: u64,
+    /// batch_id the slot reopened with
+    pub new_batch_id: u64,
+    pub discarded_orders: u8,
+    /// BATCH_RESET_REASON_* code (EMERGENCY_FLUSH for flush_batch)
+    pub reason: u8,
+    pub reset_at: i64,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+/// Emitted when an admin clears a user's pending state
+#[event]
+pub struct PendingStateClearedEvent {
+    pub owner: Pubkey,
+    /// Whether a pending order was dropped
+    pub had_order: bool,
+    /// Batch of the dropped order (0 if none)
+    pub batch_id: u64,
+    pub pending_asset_id: u8,
+    /// CLEAR_PENDING_REASON_* code
+    pub reason: u8,
+    pub cleared_by: Pubkey,
+    pub cleared_at: i64,
+    /// EventCursor sequence number (0 if the transaction failed)
+    pub seq: u64,
+}
+
+// =============================================================================
+// CHECK PRIVACY ACCOUNT EXISTS (Phase 6.75)
+// =============================================================================
+
+/// Accounts for checking if a privacy account exists
+#[derive(Accounts)]
+pub struct CheckPrivacyAccountExists<'info> {
+    /// The privacy account to check
+    /// If this doesn't exist, Anchor will return AccountNotInitialized error
+    pub user_account: Box<Account<'info, UserProfile>>,
+}
+// INIT SUB_BALANCE COMPUTATION DEFINITION (Phase 6.5)
+// =============================================================================
+
+#[init_computation_definition_accounts("sub_balance", payer)]
+#[derive(Accounts)]
+pub struct InitSubBalanceCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program.
+    /// Can't check it here as it's not initialized yet.
+    pub comp_def_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_mxe_lut_pda!(mxe_account.lut_offset_slot))]
+    /// CHECK: address_lookup_table, checked by arcium program.
+    pub address_lookup_table: UncheckedAccount<'info>,
+    #[account(address = LUT_PROGRAM_ID)]
+    /// CHECK: lut_program is the Address Lookup Table program.
+    pub lut_program: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+// =============================================================================
+// INIT TRANSFER COMPUTATION DEFINITION (Phase 6.75)
+// =============================================================================
+
+#[init_computation_definition_accounts("transfer", payer)]
+#[derive(Accounts)]
+pub struct InitTransferCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program.
+    pub comp_def_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_mxe_lut_pda!(mxe_account.lut_offset_slot))]
+    /// CHECK: address_lookup_table, checked by arcium program.
+    pub address_lookup_table: UncheckedAccount<'info>,
+    #[account(address = LUT_PROGRAM_ID)]
+    /// CHECK: lut_program is the Address Lookup Table program.
+    pub lut_program: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+// =============================================================================
+// TRANSFER CALLBACK ACCOUNTS (Phase 6.75)
+// =============================================================================
+// Callback for transfer circuit - updates both sender and recipient balances.
+
+#[callback_accounts("transfer")]
+#[derive(Accounts)]
+pub struct TransferCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_TRANSFER))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Account<'info, MXEAccount>,
+
+    /// CHECK: computation_account, checked by arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+
+    #[account(address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    pub cluster_account: Account<'info, Cluster>,
+
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar
+    pub instructions_sysvar: AccountInfo<'info>,
+
+    // Application accounts (passed via CallbackAccount)
+    #[account(mut)]
+    pub sender_account: Box<Account<'info, UserProfile>>,
+
+    #[account(mut)]
+    pub recipient_account: Box<Account<'info, UserProfile>>,
+
+    /// Sender's memo staging account (only passed with a memo)
+    #[account(mut)]
+    pub transfer_memo: Option<Box<Account<'info, TransferMemo>>>,
+
+    /// Pool event sequence (stamps `seq` into emitted events); the queue
+    /// site passes the pool's cursor
+    #[account(mut)]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+}
+
+// =============================================================================
+// SUB BALANCE QUEUE COMPUTATION ACCOUNTS (Phase 6.5)
+// =============================================================================
+// These accounts are needed when calling sub_balance instruction.
+// Queues MPC computation; token transfer happens in callback.
+
+#[queue_computation_accounts("sub_balance", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct SubBalance<'info> {
+    // =========================================================================
+    // PAYER & USER
+    // =========================================================================
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The user making the withdrawal (must sign for authorization)
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    // =========================================================================
+    // TOKEN ACCOUNTS
+    // =========================================================================
+    /// The pool account (for vault authority in callback)
+    #[account(
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// User's privacy account (will have encrypted balance updated via callback)
+    #[account(
+        mut,
+        seeds = [USER_SEED, &[pool.pool_id], user.key().as_ref()],
+        bump = user_account.bump,
+        constraint = user_account.owner == user.key() @ ErrorCode::Unauthorized,
+    )]
+    pub user_account: Box<Account<'info, UserProfile>>,
+
+    /// Context of this computation for the callback (see PendingOperation)
+    #[account(
+        init,
+        payer = payer,
+        space = PendingOperation::SIZE,
+        seeds = [
+            PENDING_OPERATION_SEED,
+            &[pool.pool_id],
+            user.key().as_ref(),
+            &computation_offset.to_le_bytes(),
+        ],
+        bump,
+    )]
+    pub pending_operation: Box<Account<'info, PendingOperation>>,
+
+    /// Mint of the asset being withdrawn (must match pool mint for asset_id)
+    #[account(mint::token_program = token_program)]
+    pub mint: Box<InterfaceAccount<'info, anchor_spl::token_interface::Mint>>,
+
+    /// Recipient's token account for the asset being withdrawn (destination of funds)
+    /// Can be the user's own account OR an external recipient's account
+    /// Caller must provide the correct token account matching the asset_id
+    #[account(
+        mut,
+        constraint = recipient_token_account.mint == mint.key() @ ErrorCode::InvalidMint,
+    )]
+    pub recipient_token_account:
+        Box<InterfaceAccount<'info, anchor_spl::token_interface::TokenAccount>>,
+
+    /// Protocol's vault for the asset being withdrawn (source of funds)
+    /// Caller must provide the correct vault matching the asset_id
+    #[account(
+        mut,
+        constraint = vault.mint == mint.key() @ ErrorCode::InvalidMint,
+    )]
+    pub vault: Box<InterfaceAccount<'info, anchor_spl::token_interface::TokenAccount>>,
+
+    pub token_program: Interface<'info, anchor_spl::token_interface::TokenInterface>,
+
+    /// Transfer policy - required once the asset is restricted
+    #[account(
+        seeds = [ASSET_POLICY_SEED, &[pool.pool_id], mint.key().as_ref()],
+        bump = asset_policy.bump,
+        constraint = asset_policy.token_program == token_program.key() @ ErrorCode::InvalidAssetPolicy,
+    )]
+    pub asset_policy: Option<Box<Account<'info, AssetTransferPolicy>>>,
+
+    /// Issuer allowlist entry for the recipient wallet (restricted assets only)
+    /// CHECK: Verified against asset_policy by check_issuer_allowlist
+    pub allowlist_entry: Option<UncheckedAccount<'info>>,
+
+    /// Risk limits (required when Pool.risk_configured is set)
+    #[account(
+        seeds = [RISK_CONFIG_SEED, &[pool.pool_id]],
+        bump = risk_config.bump,
+    )]
+    pub risk_config: Option<Box<Account<'info, RiskConfig>>>,
+
+    /// Withdrawals counted against the daily cap (required for a capped asset)
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = WithdrawalWindow::SIZE,
+        seeds = [WITHDRAWAL_WINDOW_SEED, &[pool.pool_id], user.key().as_ref()],
+        bump,
+    )]
+    pub withdrawal_window: Option<Box<Account<'info, WithdrawalWindow>>>,
+
+    // =========================================================================
+    // ARCIUM MPC ACCOUNTS
+    // =========================================================================
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Box<Account<'info, ArciumSignerAccount>>,
+
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+
+    #[account(
+        mut,
+        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_SUB_BALANCE))]
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    pub cluster_account: Box<Account<'info, Cluster>>,
+
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Box<Account<'info, FeePool>>,
+
+    #[account(
+        mut,
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+    )]
+    pub clock_account: Box<Account<'info, ClockAccount>>,
+
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+}
+
+// =============================================================================
+// SUB BALANCE CALLBACK ACCOUNTS (Phase 6.5)
+// =============================================================================
+// Callback receives MPC output, verifies has_funds, and performs token transfer.
+
+#[callback_accounts("sub_balance")]
+#[derive(Accounts)]
+pub struct SubBalanceCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_SUB_BALANCE))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Account<'info, MXEAccount>,
+
+    /// CHECK: computation_account, checked by arcium program via constraints in the callback context.
+    pub computation_account: UncheckedAccount<'info>,
+
+    #[account(address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    pub cluster_account: Account<'info, Cluster>,
+
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+
+    // =========================================================================
+    // APPLICATION ACCOUNTS (passed via CallbackAccount)
+    // =========================================================================
+    /// User's privacy account - receives the updated encrypted balance
+    #[account(mut)]
+    pub user_account: Box<Account<'info, UserProfile>>,
+
+    /// Pool PDA (authority for vault) - passed via CallbackAccount
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// Vault token account - source of tokens for withdrawal
+    /// CHECK: Passed via CallbackAccount, verified by token transfer
+    #[account(mut)]
+    pub vault: AccountInfo<'info>,
+
+    /// Recipient token account - destination for withdrawn tokens
+    /// CHECK: Passed via CallbackAccount, verified by token transfer
+    #[account(mut)]
+    pub recipient_token_account: AccountInfo<'info>,
+
+    /// Token program for transfer CPI
+    /// CHECK: Passed via CallbackAccount
+    pub token_program: AccountInfo<'info>,
+
+    /// Mint of the withdrawn asset (transfer_checked needs decimals)
+    pub mint: Box<InterfaceAccount<'info, anchor_spl::token_interface::Mint>>,
+
+    /// Context of this computation, closed on success
+    #[account(
+        mut,
+        constraint = pending_operation.owner == user_account.owner @ ErrorCode::InvalidPendingOperation,
+    )]
+    pub pending_operation: Box<Account<'info, PendingOperation>>,
+
+    /// CHECK: rent destination, must be the profile's owner
+    #[account(mut, address = user_account.owner)]
+    pub owner: UncheckedAccount<'info>,
+
+    /// Pool event sequence (stamps `seq` into emitted events)
+    #[account(
+        mut,
+        seeds = [EVENT_CURSOR_SEED, &[pool.pool_id]],
+        bump = event_cursor.bump,
+    )]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+    // Transfer-hook extra accounts (if any) arrive as remaining_accounts
+}
+
+// =============================================================================
+// PENDING OPERATION ACCOUNTS
+// =============================================================================
+// retry_deposit re-queues add_balance (completing through AddBalanceCallback);
+// cancel_withdrawal drops a withdrawal whose callback never arrived.
+
+#[queue_computation_accounts("add_balance", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, pending_offset: u64)]
+pub struct RetryDeposit<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Owner of the deposit
+    pub user: Signer<'info>,
+
+    /// Pool the account belongs to
+    #[account(
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// User's privacy account (balance read for the new computation)
+    #[account(
+        seeds = [USER_SEED, &[pool.pool_id], user.key().as_ref()],
+        bump = user_account.bump,
+    )]
+    pub user_account: Box<Account<'info, UserProfile>>,
+
+    /// The uncredited deposit
+    #[account(
+        mut,
+        seeds = [
+            PENDING_OPERATION_SEED,
+            &[pool.pool_id],
+            user.key().as_ref(),
+            &pending_offset.to_le_bytes(),
+        ],
+        bump = pending_operation.bump,
+    )]
+    pub pending_operation: Box<Account<'info, PendingOperation>>,
+
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Box<Account<'info, ArciumSignerAccount>>,
+
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+
+    #[account(
+        mut,
+        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_ADD_BALANCE))]
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    pub cluster_account: Box<Account<'info, Cluster>>,
+
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Box<Account<'info, FeePool>>,
+
+    #[account(
+        mut,
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+    )]
+    pub clock_account: Box<Account<'info, ClockAccount>>,
+
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+}
+
+#[derive(Accounts)]
+#[instruction(pending_offset: u64)]
+pub struct CancelWithdrawal<'info> {
+    /// Owner of the withdrawal; receives the rent
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    /// Pool the withdrawal was queued in
+    #[account(
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// The withdrawal being cancelled
+    #[account(
+        mut,
+        close = user,
+        seeds = [
+            PENDING_OPERATION_SEED,
+            &[pool.pool_id],
+            user.key().as_ref(),
+            &pending_offset.to_le_bytes(),
+        ],
+        bump = pending_operation.bump,
+        constraint = pending_operation.kind == PENDING_OP_WITHDRAWAL @ ErrorCode::InvalidPendingOperation,
+    )]
+    pub pending_operation: Box<Account<'info, PendingOperation>>,
+
+    /// CHECK: the withdrawal's computation account (may already be closed)
+    #[account(address = pending_operation.computation @ ErrorCode::InvalidPendingOperation)]
+    pub computation_account: UncheckedAccount<'info>,
+
+    /// Pool event sequence (stamps `seq` into emitted events)
+    #[account(
+        mut,
+        seeds = [EVENT_CURSOR_SEED, &[pool.pool_id]],
+        bump = event_cursor.bump,
+    )]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+}
+
+// =============================================================================
+// INTERNAL TRANSFER ACCOUNTS (Phase 6.75)
+// =============================================================================
+// P2P transfer between two privacy accounts.
+
+#[queue_computation_accounts("transfer", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct InternalTransfer<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Sender must sign the transaction
+    pub sender: Signer<'info>,
+
+    /// Pool the account belongs to
+    #[account(
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// Sender's privacy account (source of funds)
+    #[account(
+        mut,
+        seeds = [USER_SEED, &[pool.pool_id], sender.key().as_ref()],
+        bump,
+        constraint = sender_account.owner == sender.key() @ ErrorCode::InvalidOwner,
+    )]
+    pub sender_account: Box<Account<'info, UserProfile>>,
+
+    /// Recipient's privacy account (destination of funds)
+    /// Must exist - if not initialized, Anchor will fail with AccountNotInitialized
+    #[account(
+        mut,
+        seeds = [USER_SEED, &[pool.pool_id], recipient_account.owner.as_ref()],
+        bump = recipient_account.bump,
+    )]
+    pub recipient_account: Box<Account<'info, UserProfile>>,
+
+    /// Sender's memo staging account (only needed to attach a memo)
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = TransferMemo::SIZE,
+        seeds = [TRANSFER_MEMO_SEED, &[pool.pool_id], sender.key().as_ref()],
+        bump,
+    )]
+    pub transfer_memo: Option<Box<Account<'info, TransferMemo>>>,
+
+    // =========================================================================
+    // ARCIUM MPC ACCOUNTS
+    // =========================================================================
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, ArciumSignerAccount>,
+
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+
+    #[account(
+        mut,
+        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: computation_account, will be initialized by arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    pub cluster_account: Box<Account<'info, Cluster>>,
+
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_TRANSFER))]
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Account<'info, FeePool>,
+
+    #[account(
+        mut,
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+    )]
+    pub clock_account: Account<'info, ClockAccount>,
+
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+}
+
+// =============================================================================
+// MULTI-ASSET TRANSFER ACCOUNTS
+// =============================================================================
+// transfer_multi: all four balances of sender and recipient in one MPC call.
+
+#[init_computation_definition_accounts("transfer_multi", payer)]
+#[derive(Accounts)]
+pub struct InitTransferMultiCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program.
+    pub comp_def_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_mxe_lut_pda!(mxe_account.lut_offset_slot))]
+    /// CHECK: address_lookup_table, checked by arcium program.
+    pub address_lookup_table: UncheckedAccount<'info>,
+    #[account(address = LUT_PROGRAM_ID)]
+    /// CHECK: lut_program is the Address Lookup Table program.
+    pub lut_program: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[queue_computation_accounts("transfer_multi", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct InternalTransferMulti<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Sender must sign the transaction
+    pub sender: Signer<'info>,
+
+    /// Pool the account belongs to
+    #[account(
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// Sender's privacy account (source of funds)
+    #[account(
+        mut,
+        seeds = [USER_SEED, &[pool.pool_id], sender.key().as_ref()],
+        bump,
+        constraint = sender_account.owner == sender.key() @ ErrorCode::InvalidOwner,
+    )]
+    pub sender_account: Box<Account<'info, UserProfile>>,
+
+    /// Recipient's privacy account (destination of funds)
+    /// Must exist - if not initialized, Anchor will fail with AccountNotInitialized
+    #[account(
+        mut,
+        seeds = [USER_SEED, &[pool.pool_id], recipient_account.owner.as_ref()],
+        bump = recipient_account.bump,
+    )]
+    pub recipient_account: Box<Account<'info, UserProfile>>,
+
+    // =========================================================================
+    // ARCIUM MPC ACCOUNTS
+    // =========================================================================
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, ArciumSignerAccount>,
+
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+
+    #[account(
+        mut,
+        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: computation_account, will be initialized by arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    pub cluster_account: Box<Account<'info, Cluster>>,
+
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_TRANSFER_MULTI))]
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Account<'info, FeePool>,
+
+    #[account(
+        mut,
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+    )]
+    pub clock_account: Account<'info, ClockAccount>,
+
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+}
+
+#[callback_accounts("transfer_multi")]
+#[derive(Accounts)]
+pub struct TransferMultiCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_TRANSFER_MULTI))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Account<'info, MXEAccount>,
+
+    /// CHECK: computation_account, checked by arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+
+    #[account(address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    pub cluster_account: Account<'info, Cluster>,
+
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar
+    pub instructions_sysvar: AccountInfo<'info>,
+
+    // Application accounts (passed via CallbackAccount)
+    #[account(mut)]
+    pub sender_account: Box<Account<'info, UserProfile>>,
+
+    #[account(mut)]
+    pub recipient_account: Box<Account<'info, UserProfile>>,
+
+    /// Pool event sequence (stamps `seq` into emitted events); the queue
+    /// site passes the pool's cursor
+    #[account(mut)]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+}
+
+// =============================================================================
+// BATCH PAYOUT ACCOUNTS
+// =============================================================================
+// transfer_batch: one sender's balance split across up to four recipients in
+// one MPC call.
+
+#[init_computation_definition_accounts("transfer_batch", payer)]
+#[derive(Accounts)]
+pub struct InitTransferBatchCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program.
+    pub comp_def_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_mxe_lut_pda!(mxe_account.lut_offset_slot))]
+    /// CHECK: address_lookup_table, checked by arcium program.
+    pub address_lookup_table: UncheckedAccount<'info>,
+    #[account(address = LUT_PROGRAM_ID)]
+    /// CHECK: lut_program is the Address Lookup Table program.
+    pub lut_program: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[queue_computation_accounts("transfer_batch", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct TransferBatch<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Sender must sign the transaction
+    pub sender: Signer<'info>,
+
+    /// Pool the account belongs to
+    #[account(
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// Sender's privacy account (source of funds)
+    #[account(
+        mut,
+        seeds = [USER_SEED, &[pool.pool_id], sender.key().as_ref()],
+        bump,
+        constraint = sender_account.owner == sender.key() @ ErrorCode::InvalidOwner,
+    )]
+    pub sender_account: Box<Account<'info, UserProfile>>,
+
+    // =========================================================================
+    // ARCIUM MPC ACCOUNTS
+    // =========================================================================
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, ArciumSignerAccount>,
+
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+
+    #[account(
+        mut,
+        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: computation_account, will be initialized by arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    pub cluster_account: Box<Account<'info, Cluster>>,
+
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_TRANSFER_BATCH))]
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Account<'info, FeePool>,
+
+    #[account(
+        mut,
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+    )]
+    pub clock_account: Account<'info, ClockAccount>,
+
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    // Recipient UserProfiles (writable) arrive as remaining_accounts
+}
+
+#[callback_accounts("transfer_batch")]
+#[derive(Accounts)]
+pub struct TransferBatchCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_TRANSFER_BATCH))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Account<'info, MXEAccount>,
+
+    /// CHECK: computation_account, checked by arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+
+    #[account(address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    pub cluster_account: Account<'info, Cluster>,
+
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar
+    pub instructions_sysvar: AccountInfo<'info>,
+
+    // Application accounts (passed via CallbackAccount)
+    #[account(mut)]
+    pub sender_account: Box<Account<'info, UserProfile>>,
+
+    /// Pool event sequence (stamps `seq` into emitted events); the queue
+    /// site passes the pool's cursor
+    #[account(mut)]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+    // Recipient UserProfiles follow as remaining_accounts, in slot order
+}
+
+// =============================================================================
+// TWO-STEP TRANSFER ACCOUNTS
+// =============================================================================
+// escrow_transfer: request_transfer debits the sender into an MXE escrow held
+// by the TransferRequest. release_escrow: claim_transfer (recipient, before
+// expiry) or reclaim_transfer (sender, after expiry) credits the escrow.
+
+#[init_computation_definition_accounts("escrow_transfer", payer)]
+#[derive(Accounts)]
+pub struct InitEscrowTransferCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program.
+    pub comp_def_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_mxe_lut_pda!(mxe_account.lut_offset_slot))]
+    /// CHECK: address_lookup_table, checked by arcium program.
+    pub address_lookup_table: UncheckedAccount<'info>,
+    #[account(address = LUT_PROGRAM_ID)]
+    /// CHECK: lut_program is the Address Lookup Table program.
+    pub lut_program: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[queue_computation_accounts("escrow_transfer", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, transfer_id: u64)]
+pub struct RequestTransfer<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Sender must sign the transaction and pays the request's rent
+    #[account(mut)]
+    pub sender: Signer<'info>,
+
+    /// Pool the accounts belong to
+    #[account(
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// Sender's privacy account (source of funds)
+    #[account(
+        mut,
+        seeds = [USER_SEED, &[pool.pool_id], sender.key().as_ref()],
+        bump = sender_account.bump,
+        constraint = sender_account.owner == sender.key() @ ErrorCode::InvalidOwner,
+    )]
+    pub sender_account: Box<Account<'info, UserProfile>>,
+
+    /// Recipient's privacy account (must exist)
+    #[account(
+        seeds = [USER_SEED, &[pool.pool_id], recipient_account.owner.as_ref()],
+        bump = recipient_account.bump,
+    )]
+    pub recipient_account: Box<Account<'info, UserProfile>>,
+
+    /// Request being created
+    #[account(
+        init,
+        payer = sender,
+        space = TransferRequest::SIZE,
+        seeds = [
+            TRANSFER_REQUEST_SEED,
+            &[pool.pool_id],
+            sender.key().as_ref(),
+            &transfer_id.to_le_bytes(),
+        ],
+        bump,
+    )]
+    pub transfer_request: Box<Account<'info, TransferRequest>>,
+
+    // =========================================================================
+    // ARCIUM MPC ACCOUNTS
+    // =========================================================================
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, ArciumSignerAccount>,
+
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+
+    #[account(
+        mut,
+        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: computation_account, will be initialized by arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    pub cluster_account: Box<Account<'info, Cluster>>,
+
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_ESCROW_TRANSFER))]
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Account<'info, FeePool>,
+
+    #[account(
+        mut,
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+    )]
+    pub clock_account: Account<'info, ClockAccount>,
+
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+}
+
+#[callback_accounts("escrow_transfer")]
+#[derive(Accounts)]
+pub struct EscrowTransferCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_ESCROW_TRANSFER))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Account<'info, MXEAccount>,
+
+    /// CHECK: computation_account, checked by arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+
+    #[account(address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    pub cluster_account: Account<'info, Cluster>,
+
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar
+    pub instructions_sysvar: AccountInfo<'info>,
+
+    // Application accounts (passed via CallbackAccount)
+    #[account(mut)]
+    pub sender_account: Box<Account<'info, UserProfile>>,
+
+    #[account(mut)]
+    pub transfer_request: Box<Account<'info, TransferRequest>>,
+
+    /// Pool event sequence (stamps `seq` into emitted events); the queue
+    /// site passes the pool's cursor
+    #[account(mut)]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+}
+
+#[init_computation_definition_accounts("release_escrow", payer)]
+#[derive(Accounts)]
+pub struct InitReleaseEscrowCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program.
+    pub comp_def_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_mxe_lut_pda!(mxe_account.lut_offset_slot))]
+    /// CHECK: address_lookup_table, checked by arcium program.
+    pub address_lookup_table: UncheckedAccount<'info>,
+    #[account(address = LUT_PROGRAM_ID)]
+    /// CHECK: lut_program is the Address Lookup Table program.
+    pub lut_program: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[queue_computation_accounts("release_escrow", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct ClaimTransfer<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Recipient must sign the transaction
+    pub recipient: Signer<'info>,
+
+    /// Pool the accounts belong to
+    #[account(
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// Recipient's privacy account (credited with the escrow)
+    #[account(
+        mut,
+        seeds = [USER_SEED, &[pool.pool_id], recipient.key().as_ref()],
+        bump = recipient_account.bump,
+        constraint = recipient_account.owner == recipient.key() @ ErrorCode::InvalidOwner,
+    )]
+    pub recipient_account: Box<Account<'info, UserProfile>>,
+
+    /// Request being claimed
+    #[account(
+        mut,
+        seeds = [
+            TRANSFER_REQUEST_SEED,
+            &[pool.pool_id],
+            transfer_request.sender.as_ref(),
+            &transfer_request.transfer_id.to_le_bytes(),
+        ],
+        bump = transfer_request.bump,
+        constraint = transfer_request.recipient == recipient.key() @ ErrorCode::Unauthorized,
+        constraint = transfer_request.pool_id == pool.pool_id @ ErrorCode::InvalidTransferRequest,
+    )]
+    pub transfer_request: Box<Account<'info, TransferRequest>>,
+
+    // =========================================================================
+    // ARCIUM MPC ACCOUNTS
+    // =========================================================================
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, ArciumSignerAccount>,
+
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+
+    #[account(
+        mut,
+        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: computation_account, will be initialized by arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    pub cluster_account: Box<Account<'info, Cluster>>,
+
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_RELEASE_ESCROW))]
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Account<'info, FeePool>,
+
+    #[account(
+        mut,
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+    )]
+    pub clock_account: Account<'info, ClockAccount>,
+
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+}
+
+#[queue_computation_accounts("release_escrow", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct ReclaimTransfer<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Sender must sign the transaction
+    pub sender: Signer<'info>,
+
+    /// Pool the accounts belong to
+    #[account(
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// Sender's privacy account (credited with the escrow)
+    #[account(
+        mut,
+        seeds = [USER_SEED, &[pool.pool_id], sender.key().as_ref()],
+        bump = sender_account.bump,
+        constraint = sender_account.owner == sender.key() @ ErrorCode::InvalidOwner,
+    )]
+    pub sender_account: Box<Account<'info, UserProfile>>,
+
+    /// Request being reclaimed
+    #[account(
+        mut,
+        seeds = [
+            TRANSFER_REQUEST_SEED,
+            &[pool.pool_id],
+            sender.key().as_ref(),
+            &transfer_request.transfer_id.to_le_bytes(),
+        ],
+        bump = transfer_request.bump,
+        constraint = transfer_request.sender == sender.key() @ ErrorCode::Unauthorized,
+        constraint = transfer_request.pool_id == pool.pool_id @ ErrorCode::InvalidTransferRequest,
+    )]
+    pub transfer_request: Box<Account<'info, TransferRequest>>,
+
+    // =========================================================================
+    // ARCIUM MPC ACCOUNTS
+    // =========================================================================
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, ArciumSignerAccount>,
+
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+
+    #[account(
+        mut,
+        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: computation_account, will be initialized by arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    pub cluster_account: Box<Account<'info, Cluster>>,
+
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_RELEASE_ESCROW))]
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Account<'info, FeePool>,
+
+    #[account(
+        mut,
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+    )]
+    pub clock_account: Account<'info, ClockAccount>,
+
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+}
+
+#[derive(Accounts)]
+pub struct ResetTransferRequest<'info> {
+    /// Anyone may reset a stuck request
+    pub caller: Signer<'info>,
+
+    /// Pool the request belongs to
+    #[account(
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// Request stuck in ESCROWING or RELEASING
+    #[account(
+        mut,
+        seeds = [
+            TRANSFER_REQUEST_SEED,
+            &[pool.pool_id],
+            transfer_request.sender.as_ref(),
+            &transfer_request.transfer_id.to_le_bytes(),
+        ],
+        bump = transfer_request.bump,
+        constraint = transfer_request.pool_id == pool.pool_id @ ErrorCode::InvalidTransferRequest,
+    )]
+    pub transfer_request: Box<Account<'info, TransferRequest>>,
+
+    /// CHECK: rent destination if the request is closed, must be the sender
+    #[account(mut, address = transfer_request.sender)]
+    pub sender: UncheckedAccount<'info>,
+
+    /// CHECK: the computation the request waits on (may already be closed)
+    #[account(address = transfer_request.pending_computation @ ErrorCode::InvalidPendingOperation)]
+    pub computation_account: UncheckedAccount<'info>,
+
+    /// Pool event sequence (stamps `seq` into emitted events)
+    #[account(
+        mut,
+        seeds = [EVENT_CURSOR_SEED, &[pool.pool_id]],
+        bump = event_cursor.bump,
+    )]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+}
+
+#[callback_accounts("release_escrow")]
+#[derive(Accounts)]
+pub struct ReleaseEscrowCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_RELEASE_ESCROW))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Account<'info, MXEAccount>,
+
+    /// CHECK: computation_account, checked by arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+
+    #[account(address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    pub cluster_account: Account<'info, Cluster>,
+
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar
+    pub instructions_sysvar: AccountInfo<'info>,
+
+    // Application accounts (passed via CallbackAccount)
+    /// Claimer's privacy account (recipient or sender)
+    #[account(mut)]
+    pub user_account: Box<Account<'info, UserProfile>>,
+
+    /// Closed once released; rent returns to the sender
+    #[account(mut, close = sender)]
+    pub transfer_request: Box<Account<'info, TransferRequest>>,
+
+    /// CHECK: rent destination, must be the request's sender
+    #[account(mut, address = transfer_request.sender)]
+    pub sender: UncheckedAccount<'info>,
+
+    /// Pool event sequence (stamps `seq` into emitted events); the queue
+    /// site passes the pool's cursor
+    #[account(mut)]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+}
+
+// =============================================================================
+// PAYMENT STREAM ACCOUNTS
+// =============================================================================
+// create_stream stores the encrypted rate in a PaymentStream; advance_stream
+// (permissionless) queues stream_transfer for the accrued seconds and the
+// callback moves the amount from payer to payee.
+
+#[derive(Accounts)]
+#[instruction(stream_id: u64)]
+pub struct CreateStream<'info> {
+    /// Stream payer (signs, pays the stream's rent)
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Pool the accounts belong to
+    #[account(
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// Payer's privacy account (source of funds)
+    #[account(
+        seeds = [USER_SEED, &[pool.pool_id], payer.key().as_ref()],
+        bump = payer_account.bump,
+        constraint = payer_account.owner == payer.key() @ ErrorCode::InvalidOwner,
+    )]
+    pub payer_account: Box<Account<'info, UserProfile>>,
+
+    /// Payee's privacy account (must exist)
+    #[account(
+        seeds = [USER_SEED, &[pool.pool_id], payee_account.owner.as_ref()],
+        bump = payee_account.bump,
+    )]
+    pub payee_account: Box<Account<'info, UserProfile>>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = PaymentStream::SIZE,
+        seeds = [
+            PAYMENT_STREAM_SEED,
+            &[pool.pool_id],
+            payer.key().as_ref(),
+            &stream_id.to_le_bytes(),
+        ],
+        bump,
+    )]
+    pub payment_stream: Box<Account<'info, PaymentStream>>,
+
+    /// Pool event sequence (stamps `seq` into emitted events)
+    #[account(
+        mut,
+        seeds = [EVENT_CURSOR_SEED, &[pool.pool_id]],
+        bump = event_cursor.bump,
+    )]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[queue_computation_accounts("stream_transfer", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct AdvanceStream<'info> {
+    /// Anyone can crank a stream (pays the computation fees)
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Pool the accounts belong to
+    #[account(
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+        constraint = pool.pool_id == payment_stream.pool_id @ ErrorCode::InvalidPaymentStream,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    #[account(
+        mut,
+        seeds = [
+            PAYMENT_STREAM_SEED,
+            &[pool.pool_id],
+            payment_stream.payer.as_ref(),
+            &payment_stream.stream_id.to_le_bytes(),
+        ],
+        bump = payment_stream.bump,
+    )]
+    pub payment_stream: Box<Account<'info, PaymentStream>>,
+
+    /// Stream payer's privacy account
+    #[account(
+        seeds = [USER_SEED, &[pool.pool_id], payment_stream.payer.as_ref()],
+        bump = payer_account.bump,
+    )]
+    pub payer_account: Box<Account<'info, UserProfile>>,
+
+    /// Stream payee's privacy account
+    #[account(
+        seeds = [USER_SEED, &[pool.pool_id], payment_stream.payee.as_ref()],
+        bump = payee_account.bump,
+    )]
+    pub payee_account: Box<Account<'info, UserProfile>>,
+
+    // =========================================================================
+    // ARCIUM MPC ACCOUNTS
+    // =========================================================================
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Box<Account<'info, ArciumSignerAccount>>,
+
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+
+    #[account(
+        mut,
+        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_STREAM_TRANSFER))]
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    pub cluster_account: Box<Account<'info, Cluster>>,
+
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Box<Account<'info, FeePool>>,
+
+    #[account(
+        mut,
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+    )]
+    pub clock_account: Box<Account<'info, ClockAccount>>,
+
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+}
+
+#[callback_accounts("stream_transfer")]
+#[derive(Accounts)]
+pub struct StreamTransferCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_STREAM_TRANSFER))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Account<'info, MXEAccount>,
+
+    /// CHECK: computation_account, checked by arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+
+    #[account(address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    pub cluster_account: Account<'info, Cluster>,
+
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar
+    pub instructions_sysvar: AccountInfo<'info>,
+
+    // Application accounts (passed via CallbackAccount)
+    #[account(mut)]
+    pub payment_stream: Box<Account<'info, PaymentStream>>,
+
+    #[account(
+        mut,
+        constraint = payer_account.owner == payment_stream.payer @ ErrorCode::InvalidPaymentStream,
+    )]
+    pub payer_account: Box<Account<'info, UserProfile>>,
+
+    #[account(
+        mut,
+        constraint = payee_account.owner == payment_stream.payee @ ErrorCode::InvalidPaymentStream,
+    )]
+    pub payee_account: Box<Account<'info, UserProfile>>,
+
+    /// Pool event sequence (stamps `seq` into emitted events); the queue
+    /// site passes the pool's cursor
+    #[account(mut)]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+}
+
+#[init_computation_definition_accounts("stream_transfer", payer)]
+#[derive(Accounts)]
+pub struct InitStreamTransferCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program.
+    pub comp_def_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_mxe_lut_pda!(mxe_account.lut_offset_slot))]
+    /// CHECK: address_lookup_table, checked by arcium program.
+    pub address_lookup_table: UncheckedAccount<'info>,
+    #[account(address = LUT_PROGRAM_ID)]
+    /// CHECK: lut_program is the Address Lookup Table program.
+    pub lut_program: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CancelStream<'info> {
+    /// Stream payer - receives the reclaimed rent
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        close = payer,
+        seeds = [
+            PAYMENT_STREAM_SEED,
+            &[payment_stream.pool_id],
+            payer.key().as_ref(),
+            &payment_stream.stream_id.to_le_bytes(),
+        ],
+        bump = payment_stream.bump,
+        constraint = payment_stream.payer == payer.key() @ ErrorCode::Unauthorized,
+    )]
+    pub payment_stream: Box<Account<'info, PaymentStream>>,
+
+    /// Pool event sequence (stamps `seq` into emitted events)
+    #[account(
+        mut,
+        seeds = [EVENT_CURSOR_SEED, &[payment_stream.pool_id]],
+        bump = event_cursor.bump,
+    )]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+}
+
+// =============================================================================
+// VOUCHER ACCOUNTS
+// =============================================================================
+// fund_voucher: create_voucher debits the funder into an MXE escrow held by
+// the Voucher. redeem_voucher: claim_voucher (holder of the claim key, before
+// expiry) or reclaim_voucher (funder, after expiry) credits the escrow.
+
+#[init_computation_definition_accounts("fund_voucher", payer)]
+#[derive(Accounts)]
+pub struct InitFundVoucherCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program.
+    pub comp_def_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_mxe_lut_pda!(mxe_account.lut_offset_slot))]
+    /// CHECK: address_lookup_table, checked by arcium program.
+    pub address_lookup_table: UncheckedAccount<'info>,
+    #[account(address = LUT_PROGRAM_ID)]
+    /// CHECK: lut_program is the Address Lookup Table program.
+    pub lut_program: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[queue_computation_accounts("fund_voucher", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct CreateVoucher<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Funder must sign the transaction and pays the voucher's rent
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    /// Pool the accounts belong to
+    #[account(
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// Funder's privacy account (source of funds)
+    #[account(
+        mut,
+        seeds = [USER_SEED, &[pool.pool_id], funder.key().as_ref()],
+        bump = funder_account.bump,
+        constraint = funder_account.owner == funder.key() @ ErrorCode::InvalidOwner,
+    )]
+    pub funder_account: Box<Account<'info, UserProfile>>,
+
+    /// CHECK: public key of the voucher secret; only its signature on
+    /// claim_voucher is checked
+    pub claim_key: UncheckedAccount<'info>,
+
+    /// Voucher being created
+    #[account(
+        init,
+        payer = funder,
+        space = Voucher::SIZE,
+        seeds = [VOUCHER_SEED, &[pool.pool_id], claim_key.key().as_ref()],
+        bump,
+    )]
+    pub voucher: Box<Account<'info, Voucher>>,
+
+    // =========================================================================
+    // ARCIUM MPC ACCOUNTS
+    // =========================================================================
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, ArciumSignerAccount>,
+
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+
+    #[account(
+        mut,
+        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: computation_account, will be initialized by arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    pub cluster_account: Box<Account<'info, Cluster>>,
+
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_FUND_VOUCHER))]
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Account<'info, FeePool>,
+
+    #[account(
+        mut,
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+    )]
+    pub clock_account: Account<'info, ClockAccount>,
+
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+}
+
+#[callback_accounts("fund_voucher")]
+#[derive(Accounts)]
+pub struct FundVoucherCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_FUND_VOUCHER))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Account<'info, MXEAccount>,
+
+    /// CHECK: computation_account, checked by arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+
+    #[account(address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    pub cluster_account: Account<'info, Cluster>,
+
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar
+    pub instructions_sysvar: AccountInfo<'info>,
+
+    // Application accounts (passed via CallbackAccount)
+    #[account(mut)]
+    pub funder_account: Box<Account<'info, UserProfile>>,
+
+    #[account(mut)]
+    pub voucher: Box<Account<'info, Voucher>>,
+
+    /// Pool event sequence (stamps `seq` into emitted events); the queue
+    /// site passes the pool's cursor
+    #[account(mut)]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+}
+
+#[init_computation_definition_accounts("redeem_voucher", payer)]
+#[derive(Accounts)]
+pub struct InitRedeemVoucherCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program.
+    pub comp_def_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_mxe_lut_pda!(mxe_account.lut_offset_slot))]
+    /// CHECK: address_lookup_table, checked by arcium program.
+    pub address_lookup_table: UncheckedAccount<'info>,
+    #[account(address = LUT_PROGRAM_ID)]
+    /// CHECK: lut_program is the Address Lookup Table program.
+    pub lut_program: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[queue_computation_accounts("redeem_voucher", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct ClaimVoucher<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Wallet being credited must sign the transaction
+    pub claimer: Signer<'info>,
+
+    /// Voucher secret must sign too, binding the claim to `claimer`
+    pub claim_key: Signer<'info>,
+
+    /// Pool the accounts belong to
+    #[account(
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// Claimer's privacy account (credited with the escrow)
+    #[account(
+        mut,
+        seeds = [USER_SEED, &[pool.pool_id], claimer.key().as_ref()],
+        bump = claimer_account.bump,
+        constraint = claimer_account.owner == claimer.key() @ ErrorCode::InvalidOwner,
+    )]
+    pub claimer_account: Box<Account<'info, UserProfile>>,
+
+    /// Voucher being claimed
+    #[account(
+        mut,
+        seeds = [VOUCHER_SEED, &[pool.pool_id], claim_key.key().as_ref()],
+        bump = voucher.bump,
+        constraint = voucher.pool_id == pool.pool_id @ ErrorCode::InvalidVoucher,
+    )]
+    pub voucher: Box<Account<'info, Voucher>>,
+
+    // =========================================================================
+    // ARCIUM MPC ACCOUNTS
+    // =========================================================================
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, ArciumSignerAccount>,
+
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+
+    #[account(
+        mut,
+        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: computation_account, will be initialized by arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    pub cluster_account: Box<Account<'info, Cluster>>,
+
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_REDEEM_VOUCHER))]
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Account<'info, FeePool>,
+
+    #[account(
+        mut,
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+    )]
+    pub clock_account: Account<'info, ClockAccount>,
+
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+}
+
+#[queue_computation_accounts("redeem_voucher", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct ReclaimVoucher<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Funder must sign the transaction
+    pub funder: Signer<'info>,
+
+    /// Pool the accounts belong to
+    #[account(
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// Funder's privacy account (credited with the escrow)
+    #[account(
+        mut,
+        seeds = [USER_SEED, &[pool.pool_id], funder.key().as_ref()],
+        bump = funder_account.bump,
+        constraint = funder_account.owner == funder.key() @ ErrorCode::InvalidOwner,
+    )]
+    pub funder_account: Box<Account<'info, UserProfile>>,
+
+    /// Voucher being reclaimed
+    #[account(
+        mut,
+        seeds = [VOUCHER_SEED, &[pool.pool_id], voucher.claim_key.as_ref()],
+        bump = voucher.bump,
+        constraint = voucher.funder == funder.key() @ ErrorCode::Unauthorized,
+        constraint = voucher.pool_id == pool.pool_id @ ErrorCode::InvalidVoucher,
+    )]
+    pub voucher: Box<Account<'info, Voucher>>,
+
+    // =========================================================================
+    // ARCIUM MPC ACCOUNTS
+    // =========================================================================
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, ArciumSignerAccount>,
+
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+
+    #[account(
+        mut,
+        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: computation_account, will be initialized by arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    pub cluster_account: Box<Account<'info, Cluster>>,
+
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_REDEEM_VOUCHER))]
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Account<'info, FeePool>,
+
+    #[account(
+        mut,
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+    )]
+    pub clock_account: Account<'info, ClockAccount>,
+
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+}
+
+#[derive(Accounts)]
+pub struct ResetVoucher<'info> {
+    /// Anyone may reset a stuck voucher
+    pub caller: Signer<'info>,
+
+    /// Pool the voucher belongs to
+    #[account(
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// Voucher stuck in FUNDING or REDEEMING
+    #[account(
+        mut,
+        seeds = [VOUCHER_SEED, &[pool.pool_id], voucher.claim_key.as_ref()],
+        bump = voucher.bump,
+        constraint = voucher.pool_id == pool.pool_id @ ErrorCode::InvalidVoucher,
+    )]
+    pub voucher: Box<Account<'info, Voucher>>,
+
+    /// CHECK: rent destination if the voucher is closed, must be the funder
+    #[account(mut, address = voucher.funder)]
+    pub funder: UncheckedAccount<'info>,
+
+    /// CHECK: the computation the voucher waits on (may already be closed)
+    #[account(address = voucher.pending_computation @ ErrorCode::InvalidPendingOperation)]
+    pub computation_account: UncheckedAccount<'info>,
+
+    /// Pool event sequence (stamps `seq` into emitted events)
+    #[account(
+        mut,
+        seeds = [EVENT_CURSOR_SEED, &[pool.pool_id]],
+        bump = event_cursor.bump,
+    )]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+}
+
+#[callback_accounts("redeem_voucher")]
+#[derive(Accounts)]
+pub struct RedeemVoucherCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_REDEEM_VOUCHER))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Account<'info, MXEAccount>,
+
+    /// CHECK: computation_account, checked by arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+
+    #[account(address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    pub cluster_account: Account<'info, Cluster>,
+
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar
+    pub instructions_sysvar: AccountInfo<'info>,
+
+    // Application accounts (passed via CallbackAccount)
+    /// Claimer's privacy account (secret holder or funder)
+    #[account(mut)]
+    pub user_account: Box<Account<'info, UserProfile>>,
+
+    /// Closed once redeemed; rent returns to the funder
+    #[account(mut, close = funder)]
+    pub voucher: Box<Account<'info, Voucher>>,
+
+    /// CHECK: rent destination, must be the voucher's funder
+    #[account(mut, address = voucher.funder)]
+    pub funder: UncheckedAccount<'info>,
+
+    /// Pool event sequence (stamps `seq` into emitted events); the queue
+    /// site passes the pool's cursor
+    #[account(mut)]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+}
+
+// =============================================================================
+// INITIALIZE INSTRUCTION ACCOUNTS (Phase 3)
+// =============================================================================
+// This struct defines all accounts required for the initialize instruction.
+// Defined here in lib.rs for Anchor's IDL generation to work correctly.
+//
+
+use crate::constants::*;
+use crate::state::{
+    AccountClosure, AssetRegistry, AssetTransferPolicy, BasketOrder, BatchAccumulator, BatchLog,
+    BeneficiaryConfig, BuybackConfig, CircuitRegistry, ConfigSnapshot, DcaSchedule, Delegate,
+    Diagnostics, EventCursor, ExecutionPolicy, ExecutorRegistry, GuardianSet, InvariantMonitor,
+    KeyRotation, MarketSchedule, OracleConfig, OrderHistory, OrderTicket, PairRegistry,
+    PaymentStream, PendingOperation, Pool, ProtocolConfig, ProtocolStats, RentSponsor,
+    ReserveAuction, RiskConfig, Roles, SavingsBucket, SettlementPreference, TransferMemo,
+    TransferRequest, UserProfile, ViewableBalance, ViewerGrant, Voucher, WithdrawalWindow,
+    BASKET_STATUS_PLACED, PENDING_OP_DEPOSIT, PENDING_OP_WITHDRAWAL, TRANSFER_STATUS_ESCROWING,
+    TRANSFER_STATUS_OPEN, TRANSFER_STATUS_RELEASING, VOUCHER_STATUS_FUNDING, VOUCHER_STATUS_OPEN,
+    VOUCHER_STATUS_REDEEMING,
+};
+use anchor_spl::token::Mint;
+
+#[derive(Accounts)]
+#[instruction(pool_id: u8)]
+pub struct Initialize<'info> {
+    // =========================================================================
+    // PAYER & AUTHORITIES
+    // =========================================================================
+    /// The wallet paying for account creation (rent).
+    /// Must sign the transaction.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Admin authority for the protocol.
+    /// Can update fees, pause protocol, etc.
+    /// CHECK: This can be any valid public key - stored as Pool.authority
+    pub authority: UncheckedAccount<'info>,
+
+    /// Operator wallet for batch execution.
+    /// CHECK: This can be any valid public key - stored as Pool.operator
+    pub operator: UncheckedAccount<'info>,
+
+    /// Treasury wallet for collecting fees.
+    /// CHECK: This can be any valid public key - stored as Pool.treasury
+    pub treasury: UncheckedAccount<'info>,
+
+    // =========================================================================
+    // POOL ACCOUNT (PDA)
+    // =========================================================================
+    /// The main Pool account - central state for the protocol.
+    /// PDA derived from seeds: ["pool", pool_id]
+    /// Space calculation defined in Pool::SIZE
+    /// Note: Wrapped in Box to reduce stack usage (many accounts in this instruction)
+    #[account(
+        init,
+        payer = payer,
+        space = Pool::SIZE,
+        seeds = [POOL_SEED, &[pool_id]],
+        bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    // =========================================================================
+    // TOKEN MINTS (existing tokens on-chain)
+    // =========================================================================
+    /// USDC token mint - any valid mint can be passed
+    /// The address is stored in Pool during initialization
+    /// Note: Wrapped in Box to reduce stack usage
+    pub usdc_mint: Box<Account<'info, Mint>>,
+
+    /// TSLA token mint
+    pub tsla_mint: Box<Account<'info, Mint>>,
+
+    /// SPY token mint
+    pub spy_mint: Box<Account<'info, Mint>>,
+
+    /// AAPL token mint
+    pub aapl_mint: Box<Account<'info, Mint>>,
+
+    // =========================================================================
+    // TOKEN VAULTS (PDAs)
+    // =========================================================================
+    // These are token accounts owned by the Pool PDA.
+    // They hold the protocol's token balances.
+    //
+
+    // - `token::mint` specifies which token this account holds
+    // - `token::authority` specifies who can transfer tokens (the Pool PDA)
+    // - We use separate seeds for each vault to derive unique addresses
+    /// USDC vault - holds all deposited USDC
+    /// PDA seeds: ["vault", pool_id, "usdc"]
+    #[account(
+        init,
+        payer = payer,
+        seeds = [VAULT_SEED, &[pool_id], VAULT_USDC_SEED],
+        bump,
+        token::mint = usdc_mint,
+        token::authority = pool,
+    )]
+    pub vault_usdc: Box<Account<'info, TokenAccount>>,
+
+    /// TSLA vault - holds TSLA tokens
+    /// PDA seeds: ["vault", pool_id, "tsla"]
+    #[account(
+        init,
+        payer = payer,
+        seeds = [VAULT_SEED, &[pool_id], VAULT_TSLA_SEED],
+        bump,
+        token::mint = tsla_mint,
+        token::authority = pool,
+    )]
+    pub vault_tsla: Box<Account<'info, TokenAccount>>,
+
+    /// SPY vault - holds SPY tokens
+    /// PDA seeds: ["vault", pool_id, "spy"]
+    #[account(
+        init,
+        payer = payer,
+        seeds = [VAULT_SEED, &[pool_id], VAULT_SPY_SEED],
+        bump,
+        token::mint = spy_mint,
+        token::authority = pool,
+    )]
+    pub vault_spy: Box<Account<'info, TokenAccount>>,
+
+    /// AAPL vault - holds AAPL tokens
+    /// PDA seeds: ["vault", pool_id, "aapl"]
+    #[account(
+        init,
+        payer = payer,
+        seeds = [VAULT_SEED, &[pool_id], VAULT_AAPL_SEED],
+        bump,
+        token::mint = aapl_mint,
+        token::authority = pool,
+    )]
+    pub vault_aapl: Box<Account<'info, TokenAccount>>,
+
+    // =========================================================================
+    // RESERVE VAULTS (PDAs) - Protocol Liquidity
+    // =========================================================================
+    // These are token accounts for protocol-owned liquidity.
+    // Used to fulfill net surplus during batch execution.
+    // Separate from user deposit vaults above.
+    /// USDC reserve - protocol liquidity for swaps
+    /// PDA seeds: ["reserve", pool_id, "usdc"]
+    #[account(
+        init,
+        payer = payer,
+        seeds = [RESERVE_SEED, &[pool_id], RESERVE_USDC_SEED],
+        bump,
+        token::mint = usdc_mint,
+        token::authority = pool,
+    )]
+    pub reserve_usdc: Box<Account<'info, TokenAccount>>,
+
+    /// TSLA reserve - protocol liquidity
+    /// PDA seeds: ["reserve", pool_id, "tsla"]
+    #[account(
+        init,
+        payer = payer,
+        seeds = [RESERVE_SEED, &[pool_id], RESERVE_TSLA_SEED],
+        bump,
+        token::mint = tsla_mint,
+        token::authority = pool,
+    )]
+    pub reserve_tsla: Box<Account<'info, TokenAccount>>,
+
+    /// SPY reserve - protocol liquidity
+    /// PDA seeds: ["reserve", pool_id, "spy"]
+    #[account(
+        init,
+        payer = payer,
+        seeds = [RESERVE_SEED, &[pool_id], RESERVE_SPY_SEED],
+        bump,
+        token::mint = spy_mint,
+        token::authority = pool,
+    )]
+    pub reserve_spy: Box<Account<'info, TokenAccount>>,
+
+    /// AAPL reserve - protocol liquidity
+    /// PDA seeds: ["reserve", pool_id, "aapl"]
+    #[account(
+        init,
+        payer = payer,
+        seeds = [RESERVE_SEED, &[pool_id], RESERVE_AAPL_SEED],
+        bump,
+        token::mint = aapl_mint,
+        token::authority = pool,
+    )]
+    pub reserve_aapl: Box<Account<'info, TokenAccount>>,
+
+    // =========================================================================
+    // FAUCET VAULT (Devnet only)
+    // =========================================================================
+    /// USDC faucet vault - tokens users can claim for testing
+    /// PDA seeds: ["faucet_usdc", pool_id]
+    #[account(
+        init,
+        payer = payer,
+        seeds = [FAUCET_VAULT_SEED, &[pool_id]],
+        bump,
+        token::mint = usdc_mint,
+        token::authority = pool,
+    )]
+    pub faucet_vault: Box<Account<'info, TokenAccount>>,
+
+    // =========================================================================
+    // SYSTEM PROGRAMS
+    // =========================================================================
+    /// Required for creating accounts
+    pub system_program: Program<'info, System>,
+
+    /// Required for creating token accounts
+    pub token_program: Program<'info, Token>,
+}
+
+// ErrorCode is now defined in errors.rs and re-exported above.
+// It contains all error codes including AbortedComputation and ClusterNotSet.
+
+// =============================================================================
+// CREATE USER ACCOUNT INSTRUCTION ACCOUNTS (Phase 4)
+// =============================================================================
+// This struct defines all accounts required for the create_user_account instruction.
+//
+
+#[derive(Accounts)]
+pub struct CreateUserAccount<'info> {
+    /// The wallet paying for account creation (rent).
+    /// Usually the same as owner, but can be different (sponsored).
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The wallet that will own this privacy account.
+    /// Must sign to prove ownership.
+    pub owner: Signer<'info>,
+
+    /// Pool the account belongs to
+    #[account(
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// The user's privacy account - PDA derived from their wallet address.
+    /// Seeds: ["user", pool_id, owner.key().as_ref()]
+    /// This ensures only ONE privacy account per wallet.
+    #[account(
+        init,
+        payer = payer,
+        space = UserProfile::SIZE,
+        seeds = [USER_SEED, &[pool.pool_id], owner.key().as_ref()],
+        bump,
+    )]
+    pub user_account: Box<Account<'info, UserProfile>>,
+
+    /// Optional rent sponsorship pool; reimburses the payer the account rent
+    #[account(
+        mut,
+        seeds = [RENT_SPONSOR_SEED, &[pool.pool_id]],
+        bump = rent_sponsor.bump,
+    )]
+    pub rent_sponsor: Option<Box<Account<'info, RentSponsor>>>,
+
+    /// Required for creating accounts
+    pub system_program: Program<'info, System>,
+
+    /// Pool event sequence (stamps `seq` into emitted events)
+    #[account(
+        mut,
+        seeds = [EVENT_CURSOR_SEED, &[pool.pool_id]],
+        bump = event_cursor.bump,
+    )]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+}
+
+// =============================================================================
+// CREATE AND DEPOSIT ACCOUNTS
+// =============================================================================
+// CreateUserAccount plus the AddBalance deposit accounts; the computation
+// completes through AddBalanceCallback.
+
+#[queue_computation_accounts("add_balance", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct CreateAndDeposit<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The wallet that will own the privacy account and makes the deposit
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    /// Pool the account belongs to (vault authority)
+    #[account(
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// The new privacy account, created here
+    #[account(
+        init,
+        payer = payer,
+        space = UserProfile::SIZE,
+        seeds = [USER_SEED, &[pool.pool_id], user.key().as_ref()],
+        bump,
+    )]
+    pub user_account: Box<Account<'info, UserProfile>>,
+
+    /// Context of this computation for the callback (see PendingOperation)
+    #[account(
+        init,
+        payer = payer,
+        space = PendingOperation::SIZE,
+        seeds = [
+            PENDING_OPERATION_SEED,
+            &[pool.pool_id],
+            user.key().as_ref(),
+            &computation_offset.to_le_bytes(),
+        ],
+        bump,
+    )]
+    pub pending_operation: Box<Account<'info, PendingOperation>>,
+
+    /// Optional rent sponsorship pool; reimburses the payer the account rent
+    #[account(
+        mut,
+        seeds = [RENT_SPONSOR_SEED, &[pool.pool_id]],
+        bump = rent_sponsor.bump,
+    )]
+    pub rent_sponsor: Option<Box<Account<'info, RentSponsor>>>,
+
+    /// Mint of the asset being deposited (must match pool mint for asset_id)
+    #[account(mint::token_program = token_program)]
+    pub mint: Box<InterfaceAccount<'info, anchor_spl::token_interface::Mint>>,
+
+    /// User's token account for the asset being deposited (source of funds)
+    /// Caller must provide the correct token account matching the asset_id
+    #[account(
+        mut,
+        constraint = user_token_account.owner == user.key() @ ErrorCode::InvalidOwner,
+        constraint = user_token_account.mint == mint.key() @ ErrorCode::InvalidMint,
+    )]
+    pub user_token_account: Box<InterfaceAccount<'info, anchor_spl::token_interface::TokenAccount>>,
+
+    /// Protocol's vault for the asset being deposited (destination of funds)
+    /// Caller must provide the correct vault matching the asset_id
+    #[account(
+        mut,
+        constraint = vault.mint == mint.key() @ ErrorCode::InvalidMint,
+    )]
+    pub vault: Box<InterfaceAccount<'info, anchor_spl::token_interface::TokenAccount>>,
+
+    pub token_program: Interface<'info, anchor_spl::token_interface::TokenInterface>,
+
+    /// Transfer policy - required once the asset is restricted
+    #[account(
+        seeds = [ASSET_POLICY_SEED, &[pool.pool_id], mint.key().as_ref()],
+        bump = asset_policy.bump,
+        constraint = asset_policy.token_program == token_program.key() @ ErrorCode::InvalidAssetPolicy,
+    )]
+    pub asset_policy: Option<Box<Account<'info, AssetTransferPolicy>>>,
+
+    /// Issuer allowlist entry for the user (restricted assets only)
+    /// CHECK: Verified against asset_policy by check_issuer_allowlist
+    pub allowlist_entry: Option<UncheckedAccount<'info>>,
+
+    // =========================================================================
+    // ARCIUM MPC ACCOUNTS
+    // =========================================================================
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Box<Account<'info, ArciumSignerAccount>>,
+
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+
+    #[account(
+        mut,
+        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_ADD_BALANCE))]
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    pub cluster_account: Box<Account<'info, Cluster>>,
+
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Box<Account<'info, FeePool>>,
+
+    #[account(
+        mut,
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+    )]
+    pub clock_account: Box<Account<'info, ClockAccount>>,
+
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+
+    /// Pool event sequence (stamps `seq` into emitted events)
+    #[account(
+        mut,
+        seeds = [EVENT_CURSOR_SEED, &[pool.pool_id]],
+        bump = event_cursor.bump,
+    )]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateUserAccount<'info> {
+    /// Anyone may crank a migration
+    pub caller: Signer<'info>,
+
+    /// Profile owner - receives the freed rent
+    #[account(mut)]
+    pub owner: SystemAccount<'info>,
+
+    /// Pool the account belongs to
+    #[account(
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// Legacy profile, read and rewritten manually (it no longer
+    /// deserializes as a UserProfile)
+    #[account(
+        mut,
+        seeds = [USER_SEED, &[pool.pool_id], owner.key().as_ref()],
+        bump,
+    )]
+    /// CHECK: program ownership, discriminator and size are checked in the handler
+    pub user_account: UncheckedAccount<'info>,
+
+    /// Pool event sequence (stamps `seq` into emitted events)
+    #[account(
+        mut,
+        seeds = [EVENT_CURSOR_SEED, &[pool.pool_id]],
+        bump = event_cursor.bump,
+    )]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+}
+
+#[derive(Accounts)]
+#[instruction(pool_id: u8)]
+pub struct MigratePool<'info> {
+    /// Anyone may crank a migration; pays the extra rent
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Pool in an older layout, read and extended manually
+    #[account(
+        mut,
+        seeds = [POOL_SEED, &[pool_id]],
+        bump,
+    )]
+    /// CHECK: program ownership, discriminator and size are checked in the handler
+    pub pool: UncheckedAccount<'info>,
+
+    /// CHECK: only checked for existence, to set Pool.pair_registry_configured
+    #[account(seeds = [PAIR_REGISTRY_SEED, &[pool_id]], bump)]
+    pub pair_registry: UncheckedAccount<'info>,
+
+    /// CHECK: only checked for existence, to set Pool.execution_policy_configured
+    #[account(seeds = [EXECUTION_POLICY_SEED, &[pool_id]], bump)]
+    pub execution_policy: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Pool event sequence (stamps `seq` into emitted events); absent until
+    /// init_event_cursor has run, which needs a current-layout Pool
+    #[account(mut, seeds = [EVENT_CURSOR_SEED, &[pool_id]], bump)]
+    pub event_cursor: Option<Box<Account<'info, EventCursor>>>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateUserProfile<'info> {
+    /// Anyone may crank a migration; pays the extra rent
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: only used to derive the profile address
+    pub owner: UncheckedAccount<'info>,
+
+    /// Pool the account belongs to (migrate_pool first)
+    #[account(
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// Profile in an older layout, read and extended manually
+    #[account(
+        mut,
+        seeds = [USER_SEED, &[pool.pool_id], owner.key().as_ref()],
+        bump,
+    )]
+    /// CHECK: program ownership, discriminator and size are checked in the handler
+    pub user_account: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Pool event sequence (stamps `seq` into emitted events)
+    #[account(
+        mut,
+        seeds = [EVENT_CURSOR_SEED, &[pool.pool_id]],
+        bump = event_cursor.bump,
+    )]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+}
+
+#[derive(Accounts)]
+#[instruction(slot: u8)]
+pub struct MigrateBatchAccumulator<'info> {
+    /// Anyone may crank a migration; pays the extra rent
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Pool the account belongs to (migrate_pool first)
+    #[account(
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// Accumulator slot in an older layout, read and extended manually
+    #[account(
+        mut,
+        seeds = [BATCH_ACCUMULATOR_SEED, &[pool.pool_id], &[slot]],
+        bump,
+    )]
+    /// CHECK: program ownership, discriminator and size are checked in the handler
+    pub batch_accumulator: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Pool event sequence (stamps `seq` into emitted events)
+    #[account(
+        mut,
+        seeds = [EVENT_CURSOR_SEED, &[pool.pool_id]],
+        bump = event_cursor.bump,
+    )]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+}
+
+#[derive(Accounts)]
+#[instruction(batch_id: u64)]
+pub struct MigrateBatchLog<'info> {
+    /// Anyone may crank a migration; pays the extra rent
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Pool the account belongs to (migrate_pool first)
+    #[account(
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// Batch log in an older layout, read and extended manually
+    #[account(
+        mut,
+        seeds = [BATCH_LOG_SEED, &[pool.pool_id], &batch_id.to_le_bytes()],
+        bump,
+    )]
+    /// CHECK: program ownership, discriminator and size are checked in the handler
+    pub batch_log: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Pool event sequence (stamps `seq` into emitted events)
+    #[account(
+        mut,
+        seeds = [EVENT_CURSOR_SEED, &[pool.pool_id]],
+        bump = event_cursor.bump,
+    )]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+}
+
+// =============================================================================
+// RENT SPONSOR ACCOUNTS
+// =============================================================================
+
+#[derive(Accounts)]
+pub struct FundRentSponsor<'info> {
+    /// Pool authority
+    #[account(
+        mut,
+        constraint = roles.is_admin(&pool, &authority.key()) @ ErrorCode::Unauthorized,
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// Role grants (admins and operators besides the pool's own)
+    #[account(
+        seeds = [ROLES_SEED, &[pool.pool_id]],
+        bump = roles.bump,
+    )]
+    pub roles: Box<Account<'info, Roles>>,
+
+    /// Sponsorship pool (created on first call)
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = RentSponsor::SIZE,
+        seeds = [RENT_SPONSOR_SEED, &[pool.pool_id]],
+        bump,
+    )]
+    pub rent_sponsor: Box<Account<'info, RentSponsor>>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Pool event sequence (stamps `seq` into emitted events)
+    #[account(
+        mut,
+        seeds = [EVENT_CURSOR_SEED, &[pool.pool_id]],
+        bump = event_cursor.bump,
+    )]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawRentSponsor<'info> {
+    /// Pool authority - receives the lamports
+    #[account(
+        mut,
+        constraint = roles.is_admin(&pool, &authority.key()) @ ErrorCode::Unauthorized,
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// Role grants (admins and operators besides the pool's own)
+    #[account(
+        seeds = [ROLES_SEED, &[pool.pool_id]],
+        bump = roles.bump,
+    )]
+    pub roles: Box<Account<'info, Roles>>,
+
+    #[account(
+        mut,
+        seeds = [RENT_SPONSOR_SEED, &[pool.pool_id]],
+        bump = rent_sponsor.bump,
+    )]
+    pub rent_sponsor: Box<Account<'info, RentSponsor>>,
+
+    /// Pool event sequence (stamps `seq` into emitted events)
+    #[account(
+        mut,
+        seeds = [EVENT_CURSOR_SEED, &[pool.pool_id]],
+        bump = event_cursor.bump,
+    )]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+}
+
+// Legacy Deposit struct removed in Phase 6.
+// Use AddBalance for encrypted deposits via Arcium MPC.
+
+// =============================================================================
+// INIT BATCH ACCUMULATOR ACCOUNTS (Phase 8)
+// =============================================================================
+// Accounts for initializing a BatchAccumulator slot.
+
+#[derive(Accounts)]
+#[instruction(slot: u8)]
+pub struct InitBatchAccumulator<'info> {
+    /// Pool authority (pays for account creation)
+    #[account(
+        mut,
+        constraint = payer.key() == pool.authority @ ErrorCode::Unauthorized,
+    )]
+    pub payer: Signer<'info>,
+
+    /// Pool the account belongs to
+    #[account(
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// The BatchAccumulator PDA to create.
+    /// Seeds: ["batch_accumulator", pool_id, slot]
+    #[account(
+        init,
+        payer = payer,
+        space = BatchAccumulator::SIZE,
+        seeds = [BATCH_ACCUMULATOR_SEED, &[pool.pool_id], &[slot]],
+        bump,
+    )]
+    pub batch_accumulator: Account<'info, BatchAccumulator>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct InitEventCursor<'info> {
+    /// The payer for account creation.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Pool the cursor sequences events for
+    #[account(
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// The EventCursor PDA to create.
+    /// Seeds: ["event_cursor", pool_id]
+    #[account(
+        init,
+        payer = payer,
+        space = EventCursor::SIZE,
+        seeds = [EVENT_CURSOR_SEED, &[pool.pool_id]],
+        bump,
+    )]
+    pub event_cursor: Account<'info, EventCursor>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct InitProtocolStats<'info> {
+    /// The payer for account creation.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Pool the statistics are kept for
+    #[account(
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// The ProtocolStats PDA to create.
+    /// Seeds: ["protocol_stats", pool_id]
+    #[account(
+        init,
+        payer = payer,
+        space = ProtocolStats::SIZE,
+        seeds = [PROTOCOL_STATS_SEED, &[pool.pool_id]],
+        bump,
+    )]
+    pub protocol_stats: Box<Account<'info, ProtocolStats>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// =============================================================================
+// TEST SWAP CPI ACCOUNTS (Phase 8)
+// =============================================================================
+// Accounts for CPI call from shuffle_protocol to mock_jupiter's `swap` instruction.
+// The Pool PDA acts as user_authority since it owns the source/dest vaults.
+//
+
+#[derive(Accounts)]
+pub struct TestSwap<'info> {
+    /// Operator triggers swaps (authorized backend service)
+    #[account(
+        constraint = roles.is_operator(&pool, &operator.key()) @ ErrorCode::Unauthorized,
+    )]
+    pub operator: Signer<'info>,
+
+    /// Pool PDA - acts as signer for the CPI and owns the shuffle_protocol vaults.
+    /// Must be mut because mock_jupiter's Swap marks user_authority as mut,
+    /// and Solana requires writable privilege to be present in the outer instruction.
+    #[account(
+        mut,
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// Role grants (admins and operators besides the pool's own)
+    #[account(
+        seeds = [ROLES_SEED, &[pool.pool_id]],
+        bump = roles.bump,
+    )]
+    pub roles: Box<Account<'info, Roles>>,
+
+    /// Source token mint (e.g., USDC)
+    pub source_mint: Box<Account<'info, Mint>>,
+
+    /// Destination token mint (e.g., TSLA)
+    pub destination_mint: Box<Account<'info, Mint>>,
+
+    /// Shuffle Protocol vault for source asset (Pool PDA is authority).
+    /// Tokens are sent FROM here to mock_jupiter.
+    #[account(
+        mut,
+        token::mint = source_mint,
+        token::authority = pool,
+    )]
+    pub pool_source_vault: Box<Account<'info, TokenAccount>>,
+
+    /// Shuffle Protocol vault for destination asset (Pool PDA is authority).
+    /// Tokens are received INTO here from mock_jupiter.
+    #[account(
+        mut,
+        token::mint = destination_mint,
+        token::authority = pool,
+    )]
+    pub pool_dest_vault: Box<Account<'info, TokenAccount>>,
+
+    /// mock_jupiter program to CPI into
+    /// CHECK: Validated by the instruction handler (program ID check optional for test)
+    pub jupiter_program: UncheckedAccount<'info>,
+
+    /// mock_jupiter swap_pool PDA
+    /// CHECK: Validated by mock_jupiter program during CPI
+    #[account(mut)]
+    pub jupiter_swap_pool: UncheckedAccount<'info>,
+
+    /// mock_jupiter source vault (receives source tokens from our pool)
+    /// CHECK: Validated by mock_jupiter program during CPI
+    #[account(mut)]
+    pub jupiter_source_vault: UncheckedAccount<'info>,
+
+    /// mock_jupiter destination vault (sends dest tokens to our pool)
+    /// CHECK: Validated by mock_jupiter program during CPI
+    #[account(mut)]
+    pub jupiter_dest_vault: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+// =============================================================================
+// PLACE ORDER ACCOUNTS (Phase 8)
+// =============================================================================
+// Queue computation to place an encrypted order in the batch.
+
+#[queue_computation_accounts("accumulate_order", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct PlaceOrder<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Owner placing the order, or a session key holding a Delegate
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    /// User's privacy account
+    #[account(
+        mut,
+        seeds = [USER_SEED, &[pool.pool_id], user_account.owner.as_ref()],
+        bump = user_account.bump,
+        constraint = user_account.pending_order.is_none() @ ErrorCode::PendingOrderExists,
+    )]
+    pub user_account: Box<Account<'info, UserProfile>>,
+
+    /// Session key authorization (required when `user` is not the owner)
+    #[account(
+        mut,
+        seeds = [DELEGATE_SEED, &[pool.pool_id], user_account.owner.as_ref(), user.key().as_ref()],
+        bump = delegate.bump,
+    )]
+    pub delegate: Option<Box<Account<'info, Delegate>>>,
+
+    /// Pool account (asset freeze check)
+    #[account(
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// Active batch accumulator slot
+    #[account(
+        mut,
+        seeds = [BATCH_ACCUMULATOR_SEED, &[pool.pool_id], &[batch_accumulator.slot]],
+        bump = batch_accumulator.bump,
+        constraint = batch_accumulator.active @ ErrorCode::InvalidBatchSlot,
+    )]
+    pub batch_accumulator: Box<Account<'info, BatchAccumulator>>,
+
+    // =========================================================================
+    // ARCIUM MPC ACCOUNTS
+    // =========================================================================
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Box<Account<'info, ArciumSignerAccount>>,
+
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+
+    #[account(
+        mut,
+        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_ACCUMULATE_ORDER))]
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    pub cluster_account: Box<Account<'info, Cluster>>,
+
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Box<Account<'info, FeePool>>,
+
+    #[account(
+        mut,
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+    )]
+    pub clock_account: Box<Account<'info, ClockAccount>>,
+
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+}
+
+// =============================================================================
+// PLACE ORDER CALLBACK ACCOUNTS (Phase 8)
+// =============================================================================
+
+#[callback_accounts("accumulate_order")]
+#[derive(Accounts)]
+pub struct AccumulateOrderCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_ACCUMULATE_ORDER))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Account<'info, MXEAccount>,
+
+    /// CHECK: computation_account, checked by arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+
+    #[account(address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    pub cluster_account: Account<'info, Cluster>,
+
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar
+    pub instructions_sysvar: AccountInfo<'info>,
+
+    // Application accounts (passed via CallbackAccount)
+    #[account(mut)]
+    pub user_account: Box<Account<'info, UserProfile>>,
+
+    #[account(mut)]
+    pub batch_accumulator: Box<Account<'info, BatchAccumulator>>,
+
+    /// Savings bucket the order was drawn from (bucket-funded DCA only);
+    /// receives the new balance instead of the profile
+    #[account(
+        mut,
+        constraint = savings_bucket.owner == user_account.owner @ ErrorCode::InvalidSavingsBucket,
+    )]
+    pub savings_bucket: Option<Box<Account<'info, SavingsBucket>>>,
+
+    /// Pool event sequence (stamps `seq` into emitted events); the queue
+    /// site passes the pool's cursor
+    #[account(mut)]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+}
+
+// =============================================================================
+// DCA SCHEDULE ACCOUNTS
+// =============================================================================
+
+#[derive(Accounts)]
+#[instruction(dca_id: u64)]
+pub struct CreateDca<'info> {
+    /// Schedule owner (pays rent)
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    /// Pool the account belongs to
+    #[account(
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// Owner must already have a privacy account
+    #[account(
+        seeds = [USER_SEED, &[pool.pool_id], user.key().as_ref()],
+        bump = user_account.bump,
+        constraint = user_account.owner == user.key() @ ErrorCode::InvalidOwner,
+    )]
+    pub user_account: Box<Account<'info, UserProfile>>,
+
+    /// Bucket the schedule will draw from (only with funding_bucket)
+    #[account(
+        seeds = [
+            SAVINGS_BUCKET_SEED,
+            &[pool.pool_id],
+            user.key().as_ref(),
+            &[savings_bucket.bucket_id],
+        ],
+        bump = savings_bucket.bump,
+    )]
+    pub savings_bucket: Option<Box<Account<'info, SavingsBucket>>>,
+
+    #[account(
+        init,
+        payer = user,
+        space = DcaSchedule::SIZE,
+        seeds = [DCA_SEED, &[pool.pool_id], user.key().as_ref(), &dca_id.to_le_bytes()],
+        bump,
+    )]
+    pub dca_schedule: Box<Account<'info, DcaSchedule>>,
+
+    /// Pool event sequence (stamps `seq` into emitted events)
+    #[account(
+        mut,
+        seeds = [EVENT_CURSOR_SEED, &[pool.pool_id]],
+        bump = event_cursor.bump,
+    )]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CancelDca<'info> {
+    /// Schedule owner - receives the reclaimed rent
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    /// Pool the schedule belongs to
+    #[account(
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    #[account(
+        mut,
+        close = user,
+        seeds = [
+            DCA_SEED,
+            &[pool.pool_id],
+            user.key().as_ref(),
+            &dca_schedule.dca_id.to_le_bytes(),
+        ],
+        bump = dca_schedule.bump,
+        constraint = dca_schedule.owner == user.key() @ ErrorCode::Unauthorized,
+    )]
+    pub dca_schedule: Box<Account<'info, DcaSchedule>>,
+
+    /// Pool event sequence (stamps `seq` into emitted events)
+    #[account(
+        mut,
+        seeds = [EVENT_CURSOR_SEED, &[pool.pool_id]],
+        bump = event_cursor.bump,
+    )]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+}
+
+#[derive(Accounts)]
+pub struct PauseDca<'info> {
+    /// Schedule owner
+    pub user: Signer<'info>,
+
+    /// Pool the schedule belongs to
+    #[account(
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    #[account(
+        mut,
+        seeds = [
+            DCA_SEED,
+            &[pool.pool_id],
+            user.key().as_ref(),
+            &dca_schedule.dca_id.to_le_bytes(),
+        ],
+        bump = dca_schedule.bump,
+        constraint = dca_schedule.owner == user.key() @ ErrorCode::Unauthorized,
+    )]
+    pub dca_schedule: Box<Account<'info, DcaSchedule>>,
+
+    /// Pool event sequence (stamps `seq` into emitted events)
+    #[account(
+        mut,
+        seeds = [EVENT_CURSOR_SEED, &[pool.pool_id]],
+        bump = event_cursor.bump,
+    )]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+}
+
+#[derive(Accounts)]
+pub struct ResumeDca<'info> {
+    /// Schedule owner
+    pub user: Signer<'info>,
+
+    /// Pool the schedule belongs to
+    #[account(
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    #[account(
+        mut,
+        seeds = [
+            DCA_SEED,
+            &[pool.pool_id],
+            user.key().as_ref(),
+            &dca_schedule.dca_id.to_le_bytes(),
+        ],
+        bump = dca_schedule.bump,
+        constraint = dca_schedule.owner == user.key() @ ErrorCode::Unauthorized,
+    )]
+    pub dca_schedule: Box<Account<'info, DcaSchedule>>,
+
+    /// Pool event sequence (stamps `seq` into emitted events)
+    #[account(
+        mut,
+        seeds = [EVENT_CURSOR_SEED, &[pool.pool_id]],
+        bump = event_cursor.bump,
+    )]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+}
+
+#[queue_computation_accounts("accumulate_order", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct ExecuteDca<'info> {
+    /// Crank caller (pays MPC fees)
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            DCA_SEED,
+            &[pool.pool_id],
+            dca_schedule.owner.as_ref(),
+            &dca_schedule.dca_id.to_le_bytes(),
+        ],
+        bump = dca_schedule.bump,
+    )]
+    pub dca_schedule: Box<Account<'info, DcaSchedule>>,
+
+    /// Schedule owner's privacy account
+    #[account(
+        mut,
+        seeds = [USER_SEED, &[pool.pool_id], dca_schedule.owner.as_ref()],
+        bump = user_account.bump,
+        constraint = user_account.pending_order.is_none() @ ErrorCode::PendingOrderExists,
+    )]
+    pub user_account: Box<Account<'info, UserProfile>>,
+
+    /// Pool account (asset freeze check)
+    #[account(
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// Active batch accumulator slot
+    #[account(
+        mut,
+        seeds = [BATCH_ACCUMULATOR_SEED, &[pool.pool_id], &[batch_accumulator.slot]],
+        bump = batch_accumulator.bump,
+        constraint = batch_accumulator.active @ ErrorCode::InvalidBatchSlot,
+    )]
+    pub batch_accumulator: Box<Account<'info, BatchAccumulator>>,
+
+    /// Bucket the schedule draws from (required iff funding_bucket is set)
+    #[account(
+        seeds = [
+            SAVINGS_BUCKET_SEED,
+            &[pool.pool_id],
+            dca_schedule.owner.as_ref(),
+            &[savings_bucket.bucket_id],
+        ],
+        bump = savings_bucket.bump,
+    )]
+    pub savings_bucket: Option<Box<Account<'info, SavingsBucket>>>,
+
+    // =========================================================================
+    // ARCIUM MPC ACCOUNTS
+    // =========================================================================
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Box<Account<'info, ArciumSignerAccount>>,
+
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+
+    #[account(
+        mut,
+        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_ACCUMULATE_ORDER))]
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    pub cluster_account: Box<Account<'info, Cluster>>,
+
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Box<Account<'info, FeePool>>,
+
+    #[account(
+        mut,
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+    )]
+    pub clock_account: Box<Account<'info, ClockAccount>>,
+
+    /// Pool event sequence (stamps `seq` into emitted events)
+    #[account(
+        mut,
+        seeds = [EVENT_CURSOR_SEED, &[pool.pool_id]],
+        bump = event_cursor.bump,
+    )]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+}
+
+// =============================================================================
+// SAVINGS BUCKET ACCOUNTS
+// =============================================================================
+
+#[derive(Accounts)]
+#[instruction(bucket_id: u8)]
+pub struct CreateSavingsBucket<'info> {
+    /// Bucket owner (pays rent)
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    /// Pool the account belongs to
+    #[account(
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// Owner must already have a privacy account
+    #[account(
+        seeds = [USER_SEED, &[pool.pool_id], user.key().as_ref()],
+        bump = user_account.bump,
+        constraint = user_account.owner == user.key() @ ErrorCode::InvalidOwner,
+    )]
+    pub user_account: Box<Account<'info, UserProfile>>,
+
+    #[account(
+        init,
+        payer = user,
+        space = SavingsBucket::SIZE,
+        seeds = [SAVINGS_BUCKET_SEED, &[pool.pool_id], user.key().as_ref(), &[bucket_id]],
+        bump,
+    )]
+    pub savings_bucket: Box<Account<'info, SavingsBucket>>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Pool event sequence (stamps `seq` into emitted events)
+    #[account(
+        mut,
+        seeds = [EVENT_CURSOR_SEED, &[pool.pool_id]],
+        bump = event_cursor.bump,
+    )]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+}
+
+#[queue_computation_accounts("move_savings", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct MoveSavings<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Owner of the profile and the bucket
+    pub user: Signer<'info>,
+
+    /// Pool the account belongs to
+    #[account(
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    #[account(
+        mut,
+        seeds = [USER_SEED, &[pool.pool_id], user.key().as_ref()],
+        bump = user_account.bump,
+        constraint = user_account.owner == user.key() @ ErrorCode::InvalidOwner,
+    )]
+    pub user_account: Box<Account<'info, UserProfile>>,
+
+    #[account(
+        seeds = [
+            SAVINGS_BUCKET_SEED,
+            &[pool.pool_id],
+            user.key().as_ref(),
+            &[savings_bucket.bucket_id],
+        ],
+        bump = savings_bucket.bump,
+    )]
+    pub savings_bucket: Box<Account<'info, SavingsBucket>>,
+
+    // =========================================================================
+    // ARCIUM MPC ACCOUNTS
+    // =========================================================================
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Box<Account<'info, ArciumSignerAccount>>,
+
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+
+    #[account(
+        mut,
+        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_MOVE_SAVINGS))]
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    pub cluster_account: Box<Account<'info, Cluster>>,
+
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Box<Account<'info, FeePool>>,
+
+    #[account(
+        mut,
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+    )]
+    pub clock_account: Box<Account<'info, ClockAccount>>,
+
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+}
+
+#[callback_accounts("move_savings")]
+#[derive(Accounts)]
+pub struct MoveSavingsCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_MOVE_SAVINGS))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Account<'info, MXEAccount>,
+
+    /// CHECK: computation_account, checked by arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+
+    #[account(address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    pub cluster_account: Account<'info, Cluster>,
+
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar
+    pub instructions_sysvar: AccountInfo<'info>,
+
+    // Application accounts (passed via CallbackAccount)
+    #[account(mut)]
+    pub user_account: Box<Account<'info, UserProfile>>,
+
+    #[account(
+        mut,
+        constraint = savings_bucket.owner == user_account.owner @ ErrorCode::InvalidSavingsBucket,
+    )]
+    pub savings_bucket: Box<Account<'info, SavingsBucket>>,
+
+    /// Pool event sequence (stamps `seq` into emitted events); the queue
+    /// site passes the pool's cursor
+    #[account(mut)]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+}
+
+#[init_computation_definition_accounts("move_savings", payer)]
+#[derive(Accounts)]
+pub struct InitMoveSavingsCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program.
+    pub comp_def_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_mxe_lut_pda!(mxe_account.lut_offset_slot))]
+    /// CHECK: address_lookup_table, checked by arcium program.
+    pub address_lookup_table: UncheckedAccount<'info>,
+    #[account(address = LUT_PROGRAM_ID)]
+    /// CHECK: lut_program is the Address Lookup Table program.
+    pub lut_program: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+// =============================================================================
+// RECOVER BATCH REVEAL ACCOUNTS
+// =============================================================================
+
+#[derive(Accounts)]
+pub struct RecoverBatchReveal<'info> {
+    /// Operator or pool authority
+    #[account(
+        constraint = roles.is_operator(&pool, &caller.key()) || roles.is_admin(&pool, &caller.key()) @ ErrorCode::Unauthorized,
+    )]
+    pub caller: Signer<'info>,
+
+    #[account(
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// Role grants (admins and operators besides the pool's own)
+    #[account(
+        seeds = [ROLES_SEED, &[pool.pool_id]],
+        bump = roles.bump,
+    )]
+    pub roles: Box<Account<'info, Roles>>,
+
+    #[account(
+        mut,
+        seeds = [BATCH_ACCUMULATOR_SEED, &[pool.pool_id], &[batch_accumulator.slot]],
+        bump = batch_accumulator.bump,
+    )]
+    pub batch_accumulator: Box<Account<'info, BatchAccumulator>>,
+
+    /// Pool event sequence (stamps `seq` into emitted events)
+    #[account(
+        mut,
+        seeds = [EVENT_CURSOR_SEED, &[pool.pool_id]],
+        bump = event_cursor.bump,
+    )]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+}
+
+// =============================================================================
+// RETRY EXECUTE BATCH ACCOUNTS
+// =============================================================================
+
+#[queue_computation_accounts("reveal_batch", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct RetryExecuteBatch<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Accumulator slot whose reveal failed or went stale
+    #[account(
+        mut,
+        seeds = [BATCH_ACCUMULATOR_SEED, &[pool.pool_id], &[batch_accumulator.slot]],
+        bump = batch_accumulator.bump,
+    )]
+    pub batch_accumulator: Box<Account<'info, BatchAccumulator>>,
+
+    /// BatchLog created by execute_batch for this batch
+    #[account(
+        mut,
+        seeds = [BATCH_LOG_SEED, &[pool.pool_id], &batch_accumulator.batch_id.to_le_bytes()],
+        bump = batch_log.bump,
+    )]
+    pub batch_log: Box<Account<'info, BatchLog>>,
+
+    #[account(
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// Bonded executors (required when Pool.executor_gated is set)
+    #[account(
+        seeds = [EXECUTOR_REGISTRY_SEED, &[pool.pool_id]],
+        bump = executor_registry.bump,
+    )]
+    pub executor_registry: Option<Box<Account<'info, ExecutorRegistry>>>,
+
+    /// Optional diagnostics account (passed on to the reveal callback)
+    #[account(
+        mut,
+        seeds = [DIAGNOSTICS_SEED, &[pool.pool_id]],
+        bump = diagnostics.bump,
+    )]
+    pub diagnostics: Option<Account<'info, Diagnostics>>,
+
+    // =========================================================================
+    // ARCIUM MPC ACCOUNTS
+    // =========================================================================
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Box<Account<'info, ArciumSignerAccount>>,
+
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+
+    #[account(
+        mut,
+        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_REVEAL_BATCH))]
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    pub cluster_account: Box<Account<'info, Cluster>>,
+
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Box<Account<'info, FeePool>>,
+
+    #[account(
+        mut,
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+    )]
+    pub clock_account: Box<Account<'info, ClockAccount>>,
+
+    /// Pool event sequence (stamps `seq` into emitted events)
+    #[account(
+        mut,
+        seeds = [EVENT_CURSOR_SEED, &[pool.pool_id]],
+        bump = event_cursor.bump,
+    )]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+}
+
+// =============================================================================
+// RESET BATCH ACCOUNTS (reset_batch, flush_batch)
+// =============================================================================
+
+#[queue_computation_accounts("init_batch_state", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct ResetBatch<'info> {
+    /// Pool authority
+    #[account(
+        mut,
+        constraint = roles.is_admin(&pool, &payer.key()) @ ErrorCode::Unauthorized,
+    )]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// Role grants (admins and operators besides the pool's own)
+    #[account(
+        seeds = [ROLES_SEED, &[pool.pool_id]],
+        bump = roles.bump,
+    )]
+    pub roles: Box<Account<'info, Roles>>,
+
+    /// Accumulator slot to reset
+    #[account(
+        mut,
+        seeds = [BATCH_ACCUMULATOR_SEED, &[pool.pool_id], &[batch_accumulator.slot]],
+        bump = batch_accumulator.bump,
+    )]
+    pub batch_accumulator: Box<Account<'info, BatchAccumulator>>,
+
+    /// BatchLog of the discarded batch (created if the batch never revealed)
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = BatchLog::SIZE,
+        seeds = [BATCH_LOG_SEED, &[pool.pool_id], &batch_accumulator.batch_id.to_le_bytes()],
+        bump,
+    )]
+    pub batch_log: Box<Account<'info, BatchLog>>,
+
+    // =========================================================================
+    // ARCIUM MPC ACCOUNTS
+    // =========================================================================
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Box<Account<'info, ArciumSignerAccount>>,
+
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+
+    #[account(
+        mut,
+        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_INIT_BATCH_STATE))]
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    pub cluster_account: Box<Account<'info, Cluster>>,
+
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Box<Account<'info, FeePool>>,
+
+    #[account(
+        mut,
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+    )]
+    pub clock_account: Box<Account<'info, ClockAccount>>,
+
+    /// Pool event sequence (stamps `seq` into emitted events)
+    #[account(
+        mut,
+        seeds = [EVENT_CURSOR_SEED, &[pool.pool_id]],
+        bump = event_cursor.bump,
+    )]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct AdminClearPending<'info> {
+    /// Admin
+    #[account(constraint = roles.is_admin(&pool, &authority.key()) @ ErrorCode::Unauthorized)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// Role grants (admins and operators besides the pool's own)
+    #[account(
+        seeds = [ROLES_SEED, &[pool.pool_id]],
+        bump = roles.bump,
+    )]
+    pub roles: Box<Account<'info, Roles>>,
+
+    /// Profile to unstick
+    #[account(
+        mut,
+        seeds = [USER_SEED, &[pool.pool_id], user_account.owner.as_ref()],
+        bump = user_account.bump,
+    )]
+    pub user_account: Box<Account<'info, UserProfile>>,
+
+    /// BatchLog of the dropped order, to release its unsettled count
+    #[account(
+        mut,
+        seeds = [BATCH_LOG_SEED, &[pool.pool_id], &batch_log.batch_id.to_le_bytes()],
+        bump = batch_log.bump,
+    )]
+    pub batch_log: Option<Box<Account<'info, BatchLog>>>,
+
+    /// Pool event sequence (stamps `seq` into emitted events)
+    #[account(
+        mut,
+        seeds = [EVENT_CURSOR_SEED, &[pool.pool_id]],
+        bump = event_cursor.bump,
+    )]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+}
+
+// =============================================================================
+// BASKET ORDER ACCOUNTS
+// =============================================================================
+
+#[queue_computation_accounts("accumulate_basket", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct PlaceBasketOrder<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Owner placing the basket, or a session key holding a Delegate
+    pub user: Signer<'info>,
+
+    /// Pool account (asset freeze check)
+    #[account(
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// User's privacy account
+    #[account(
+        mut,
+        seeds = [USER_SEED, &[pool.pool_id], user_account.owner.as_ref()],
+        bump = user_account.bump,
+    )]
+    pub user_account: Box<Account<'info, UserProfile>>,
+
+    /// Session key authorization (required when `user` is not the owner)
+    #[account(
+        mut,
+        seeds = [DELEGATE_SEED, &[pool.pool_id], user_account.owner.as_ref(), user.key().as_ref()],
+        bump = delegate.bump,
+    )]
+    pub delegate: Option<Box<Account<'info, Delegate>>>,
+
+    /// User's basket order (created on first basket, reused afterwards)
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = BasketOrder::SIZE,
+        seeds = [BASKET_ORDER_SEED, &[pool.pool_id], user_account.owner.as_ref()],
+        bump,
+    )]
+    pub basket_order: Box<Account<'info, BasketOrder>>,
+
+    /// Active batch accumulator slot
+    #[account(
+        mut,
+        seeds = [BATCH_ACCUMULATOR_SEED, &[pool.pool_id], &[batch_accumulator.slot]],
+        bump = batch_accumulator.bump,
+        constraint = batch_accumulator.active @ ErrorCode::InvalidBatchSlot,
+    )]
+    pub batch_accumulator: Box<Account<'info, BatchAccumulator>>,
+
+    // =========================================================================
+    // ARCIUM MPC ACCOUNTS
+    // =========================================================================
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Box<Account<'info, ArciumSignerAccount>>,
+
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+
+    #[account(
+        mut,
+        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_ACCUMULATE_BASKET))]
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    pub cluster_account: Box<Account<'info, Cluster>>,
+
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Box<Account<'info, FeePool>>,
+
+    #[account(
+        mut,
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+    )]
+    pub clock_account: Box<Account<'info, ClockAccount>>,
+
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+}
+
+#[callback_accounts("accumulate_basket")]
+#[derive(Accounts)]
+pub struct AccumulateBasketCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_ACCUMULATE_BASKET))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Account<'info, MXEAccount>,
+
+    /// CHECK: computation_account, checked by arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+
+    #[account(address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    pub cluster_account: Account<'info, Cluster>,
+
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar
+    pub instructions_sysvar: AccountInfo<'info>,
+
+    // Application accounts (passed via CallbackAccount)
+    #[account(mut)]
+    pub user_account: Box<Account<'info, UserProfile>>,
+
+    #[account(mut)]
+    pub batch_accumulator: Box<Account<'info, BatchAccumulator>>,
+
+    #[account(mut)]
+    pub basket_order: Box<Account<'info, BasketOrder>>,
+
+    /// Pool event sequence (stamps `seq` into emitted events); the queue
+    /// site passes the pool's cursor
+    #[account(mut)]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+}
+
+#[init_computation_definition_accounts("accumulate_basket", payer)]
+#[derive(Accounts)]
+pub struct InitAccumulateBasketCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program.
+    pub comp_def_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_mxe_lut_pda!(mxe_account.lut_offset_slot))]
+    /// CHECK: address_lookup_table, checked by arcium program.
+    pub address_lookup_table: UncheckedAccount<'info>,
+    #[account(address = LUT_PROGRAM_ID)]
+    /// CHECK: lut_program is the Address Lookup Table program.
+    pub lut_program: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[queue_computation_accounts("calculate_payout", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct SettleBasketLeg<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Owner settling the leg, or a session key holding a Delegate
+    pub user: Signer<'info>,
+
+    /// User's privacy account
+    #[account(
+        mut,
+        seeds = [USER_SEED, &[pool.pool_id], user_account.owner.as_ref()],
+        bump = user_account.bump,
+    )]
+    pub user_account: Box<Account<'info, UserProfile>>,
+
+    /// Session key authorization (required when `user` is not the owner)
+    #[account(
+        seeds = [DELEGATE_SEED, &[pool.pool_id], user_account.owner.as_ref(), user.key().as_ref()],
+        bump = delegate.bump,
+    )]
+    pub delegate: Option<Box<Account<'info, Delegate>>>,
+
+    /// Basket holding the leg
+    #[account(
+        mut,
+        seeds = [BASKET_ORDER_SEED, &[pool.pool_id], user_account.owner.as_ref()],
+        bump = basket_order.bump,
+        constraint = basket_order.status == BASKET_STATUS_PLACED @ ErrorCode::NoBasketOrder,
+    )]
+    pub basket_order: Box<Account<'info, BasketOrder>>,
+
+    #[account(
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// BatchLog for the basket's batch
+    #[account(
+        seeds = [BATCH_LOG_SEED, &[pool.pool_id], &basket_order.batch_id.to_le_bytes()],
+        bump,
+    )]
+    pub batch_log: Account<'info, BatchLog>,
+
+    /// User's order history (created on first settlement, appended in callback)
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = OrderHistory::SIZE,
+        seeds = [ORDER_HISTORY_SEED, &[pool.pool_id], user_account.owner.as_ref()],
+        bump,
+    )]
+    pub order_history: Box<Account<'info, OrderHistory>>,
+
+    // =========================================================================
+    // ARCIUM MPC ACCOUNTS
+    // =========================================================================
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Box<Account<'info, ArciumSignerAccount>>,
+
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+
+    #[account(
+        mut,
+        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_CALCULATE_PAYOUT))]
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    pub cluster_account: Box<Account<'info, Cluster>>,
+
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Box<Account<'info, FeePool>>,
+
+    #[account(
+        mut,
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+    )]
+    pub clock_account: Box<Account<'info, ClockAccount>>,
+
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+}
+
+// =============================================================================
+// USD-SIZED ORDER ACCOUNTS
+// =============================================================================
+// Same accounts as place_order, bound to the accumulate_order_usd circuit.
+
+#[queue_computation_accounts("accumulate_order_usd", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct PlaceOrderUsd<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Owner placing the order, or a session key holding a Delegate
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    /// User's privacy account
+    #[account(
+        mut,
+        seeds = [USER_SEED, &[pool.pool_id], user_account.owner.as_ref()],
+        bump = user_account.bump,
+        constraint = user_account.pending_order.is_none() @ ErrorCode::PendingOrderExists,
+    )]
+    pub user_account: Box<Account<'info, UserProfile>>,
+
+    /// Session key authorization (required when `user` is not the owner)
+    #[account(
+        mut,
+        seeds = [DELEGATE_SEED, &[pool.pool_id], user_account.owner.as_ref(), user.key().as_ref()],
+        bump = delegate.bump,
+    )]
+    pub delegate: Option<Box<Account<'info, Delegate>>>,
+
+    /// Pool account (asset freeze check)
+    #[account(
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// Active batch accumulator slot
+    #[account(
+        mut,
+        seeds = [BATCH_ACCUMULATOR_SEED, &[pool.pool_id], &[batch_accumulator.slot]],
+        bump = batch_accumulator.bump,
+        constraint = batch_accumulator.active @ ErrorCode::InvalidBatchSlot,
+    )]
+    pub batch_accumulator: Box<Account<'info, BatchAccumulator>>,
+
+    /// Price feeds and limits (required when Pool.oracle_configured is set)
+    #[account(
+        seeds = [ORACLE_CONFIG_SEED, &[pool.pool_id]],
+        bump = oracle_config.bump,
+    )]
+    pub oracle_config: Option<Box<Account<'info, OracleConfig>>>,
+
+    /// Pyth update of the source asset (required when it has a Pyth feed)
+    /// CHECK: owner, layout and feed id are checked by oracle::read_pyth_price
+    pub price_update: Option<UncheckedAccount<'info>>,
+
+    /// Switchboard feed of the source asset (required when it has a fallback)
+    /// CHECK: matched against OracleConfig.fallback_feeds, layout checked by
+    /// oracle::read_switchboard_price
+    pub fallback_feed: Option<UncheckedAccount<'info>>,
+
+    // =========================================================================
+    // ARCIUM MPC ACCOUNTS
+    // =========================================================================
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Box<Account<'info, ArciumSignerAccount>>,
+
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+
+    #[account(
+        mut,
+        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_ACCUMULATE_ORDER_USD))]
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    pub cluster_account: Box<Account<'info, Cluster>>,
+
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Box<Account<'info, FeePool>>,
+
+    #[account(
+        mut,
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+    )]
+    pub clock_account: Box<Account<'info, ClockAccount>>,
+
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+}
+
+#[callback_accounts("accumulate_order_usd")]
+#[derive(Accounts)]
+pub struct AccumulateOrderUsdCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_ACCUMULATE_ORDER_USD))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Account<'info, MXEAccount>,
+
+    /// CHECK: computation_account, checked by arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+
+    #[account(address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    pub cluster_account: Account<'info, Cluster>,
+
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar
+    pub instructions_sysvar: AccountInfo<'info>,
+
+    // Application accounts (passed via CallbackAccount)
+    #[account(mut)]
+    pub user_account: Box<Account<'info, UserProfile>>,
+
+    #[account(mut)]
+    pub batch_accumulator: Box<Account<'info, BatchAccumulator>>,
+
+    /// Pool event sequence (stamps `seq` into emitted events); the queue
+    /// site passes the pool's cursor
+    #[account(mut)]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+}
+
+#[init_computation_definition_accounts("accumulate_order_usd", payer)]
+#[derive(Accounts)]
+pub struct InitAccumulateOrderUsdCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program.
+    pub comp_def_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_mxe_lut_pda!(mxe_account.lut_offset_slot))]
+    /// CHECK: address_lookup_table, checked by arcium program.
+    pub address_lookup_table: UncheckedAccount<'info>,
+    #[account(address = LUT_PROGRAM_ID)]
+    /// CHECK: lut_program is the Address Lookup Table program.
+    pub lut_program: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+// =============================================================================
+// PLACE STAGED ORDER ACCOUNTS
+// =============================================================================
+// Permissionless crank for orders staged by settle_and_place_order.
+
+#[queue_computation_accounts("accumulate_order", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct PlaceStagedOrder<'info> {
+    /// Crank caller (pays MPC fees)
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Privacy account holding the staged order
+    #[account(
+        mut,
+        seeds = [USER_SEED, &[pool.pool_id], user_account.owner.as_ref()],
+        bump = user_account.bump,
+        constraint = user_account.pending_order.is_none() @ ErrorCode::PendingOrderExists,
+        constraint = user_account.staged_order.is_some() @ ErrorCode::NoStagedOrder,
+    )]
+    pub user_account: Box<Account<'info, UserProfile>>,
+
+    /// Pool account (asset freeze check)
+    #[account(
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// Active batch accumulator slot
+    #[account(
+        mut,
+        seeds = [BATCH_ACCUMULATOR_SEED, &[pool.pool_id], &[batch_accumulator.slot]],
+        bump = batch_accumulator.bump,
+        constraint = batch_accumulator.active @ ErrorCode::InvalidBatchSlot,
+    )]
+    pub batch_accumulator: Box<Account<'info, BatchAccumulator>>,
+
+    // =========================================================================
+    // ARCIUM MPC ACCOUNTS
+    // =========================================================================
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Box<Account<'info, ArciumSignerAccount>>,
+
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+
+    #[account(
+        mut,
+        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_ACCUMULATE_ORDER))]
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    pub cluster_account: Box<Account<'info, Cluster>>,
+
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Box<Account<'info, FeePool>>,
+
+    #[account(
+        mut,
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+    )]
+    pub clock_account: Box<Account<'info, ClockAccount>>,
+
+    /// Pool event sequence (stamps `seq` into emitted events)
+    #[account(
+        mut,
+        seeds = [EVENT_CURSOR_SEED, &[pool.pool_id]],
+        bump = event_cursor.bump,
+    )]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+}
+
+#[derive(Accounts)]
+pub struct SetSettlementPreference<'info> {
+    /// Owner (pays rent)
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    /// Pool the account belongs to
+    #[account(
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    #[account(
+        mut,
+        seeds = [USER_SEED, &[pool.pool_id], user.key().as_ref()],
+        bump = user_account.bump,
+        constraint = user_account.owner == user.key() @ ErrorCode::InvalidOwner,
+    )]
+    pub user_account: Box<Account<'info, UserProfile>>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = SettlementPreference::SIZE,
+        seeds = [SETTLEMENT_PREFERENCE_SEED, &[pool.pool_id], user.key().as_ref()],
+        bump,
+    )]
+    pub settlement_preference: Box<Account<'info, SettlementPreference>>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Pool event sequence (stamps `seq` into emitted events)
+    #[account(
+        mut,
+        seeds = [EVENT_CURSOR_SEED, &[pool.pool_id]],
+        bump = event_cursor.bump,
+    )]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+}
+
+#[derive(Accounts)]
+pub struct ClearSettlementPreference<'info> {
+    /// Owner - receives the reclaimed rent
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    /// Pool the account belongs to
+    #[account(
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    #[account(
+        mut,
+        seeds = [USER_SEED, &[pool.pool_id], user.key().as_ref()],
+        bump = user_account.bump,
+        constraint = user_account.owner == user.key() @ ErrorCode::InvalidOwner,
+    )]
+    pub user_account: Box<Account<'info, UserProfile>>,
+
+    #[account(
+        mut,
+        close = user,
+        seeds = [SETTLEMENT_PREFERENCE_SEED, &[pool.pool_id], user.key().as_ref()],
+        bump = settlement_preference.bump,
+    )]
+    pub settlement_preference: Box<Account<'info, SettlementPreference>>,
+
+    /// Pool event sequence (stamps `seq` into emitted events)
+    #[account(
+        mut,
+        seeds = [EVENT_CURSOR_SEED, &[pool.pool_id]],
+        bump = event_cursor.bump,
+    )]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+}
+
+// =============================================================================
+// SESSION-KEY DELEGATION ACCOUNTS
+// =============================================================================
+
+#[derive(Accounts)]
+#[instruction(delegate: Pubkey)]
+pub struct CreateDelegate<'info> {
+    /// Owner (pays rent)
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    /// Pool the account belongs to
+    #[account(
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    #[account(
+        seeds = [USER_SEED, &[pool.pool_id], user.key().as_ref()],
+        bump = user_account.bump,
+        constraint = user_account.owner == user.key() @ ErrorCode::InvalidOwner,
+    )]
+    pub user_account: Box<Account<'info, UserProfile>>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = Delegate::SIZE,
+        seeds = [DELEGATE_SEED, &[pool.pool_id], user.key().as_ref(), delegate.as_ref()],
+        bump,
+    )]
+    pub delegate_account: Box<Account<'info, Delegate>>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Pool event sequence (stamps `seq` into emitted events)
+    #[account(
+        mut,
+        seeds = [EVENT_CURSOR_SEED, &[pool.pool_id]],
+        bump = event_cursor.bump,
+    )]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+}
+
+#[derive(Accounts)]
+pub struct RevokeDelegate<'info> {
+    /// Owner - receives the reclaimed rent
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    /// Pool the delegation belongs to
+    #[account(
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    #[account(
+        mut,
+        close = user,
+        seeds = [
+            DELEGATE_SEED,
+            &[pool.pool_id],
+            user.key().as_ref(),
+            delegate_account.delegate.as_ref(),
+        ],
+        bump = delegate_account.bump,
+    )]
+    pub delegate_account: Box<Account<'info, Delegate>>,
+
+    /// Pool event sequence (stamps `seq` into emitted events)
+    #[account(
+        mut,
+        seeds = [EVENT_CURSOR_SEED, &[pool.pool_id]],
+        bump = event_cursor.bump,
+    )]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+}
+
+#[derive(Accounts)]
+pub struct SetDelegateAllowance<'info> {
+    /// Owner
+    pub user: Signer<'info>,
+
+    /// Pool the delegation belongs to
+    #[account(
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    #[account(
+        mut,
+        seeds = [
+            DELEGATE_SEED,
+            &[pool.pool_id],
+            user.key().as_ref(),
+            delegate_account.delegate.as_ref(),
+        ],
+        bump = delegate_account.bump,
+    )]
+    pub delegate_account: Box<Account<'info, Delegate>>,
+
+    /// Pool event sequence (stamps `seq` into emitted events)
+    #[account(
+        mut,
+        seeds = [EVENT_CURSOR_SEED, &[pool.pool_id]],
+        bump = event_cursor.bump,
+    )]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+}
+
+// =============================================================================
+// DELEGATED ORDER ACCOUNTS
+// =============================================================================
+// Same accounts as place_order with a required Delegate, bound to the
+// accumulate_order_delegated circuit.
+
+#[queue_computation_accounts("accumulate_order_delegated", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct PlaceDelegatedOrder<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Session key, bot or program holding the Delegate
+    pub user: Signer<'info>,
+
+    /// Owner's privacy account
+    #[account(
+        mut,
+        seeds = [USER_SEED, &[pool.pool_id], user_account.owner.as_ref()],
+        bump = user_account.bump,
+        constraint = user_account.pending_order.is_none() @ ErrorCode::PendingOrderExists,
+    )]
+    pub user_account: Box<Account<'info, UserProfile>>,
+
+    /// Delegation holding the allowance
+    #[account(
+        mut,
+        seeds = [DELEGATE_SEED, &[pool.pool_id], user_account.owner.as_ref(), user.key().as_ref()],
+        bump = delegate.bump,
+    )]
+    pub delegate: Box<Account<'info, Delegate>>,
+
+    /// Pool account (asset freeze check, batch thresholds)
+    #[account(
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// Active batch accumulator slot
+    #[account(
+        mut,
+        seeds = [BATCH_ACCUMULATOR_SEED, &[pool.pool_id], &[batch_accumulator.slot]],
+        bump = batch_accumulator.bump,
+        constraint = batch_accumulator.active @ ErrorCode::InvalidBatchSlot,
+    )]
+    pub batch_accumulator: Box<Account<'info, BatchAccumulator>>,
+
+    // =========================================================================
+    // ARCIUM MPC ACCOUNTS
+    // =========================================================================
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Box<Account<'info, ArciumSignerAccount>>,
+
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+
+    #[account(
+        mut,
+        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_ACCUMULATE_ORDER_DELEGATED))]
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    pub cluster_account: Box<Account<'info, Cluster>>,
+
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Box<Account<'info, FeePool>>,
+
+    #[account(
+        mut,
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+    )]
+    pub clock_account: Box<Account<'info, ClockAccount>>,
+
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+}
+
+#[callback_accounts("accumulate_order_delegated")]
+#[derive(Accounts)]
+pub struct AccumulateOrderDelegatedCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_ACCUMULATE_ORDER_DELEGATED))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Account<'info, MXEAccount>,
+
+    /// CHECK: computation_account, checked by arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+
+    #[account(address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    pub cluster_account: Account<'info, Cluster>,
+
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar
+    pub instructions_sysvar: AccountInfo<'info>,
+
+    // Application accounts (passed via CallbackAccount)
+    #[account(mut)]
+    pub user_account: Box<Account<'info, UserProfile>>,
+
+    #[account(mut)]
+    pub batch_accumulator: Box<Account<'info, BatchAccumulator>>,
+
+    /// Pool event sequence (stamps `seq` into emitted events); the queue
+    /// site passes the pool's cursor
+    #[account(mut)]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+
+    /// Delegate whose allowance the order drew on
+    #[account(mut)]
+    pub delegate: Box<Account<'info, Delegate>>,
+}
+
+#[init_computation_definition_accounts("accumulate_order_delegated", payer)]
+#[derive(Accounts)]
+pub struct InitAccumulateOrderDelegatedCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program.
+    pub comp_def_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_mxe_lut_pda!(mxe_account.lut_offset_slot))]
+    /// CHECK: address_lookup_table, checked by arcium program.
+    pub address_lookup_table: UncheckedAccount<'info>,
+    #[account(address = LUT_PROGRAM_ID)]
+    /// CHECK: lut_program is the Address Lookup Table program.
+    pub lut_program: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+// =============================================================================
+// DELEGATED WITHDRAW ACCOUNTS
+// =============================================================================
+// Same accounts as sub_balance, signed by a delegate with an allowance,
+// bound to the sub_balance_delegated circuit.
+
+#[queue_computation_accounts("sub_balance_delegated", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct DelegatedWithdraw<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Session key, bot or program holding the Delegate
+    pub user: Signer<'info>,
+
+    /// Pool account (vault authority in the callback)
+    #[account(
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// Owner's privacy account
+    #[account(
+        seeds = [USER_SEED, &[pool.pool_id], user_account.owner.as_ref()],
+        bump = user_account.bump,
+    )]
+    pub user_account: Box<Account<'info, UserProfile>>,
+
+    /// Delegation holding the allowance
+    #[account(
+        seeds = [DELEGATE_SEED, &[pool.pool_id], user_account.owner.as_ref(), user.key().as_ref()],
+        bump = delegate.bump,
+    )]
+    pub delegate: Box<Account<'info, Delegate>>,
+
+    /// Context of this computation for the callback (see PendingOperation)
+    #[account(
+        init,
+        payer = payer,
+        space = PendingOperation::SIZE,
+        seeds = [
+            PENDING_OPERATION_SEED,
+            &[pool.pool_id],
+            user_account.owner.as_ref(),
+            &computation_offset.to_le_bytes(),
+        ],
+        bump,
+    )]
+    pub pending_operation: Box<Account<'info, PendingOperation>>,
+
+    /// Mint of the asset being withdrawn (must match pool mint for asset_id)
+    #[account(mint::token_program = token_program)]
+    pub mint: Box<InterfaceAccount<'info, anchor_spl::token_interface::Mint>>,
+
+    /// Recipient's token account for the asset being withdrawn (destination of funds)
+    /// Can be the user's own account OR an external recipient's account
+    /// Caller must provide the correct token account matching the asset_id
+    #[account(
+        mut,
+        constraint = recipient_token_account.mint == mint.key() @ ErrorCode::InvalidMint,
+    )]
+    pub recipient_token_account:
+        Box<InterfaceAccount<'info, anchor_spl::token_interface::TokenAccount>>,
+
+    /// Protocol's vault for the asset being withdrawn (source of funds)
+    /// Caller must provide the correct vault matching the asset_id
+    #[account(
+        mut,
+        constraint = vault.mint == mint.key() @ ErrorCode::InvalidMint,
+    )]
+    pub vault: Box<InterfaceAccount<'info, anchor_spl::token_interface::TokenAccount>>,
+
+    pub token_program: Interface<'info, anchor_spl::token_interface::TokenInterface>,
+
+    /// Transfer policy - required once the asset is restricted
+    #[account(
+        seeds = [ASSET_POLICY_SEED, &[pool.pool_id], mint.key().as_ref()],
+        bump = asset_policy.bump,
+        constraint = asset_policy.token_program == token_program.key() @ ErrorCode::InvalidAssetPolicy,
+    )]
+    pub asset_policy: Option<Box<Account<'info, AssetTransferPolicy>>>,
+
+    /// Issuer allowlist entry for the recipient wallet (restricted assets only)
+    /// CHECK: Verified against asset_policy by check_issuer_allowlist
+    pub allowlist_entry: Option<UncheckedAccount<'info>>,
+
+    /// Risk limits (required when Pool.risk_configured is set)
+    #[account(
+        seeds = [RISK_CONFIG_SEED, &[pool.pool_id]],
+        bump = risk_config.bump,
+    )]
+    pub risk_config: Option<Box<Account<'info, RiskConfig>>>,
+
+    /// Owner's withdrawals counted against the daily cap (required for a
+    /// capped asset)
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = WithdrawalWindow::SIZE,
+        seeds = [WITHDRAWAL_WINDOW_SEED, &[pool.pool_id], user_account.owner.as_ref()],
+        bump,
+    )]
+    pub withdrawal_window: Option<Box<Account<'info, WithdrawalWindow>>>,
+
+    // =========================================================================
+    // ARCIUM MPC ACCOUNTS
+    // =========================================================================
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Box<Account<'info, ArciumSignerAccount>>,
+
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+
+    #[account(
+        mut,
+        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_SUB_BALANCE_DELEGATED))]
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    pub cluster_account: Box<Account<'info, Cluster>>,
+
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Box<Account<'info, FeePool>>,
+
+    #[account(
+        mut,
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+    )]
+    pub clock_account: Box<Account<'info, ClockAccount>>,
+
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+}
+
+#[callback_accounts("sub_balance_delegated")]
+#[derive(Accounts)]
+pub struct SubBalanceDelegatedCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_SUB_BALANCE_DELEGATED))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Account<'info, MXEAccount>,
+
+    /// CHECK: computation_account, checked by arcium program via constraints in the callback context.
+    pub computation_account: UncheckedAccount<'info>,
+
+    #[account(address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    pub cluster_account: Account<'info, Cluster>,
+
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+
+    // =========================================================================
+    // APPLICATION ACCOUNTS (passed via CallbackAccount)
+    // =========================================================================
+    /// User's privacy account - receives the updated encrypted balance
+    #[account(mut)]
+    pub user_account: Box<Account<'info, UserProfile>>,
+
+    /// Pool PDA (authority for vault) - passed via CallbackAccount
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// Vault token account - source of tokens for withdrawal
+    /// CHECK: Passed via CallbackAccount, verified by token transfer
+    #[account(mut)]
+    pub vault: AccountInfo<'info>,
+
+    /// Recipient token account - destination for withdrawn tokens
+    /// CHECK: Passed via CallbackAccount, verified by token transfer
+    #[account(mut)]
+    pub recipient_token_account: AccountInfo<'info>,
+
+    /// Token program for transfer CPI
+    /// CHECK: Passed via CallbackAccount
+    pub token_program: AccountInfo<'info>,
+
+    /// Mint of the withdrawn asset (transfer_checked needs decimals)
+    pub mint: Box<InterfaceAccount<'info, anchor_spl::token_interface::Mint>>,
+
+    /// Delegate whose allowance the withdrawal drew on
+    #[account(
+        mut,
+        constraint = delegate.owner == user_account.owner @ ErrorCode::InvalidDelegate,
+    )]
+    pub delegate: Box<Account<'info, Delegate>>,
+
+    /// Context of this computation, closed on success
+    #[account(
+        mut,
+        constraint = pending_operation.owner == user_account.owner @ ErrorCode::InvalidPendingOperation,
+    )]
+    pub pending_operation: Box<Account<'info, PendingOperation>>,
+
+    /// CHECK: rent destination, must be the profile's owner
+    #[account(mut, address = user_account.owner)]
+    pub owner: UncheckedAccount<'info>,
+
+    /// Pool event sequence (stamps `seq` into emitted events)
+    #[account(
+        mut,
+        seeds = [EVENT_CURSOR_SEED, &[pool.pool_id]],
+        bump = event_cursor.bump,
+    )]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+    // Transfer-hook extra accounts (if any) arrive as remaining_accounts
+}
+
+#[init_computation_definition_accounts("sub_balance_delegated", payer)]
+#[derive(Accounts)]
+pub struct InitSubBalanceDelegatedCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program.
+    pub comp_def_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_mxe_lut_pda!(mxe_account.lut_offset_slot))]
+    /// CHECK: address_lookup_table, checked by arcium program.
+    pub address_lookup_table: UncheckedAccount<'info>,
+    #[account(address = LUT_PROGRAM_ID)]
+    /// CHECK: lut_program is the Address Lookup Table program.
+    pub lut_program: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+// =============================================================================
+// BENEFICIARY ACCOUNTS (Dead-man's switch)
+// =============================================================================
+
+#[derive(Accounts)]
+pub struct SetBeneficiary<'info> {
+    /// Owner (pays rent)
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    /// Pool the account belongs to
+    #[account(
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    #[account(
+        mut,
+        seeds = [USER_SEED, &[pool.pool_id], user.key().as_ref()],
+        bump = user_account.bump,
+        constraint = user_account.owner == user.key() @ ErrorCode::InvalidOwner,
+    )]
+    pub user_account: Box<Account<'info, UserProfile>>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = BeneficiaryConfig::SIZE,
+        seeds = [BENEFICIARY_SEED, &[pool.pool_id], user.key().as_ref()],
+        bump,
+    )]
+    pub beneficiary_config: Box<Account<'info, BeneficiaryConfig>>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Pool event sequence (stamps `seq` into emitted events)
+    #[account(
+        mut,
+        seeds = [EVENT_CURSOR_SEED, &[pool.pool_id]],
+        bump = event_cursor.bump,
+    )]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+}
+
+#[derive(Accounts)]
+pub struct RemoveBeneficiary<'info> {
+    /// Owner - receives the reclaimed rent
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    /// Pool the account belongs to
+    #[account(
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    #[account(
+        mut,
+        seeds = [USER_SEED, &[pool.pool_id], user.key().as_ref()],
+        bump = user_account.bump,
+        constraint = user_account.owner == user.key() @ ErrorCode::InvalidOwner,
+    )]
+    pub user_account: Box<Account<'info, UserProfile>>,
+
+    #[account(
+        mut,
+        close = user,
+        seeds = [BENEFICIARY_SEED, &[pool.pool_id], user.key().as_ref()],
+        bump = beneficiary_config.bump,
+    )]
+    pub beneficiary_config: Box<Account<'info, BeneficiaryConfig>>,
+
+    /// Pool event sequence (stamps `seq` into emitted events)
+    #[account(
+        mut,
+        seeds = [EVENT_CURSOR_SEED, &[pool.pool_id]],
+        bump = event_cursor.bump,
+    )]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+}
+
+#[queue_computation_accounts("sweep_balance", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct ClaimInheritance<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Registered beneficiary
+    pub beneficiary: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [BENEFICIARY_SEED, &[pool.pool_id], beneficiary_config.owner.as_ref()],
+        bump = beneficiary_config.bump,
+        constraint = beneficiary_config.beneficiary == beneficiary.key() @ ErrorCode::Unauthorized,
+    )]
+    pub beneficiary_config: Box<Account<'info, BeneficiaryConfig>>,
+
+    /// Pool the account belongs to
+    #[account(
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// Inactive owner's privacy account (source of funds)
+    #[account(
+        mut,
+        seeds = [USER_SEED, &[pool.pool_id], beneficiary_config.owner.as_ref()],
+        bump = owner_account.bump,
+    )]
+    pub owner_account: Box<Account<'info, UserProfile>>,
+
+    /// Beneficiary's privacy account (destination of funds)
+    #[account(
+        mut,
+        seeds = [USER_SEED, &[pool.pool_id], beneficiary.key().as_ref()],
+        bump = beneficiary_account.bump,
+    )]
+    pub beneficiary_account: Box<Account<'info, UserProfile>>,
+
+    // =========================================================================
+    // ARCIUM MPC ACCOUNTS
+    // =========================================================================
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Box<Account<'info, ArciumSignerAccount>>,
+
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+
+    #[account(
+        mut,
+        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_SWEEP_BALANCE))]
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    pub cluster_account: Box<Account<'info, Cluster>>,
+
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Box<Account<'info, FeePool>>,
+
+    #[account(
+        mut,
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+    )]
+    pub clock_account: Box<Account<'info, ClockAccount>>,
+
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+}
+
+#[callback_accounts("sweep_balance")]
+#[derive(Accounts)]
+pub struct SweepBalanceCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_SWEEP_BALANCE))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Account<'info, MXEAccount>,
+
+    /// CHECK: computation_account, checked by arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+
+    #[account(address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    pub cluster_account: Account<'info, Cluster>,
+
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar
+    pub instructions_sysvar: AccountInfo<'info>,
+
+    // Application accounts (passed via CallbackAccount)
+    #[account(mut)]
+    pub owner_account: Box<Account<'info, UserProfile>>,
+
+    #[account(mut)]
+    pub beneficiary_account: Box<Account<'info, UserProfile>>,
+
+    pub beneficiary_config: Box<Account<'info, BeneficiaryConfig>>,
+
+    /// Pool event sequence (stamps `seq` into emitted events); the queue
+    /// site passes the pool's cursor
+    #[account(mut)]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+}
+
+#[init_computation_definition_accounts("sweep_balance", payer)]
+#[derive(Accounts)]
+pub struct InitSweepBalanceCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program.
+    pub comp_def_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_mxe_lut_pda!(mxe_account.lut_offset_slot))]
+    /// CHECK: address_lookup_table, checked by arcium program.
+    pub address_lookup_table: UncheckedAccount<'info>,
+    #[account(address = LUT_PROGRAM_ID)]
+    /// CHECK: lut_program is the Address Lookup Table program.
+    pub lut_program: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+// =============================================================================
+// GUARDIAN RECOVERY ACCOUNTS (Social recovery)
+// =============================================================================
+
+#[derive(Accounts)]
+pub struct SetGuardians<'info> {
+    /// Owner (pays rent)
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    /// Pool the account belongs to
+    #[account(
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    #[account(
+        mut,
+        seeds = [USER_SEED, &[pool.pool_id], user.key().as_ref()],
+        bump = user_account.bump,
+        constraint = user_account.owner == user.key() @ ErrorCode::InvalidOwner,
+    )]
+    pub user_account: Box<Account<'info, UserProfile>>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = GuardianSet::SIZE,
+        seeds = [GUARDIAN_SET_SEED, &[pool.pool_id], user.key().as_ref()],
+        bump,
+    )]
+    pub guardian_set: Box<Account<'info, GuardianSet>>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Pool event sequence (stamps `seq` into emitted events)
+    #[account(
+        mut,
+        seeds = [EVENT_CURSOR_SEED, &[pool.pool_id]],
+        bump = event_cursor.bump,
+    )]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+}
+
+#[derive(Accounts)]
+pub struct ApproveRecovery<'info> {
+    /// One of the owner's guardians
+    pub guardian: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GUARDIAN_SET_SEED, &[guardian_set.pool_id], guardian_set.owner.as_ref()],
+        bump = guardian_set.bump,
+    )]
+    pub guardian_set: Box<Account<'info, GuardianSet>>,
+
+    /// Pool event sequence (stamps `seq` into emitted events)
+    #[account(
+        mut,
+        seeds = [EVENT_CURSOR_SEED, &[guardian_set.pool_id]],
+        bump = event_cursor.bump,
+    )]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+}
+
+#[derive(Accounts)]
+pub struct CancelRecovery<'info> {
+    /// Owner of the account being recovered
+    pub user: Signer<'info>,
+
+    /// Pool the account belongs to
+    #[account(
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    #[account(
+        mut,
+        seeds = [USER_SEED, &[pool.pool_id], user.key().as_ref()],
+        bump = user_account.bump,
+        constraint = user_account.owner == user.key() @ ErrorCode::InvalidOwner,
+    )]
+    pub user_account: Box<Account<'info, UserProfile>>,
+
+    #[account(
+        mut,
+        seeds = [GUARDIAN_SET_SEED, &[pool.pool_id], user.key().as_ref()],
+        bump = guardian_set.bump,
+    )]
+    pub guardian_set: Box<Account<'info, GuardianSet>>,
+
+    /// Pool event sequence (stamps `seq` into emitted events)
+    #[account(
+        mut,
+        seeds = [EVENT_CURSOR_SEED, &[pool.pool_id]],
+        bump = event_cursor.bump,
+    )]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+}
+
+#[queue_computation_accounts("recover_account", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct ExecuteRecovery<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Approved new owner
+    pub new_owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GUARDIAN_SET_SEED, &[pool.pool_id], guardian_set.owner.as_ref()],
+        bump = guardian_set.bump,
+        constraint = guardian_set.proposed_owner == new_owner.key() @ ErrorCode::Unauthorized,
+    )]
+    pub guardian_set: Box<Account<'info, GuardianSet>>,
+
+    /// Pool the accounts belong to
+    #[account(
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+        constraint = pool.pool_id == guardian_set.pool_id @ ErrorCode::InvalidRecovery,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// Privacy account being recovered (source of funds, closed)
+    #[account(
+        seeds = [USER_SEED, &[pool.pool_id], guardian_set.owner.as_ref()],
+        bump = old_account.bump,
+    )]
+    pub old_account: Box<Account<'info, UserProfile>>,
+
+    /// New owner's privacy account (destination of funds)
+    #[account(
+        mut,
+        seeds = [USER_SEED, &[pool.pool_id], new_owner.key().as_ref()],
+        bump = new_account.bump,
+        constraint = new_account.owner == new_owner.key() @ ErrorCode::InvalidOwner,
+    )]
+    pub new_account: Box<Account<'info, UserProfile>>,
+
+    /// RentSponsor pool (only passed when the old profile's rent was sponsored)
+    #[account(
+        mut,
+        seeds = [RENT_SPONSOR_SEED, &[pool.pool_id]],
+        bump = rent_sponsor.bump,
+    )]
+    pub rent_sponsor: Option<Box<Account<'info, RentSponsor>>>,
+
+    // =========================================================================
+    // ARCIUM MPC ACCOUNTS
+    // =========================================================================
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Box<Account<'info, ArciumSignerAccount>>,
+
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+
+    #[account(
+        mut,
+        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_RECOVER_ACCOUNT))]
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    pub cluster_account: Box<Account<'info, Cluster>>,
+
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Box<Account<'info, FeePool>>,
+
+    #[account(
+        mut,
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+    )]
+    pub clock_account: Box<Account<'info, ClockAccount>>,
+
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+}
+
+#[callback_accounts("recover_account")]
+#[derive(Accounts)]
+pub struct RecoverAccountCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_RECOVER_ACCOUNT))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Account<'info, MXEAccount>,
+
+    /// CHECK: computation_account, checked by arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+
+    #[account(address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    pub cluster_account: Account<'info, Cluster>,
+
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar
+    pub instructions_sysvar: AccountInfo<'info>,
+
+    // Application accounts (passed via CallbackAccount)
+    /// Closed once recovered; rent goes to the new owner
+    #[account(mut)]
+    pub guardian_set: Box<Account<'info, GuardianSet>>,
+
+    #[account(
+        mut,
+        constraint = old_account.owner == guardian_set.owner @ ErrorCode::InvalidRecovery,
+    )]
+    pub old_account: Box<Account<'info, UserProfile>>,
+
+    #[account(
+        mut,
+        constraint = new_account.owner == guardian_set.proposed_owner @ ErrorCode::InvalidRecovery,
+    )]
+    pub new_account: Box<Account<'info, UserProfile>>,
+
+    /// CHECK: rent destination, must be the approved new owner
+    #[account(mut, address = guardian_set.proposed_owner)]
+    pub new_owner: UncheckedAccount<'info>,
+
+    /// RentSponsor pool (only passed when the old profile's rent was sponsored)
+    #[account(
+        mut,
+        seeds = [RENT_SPONSOR_SEED, &[guardian_set.pool_id]],
+        bump = rent_sponsor.bump,
+    )]
+    pub rent_sponsor: Option<Box<Account<'info, RentSponsor>>>,
+
+    /// Pool event sequence (stamps `seq` into emitted events); the queue
+    /// site passes the pool's cursor
+    #[account(mut)]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+}
+
+#[init_computation_definition_accounts("recover_account", payer)]
+#[derive(Accounts)]
+pub struct InitRecoverAccountCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program.
+    pub comp_def_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_mxe_lut_pda!(mxe_account.lut_offset_slot))]
+    /// CHECK: address_lookup_table, checked by arcium program.
+    pub address_lookup_table: UncheckedAccount<'info>,
+    #[account(address = LUT_PROGRAM_ID)]
+    /// CHECK: lut_program is the Address Lookup Table program.
+    pub lut_program: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetViewingKey<'info> {
+    /// Owner (pays rent)
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    /// Pool the account belongs to
+    #[account(
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    #[account(
+        mut,
+        seeds = [USER_SEED, &[pool.pool_id], user.key().as_ref()],
+        bump = user_account.bump,
+        constraint = user_account.owner == user.key() @ ErrorCode::InvalidOwner,
+    )]
+    pub user_account: Box<Account<'info, UserProfile>>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = ViewableBalance::SIZE,
+        seeds = [VIEWABLE_BALANCE_SEED, &[pool.pool_id], user.key().as_ref()],
+        bump,
+    )]
+    pub viewable_balance: Box<Account<'info, ViewableBalance>>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Pool event sequence (stamps `seq` into emitted events)
+    #[account(
+        mut,
+        seeds = [EVENT_CURSOR_SEED, &[pool.pool_id]],
+        bump = event_cursor.bump,
+    )]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+}
+
+#[queue_computation_accounts("reencrypt_balance", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct RefreshViewableBalance<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Owner of the balances
+    pub user: Signer<'info>,
+
+    /// Pool the account belongs to
+    #[account(
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    #[account(
+        seeds = [USER_SEED, &[pool.pool_id], user.key().as_ref()],
+        bump = user_account.bump,
+        constraint = user_account.owner == user.key() @ ErrorCode::InvalidOwner,
+    )]
+    pub user_account: Box<Account<'info, UserProfile>>,
+
+    #[account(
+        mut,
+        seeds = [VIEWABLE_BALANCE_SEED, &[pool.pool_id], user.key().as_ref()],
+        bump = viewable_balance.bump,
+    )]
+    pub viewable_balance: Box<Account<'info, ViewableBalance>>,
+
+    // =========================================================================
+    // ARCIUM MPC ACCOUNTS
+    // =========================================================================
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Box<Account<'info, ArciumSignerAccount>>,
+
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+
+    #[account(
+        mut,
+        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_REENCRYPT_BALANCE))]
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    pub cluster_account: Box<Account<'info, Cluster>>,
+
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Box<Account<'info, FeePool>>,
+
+    #[account(
+        mut,
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+    )]
+    pub clock_account: Box<Account<'info, ClockAccount>>,
+
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+}
+
+#[callback_accounts("reencrypt_balance")]
+#[derive(Accounts)]
+pub struct ReencryptBalanceCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_REENCRYPT_BALANCE))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Account<'info, MXEAccount>,
+
+    /// CHECK: computation_account, checked by arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+
+    #[account(address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    pub cluster_account: Account<'info, Cluster>,
+
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar
+    pub instructions_sysvar: AccountInfo<'info>,
+
+    // Application accounts (passed via CallbackAccount)
+    #[account(mut)]
+    pub viewable_balance: Box<Account<'info, ViewableBalance>>,
+
+    /// Pool event sequence (stamps `seq` into emitted events); the queue
+    /// site passes the pool's cursor
+    #[account(mut)]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+}
+
+#[init_computation_definition_accounts("reencrypt_balance", payer)]
+#[derive(Accounts)]
+pub struct InitReencryptBalanceCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program.
+    pub comp_def_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_mxe_lut_pda!(mxe_account.lut_offset_slot))]
+    /// CHECK: address_lookup_table, checked by arcium program.
+    pub address_lookup_table: UncheckedAccount<'info>,
+    #[account(address = LUT_PROGRAM_ID)]
+    /// CHECK: lut_program is the Address Lookup Table program.
+    pub lut_program: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[queue_computation_accounts("rotate_user_key", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct RotateUserKey<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Owner of the balances (pays rent)
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    /// Pool the account belongs to
+    #[account(
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    #[account(
+        mut,
+        seeds = [USER_SEED, &[pool.pool_id], user.key().as_ref()],
+        bump = user_account.bump,
+        constraint = user_account.owner == user.key() @ ErrorCode::InvalidOwner,
+    )]
+    pub user_account: Box<Account<'info, UserProfile>>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = KeyRotation::SIZE,
+        seeds = [KEY_ROTATION_SEED, &[pool.pool_id], user.key().as_ref()],
+        bump,
+    )]
+    pub key_rotation: Box<Account<'info, KeyRotation>>,
+
+    // =========================================================================
+    // ARCIUM MPC ACCOUNTS
+    // =========================================================================
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Box<Account<'info, ArciumSignerAccount>>,
+
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+
+    #[account(
+        mut,
+        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_ROTATE_USER_KEY))]
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    pub cluster_account: Box<Account<'info, Cluster>>,
+
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Box<Account<'info, FeePool>>,
+
+    #[account(
+        mut,
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+    )]
+    pub clock_account: Box<Account<'info, ClockAccount>>,
+
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+}
+
+#[callback_accounts("rotate_user_key")]
+#[derive(Accounts)]
+pub struct RotateUserKeyCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_ROTATE_USER_KEY))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Account<'info, MXEAccount>,
+
+    /// CHECK: computation_account, checked by arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+
+    #[account(address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    pub cluster_account: Account<'info, Cluster>,
+
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar
+    pub instructions_sysvar: AccountInfo<'info>,
+
+    // Application accounts (passed via CallbackAccount)
+    #[account(mut)]
+    pub user_account: Box<Account<'info, UserProfile>>,
+
+    #[account(mut)]
+    pub key_rotation: Box<Account<'info, KeyRotation>>,
+
+    /// Pool event sequence (stamps `seq` into emitted events); the queue
+    /// site passes the pool's cursor
+    #[account(mut)]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+}
+
+#[init_computation_definition_accounts("rotate_user_key", payer)]
+#[derive(Accounts)]
+pub struct InitRotateUserKeyCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program.
+    pub comp_def_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_mxe_lut_pda!(mxe_account.lut_offset_slot))]
+    /// CHECK: address_lookup_table, checked by arcium program.
+    pub address_lookup_table: UncheckedAccount<'info>,
+    #[account(address = LUT_PROGRAM_ID)]
+    /// CHECK: lut_program is the Address Lookup Table program.
+    pub lut_program: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[queue_computation_accounts("check_balances_zero", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct CloseUserAccount<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Owner of the profile (pays the AccountClosure rent, refunded on close)
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    /// Pool the account belongs to
+    #[account(
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    #[account(
+        mut,
+        seeds = [USER_SEED, &[pool.pool_id], user.key().as_ref()],
+        bump = user_account.bump,
+        constraint = user_account.owner == user.key() @ ErrorCode::InvalidOwner,
+    )]
+    pub user_account: Box<Account<'info, UserProfile>>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = AccountClosure::SIZE,
+        seeds = [ACCOUNT_CLOSURE_SEED, &[pool.pool_id], user.key().as_ref()],
+        bump,
+    )]
+    pub account_closure: Box<Account<'info, AccountClosure>>,
+
+    /// RentSponsor pool (only passed when the profile's rent was sponsored)
+    #[account(
+        mut,
+        seeds = [RENT_SPONSOR_SEED, &[pool.pool_id]],
+        bump = rent_sponsor.bump,
+    )]
+    pub rent_sponsor: Option<Box<Account<'info, RentSponsor>>>,
+
+    // =========================================================================
+    // ARCIUM MPC ACCOUNTS
+    // =========================================================================
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Box<Account<'info, ArciumSignerAccount>>,
+
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+
+    #[account(
+        mut,
+        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_CHECK_BALANCES_ZERO))]
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    pub cluster_account: Box<Account<'info, Cluster>>,
+
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Box<Account<'info, FeePool>>,
+
+    #[account(
+        mut,
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+    )]
+    pub clock_account: Box<Account<'info, ClockAccount>>,
+
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+}
+
+#[callback_accounts("check_balances_zero")]
+#[derive(Accounts)]
+pub struct CheckBalancesZeroCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_CHECK_BALANCES_ZERO))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Account<'info, MXEAccount>,
+
+    /// CHECK: computation_account, checked by arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+
+    #[account(address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    pub cluster_account: Account<'info, Cluster>,
+
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar
+    pub instructions_sysvar: AccountInfo<'info>,
+
+    // Application accounts (passed via CallbackAccount)
+    #[account(mut)]
+    pub user_account: Box<Account<'info, UserProfile>>,
+
+    #[account(mut)]
+    pub account_closure: Box<Account<'info, AccountClosure>>,
+
+    /// CHECK: rent destination, must be the profile's owner
+    #[account(mut, address = user_account.owner)]
+    pub owner: UncheckedAccount<'info>,
+
+    /// RentSponsor pool (only passed when the profile's rent was sponsored;
+    /// its seeds are checked by close_user_account when queuing)
+    #[account(mut)]
+    pub rent_sponsor: Option<Box<Account<'info, RentSponsor>>>,
+
+    /// Pool event sequence (stamps `seq` into emitted events); the queue
+    /// site passes the pool's cursor
+    #[account(mut)]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+}
+
+#[init_computation_definition_accounts("check_balances_zero", payer)]
+#[derive(Accounts)]
+pub struct InitCheckBalancesZeroCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program.
+    pub comp_def_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_mxe_lut_pda!(mxe_account.lut_offset_slot))]
+    /// CHECK: address_lookup_table, checked by arcium program.
+    pub address_lookup_table: UncheckedAccount<'info>,
+    #[account(address = LUT_PROGRAM_ID)]
+    /// CHECK: lut_program is the Address Lookup Table program.
+    pub lut_program: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[queue_computation_accounts("disclose_balances", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, viewer_pubkey: [u8; 32])]
+pub struct AddViewer<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Owner of the balances (pays rent)
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    /// Pool the account belongs to
+    #[account(
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    #[account(
+        mut,
+        seeds = [USER_SEED, &[pool.pool_id], user.key().as_ref()],
+        bump = user_account.bump,
+        constraint = user_account.owner == user.key() @ ErrorCode::InvalidOwner,
+    )]
+    pub user_account: Box<Account<'info, UserProfile>>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = ViewerGrant::SIZE,
+        seeds = [VIEWER_GRANT_SEED, &[pool.pool_id], user.key().as_ref(), viewer_pubkey.as_ref()],
+        bump,
+    )]
+    pub viewer_grant: Box<Account<'info, ViewerGrant>>,
+
+    // =========================================================================
+    // ARCIUM MPC ACCOUNTS
+    // =========================================================================
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Box<Account<'info, ArciumSignerAccount>>,
+
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+
+    #[account(
+        mut,
+        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_DISCLOSE_BALANCES))]
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    pub cluster_account: Box<Account<'info, Cluster>>,
+
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Box<Account<'info, FeePool>>,
+
+    #[account(
+        mut,
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+    )]
+    pub clock_account: Box<Account<'info, ClockAccount>>,
+
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+
+    /// Pool event sequence (stamps `seq` into emitted events)
+    #[account(
+        mut,
+        seeds = [EVENT_CURSOR_SEED, &[pool.pool_id]],
+        bump = event_cursor.bump,
+    )]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+}
+
+#[callback_accounts("disclose_balances")]
+#[derive(Accounts)]
+pub struct DiscloseBalancesCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_DISCLOSE_BALANCES))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Account<'info, MXEAccount>,
+
+    /// CHECK: computation_account, checked by arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+
+    #[account(address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    pub cluster_account: Account<'info, Cluster>,
+
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar
+    pub instructions_sysvar: AccountInfo<'info>,
+
+    // Application accounts (passed via CallbackAccount)
+    #[account(mut)]
+    pub viewer_grant: Box<Account<'info, ViewerGrant>>,
+
+    /// Pool event sequence (stamps `seq` into emitted events); the queue
+    /// site passes the pool's cursor
+    #[account(mut)]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+}
+
+#[init_computation_definition_accounts("disclose_balances", payer)]
+#[derive(Accounts)]
+pub struct InitDiscloseBalancesCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program.
+    pub comp_def_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_mxe_lut_pda!(mxe_account.lut_offset_slot))]
+    /// CHECK: address_lookup_table, checked by arcium program.
+    pub address_lookup_table: UncheckedAccount<'info>,
+    #[account(address = LUT_PROGRAM_ID)]
+    /// CHECK: lut_program is the Address Lookup Table program.
+    pub lut_program: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(viewer_pubkey: [u8; 32])]
+pub struct RemoveViewer<'info> {
+    /// Owner - receives the reclaimed rent
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    /// Pool the account belongs to
+    #[account(
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    #[account(
+        mut,
+        seeds = [USER_SEED, &[pool.pool_id], user.key().as_ref()],
+        bump = user_account.bump,
+        constraint = user_account.owner == user.key() @ ErrorCode::InvalidOwner,
+    )]
+    pub user_account: Box<Account<'info, UserProfile>>,
+
+    #[account(
+        mut,
+        close = user,
+        seeds = [VIEWER_GRANT_SEED, &[pool.pool_id], user.key().as_ref(), viewer_pubkey.as_ref()],
+        bump = viewer_grant.bump,
+    )]
+    pub viewer_grant: Box<Account<'info, ViewerGrant>>,
+
+    /// Pool event sequence (stamps `seq` into emitted events)
+    #[account(
+        mut,
+        seeds = [EVENT_CURSOR_SEED, &[pool.pool_id]],
+        bump = event_cursor.bump,
+    )]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+}
+
+// =============================================================================
+// EXECUTE BATCH ACCOUNTS (Phase 9)
+// =============================================================================
+
+#[queue_computation_accounts("reveal_batch", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct ExecuteBatch<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Batch accumulator to read state from (the active slot, or a slot
+    /// reopened by recover_batch_reveal)
+    #[account(
+        mut,
+        seeds = [BATCH_ACCUMULATOR_SEED, &[pool.pool_id], &[batch_accumulator.slot]],
+        bump = batch_accumulator.bump,
+    )]
+    pub batch_accumulator: Box<Account<'info, BatchAccumulator>>,
+
+    /// The other accumulator slot, which becomes active for the next batch
+    #[account(
+        mut,
+        seeds = [BATCH_ACCUMULATOR_SEED, &[pool.pool_id], &[next_accumulator.slot]],
+        bump = next_accumulator.bump,
+        constraint = next_accumulator.slot != batch_accumulator.slot @ ErrorCode::InvalidBatchSlot,
+    )]
+    pub next_accumulator: Box<Account<'info, BatchAccumulator>>,
+
+    /// BatchLog PDA to create (will be initialized in callback).
+    /// Reused if a previous reveal for this batch_id aborted.
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = BatchLog::SIZE,
+        seeds = [BATCH_LOG_SEED, &[pool.pool_id], &batch_accumulator.batch_id.to_le_bytes()],
+        bump,
+    )]
+    pub batch_log: Box<Account<'info, BatchLog>>,
+
+    /// Pool account (frozen assets snapshotted into BatchLog, batch rate limit)
+    #[account(
+        mut,
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// Role grants; only needed when a granted operator flushes a batch
+    /// below the trigger count
+    #[account(
+        seeds = [ROLES_SEED, &[pool.pool_id]],
+        bump = roles.bump,
+    )]
+    pub roles: Option<Box<Account<'info, Roles>>>,
+
+    // =========================================================================
+    // RESERVE ACCOUNTS (snapshotted to cap surplus fills)
+    // =========================================================================
+    #[account(seeds = [RESERVE_SEED, &[pool.pool_id], RESERVE_USDC_SEED], bump)]
+    pub reserve_usdc: Box<Account<'info, TokenAccount>>,
+
+    #[account(seeds = [RESERVE_SEED, &[pool.pool_id], RESERVE_TSLA_SEED], bump)]
+    pub reserve_tsla: Box<Account<'info, TokenAccount>>,
+
+    #[account(seeds = [RESERVE_SEED, &[pool.pool_id], RESERVE_SPY_SEED], bump)]
+    pub reserve_spy: Box<Account<'info, TokenAccount>>,
+
+    #[account(seeds = [RESERVE_SEED, &[pool.pool_id], RESERVE_AAPL_SEED], bump)]
+    pub reserve_aapl: Box<Account<'info, TokenAccount>>,
+
+    /// Per-pair execution policy, snapshotted into BatchLog (required when
+    /// Pool.execution_policy_configured is set)
+    #[account(
+        seeds = [EXECUTION_POLICY_SEED, &[pool.pool_id]],
+        bump = execution_policy.bump,
+    )]
+    pub execution_policy: Option<Box<Account<'info, ExecutionPolicy>>>,
+
+    /// Per-pair switches, fees and price bands, snapshotted into BatchLog
+    /// (required when Pool.pair_registry_configured is set)
+    #[account(
+        seeds = [PAIR_REGISTRY_SEED, &[pool.pool_id]],
+        bump = pair_registry.bump,
+    )]
+    pub pair_registry: Option<Box<Account<'info, PairRegistry>>>,
+
+    /// Equity trading hours (required when Pool.market_hours_enforced is set)
+    #[account(
+        seeds = [MARKET_SCHEDULE_SEED, &[pool.pool_id]],
+        bump = market_schedule.bump,
+    )]
+    pub market_schedule: Option<Box<Account<'info, MarketSchedule>>>,
+
+    /// Bonded executors (required when Pool.executor_gated is set)
+    #[account(
+        seeds = [EXECUTOR_REGISTRY_SEED, &[pool.pool_id]],
+        bump = executor_registry.bump,
+    )]
+    pub executor_registry: Option<Box<Account<'info, ExecutorRegistry>>>,
+
+    /// Circuit versions; reveal_batch's is stamped into the BatchLog
+    #[account(
+        seeds = [CIRCUIT_REGISTRY_SEED, &[pool.pool_id]],
+        bump = circuit_registry.bump,
+    )]
+    pub circuit_registry: Option<Box<Account<'info, CircuitRegistry>>>,
+
+    /// Optional diagnostics account (records CU usage when profiling is enabled)
+    #[account(
+        mut,
+        seeds = [DIAGNOSTICS_SEED, &[pool.pool_id]],
+        bump = diagnostics.bump,
+    )]
+    pub diagnostics: Option<Account<'info, Diagnostics>>,
+
+    /// Price feeds and limits (required when Pool.oracle_configured is set)
+    #[account(
+        seeds = [ORACLE_CONFIG_SEED, &[pool.pool_id]],
+        bump = oracle_config.bump,
+    )]
+    pub oracle_config: Option<Box<Account<'info, OracleConfig>>>,
+
+    /// Risk limits (required when Pool.risk_configured is set)
+    #[account(
+        seeds = [RISK_CONFIG_SEED, &[pool.pool_id]],
+        bump = risk_config.bump,
+    )]
+    pub risk_config: Option<Box<Account<'info, RiskConfig>>>,
+
+    // =========================================================================
+    // PYTH PRICE UPDATES (required for each asset with a Pyth feed)
+    // =========================================================================
+    /// CHECK: owner, layout and feed id are checked by oracle::read_pyth_price
+    pub price_update_usdc: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: owner, layout and feed id are checked by oracle::read_pyth_price
+    pub price_update_tsla: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: owner, layout and feed id are checked by oracle::read_pyth_price
+    pub price_update_spy: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: owner, layout and feed id are checked by oracle::read_pyth_price
+    pub price_update_aapl: Option<UncheckedAccount<'info>>,
+
+    // =========================================================================
+    // SWITCHBOARD FALLBACK FEEDS (required for each asset with a fallback)
+    // =========================================================================
+    /// CHECK: matched against OracleConfig.fallback_feeds, layout checked by
+    /// oracle::read_switchboard_price
+    pub fallback_feed_usdc: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: matched against OracleConfig.fallback_feeds, layout checked by
+    /// oracle::read_switchboard_price
+    pub fallback_feed_tsla: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: matched against OracleConfig.fallback_feeds, layout checked by
+    /// oracle::read_switchboard_price
+    pub fallback_feed_spy: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: matched against OracleConfig.fallback_feeds, layout checked by
+    /// oracle::read_switchboard_price
+    pub fallback_feed_aapl: Option<UncheckedAccount<'info>>,
+
+    // =========================================================================
+    // ARCIUM MPC ACCOUNTS
+    // =========================================================================
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Box<Account<'info, ArciumSignerAccount>>,
+
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+
+    #[account(
+        mut,
+        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_REVEAL_BATCH))]
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    pub cluster_account: Box<Account<'info, Cluster>>,
+
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Box<Account<'info, FeePool>>,
+
+    #[account(
+        mut,
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+    )]
+    pub clock_account: Box<Account<'info, ClockAccount>>,
+
+    /// Pool event sequence (stamps `seq` into emitted events)
+    #[account(
+        mut,
+        seeds = [EVENT_CURSOR_SEED, &[pool.pool_id]],
+        bump = event_cursor.bump,
+    )]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+}
+
+// =============================================================================
+// REVEAL BATCH CALLBACK ACCOUNTS (Phase 9)
+// =============================================================================
+
+#[callback_accounts("reveal_batch")]
+#[derive(Accounts)]
+pub struct RevealBatchCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_REVEAL_BATCH))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Account<'info, MXEAccount>,
+
+    /// CHECK: computation_account, checked by arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+
+    #[account(address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    pub cluster_account: Account<'info, Cluster>,
+
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar
+    pub instructions_sysvar: AccountInfo<'info>,
+
+    // Application accounts (passed via CallbackAccount)
+    #[account(mut)]
+    pub batch_accumulator: Box<Account<'info, BatchAccumulator>>,
+
+    #[account(mut)]
+    pub batch_log: Account<'info, BatchLog>,
+
+    #[account(mut)]
+    pub diagnostics: Option<Account<'info, Diagnostics>>,
+
+    /// Pool account (records the batch's execution prices)
+    #[account(
+        mut,
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// Pool event sequence (stamps `seq` into emitted events)
+    #[account(
+        mut,
+        seeds = [EVENT_CURSOR_SEED, &[pool.pool_id]],
+        bump = event_cursor.bump,
+    )]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+
+    /// Cumulative analytics (volume, revenue, reveal latency)
+    #[account(
+        mut,
+        seeds = [PROTOCOL_STATS_SEED, &[pool.pool_id]],
+        bump = protocol_stats.bump,
+    )]
+    pub protocol_stats: Box<Account<'info, ProtocolStats>>,
+    // TODO: Re-add these accounts after testing callback limit
+    // pub vault_usdc: Box<Account<'info, TokenAccount>>,
+    // pub vault_tsla: Box<Account<'info, TokenAccount>>,
+    // pub vault_spy: Box<Account<'info, TokenAccount>>,
+    // pub vault_aapl: Box<Account<'info, TokenAccount>>,
+    // pub reserve_usdc: Box<Account<'info, TokenAccount>>,
+    // pub reserve_tsla: Box<Account<'info, TokenAccount>>,
+    // pub reserve_spy: Box<Account<'info, TokenAccount>>,
+    // pub reserve_aapl: Box<Account<'info, TokenAccount>>,
+    // pub token_program: Program<'info, Token>,
+}
+
+// =============================================================================
+// APPROVE BATCH ACCOUNTS
+// =============================================================================
+
+#[derive(Accounts)]
+#[instruction(batch_id: u64)]
+pub struct ApproveBatch<'info> {
+    /// Co-operator (second signer for large batches)
+    #[account(
+        constraint = co_operator.key() == pool.co_operator @ ErrorCode::Unauthorized,
+    )]
+    pub co_operator: Signer<'info>,
+
+    #[account(
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// BatchLog of the batch to approve
+    #[account(
+        mut,
+        seeds = [BATCH_LOG_SEED, &[pool.pool_id], &batch_id.to_le_bytes()],
+        bump = batch_log.bump,
+    )]
+    pub batch_log: Box<Account<'info, BatchLog>>,
+
+    /// Pool event sequence (stamps `seq` into emitted events)
+    #[account(
+        mut,
+        seeds = [EVENT_CURSOR_SEED, &[pool.pool_id]],
+        bump = event_cursor.bump,
+    )]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+}
+
+#[derive(Accounts)]
+#[instruction(batch_id: u64)]
+pub struct OverridePriceBand<'info> {
+    /// Pool authority
+    #[account(constraint = roles.is_risk_manager(&pool, &authority.key()) @ ErrorCode::Unauthorized)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// Role grants (admins and operators besides the pool's own)
+    #[account(
+        seeds = [ROLES_SEED, &[pool.pool_id]],
+        bump = roles.bump,
+    )]
+    pub roles: Box<Account<'info, Roles>>,
+
+    /// BatchLog of the batch to accept
+    #[account(
+        mut,
+        seeds = [BATCH_LOG_SEED, &[pool.pool_id], &batch_id.to_le_bytes()],
+        bump = batch_log.bump,
+    )]
+    pub batch_log: Box<Account<'info, BatchLog>>,
+
+    /// Pool event sequence (stamps `seq` into emitted events)
+    #[account(
+        mut,
+        seeds = [EVENT_CURSOR_SEED, &[pool.pool_id]],
+        bump = event_cursor.bump,
+    )]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+}
+
+// =============================================================================
+// EXECUTE SWAPS ACCOUNTS (Phase 9.5)
+// =============================================================================
+
+#[derive(Accounts)]
+#[instruction(batch_id: u64)]
+pub struct ExecuteSwaps<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Operator authorized to trigger swaps; any bonded executor instead
+    /// when execution is registry-gated
+    #[account(
+        constraint = pool.executor_gated || roles.is_operator(&pool, &operator.key()) @ ErrorCode::Unauthorized,
+    )]
+    pub operator: Signer<'info>,
+
+    /// Pool account for operator verification and PDA authority
+    /// (accrues the execution fees)
+    #[account(
+        mut,
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// Role grants (admins and operators besides the pool's own)
+    #[account(
+        seeds = [ROLES_SEED, &[pool.pool_id]],
+        bump = roles.bump,
+    )]
+    pub roles: Box<Account<'info, Roles>>,
+
+    /// BatchLog containing netting results (must be for matching batch_id)
+    #[account(
+        mut,
+        seeds = [BATCH_LOG_SEED, &[pool.pool_id], &batch_id.to_le_bytes()],
+        bump,
+    )]
+    pub batch_log: Account<'info, BatchLog>,
+
+    /// Bonded executors (required when Pool.executor_gated is set)
+    #[account(
+        seeds = [EXECUTOR_REGISTRY_SEED, &[pool.pool_id]],
+        bump = executor_registry.bump,
+    )]
+    pub executor_registry: Option<Box<Account<'info, ExecutorRegistry>>>,
+
+    // =========================================================================
+    // VAULT ACCOUNTS (user deposits)
+    // =========================================================================
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, &[pool.pool_id], VAULT_USDC_SEED],
+        bump,
+    )]
+    pub vault_usdc: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, &[pool.pool_id], VAULT_TSLA_SEED],
+        bump,
+    )]
+    pub vault_tsla: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, &[pool.pool_id], VAULT_SPY_SEED],
+        bump,
+    )]
+    pub vault_spy: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, &[pool.pool_id], VAULT_AAPL_SEED],
+        bump,
+    )]
+    pub vault_aapl: Box<Account<'info, TokenAccount>>,
+
+    // =========================================================================
+    // RESERVE ACCOUNTS (protocol liquidity)
+    // =========================================================================
+    #[account(
+        mut,
+        seeds = [RESERVE_SEED, &[pool.pool_id], RESERVE_USDC_SEED],
+        bump,
+    )]
+    pub reserve_usdc: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [RESERVE_SEED, &[pool.pool_id], RESERVE_TSLA_SEED],
+        bump,
+    )]
+    pub reserve_tsla: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [RESERVE_SEED, &[pool.pool_id], RESERVE_SPY_SEED],
+        bump,
+    )]
+    pub reserve_spy: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [RESERVE_SEED, &[pool.pool_id], RESERVE_AAPL_SEED],
+        bump,
+    )]
+    pub reserve_aapl: Box<Account<'info, TokenAccount>>,
+
+    /// Optional diagnostics account (records CU usage when profiling is enabled)
+    #[account(
+        mut,
+        seeds = [DIAGNOSTICS_SEED, &[pool.pool_id]],
+        bump = diagnostics.bump,
+    )]
+    pub diagnostics: Option<Account<'info, Diagnostics>>,
+
+    /// Cumulative analytics (reserve flows, swap latency)
+    #[account(
+        mut,
+        seeds = [PROTOCOL_STATS_SEED, &[pool.pool_id]],
+        bump = protocol_stats.bump,
+    )]
+    pub protocol_stats: Box<Account<'info, ProtocolStats>>,
+
+    /// Pool event sequence (stamps `seq` into emitted events)
+    #[account(
+        mut,
+        seeds = [EVENT_CURSOR_SEED, &[pool.pool_id]],
+        bump = event_cursor.bump,
+    )]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+
+    /// execute_batch caller's USDC account, receives the keeper reward.
+    /// Without it the reward is skipped and stays in Pool.fees_owed.
+    #[account(
+        mut,
+        constraint = keeper_usdc.owner == batch_log.executor @ ErrorCode::InvalidOwner,
+        constraint = keeper_usdc.mint == pool.usdc_mint @ ErrorCode::InvalidMint,
+    )]
+    pub keeper_usdc: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// Token program for transfers
+    pub token_program: Program<'info, Token>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// =============================================================================
+// SETTLE ORDER ACCOUNTS (Phase 10)
+// =============================================================================
+
+#[queue_computation_accounts("calculate_payout", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, pubkey: [u8; 32], nonce: u128, pair_id: u8, direction: u8)]
+pub struct SettleOrder<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Owner settling the order, or a session key holding a Delegate
+    pub user: Signer<'info>,
+
+    /// User's privacy account
+    #[account(
+        mut,
+        seeds = [USER_SEED, &[pool.pool_id], user_account.owner.as_ref()],
+        bump = user_account.bump,
+        constraint = user_account.pending_order.is_some() @ ErrorCode::NoPendingOrder,
+    )]
+    pub user_account: Box<Account<'info, UserProfile>>,
+
+    /// Session key authorization (required when `user` is not the owner)
+    #[account(
+        seeds = [DELEGATE_SEED, &[pool.pool_id], user_account.owner.as_ref(), user.key().as_ref()],
+        bump = delegate.bump,
+    )]
+    pub delegate: Option<Box<Account<'info, Delegate>>>,
+
+    #[account(
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// BatchLog for the batch being settled
+    #[account(
+        seeds = [
+            BATCH_LOG_SEED,
+            &[pool.pool_id],
+            &user_account.pending_order.unwrap().batch_id.to_le_bytes(),
+        ],
+        bump,
+    )]
+    pub batch_log: Account<'info, BatchLog>,
+
+    /// Owner's settlement currency preference, if set (omit to keep the payout)
+    #[account(
+        seeds = [SETTLEMENT_PREFERENCE_SEED, &[pool.pool_id], user_account.owner.as_ref()],
+        bump = settlement_preference.bump,
+    )]
+    pub settlement_preference: Option<Box<Account<'info, SettlementPreference>>>,
+
+    /// User's order history (created on first settlement, appended in callback)
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = OrderHistory::SIZE,
+        seeds = [ORDER_HISTORY_SEED, &[pool.pool_id], user_account.owner.as_ref()],
+        bump,
+    )]
+    pub order_history: Box<Account<'info, OrderHistory>>,
+
+    /// Optional diagnostics account (records CU usage when profiling is enabled)
+    #[account(
+        mut,
+        seeds = [DIAGNOSTICS_SEED, &[pool.pool_id]],
+        bump = diagnostics.bump,
+    )]
+    pub diagnostics: Option<Account<'info, Diagnostics>>,
+
+    // =========================================================================
+    // ARCIUM MPC ACCOUNTS
+    // =========================================================================
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Box<Account<'info, ArciumSignerAccount>>,
+
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+
+    #[account(
+        mut,
+        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_CALCULATE_PAYOUT))]
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    pub cluster_account: Box<Account<'info, Cluster>>,
+
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Box<Account<'info, FeePool>>,
+
+    #[account(
+        mut,
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+    )]
+    pub clock_account: Box<Account<'info, ClockAccount>>,
+
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+}
+
+// =============================================================================
+// FORCE SETTLE ACCOUNTS
+// =============================================================================
+// Permissionless settlement of a stale order; the cranker is paid a bounty
+// from the output asset vault in calculate_payout_callback.
+
+#[queue_computation_accounts("calculate_payout", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct ForceSettle<'info> {
+    /// Cranker (pays MPC fees, receives the bounty)
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// Privacy account of the user whose order is settled
+    #[account(
+        mut,
+        seeds = [USER_SEED, &[pool.pool_id], user_account.owner.as_ref()],
+        bump = user_account.bump,
+        constraint = user_account.pending_order.is_some() @ ErrorCode::NoPendingOrder,
+    )]
+    pub user_account: Box<Account<'info, UserProfile>>,
+
+    /// BatchLog for the batch being settled
+    #[account(
+        seeds = [
+            BATCH_LOG_SEED,
+            &[pool.pool_id],
+            &user_account.pending_order.unwrap().batch_id.to_le_bytes(),
+        ],
+        bump,
+    )]
+    pub batch_log: Account<'info, BatchLog>,
+
+    /// User's order history (created on first settlement, appended in callback)
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = OrderHistory::SIZE,
+        seeds = [ORDER_HISTORY_SEED, &[pool.pool_id], user_account.owner.as_ref()],
+        bump,
+    )]
+    pub order_history: Box<Account<'info, OrderHistory>>,
+
+    /// Protocol deposit vault of the order's output asset (bounty source)
+    #[account(mut)]
+    pub payout_vault: Box<Account<'info, TokenAccount>>,
+
+    /// Cranker's token account for the output asset (bounty destination).
+    /// For forfeit_order this must be the treasury's token account.
+    #[account(
+        mut,
+        constraint = bounty_token_account.mint == payout_vault.mint @ ErrorCode::InvalidMint,
+    )]
+    pub bounty_token_account: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+
+    // =========================================================================
+    // ARCIUM MPC ACCOUNTS
+    // =========================================================================
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Box<Account<'info, ArciumSignerAccount>>,
+
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+
+    #[account(
+        mut,
+        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_CALCULATE_PAYOUT))]
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    pub cluster_account: Box<Account<'info, Cluster>>,
+
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Box<Account<'info, FeePool>>,
+
+    #[account(
+        mut,
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+    )]
+    pub clock_account: Box<Account<'info, ClockAccount>>,
+
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+}
+
+// =============================================================================
+// CALCULATE PAYOUT CALLBACK ACCOUNTS (Phase 10)
+// =============================================================================
+
+#[callback_accounts("calculate_payout")]
+#[derive(Accounts)]
+pub struct CalculatePayoutCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_CALCULATE_PAYOUT))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Account<'info, MXEAccount>,
+
+    /// CHECK: computation_account, checked by arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+
+    #[account(address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    pub cluster_account: Account<'info, Cluster>,
+
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar
+    pub instructions_sysvar: AccountInfo<'info>,
+
+    // Application accounts (passed via CallbackAccount)
+    #[account(mut)]
+    pub user_account: Box<Account<'info, UserProfile>>,
+
+    #[account(mut)]
+    pub order_history: Box<Account<'info, OrderHistory>>,
+
+    /// BatchLog of the settled order (unsettled_count is decremented)
+    #[account(mut)]
+    pub batch_log: Box<Account<'info, BatchLog>>,
+
+    #[account(mut)]
+    pub diagnostics: Option<Account<'info, Diagnostics>>,
+
+    // Crank bounty accounts (only passed by force_settle / forfeit_order)
+    #[account(mut)]
+    pub pool: Option<Box<Account<'info, Pool>>>,
+
+    #[account(mut)]
+    pub payout_vault: Option<Box<Account<'info, TokenAccount>>>,
+
+    #[account(mut)]
+    pub bounty_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+
+    /// Basket being settled (only passed by settle_basket_leg)
+    #[account(mut)]
+    pub basket_order: Option<Box<Account<'info, BasketOrder>>>,
+
+    /// Pool event sequence (stamps `seq` into emitted events)
+    #[account(
+        mut,
+        seeds = [EVENT_CURSOR_SEED, &[pool.pool_id]],
+        bump = event_cursor.bump,
+    )]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+}
+
+// =============================================================================
+// SETTLEMENT DEADLINE ACCOUNTS
+// =============================================================================
+
+#[derive(Accounts)]
+pub struct SetSettlementDeadline<'info> {
+    /// Pool authority
+    #[account(constraint = roles.is_admin(&pool, &authority.key()) @ ErrorCode::Unauthorized)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// Role grants (admins and operators besides the pool's own)
+    #[account(
+        seeds = [ROLES_SEED, &[pool.pool_id]],
+        bump = roles.bump,
+    )]
+    pub roles: Box<Account<'info, Roles>>,
+
+    /// Pool event sequence (stamps `seq` into emitted events)
+    #[account(
+        mut,
+        seeds = [EVENT_CURSOR_SEED, &[pool.pool_id]],
+        bump = event_cursor.bump,
+    )]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+}
+
+#[derive(Accounts)]
+#[instruction(batch_id: u64)]
+pub struct CloseBatchLog<'info> {
+    /// Operator or pool authority
+    #[account(
+        constraint = roles.is_operator(&pool, &caller.key()) || roles.is_admin(&pool, &caller.key()) @ ErrorCode::Unauthorized,
+    )]
+    pub caller: Signer<'info>,
+
+    #[account(
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// Role grants (admins and operators besides the pool's own)
+    #[account(
+        seeds = [ROLES_SEED, &[pool.pool_id]],
+        bump = roles.bump,
+    )]
+    pub roles: Box<Account<'info, Roles>>,
+
+    /// Treasury wallet - receives the reclaimed rent
+    /// CHECK: Address checked against Pool.treasury
+    #[account(mut, address = pool.treasury @ ErrorCode::Unauthorized)]
+    pub treasury: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = treasury,
+        seeds = [BATCH_LOG_SEED, &[pool.pool_id], &batch_id.to_le_bytes()],
+        bump = batch_log.bump,
+        constraint = batch_log.executed_at > 0 @ ErrorCode::BatchNotFinalized,
+        constraint = batch_log.unsettled_count == 0 && batch_log.swaps_executed @ ErrorCode::BatchLogInUse,
+    )]
+    pub batch_log: Box<Account<'info, BatchLog>>,
+}
+
+#[derive(Accounts)]
+pub struct SweepFees<'info> {
+    /// Anyone may sweep fees; they can only go to the treasury
+    pub caller: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    #[account(mut, seeds = [VAULT_SEED, &[pool.pool_id], VAULT_USDC_SEED], bump)]
+    pub vault_usdc: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut, seeds = [VAULT_SEED, &[pool.pool_id], VAULT_TSLA_SEED], bump)]
+    pub vault_tsla: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut, seeds = [VAULT_SEED, &[pool.pool_id], VAULT_SPY_SEED], bump)]
+    pub vault_spy: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut, seeds = [VAULT_SEED, &[pool.pool_id], VAULT_AAPL_SEED], bump)]
+    pub vault_aapl: Box<Account<'info, TokenAccount>>,
+
+    /// Treasury's USDC token account
+    #[account(
+        mut,
+        constraint = treasury_usdc.owner == pool.treasury @ ErrorCode::Unauthorized,
+        constraint = treasury_usdc.mint == pool.usdc_mint @ ErrorCode::InvalidMint,
+    )]
+    pub treasury_usdc: Box<Account<'info, TokenAccount>>,
+
+    /// Treasury's TSLA token account
+    #[account(
+        mut,
+        constraint = treasury_tsla.owner == pool.treasury @ ErrorCode::Unauthorized,
+        constraint = treasury_tsla.mint == pool.tsla_mint @ ErrorCode::InvalidMint,
+    )]
+    pub treasury_tsla: Box<Account<'info, TokenAccount>>,
+
+    /// Treasury's SPY token account
+    #[account(
+        mut,
+        constraint = treasury_spy.owner == pool.treasury @ ErrorCode::Unauthorized,
+        constraint = treasury_spy.mint == pool.spy_mint @ ErrorCode::InvalidMint,
+    )]
+    pub treasury_spy: Box<Account<'info, TokenAccount>>,
+
+    /// Treasury's AAPL token account
+    #[account(
+        mut,
+        constraint = treasury_aapl.owner == pool.treasury @ ErrorCode::Unauthorized,
+        constraint = treasury_aapl.mint == pool.aapl_mint @ ErrorCode::InvalidMint,
+    )]
+    pub treasury_aapl: Box<Account<'info, TokenAccount>>,
+
+    /// Pool event sequence (stamps `seq` into emitted events)
+    #[account(
+        mut,
+        seeds = [EVENT_CURSOR_SEED, &[pool.pool_id]],
+        bump = event_cursor.bump,
+    )]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+// =============================================================================
+// CLOSE ORDER HISTORY ACCOUNTS
+// =============================================================================
+
+#[derive(Accounts)]
+pub struct CloseOrderHistory<'info> {
+    /// Owner of the history - receives the reclaimed rent
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// Pool the history belongs to
+    #[account(
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// Order history PDA to close
+    #[account(
+        mut,
+        close = owner,
+        seeds = [ORDER_HISTORY_SEED, &[pool.pool_id], owner.key().as_ref()],
+        bump = order_history.bump,
+        constraint = order_history.owner == owner.key() @ ErrorCode::Unauthorized,
+    )]
+    pub order_history: Box<Account<'info, OrderHistory>>,
+}
+
+// =============================================================================
+// LIQUIDITY MANAGEMENT ACCOUNTS (Protocol Reserves)
+// =============================================================================
+
+#[derive(Accounts)]
+#[instruction(asset_id: u8)]
+pub struct AddLiquidity<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Account<'info, Pool>,
+
+    /// Mint of the asset (SPL Token or Token-2022; transfer_checked needs decimals)
+    #[account(mint::token_program = token_program)]
+    pub mint: Box<InterfaceAccount<'info, anchor_spl::token_interface::Mint>>,
+
+    /// Authority's token account (source of funds)
+    #[account(mut, constraint = authority_token_account.mint == mint.key() @ ErrorCode::InvalidMint)]
+    pub authority_token_account:
+        Box<InterfaceAccount<'info, anchor_spl::token_interface::TokenAccount>>,
+
+    /// Reserve vault for the specified asset (destination)
+    #[account(
+        mut,
+        constraint = reserve_vault.mint == mint.key() @ ErrorCode::InvalidMint,
+        constraint = reserve_vault.owner == pool.key() @ ErrorCode::InvalidOwner,
+    )]
+    pub reserve_vault: Box<InterfaceAccount<'info, anchor_spl::token_interface::TokenAccount>>,
+
+    pub token_program: Interface<'info, anchor_spl::token_interface::TokenInterface>,
+}
+
+#[derive(Accounts)]
+#[instruction(asset_id: u8)]
+pub struct RemoveLiquidity<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Account<'info, Pool>,
+
+    /// Mint of the asset (SPL Token or Token-2022; transfer_checked needs decimals)
+    #[account(mint::token_program = token_program)]
+    pub mint: Box<InterfaceAccount<'info, anchor_spl::token_interface::Mint>>,
+
+    /// Authority's token account (destination)
+    #[account(mut, constraint = authority_token_account.mint == mint.key() @ ErrorCode::InvalidMint)]
+    pub authority_token_account:
+        Box<InterfaceAccount<'info, anchor_spl::token_interface::TokenAccount>>,
+
+    /// Reserve vault for the specified asset (source)
+    #[account(
+        mut,
+        constraint = reserve_vault.mint == mint.key() @ ErrorCode::InvalidMint,
+        constraint = reserve_vault.owner == pool.key() @ ErrorCode::InvalidOwner,
+    )]
+    pub reserve_vault: Box<InterfaceAccount<'info, anchor_spl::token_interface::TokenAccount>>,
+
+    pub token_program: Interface<'info, anchor_spl::token_interface::TokenInterface>,
+}
+
+// =============================================================================
+// INIT ACCUMULATE_ORDER COMPUTATION DEFINITION (Phase 8)
+// =============================================================================
+
+#[init_computation_definition_accounts("accumulate_order", payer)]
+#[derive(Accounts)]
+pub struct InitAccumulateOrderCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program.
+    pub comp_def_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_mxe_lut_pda!(mxe_account.lut_offset_slot))]
+    /// CHECK: address_lookup_table, checked by arcium program.
+    pub address_lookup_table: UncheckedAccount<'info>,
+    #[account(address = LUT_PROGRAM_ID)]
+    /// CHECK: lut_program is the Address Lookup Table program.
+    pub lut_program: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+// =============================================================================
+// INIT INIT_BATCH_STATE COMPUTATION DEFINITION (Phase 8)
+// =============================================================================
+
+#[init_computation_definition_accounts("init_batch_state", payer)]
+#[derive(Accounts)]
+pub struct InitInitBatchStateCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program.
+    pub comp_def_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_mxe_lut_pda!(mxe_account.lut_offset_slot))]
+    /// CHECK: address_lookup_table, checked by arcium program.
+    pub address_lookup_table: UncheckedAccount<'info>,
+    #[account(address = LUT_PROGRAM_ID)]
+    /// CHECK: lut_program is the Address Lookup Table program.
+    pub lut_program: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+// =============================================================================
+// INIT_BATCH_STATE QUEUE ACCOUNTS
+// =============================================================================
+
+#[queue_computation_accounts("init_batch_state", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct InitBatchState<'info> {
+    /// Pool authority
+    #[account(
+        mut,
+        constraint = payer.key() == pool.authority @ ErrorCode::Unauthorized,
+    )]
+    pub payer: Signer<'info>,
+
+    /// Pool the account belongs to
+    #[account(
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// Batch accumulator to initialize
+    #[account(
+        mut,
+        seeds = [BATCH_ACCUMULATOR_SEED, &[pool.pool_id], &[batch_accumulator.slot]],
+        bump = batch_accumulator.bump,
+    )]
+    pub batch_accumulator: Box<Account<'info, BatchAccumulator>>,
+
+    // =========================================================================
+    // ARCIUM MPC ACCOUNTS
+    // =========================================================================
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Box<Account<'info, ArciumSignerAccount>>,
+
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+
+    #[account(
+        mut,
+        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_INIT_BATCH_STATE))]
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    pub cluster_account: Box<Account<'info, Cluster>>,
+
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Box<Account<'info, FeePool>>,
+
+    #[account(
+        mut,
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+    )]
+    pub clock_account: Box<Account<'info, ClockAccount>>,
+
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+// =============================================================================
+// INIT_BATCH_STATE CALLBACK ACCOUNTS
+// =============================================================================
+
+#[callback_accounts("init_batch_state")]
+#[derive(Accounts)]
+pub struct InitBatchStateCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_INIT_BATCH_STATE)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Account<'info, MXEAccount>,
+    /// CHECK: computation_account, checked by arcium program via constraints in the callback context.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+
+    /// Pool the account belongs to
+    #[account(
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// Batch accumulator to update with encrypted zeros
+    #[account(
+        mut,
+        seeds = [BATCH_ACCUMULATOR_SEED, &[pool.pool_id], &[batch_accumulator.slot]],
+        bump = batch_accumulator.bump,
+    )]
+    pub batch_accumulator: Box<Account<'info, BatchAccumulator>>,
+}
+
+// =============================================================================
+// INIT REVEAL_BATCH COMPUTATION DEFINITION (Phase 9)
+// =============================================================================
+
+#[init_computation_definition_accounts("reveal_batch", payer)]
+#[derive(Accounts)]
+pub struct InitRevealBatchCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program.
+    pub comp_def_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_mxe_lut_pda!(mxe_account.lut_offset_slot))]
+    /// CHECK: address_lookup_table, checked by arcium program.
+    pub address_lookup_table: UncheckedAccount<'info>,
+    #[account(address = LUT_PROGRAM_ID)]
+    /// CHECK: lut_program is the Address Lookup Table program.
+    pub lut_program: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+// =============================================================================
+// INIT CALCULATE_PAYOUT COMPUTATION DEFINITION (Phase 10)
+// =============================================================================
+
+#[init_computation_definition_accounts("calculate_payout", payer)]
+#[derive(Accounts)]
+pub struct InitCalculatePayoutCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program.
+    pub comp_def_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_mxe_lut_pda!(mxe_account.lut_offset_slot))]
+    /// CHECK: address_lookup_table, checked by arcium program.
+    pub address_lookup_table: UncheckedAccount<'info>,
+    #[account(address = LUT_PROGRAM_ID)]
+    /// CHECK: lut_program is the Address Lookup Table program.
+    pub lut_program: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+// =============================================================================
+// EMERGENCY ASSET FREEZE ACCOUNTS
+// =============================================================================
+
+#[derive(Accounts)]
+pub struct FreezeAsset<'info> {
+    /// Pool authority
+    #[account(constraint = roles.is_risk_manager(&pool, &authority.key()) @ ErrorCode::Unauthorized)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// Role grants (admins and operators besides the pool's own)
+    #[account(
+        seeds = [ROLES_SEED, &[pool.pool_id]],
+        bump = roles.bump,
+    )]
+    pub roles: Box<Account<'info, Roles>>,
+
+    /// Pool event sequence (stamps `seq` into emitted events)
+    #[account(
+        mut,
+        seeds = [EVENT_CURSOR_SEED, &[pool.pool_id]],
+        bump = event_cursor.bump,
+    )]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+}
+
+#[derive(Accounts)]
+pub struct RequestUnfreezeAsset<'info> {
+    /// Pool authority
+    #[account(constraint = roles.is_admin(&pool, &authority.key()) @ ErrorCode::Unauthorized)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// Role grants (admins and operators besides the pool's own)
+    #[account(
+        seeds = [ROLES_SEED, &[pool.pool_id]],
+        bump = roles.bump,
+    )]
+    pub roles: Box<Account<'info, Roles>>,
+
+    /// Pool event sequence (stamps `seq` into emitted events)
+    #[account(
+        mut,
+        seeds = [EVENT_CURSOR_SEED, &[pool.pool_id]],
+        bump = event_cursor.bump,
+    )]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+}
+
+#[derive(Accounts)]
+pub struct UnfreezeAsset<'info> {
+    /// Pool authority
+    #[account(constraint = roles.is_admin(&pool, &authority.key()) @ ErrorCode::Unauthorized)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// Role grants (admins and operators besides the pool's own)
+    #[account(
+        seeds = [ROLES_SEED, &[pool.pool_id]],
+        bump = roles.bump,
+    )]
+    pub roles: Box<Account<'info, Roles>>,
+
+    /// Pool event sequence (stamps `seq` into emitted events)
+    #[account(
+        mut,
+        seeds = [EVENT_CURSOR_SEED, &[pool.pool_id]],
+        bump = event_cursor.bump,
+    )]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+}
+
+#[derive(Accounts)]
+pub struct SetAssetDelisted<'info> {
+    /// Pool authority
+    #[account(constraint = roles.is_admin(&pool, &authority.key()) @ ErrorCode::Unauthorized)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// Role grants (admins and operators besides the pool's own)
+    #[account(
+        seeds = [ROLES_SEED, &[pool.pool_id]],
+        bump = roles.bump,
+    )]
+    pub roles: Box<Account<'info, Roles>>,
+
+    /// Pool event sequence (stamps `seq` into emitted events)
+    #[account(
+        mut,
+        seeds = [EVENT_CURSOR_SEED, &[pool.pool_id]],
+        bump = event_cursor.bump,
+    )]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+}
+
+// =============================================================================
+// ASSET TRANSFER POLICY ACCOUNTS
+// =============================================================================
+
+#[derive(Accounts)]
+pub struct ConfigureAssetPolicy<'info> {
+    /// Pool authority (pays for the policy account)
+    #[account(
+        mut,
+        constraint = roles.is_admin(&pool, &authority.key()) @ ErrorCode::Unauthorized,
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// Role grants (admins and operators besides the pool's own)
+    #[account(
+        seeds = [ROLES_SEED, &[pool.pool_id]],
+        bump = roles.bump,
+    )]
+    pub roles: Box<Account<'info, Roles>>,
+
+    /// Asset mint (SPL Token or Token-2022)
+    pub mint: Box<InterfaceAccount<'info, anchor_spl::token_interface::Mint>>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = AssetTransferPolicy::SIZE,
+        seeds = [ASSET_POLICY_SEED, &[pool.pool_id], mint.key().as_ref()],
+        bump,
+    )]
+    pub asset_policy: Box<Account<'info, AssetTransferPolicy>>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Pool event sequence (stamps `seq` into emitted events)
+    #[account(
+        mut,
+        seeds = [EVENT_CURSOR_SEED, &[pool.pool_id]],
+        bump = event_cursor.bump,
+    )]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+}
+
+// =============================================================================
+// ASSET REGISTRY ACCOUNTS
+// =============================================================================
+
+#[derive(Accounts)]
+pub struct RegisterAsset<'info> {
+    /// Pool authority (pays for the registry and the vault)
+    #[account(
+        mut,
+        constraint = roles.is_admin(&pool, &authority.key()) @ ErrorCode::Unauthorized,
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// Role grants (admins and operators besides the pool's own)
+    #[account(
+        seeds = [ROLES_SEED, &[pool.pool_id]],
+        bump = roles.bump,
+    )]
+    pub roles: Box<Account<'info, Roles>>,
+
+    /// Asset registry (created on first call)
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = AssetRegistry::SIZE,
+        seeds = [ASSET_REGISTRY_SEED, &[pool.pool_id]],
+        bump,
+    )]
+    pub asset_registry: Box<Account<'info, AssetRegistry>>,
+
+    /// Mint of the asset to list (SPL Token or Token-2022)
+    #[account(mint::token_program = token_program)]
+    pub mint: Box<InterfaceAccount<'info, anchor_spl::token_interface::Mint>>,
+
+    /// Pool-owned deposit vault for the asset, under the mint's token program
+    #[account(
+        init,
+        payer = authority,
+        seeds = [VAULT_SEED, &[pool.pool_id], mint.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = pool,
+        token::token_program = token_program,
+    )]
+    pub asset_vault: Box<InterfaceAccount<'info, anchor_spl::token_interface::TokenAccount>>,
+
+    pub token_program: Interface<'info, anchor_spl::token_interface::TokenInterface>,
+    pub system_program: Program<'info, System>,
+
+    /// Pool event sequence (stamps `seq` into emitted events)
+    #[account(
+        mut,
+        seeds = [EVENT_CURSOR_SEED, &[pool.pool_id]],
+        bump = event_cursor.bump,
+    )]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+}
+
+// =============================================================================
+// AUDIT VAULTS ACCOUNTS
+// =============================================================================
+// Read-only view over every token account the Pool PDA controls.
+
+#[derive(Accounts)]
+pub struct AuditVaults<'info> {
+    /// Anyone may run the audit
+    pub auditor: Signer<'info>,
+
+    #[account(
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    #[account(seeds = [VAULT_SEED, &[pool.pool_id], VAULT_USDC_SEED], bump)]
+    pub vault_usdc: Box<Account<'info, TokenAccount>>,
+
+    #[account(seeds = [VAULT_SEED, &[pool.pool_id], VAULT_TSLA_SEED], bump)]
+    pub vault_tsla: Box<Account<'info, TokenAccount>>,
+
+    #[account(seeds = [VAULT_SEED, &[pool.pool_id], VAULT_SPY_SEED], bump)]
+    pub vault_spy: Box<Account<'info, TokenAccount>>,
+
+    #[account(seeds = [VAULT_SEED, &[pool.pool_id], VAULT_AAPL_SEED], bump)]
+    pub vault_aapl: Box<Account<'info, TokenAccount>>,
+
+    #[account(seeds = [RESERVE_SEED, &[pool.pool_id], RESERVE_USDC_SEED], bump)]
+    pub reserve_usdc: Box<Account<'info, TokenAccount>>,
+
+    #[account(seeds = [RESERVE_SEED, &[pool.pool_id], RESERVE_TSLA_SEED], bump)]
+    pub reserve_tsla: Box<Account<'info, TokenAccount>>,
+
+    #[account(seeds = [RESERVE_SEED, &[pool.pool_id], RESERVE_SPY_SEED], bump)]
+    pub reserve_spy: Box<Account<'info, TokenAccount>>,
+
+    #[account(seeds = [RESERVE_SEED, &[pool.pool_id], RESERVE_AAPL_SEED], bump)]
+    pub reserve_aapl: Box<Account<'info, TokenAccount>>,
+
+    #[account(seeds = [FAUCET_VAULT_SEED, &[pool.pool_id]], bump)]
+    pub faucet_vault: Box<Account<'info, TokenAccount>>,
+
+    /// Pool event sequence (stamps `seq` into emitted events)
+    #[account(
+        mut,
+        seeds = [EVENT_CURSOR_SEED, &[pool.pool_id]],
+        bump = event_cursor.bump,
+    )]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+}
+
+// =============================================================================
+// CHECK INVARIANTS ACCOUNTS
+// =============================================================================
+// Both accumulator slots, the BatchLog PDA of each slot's batch (which may
+// not exist), and the vaults/reserves. Accumulator seeds are verified by the
+// handler so a mismatch is reported rather than rejected.
+
+#[derive(Accounts)]
+pub struct CheckInvariants<'info> {
+    /// Anyone may run the check (pays for the monitor on first run)
+    #[account(mut)]
+    pub checker: Signer<'info>,
+
+    #[account(
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// Checkpoint of the previous run (monotonicity checks)
+    #[account(
+        init_if_needed,
+        payer = checker,
+        space = InvariantMonitor::SIZE,
+        seeds = [INVARIANT_MONITOR_SEED, &[pool.pool_id]],
+        bump,
+    )]
+    pub monitor: Box<Account<'info, InvariantMonitor>>,
+
+    #[account(constraint = accumulator_0.key() != accumulator_1.key() @ ErrorCode::InvalidBatchSlot)]
+    pub accumulator_0: Box<Account<'info, BatchAccumulator>>,
+
+    pub accumulator_1: Box<Account<'info, BatchAccumulator>>,
+
+    /// CHECK: BatchLog PDA for accumulator_0's batch; may not exist
+    #[account(seeds = [BATCH_LOG_SEED, &[pool.pool_id], &accumulator_0.batch_id.to_le_bytes()], bump)]
+    pub batch_log_0: UncheckedAccount<'info>,
+
+    /// CHECK: BatchLog PDA for accumulator_1's batch; may not exist
+    #[account(seeds = [BATCH_LOG_SEED, &[pool.pool_id], &accumulator_1.batch_id.to_le_bytes()], bump)]
+    pub batch_log_1: UncheckedAccount<'info>,
+
+    #[account(seeds = [VAULT_SEED, &[pool.pool_id], VAULT_USDC_SEED], bump)]
+    pub vault_usdc: Box<Account<'info, TokenAccount>>,
+
+    #[account(seeds = [VAULT_SEED, &[pool.pool_id], VAULT_TSLA_SEED], bump)]
+    pub vault_tsla: Box<Account<'info, TokenAccount>>,
+
+    #[account(seeds = [VAULT_SEED, &[pool.pool_id], VAULT_SPY_SEED], bump)]
+    pub vault_spy: Box<Account<'info, TokenAccount>>,
+
+    #[account(seeds = [VAULT_SEED, &[pool.pool_id], VAULT_AAPL_SEED], bump)]
+    pub vault_aapl: Box<Account<'info, TokenAccount>>,
+
+    #[account(seeds = [RESERVE_SEED, &[pool.pool_id], RESERVE_USDC_SEED], bump)]
+    pub reserve_usdc: Box<Account<'info, TokenAccount>>,
 
-        // Update sender's encrypted balance and USDC nonce
-        ctx.accounts.sender_account.usdc_credit = o.field_0.field_0.ciphertexts[0];
-        ctx.accounts.sender_account.usdc_nonce = o.field_0.field_0.nonce;
+    #[account(seeds = [RESERVE_SEED, &[pool.pool_id], RESERVE_TSLA_SEED], bump)]
+    pub reserve_tsla: Box<Account<'info, TokenAccount>>,
 
-        // Update recipient's encrypted balance and USDC nonce
-        ctx.accounts.recipient_account.usdc_credit = o.field_0.field_1.ciphertexts[0];
-        ctx.accounts.recipient_account.usdc_nonce = o.field_0.field_1.nonce;
+    #[account(seeds = [RESERVE_SEED, &[pool.pool_id], RESERVE_SPY_SEED], bump)]
+    pub reserve_spy: Box<Account<'info, TokenAccount>>,
 
-        emit!(TransferEvent {
-            from: ctx.accounts.sender_account.owner,
-            to: ctx.accounts.recipient_account.owner,
-            amount: 0, // Amount not revealed in callback
-            sender_nonce: o.field_0.field_0.nonce.to_le_bytes(),
-        });
+    #[account(seeds = [RESERVE_SEED, &[pool.pool_id], RESERVE_AAPL_SEED], bump)]
+    pub reserve_aapl: Box<Account<'info, TokenAccount>>,
 
-        msg!(
-            "Transfer callback: {} -> {} balances updated",
-            ctx.accounts.sender_account.owner,
-            ctx.accounts.recipient_account.owner
-        );
-        Ok(())
-    }
+    pub system_program: Program<'info, System>,
+
+    /// Pool event sequence (stamps `seq` into emitted events)
+    #[account(
+        mut,
+        seeds = [EVENT_CURSOR_SEED, &[pool.pool_id]],
+        bump = event_cursor.bump,
+    )]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
 }
 
-#[queue_computation_accounts("add_together", payer)]
+// =============================================================================
+// RESERVE AUCTION ACCOUNTS
+// =============================================================================
+// Sealed-bid auction of excess reserve inventory (3 MPC circuits + claim).
+
+#[init_computation_definition_accounts("init_auction_state", payer)]
 #[derive(Accounts)]
-#[instruction(computation_offset: u64)]
-pub struct AddTogether<'info> {
+pub struct InitInitAuctionStateCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program.
+    pub comp_def_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_mxe_lut_pda!(mxe_account.lut_offset_slot))]
+    /// CHECK: address_lookup_table, checked by arcium program.
+    pub address_lookup_table: UncheckedAccount<'info>,
+    #[account(address = LUT_PROGRAM_ID)]
+    /// CHECK: lut_program is the Address Lookup Table program.
+    pub lut_program: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[init_computation_definition_accounts("submit_auction_bid", payer)]
+#[derive(Accounts)]
+pub struct InitSubmitAuctionBidCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program.
+    pub comp_def_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_mxe_lut_pda!(mxe_account.lut_offset_slot))]
+    /// CHECK: address_lookup_table, checked by arcium program.
+    pub address_lookup_table: UncheckedAccount<'info>,
+    #[account(address = LUT_PROGRAM_ID)]
+    /// CHECK: lut_program is the Address Lookup Table program.
+    pub lut_program: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[init_computation_definition_accounts("reveal_auction", payer)]
+#[derive(Accounts)]
+pub struct InitRevealAuctionCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
     #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program.
+    pub comp_def_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_mxe_lut_pda!(mxe_account.lut_offset_slot))]
+    /// CHECK: address_lookup_table, checked by arcium program.
+    pub address_lookup_table: UncheckedAccount<'info>,
+    #[account(address = LUT_PROGRAM_ID)]
+    /// CHECK: lut_program is the Address Lookup Table program.
+    pub lut_program: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[queue_computation_accounts("init_auction_state", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, auction_id: u64)]
+pub struct OpenReserveAuction<'info> {
+    /// Pool authority - lists reserve inventory and pays for the auction account
+    #[account(
+        mut,
+        constraint = roles.is_admin(&pool, &payer.key()) @ ErrorCode::Unauthorized,
+    )]
     pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// Role grants (admins and operators besides the pool's own)
+    #[account(
+        seeds = [ROLES_SEED, &[pool.pool_id]],
+        bump = roles.bump,
+    )]
+    pub roles: Box<Account<'info, Roles>>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = ReserveAuction::SIZE,
+        seeds = [RESERVE_AUCTION_SEED, &[pool.pool_id], &auction_id.to_le_bytes()],
+        bump,
+    )]
+    pub reserve_auction: Box<Account<'info, ReserveAuction>>,
+
+    // =========================================================================
+    // ARCIUM MPC ACCOUNTS
+    // =========================================================================
     #[account(
         init_if_needed,
         space = 9,
@@ -1484,178 +16187,225 @@ pub struct AddTogether<'info> {
         address = derive_sign_pda!(),
     )]
     pub sign_pda_account: Box<Account<'info, ArciumSignerAccount>>,
-    #[account(
-        address = derive_mxe_pda!()
-    )]
-    pub mxe_account: Account<'info, MXEAccount>,
+
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+
     #[account(
         mut,
         address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
     )]
     /// CHECK: mempool_account, checked by the arcium program.
     pub mempool_account: UncheckedAccount<'info>,
+
     #[account(
         mut,
         address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
     )]
     /// CHECK: executing_pool, checked by the arcium program.
     pub executing_pool: UncheckedAccount<'info>,
+
     #[account(
         mut,
         address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
     )]
     /// CHECK: computation_account, checked by the arcium program.
     pub computation_account: UncheckedAccount<'info>,
-    #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_ADD_TOGETHER)
-    )]
+
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_INIT_AUCTION_STATE))]
     pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+
     #[account(
         mut,
         address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
     )]
     pub cluster_account: Box<Account<'info, Cluster>>,
+
     #[account(
         mut,
         address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
     )]
     pub pool_account: Box<Account<'info, FeePool>>,
+
     #[account(
         mut,
         address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
     )]
     pub clock_account: Box<Account<'info, ClockAccount>>,
-    pub system_program: Program<'info, System>,
+
     pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
 }
 
-#[callback_accounts("add_together")]
+#[callback_accounts("init_auction_state")]
 #[derive(Accounts)]
-pub struct AddTogetherCallback<'info> {
+pub struct InitAuctionStateCallback<'info> {
     pub arcium_program: Program<'info, Arcium>,
-    #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_ADD_TOGETHER)
-    )]
+
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_INIT_AUCTION_STATE))]
     pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
-    #[account(
-        address = derive_mxe_pda!()
-    )]
+
+    #[account(address = derive_mxe_pda!())]
     pub mxe_account: Account<'info, MXEAccount>,
-    /// CHECK: computation_account, checked by arcium program via constraints in the callback context.
+
+    /// CHECK: computation_account, checked by arcium program.
     pub computation_account: UncheckedAccount<'info>,
-    #[account(
-        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
-    )]
+
+    #[account(address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet))]
     pub cluster_account: Account<'info, Cluster>,
+
     #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
-    /// CHECK: instructions_sysvar, checked by the account constraint
+    /// CHECK: instructions_sysvar
     pub instructions_sysvar: AccountInfo<'info>,
+
+    // Application accounts (passed via CallbackAccount)
+    #[account(mut)]
+    pub reserve_auction: Box<Account<'info, ReserveAuction>>,
+
+    /// Pool event sequence (stamps `seq` into emitted events); the queue
+    /// site passes the pool's cursor
+    #[account(mut)]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
 }
 
-#[init_computation_definition_accounts("add_together", payer)]
+#[queue_computation_accounts("submit_auction_bid", payer)]
 #[derive(Accounts)]
-pub struct InitAddTogetherCompDef<'info> {
+#[instruction(computation_offset: u64)]
+pub struct SubmitAuctionBid<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
+
+    /// LP / market maker placing the bid
+    pub bidder: Signer<'info>,
+
+    /// Pool the account belongs to
+    #[account(
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
     #[account(
         mut,
-        address = derive_mxe_pda!()
+        seeds = [RESERVE_AUCTION_SEED, &[pool.pool_id], &reserve_auction.auction_id.to_le_bytes()],
+        bump = reserve_auction.bump,
+    )]
+    pub reserve_auction: Box<Account<'info, ReserveAuction>>,
+
+    // =========================================================================
+    // ARCIUM MPC ACCOUNTS
+    // =========================================================================
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
     )]
+    pub sign_pda_account: Box<Account<'info, ArciumSignerAccount>>,
+
+    #[account(address = derive_mxe_pda!())]
     pub mxe_account: Box<Account<'info, MXEAccount>>,
-    #[account(mut)]
-    /// CHECK: comp_def_account, checked by arcium program.
-    /// Can't check it here as it's not initialized yet.
-    pub comp_def_account: UncheckedAccount<'info>,
-    #[account(mut, address = derive_mxe_lut_pda!(mxe_account.lut_offset_slot))]
-    /// CHECK: address_lookup_table, checked by arcium program.
-    pub address_lookup_table: UncheckedAccount<'info>,
-    #[account(address = LUT_PROGRAM_ID)]
-    /// CHECK: lut_program is the Address Lookup Table program.
-    pub lut_program: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_SUBMIT_AUCTION_BID))]
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    pub cluster_account: Box<Account<'info, Cluster>>,
+
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Box<Account<'info, FeePool>>,
+
+    #[account(
+        mut,
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+    )]
+    pub clock_account: Box<Account<'info, ClockAccount>>,
+
     pub arcium_program: Program<'info, Arcium>,
     pub system_program: Program<'info, System>,
 }
 
-// =============================================================================
-// INIT ADD_BALANCE COMPUTATION DEFINITION (Phase 6)
-// =============================================================================
-
-#[init_computation_definition_accounts("add_balance", payer)]
+#[callback_accounts("submit_auction_bid")]
 #[derive(Accounts)]
-pub struct InitAddBalanceCompDef<'info> {
+pub struct SubmitAuctionBidCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_SUBMIT_AUCTION_BID))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Account<'info, MXEAccount>,
+
+    /// CHECK: computation_account, checked by arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+
+    #[account(address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet))]
+    pub cluster_account: Account<'info, Cluster>,
+
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar
+    pub instructions_sysvar: AccountInfo<'info>,
+
+    // Application accounts (passed via CallbackAccount)
     #[account(mut)]
-    pub payer: Signer<'info>,
-    #[account(
-        mut,
-        address = derive_mxe_pda!()
-    )]
-    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    pub reserve_auction: Box<Account<'info, ReserveAuction>>,
+
+    /// Pool event sequence (stamps `seq` into emitted events); the queue
+    /// site passes the pool's cursor
     #[account(mut)]
-    /// CHECK: comp_def_account, checked by arcium program.
-    /// Can't check it here as it's not initialized yet.
-    pub comp_def_account: UncheckedAccount<'info>,
-    #[account(mut, address = derive_mxe_lut_pda!(mxe_account.lut_offset_slot))]
-    /// CHECK: address_lookup_table, checked by arcium program.
-    pub address_lookup_table: UncheckedAccount<'info>,
-    #[account(address = LUT_PROGRAM_ID)]
-    /// CHECK: lut_program is the Address Lookup Table program.
-    pub lut_program: UncheckedAccount<'info>,
-    pub arcium_program: Program<'info, Arcium>,
-    pub system_program: Program<'info, System>,
+    pub event_cursor: Box<Account<'info, EventCursor>>,
 }
 
-// =============================================================================
-// ADD BALANCE QUEUE COMPUTATION ACCOUNTS (Phase 6)
-// =============================================================================
-// These accounts are needed when calling add_balance instruction.
-// Combines token transfer + MPC queue in single instruction.
-
-#[queue_computation_accounts("add_balance", payer)]
+#[queue_computation_accounts("reveal_auction", payer)]
 #[derive(Accounts)]
 #[instruction(computation_offset: u64)]
-pub struct AddBalance<'info> {
-    // =========================================================================
-    // PAYER & USER
-    // =========================================================================
+pub struct RevealReserveAuction<'info> {
+    /// Anyone may reveal once bidding has closed
     #[account(mut)]
     pub payer: Signer<'info>,
 
-    /// The user making the deposit (must sign for token transfer)
-    #[account(mut)]
-    pub user: Signer<'info>,
-
-    // =========================================================================
-    // TOKEN ACCOUNTS
-    // =========================================================================
-    /// The pool account (for vault authority)
+    /// Pool the account belongs to
     #[account(
-        seeds = [POOL_SEED],
+        seeds = [POOL_SEED, &[pool.pool_id]],
         bump = pool.bump,
     )]
     pub pool: Box<Account<'info, Pool>>,
 
-    /// User's privacy account (will have encrypted balance updated via callback)
-    #[account(
-        mut,
-        seeds = [USER_SEED, user.key().as_ref()],
-        bump = user_account.bump,
-    )]
-    pub user_account: Box<Account<'info, UserProfile>>,
-
-    /// User's token account for the asset being deposited (source of funds)
-    /// Caller must provide the correct token account matching the asset_id
     #[account(
         mut,
-        constraint = user_token_account.owner == user.key() @ ErrorCode::InvalidOwner,
+        seeds = [RESERVE_AUCTION_SEED, &[pool.pool_id], &reserve_auction.auction_id.to_le_bytes()],
+        bump = reserve_auction.bump,
     )]
-    pub user_token_account: Box<Account<'info, anchor_spl::token::TokenAccount>>,
-
-    /// Protocol's vault for the asset being deposited (destination of funds)
-    /// Caller must provide the correct vault matching the asset_id
-    #[account(mut)]
-    pub vault: Box<Account<'info, anchor_spl::token::TokenAccount>>,
-
-    pub token_program: Program<'info, anchor_spl::token::Token>,
+    pub reserve_auction: Box<Account<'info, ReserveAuction>>,
 
     // =========================================================================
     // ARCIUM MPC ACCOUNTS
@@ -1694,7 +16444,7 @@ pub struct AddBalance<'info> {
     /// CHECK: computation_account, checked by the arcium program.
     pub computation_account: UncheckedAccount<'info>,
 
-    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_ADD_BALANCE))]
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_REVEAL_AUCTION))]
     pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
 
     #[account(
@@ -1715,1560 +16465,1700 @@ pub struct AddBalance<'info> {
     )]
     pub clock_account: Box<Account<'info, ClockAccount>>,
 
-    pub system_program: Program<'info, System>,
     pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
 }
 
-// =============================================================================
-// ADD BALANCE CALLBACK ACCOUNTS (Phase 6)
-// =============================================================================
-
-#[callback_accounts("add_balance")]
+#[callback_accounts("reveal_auction")]
 #[derive(Accounts)]
-pub struct AddBalanceCallback<'info> {
+pub struct RevealAuctionCallback<'info> {
     pub arcium_program: Program<'info, Arcium>,
 
-    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_ADD_BALANCE))]
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_REVEAL_AUCTION))]
     pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
 
     #[account(address = derive_mxe_pda!())]
     pub mxe_account: Account<'info, MXEAccount>,
 
-    /// CHECK: computation_account, checked by arcium program via constraints in the callback context.
+    /// CHECK: computation_account, checked by arcium program.
     pub computation_account: UncheckedAccount<'info>,
 
     #[account(address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet))]
     pub cluster_account: Account<'info, Cluster>,
 
     #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
-    /// CHECK: instructions_sysvar, checked by the account constraint
+    /// CHECK: instructions_sysvar
     pub instructions_sysvar: AccountInfo<'info>,
 
-    /// User's privacy account - receives the updated encrypted balance
+    // Application accounts (passed via CallbackAccount)
     #[account(mut)]
-    pub user_account: Box<Account<'info, UserProfile>>,
-}
+    pub reserve_auction: Box<Account<'info, ReserveAuction>>,
 
-#[event]
-pub struct SumEvent {
-    pub sum: [u8; 32],
-    pub nonce: [u8; 16],
+    /// Pool event sequence (stamps `seq` into emitted events); the queue
+    /// site passes the pool's cursor
+    #[account(mut)]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
 }
 
-#[event]
-pub struct DepositEvent {
-    pub user: Pubkey,
-    pub encrypted_balance: [u8; 32],
-    pub nonce: [u8; 16],
-}
+#[derive(Accounts)]
+pub struct ClaimReserveAuction<'info> {
+    /// Winning bidder
+    pub winner: Signer<'info>,
 
-#[event]
-pub struct WithdrawEvent {
-    pub user: Pubkey,
-    pub encrypted_balance: [u8; 32],
-    pub nonce: [u8; 16],
-}
+    /// Pool PDA (authority for reserves)
+    #[account(
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
 
-#[event]
-pub struct TransferEvent {
-    pub from: Pubkey,
-    pub to: Pubkey,
-    pub amount: u64,
-    pub sender_nonce: [u8; 16],
-}
+    #[account(
+        mut,
+        seeds = [RESERVE_AUCTION_SEED, &[pool.pool_id], &reserve_auction.auction_id.to_le_bytes()],
+        bump = reserve_auction.bump,
+        constraint = reserve_auction.winner == winner.key() @ ErrorCode::Unauthorized,
+    )]
+    pub reserve_auction: Box<Account<'info, ReserveAuction>>,
 
-#[event]
-pub struct OrderPlacedEvent {
-    pub user: Pubkey,
-    pub batch_id: u64,
-}
+    /// Winner's USDC account (pays the clearing price)
+    #[account(
+        mut,
+        constraint = winner_usdc_account.owner == winner.key() @ ErrorCode::InvalidOwner,
+        constraint = winner_usdc_account.mint == pool.usdc_mint @ ErrorCode::InvalidMint,
+    )]
+    pub winner_usdc_account: Box<Account<'info, TokenAccount>>,
 
-#[event]
-pub struct SettlementEvent {
-    pub user: Pubkey,
-    pub batch_id: u64,
-    pub encrypted_payout: [u8; 32],
-    pub nonce: [u8; 16],
-    /// DEBUG: Revealed payout value from MPC for verification
-    pub revealed_payout: u64,
-}
+    /// Winner's account for the auctioned asset (receives the lot)
+    #[account(
+        mut,
+        constraint = winner_asset_account.owner == winner.key() @ ErrorCode::InvalidOwner,
+        constraint = Some(winner_asset_account.mint) == pool.mint_for_asset(reserve_auction.asset_id) @ ErrorCode::InvalidMint,
+    )]
+    pub winner_asset_account: Box<Account<'info, TokenAccount>>,
 
-/// Emitted when batch meets execution criteria (8+ orders, 2+ pairs)
-/// MPC computes requirements check and reveals batch_ready boolean
-/// Can be used by external services (webhooks) to trigger batch execution
-#[event]
-pub struct BatchReadyEvent {
-    pub batch_id: u64,
-    pub batch_accumulator: Pubkey,
-}
+    #[account(
+        mut,
+        seeds = [RESERVE_SEED, &[pool.pool_id], RESERVE_USDC_SEED],
+        bump,
+    )]
+    pub reserve_usdc: Box<Account<'info, TokenAccount>>,
 
-/// Emitted when batch execution fails, signals retry needed
-#[event]
-pub struct BatchExecutionFailedEvent {
-    pub batch_id: u64,
-    pub error_code: u32,
+    /// Reserve holding the auctioned asset (PDA verified in handler)
+    #[account(mut)]
+    pub reserve_asset: Box<Account<'info, TokenAccount>>,
+
+    /// Pool event sequence (stamps `seq` into emitted events)
+    #[account(
+        mut,
+        seeds = [EVENT_CURSOR_SEED, &[pool.pool_id]],
+        bump = event_cursor.bump,
+    )]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+
+    pub token_program: Program<'info, Token>,
 }
 
-/// Emitted when batch MPC completes and BatchLog is created
-/// Backend listens for this to call execute_swaps
-#[event]
-pub struct BatchExecutedEvent {
-    pub batch_id: u64,
-    pub batch_log: Pubkey,
+// =============================================================================
+// MPC CALLBACK PRIORITY ACCOUNTS
+// =============================================================================
+
+#[derive(Accounts)]
+pub struct SetCallbackPriorityFee<'info> {
+    /// Pool authority
+    #[account(constraint = roles.is_admin(&pool, &authority.key()) @ ErrorCode::Unauthorized)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// Role grants (admins and operators besides the pool's own)
+    #[account(
+        seeds = [ROLES_SEED, &[pool.pool_id]],
+        bump = roles.bump,
+    )]
+    pub roles: Box<Account<'info, Roles>>,
+
+    /// Pool event sequence (stamps `seq` into emitted events)
+    #[account(
+        mut,
+        seeds = [EVENT_CURSOR_SEED, &[pool.pool_id]],
+        bump = event_cursor.bump,
+    )]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
 }
 
 // =============================================================================
-// CHECK PRIVACY ACCOUNT EXISTS (Phase 6.75)
+// BATCH APPROVAL ACCOUNTS
 // =============================================================================
 
-/// Accounts for checking if a privacy account exists
 #[derive(Accounts)]
-pub struct CheckPrivacyAccountExists<'info> {
-    /// The privacy account to check
-    /// If this doesn't exist, Anchor will return AccountNotInitialized error
-    pub user_account: Box<Account<'info, UserProfile>>,
+pub struct SetBatchApproval<'info> {
+    /// Pool authority
+    #[account(constraint = roles.is_admin(&pool, &authority.key()) @ ErrorCode::Unauthorized)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// Role grants (admins and operators besides the pool's own)
+    #[account(
+        seeds = [ROLES_SEED, &[pool.pool_id]],
+        bump = roles.bump,
+    )]
+    pub roles: Box<Account<'info, Roles>>,
+
+    /// Pool event sequence (stamps `seq` into emitted events)
+    #[account(
+        mut,
+        seeds = [EVENT_CURSOR_SEED, &[pool.pool_id]],
+        bump = event_cursor.bump,
+    )]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
 }
-// INIT SUB_BALANCE COMPUTATION DEFINITION (Phase 6.5)
+
+// =============================================================================
+// EXECUTOR REGISTRY ACCOUNTS
 // =============================================================================
 
-#[init_computation_definition_accounts("sub_balance", payer)]
 #[derive(Accounts)]
-pub struct InitSubBalanceCompDef<'info> {
+pub struct ConfigureExecutorRegistry<'info> {
+    /// Pool authority
+    #[account(
+        mut,
+        constraint = roles.is_admin(&pool, &authority.key()) @ ErrorCode::Unauthorized,
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// Role grants (admins and operators besides the pool's own)
+    #[account(
+        seeds = [ROLES_SEED, &[pool.pool_id]],
+        bump = roles.bump,
+    )]
+    pub roles: Box<Account<'info, Roles>>,
+
+    /// Executor registry (created on first call)
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = ExecutorRegistry::SIZE,
+        seeds = [EXECUTOR_REGISTRY_SEED, &[pool.pool_id]],
+        bump,
+    )]
+    pub executor_registry: Box<Account<'info, ExecutorRegistry>>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Pool event sequence (stamps `seq` into emitted events)
+    #[account(
+        mut,
+        seeds = [EVENT_CURSOR_SEED, &[pool.pool_id]],
+        bump = event_cursor.bump,
+    )]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+}
+
+#[derive(Accounts)]
+pub struct RegisterExecutor<'info> {
+    /// Executor posting the bond
     #[account(mut)]
-    pub payer: Signer<'info>,
+    pub executor: Signer<'info>,
+
+    /// Pool the registry belongs to
+    #[account(
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    #[account(
+        mut,
+        seeds = [EXECUTOR_REGISTRY_SEED, &[pool.pool_id]],
+        bump = executor_registry.bump,
+    )]
+    pub executor_registry: Box<Account<'info, ExecutorRegistry>>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Pool event sequence (stamps `seq` into emitted events)
+    #[account(
+        mut,
+        seeds = [EVENT_CURSOR_SEED, &[pool.pool_id]],
+        bump = event_cursor.bump,
+    )]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+}
+
+#[derive(Accounts)]
+pub struct SlashExecutor<'info> {
+    /// Pool authority
+    #[account(constraint = roles.is_admin(&pool, &authority.key()) @ ErrorCode::Unauthorized)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// Role grants (admins and operators besides the pool's own)
+    #[account(
+        seeds = [ROLES_SEED, &[pool.pool_id]],
+        bump = roles.bump,
+    )]
+    pub roles: Box<Account<'info, Roles>>,
+
+    #[account(
+        mut,
+        seeds = [EXECUTOR_REGISTRY_SEED, &[pool.pool_id]],
+        bump = executor_registry.bump,
+    )]
+    pub executor_registry: Box<Account<'info, ExecutorRegistry>>,
+
+    /// CHECK: Address checked against Pool.treasury; receives the bond
+    #[account(mut, address = pool.treasury @ ErrorCode::Unauthorized)]
+    pub treasury: UncheckedAccount<'info>,
+
+    /// Pool event sequence (stamps `seq` into emitted events)
     #[account(
         mut,
-        address = derive_mxe_pda!()
+        seeds = [EVENT_CURSOR_SEED, &[pool.pool_id]],
+        bump = event_cursor.bump,
     )]
-    pub mxe_account: Box<Account<'info, MXEAccount>>,
-    #[account(mut)]
-    /// CHECK: comp_def_account, checked by arcium program.
-    /// Can't check it here as it's not initialized yet.
-    pub comp_def_account: UncheckedAccount<'info>,
-    #[account(mut, address = derive_mxe_lut_pda!(mxe_account.lut_offset_slot))]
-    /// CHECK: address_lookup_table, checked by arcium program.
-    pub address_lookup_table: UncheckedAccount<'info>,
-    #[account(address = LUT_PROGRAM_ID)]
-    /// CHECK: lut_program is the Address Lookup Table program.
-    pub lut_program: UncheckedAccount<'info>,
-    pub arcium_program: Program<'info, Arcium>,
-    pub system_program: Program<'info, System>,
+    pub event_cursor: Box<Account<'info, EventCursor>>,
 }
 
 // =============================================================================
-// INIT TRANSFER COMPUTATION DEFINITION (Phase 6.75)
+// BOOTSTRAP ACCOUNTS
 // =============================================================================
 
-#[init_computation_definition_accounts("transfer", payer)]
 #[derive(Accounts)]
-pub struct InitTransferCompDef<'info> {
-    #[account(mut)]
-    pub payer: Signer<'info>,
+pub struct Bootstrap<'info> {
+    /// Anyone may record deployment progress
+    pub caller: Signer<'info>,
+
     #[account(
         mut,
-        address = derive_mxe_pda!()
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
     )]
-    pub mxe_account: Box<Account<'info, MXEAccount>>,
-    #[account(mut)]
-    /// CHECK: comp_def_account, checked by arcium program.
-    pub comp_def_account: UncheckedAccount<'info>,
-    #[account(mut, address = derive_mxe_lut_pda!(mxe_account.lut_offset_slot))]
-    /// CHECK: address_lookup_table, checked by arcium program.
-    pub address_lookup_table: UncheckedAccount<'info>,
-    #[account(address = LUT_PROGRAM_ID)]
-    /// CHECK: lut_program is the Address Lookup Table program.
-    pub lut_program: UncheckedAccount<'info>,
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// Accumulator slot 0, if created
+    #[account(
+        seeds = [BATCH_ACCUMULATOR_SEED, &[pool.pool_id], &[0]],
+        bump = batch_accumulator_0.bump,
+    )]
+    pub batch_accumulator_0: Option<Box<Account<'info, BatchAccumulator>>>,
+
+    /// Accumulator slot 1, if created
+    #[account(
+        seeds = [BATCH_ACCUMULATOR_SEED, &[pool.pool_id], &[1]],
+        bump = batch_accumulator_1.bump,
+    )]
+    pub batch_accumulator_1: Option<Box<Account<'info, BatchAccumulator>>>,
+
+    /// Owner of initialized comp def accounts
     pub arcium_program: Program<'info, Arcium>,
-    pub system_program: Program<'info, System>,
+
+    /// Pool event sequence (stamps `seq` into emitted events)
+    #[account(
+        mut,
+        seeds = [EVENT_CURSOR_SEED, &[pool.pool_id]],
+        bump = event_cursor.bump,
+    )]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
 }
 
 // =============================================================================
-// TRANSFER CALLBACK ACCOUNTS (Phase 6.75)
+// CIRCUIT REGISTRY ACCOUNTS
 // =============================================================================
-// Callback for transfer circuit - updates both sender and recipient balances.
 
-#[callback_accounts("transfer")]
 #[derive(Accounts)]
-pub struct TransferCallback<'info> {
-    pub arcium_program: Program<'info, Arcium>,
-
-    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_TRANSFER))]
-    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
-
-    #[account(address = derive_mxe_pda!())]
-    pub mxe_account: Account<'info, MXEAccount>,
+pub struct UpgradeCircuit<'info> {
+    /// Admin
+    #[account(
+        mut,
+        constraint = roles.is_admin(&pool, &authority.key()) @ ErrorCode::Unauthorized,
+    )]
+    pub authority: Signer<'info>,
 
-    /// CHECK: computation_account, checked by arcium program.
-    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
 
-    #[account(address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet))]
-    pub cluster_account: Account<'info, Cluster>,
+    /// Role grants (admins and operators besides the pool's own)
+    #[account(
+        seeds = [ROLES_SEED, &[pool.pool_id]],
+        bump = roles.bump,
+    )]
+    pub roles: Box<Account<'info, Roles>>,
 
-    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
-    /// CHECK: instructions_sysvar
-    pub instructions_sysvar: AccountInfo<'info>,
+    /// Circuit registry (created on first call)
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = CircuitRegistry::SIZE,
+        seeds = [CIRCUIT_REGISTRY_SEED, &[pool.pool_id]],
+        bump,
+    )]
+    pub circuit_registry: Box<Account<'info, CircuitRegistry>>,
 
-    // Application accounts (passed via CallbackAccount)
-    #[account(mut)]
-    pub sender_account: Box<Account<'info, UserProfile>>,
+    pub system_program: Program<'info, System>,
 
-    #[account(mut)]
-    pub recipient_account: Box<Account<'info, UserProfile>>,
+    /// Pool event sequence (stamps `seq` into emitted events)
+    #[account(
+        mut,
+        seeds = [EVENT_CURSOR_SEED, &[pool.pool_id]],
+        bump = event_cursor.bump,
+    )]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
 }
 
 // =============================================================================
-// SUB BALANCE QUEUE COMPUTATION ACCOUNTS (Phase 6.5)
+// CONFIG SNAPSHOT ACCOUNTS
 // =============================================================================
-// These accounts are needed when calling sub_balance instruction.
-// Queues MPC computation; token transfer happens in callback.
 
-#[queue_computation_accounts("sub_balance", payer)]
 #[derive(Accounts)]
-#[instruction(computation_offset: u64)]
-pub struct SubBalance<'info> {
-    // =========================================================================
-    // PAYER & USER
-    // =========================================================================
-    #[account(mut)]
-    pub payer: Signer<'info>,
-
-    /// The user making the withdrawal (must sign for authorization)
-    #[account(mut)]
-    pub user: Signer<'info>,
+pub struct ExportConfig<'info> {
+    /// Pool authority
+    #[account(
+        mut,
+        constraint = roles.is_admin(&pool, &authority.key()) @ ErrorCode::Unauthorized,
+    )]
+    pub authority: Signer<'info>,
 
-    // =========================================================================
-    // TOKEN ACCOUNTS
-    // =========================================================================
-    /// The pool account (for vault authority in callback)
     #[account(
-        seeds = [POOL_SEED],
+        seeds = [POOL_SEED, &[pool.pool_id]],
         bump = pool.bump,
     )]
     pub pool: Box<Account<'info, Pool>>,
 
-    /// User's privacy account (will have encrypted balance updated via callback)
+    /// Role grants (admins and operators besides the pool's own)
     #[account(
-        mut,
-        seeds = [USER_SEED, user.key().as_ref()],
-        bump = user_account.bump,
-        constraint = user_account.owner == user.key() @ ErrorCode::Unauthorized,
+        seeds = [ROLES_SEED, &[pool.pool_id]],
+        bump = roles.bump,
     )]
-    pub user_account: Box<Account<'info, UserProfile>>,
-
-    /// Recipient's token account for the asset being withdrawn (destination of funds)
-    /// Can be the user's own account OR an external recipient's account
-    /// Caller must provide the correct token account matching the asset_id
-    #[account(mut)]
-    pub recipient_token_account: Box<Account<'info, anchor_spl::token::TokenAccount>>,
-
-    /// Protocol's vault for the asset being withdrawn (source of funds)
-    /// Caller must provide the correct vault matching the asset_id
-    #[account(mut)]
-    pub vault: Box<Account<'info, anchor_spl::token::TokenAccount>>,
+    pub roles: Box<Account<'info, Roles>>,
 
-    pub token_program: Program<'info, anchor_spl::token::Token>,
+    /// Optional executor registry (its minimum bond is exported)
+    #[account(
+        seeds = [EXECUTOR_REGISTRY_SEED, &[pool.pool_id]],
+        bump = executor_registry.bump,
+    )]
+    pub executor_registry: Option<Box<Account<'info, ExecutorRegistry>>>,
 
-    // =========================================================================
-    // ARCIUM MPC ACCOUNTS
-    // =========================================================================
+    /// Snapshot (created on first export)
     #[account(
         init_if_needed,
-        space = 9,
-        payer = payer,
-        seeds = [&SIGN_PDA_SEED],
+        payer = authority,
+        space = ConfigSnapshot::SIZE,
+        seeds = [CONFIG_SNAPSHOT_SEED, &[pool.pool_id]],
         bump,
-        address = derive_sign_pda!(),
     )]
-    pub sign_pda_account: Box<Account<'info, ArciumSignerAccount>>,
+    pub config_snapshot: Box<Account<'info, ConfigSnapshot>>,
 
-    #[account(address = derive_mxe_pda!())]
-    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    pub system_program: Program<'info, System>,
 
+    /// Pool event sequence (stamps `seq` into emitted events)
     #[account(
         mut,
-        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+        seeds = [EVENT_CURSOR_SEED, &[pool.pool_id]],
+        bump = event_cursor.bump,
     )]
-    /// CHECK: mempool_account, checked by the arcium program.
-    pub mempool_account: UncheckedAccount<'info>,
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+}
 
-    #[account(
-        mut,
-        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
-    )]
-    /// CHECK: executing_pool, checked by the arcium program.
-    pub executing_pool: UncheckedAccount<'info>,
+#[derive(Accounts)]
+pub struct ApplyConfig<'info> {
+    /// Pool authority
+    #[account(constraint = roles.is_admin(&pool, &authority.key()) @ ErrorCode::Unauthorized)]
+    pub authority: Signer<'info>,
 
     #[account(
         mut,
-        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
     )]
-    /// CHECK: computation_account, checked by the arcium program.
-    pub computation_account: UncheckedAccount<'info>,
-
-    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_SUB_BALANCE))]
-    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+    pub pool: Box<Account<'info, Pool>>,
 
+    /// Role grants (admins and operators besides the pool's own)
     #[account(
-        mut,
-        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+        seeds = [ROLES_SEED, &[pool.pool_id]],
+        bump = roles.bump,
     )]
-    pub cluster_account: Box<Account<'info, Cluster>>,
+    pub roles: Box<Account<'info, Roles>>,
 
+    /// Optional executor registry (receives the minimum bond)
     #[account(
         mut,
-        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+        seeds = [EXECUTOR_REGISTRY_SEED, &[pool.pool_id]],
+        bump = executor_registry.bump,
     )]
-    pub pool_account: Box<Account<'info, FeePool>>,
+    pub executor_registry: Option<Box<Account<'info, ExecutorRegistry>>>,
 
+    /// Pool event sequence (stamps `seq` into emitted events)
     #[account(
         mut,
-        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+        seeds = [EVENT_CURSOR_SEED, &[pool.pool_id]],
+        bump = event_cursor.bump,
     )]
-    pub clock_account: Box<Account<'info, ClockAccount>>,
-
-    pub system_program: Program<'info, System>,
-    pub arcium_program: Program<'info, Arcium>,
+    pub event_cursor: Box<Account<'info, EventCursor>>,
 }
 
 // =============================================================================
-// SUB BALANCE CALLBACK ACCOUNTS (Phase 6.5)
+// KEEPER REWARD ACCOUNTS
 // =============================================================================
-// Callback receives MPC output, verifies has_funds, and performs token transfer.
 
-#[callback_accounts("sub_balance")]
 #[derive(Accounts)]
-pub struct SubBalanceCallback<'info> {
-    pub arcium_program: Program<'info, Arcium>,
-
-    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_SUB_BALANCE))]
-    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
-
-    #[account(address = derive_mxe_pda!())]
-    pub mxe_account: Account<'info, MXEAccount>,
-
-    /// CHECK: computation_account, checked by arcium program via constraints in the callback context.
-    pub computation_account: UncheckedAccount<'info>,
-
-    #[account(address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet))]
-    pub cluster_account: Account<'info, Cluster>,
-
-    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
-    /// CHECK: instructions_sysvar, checked by the account constraint
-    pub instructions_sysvar: AccountInfo<'info>,
-
-    // =========================================================================
-    // APPLICATION ACCOUNTS (passed via CallbackAccount)
-    // =========================================================================
-    /// User's privacy account - receives the updated encrypted balance
-    #[account(mut)]
-    pub user_account: Box<Account<'info, UserProfile>>,
+pub struct SetKeeperReward<'info> {
+    /// Pool authority
+    #[account(constraint = roles.is_admin(&pool, &authority.key()) @ ErrorCode::Unauthorized)]
+    pub authority: Signer<'info>,
 
-    /// Pool PDA (authority for vault) - passed via CallbackAccount
+    #[account(
+        mut,
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
     pub pool: Box<Account<'info, Pool>>,
 
-    /// Vault token account - source of tokens for withdrawal
-    /// CHECK: Passed via CallbackAccount, verified by token transfer
-    #[account(mut)]
-    pub vault: AccountInfo<'info>,
-
-    /// Recipient token account - destination for withdrawn tokens
-    /// CHECK: Passed via CallbackAccount, verified by token transfer
-    #[account(mut)]
-    pub recipient_token_account: AccountInfo<'info>,
+    /// Role grants (admins and operators besides the pool's own)
+    #[account(
+        seeds = [ROLES_SEED, &[pool.pool_id]],
+        bump = roles.bump,
+    )]
+    pub roles: Box<Account<'info, Roles>>,
 
-    /// Token program for transfer CPI
-    /// CHECK: Passed via CallbackAccount
-    pub token_program: AccountInfo<'info>,
+    /// Pool event sequence (stamps `seq` into emitted events)
+    #[account(
+        mut,
+        seeds = [EVENT_CURSOR_SEED, &[pool.pool_id]],
+        bump = event_cursor.bump,
+    )]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
 }
 
 // =============================================================================
-// INTERNAL TRANSFER ACCOUNTS (Phase 6.75)
+// ORACLE CONFIG ACCOUNTS
 // =============================================================================
-// P2P transfer between two privacy accounts.
 
-#[queue_computation_accounts("transfer", payer)]
 #[derive(Accounts)]
-#[instruction(computation_offset: u64)]
-pub struct InternalTransfer<'info> {
-    #[account(mut)]
-    pub payer: Signer<'info>,
-
-    /// Sender must sign the transaction
-    pub sender: Signer<'info>,
+pub struct SetOracleFeed<'info> {
+    /// Pool authority
+    #[account(
+        mut,
+        constraint = roles.is_admin(&pool, &authority.key()) @ ErrorCode::Unauthorized,
+    )]
+    pub authority: Signer<'info>,
 
-    /// Sender's privacy account (source of funds)
     #[account(
         mut,
-        seeds = [USER_SEED, sender.key().as_ref()],
-        bump,
-        constraint = sender_account.owner == sender.key() @ ErrorCode::InvalidOwner,
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
     )]
-    pub sender_account: Box<Account<'info, UserProfile>>,
+    pub pool: Box<Account<'info, Pool>>,
 
-    /// Recipient's privacy account (destination of funds)
-    /// Must exist - if not initialized, Anchor will fail with AccountNotInitialized
-    #[account(mut)]
-    pub recipient_account: Box<Account<'info, UserProfile>>,
+    /// Role grants (admins and operators besides the pool's own)
+    #[account(
+        seeds = [ROLES_SEED, &[pool.pool_id]],
+        bump = roles.bump,
+    )]
+    pub roles: Box<Account<'info, Roles>>,
 
-    // =========================================================================
-    // ARCIUM MPC ACCOUNTS
-    // =========================================================================
+    /// Oracle config (created on first call)
     #[account(
         init_if_needed,
-        space = 9,
-        payer = payer,
-        seeds = [&SIGN_PDA_SEED],
+        payer = authority,
+        space = OracleConfig::SIZE,
+        seeds = [ORACLE_CONFIG_SEED, &[pool.pool_id]],
         bump,
-        address = derive_sign_pda!(),
     )]
-    pub sign_pda_account: Account<'info, ArciumSignerAccount>,
+    pub oracle_config: Box<Account<'info, OracleConfig>>,
 
-    #[account(address = derive_mxe_pda!())]
-    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    pub system_program: Program<'info, System>,
 
+    /// Pool event sequence (stamps `seq` into emitted events)
     #[account(
         mut,
-        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+        seeds = [EVENT_CURSOR_SEED, &[pool.pool_id]],
+        bump = event_cursor.bump,
     )]
-    /// CHECK: mempool_account, checked by the arcium program.
-    pub mempool_account: UncheckedAccount<'info>,
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+}
 
+#[derive(Accounts)]
+pub struct SetOracleParams<'info> {
+    /// Pool authority
     #[account(
         mut,
-        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+        constraint = roles.is_risk_manager(&pool, &authority.key()) @ ErrorCode::Unauthorized,
     )]
-    /// CHECK: executing_pool, checked by the arcium program.
-    pub executing_pool: UncheckedAccount<'info>,
+    pub authority: Signer<'info>,
 
     #[account(
         mut,
-        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
     )]
-    /// CHECK: computation_account, will be initialized by arcium program.
-    pub computation_account: UncheckedAccount<'info>,
+    pub pool: Box<Account<'info, Pool>>,
 
+    /// Role grants (admins and operators besides the pool's own)
     #[account(
-        mut,
-        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+        seeds = [ROLES_SEED, &[pool.pool_id]],
+        bump = roles.bump,
     )]
-    pub cluster_account: Box<Account<'info, Cluster>>,
-
-    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_TRANSFER))]
-    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+    pub roles: Box<Account<'info, Roles>>,
 
+    /// Oracle config (created on first call)
     #[account(
-        mut,
-        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+        init_if_needed,
+        payer = authority,
+        space = OracleConfig::SIZE,
+        seeds = [ORACLE_CONFIG_SEED, &[pool.pool_id]],
+        bump,
     )]
-    pub pool_account: Account<'info, FeePool>,
+    pub oracle_config: Box<Account<'info, OracleConfig>>,
+
+    pub system_program: Program<'info, System>,
 
+    /// Pool event sequence (stamps `seq` into emitted events)
     #[account(
         mut,
-        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+        seeds = [EVENT_CURSOR_SEED, &[pool.pool_id]],
+        bump = event_cursor.bump,
     )]
-    pub clock_account: Account<'info, ClockAccount>,
-
-    pub system_program: Program<'info, System>,
-    pub arcium_program: Program<'info, Arcium>,
+    pub event_cursor: Box<Account<'info, EventCursor>>,
 }
 
 // =============================================================================
-// INITIALIZE INSTRUCTION ACCOUNTS (Phase 3)
+// POOL ADMINISTRATION ACCOUNTS
 // =============================================================================
-// This struct defines all accounts required for the initialize instruction.
-// Defined here in lib.rs for Anchor's IDL generation to work correctly.
-//
-
-use crate::constants::*;
-use crate::state::{BatchAccumulator, BatchLog, Pool, UserProfile};
-use anchor_spl::token::Mint;
 
 #[derive(Accounts)]
-pub struct Initialize<'info> {
-    // =========================================================================
-    // PAYER & AUTHORITIES
-    // =========================================================================
-    /// The wallet paying for account creation (rent).
-    /// Must sign the transaction.
-    #[account(mut)]
-    pub payer: Signer<'info>,
-
-    /// Admin authority for the protocol.
-    /// Can update fees, pause protocol, etc.
-    /// CHECK: This can be any valid public key - stored as Pool.authority
-    pub authority: UncheckedAccount<'info>,
-
-    /// Operator wallet for batch execution.
-    /// CHECK: This can be any valid public key - stored as Pool.operator
-    pub operator: UncheckedAccount<'info>,
-
-    /// Treasury wallet for collecting fees.
-    /// CHECK: This can be any valid public key - stored as Pool.treasury
-    pub treasury: UncheckedAccount<'info>,
+pub struct UpdatePoolConfig<'info> {
+    /// Pool authority
+    #[account(constraint = roles.is_admin(&pool, &authority.key()) @ ErrorCode::Unauthorized)]
+    pub authority: Signer<'info>,
 
-    // =========================================================================
-    // POOL ACCOUNT (PDA)
-    // =========================================================================
-    /// The main Pool account - central state for the protocol.
-    /// PDA derived from seeds: ["pool"]
-    /// Space calculation defined in Pool::SIZE
-    /// Note: Wrapped in Box to reduce stack usage (many accounts in this instruction)
     #[account(
-        init,
-        payer = payer,
-        space = Pool::SIZE,
-        seeds = [POOL_SEED],
-        bump,
+        mut,
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
     )]
     pub pool: Box<Account<'info, Pool>>,
 
-    // =========================================================================
-    // TOKEN MINTS (existing tokens on-chain)
-    // =========================================================================
-    /// USDC token mint - any valid mint can be passed
-    /// The address is stored in Pool during initialization
-    /// Note: Wrapped in Box to reduce stack usage
-    pub usdc_mint: Box<Account<'info, Mint>>,
-
-    /// TSLA token mint
-    pub tsla_mint: Box<Account<'info, Mint>>,
-
-    /// SPY token mint
-    pub spy_mint: Box<Account<'info, Mint>>,
-
-    /// AAPL token mint
-    pub aapl_mint: Box<Account<'info, Mint>>,
-
-    // =========================================================================
-    // TOKEN VAULTS (PDAs)
-    // =========================================================================
-    // These are token accounts owned by the Pool PDA.
-    // They hold the protocol's token balances.
-    //
-
-    // - `token::mint` specifies which token this account holds
-    // - `token::authority` specifies who can transfer tokens (the Pool PDA)
-    // - We use separate seeds for each vault to derive unique addresses
-    /// USDC vault - holds all deposited USDC
-    /// PDA seeds: ["vault", "usdc"]
+    /// Role grants (admins and operators besides the pool's own)
     #[account(
-        init,
-        payer = payer,
-        seeds = [VAULT_SEED, VAULT_USDC_SEED],
-        bump,
-        token::mint = usdc_mint,
-        token::authority = pool,
+        seeds = [ROLES_SEED, &[pool.pool_id]],
+        bump = roles.bump,
     )]
-    pub vault_usdc: Box<Account<'info, TokenAccount>>,
+    pub roles: Box<Account<'info, Roles>>,
 
-    /// TSLA vault - holds TSLA tokens
-    /// PDA seeds: ["vault", "tsla"]
+    /// Pool event sequence (stamps `seq` into emitted events)
     #[account(
-        init,
-        payer = payer,
-        seeds = [VAULT_SEED, VAULT_TSLA_SEED],
-        bump,
-        token::mint = tsla_mint,
-        token::authority = pool,
+        mut,
+        seeds = [EVENT_CURSOR_SEED, &[pool.pool_id]],
+        bump = event_cursor.bump,
     )]
-    pub vault_tsla: Box<Account<'info, TokenAccount>>,
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+}
+
+#[derive(Accounts)]
+pub struct SetOperator<'info> {
+    /// Pool authority
+    #[account(constraint = roles.is_admin(&pool, &authority.key()) @ ErrorCode::Unauthorized)]
+    pub authority: Signer<'info>,
 
-    /// SPY vault - holds SPY tokens
-    /// PDA seeds: ["vault", "spy"]
     #[account(
-        init,
-        payer = payer,
-        seeds = [VAULT_SEED, VAULT_SPY_SEED],
-        bump,
-        token::mint = spy_mint,
-        token::authority = pool,
+        mut,
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
     )]
-    pub vault_spy: Box<Account<'info, TokenAccount>>,
+    pub pool: Box<Account<'info, Pool>>,
 
-    /// AAPL vault - holds AAPL tokens
-    /// PDA seeds: ["vault", "aapl"]
+    /// Role grants (admins and operators besides the pool's own)
     #[account(
-        init,
-        payer = payer,
-        seeds = [VAULT_SEED, VAULT_AAPL_SEED],
-        bump,
-        token::mint = aapl_mint,
-        token::authority = pool,
+        seeds = [ROLES_SEED, &[pool.pool_id]],
+        bump = roles.bump,
     )]
-    pub vault_aapl: Box<Account<'info, TokenAccount>>,
+    pub roles: Box<Account<'info, Roles>>,
 
-    // =========================================================================
-    // RESERVE VAULTS (PDAs) - Protocol Liquidity
-    // =========================================================================
-    // These are token accounts for protocol-owned liquidity.
-    // Used to fulfill net surplus during batch execution.
-    // Separate from user deposit vaults above.
-    /// USDC reserve - protocol liquidity for swaps
-    /// PDA seeds: ["reserve", "usdc"]
+    /// Pool event sequence (stamps `seq` into emitted events)
     #[account(
-        init,
-        payer = payer,
-        seeds = [RESERVE_SEED, RESERVE_USDC_SEED],
-        bump,
-        token::mint = usdc_mint,
-        token::authority = pool,
+        mut,
+        seeds = [EVENT_CURSOR_SEED, &[pool.pool_id]],
+        bump = event_cursor.bump,
     )]
-    pub reserve_usdc: Box<Account<'info, TokenAccount>>,
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+}
+
+#[derive(Accounts)]
+pub struct SetTreasury<'info> {
+    /// Pool authority
+    #[account(constraint = roles.is_admin(&pool, &authority.key()) @ ErrorCode::Unauthorized)]
+    pub authority: Signer<'info>,
 
-    /// TSLA reserve - protocol liquidity
-    /// PDA seeds: ["reserve", "tsla"]
     #[account(
-        init,
-        payer = payer,
-        seeds = [RESERVE_SEED, RESERVE_TSLA_SEED],
-        bump,
-        token::mint = tsla_mint,
-        token::authority = pool,
+        mut,
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
     )]
-    pub reserve_tsla: Box<Account<'info, TokenAccount>>,
+    pub pool: Box<Account<'info, Pool>>,
 
-    /// SPY reserve - protocol liquidity
-    /// PDA seeds: ["reserve", "spy"]
+    /// Role grants (admins and operators besides the pool's own)
     #[account(
-        init,
-        payer = payer,
-        seeds = [RESERVE_SEED, RESERVE_SPY_SEED],
-        bump,
-        token::mint = spy_mint,
-        token::authority = pool,
+        seeds = [ROLES_SEED, &[pool.pool_id]],
+        bump = roles.bump,
     )]
-    pub reserve_spy: Box<Account<'info, TokenAccount>>,
+    pub roles: Box<Account<'info, Roles>>,
 
-    /// AAPL reserve - protocol liquidity
-    /// PDA seeds: ["reserve", "aapl"]
+    /// Pool event sequence (stamps `seq` into emitted events)
     #[account(
-        init,
-        payer = payer,
-        seeds = [RESERVE_SEED, RESERVE_AAPL_SEED],
-        bump,
-        token::mint = aapl_mint,
-        token::authority = pool,
+        mut,
+        seeds = [EVENT_CURSOR_SEED, &[pool.pool_id]],
+        bump = event_cursor.bump,
     )]
-    pub reserve_aapl: Box<Account<'info, TokenAccount>>,
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+}
+
+#[derive(Accounts)]
+pub struct ProposeAuthority<'info> {
+    /// Pool authority
+    #[account(constraint = authority.key() == pool.authority @ ErrorCode::Unauthorized)]
+    pub authority: Signer<'info>,
 
-    // =========================================================================
-    // FAUCET VAULT (Devnet only)
-    // =========================================================================
-    /// USDC faucet vault - tokens users can claim for testing
-    /// PDA seeds: ["faucet_usdc"]
     #[account(
-        init,
-        payer = payer,
-        seeds = [FAUCET_VAULT_SEED],
-        bump,
-        token::mint = usdc_mint,
-        token::authority = pool,
+        mut,
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
     )]
-    pub faucet_vault: Box<Account<'info, TokenAccount>>,
-
-    // =========================================================================
-    // SYSTEM PROGRAMS
-    // =========================================================================
-    /// Required for creating accounts
-    pub system_program: Program<'info, System>,
+    pub pool: Box<Account<'info, Pool>>,
 
-    /// Required for creating token accounts
-    pub token_program: Program<'info, Token>,
+    /// Pool event sequence (stamps `seq` into emitted events)
+    #[account(
+        mut,
+        seeds = [EVENT_CURSOR_SEED, &[pool.pool_id]],
+        bump = event_cursor.bump,
+    )]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
 }
 
-// ErrorCode is now defined in errors.rs and re-exported above.
-// It contains all error codes including AbortedComputation and ClusterNotSet.
-
-// =============================================================================
-// CREATE USER ACCOUNT INSTRUCTION ACCOUNTS (Phase 4)
-// =============================================================================
-// This struct defines all accounts required for the create_user_account instruction.
-//
-
 #[derive(Accounts)]
-pub struct CreateUserAccount<'info> {
-    /// The wallet paying for account creation (rent).
-    /// Usually the same as owner, but can be different (sponsored).
-    #[account(mut)]
-    pub payer: Signer<'info>,
-
-    /// The wallet that will own this privacy account.
-    /// Must sign to prove ownership.
-    pub owner: Signer<'info>,
+pub struct AcceptAuthority<'info> {
+    /// Wallet proposed with propose_authority
+    #[account(
+        constraint = pending_authority.key() == pool.pending_authority @ ErrorCode::Unauthorized,
+    )]
+    pub pending_authority: Signer<'info>,
 
-    /// The user's privacy account - PDA derived from their wallet address.
-    /// Seeds: ["user", owner.key().as_ref()]
-    /// This ensures only ONE privacy account per wallet.
     #[account(
-        init,
-        payer = payer,
-        space = UserProfile::SIZE,
-        seeds = [USER_SEED, owner.key().as_ref()],
-        bump,
+        mut,
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
     )]
-    pub user_account: Box<Account<'info, UserProfile>>,
+    pub pool: Box<Account<'info, Pool>>,
 
-    /// Required for creating accounts
-    pub system_program: Program<'info, System>,
+    /// Pool event sequence (stamps `seq` into emitted events)
+    #[account(
+        mut,
+        seeds = [EVENT_CURSOR_SEED, &[pool.pool_id]],
+        bump = event_cursor.bump,
+    )]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
 }
 
-// Legacy Deposit struct removed in Phase 6.
-// Use AddBalance for encrypted deposits via Arcium MPC.
-
 // =============================================================================
-// INIT BATCH ACCUMULATOR ACCOUNTS (Phase 8)
+// ROLES & PAUSE ACCOUNTS
 // =============================================================================
-// Accounts for initializing the BatchAccumulator singleton.
 
 #[derive(Accounts)]
-pub struct InitBatchAccumulator<'info> {
-    /// The payer for account creation.
-    #[account(mut)]
-    pub payer: Signer<'info>,
+pub struct InitRoles<'info> {
+    /// Pool authority (pays rent)
+    #[account(
+        mut,
+        constraint = authority.key() == pool.authority @ ErrorCode::Unauthorized,
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
 
-    /// The BatchAccumulator PDA to create.
-    /// Seeds: ["batch_accumulator"]
     #[account(
         init,
-        payer = payer,
-        space = BatchAccumulator::SIZE,
-        seeds = [BATCH_ACCUMULATOR_SEED],
+        payer = authority,
+        space = Roles::SIZE,
+        seeds = [ROLES_SEED, &[pool.pool_id]],
         bump,
     )]
-    pub batch_accumulator: Account<'info, BatchAccumulator>,
+    pub roles: Box<Account<'info, Roles>>,
 
     pub system_program: Program<'info, System>,
 }
 
-// =============================================================================
-// TEST SWAP CPI ACCOUNTS (Phase 8)
-// =============================================================================
-// Accounts for CPI call from shuffle_protocol to mock_jupiter's `swap` instruction.
-// The Pool PDA acts as user_authority since it owns the source/dest vaults.
-//
-
 #[derive(Accounts)]
-pub struct TestSwap<'info> {
-    /// Operator triggers swaps (authorized backend service)
+pub struct GrantRole<'info> {
+    /// Admin
+    #[account(constraint = roles.is_admin(&pool, &authority.key()) @ ErrorCode::Unauthorized)]
+    pub authority: Signer<'info>,
+
     #[account(
-        constraint = operator.key() == pool.operator @ ErrorCode::Unauthorized,
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
     )]
-    pub operator: Signer<'info>,
+    pub pool: Box<Account<'info, Pool>>,
 
-    /// Pool PDA - acts as signer for the CPI and owns the shuffle_protocol vaults.
-    /// Must be mut because mock_jupiter's Swap marks user_authority as mut,
-    /// and Solana requires writable privilege to be present in the outer instruction.
     #[account(
         mut,
-        seeds = [POOL_SEED],
+        seeds = [ROLES_SEED, &[pool.pool_id]],
+        bump = roles.bump,
+    )]
+    pub roles: Box<Account<'info, Roles>>,
+
+    /// Pool event sequence (stamps `seq` into emitted events)
+    #[account(
+        mut,
+        seeds = [EVENT_CURSOR_SEED, &[pool.pool_id]],
+        bump = event_cursor.bump,
+    )]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+}
+
+#[derive(Accounts)]
+pub struct RevokeRole<'info> {
+    /// Admin
+    #[account(constraint = roles.is_admin(&pool, &authority.key()) @ ErrorCode::Unauthorized)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [POOL_SEED, &[pool.pool_id]],
         bump = pool.bump,
     )]
     pub pool: Box<Account<'info, Pool>>,
 
-    /// Source token mint (e.g., USDC)
-    pub source_mint: Box<Account<'info, Mint>>,
+    #[account(
+        mut,
+        seeds = [ROLES_SEED, &[pool.pool_id]],
+        bump = roles.bump,
+    )]
+    pub roles: Box<Account<'info, Roles>>,
 
-    /// Destination token mint (e.g., TSLA)
-    pub destination_mint: Box<Account<'info, Mint>>,
+    /// Pool event sequence (stamps `seq` into emitted events)
+    #[account(
+        mut,
+        seeds = [EVENT_CURSOR_SEED, &[pool.pool_id]],
+        bump = event_cursor.bump,
+    )]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+}
+
+#[derive(Accounts)]
+pub struct PauseProtocol<'info> {
+    /// Pauser or admin
+    #[account(constraint = roles.is_pauser(&pool, &authority.key()) @ ErrorCode::Unauthorized)]
+    pub authority: Signer<'info>,
 
-    /// Shuffle Protocol vault for source asset (Pool PDA is authority).
-    /// Tokens are sent FROM here to mock_jupiter.
     #[account(
         mut,
-        token::mint = source_mint,
-        token::authority = pool,
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
     )]
-    pub pool_source_vault: Box<Account<'info, TokenAccount>>,
+    pub pool: Box<Account<'info, Pool>>,
 
-    /// Shuffle Protocol vault for destination asset (Pool PDA is authority).
-    /// Tokens are received INTO here from mock_jupiter.
+    /// Role grants (admins and operators besides the pool's own)
+    #[account(
+        seeds = [ROLES_SEED, &[pool.pool_id]],
+        bump = roles.bump,
+    )]
+    pub roles: Box<Account<'info, Roles>>,
+
+    /// Pool event sequence (stamps `seq` into emitted events)
     #[account(
         mut,
-        token::mint = destination_mint,
-        token::authority = pool,
+        seeds = [EVENT_CURSOR_SEED, &[pool.pool_id]],
+        bump = event_cursor.bump,
     )]
-    pub pool_dest_vault: Box<Account<'info, TokenAccount>>,
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+}
 
-    /// mock_jupiter program to CPI into
-    /// CHECK: Validated by the instruction handler (program ID check optional for test)
-    pub jupiter_program: UncheckedAccount<'info>,
+#[derive(Accounts)]
+pub struct UnpauseProtocol<'info> {
+    /// Admin
+    #[account(constraint = roles.is_admin(&pool, &authority.key()) @ ErrorCode::Unauthorized)]
+    pub authority: Signer<'info>,
 
-    /// mock_jupiter swap_pool PDA
-    /// CHECK: Validated by mock_jupiter program during CPI
-    #[account(mut)]
-    pub jupiter_swap_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
 
-    /// mock_jupiter source vault (receives source tokens from our pool)
-    /// CHECK: Validated by mock_jupiter program during CPI
-    #[account(mut)]
-    pub jupiter_source_vault: UncheckedAccount<'info>,
+    /// Role grants (admins and operators besides the pool's own)
+    #[account(
+        seeds = [ROLES_SEED, &[pool.pool_id]],
+        bump = roles.bump,
+    )]
+    pub roles: Box<Account<'info, Roles>>,
 
-    /// mock_jupiter destination vault (sends dest tokens to our pool)
-    /// CHECK: Validated by mock_jupiter program during CPI
-    #[account(mut)]
-    pub jupiter_dest_vault: UncheckedAccount<'info>,
+    /// Pool event sequence (stamps `seq` into emitted events)
+    #[account(
+        mut,
+        seeds = [EVENT_CURSOR_SEED, &[pool.pool_id]],
+        bump = event_cursor.bump,
+    )]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+}
 
-    pub token_program: Program<'info, Token>,
+#[derive(Accounts)]
+pub struct SetEmergencyWithdraw<'info> {
+    /// Admin
+    #[account(constraint = roles.is_admin(&pool, &authority.key()) @ ErrorCode::Unauthorized)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// Role grants (admins and operators besides the pool's own)
+    #[account(
+        seeds = [ROLES_SEED, &[pool.pool_id]],
+        bump = roles.bump,
+    )]
+    pub roles: Box<Account<'info, Roles>>,
+
+    /// Pool event sequence (stamps `seq` into emitted events)
+    #[account(
+        mut,
+        seeds = [EVENT_CURSOR_SEED, &[pool.pool_id]],
+        bump = event_cursor.bump,
+    )]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
 }
 
 // =============================================================================
-// PLACE ORDER ACCOUNTS (Phase 8)
+// BATCH CONFIGURATION ACCOUNTS
 // =============================================================================
-// Queue computation to place an encrypted order in the batch.
 
-#[queue_computation_accounts("accumulate_order", payer)]
 #[derive(Accounts)]
-#[instruction(computation_offset: u64)]
-pub struct PlaceOrder<'info> {
-    #[account(mut)]
-    pub payer: Signer<'info>,
-
-    /// User placing the order
-    #[account(mut)]
-    pub user: Signer<'info>,
+pub struct UpdateBatchConfig<'info> {
+    /// Pool authority
+    #[account(constraint = roles.is_admin(&pool, &authority.key()) @ ErrorCode::Unauthorized)]
+    pub authority: Signer<'info>,
 
-    /// User's privacy account
     #[account(
         mut,
-        seeds = [USER_SEED, user.key().as_ref()],
-        bump = user_account.bump,
-        constraint = user_account.owner == user.key() @ ErrorCode::InvalidOwner,
-        constraint = user_account.pending_order.is_none() @ ErrorCode::PendingOrderExists,
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
     )]
-    pub user_account: Box<Account<'info, UserProfile>>,
+    pub pool: Box<Account<'info, Pool>>,
 
-    /// Batch accumulator singleton
+    /// Role grants (admins and operators besides the pool's own)
     #[account(
-        mut,
-        seeds = [BATCH_ACCUMULATOR_SEED],
-        bump = batch_accumulator.bump,
+        seeds = [ROLES_SEED, &[pool.pool_id]],
+        bump = roles.bump,
     )]
-    pub batch_accumulator: Box<Account<'info, BatchAccumulator>>,
+    pub roles: Box<Account<'info, Roles>>,
 
-    // =========================================================================
-    // ARCIUM MPC ACCOUNTS
-    // =========================================================================
+    /// Pool event sequence (stamps `seq` into emitted events)
     #[account(
-        init_if_needed,
-        space = 9,
-        payer = payer,
-        seeds = [&SIGN_PDA_SEED],
-        bump,
-        address = derive_sign_pda!(),
+        mut,
+        seeds = [EVENT_CURSOR_SEED, &[pool.pool_id]],
+        bump = event_cursor.bump,
     )]
-    pub sign_pda_account: Box<Account<'info, ArciumSignerAccount>>,
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+}
 
-    #[account(address = derive_mxe_pda!())]
-    pub mxe_account: Box<Account<'info, MXEAccount>>,
+#[derive(Accounts)]
+pub struct SetBatchRateLimit<'info> {
+    /// Pool authority
+    #[account(constraint = roles.is_risk_manager(&pool, &authority.key()) @ ErrorCode::Unauthorized)]
+    pub authority: Signer<'info>,
 
     #[account(
         mut,
-        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
     )]
-    /// CHECK: mempool_account, checked by the arcium program.
-    pub mempool_account: UncheckedAccount<'info>,
+    pub pool: Box<Account<'info, Pool>>,
 
+    /// Role grants (admins and operators besides the pool's own)
     #[account(
-        mut,
-        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+        seeds = [ROLES_SEED, &[pool.pool_id]],
+        bump = roles.bump,
     )]
-    /// CHECK: executing_pool, checked by the arcium program.
-    pub executing_pool: UncheckedAccount<'info>,
+    pub roles: Box<Account<'info, Roles>>,
 
+    /// Pool event sequence (stamps `seq` into emitted events)
     #[account(
         mut,
-        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
+        seeds = [EVENT_CURSOR_SEED, &[pool.pool_id]],
+        bump = event_cursor.bump,
     )]
-    /// CHECK: computation_account, checked by the arcium program.
-    pub computation_account: UncheckedAccount<'info>,
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+}
 
-    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_ACCUMULATE_ORDER))]
-    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+#[derive(Accounts)]
+pub struct SetPrivacyFloor<'info> {
+    /// Pool authority
+    #[account(constraint = roles.is_risk_manager(&pool, &authority.key()) @ ErrorCode::Unauthorized)]
+    pub authority: Signer<'info>,
 
     #[account(
         mut,
-        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
     )]
-    pub cluster_account: Box<Account<'info, Cluster>>,
+    pub pool: Box<Account<'info, Pool>>,
 
+    /// Role grants (admins and operators besides the pool's own)
     #[account(
-        mut,
-        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+        seeds = [ROLES_SEED, &[pool.pool_id]],
+        bump = roles.bump,
     )]
-    pub pool_account: Box<Account<'info, FeePool>>,
+    pub roles: Box<Account<'info, Roles>>,
 
+    /// Pool event sequence (stamps `seq` into emitted events)
     #[account(
         mut,
-        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+        seeds = [EVENT_CURSOR_SEED, &[pool.pool_id]],
+        bump = event_cursor.bump,
     )]
-    pub clock_account: Box<Account<'info, ClockAccount>>,
-
-    pub system_program: Program<'info, System>,
-    pub arcium_program: Program<'info, Arcium>,
+    pub event_cursor: Box<Account<'info, EventCursor>>,
 }
 
-// =============================================================================
-// PLACE ORDER CALLBACK ACCOUNTS (Phase 8)
-// =============================================================================
-
-#[callback_accounts("accumulate_order")]
 #[derive(Accounts)]
-pub struct AccumulateOrderCallback<'info> {
-    pub arcium_program: Program<'info, Arcium>,
-
-    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_ACCUMULATE_ORDER))]
-    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
-
-    #[account(address = derive_mxe_pda!())]
-    pub mxe_account: Account<'info, MXEAccount>,
+pub struct SetRiskConfig<'info> {
+    /// Admin or risk manager (pays rent on first call)
+    #[account(
+        mut,
+        constraint = roles.is_risk_manager(&pool, &authority.key()) @ ErrorCode::Unauthorized,
+    )]
+    pub authority: Signer<'info>,
 
-    /// CHECK: computation_account, checked by arcium program.
-    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
 
-    #[account(address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet))]
-    pub cluster_account: Account<'info, Cluster>,
+    /// Role grants (admins and operators besides the pool's own)
+    #[account(
+        seeds = [ROLES_SEED, &[pool.pool_id]],
+        bump = roles.bump,
+    )]
+    pub roles: Box<Account<'info, Roles>>,
 
-    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
-    /// CHECK: instructions_sysvar
-    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = RiskConfig::SIZE,
+        seeds = [RISK_CONFIG_SEED, &[pool.pool_id]],
+        bump,
+    )]
+    pub risk_config: Box<Account<'info, RiskConfig>>,
 
-    // Application accounts (passed via CallbackAccount)
-    #[account(mut)]
-    pub user_account: Box<Account<'info, UserProfile>>,
+    pub system_program: Program<'info, System>,
 
-    #[account(mut)]
-    pub batch_accumulator: Box<Account<'info, BatchAccumulator>>,
+    /// Pool event sequence (stamps `seq` into emitted events)
+    #[account(
+        mut,
+        seeds = [EVENT_CURSOR_SEED, &[pool.pool_id]],
+        bump = event_cursor.bump,
+    )]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
 }
 
 // =============================================================================
-// EXECUTE BATCH ACCOUNTS (Phase 9)
+// EXECUTION MODE ACCOUNTS
 // =============================================================================
 
-#[queue_computation_accounts("reveal_batch", payer)]
 #[derive(Accounts)]
-#[instruction(computation_offset: u64)]
-pub struct ExecuteBatch<'info> {
-    #[account(mut)]
-    pub payer: Signer<'info>,
+pub struct SetExecutionMode<'info> {
+    /// Pool authority
+    #[account(constraint = roles.is_admin(&pool, &authority.key()) @ ErrorCode::Unauthorized)]
+    pub authority: Signer<'info>,
 
-    /// Batch accumulator to read state from
     #[account(
         mut,
-        seeds = [BATCH_ACCUMULATOR_SEED],
-        bump = batch_accumulator.bump,
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
     )]
-    pub batch_accumulator: Box<Account<'info, BatchAccumulator>>,
+    pub pool: Box<Account<'info, Pool>>,
 
-    /// BatchLog PDA to create (will be initialized in callback)
+    /// Role grants (admins and operators besides the pool's own)
     #[account(
-        init,
-        payer = payer,
-        space = BatchLog::SIZE,
-        seeds = [BATCH_LOG_SEED, &batch_accumulator.batch_id.to_le_bytes()],
-        bump,
+        seeds = [ROLES_SEED, &[pool.pool_id]],
+        bump = roles.bump,
     )]
-    pub batch_log: Box<Account<'info, BatchLog>>,
+    pub roles: Box<Account<'info, Roles>>,
 
-    // =========================================================================
-    // ARCIUM MPC ACCOUNTS
-    // =========================================================================
+    /// Pool event sequence (stamps `seq` into emitted events)
     #[account(
-        init_if_needed,
-        space = 9,
-        payer = payer,
-        seeds = [&SIGN_PDA_SEED],
-        bump,
-        address = derive_sign_pda!(),
+        mut,
+        seeds = [EVENT_CURSOR_SEED, &[pool.pool_id]],
+        bump = event_cursor.bump,
     )]
-    pub sign_pda_account: Box<Account<'info, ArciumSignerAccount>>,
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+}
+
+#[derive(Accounts)]
+pub struct SetMaxSlippage<'info> {
+    /// Pool authority
+    #[account(constraint = roles.is_risk_manager(&pool, &authority.key()) @ ErrorCode::Unauthorized)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
 
-    #[account(address = derive_mxe_pda!())]
-    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    /// Role grants (admins and operators besides the pool's own)
+    #[account(
+        seeds = [ROLES_SEED, &[pool.pool_id]],
+        bump = roles.bump,
+    )]
+    pub roles: Box<Account<'info, Roles>>,
 
+    /// Pool event sequence (stamps `seq` into emitted events)
     #[account(
         mut,
-        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+        seeds = [EVENT_CURSOR_SEED, &[pool.pool_id]],
+        bump = event_cursor.bump,
     )]
-    /// CHECK: mempool_account, checked by the arcium program.
-    pub mempool_account: UncheckedAccount<'info>,
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+}
 
+#[derive(Accounts)]
+#[instruction(batch_id: u64)]
+pub struct RouteSurplus<'info> {
+    /// Operator routes surpluses (same as batch execution)
     #[account(
-        mut,
-        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+        constraint = roles.is_operator(&pool, &operator.key()) @ ErrorCode::Unauthorized,
     )]
-    /// CHECK: executing_pool, checked by the arcium program.
-    pub executing_pool: UncheckedAccount<'info>,
+    pub operator: Signer<'info>,
 
+    /// Pool PDA - signs the swap CPI as owner of the vaults.
+    /// Must be mut because the swap adapter marks user_authority as mut.
     #[account(
         mut,
-        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
     )]
-    /// CHECK: computation_account, checked by the arcium program.
-    pub computation_account: UncheckedAccount<'info>,
+    pub pool: Box<Account<'info, Pool>>,
 
-    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_REVEAL_BATCH))]
-    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+    /// Role grants (admins and operators besides the pool's own)
+    #[account(
+        seeds = [ROLES_SEED, &[pool.pool_id]],
+        bump = roles.bump,
+    )]
+    pub roles: Box<Account<'info, Roles>>,
 
+    /// BatchLog whose pending route is filled
     #[account(
         mut,
-        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+        seeds = [BATCH_LOG_SEED, &[pool.pool_id], &batch_id.to_le_bytes()],
+        bump = batch_log.bump,
     )]
-    pub cluster_account: Box<Account<'info, Cluster>>,
+    pub batch_log: Box<Account<'info, BatchLog>>,
+
+    /// Mint of the surplus asset
+    pub source_mint: Box<Account<'info, Mint>>,
+
+    /// Mint of the asset bought with the surplus
+    pub destination_mint: Box<Account<'info, Mint>>,
 
+    /// Deposit vault holding the surplus (verified in handler)
     #[account(
         mut,
-        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+        token::mint = source_mint,
+        token::authority = pool,
     )]
-    pub pool_account: Box<Account<'info, FeePool>>,
+    pub source_vault: Box<Account<'info, TokenAccount>>,
 
+    /// Deposit vault receiving the venue output (verified in handler)
     #[account(
         mut,
-        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+        token::mint = destination_mint,
+        token::authority = pool,
     )]
-    pub clock_account: Box<Account<'info, ClockAccount>>,
-
-    pub system_program: Program<'info, System>,
-    pub arcium_program: Program<'info, Arcium>,
-}
-
-// =============================================================================
-// REVEAL BATCH CALLBACK ACCOUNTS (Phase 9)
-// =============================================================================
+    pub destination_vault: Box<Account<'info, TokenAccount>>,
 
-#[callback_accounts("reveal_batch")]
-#[derive(Accounts)]
-pub struct RevealBatchCallback<'info> {
-    pub arcium_program: Program<'info, Arcium>,
+    /// External swap program configured by governance
+    /// CHECK: Address verified against Pool.swap_program
+    #[account(address = pool.swap_program @ ErrorCode::InvalidSwapProgram)]
+    pub swap_program: UncheckedAccount<'info>,
 
-    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_REVEAL_BATCH))]
-    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    /// CHECK: Validated by the swap program during CPI
+    #[account(mut)]
+    pub swap_pool: UncheckedAccount<'info>,
 
-    #[account(address = derive_mxe_pda!())]
-    pub mxe_account: Account<'info, MXEAccount>,
+    /// Swap program vault receiving the surplus
+    /// CHECK: Validated by the swap program during CPI
+    #[account(mut)]
+    pub swap_source_vault: UncheckedAccount<'info>,
 
-    /// CHECK: computation_account, checked by arcium program.
-    pub computation_account: UncheckedAccount<'info>,
+    /// Swap program vault sending the output
+    /// CHECK: Validated by the swap program during CPI
+    #[account(mut)]
+    pub swap_dest_vault: UncheckedAccount<'info>,
 
-    #[account(address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet))]
-    pub cluster_account: Account<'info, Cluster>,
+    /// Reserve of the surplus asset (POLICY_BEST_QUOTE, verified in handler)
+    #[account(
+        mut,
+        token::mint = source_mint,
+        token::authority = pool,
+    )]
+    pub source_reserve: Option<Box<Account<'info, TokenAccount>>>,
 
-    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
-    /// CHECK: instructions_sysvar
-    pub instructions_sysvar: AccountInfo<'info>,
+    /// Reserve paying the output (POLICY_BEST_QUOTE, verified in handler)
+    #[account(
+        mut,
+        token::mint = destination_mint,
+        token::authority = pool,
+    )]
+    pub destination_reserve: Option<Box<Account<'info, TokenAccount>>>,
 
-    // Application accounts (passed via CallbackAccount)
-    #[account(mut)]
-    pub batch_accumulator: Box<Account<'info, BatchAccumulator>>,
+    /// Pool event sequence (stamps `seq` into emitted events)
+    #[account(
+        mut,
+        seeds = [EVENT_CURSOR_SEED, &[pool.pool_id]],
+        bump = event_cursor.bump,
+    )]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
 
-    #[account(mut)]
-    pub batch_log: Account<'info, BatchLog>,
-    // TODO: Re-add these accounts after testing callback limit
-    // pub pool: Box<Account<'info, Pool>>,
-    // pub vault_usdc: Box<Account<'info, TokenAccount>>,
-    // pub vault_tsla: Box<Account<'info, TokenAccount>>,
-    // pub vault_spy: Box<Account<'info, TokenAccount>>,
-    // pub vault_aapl: Box<Account<'info, TokenAccount>>,
-    // pub reserve_usdc: Box<Account<'info, TokenAccount>>,
-    // pub reserve_tsla: Box<Account<'info, TokenAccount>>,
-    // pub reserve_spy: Box<Account<'info, TokenAccount>>,
-    // pub reserve_aapl: Box<Account<'info, TokenAccount>>,
-    // pub token_program: Program<'info, Token>,
+    pub token_program: Program<'info, Token>,
 }
 
 // =============================================================================
-// EXECUTE SWAPS ACCOUNTS (Phase 9.5)
+// EXECUTE EXTERNAL SWAP ACCOUNTS (Jupiter v6)
 // =============================================================================
 
 #[derive(Accounts)]
 #[instruction(batch_id: u64)]
-pub struct ExecuteSwaps<'info> {
-    #[account(mut)]
-    pub payer: Signer<'info>,
-
-    /// Operator authorized to trigger swaps (same as batch execution)
+pub struct ExecuteExternalSwap<'info> {
+    /// Operator routes surpluses (same as route_surplus)
     #[account(
-        constraint = operator.key() == pool.operator @ ErrorCode::Unauthorized,
+        constraint = roles.is_operator(&pool, &operator.key()) @ ErrorCode::Unauthorized,
     )]
     pub operator: Signer<'info>,
 
-    /// Pool account for operator verification and PDA authority
+    /// Pool PDA - signs the Jupiter route as user transfer authority
     #[account(
-        seeds = [POOL_SEED],
+        seeds = [POOL_SEED, &[pool.pool_id]],
         bump = pool.bump,
     )]
     pub pool: Box<Account<'info, Pool>>,
 
-    /// BatchLog containing netting results (must be for matching batch_id)
+    /// Role grants (admins and operators besides the pool's own)
     #[account(
-        mut,
-        seeds = [BATCH_LOG_SEED, &batch_id.to_le_bytes()],
-        bump,
+        seeds = [ROLES_SEED, &[pool.pool_id]],
+        bump = roles.bump,
     )]
-    pub batch_log: Account<'info, BatchLog>,
+    pub roles: Box<Account<'info, Roles>>,
 
-    // =========================================================================
-    // VAULT ACCOUNTS (user deposits)
-    // =========================================================================
+    /// BatchLog whose pending route is filled
     #[account(
         mut,
-        seeds = [VAULT_SEED, VAULT_USDC_SEED],
-        bump,
+        seeds = [BATCH_LOG_SEED, &[pool.pool_id], &batch_id.to_le_bytes()],
+        bump = batch_log.bump,
     )]
-    pub vault_usdc: Box<Account<'info, TokenAccount>>,
+    pub batch_log: Box<Account<'info, BatchLog>>,
+
+    /// Deposit vault holding the surplus (verified in handler)
+    #[account(mut, token::authority = pool)]
+    pub source_vault: Box<Account<'info, TokenAccount>>,
+
+    /// Deposit vault receiving the route output (verified in handler)
+    #[account(mut, token::authority = pool)]
+    pub destination_vault: Box<Account<'info, TokenAccount>>,
 
+    /// Jupiter Aggregator v6
+    /// CHECK: Address verified against JUPITER_PROGRAM_ID
+    #[account(address = JUPITER_PROGRAM_ID @ ErrorCode::InvalidSwapProgram)]
+    pub jupiter_program: UncheckedAccount<'info>,
+
+    /// Pool event sequence (stamps `seq` into emitted events)
     #[account(
         mut,
-        seeds = [VAULT_SEED, VAULT_TSLA_SEED],
-        bump,
+        seeds = [EVENT_CURSOR_SEED, &[pool.pool_id]],
+        bump = event_cursor.bump,
     )]
-    pub vault_tsla: Box<Account<'info, TokenAccount>>,
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+}
 
+#[derive(Accounts)]
+pub struct FlushNettingBuffer<'info> {
+    /// Operator flushes buffers (same as batch execution)
     #[account(
-        mut,
-        seeds = [VAULT_SEED, VAULT_SPY_SEED],
-        bump,
+        constraint = roles.is_operator(&pool, &operator.key()) @ ErrorCode::Unauthorized,
     )]
-    pub vault_spy: Box<Account<'info, TokenAccount>>,
+    pub operator: Signer<'info>,
 
+    /// Pool PDA - signs the swap CPI as owner of the reserves.
+    /// Must be mut because the swap adapter marks user_authority as mut.
     #[account(
         mut,
-        seeds = [VAULT_SEED, VAULT_AAPL_SEED],
-        bump,
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
     )]
-    pub vault_aapl: Box<Account<'info, TokenAccount>>,
+    pub pool: Box<Account<'info, Pool>>,
 
-    // =========================================================================
-    // RESERVE ACCOUNTS (protocol liquidity)
-    // =========================================================================
+    /// Role grants (admins and operators besides the pool's own)
     #[account(
-        mut,
-        seeds = [RESERVE_SEED, RESERVE_USDC_SEED],
-        bump,
+        seeds = [ROLES_SEED, &[pool.pool_id]],
+        bump = roles.bump,
     )]
-    pub reserve_usdc: Box<Account<'info, TokenAccount>>,
+    pub roles: Box<Account<'info, Roles>>,
 
+    /// Idle batch accumulator slot holding the netting buffers
     #[account(
         mut,
-        seeds = [RESERVE_SEED, RESERVE_TSLA_SEED],
-        bump,
+        seeds = [BATCH_ACCUMULATOR_SEED, &[pool.pool_id], &[batch_accumulator.slot]],
+        bump = batch_accumulator.bump,
     )]
-    pub reserve_tsla: Box<Account<'info, TokenAccount>>,
+    pub batch_accumulator: Box<Account<'info, BatchAccumulator>>,
 
+    /// Mint of the buffered asset
+    pub source_mint: Box<Account<'info, Mint>>,
+
+    /// Mint of the other asset of the pair
+    pub destination_mint: Box<Account<'info, Mint>>,
+
+    /// Reserve holding the buffered asset (verified in handler)
     #[account(
         mut,
-        seeds = [RESERVE_SEED, RESERVE_SPY_SEED],
-        bump,
+        token::mint = source_mint,
+        token::authority = pool,
     )]
-    pub reserve_spy: Box<Account<'info, TokenAccount>>,
+    pub source_reserve: Box<Account<'info, TokenAccount>>,
 
+    /// Reserve receiving the venue output (verified in handler)
     #[account(
         mut,
-        seeds = [RESERVE_SEED, RESERVE_AAPL_SEED],
-        bump,
+        token::mint = destination_mint,
+        token::authority = pool,
     )]
-    pub reserve_aapl: Box<Account<'info, TokenAccount>>,
+    pub destination_reserve: Box<Account<'info, TokenAccount>>,
 
-    /// Token program for transfers
-    pub token_program: Program<'info, Token>,
+    /// External swap program configured by governance
+    /// CHECK: Address verified against Pool.swap_program
+    #[account(address = pool.swap_program @ ErrorCode::InvalidSwapProgram)]
+    pub swap_program: UncheckedAccount<'info>,
 
-    pub system_program: Program<'info, System>,
-}
+    /// CHECK: Validated by the swap program during CPI
+    #[account(mut)]
+    pub swap_pool: UncheckedAccount<'info>,
 
-// =============================================================================
-// SETTLE ORDER ACCOUNTS (Phase 10)
-// =============================================================================
+    /// Swap program vault receiving the buffered asset
+    /// CHECK: Validated by the swap program during CPI
+    #[account(mut)]
+    pub swap_source_vault: UncheckedAccount<'info>,
 
-#[queue_computation_accounts("calculate_payout", payer)]
-#[derive(Accounts)]
-#[instruction(computation_offset: u64, pubkey: [u8; 32], nonce: u128, pair_id: u8, direction: u8)]
-pub struct SettleOrder<'info> {
+    /// Swap program vault sending the output
+    /// CHECK: Validated by the swap program during CPI
     #[account(mut)]
-    pub payer: Signer<'info>,
+    pub swap_dest_vault: UncheckedAccount<'info>,
 
-    /// User settling the order
-    pub user: Signer<'info>,
+    pub token_program: Program<'info, Token>,
 
-    /// User's privacy account
+    /// Pool event sequence (stamps `seq` into emitted events)
     #[account(
         mut,
-        seeds = [USER_SEED, user.key().as_ref()],
-        bump = user_account.bump,
-        constraint = user_account.owner == user.key() @ ErrorCode::InvalidOwner,
-        constraint = user_account.pending_order.is_some() @ ErrorCode::NoPendingOrder,
+        seeds = [EVENT_CURSOR_SEED, &[pool.pool_id]],
+        bump = event_cursor.bump,
     )]
-    pub user_account: Box<Account<'info, UserProfile>>,
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+}
 
-    /// BatchLog for the batch being settled
+#[derive(Accounts)]
+pub struct SetMarketSchedule<'info> {
+    /// Pool authority
     #[account(
-        seeds = [BATCH_LOG_SEED, &user_account.pending_order.unwrap().batch_id.to_le_bytes()],
-        bump,
+        mut,
+        constraint = roles.is_admin(&pool, &authority.key()) @ ErrorCode::Unauthorized,
     )]
-    pub batch_log: Account<'info, BatchLog>,
+    pub authority: Signer<'info>,
 
-    // =========================================================================
-    // ARCIUM MPC ACCOUNTS
-    // =========================================================================
     #[account(
-        init_if_needed,
-        space = 9,
-        payer = payer,
-        seeds = [&SIGN_PDA_SEED],
-        bump,
-        address = derive_sign_pda!(),
+        mut,
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
     )]
-    pub sign_pda_account: Box<Account<'info, ArciumSignerAccount>>,
+    pub pool: Box<Account<'info, Pool>>,
 
-    #[account(address = derive_mxe_pda!())]
-    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    /// Role grants (admins and operators besides the pool's own)
+    #[account(
+        seeds = [ROLES_SEED, &[pool.pool_id]],
+        bump = roles.bump,
+    )]
+    pub roles: Box<Account<'info, Roles>>,
 
+    /// Market schedule (created on first call)
     #[account(
-        mut,
-        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+        init_if_needed,
+        payer = authority,
+        space = MarketSchedule::SIZE,
+        seeds = [MARKET_SCHEDULE_SEED, &[pool.pool_id]],
+        bump,
     )]
-    /// CHECK: mempool_account, checked by the arcium program.
-    pub mempool_account: UncheckedAccount<'info>,
+    pub market_schedule: Box<Account<'info, MarketSchedule>>,
+
+    pub system_program: Program<'info, System>,
 
+    /// Pool event sequence (stamps `seq` into emitted events)
     #[account(
         mut,
-        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+        seeds = [EVENT_CURSOR_SEED, &[pool.pool_id]],
+        bump = event_cursor.bump,
     )]
-    /// CHECK: executing_pool, checked by the arcium program.
-    pub executing_pool: UncheckedAccount<'info>,
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+}
 
+#[derive(Accounts)]
+pub struct SetPairPolicy<'info> {
+    /// Pool authority
     #[account(
         mut,
-        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
+        constraint = roles.is_admin(&pool, &authority.key()) @ ErrorCode::Unauthorized,
     )]
-    /// CHECK: computation_account, checked by the arcium program.
-    pub computation_account: UncheckedAccount<'info>,
-
-    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_CALCULATE_PAYOUT))]
-    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+    pub authority: Signer<'info>,
 
     #[account(
         mut,
-        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
     )]
-    pub cluster_account: Box<Account<'info, Cluster>>,
+    pub pool: Box<Account<'info, Pool>>,
 
+    /// Role grants (admins and operators besides the pool's own)
     #[account(
-        mut,
-        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+        seeds = [ROLES_SEED, &[pool.pool_id]],
+        bump = roles.bump,
     )]
-    pub pool_account: Box<Account<'info, FeePool>>,
+    pub roles: Box<Account<'info, Roles>>,
 
+    /// Execution policy (created on first call)
     #[account(
-        mut,
-        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+        init_if_needed,
+        payer = authority,
+        space = ExecutionPolicy::SIZE,
+        seeds = [EXECUTION_POLICY_SEED, &[pool.pool_id]],
+        bump,
     )]
-    pub clock_account: Box<Account<'info, ClockAccount>>,
+    pub execution_policy: Box<Account<'info, ExecutionPolicy>>,
 
     pub system_program: Program<'info, System>,
-    pub arcium_program: Program<'info, Arcium>,
-}
-
-// =============================================================================
-// CALCULATE PAYOUT CALLBACK ACCOUNTS (Phase 10)
-// =============================================================================
-
-#[callback_accounts("calculate_payout")]
-#[derive(Accounts)]
-pub struct CalculatePayoutCallback<'info> {
-    pub arcium_program: Program<'info, Arcium>,
-
-    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_CALCULATE_PAYOUT))]
-    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
-
-    #[account(address = derive_mxe_pda!())]
-    pub mxe_account: Account<'info, MXEAccount>,
-
-    /// CHECK: computation_account, checked by arcium program.
-    pub computation_account: UncheckedAccount<'info>,
-
-    #[account(address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet))]
-    pub cluster_account: Account<'info, Cluster>,
 
-    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
-    /// CHECK: instructions_sysvar
-    pub instructions_sysvar: AccountInfo<'info>,
-
-    // Application accounts (passed via CallbackAccount)
-    #[account(mut)]
-    pub user_account: Box<Account<'info, UserProfile>>,
+    /// Pool event sequence (stamps `seq` into emitted events)
+    #[account(
+        mut,
+        seeds = [EVENT_CURSOR_SEED, &[pool.pool_id]],
+        bump = event_cursor.bump,
+    )]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
 }
 
-// =============================================================================
-// LIQUIDITY MANAGEMENT ACCOUNTS (Protocol Reserves)
-// =============================================================================
-
 #[derive(Accounts)]
-#[instruction(asset_id: u8)]
-pub struct AddLiquidity<'info> {
-    #[account(mut)]
+pub struct ConfigurePair<'info> {
+    /// Pool authority
+    #[account(
+        mut,
+        constraint = roles.is_admin(&pool, &authority.key()) @ ErrorCode::Unauthorized,
+    )]
     pub authority: Signer<'info>,
 
     #[account(
-        seeds = [POOL_SEED],
+        mut,
+        seeds = [POOL_SEED, &[pool.pool_id]],
         bump = pool.bump,
     )]
-    pub pool: Account<'info, Pool>,
+    pub pool: Box<Account<'info, Pool>>,
 
-    /// Authority's token account (source of funds)
-    #[account(mut)]
-    pub authority_token_account: Account<'info, TokenAccount>,
+    /// Role grants (admins and operators besides the pool's own)
+    #[account(
+        seeds = [ROLES_SEED, &[pool.pool_id]],
+        bump = roles.bump,
+    )]
+    pub roles: Box<Account<'info, Roles>>,
 
-    /// Reserve vault for the specified asset (destination)
-    #[account(mut)]
-    pub reserve_vault: Account<'info, TokenAccount>,
+    /// Pair registry (created on first call)
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = PairRegistry::SIZE,
+        seeds = [PAIR_REGISTRY_SEED, &[pool.pool_id]],
+        bump,
+    )]
+    pub pair_registry: Box<Account<'info, PairRegistry>>,
 
-    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+
+    /// Pool event sequence (stamps `seq` into emitted events)
+    #[account(
+        mut,
+        seeds = [EVENT_CURSOR_SEED, &[pool.pool_id]],
+        bump = event_cursor.bump,
+    )]
+    pub event_cursor: Box<Account<'info, EventCursor>>,
 }
 
 #[derive(Accounts)]
-#[instruction(asset_id: u8)]
-pub struct RemoveLiquidity<'info> {
-    #[account(mut)]
+pub struct SetPriceBand<'info> {
+    /// Pool authority
+    #[account(
+        mut,
+        constraint = roles.is_risk_manager(&pool, &authority.key()) @ ErrorCode::Unauthorized,
+    )]
     pub authority: Signer<'info>,
 
     #[account(
-        seeds = [POOL_SEED],
+        mut,
+        seeds = [POOL_SEED, &[pool.pool_id]],
         bump = pool.bump,
     )]
-    pub pool: Account<'info, Pool>,
-
-    /// Authority's token account (destination)
-    #[account(mut)]
-    pub authority_token_account: Account<'info, TokenAccount>,
+    pub pool: Box<Account<'info, Pool>>,
 
-    /// Reserve vault for the specified asset (source)
-    #[account(mut)]
-    pub reserve_vault: Account<'info, TokenAccount>,
+    /// Role grants (admins and operators besides the pool's own)
+    #[account(
+        seeds = [ROLES_SEED, &[pool.pool_id]],
+        bump = roles.bump,
+    )]
+    pub roles: Box<Account<'info, Roles>>,
 
-    pub token_program: Program<'info, Token>,
-}
+    /// Pair registry (created on first call)
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = PairRegistry::SIZE,
+        seeds = [PAIR_REGISTRY_SEED, &[pool.pool_id]],
+        bump,
+    )]
+    pub pair_registry: Box<Account<'info, PairRegistry>>,
 
-// =============================================================================
-// INIT ACCUMULATE_ORDER COMPUTATION DEFINITION (Phase 8)
-// =============================================================================
+    pub system_program: Program<'info, System>,
 
-#[init_computation_definition_accounts("accumulate_order", payer)]
-#[derive(Accounts)]
-pub struct InitAccumulateOrderCompDef<'info> {
-    #[account(mut)]
-    pub payer: Signer<'info>,
+    /// Pool event sequence (stamps `seq` into emitted events)
     #[account(
         mut,
-        address = derive_mxe_pda!()
+        seeds = [EVENT_CURSOR_SEED, &[pool.pool_id]],
+        bump = event_cursor.bump,
     )]
-    pub mxe_account: Box<Account<'info, MXEAccount>>,
-    #[account(mut)]
-    /// CHECK: comp_def_account, checked by arcium program.
-    pub comp_def_account: UncheckedAccount<'info>,
-    #[account(mut, address = derive_mxe_lut_pda!(mxe_account.lut_offset_slot))]
-    /// CHECK: address_lookup_table, checked by arcium program.
-    pub address_lookup_table: UncheckedAccount<'info>,
-    #[account(address = LUT_PROGRAM_ID)]
-    /// CHECK: lut_program is the Address Lookup Table program.
-    pub lut_program: UncheckedAccount<'info>,
-    pub arcium_program: Program<'info, Arcium>,
-    pub system_program: Program<'info, System>,
+    pub event_cursor: Box<Account<'info, EventCursor>>,
 }
 
 // =============================================================================
-// INIT INIT_BATCH_STATE COMPUTATION DEFINITION (Phase 8)
+// DIAGNOSTICS ACCOUNTS
 // =============================================================================
 
-#[init_computation_definition_accounts("init_batch_state", payer)]
 #[derive(Accounts)]
-pub struct InitInitBatchStateCompDef<'info> {
-    #[account(mut)]
-    pub payer: Signer<'info>,
+pub struct ConfigureProfiling<'info> {
+    /// Pool authority
     #[account(
         mut,
-        address = derive_mxe_pda!()
+        constraint = roles.is_admin(&pool, &authority.key()) @ ErrorCode::Unauthorized,
     )]
-    pub mxe_account: Box<Account<'info, MXEAccount>>,
-    #[account(mut)]
-    /// CHECK: comp_def_account, checked by arcium program.
-    pub comp_def_account: UncheckedAccount<'info>,
-    #[account(mut, address = derive_mxe_lut_pda!(mxe_account.lut_offset_slot))]
-    /// CHECK: address_lookup_table, checked by arcium program.
-    pub address_lookup_table: UncheckedAccount<'info>,
-    #[account(address = LUT_PROGRAM_ID)]
-    /// CHECK: lut_program is the Address Lookup Table program.
-    pub lut_program: UncheckedAccount<'info>,
-    pub arcium_program: Program<'info, Arcium>,
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// Role grants (admins and operators besides the pool's own)
+    #[account(
+        seeds = [ROLES_SEED, &[pool.pool_id]],
+        bump = roles.bump,
+    )]
+    pub roles: Box<Account<'info, Roles>>,
+
+    /// Diagnostics account (created on first call)
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = Diagnostics::SIZE,
+        seeds = [DIAGNOSTICS_SEED, &[pool.pool_id]],
+        bump,
+    )]
+    pub diagnostics: Account<'info, Diagnostics>,
+
     pub system_program: Program<'info, System>,
 }
 
 // =============================================================================
-// INIT_BATCH_STATE QUEUE ACCOUNTS
+// TREASURY BUYBACK ACCOUNTS
 // =============================================================================
+// Governance config + operator crank for the buyback-and-burn module.
 
-#[queue_computation_accounts("init_batch_state", payer)]
 #[derive(Accounts)]
-#[instruction(computation_offset: u64)]
-pub struct InitBatchState<'info> {
-    #[account(mut)]
-    pub payer: Signer<'info>,
-
-    /// Batch accumulator to initialize
+pub struct ConfigureBuyback<'info> {
+    /// Pool authority (governance)
     #[account(
         mut,
-        seeds = [BATCH_ACCUMULATOR_SEED],
-        bump = batch_accumulator.bump,
+        constraint = roles.is_admin(&pool, &authority.key()) @ ErrorCode::Unauthorized,
     )]
-    pub batch_accumulator: Box<Account<'info, BatchAccumulator>>,
+    pub authority: Signer<'info>,
 
-    // =========================================================================
-    // ARCIUM MPC ACCOUNTS
-    // =========================================================================
     #[account(
-        init_if_needed,
-        space = 9,
-        payer = payer,
-        seeds = [&SIGN_PDA_SEED],
-        bump,
-        address = derive_sign_pda!(),
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
     )]
-    pub sign_pda_account: Box<Account<'info, ArciumSignerAccount>>,
-
-    #[account(address = derive_mxe_pda!())]
-    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    pub pool: Box<Account<'info, Pool>>,
 
+    /// Role grants (admins and operators besides the pool's own)
     #[account(
-        mut,
-        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+        seeds = [ROLES_SEED, &[pool.pool_id]],
+        bump = roles.bump,
     )]
-    /// CHECK: mempool_account, checked by the arcium program.
-    pub mempool_account: UncheckedAccount<'info>,
+    pub roles: Box<Account<'info, Roles>>,
 
+    /// Buyback configuration (created on first call)
     #[account(
-        mut,
-        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+        init_if_needed,
+        payer = authority,
+        space = BuybackConfig::SIZE,
+        seeds = [BUYBACK_CONFIG_SEED, &[pool.pool_id]],
+        bump,
     )]
-    /// CHECK: executing_pool, checked by the arcium program.
-    pub executing_pool: UncheckedAccount<'info>,
+    pub buyback_config: Box<Account<'info, BuybackConfig>>,
+
+    /// Token to buy back and burn
+    pub buyback_mint: Box<Account<'info, Mint>>,
 
+    /// Pool-owned vault that receives bought tokens before burning
     #[account(
-        mut,
-        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
+        init_if_needed,
+        payer = authority,
+        seeds = [BUYBACK_VAULT_SEED, &[pool.pool_id], buyback_mint.key().as_ref()],
+        bump,
+        token::mint = buyback_mint,
+        token::authority = pool,
     )]
-    /// CHECK: computation_account, checked by the arcium program.
-    pub computation_account: UncheckedAccount<'info>,
+    pub buyback_vault: Box<Account<'info, TokenAccount>>,
 
-    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_INIT_BATCH_STATE))]
-    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
 
+    /// Pool event sequence (stamps `seq` into emitted events)
     #[account(
         mut,
-        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+        seeds = [EVENT_CURSOR_SEED, &[pool.pool_id]],
+        bump = event_cursor.bump,
     )]
-    pub cluster_account: Box<Account<'info, Cluster>>,
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+}
 
+#[derive(Accounts)]
+pub struct ExecuteBuyback<'info> {
+    /// Operator runs the buyback crank
     #[account(
-        mut,
-        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+        constraint = roles.is_operator(&pool, &operator.key()) @ ErrorCode::Unauthorized,
     )]
-    pub pool_account: Box<Account<'info, FeePool>>,
+    pub operator: Signer<'info>,
 
+    /// Pool PDA - signs the swap CPI and the burn.
+    /// Must be mut because the swap adapter marks user_authority as mut.
     #[account(
         mut,
-        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+        seeds = [POOL_SEED, &[pool.pool_id]],
+        bump = pool.bump,
     )]
-    pub clock_account: Box<Account<'info, ClockAccount>>,
-
-    pub arcium_program: Program<'info, Arcium>,
-    pub system_program: Program<'info, System>,
-}
-
-// =============================================================================
-// INIT_BATCH_STATE CALLBACK ACCOUNTS
-// =============================================================================
+    pub pool: Box<Account<'info, Pool>>,
 
-#[callback_accounts("init_batch_state")]
-#[derive(Accounts)]
-pub struct InitBatchStateCallback<'info> {
-    pub arcium_program: Program<'info, Arcium>,
+    /// Role grants (admins and operators besides the pool's own)
     #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_INIT_BATCH_STATE)
+        seeds = [ROLES_SEED, &[pool.pool_id]],
+        bump = roles.bump,
     )]
-    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    pub roles: Box<Account<'info, Roles>>,
+
     #[account(
-        address = derive_mxe_pda!()
+        mut,
+        seeds = [BUYBACK_CONFIG_SEED, &[pool.pool_id]],
+        bump = buyback_config.bump,
     )]
-    pub mxe_account: Account<'info, MXEAccount>,
-    /// CHECK: computation_account, checked by arcium program via constraints in the callback context.
-    pub computation_account: UncheckedAccount<'info>,
+    pub buyback_config: Box<Account<'info, BuybackConfig>>,
+
+    #[account(address = pool.usdc_mint @ ErrorCode::InvalidMint)]
+    pub usdc_mint: Box<Account<'info, Mint>>,
+
+    /// Buyback token mint (mut for burn)
     #[account(
-        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+        mut,
+        address = buyback_config.buyback_mint @ ErrorCode::InvalidMint,
     )]
-    pub cluster_account: Account<'info, Cluster>,
-    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
-    /// CHECK: instructions_sysvar, checked by the account constraint
-    pub instructions_sysvar: AccountInfo<'info>,
+    pub buyback_mint: Box<Account<'info, Mint>>,
 
-    /// Batch accumulator to update with encrypted zeros
+    /// USDC reserve - source of fee USDC for the swap
     #[account(
         mut,
-        seeds = [BATCH_ACCUMULATOR_SEED],
-        bump = batch_accumulator.bump,
+        seeds = [RESERVE_SEED, &[pool.pool_id], RESERVE_USDC_SEED],
+        bump,
     )]
-    pub batch_accumulator: Box<Account<'info, BatchAccumulator>>,
-}
-
-// =============================================================================
-// INIT REVEAL_BATCH COMPUTATION DEFINITION (Phase 9)
-// =============================================================================
+    pub reserve_usdc: Box<Account<'info, TokenAccount>>,
 
-#[init_computation_definition_accounts("reveal_batch", payer)]
-#[derive(Accounts)]
-pub struct InitRevealBatchCompDef<'info> {
-    #[account(mut)]
-    pub payer: Signer<'info>,
+    /// Receives bought tokens, burned in the same instruction
     #[account(
         mut,
-        address = derive_mxe_pda!()
+        seeds = [BUYBACK_VAULT_SEED, &[pool.pool_id], buyback_mint.key().as_ref()],
+        bump,
     )]
-    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    pub buyback_vault: Box<Account<'info, TokenAccount>>,
+
+    /// External swap program configured by governance
+    /// CHECK: Address verified against BuybackConfig.swap_program
+    #[account(address = buyback_config.swap_program @ ErrorCode::InvalidSwapProgram)]
+    pub swap_program: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by the swap program during CPI
     #[account(mut)]
-    /// CHECK: comp_def_account, checked by arcium program.
-    pub comp_def_account: UncheckedAccount<'info>,
-    #[account(mut, address = derive_mxe_lut_pda!(mxe_account.lut_offset_slot))]
-    /// CHECK: address_lookup_table, checked by arcium program.
-    pub address_lookup_table: UncheckedAccount<'info>,
-    #[account(address = LUT_PROGRAM_ID)]
-    /// CHECK: lut_program is the Address Lookup Table program.
-    pub lut_program: UncheckedAccount<'info>,
-    pub arcium_program: Program<'info, Arcium>,
-    pub system_program: Program<'info, System>,
-}
+    pub swap_pool: UncheckedAccount<'info>,
 
-// =============================================================================
-// INIT CALCULATE_PAYOUT COMPUTATION DEFINITION (Phase 10)
-// =============================================================================
+    /// Swap program vault receiving USDC
+    /// CHECK: Validated by the swap program during CPI
+    #[account(mut)]
+    pub swap_source_vault: UncheckedAccount<'info>,
 
-#[init_computation_definition_accounts("calculate_payout", payer)]
-#[derive(Accounts)]
-pub struct InitCalculatePayoutCompDef<'info> {
+    /// Swap program vault sending the buyback token
+    /// CHECK: Validated by the swap program during CPI
     #[account(mut)]
-    pub payer: Signer<'info>,
+    pub swap_dest_vault: UncheckedAccount<'info>,
+
+    /// Pool event sequence (stamps `seq` into emitted events)
     #[account(
         mut,
-        address = derive_mxe_pda!()
+        seeds = [EVENT_CURSOR_SEED, &[pool.pool_id]],
+        bump = event_cursor.bump,
     )]
-    pub mxe_account: Box<Account<'info, MXEAccount>>,
-    #[account(mut)]
-    /// CHECK: comp_def_account, checked by arcium program.
-    pub comp_def_account: UncheckedAccount<'info>,
-    #[account(mut, address = derive_mxe_lut_pda!(mxe_account.lut_offset_slot))]
-    /// CHECK: address_lookup_table, checked by arcium program.
-    pub address_lookup_table: UncheckedAccount<'info>,
-    #[account(address = LUT_PROGRAM_ID)]
-    /// CHECK: lut_program is the Address Lookup Table program.
-    pub lut_program: UncheckedAccount<'info>,
-    pub arcium_program: Program<'info, Arcium>,
-    pub system_program: Program<'info, System>,
+    pub event_cursor: Box<Account<'info, EventCursor>>,
+
+    pub token_program: Program<'info, Token>,
 }
 
 // =============================================================================
@@ -3284,7 +18174,7 @@ pub struct Faucet<'info> {
     /// User's privacy account (tracks total claimed)
     #[account(
         mut,
-        seeds = [USER_SEED, user.key().as_ref()],
+        seeds = [USER_SEED, &[pool.pool_id], user.key().as_ref()],
         bump = user_account.bump,
     )]
     pub user_account: Box<Account<'info, UserProfile>>,
@@ -3295,11 +18185,18 @@ pub struct Faucet<'info> {
         constraint = user_usdc_account.owner == user.key() @ ErrorCode::InvalidOwner,
         constraint = user_usdc_account.mint == pool.usdc_mint @ ErrorCode::InvalidMint,
     )]
-    pub user_usdc_account: Box<Account<'info, TokenAccount>>,
+    pub user_usdc_account: Box<InterfaceAccount<'info, anchor_spl::token_interface::TokenAccount>>,
+
+    /// USDC mint (transfer_checked needs decimals)
+    #[account(
+        address = pool.usdc_mint @ ErrorCode::InvalidMint,
+        mint::token_program = token_program,
+    )]
+    pub usdc_mint: Box<InterfaceAccount<'info, anchor_spl::token_interface::Mint>>,
 
     /// Pool PDA (authority for vaults)
     #[account(
-        seeds = [POOL_SEED],
+        seeds = [POOL_SEED, &[pool.pool_id]],
         bump = pool.bump,
     )]
     pub pool: Box<Account<'info, Pool>>,
@@ -3307,12 +18204,12 @@ pub struct Faucet<'info> {
     /// Faucet USDC vault (source of tokens)
     #[account(
         mut,
-        seeds = [FAUCET_VAULT_SEED],
+        seeds = [FAUCET_VAULT_SEED, &[pool.pool_id]],
         bump,
         token::mint = pool.usdc_mint,
         token::authority = pool,
     )]
-    pub faucet_vault: Box<Account<'info, TokenAccount>>,
+    pub faucet_vault: Box<InterfaceAccount<'info, anchor_spl::token_interface::TokenAccount>>,
 
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, anchor_spl::token_interface::TokenInterface>,
 }
@@ -0,0 +1,281 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{
+    BPS_DENOMINATOR, PYTH_RECEIVER_PROGRAM_ID, SWITCHBOARD_ON_DEMAND_PROGRAM_ID,
+};
+use crate::errors::ErrorCode;
+use crate::state::OracleConfig;
+
+// =============================================================================
+// PYTH ORACLE
+// =============================================================================
+// execute_batch reads one Pyth PriceUpdateV2 account per asset with a feed
+// in the OracleConfig and snapshots the prices into the BatchLog, which the
+// reveal callback nets the batch at. A Switchboard pull feed can back each
+// asset up (see SWITCHBOARD FALLBACK below).
+//
+// The account is decoded by hand rather than through the Pyth receiver SDK.
+// Only fully verified updates are accepted, so the layout is fixed:
+//   8  discriminator
+//   32 write_authority
+//   1  verification_level (1 = Full)
+//   32 feed_id | 8 price (i64) | 8 conf (u64) | 4 exponent (i32)
+//   8  publish_time (i64) | ... (prev_publish_time, EMA, posted_slot)
+
+/// sha256("account:PriceUpdateV2")[0..8]
+const PRICE_UPDATE_V2_DISCRIMINATOR: [u8; 8] = [34, 241, 35, 99, 157, 126, 244, 205];
+
+/// VerificationLevel::Full (Partial carries a signature count and is rejected)
+const VERIFICATION_LEVEL_FULL: u8 = 1;
+
+/// Offset of the price message in a fully verified update
+const PRICE_MESSAGE_OFFSET: usize = 8 + 32 + 1;
+
+/// feed_id + price + conf + exponent + publish_time
+const PRICE_MESSAGE_LEN: usize = 32 + 8 + 8 + 4 + 8;
+
+/// Decimals of oracle prices (USDC base units per whole token)
+const PRICE_DECIMALS: i32 = 6;
+
+/// A Pyth price converted to USDC base units per whole token
+pub struct OraclePrice {
+    pub price: u64,
+    pub confidence: u64,
+}
+
+/// Read and validate a Pyth price update for `feed_id`.
+///
+/// Rejects updates not owned by the Pyth receiver, not fully verified, for
+/// another feed, published more than `max_age_secs` before `now`, or whose
+/// confidence interval exceeds `max_conf_bps` of the price.
+pub fn read_pyth_price(
+    info: &AccountInfo,
+    feed_id: &[u8; 32],
+    now: i64,
+    max_age_secs: i64,
+    max_conf_bps: u16,
+) -> Result<OraclePrice> {
+    require!(
+        *info.owner == PYTH_RECEIVER_PROGRAM_ID,
+        ErrorCode::InvalidPriceUpdate
+    );
+    let data = info.try_borrow_data()?;
+    require!(
+        data.len() >= PRICE_MESSAGE_OFFSET + PRICE_MESSAGE_LEN
+            && data[..8] == PRICE_UPDATE_V2_DISCRIMINATOR
+            && data[40] == VERIFICATION_LEVEL_FULL,
+        ErrorCode::InvalidPriceUpdate
+    );
+
+    let message = &data[PRICE_MESSAGE_OFFSET..];
+    require!(message[..32] == feed_id[..], ErrorCode::PriceFeedMismatch);
+    let price = i64::from_le_bytes(message[32..40].try_into().unwrap());
+    let conf = u64::from_le_bytes(message[40..48].try_into().unwrap());
+    let exponent = i32::from_le_bytes(message[48..52].try_into().unwrap());
+    let publish_time = i64::from_le_bytes(message[52..60].try_into().unwrap());
+
+    require!(price > 0, ErrorCode::InvalidPriceUpdate);
+    require!(
+        now.saturating_sub(publish_time) <= max_age_secs,
+        ErrorCode::PriceTooStale
+    );
+    require!(
+        conf as u128 * BPS_DENOMINATOR as u128 <= price as u128 * max_conf_bps as u128,
+        ErrorCode::PriceConfidenceTooWide
+    );
+
+    let price = scale_price(price as u64, exponent)?;
+    let confidence = scale_price(conf, exponent)?;
+    require!(price > 0, ErrorCode::InvalidPriceUpdate);
+    Ok(OraclePrice { price, confidence })
+}
+
+/// Convert `value * 10^exponent` into USDC base units (6 decimals)
+fn scale_price(value: u64, exponent: i32) -> Result<u64> {
+    let shift = exponent + PRICE_DECIMALS;
+    let scaled = if shift >= 0 {
+        10u128
+            .checked_pow(shift as u32)
+            .and_then(|factor| (value as u128).checked_mul(factor))
+    } else {
+        10u128
+            .checked_pow(shift.unsigned_abs())
+            .map(|factor| value as u128 / factor)
+    };
+    scaled
+        .and_then(|scaled| u64::try_from(scaled).ok())
+        .ok_or_else(|| ErrorCode::InvalidPriceUpdate.into())
+}
+
+// =============================================================================
+// SWITCHBOARD FALLBACK
+// =============================================================================
+// A Switchboard On-Demand PullFeedAccountData (zero-copy, repr(C)):
+//   8    discriminator
+//   2048 submissions (32 × 64)
+//   32 authority | 32 queue | 32 feed_hash | 8 initialized_at | 8 permissions
+//   8 max_variance | 4 min_responses | 32 name | 2 padding
+//   1 historical_result_idx | 1 min_sample_size
+//   8    last_update_timestamp (i64)
+//   8 lut_slot | 32 reserved
+//   result: 16 value (i128) | 16 std_dev (i128) | ...
+// Values carry 18 decimals.
+
+/// sha256("account:PullFeedAccountData")[0..8]
+const PULL_FEED_DISCRIMINATOR: [u8; 8] = [196, 27, 108, 196, 10, 215, 219, 40];
+
+/// Offset of last_update_timestamp
+const PULL_FEED_UPDATED_AT_OFFSET: usize = 2216;
+
+/// Offset of result.value (followed by result.std_dev)
+const PULL_FEED_RESULT_OFFSET: usize = 2264;
+
+/// Decimals of Switchboard values
+const SWITCHBOARD_DECIMALS: i32 = 18;
+
+/// Read and validate a Switchboard pull feed.
+///
+/// Rejects feeds not owned by Switchboard On-Demand, last updated more than
+/// `max_age_secs` before `now`, or whose standard deviation exceeds
+/// `max_conf_bps` of the price. The standard deviation is reported as the
+/// confidence.
+pub fn read_switchboard_price(
+    info: &AccountInfo,
+    now: i64,
+    max_age_secs: i64,
+    max_conf_bps: u16,
+) -> Result<OraclePrice> {
+    require!(
+        *info.owner == SWITCHBOARD_ON_DEMAND_PROGRAM_ID,
+        ErrorCode::InvalidPriceUpdate
+    );
+    let data = info.try_borrow_data()?;
+    require!(
+        data.len() >= PULL_FEED_RESULT_OFFSET + 32 && data[..8] == PULL_FEED_DISCRIMINATOR,
+        ErrorCode::InvalidPriceUpdate
+    );
+
+    let updated_at = i64::from_le_bytes(
+        data[PULL_FEED_UPDATED_AT_OFFSET..PULL_FEED_UPDATED_AT_OFFSET + 8]
+            .try_into()
+            .unwrap(),
+    );
+    let result = &data[PULL_FEED_RESULT_OFFSET..];
+    let value = i128::from_le_bytes(result[..16].try_into().unwrap());
+    let std_dev = i128::from_le_bytes(result[16..32].try_into().unwrap());
+
+    require!(value > 0 && std_dev >= 0, ErrorCode::InvalidPriceUpdate);
+    require!(
+        now.saturating_sub(updated_at) <= max_age_secs,
+        ErrorCode::PriceTooStale
+    );
+    let (value, std_dev) = (value as u128, std_dev as u128);
+    require!(
+        std_dev.saturating_mul(BPS_DENOMINATOR as u128)
+            <= value.saturating_mul(max_conf_bps as u128),
+        ErrorCode::PriceConfidenceTooWide
+    );
+
+    let factor = 10u128.pow((SWITCHBOARD_DECIMALS - PRICE_DECIMALS) as u32);
+    let price = u64::try_from(value / factor).map_err(|_| ErrorCode::InvalidPriceUpdate)?;
+    let confidence = u64::try_from(std_dev / factor).unwrap_or(u64::MAX);
+    require!(price > 0, ErrorCode::InvalidPriceUpdate);
+    Ok(OraclePrice { price, confidence })
+}
+
+/// Gap between two prices in bps of the lower one
+pub fn divergence_bps(a: u64, b: u64) -> u64 {
+    let (low, high) = if a < b { (a, b) } else { (b, a) };
+    ((high - low) as u128 * BPS_DENOMINATOR as u128 / low.max(1) as u128).min(u64::MAX as u128)
+        as u64
+}
+
+// =============================================================================
+// ASSET PRICE
+// =============================================================================
+// One asset's price under an OracleConfig: Pyth when the asset has a feed,
+// the Switchboard fallback when the Pyth update is stale, and a divergence
+// check when both are healthy. Only staleness switches sources: a missing,
+// mismatched or out-of-confidence account is an error, so a caller cannot
+// pick its source (or skip the divergence check) by omitting one. execute_batch and place_order_usd
+// both price through this, so a USD-sized order converts at the same source
+// the batch later nets at.
+
+/// Price of one asset read through the OracleConfig
+pub enum AssetPrice {
+    /// The asset has neither a Pyth nor a Switchboard feed
+    Unconfigured,
+    /// Price from the primary, or from the fallback when the primary is unusable
+    Price(OraclePrice),
+    /// Both sources are healthy but further apart than
+    /// OracleConfig.max_price_divergence_bps
+    Diverged {
+        primary: u64,
+        fallback: u64,
+        divergence_bps: u64,
+    },
+}
+
+/// Read `asset`'s price from its Pyth update and/or Switchboard feed.
+///
+/// `price_update` and `fallback_feed` are required when the asset has the
+/// corresponding feed configured. The fallback feed must be the configured
+/// account; the Pyth update must carry the configured feed id. A stale
+/// source is skipped when the other one is configured; any other error
+/// from either source fails the read.
+pub fn read_asset_price(
+    oracle: &OracleConfig,
+    asset: usize,
+    price_update: Option<&AccountInfo>,
+    fallback_feed: Option<&AccountInfo>,
+    now: i64,
+) -> Result<AssetPrice> {
+    let feed = oracle.feeds[asset];
+    let primary = (feed != Pubkey::default()).then(|| -> Result<OraclePrice> {
+        let info = price_update.ok_or(ErrorCode::PriceUpdateMissing)?;
+        read_pyth_price(
+            info,
+            &feed.to_bytes(),
+            now,
+            oracle.max_price_age_secs,
+            oracle.max_price_conf_bps,
+        )
+    });
+    let fallback_key = oracle.fallback_feeds[asset];
+    let fallback = (fallback_key != Pubkey::default()).then(|| -> Result<OraclePrice> {
+        let info = fallback_feed.ok_or(ErrorCode::PriceUpdateMissing)?;
+        require_keys_eq!(info.key(), fallback_key, ErrorCode::PriceFeedMismatch);
+        read_switchboard_price(
+            info,
+            now,
+            oracle.max_price_age_secs,
+            oracle.max_price_conf_bps,
+        )
+    });
+
+    let stale = |err: &anchor_lang::error::Error| *err == ErrorCode::PriceTooStale.into();
+    Ok(match (primary, fallback) {
+        (None, None) => AssetPrice::Unconfigured,
+        (Some(primary), None) => AssetPrice::Price(primary?),
+        (None, Some(fallback)) => AssetPrice::Price(fallback?),
+        (Some(Err(err)), Some(_)) | (Some(Ok(_)), Some(Err(err))) if !stale(&err) => {
+            return Err(err);
+        }
+        (Some(Err(_)), Some(fallback)) => AssetPrice::Price(fallback?),
+        (Some(Ok(primary)), Some(Err(_))) => AssetPrice::Price(primary),
+        (Some(Ok(primary)), Some(Ok(fallback))) => {
+            // Two healthy sources that disagree: one of them is wrong, so
+            // price at neither
+            let divergence = divergence_bps(primary.price, fallback.price);
+            if divergence > oracle.max_price_divergence_bps as u64 {
+                AssetPrice::Diverged {
+                    primary: primary.price,
+                    fallback: fallback.price,
+                    divergence_bps: divergence,
+                }
+            } else {
+                AssetPrice::Price(primary)
+            }
+        }
+    })
+}
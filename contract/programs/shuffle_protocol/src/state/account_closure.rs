@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+// =============================================================================
+// ACCOUNT CLOSURE (close_user_account)
+// =============================================================================
+// Side PDA of a UserProfile holding the closure queued by close_user_account.
+// The check_balances_zero circuit reveals only whether all four balances are
+// zero; its callback then closes the profile and this PDA.
+//
+// The balance nonces are snapshotted at queue time, as for KeyRotation: a
+// deposit or incoming transfer landing before the callback replaces a nonce,
+// and the callback aborts instead of closing an account that now holds funds.
+
+/// Closure queued for one owner's UserProfile.
+/// PDA derived with seeds: ["account_closure", pool_id, owner.key().as_ref()]
+#[account]
+pub struct AccountClosure {
+    /// Wallet the profile belongs to (same as UserProfile.owner)
+    pub owner: Pubkey,
+
+    /// Balance nonces (USDC, TSLA, SPY, AAPL) when the closure was queued
+    pub source_nonces: [u128; 4],
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl AccountClosure {
+    /// Size of the AccountClosure account in bytes.
+    ///
+    /// Calculation:
+    /// - 8 bytes: Anchor discriminator
+    /// - 32 bytes: owner (Pubkey)
+    /// - 64 bytes: source_nonces ([u128; 4])
+    /// - 1 byte: bump (u8)
+    pub const SIZE: usize = 8 + // discriminator
+        32 +  // owner
+        64 +  // source_nonces
+        1; // bump
+}
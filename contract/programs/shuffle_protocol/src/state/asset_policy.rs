@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+
+// =============================================================================
+// ASSET TRANSFER POLICY (Restricted tokenized stocks)
+// =============================================================================
+// Tokenized equities (xStocks-style) are often Token-2022 mints with a
+// transfer hook, and/or restrict holders to an issuer-maintained allowlist.
+// The policy records what the deposit and withdrawal paths must honour:
+// - token_program: SPL Token or Token-2022 (owner of the mint)
+// - transfer_hook_program: hook invoked on every transfer_checked
+// - allowlist_program: issuer program owning per-wallet allowlist entries
+//
+// Allowlist entries are PDAs of the issuer program with seeds
+// ["allowlist", mint, wallet]. An entry must exist for the depositing wallet
+// and for the withdrawal recipient.
+//
+// Only add_balance / sub_balance go through this policy. The built-in vaults
+// and reserves are created by initialize under the SPL Token program, and
+// vault <-> reserve moves during batch execution use it too; vaults of
+// assets listed with register_asset are created under the mint's program.
+
+/// Transfer restrictions for a single asset mint.
+/// PDA derived with seeds: ["asset_policy", pool_id, mint.key().as_ref()]
+#[account]
+pub struct AssetTransferPolicy {
+    /// Mint this policy applies to
+    pub mint: Pubkey,
+
+    /// Token program owning the mint (SPL Token or Token-2022)
+    pub token_program: Pubkey,
+
+    /// Mint decimals (required by transfer_checked)
+    pub decimals: u8,
+
+    /// Token-2022 transfer hook program (Pubkey::default() = no hook)
+    pub transfer_hook_program: Pubkey,
+
+    /// Issuer allowlist program (Pubkey::default() = no allowlist)
+    pub allowlist_program: Pubkey,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl AssetTransferPolicy {
+    /// Size of the AssetTransferPolicy account in bytes.
+    ///
+    /// Calculation:
+    /// - 8 bytes: Anchor discriminator
+    /// - 32 bytes: mint (Pubkey)
+    /// - 32 bytes: token_program (Pubkey)
+    /// - 1 byte: decimals (u8)
+    /// - 32 bytes: transfer_hook_program (Pubkey)
+    /// - 32 bytes: allowlist_program (Pubkey)
+    /// - 1 byte: bump (u8)
+    pub const SIZE: usize = 8 + // discriminator
+        32 +  // mint
+        32 +  // token_program
+        1 +   // decimals
+        32 +  // transfer_hook_program
+        32 +  // allowlist_program
+        1; // bump
+
+    /// Whether transfers must carry transfer-hook extra accounts
+    pub fn has_transfer_hook(&self) -> bool {
+        self.transfer_hook_program != Pubkey::default()
+    }
+
+    /// Whether holders must be on the issuer allowlist
+    pub fn requires_allowlist(&self) -> bool {
+        self.allowlist_program != Pubkey::default()
+    }
+}
@@ -0,0 +1,95 @@
+use anchor_lang::prelude::*;
+
+// =============================================================================
+// ASSET REGISTRY (Listed assets beyond the built-in four)
+// =============================================================================
+// USDC, TSLA, SPY and AAPL (asset IDs 0-3) are fixed in the Pool and keep
+// their named vault seeds. Further tokenized stocks are listed here with
+// register_asset, which records the mint and creates its deposit vault with
+// seeds ["vault", pool_id, mint] under the mint's token program (SPL Token or
+// Token-2022), so listing needs no program upgrade.
+//
+// Registered assets take IDs from NUM_BUILTIN_ASSETS upward in listing
+// order. Encrypted balances and the batch circuits still cover the four
+// built-in assets and six pairs; a registered asset becomes tradable once
+// the circuits and UserProfile are widened to carry it.
+//
+// wSOL is listed like any other asset (native mint, SPL Token). A
+// deposit_sol that wraps lamports straight into its vault, and unwrapping on
+// withdrawal, wait on the same widening: there is no encrypted balance yet to
+// credit the wrapped SOL to.
+
+/// Number of assets fixed in the Pool (USDC, TSLA, SPY, AAPL)
+pub const NUM_BUILTIN_ASSETS: u8 = 4;
+
+/// Maximum number of registered (non built-in) assets
+pub const MAX_REGISTERED_ASSETS: usize = 16;
+
+/// Maximum length of an asset symbol in bytes
+pub const MAX_ASSET_SYMBOL_LEN: usize = 8;
+
+/// A listed asset
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct AssetEntry {
+    /// Token mint
+    pub mint: Pubkey,
+
+    /// Deposit vault PDA (seeds: ["vault", pool_id, mint])
+    pub vault: Pubkey,
+
+    /// Mint decimals
+    pub decimals: u8,
+
+    /// Ticker, ASCII, zero-padded (e.g. "NVDA")
+    pub symbol: [u8; MAX_ASSET_SYMBOL_LEN],
+
+    /// Unix timestamp the asset was registered
+    pub registered_at: i64,
+}
+
+impl AssetEntry {
+    /// Serialized size of an AssetEntry in bytes:
+    /// 32 + 32 + 1 + 8 + 8
+    pub const SIZE: usize = 81;
+}
+
+/// Registry of listed assets.
+/// PDA derived with seeds: ["asset_registry", pool_id]
+#[account]
+pub struct AssetRegistry {
+    /// Number of registered assets (entries beyond it are empty)
+    pub asset_count: u8,
+
+    /// Registered assets; entry i has asset ID NUM_BUILTIN_ASSETS + i
+    pub assets: [AssetEntry; MAX_REGISTERED_ASSETS],
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl AssetRegistry {
+    /// Size of the AssetRegistry account in bytes.
+    ///
+    /// Calculation:
+    /// - 8 bytes: Anchor discriminator
+    /// - 1 byte: asset_count (u8)
+    /// - 1296 bytes: assets (16 × AssetEntry::SIZE)
+    /// - 1 byte: bump (u8)
+    pub const SIZE: usize = 8 + // discriminator
+        1 +   // asset_count
+        MAX_REGISTERED_ASSETS * AssetEntry::SIZE + // assets
+        1; // bump
+
+    /// Registered entries
+    pub fn registered(&self) -> &[AssetEntry] {
+        &self.assets[..self.asset_count as usize]
+    }
+
+    /// Asset ID of a registered mint
+    pub fn asset_id(&self, mint: &Pubkey) -> Option<u8> {
+        self.registered()
+            .iter()
+            .position(|entry| entry.mint == *mint)
+            .map(|index| NUM_BUILTIN_ASSETS + index as u8)
+    }
+}
@@ -0,0 +1,113 @@
+use anchor_lang::prelude::*;
+
+// =============================================================================
+// RESERVE AUCTION
+// =============================================================================
+// Protocol-level sealed-bid auction for excess reserve inventory.
+//
+// Flow:
+// 1. Authority opens an auction for a lot of TSLA/SPY/AAPL from reserves
+// 2. LPs / market makers submit encrypted USDC bids for the whole lot;
+//    the MPC keeps only the best bid (Enc<Mxe>) - losing bids are never revealed
+// 3. After the deadline, the MPC reveals the clearing price and winner index
+// 4. Winner claims: pays clearing_price USDC into reserves, receives the lot
+
+/// Maximum number of bids per auction (bidder index is passed to MPC as u8)
+pub const MAX_AUCTION_BIDS: usize = 16;
+
+/// Lifecycle of a reserve auction
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum AuctionStatus {
+    /// Waiting for init_auction_state MPC to produce encrypted zeros
+    Initializing,
+    /// Accepting encrypted bids until bid_deadline
+    Open,
+    /// Best bid met the reserve price - winner may claim
+    Cleared,
+    /// No bid met the reserve price - lot stays in reserves
+    NoWinner,
+    /// Winner paid and received the lot
+    Claimed,
+}
+
+/// A single sealed-bid auction of reserve inventory.
+///
+/// PDA derived with seeds: ["reserve_auction", pool_id, auction_id.to_le_bytes()]
+///
+/// NOTE: encrypted_state MUST stay the first field - the MPC reads it by
+/// offset (8 bytes discriminator, 2 ciphertexts × 32 bytes).
+#[account]
+pub struct ReserveAuction {
+    /// Encrypted AuctionState: [best_price, best_bidder]
+    pub encrypted_state: [[u8; 32]; 2],
+
+    /// MXE output nonce for next read (updated on each MPC callback)
+    pub mxe_nonce: u128,
+
+    /// Auction identifier (chosen by the authority)
+    pub auction_id: u64,
+
+    /// Asset sold from reserves (1=TSLA, 2=SPY, 3=AAPL)
+    pub asset_id: u8,
+
+    /// Amount of the asset being auctioned (base units)
+    pub lot_amount: u64,
+
+    /// Minimum total USDC price for the lot (plaintext, public)
+    pub reserve_price: u64,
+
+    /// Unix timestamp after which no bids are accepted
+    pub bid_deadline: i64,
+
+    /// Current lifecycle status
+    pub status: AuctionStatus,
+
+    /// Number of bids submitted
+    pub bid_count: u8,
+
+    /// Bidder wallets, indexed by the bid index passed to the MPC
+    pub bidders: [Pubkey; MAX_AUCTION_BIDS],
+
+    /// Revealed winning price (total USDC for the lot, 0 until cleared)
+    pub clearing_price: u64,
+
+    /// Winning bidder (default until cleared)
+    pub winner: Pubkey,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl ReserveAuction {
+    /// Size of the ReserveAuction account in bytes.
+    ///
+    /// Calculation:
+    /// - 8 bytes: Anchor discriminator
+    /// - 64 bytes: encrypted_state (2 × 32)
+    /// - 16 bytes: mxe_nonce (u128)
+    /// - 8 bytes: auction_id (u64)
+    /// - 1 byte: asset_id (u8)
+    /// - 8 bytes: lot_amount (u64)
+    /// - 8 bytes: reserve_price (u64)
+    /// - 8 bytes: bid_deadline (i64)
+    /// - 1 byte: status (enum)
+    /// - 1 byte: bid_count (u8)
+    /// - 16 * 32 bytes: bidders
+    /// - 8 bytes: clearing_price (u64)
+    /// - 32 bytes: winner (Pubkey)
+    /// - 1 byte: bump (u8)
+    pub const SIZE: usize = 8 + // discriminator
+        64 +  // encrypted_state
+        16 +  // mxe_nonce
+        8 +   // auction_id
+        1 +   // asset_id
+        8 +   // lot_amount
+        8 +   // reserve_price
+        8 +   // bid_deadline
+        1 +   // status
+        1 +   // bid_count
+        (MAX_AUCTION_BIDS * 32) + // bidders: 16 × 32 = 512
+        8 +   // clearing_price
+        32 +  // winner
+        1; // bump
+}
@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+
+use super::OrderTicket;
+
+// =============================================================================
+// BASKET ORDER (One encrypted order across several pairs)
+// =============================================================================
+// A basket sells one source asset into up to BASKET_LEGS pairs with encrypted
+// weights (e.g. 50% TSLA, 30% SPY, 20% AAPL from USDC). accumulate_basket
+// splits the amount inside MPC and returns each leg as an encrypted
+// OrderInput, which is stored here as an OrderTicket so every leg can be
+// settled with the regular calculate_payout circuit.
+//
+// A basket counts as a single order in the BatchLog: unsettled_count is only
+// decremented once every leg has settled.
+
+/// Number of legs in a basket order (must match the circuit)
+pub const BASKET_LEGS: usize = 3;
+
+/// BasketOrder.status: no basket in flight, a new one may be placed
+pub const BASKET_STATUS_EMPTY: u8 = 0;
+
+/// BasketOrder.status: accumulate_basket queued, waiting for the callback
+pub const BASKET_STATUS_QUEUED: u8 = 1;
+
+/// BasketOrder.status: legs stored, waiting for settlement
+pub const BASKET_STATUS_PLACED: u8 = 2;
+
+/// A user's basket order. One basket per user at a time; the account is
+/// reused for the next basket once every leg has settled.
+///
+/// PDA derived with seeds: ["basket_order", pool_id, owner.key().as_ref()]
+#[account]
+pub struct BasketOrder {
+    /// The wallet that owns this basket (same as UserProfile.owner)
+    pub owner: Pubkey,
+
+    /// Batch the basket was placed in
+    pub batch_id: u64,
+
+    /// Asset sold by every leg (0=USDC, 1=TSLA, 2=SPY, 3=AAPL)
+    pub source_asset_id: u8,
+
+    /// Lifecycle status (BASKET_STATUS_*)
+    pub status: u8,
+
+    /// Per-leg orders returned by MPC (batch_id set to the basket's batch)
+    pub legs: [OrderTicket; BASKET_LEGS],
+
+    /// Bitmask of legs that have settled (bit N = leg N)
+    pub settled_legs: u8,
+
+    /// Leg being settled by the in-flight calculate_payout computation
+    pub settling_leg: u8,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl BasketOrder {
+    /// Size of the BasketOrder account in bytes.
+    ///
+    /// Calculation:
+    /// - 8 bytes: Anchor discriminator
+    /// - 32 bytes: owner (Pubkey)
+    /// - 8 bytes: batch_id (u64)
+    /// - 1 byte: source_asset_id (u8)
+    /// - 1 byte: status (u8)
+    /// - 3 * 128 bytes: legs (OrderTicket)
+    /// - 1 byte: settled_legs (u8)
+    /// - 1 byte: settling_leg (u8)
+    /// - 1 byte: bump (u8)
+    pub const SIZE: usize = 8 + // discriminator
+        32 +  // owner
+        8 +   // batch_id
+        1 +   // source_asset_id
+        1 +   // status
+        (BASKET_LEGS * OrderTicket::SIZE) + // legs: 3 × 128 = 384
+        1 +   // settled_legs
+        1 +   // settling_leg
+        1; // bump
+
+    /// Whether leg `leg` has already settled
+    pub fn is_leg_settled(&self, leg: u8) -> bool {
+        self.settled_legs & (1 << leg) != 0
+    }
+
+    /// Whether every leg has settled
+    pub fn is_fully_settled(&self) -> bool {
+        self.settled_legs == (1 << BASKET_LEGS) - 1
+    }
+}
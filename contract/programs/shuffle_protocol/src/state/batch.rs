@@ -1,5 +1,8 @@
 use anchor_lang::prelude::*;
 
+use super::PairPolicy;
+use crate::constants::BPS_DENOMINATOR;
+
 // =============================================================================
 // BATCH ACCUMULATOR & BATCH LOG
 // =============================================================================
@@ -12,10 +15,39 @@ use anchor_lang::prelude::*;
 //   PairID_3: TSLA / SPY
 //   PairID_4: TSLA / AAPL
 //   PairID_5: SPY / AAPL
+//
+// Per-pair sharded accumulators: not implemented. The pair of an order is
+// encrypted (OrderTicket.pair_id) and the single accumulator is what keeps it
+// hidden: every order updates all 6 encrypted pair totals, so observers can't
+// tell which pair it touched. A PDA per pair_id would require the pair in
+// plaintext at placement, giving up that privacy for every order, and the
+// BatchLog, netting buffers and settlement all assume one batch_id across
+// pairs. Placement contention is instead reduced by the double-buffered
+// slots below, which let the next batch fill while the current one reveals.
+// If pair sharding is ever wanted, it should be an opt-in "public pair"
+// order path with its own accumulator and BatchLog series, leaving the
+// private omni-batch as is.
 
 /// Number of trading pairs supported (6 pairs from 4 assets)
 pub const NUM_PAIRS: usize = 6;
 
+/// BatchAccumulator.status: accepting orders
+pub const BATCH_STATUS_OPEN: u8 = 0;
+
+/// BatchAccumulator.status: reveal_batch queued, orders rejected until the
+/// callback reopens the slot (or recover_batch_reveal does after a timeout)
+pub const BATCH_STATUS_REVEALING: u8 = 1;
+
+/// reset_batch reason: an MPC callback aborted mid-batch
+pub const BATCH_RESET_REASON_ABORTED_CALLBACK: u8 = 0;
+/// reset_batch reason: mxe_nonce or ciphertexts no longer match the MPC state
+pub const BATCH_RESET_REASON_INCONSISTENT_STATE: u8 = 1;
+/// reset_batch reason: any other operational cause
+pub const BATCH_RESET_REASON_OTHER: u8 = 2;
+/// Emitted by flush_batch (not accepted by reset_batch): incident-response
+/// flush of the active batch
+pub const BATCH_RESET_REASON_EMERGENCY_FLUSH: u8 = 3;
+
 /// Per-pair encrypted totals within a batch.
 /// Stores the cumulative buy/sell pressure for a single trading pair.
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
@@ -26,10 +58,37 @@ pub struct PairAccumulator {
     pub encrypted_token_b_in: [u8; 32],
 }
 
-/// Transient batch state - encrypted accumulator for the currently active batch.
+/// Surplus of one pair held by the reserves across batches, waiting to be
+/// netted against an opposite surplus before being flushed externally.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct NettingBuffer {
+    /// Asset held (0=USDC, 1=TSLA, 2=SPY, 3=AAPL)
+    pub asset_id: u8,
+    /// Amount held (0 = buffer empty)
+    pub amount: u64,
+    /// Unix timestamp the buffer was opened; the window runs from here
+    pub opened_at: i64,
+}
+
+impl NettingBuffer {
+    /// Size of NettingBuffer in bytes: asset_id (1) + amount (8) + opened_at (8)
+    pub const SIZE: usize = 1 + 8 + 8;
+
+    /// Whether the buffer holds inventory still inside the netting window
+    pub fn is_live(&self, window_secs: i64, now: i64) -> bool {
+        self.amount > 0 && now <= self.opened_at.saturating_add(window_secs)
+    }
+}
+
+/// Transient batch state - encrypted accumulator for one batch.
 /// Reset after each batch execution.
 ///
-/// PDA derived with seeds: ["batch_accumulator"]
+/// Double-buffered: two accumulators (slots 0 and 1) alternate. Orders land
+/// in the active slot; execute_batch hands the active flag (and the next
+/// batch_id) to the other slot before queuing the reveal, so orders keep
+/// flowing into the next batch while the current one reveals and settles.
+///
+/// PDA derived with seeds: ["batch_accumulator", pool_id, slot]
 ///
 /// NOTE: BatchState in MPC has 12 encrypted u64 values (6 pairs × 2 totals each).
 /// order_count is tracked as plaintext on Solana and passed to MPC for batch_ready calculation.
@@ -38,7 +97,10 @@ pub struct BatchAccumulator {
     /// Current batch ID (incrementing)
     pub batch_id: u64,
 
-    /// Number of orders in current batch (plaintext, for batch_ready calculation)
+    /// Number of orders in current batch (plaintext, for batch_ready calculation).
+    /// Counts regular orders and baskets. A wallet can have one of each in the
+    /// same batch, so this is not a count of distinct wallets (see
+    /// `min_distinct_wallets`).
     pub order_count: u8,
 
     /// Encrypted accumulator state for each of the 6 pairs
@@ -49,6 +111,49 @@ pub struct BatchAccumulator {
 
     /// PDA bump seed
     pub bump: u8,
+
+    /// Lifecycle status of batch_id (BATCH_STATUS_OPEN / BATCH_STATUS_REVEALING).
+    /// Kept after the MPC-read fields so their byte offsets don't change.
+    pub status: u8,
+
+    /// Unix timestamp when the current reveal was queued (0 if none)
+    pub reveal_queued_at: i64,
+
+    /// Per-pair surplus held by the reserves across batches (cross-batch
+    /// netting). Updated by the reveal callback and flush_netting_buffer.
+    /// Only the idle slot (last revealed) holds the current buffers;
+    /// execute_batch copies them into the slot being revealed.
+    pub netting_buffers: [NettingBuffer; NUM_PAIRS],
+
+    /// Accumulator slot (0 or 1), part of the PDA seeds
+    pub slot: u8,
+
+    /// Whether this slot receives new orders (exactly one slot is active)
+    pub active: bool,
+
+    /// computation_offset of the reveal_batch currently queued (0 if none)
+    pub reveal_computation_offset: u64,
+
+    /// computation_offset of a reveal whose callback failed verification
+    /// (0 if none). retry_execute_batch may re-queue the reveal right away.
+    pub failed_computation_offset: u64,
+
+    /// Reveals re-queued by retry_execute_batch for this batch
+    pub reveal_retries: u8,
+
+    /// Layout version (see BatchAccumulator::VERSION). Stays the last field of the
+    /// versioned layout; fields added later go after it and migrate_batch_accumulator
+    /// zero-extends old accounts to them.
+    pub version: u8,
+
+    /// Whether init_batch_state_callback stored the slot's encrypted zeros.
+    /// init_batch_state refuses a slot that has them; reset_batch clears the
+    /// flag while it queues fresh ones.
+    pub state_initialized: bool,
+
+    /// Baskets among order_count. Baskets don't use UserProfile.pending_order,
+    /// so one wallet can add a basket and a regular order to the same batch.
+    pub basket_count: u8,
 }
 
 impl BatchAccumulator {
@@ -61,14 +166,77 @@ impl BatchAccumulator {
     /// - 6 * 64 bytes: pair_states (6 pairs × (32 + 32) bytes each) = 384
     /// - 16 bytes: mxe_nonce (u128)
     /// - 1 byte: bump (u8)
+    /// - 1 byte: status (u8)
+    /// - 8 bytes: reveal_queued_at (i64)
+    /// - 6 * 17 bytes: netting_buffers (6 pairs × NettingBuffer::SIZE)
+    /// - 1 byte: slot (u8)
+    /// - 1 byte: active (bool)
+    /// - 8 bytes: reveal_computation_offset (u64)
+    /// - 8 bytes: failed_computation_offset (u64)
+    /// - 1 byte: reveal_retries (u8)
+    /// - 1 byte: version (u8)
+    /// - 1 byte: state_initialized (bool)
+    /// - 1 byte: basket_count (u8)
     pub const SIZE: usize = 8 + // discriminator
         8 +   // batch_id
         1 +   // order_count
         (NUM_PAIRS * 64) + // pair_states: 6 × (32 + 32) = 384
         16 +  // mxe_nonce
-        1; // bump = 418 total
+        1 +   // bump
+        1 +   // status
+        8 +   // reveal_queued_at
+        (NUM_PAIRS * NettingBuffer::SIZE) + // netting_buffers: 6 × 17
+        1 +   // slot
+        1 +   // active
+        8 +   // reveal_computation_offset
+        8 +   // failed_computation_offset
+        1 +   // reveal_retries
+        1 +   // version
+        1 +   // state_initialized
+        1; // basket_count = 551 total
+
+    /// Current layout version, written on creation and by migrate_batch_accumulator.
+    /// 2 added state_initialized, 3 added basket_count.
+    pub const VERSION: u8 = 3;
+
+    /// Byte offset of `version`: SIZE minus version and the fields added
+    /// after it (state_initialized, basket_count). Accounts created before
+    /// versioning end right here (version 0).
+    pub const VERSION_OFFSET: usize = Self::SIZE - 1 - 1 - 1;
+
+    /// Whether the current batch accepts new orders
+    pub fn is_open(&self) -> bool {
+        self.status == BATCH_STATUS_OPEN
+    }
+
+    /// Whether an order queued for `batch_id` can still be accumulated here
+    /// (the slot was not handed over while the computation was in flight)
+    pub fn accepts(&self, batch_id: u64) -> bool {
+        self.active && self.is_open() && self.batch_id == batch_id
+    }
+
+    /// Lower bound on the distinct wallets in the batch, checked against
+    /// Pool.min_unique_users. Regular orders come from distinct wallets (one
+    /// pending order per UserProfile) and so do baskets (one BasketOrder per
+    /// owner), but a basket's owner may also have a regular order here.
+    pub fn min_distinct_wallets(&self) -> u8 {
+        let regular = self.order_count.saturating_sub(self.basket_count);
+        regular.max(self.basket_count)
+    }
+
+    /// Whether this slot is free to become the active one: not receiving
+    /// orders, not revealing, and holding no orders from a recovered reveal
+    pub fn is_idle(&self) -> bool {
+        !self.active && self.is_open() && self.order_count == 0
+    }
 }
 
+/// Fill ratio denominator: a side with this ratio executed completely.
+pub const FILL_RATIO_FULL: u32 = 1_000_000;
+
+/// Execution price denominator: quote units per base unit, times this.
+pub const EXECUTION_PRICE_SCALE: u64 = 1_000_000;
+
 /// Per-pair execution results after batch finalization (plaintext).
 /// Used for lazy settlement calculations.
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
@@ -77,16 +245,58 @@ pub struct PairResult {
     pub total_a_in: u64,
     /// Revealed total Token B input for this pair
     pub total_b_in: u64,
-    /// Amount of Token A held after netting + swap
+    /// Amount of Token A held after netting + swap (before the execution fee)
     pub final_pool_a: u64,
-    /// Amount of Token B held after netting + swap
+    /// Amount of Token B held after netting + swap (before the execution fee)
     pub final_pool_b: u64,
+    /// Fraction of total_a_in that executed (out of FILL_RATIO_FULL).
+    /// Below full when reserves could not absorb the whole A-side surplus;
+    /// the unfilled portion is refunded to A sellers at settlement.
+    pub fill_ratio_a: u32,
+    /// Fraction of total_b_in that executed (out of FILL_RATIO_FULL)
+    pub fill_ratio_b: u32,
+    /// Price both sides were netted at, in quote units per base unit
+    /// (scaled by EXECUTION_PRICE_SCALE). 0 if the pair did not execute.
+    /// Surplus filled by route_surplus executes at the venue's price instead.
+    pub execution_price: u64,
+}
+
+impl PairResult {
+    /// Size in bytes: 4 × 8 + 2 × 4 + 8 = 48
+    pub const SIZE: usize = 8 + 8 + 8 + 8 + 4 + 4 + 8;
+
+    /// Total Token A refunded to A sellers (upper bound on the sum of per-user refunds)
+    pub fn refund_a(&self) -> u64 {
+        unfilled_amount(self.total_a_in, self.fill_ratio_a)
+    }
+
+    /// Total Token B refunded to B sellers (upper bound on the sum of per-user refunds)
+    pub fn refund_b(&self) -> u64 {
+        unfilled_amount(self.total_b_in, self.fill_ratio_b)
+    }
+}
+
+/// Fraction of a side that executed, rounded up so refunds computed from it
+/// never exceed the tokens actually left unfilled
+pub fn fill_ratio(total_in: u64, unfilled: u64) -> u32 {
+    if unfilled == 0 || total_in == 0 {
+        return FILL_RATIO_FULL;
+    }
+    let filled = (total_in - unfilled) as u128 * FILL_RATIO_FULL as u128;
+    filled.div_ceil(total_in as u128) as u32
+}
+
+/// Unfilled part of `amount` for a given fill ratio - same formula as the
+/// calculate_payout circuit uses per order.
+fn unfilled_amount(amount: u64, fill_ratio: u32) -> u64 {
+    let unfilled_ratio = FILL_RATIO_FULL.saturating_sub(fill_ratio) as u128;
+    ((amount as u128 * unfilled_ratio) / FILL_RATIO_FULL as u128) as u64
 }
 
 /// Historical batch results - immutable plaintext record after execution.
 /// Used for user lazy settlement.
 ///
-/// PDA derived with seeds: ["batch_log", batch_id.to_le_bytes()]
+/// PDA derived with seeds: ["batch_log", pool_id, batch_id.to_le_bytes()]
 #[account]
 pub struct BatchLog {
     /// Batch ID this log corresponds to
@@ -98,11 +308,142 @@ pub struct BatchLog {
     /// Unix timestamp when batch was executed
     pub executed_at: i64,
 
+    /// Reserve balances [USDC, TSLA, SPY, AAPL] when execute_batch was queued
+    /// (capped by Pool.max_reserve_utilization_bps in reserve-only mode).
+    /// Caps how much net surplus the callback may route into reserves.
+    pub reserve_snapshot: [u64; 4],
+
     /// Whether vault↔reserve swaps have been executed for this batch
     pub swaps_executed: bool,
 
     /// PDA bump seed
     pub bump: u8,
+
+    /// Pool.frozen_assets when execute_batch was queued.
+    /// Pairs touching a frozen asset are not executed and fully refunded.
+    pub frozen_assets: u8,
+
+    /// Orders from this batch not yet settled or forfeited.
+    /// The log can be closed once this reaches zero.
+    pub unsettled_count: u8,
+
+    /// Pool.execution_mode when execute_batch was queued.
+    pub execution_mode: u8,
+
+    /// Spread charged on absorbed surplus, in basis points: Pool.reserve_spread_bps
+    /// in reserve-only mode, Pool.max_slippage_bps otherwise.
+    pub reserve_spread_bps: u16,
+
+    /// How much more of each asset the reserves may absorb this batch
+    /// (Pool.max_reserve_inventory minus the reserve balance, u64::MAX if unlimited).
+    pub inventory_headroom: [u64; 4],
+
+    /// Bitmask of pairs whose net surplus still awaits route_surplus
+    /// (external-only mode or a routing pair policy). Settlement of these
+    /// pairs is blocked until the realized fill is recorded.
+    pub pending_routes: u8,
+
+    /// ExecutionPolicy.pairs when execute_batch was queued
+    /// (all POLICY_POOL_MODE if no policy account exists).
+    pub pair_policies: [PairPolicy; NUM_PAIRS],
+
+    /// Surplus each pair leaves in its deposit vault for route_surplus
+    pub route_amounts: [u64; NUM_PAIRS],
+
+    /// Output route_surplus delivered to each pair's deposit vault.
+    /// execute_swaps nets routed amounts out of the vault↔reserve transfers.
+    pub routed_out: [u64; NUM_PAIRS],
+
+    /// Pool.netting_window_secs when execute_batch was queued.
+    pub netting_window_secs: i64,
+
+    /// Caller of the execute_batch that queued the reveal.
+    /// Receives the keeper reward from execute_swaps (see keeper_reward_bps).
+    pub executor: Pubkey,
+
+    /// Pool.approval_threshold_usdc when execute_batch was queued (0 = none).
+    pub approval_threshold_usdc: u64,
+
+    /// Revealed notional of the executing pairs, valued in USDC base units
+    /// at oracle prices. Set by the reveal callback; sizes the keeper reward.
+    pub notional_usdc: u64,
+
+    /// Co-operator that approved the batch (default = not approved).
+    /// Required before execute_swaps or route_surplus when the notional
+    /// exceeds approval_threshold_usdc.
+    pub approved_by: Pubkey,
+
+    /// Oracle prices [USDC, TSLA, SPY, AAPL] (USDC base units), read by
+    /// execute_batch, that the reveal callback netted and valued the batch at
+    pub oracle_prices: [u64; 4],
+
+    /// Slot the oracle prices were read in (execute_batch's slot).
+    /// Together with oracle_prices, oracle_confidence and each pair's
+    /// execution_price this settles disputes about execution rates from
+    /// on-chain data alone.
+    pub oracle_slot: u64,
+
+    /// Confidence interval of each oracle price, in the same units
+    /// (0 = exact, as for assets on the mock price)
+    pub oracle_confidence: [u64; 4],
+
+    /// Pool.execution_fee_bps when execute_batch was queued. The fee
+    /// actually charged is per pair, see pair_fee_bps.
+    pub execution_fee_bps: u16,
+
+    /// Pool.max_slippage_bps when execute_batch was queued.
+    pub max_slippage_bps: u16,
+
+    /// Minimum output each pair's routed surplus must return: its oracle
+    /// value minus max_slippage_bps. Set by the reveal callback, enforced by
+    /// route_surplus / execute_external_swap and rechecked by execute_swaps.
+    pub route_min_out: [u64; NUM_PAIRS],
+
+    /// Bitmask of pairs execute_swaps partially filled because a reserve
+    /// could no longer pay what the reveal callback netted against it.
+    pub shortfall_pairs: u8,
+
+    /// Pairs whose vault↔reserve transfers are done. Lets execute_swaps /
+    /// execute_pair_swaps resume a batch pair by pair; swaps_executed is set
+    /// once all are.
+    pub swap_executed: [bool; NUM_PAIRS],
+
+    /// Bitmask of pairs disabled in the PairRegistry when execute_batch was
+    /// queued. The reveal callback refunds their orders in full.
+    pub halted_pairs: u8,
+
+    /// Execution fee of each pair when execute_batch was queued. Withheld
+    /// from each side's output pool at settlement; execute_swaps accrues it
+    /// to Pool.fees_owed.
+    pub pair_fee_bps: [u16; NUM_PAIRS],
+
+    /// Price band of each pair when execute_batch was queued
+    /// (PairConfig.max_price_deviation_bps, 0 = none)
+    pub price_bands: [u16; NUM_PAIRS],
+
+    /// Bitmask of pairs the reveal callback netted outside their price band.
+    /// Non-zero holds execute_swaps and route_surplus until the authority
+    /// overrides the band (override_price_band).
+    pub price_band_breaches: u8,
+
+    /// Authority that accepted the out-of-band prices (default = not yet)
+    pub price_band_overridden_by: Pubkey,
+
+    /// Layout version (see BatchLog::VERSION). Stays the last field of the
+    /// versioned layout; fields added later go after it and migrate_batch_log
+    /// zero-extends old accounts to them.
+    pub version: u8,
+
+    /// CircuitRegistry version of reveal_batch when execute_batch was queued
+    /// (0 = unregistered, registry not passed, or a log from before version 2)
+    pub circuit_version: u16,
+
+    /// Pool.keeper_reward_bps when execute_batch was queued
+    pub keeper_reward_bps: u16,
+
+    /// Execution fees accrued by this batch's swaps so far, valued in USDC
+    /// base units. Caps the keeper reward.
+    pub fees_usdc: u64,
 }
 
 impl BatchLog {
@@ -111,14 +452,108 @@ impl BatchLog {
     /// Calculation:
     /// - 8 bytes: Anchor discriminator
     /// - 8 bytes: batch_id (u64)
-    /// - 6 * 32 bytes: results (6 pairs × (8 + 8 + 8 + 8) bytes each)
+    /// - 6 * 48 bytes: results (6 pairs × PairResult::SIZE)
     /// - 8 bytes: executed_at (i64)
+    /// - 32 bytes: reserve_snapshot (4 × u64)
     /// - 1 byte: swaps_executed (bool)
     /// - 1 byte: bump (u8)
+    /// - 1 byte: frozen_assets (u8)
+    /// - 1 byte: unsettled_count (u8)
+    /// - 1 byte: execution_mode (u8)
+    /// - 2 bytes: reserve_spread_bps (u16)
+    /// - 32 bytes: inventory_headroom (4 × u64)
+    /// - 1 byte: pending_routes (u8)
+    /// - 6 * 3 bytes: pair_policies (6 pairs × PairPolicy::SIZE)
+    /// - 48 bytes: route_amounts (6 × u64)
+    /// - 48 bytes: routed_out (6 × u64)
+    /// - 8 bytes: netting_window_secs (i64)
+    /// - 32 bytes: executor (Pubkey)
+    /// - 8 bytes: approval_threshold_usdc (u64)
+    /// - 8 bytes: notional_usdc (u64)
+    /// - 32 bytes: approved_by (Pubkey)
+    /// - 32 bytes: oracle_prices (4 × u64)
+    /// - 8 bytes: oracle_slot (u64)
+    /// - 32 bytes: oracle_confidence (4 × u64)
+    /// - 2 bytes: execution_fee_bps (u16)
+    /// - 2 bytes: max_slippage_bps (u16)
+    /// - 48 bytes: route_min_out (6 × u64)
+    /// - 1 byte: shortfall_pairs (u8)
+    /// - 6 bytes: swap_executed (6 × bool)
+    /// - 1 byte: halted_pairs (u8)
+    /// - 12 bytes: pair_fee_bps (6 × u16)
+    /// - 12 bytes: price_bands (6 × u16)
+    /// - 1 byte: price_band_breaches (u8)
+    /// - 32 bytes: price_band_overridden_by (Pubkey)
+    /// - 1 byte: version (u8)
+    /// - 2 bytes: circuit_version (u16)
+    /// - 2 bytes: keeper_reward_bps (u16)
+    /// - 8 bytes: fees_usdc (u64)
     pub const SIZE: usize = 8 + // discriminator
         8 +   // batch_id
-        (NUM_PAIRS * 32) + // results: 6 × (8 + 8 + 8 + 8)
+        (NUM_PAIRS * PairResult::SIZE) + // results: 6 × 48
         8 +   // executed_at
+        32 +  // reserve_snapshot
         1 +   // swaps_executed
-        1; // bump
+        1 +   // bump
+        1 +   // frozen_assets
+        1 +   // unsettled_count
+        1 +   // execution_mode
+        2 +   // reserve_spread_bps
+        32 +  // inventory_headroom
+        1 +   // pending_routes
+        (NUM_PAIRS * PairPolicy::SIZE) + // pair_policies: 6 × 3
+        48 +  // route_amounts
+        48 +  // routed_out
+        8 +   // netting_window_secs
+        32 +  // executor
+        8 +   // approval_threshold_usdc
+        8 +   // notional_usdc
+        32 +  // approved_by
+        32 +  // oracle_prices
+        8 +   // oracle_slot
+        32 +  // oracle_confidence
+        2 +   // execution_fee_bps
+        2 +   // max_slippage_bps
+        48 +  // route_min_out
+        1 +   // shortfall_pairs
+        6 +   // swap_executed
+        1 +   // halted_pairs
+        12 +  // pair_fee_bps
+        12 +  // price_bands
+        1 +   // price_band_breaches
+        32 +  // price_band_overridden_by
+        1 +   // version
+        2 +   // circuit_version
+        2 +   // keeper_reward_bps
+        8; // fees_usdc
+
+    /// Current layout version, written on creation and by migrate_batch_log.
+    /// 2 added circuit_version, 3 added keeper_reward_bps and fees_usdc.
+    pub const VERSION: u8 = 3;
+
+    /// Byte offset of `version`: SIZE minus version and the fields added
+    /// after it (circuit_version, keeper_reward_bps, fees_usdc). Accounts
+    /// created before versioning end right here (version 0).
+    pub const VERSION_OFFSET: usize = Self::SIZE - 1 - 2 - 2 - 8;
+
+    /// Whether the batch is above its approval threshold and still waits for
+    /// the co-operator (approve_batch)
+    pub fn awaiting_approval(&self) -> bool {
+        self.approval_threshold_usdc > 0
+            && self.notional_usdc > self.approval_threshold_usdc
+            && self.approved_by == Pubkey::default()
+    }
+
+    /// Whether a pair executed outside its price band and the authority has
+    /// not overridden it yet (override_price_band)
+    pub fn awaiting_price_override(&self) -> bool {
+        self.price_band_breaches != 0 && self.price_band_overridden_by == Pubkey::default()
+    }
+
+    /// Execution fee withheld from a side's output pool of a pair (rounded
+    /// down, so the pool paid out pro-rata never exceeds `pool_output - fee`)
+    pub fn execution_fee(&self, pair_id: usize, pool_output: u64) -> u64 {
+        ((pool_output as u128 * self.pair_fee_bps[pair_id] as u128) / BPS_DENOMINATOR as u128)
+            as u64
+    }
 }
@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+
+// =============================================================================
+// BENEFICIARY (Dead-man's switch)
+// =============================================================================
+// A user may nominate another privacy account to inherit their encrypted
+// balances. Once UserProfile.last_active_at is older than the configured
+// inactivity period, the beneficiary can sweep each asset balance into their
+// own account through the sweep_balance circuit.
+//
+// Any owner activity (deposit, withdrawal, transfer, order, settlement)
+// refreshes last_active_at and therefore cancels an eligible claim.
+
+/// Inheritance settings for a single owner.
+/// PDA derived with seeds: ["beneficiary", pool_id, owner.key().as_ref()]
+#[account]
+pub struct BeneficiaryConfig {
+    /// Wallet whose balances are inherited (same as UserProfile.owner)
+    pub owner: Pubkey,
+
+    /// Beneficiary wallet - balances move to its UserProfile
+    pub beneficiary: Pubkey,
+
+    /// Seconds of owner inactivity before the beneficiary may claim
+    pub inactivity_period_secs: i64,
+
+    /// Unix timestamp when the beneficiary was (re)registered
+    pub registered_at: i64,
+
+    /// Asset ID of the sweep currently queued in MPC
+    pub pending_asset_id: u8,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl BeneficiaryConfig {
+    /// Size of the BeneficiaryConfig account in bytes.
+    ///
+    /// Calculation:
+    /// - 8 bytes: Anchor discriminator
+    /// - 32 bytes: owner (Pubkey)
+    /// - 32 bytes: beneficiary (Pubkey)
+    /// - 8 bytes: inactivity_period_secs (i64)
+    /// - 8 bytes: registered_at (i64)
+    /// - 1 byte: pending_asset_id (u8)
+    /// - 1 byte: bump (u8)
+    pub const SIZE: usize = 8 + // discriminator
+        32 +  // owner
+        32 +  // beneficiary
+        8 +   // inactivity_period_secs
+        8 +   // registered_at
+        1 +   // pending_asset_id
+        1; // bump
+
+    /// Whether the owner has been inactive long enough for a claim
+    pub fn is_claimable(&self, last_active_at: i64, now: i64) -> bool {
+        now >= last_active_at.saturating_add(self.inactivity_period_secs)
+    }
+}
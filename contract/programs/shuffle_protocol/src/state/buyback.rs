@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::BPS_DENOMINATOR;
+
+// =============================================================================
+// TREASURY BUYBACK CONFIG
+// =============================================================================
+// Optional module: a governance-configured share of USDC fees is periodically
+// swapped into a designated token through the external swap adapter and the
+// purchased tokens are burned.
+//
+// The USDC budget is derived from Pool.total_fees_collected, so buybacks can
+// never spend more than fee_share_bps of the fees swept to the treasury.
+
+/// Buyback schedule, caps and lifetime counters.
+/// PDA derived with seeds: ["buyback_config", pool_id]
+#[account]
+pub struct BuybackConfig {
+    /// Whether execute_buyback is currently allowed
+    pub enabled: bool,
+
+    /// Token bought back and burned
+    pub buyback_mint: Pubkey,
+
+    /// External swap program used for buybacks (Jupiter / mock_jupiter)
+    pub swap_program: Pubkey,
+
+    /// Share of collected USDC fees allocated to buybacks, in basis points
+    pub fee_share_bps: u16,
+
+    /// Minimum seconds between two buybacks
+    pub min_interval_secs: i64,
+
+    /// Maximum USDC spent by a single buyback (base units)
+    pub max_usdc_per_execution: u64,
+
+    /// Unix timestamp of the last buyback (0 = never)
+    pub last_executed_at: i64,
+
+    /// Total USDC spent on buybacks (base units)
+    pub total_usdc_spent: u64,
+
+    /// Total buyback tokens burned (base units)
+    pub total_burned: u64,
+
+    /// Number of buybacks executed
+    pub executions: u64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl BuybackConfig {
+    /// Size of the BuybackConfig account in bytes.
+    ///
+    /// Calculation:
+    /// - 8 bytes: Anchor discriminator
+    /// - 1 byte: enabled (bool)
+    /// - 32 bytes: buyback_mint (Pubkey)
+    /// - 32 bytes: swap_program (Pubkey)
+    /// - 2 bytes: fee_share_bps (u16)
+    /// - 8 bytes: min_interval_secs (i64)
+    /// - 8 bytes: max_usdc_per_execution (u64)
+    /// - 8 bytes: last_executed_at (i64)
+    /// - 8 bytes: total_usdc_spent (u64)
+    /// - 8 bytes: total_burned (u64)
+    /// - 8 bytes: executions (u64)
+    /// - 1 byte: bump (u8)
+    pub const SIZE: usize = 8 + // discriminator
+        1 +   // enabled
+        32 +  // buyback_mint
+        32 +  // swap_program
+        2 +   // fee_share_bps
+        8 +   // min_interval_secs
+        8 +   // max_usdc_per_execution
+        8 +   // last_executed_at
+        8 +   // total_usdc_spent
+        8 +   // total_burned
+        8 +   // executions
+        1; // bump
+
+    /// USDC still available for buybacks given the protocol's collected fees.
+    pub fn available_budget(&self, total_fees_collected: u64) -> u64 {
+        let allocated = (total_fees_collected as u128 * self.fee_share_bps as u128
+            / BPS_DENOMINATOR as u128) as u64;
+        allocated.saturating_sub(self.total_usdc_spent)
+    }
+}
@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+
+// =============================================================================
+// CIRCUIT REGISTRY (Circuit versions on-chain)
+// =============================================================================
+// Circuit sources are pinned off-chain (offchain_circuits_snippet.rs, the
+// init_*_comp_def instructions); this PDA records which circuit hash each
+// computation definition runs and a version bumped on every upgrade_circuit.
+// execute_batch stamps the reveal_batch version into BatchLog.circuit_version,
+// so each batch can be traced to the circuit that revealed it.
+//
+// The registry is a record only: it does not change the Arcium computation
+// definition. Call upgrade_circuit when a comp def is (re)initialized with a
+// new circuit.
+
+/// Maximum number of computation definitions tracked
+pub const MAX_CIRCUITS: usize = 32;
+
+/// Registered circuit of one computation definition
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct CircuitEntry {
+    /// comp_def_offset of the computation definition
+    pub comp_def_offset: u32,
+    /// Hash of the circuit (as in circuit_hash!)
+    pub hash: [u8; 32],
+    /// 1 on first registration, bumped on every upgrade (0 = free entry)
+    pub version: u16,
+    /// Unix timestamp of the last upgrade
+    pub updated_at: i64,
+}
+
+impl CircuitEntry {
+    /// Size of CircuitEntry in bytes: comp_def_offset (4) + hash (32) +
+    /// version (2) + updated_at (8)
+    pub const SIZE: usize = 4 + 32 + 2 + 8;
+}
+
+/// Circuit hash and version per computation definition.
+/// PDA derived with seeds: ["circuit_registry", pool_id]
+#[account]
+pub struct CircuitRegistry {
+    /// Registered circuits (unused entries have version 0)
+    pub circuits: [CircuitEntry; MAX_CIRCUITS],
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl CircuitRegistry {
+    /// Size of the CircuitRegistry account in bytes.
+    ///
+    /// Calculation:
+    /// - 8 bytes: Anchor discriminator
+    /// - 32 * 46 bytes: circuits (MAX_CIRCUITS × CircuitEntry::SIZE)
+    /// - 1 byte: bump (u8)
+    pub const SIZE: usize = 8 + // discriminator
+        (MAX_CIRCUITS * CircuitEntry::SIZE) + // circuits: 32 × 46
+        1; // bump
+
+    /// Index of a computation definition's entry, if registered
+    pub fn find(&self, comp_def_offset: u32) -> Option<usize> {
+        self.circuits
+            .iter()
+            .position(|c| c.version > 0 && c.comp_def_offset == comp_def_offset)
+    }
+
+    /// Index of the first unused entry
+    pub fn free_entry(&self) -> Option<usize> {
+        self.circuits.iter().position(|c| c.version == 0)
+    }
+
+    /// Registered version of a computation definition (0 = unregistered)
+    pub fn version_of(&self, comp_def_offset: u32) -> u16 {
+        self.find(comp_def_offset)
+            .map_or(0, |i| self.circuits[i].version)
+    }
+}
@@ -0,0 +1,163 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+
+use super::{ExecutorRegistry, Pool};
+
+// =============================================================================
+// PROTOCOL CONFIG SNAPSHOT (export_config / apply_config)
+// =============================================================================
+// Every tunable Pool parameter plus the executor registry's minimum bond, as
+// one versioned blob. export_config writes it to the ConfigSnapshot PDA;
+// apply_config validates a blob and writes it back, so parameters can be
+// promoted from devnet to mainnet or restored after an incident.
+//
+// Keys, mints, the authority/operator/treasury and operational state
+// (paused, frozen assets, counters) are not part of the config. swap_program
+// and co_operator are cluster-specific and usually edited before applying.
+// Oracle feeds are cluster-specific too and live in their own OracleConfig
+// PDA (set_oracle_feed / set_oracle_params), outside the config.
+//
+// Bump CONFIG_VERSION whenever ProtocolConfig changes layout.
+
+/// Layout version of ProtocolConfig
+pub const CONFIG_VERSION: u16 = 8;
+
+/// Tunable protocol parameters
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct ProtocolConfig {
+    pub execution_trigger_count: u8,
+    pub min_active_pairs: u8,
+    pub execution_fee_bps: u16,
+    pub settlement_deadline_secs: i64,
+    pub callback_cu_price_micro: u64,
+    pub execution_mode: u8,
+    pub reserve_spread_bps: u16,
+    pub max_reserve_utilization_bps: u16,
+    pub max_reserve_inventory: [u64; 4],
+    pub swap_program: Pubkey,
+    pub netting_window_secs: i64,
+    pub keeper_reward_bps: u16,
+    pub co_operator: Pubkey,
+    pub approval_threshold_usdc: u64,
+    pub executor_gated: bool,
+    /// ExecutorRegistry.min_bond_lamports (0 if no registry)
+    pub min_executor_bond_lamports: u64,
+    pub max_batches_per_window: u16,
+    pub batch_window_secs: i64,
+    pub max_batch_wait_secs: i64,
+    pub max_slippage_bps: u16,
+    pub min_unique_users: u8,
+}
+
+impl ProtocolConfig {
+    /// Serialized size of ProtocolConfig in bytes:
+    /// 1 + 1 + 2 + 8 + 8 + 1 + 2 + 2 + 32 + 32 + 8 + 2 + 32 + 8 + 1 + 8 + 2 + 8 + 8 + 2 + 1
+    pub const SIZE: usize = 169;
+
+    /// Read the current parameters
+    pub fn capture(pool: &Pool, registry: Option<&ExecutorRegistry>) -> Self {
+        Self {
+            execution_trigger_count: pool.execution_trigger_count,
+            min_active_pairs: pool.min_active_pairs,
+            execution_fee_bps: pool.execution_fee_bps,
+            settlement_deadline_secs: pool.settlement_deadline_secs,
+            callback_cu_price_micro: pool.callback_cu_price_micro,
+            execution_mode: pool.execution_mode,
+            reserve_spread_bps: pool.reserve_spread_bps,
+            max_reserve_utilization_bps: pool.max_reserve_utilization_bps,
+            max_reserve_inventory: pool.max_reserve_inventory,
+            swap_program: pool.swap_program,
+            netting_window_secs: pool.netting_window_secs,
+            keeper_reward_bps: pool.keeper_reward_bps,
+            co_operator: pool.co_operator,
+            approval_threshold_usdc: pool.approval_threshold_usdc,
+            executor_gated: pool.executor_gated,
+            min_executor_bond_lamports: registry.map_or(0, |r| r.min_bond_lamports),
+            max_batches_per_window: pool.max_batches_per_window,
+            batch_window_secs: pool.batch_window_secs,
+            max_batch_wait_secs: pool.max_batch_wait_secs,
+            max_slippage_bps: pool.max_slippage_bps,
+            min_unique_users: pool.min_unique_users,
+        }
+    }
+
+    /// Write the Pool parameters (the registry bond is written by the caller)
+    pub fn apply_to(&self, pool: &mut Pool) {
+        pool.execution_trigger_count = self.execution_trigger_count;
+        pool.min_active_pairs = self.min_active_pairs;
+        pool.execution_fee_bps = self.execution_fee_bps;
+        pool.settlement_deadline_secs = self.settlement_deadline_secs;
+        pool.callback_cu_price_micro = self.callback_cu_price_micro;
+        pool.execution_mode = self.execution_mode;
+        pool.reserve_spread_bps = self.reserve_spread_bps;
+        pool.max_reserve_utilization_bps = self.max_reserve_utilization_bps;
+        pool.max_reserve_inventory = self.max_reserve_inventory;
+        pool.swap_program = self.swap_program;
+        pool.netting_window_secs = self.netting_window_secs;
+        pool.keeper_reward_bps = self.keeper_reward_bps;
+        pool.co_operator = self.co_operator;
+        pool.approval_threshold_usdc = self.approval_threshold_usdc;
+        pool.executor_gated = self.executor_gated;
+        pool.max_batches_per_window = self.max_batches_per_window;
+        pool.batch_window_secs = self.batch_window_secs;
+        pool.max_batch_wait_secs = self.max_batch_wait_secs;
+        pool.max_slippage_bps = self.max_slippage_bps;
+        pool.min_unique_users = self.min_unique_users;
+    }
+
+    /// SHA-256 of the serialized config, to compare exported and applied blobs
+    pub fn hash(&self) -> [u8; 32] {
+        let mut data = Vec::with_capacity(Self::SIZE);
+        // Writing into a Vec cannot fail
+        let _ = self.serialize(&mut data);
+        hash(&data).to_bytes()
+    }
+}
+
+/// Last exported configuration.
+/// PDA derived with seeds: ["config_snapshot", pool_id]
+#[account]
+pub struct ConfigSnapshot {
+    /// CONFIG_VERSION the config was exported with
+    pub version: u16,
+
+    /// Number of exports so far
+    pub sequence: u64,
+
+    /// Unix timestamp of the export
+    pub exported_at: i64,
+
+    /// Authority that exported the config
+    pub exported_by: Pubkey,
+
+    /// SHA-256 of the serialized config
+    pub config_hash: [u8; 32],
+
+    /// Exported parameters
+    pub config: ProtocolConfig,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl ConfigSnapshot {
+    /// Size of the ConfigSnapshot account in bytes.
+    ///
+    /// Calculation:
+    /// - 8 bytes: Anchor discriminator
+    /// - 2 bytes: version (u16)
+    /// - 8 bytes: sequence (u64)
+    /// - 8 bytes: exported_at (i64)
+    /// - 32 bytes: exported_by (Pubkey)
+    /// - 32 bytes: config_hash ([u8; 32])
+    /// - 175 bytes: config (ProtocolConfig::SIZE)
+    /// - 1 byte: bump (u8)
+    pub const SIZE: usize = 8 + // discriminator
+        2 +   // version
+        8 +   // sequence
+        8 +   // exported_at
+        32 +  // exported_by
+        32 +  // config_hash
+        ProtocolConfig::SIZE + // config
+        1; // bump
+}
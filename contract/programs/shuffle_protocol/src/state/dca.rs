@@ -0,0 +1,132 @@
+use anchor_lang::prelude::*;
+
+// =============================================================================
+// DCA SCHEDULE
+// =============================================================================
+// Recurring order placed into the current batch on the user's behalf by a
+// permissionless crank (execute_dca).
+//
+// The order is encrypted once at creation (pair, direction, amount per
+// interval) and the same ciphertexts are re-submitted each interval, so the
+// crank never learns what is being bought. Each execution becomes the user's
+// pending_order and is settled like a manual order.
+//
+// Owners can pause a schedule without losing its PDA or encrypted order;
+// intervals that fall due while paused are counted in skipped_count.
+//
+// A USDC schedule can draw from a SavingsBucket (funding_bucket) instead of
+// the trading balance; the crank then passes the bucket and the order is
+// checked and deducted there.
+
+/// A user's recurring order schedule.
+///
+/// PDA derived with seeds: ["dca", pool_id, owner.key().as_ref(), dca_id.to_le_bytes()]
+#[account]
+pub struct DcaSchedule {
+    /// The wallet that owns this schedule
+    pub owner: Pubkey,
+
+    /// Schedule identifier (chosen by the owner, allows multiple schedules)
+    pub dca_id: u64,
+
+    /// User's x25519 public key used to encrypt the order
+    pub pubkey: [u8; 32],
+
+    /// Encrypted pair ID (0-5)
+    pub encrypted_pair_id: [u8; 32],
+
+    /// Encrypted direction: A_to_B (0) or B_to_A (1)
+    pub encrypted_direction: [u8; 32],
+
+    /// Encrypted amount placed each interval
+    pub encrypted_amount: [u8; 32],
+
+    /// Nonce used to encrypt the order
+    pub order_nonce: u128,
+
+    /// Plaintext hint: which asset is sold (0=USDC, 1=TSLA, 2=SPY, 3=AAPL)
+    pub source_asset_id: u8,
+
+    /// Seconds between executions
+    pub interval_secs: i64,
+
+    /// Unix timestamp at/after which the next execution is allowed
+    pub next_execution_ts: i64,
+
+    /// Number of executions so far
+    pub executions: u32,
+
+    /// Stop after this many executions (0 = unlimited)
+    pub max_executions: u32,
+
+    /// Unix timestamp when the schedule was created
+    pub created_at: i64,
+
+    /// Paused schedules cannot be executed until resumed
+    pub paused: bool,
+
+    /// Number of intervals skipped while paused
+    pub skipped_count: u32,
+
+    /// SavingsBucket the orders draw from (None = trading balance)
+    pub funding_bucket: Option<u8>,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl DcaSchedule {
+    /// Size of the DcaSchedule account in bytes.
+    ///
+    /// Calculation:
+    /// - 8 bytes: Anchor discriminator
+    /// - 32 bytes: owner (Pubkey)
+    /// - 8 bytes: dca_id (u64)
+    /// - 32 bytes: pubkey
+    /// - 32 bytes: encrypted_pair_id
+    /// - 32 bytes: encrypted_direction
+    /// - 32 bytes: encrypted_amount
+    /// - 16 bytes: order_nonce (u128)
+    /// - 1 byte: source_asset_id (u8)
+    /// - 8 bytes: interval_secs (i64)
+    /// - 8 bytes: next_execution_ts (i64)
+    /// - 4 bytes: executions (u32)
+    /// - 4 bytes: max_executions (u32)
+    /// - 8 bytes: created_at (i64)
+    /// - 1 byte: paused (bool)
+    /// - 4 bytes: skipped_count (u32)
+    /// - 2 bytes: funding_bucket (Option<u8>)
+    /// - 1 byte: bump (u8)
+    pub const SIZE: usize = 8 + // discriminator
+        32 +  // owner
+        8 +   // dca_id
+        32 +  // pubkey
+        32 +  // encrypted_pair_id
+        32 +  // encrypted_direction
+        32 +  // encrypted_amount
+        16 +  // order_nonce
+        1 +   // source_asset_id
+        8 +   // interval_secs
+        8 +   // next_execution_ts
+        4 +   // executions
+        4 +   // max_executions
+        8 +   // created_at
+        1 +   // paused
+        4 +   // skipped_count
+        2 +   // funding_bucket (Option)
+        1; // bump
+
+    /// Whether the schedule has used up all of its executions
+    pub fn is_complete(&self) -> bool {
+        self.max_executions != 0 && self.executions >= self.max_executions
+    }
+
+    /// Number of whole intervals that fell due before `now` without running.
+    /// The interval currently due (if any) is not counted - it can still run.
+    pub fn missed_intervals(&self, now: i64) -> u32 {
+        if now <= self.next_execution_ts {
+            return 0;
+        }
+        ((now - self.next_execution_ts) / self.interval_secs).min(u32::MAX as i64) as u32
+    }
+}
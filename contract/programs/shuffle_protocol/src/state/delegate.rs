@@ -0,0 +1,107 @@
+use anchor_lang::prelude::*;
+
+// =============================================================================
+// DELEGATE (Session keys)
+// =============================================================================
+// Lets a frontend-held session key place and settle encrypted orders for the
+// owner of a UserProfile without a wallet popup per order. The key cannot
+// deposit, transfer or change account settings, and can only withdraw an
+// asset it holds an allowance for (delegated_withdraw).
+//
+// Delegation is bounded by an expiry and a per-batch order limit; the order
+// counter resets whenever the delegate places into a new batch.
+//
+// Encrypted allowances: the owner may cap what the delegate can sell of an
+// asset (set_delegate_allowance). The allowance is an Enc<Shared> amount for
+// the owner's x25519 key. Once set, the delegate can only sell that asset
+// through place_delegated_order, whose circuit checks the order against the
+// allowance and decrements it - so a bot or third-party program can run a
+// strategy without access to the full balance. delegated_withdraw draws on
+// the same allowance, which bounds what a sub-account can take out.
+
+/// Session-key authorization for a single (owner, delegate) pair.
+/// PDA derived with seeds: ["delegate", pool_id, owner.key().as_ref(), delegate.key().as_ref()]
+#[account]
+pub struct Delegate {
+    /// Owner of the UserProfile
+    pub owner: Pubkey,
+
+    /// Secondary signer allowed to call place_order / settle_order
+    pub delegate: Pubkey,
+
+    /// Unix timestamp after which the delegation is no longer valid
+    pub expires_at: i64,
+
+    /// Maximum orders the delegate may place per batch
+    pub max_orders_per_batch: u8,
+
+    /// Batch the order counter refers to
+    pub current_batch_id: u64,
+
+    /// Orders placed by the delegate in current_batch_id
+    pub orders_in_batch: u8,
+
+    /// PDA bump seed
+    pub bump: u8,
+
+    /// Bitmask of assets with an allowance (bit N = asset_id N)
+    pub allowance_mask: u8,
+
+    /// Remaining encrypted allowance per asset [USDC, TSLA, SPY, AAPL]
+    pub allowances: [[u8; 32]; 4],
+
+    /// Encryption nonce of each allowance
+    pub allowance_nonces: [u128; 4],
+}
+
+impl Delegate {
+    /// Size of the Delegate account in bytes.
+    ///
+    /// Calculation:
+    /// - 8 bytes: Anchor discriminator
+    /// - 32 bytes: owner (Pubkey)
+    /// - 32 bytes: delegate (Pubkey)
+    /// - 8 bytes: expires_at (i64)
+    /// - 1 byte: max_orders_per_batch (u8)
+    /// - 8 bytes: current_batch_id (u64)
+    /// - 1 byte: orders_in_batch (u8)
+    /// - 1 byte: bump (u8)
+    /// - 1 byte: allowance_mask (u8)
+    /// - 128 bytes: allowances (4 × 32)
+    /// - 64 bytes: allowance_nonces (4 × u128)
+    pub const SIZE: usize = 8 + // discriminator
+        32 +  // owner
+        32 +  // delegate
+        8 +   // expires_at
+        1 +   // max_orders_per_batch
+        8 +   // current_batch_id
+        1 +   // orders_in_batch
+        1 +   // bump
+        1 +   // allowance_mask
+        128 + // allowances
+        64; // allowance_nonces
+
+    /// Whether the delegation is still valid
+    pub fn is_active(&self, now: i64) -> bool {
+        now < self.expires_at
+    }
+
+    /// Whether the delegate's orders selling `asset_id` draw on an allowance
+    pub fn has_allowance(&self, asset_id: u8) -> bool {
+        asset_id < 8 && self.allowance_mask & (1 << asset_id) != 0
+    }
+
+    /// Count an order placed in `batch_id`.
+    /// Returns false if the per-batch limit is already reached.
+    pub fn record_order(&mut self, batch_id: u64) -> bool {
+        if self.current_batch_id != batch_id {
+            self.current_batch_id = batch_id;
+            self.orders_in_batch = 0;
+        }
+        if self.orders_in_batch >= self.max_orders_per_batch {
+            return false;
+        }
+        self.orders_in_batch += 1;
+        true
+    }
+}
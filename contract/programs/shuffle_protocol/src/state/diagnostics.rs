@@ -0,0 +1,98 @@
+use anchor_lang::prelude::*;
+
+// =============================================================================
+// DIAGNOSTICS (Compute profiling)
+// =============================================================================
+// Optional on-chain profiling: when enabled, the batch and settlement
+// instructions record invocation counts and their most recent compute unit
+// usage so operators can spot CU regressions across releases.
+//
+// The account is optional everywhere it is used - clients that don't pass it
+// (or pass it while profiling is disabled) pay no extra compute.
+
+/// Number of instructions tracked by the diagnostics account
+pub const NUM_PROFILED_IXS: usize = 5;
+
+/// Number of recent CU samples kept per instruction
+pub const CU_SAMPLES: usize = 8;
+
+/// Profiled instruction slots
+pub const PROFILE_EXECUTE_BATCH: u8 = 0;
+pub const PROFILE_REVEAL_BATCH_CALLBACK: u8 = 1;
+pub const PROFILE_EXECUTE_SWAPS: u8 = 2;
+pub const PROFILE_SETTLE_ORDER: u8 = 3;
+pub const PROFILE_CALCULATE_PAYOUT_CALLBACK: u8 = 4;
+
+/// Counters for a single instruction.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct InstructionMetrics {
+    /// Number of recorded invocations
+    pub invocations: u64,
+
+    /// Highest CU usage seen since the last reset
+    pub max_cu: u32,
+
+    /// Index of the slot the next sample will be written to
+    pub sample_head: u8,
+
+    /// Ring buffer of recent CU usage samples
+    pub cu_samples: [u32; CU_SAMPLES],
+}
+
+impl InstructionMetrics {
+    /// Size in bytes: 8 + 4 + 1 + 8 * 4 = 45
+    pub const SIZE: usize = 8 + 4 + 1 + CU_SAMPLES * 4;
+}
+
+/// Per-instruction profiling counters.
+///
+/// PDA derived with seeds: ["diagnostics", pool_id]
+#[account]
+pub struct Diagnostics {
+    /// Whether instructions should record metrics
+    pub enabled: bool,
+
+    /// Unix timestamp of the last reset (e.g. at a program upgrade)
+    pub reset_at: i64,
+
+    /// Metrics indexed by PROFILE_* slot
+    pub metrics: [InstructionMetrics; NUM_PROFILED_IXS],
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl Diagnostics {
+    /// Size of the Diagnostics account in bytes.
+    ///
+    /// Calculation:
+    /// - 8 bytes: Anchor discriminator
+    /// - 1 byte: enabled (bool)
+    /// - 8 bytes: reset_at (i64)
+    /// - 5 * 45 bytes: metrics
+    /// - 1 byte: bump (u8)
+    pub const SIZE: usize = 8 + // discriminator
+        1 +   // enabled
+        8 +   // reset_at
+        (NUM_PROFILED_IXS * InstructionMetrics::SIZE) + // metrics: 5 × 45 = 225
+        1; // bump
+
+    /// Record one invocation of `ix` that consumed `cu_used` compute units.
+    pub fn record(&mut self, ix: u8, cu_used: u64) {
+        let Some(m) = self.metrics.get_mut(ix as usize) else {
+            return;
+        };
+        let cu = cu_used.min(u32::MAX as u64) as u32;
+
+        m.invocations += 1;
+        m.max_cu = m.max_cu.max(cu);
+        m.cu_samples[m.sample_head as usize] = cu;
+        m.sample_head = ((m.sample_head as usize + 1) % CU_SAMPLES) as u8;
+    }
+
+    /// Clear all counters.
+    pub fn reset(&mut self, now: i64) {
+        self.metrics = [InstructionMetrics::default(); NUM_PROFILED_IXS];
+        self.reset_at = now;
+    }
+}
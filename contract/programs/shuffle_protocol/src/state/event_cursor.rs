@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+// =============================================================================
+// EVENT CURSOR (Indexer gap detection)
+// =============================================================================
+// A per-pool sequence number stamped as `seq` into every business event the
+// pool emits: user balance and order events, batch lifecycle and settlement
+// events, fees, buybacks, DCA schedules and streams, as well as admin and
+// configuration changes, per-account settings, diagnostics and account
+// migrations. Sequence numbers start at 1 and increase by one per event, so
+// an indexer that sees seq 41 after seq 39 for a pool knows exactly one event
+// is missing and can backfill the transactions between the two slots instead
+// of replaying the full history.
+//
+// Throughput cost: every instruction that emits an event takes the pool's
+// cursor as a writable account, so the runtime never executes two of the
+// pool's transactions in parallel, even when they touch different users. A
+// pool's throughput is capped by what a single writable account allows.
+// Keeping one cursor per pool stops pools from contending with each other.
+//
+// Events carry seq 0 when they are not sequenced:
+// - the transaction then fails (the increment is rolled back), which is
+//   always the case for CallbackFailedEvent
+// - migrate_pool runs before the pool's cursor exists (init_event_cursor
+//   needs a current-layout Pool)
+// - SumEvent, from the demo add_together computation, belongs to no pool
+
+/// Per-pool event sequence.
+///
+/// PDA derived with seeds: ["event_cursor", pool_id]
+#[account]
+pub struct EventCursor {
+    /// Sequence number of the last stamped event (0 = none yet)
+    pub sequence: u64,
+
+    /// Slot of the last stamped event
+    pub last_slot: u64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl EventCursor {
+    /// Size of the EventCursor account in bytes.
+    ///
+    /// Calculation:
+    /// - 8 bytes: Anchor discriminator
+    /// - 8 bytes: sequence (u64)
+    /// - 8 bytes: last_slot (u64)
+    /// - 1 byte: bump (u8)
+    pub const SIZE: usize = 8 + // discriminator
+        8 +  // sequence
+        8 +  // last_slot
+        1; // bump
+
+    /// Claim the next sequence number for an event emitted in `slot`
+    pub fn next(&mut self, slot: u64) -> u64 {
+        self.sequence += 1;
+        self.last_slot = slot;
+        self.sequence
+    }
+}
@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+
+use super::NUM_PAIRS;
+
+// =============================================================================
+// EXECUTION POLICY (Per-pair surplus routing)
+// =============================================================================
+// Pool.execution_mode applies one behaviour to every pair. The policy
+// account overrides it per pair:
+// - POOL_MODE: follow Pool.execution_mode (the default for every pair)
+// - RESERVE_FIRST: reserves absorb up to reserve_share_bps of the net
+//   surplus (within the utilization/inventory limits), the rest is routed
+//   to the external venue with route_surplus
+// - BEST_QUOTE: the whole surplus waits for route_surplus, which fills it
+//   from the reserves instead of the venue when the reserve price beats the
+//   operator's venue quote
+//
+// Policies are snapshotted into the BatchLog by execute_batch, so a change
+// applies from the next batch executed.
+
+/// Follow Pool.execution_mode
+pub const POLICY_POOL_MODE: u8 = 0;
+/// Reserves first up to reserve_share_bps, remainder routed externally
+pub const POLICY_RESERVE_FIRST: u8 = 1;
+/// Best of the reserve price and the venue quote, decided at route time
+pub const POLICY_BEST_QUOTE: u8 = 2;
+
+/// Surplus routing policy for a single pair.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct PairPolicy {
+    /// POLICY_* constant
+    pub strategy: u8,
+    /// Share of the net surplus offered to the reserves (RESERVE_FIRST only)
+    pub reserve_share_bps: u16,
+}
+
+impl PairPolicy {
+    /// Size of PairPolicy in bytes: strategy (1) + reserve_share_bps (2)
+    pub const SIZE: usize = 1 + 2;
+}
+
+/// Per-pair execution policy.
+/// PDA derived with seeds: ["execution_policy", pool_id]
+#[account]
+pub struct ExecutionPolicy {
+    /// Policy of each of the 6 pairs
+    pub pairs: [PairPolicy; NUM_PAIRS],
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl ExecutionPolicy {
+    /// Size of the ExecutionPolicy account in bytes.
+    ///
+    /// Calculation:
+    /// - 8 bytes: Anchor discriminator
+    /// - 6 * 3 bytes: pairs (6 pairs × PairPolicy::SIZE)
+    /// - 1 byte: bump (u8)
+    pub const SIZE: usize = 8 + // discriminator
+        (NUM_PAIRS * PairPolicy::SIZE) + // pairs: 6 × 3
+        1; // bump
+}
@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+
+// =============================================================================
+// EXECUTOR REGISTRY (Bonded execution)
+// =============================================================================
+// With Pool.executor_gated set, only executors listed here may call
+// execute_batch and execute_swaps. Executors register themselves by posting
+// a lamport bond into this PDA; the authority can slash a misbehaving
+// executor, which removes it and sends its bond to the treasury.
+
+/// Maximum number of registered executors
+pub const MAX_EXECUTORS: usize = 8;
+
+/// One bonded executor
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct ExecutorEntry {
+    /// Executor wallet (default = free entry)
+    pub executor: Pubkey,
+    /// Lamports bonded
+    pub bond_lamports: u64,
+    /// Unix timestamp of registration
+    pub registered_at: i64,
+}
+
+impl ExecutorEntry {
+    /// Size of ExecutorEntry in bytes: executor (32) + bond_lamports (8) + registered_at (8)
+    pub const SIZE: usize = 32 + 8 + 8;
+}
+
+/// Bonded executor set.
+/// PDA derived with seeds: ["executor_registry", pool_id]
+#[account]
+pub struct ExecutorRegistry {
+    /// Minimum bond required to register
+    pub min_bond_lamports: u64,
+
+    /// Registered executors (unused entries are default)
+    pub executors: [ExecutorEntry; MAX_EXECUTORS],
+
+    /// Lamports slashed to the treasury so far
+    pub total_slashed: u64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl ExecutorRegistry {
+    /// Size of the ExecutorRegistry account in bytes.
+    ///
+    /// Calculation:
+    /// - 8 bytes: Anchor discriminator
+    /// - 8 bytes: min_bond_lamports (u64)
+    /// - 8 * 48 bytes: executors (MAX_EXECUTORS × ExecutorEntry::SIZE)
+    /// - 8 bytes: total_slashed (u64)
+    /// - 1 byte: bump (u8)
+    pub const SIZE: usize = 8 + // discriminator
+        8 +   // min_bond_lamports
+        (MAX_EXECUTORS * ExecutorEntry::SIZE) + // executors: 8 × 48
+        8 +   // total_slashed
+        1; // bump
+
+    /// Index of an executor's entry, if registered
+    pub fn find(&self, executor: &Pubkey) -> Option<usize> {
+        if *executor == Pubkey::default() {
+            return None;
+        }
+        self.executors.iter().position(|e| e.executor == *executor)
+    }
+
+    /// Index of the first unused entry
+    pub fn free_entry(&self) -> Option<usize> {
+        self.executors
+            .iter()
+            .position(|e| e.executor == Pubkey::default())
+    }
+
+    /// Whether an executor holds a bond
+    pub fn is_registered(&self, executor: &Pubkey) -> bool {
+        self.find(executor).is_some()
+    }
+}
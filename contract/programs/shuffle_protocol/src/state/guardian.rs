@@ -0,0 +1,121 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_GUARDIANS;
+
+// =============================================================================
+// GUARDIAN SET (Social recovery)
+// =============================================================================
+// An owner registers up to MAX_GUARDIANS guardian wallets and a threshold.
+// If the owner loses their wallet, guardians approve a recovery naming the
+// new owner wallet and the x25519 key of its privacy account. Once
+// `threshold` guardians agree, a challenge period starts during which the
+// owner can still cancel_recovery; after it the new owner runs
+// execute_recovery.
+//
+// A UserProfile's PDA is derived from its owner, so recovery moves the
+// account rather than editing it: the new owner creates a privacy account
+// with the approved key, the recover_account circuit adds the old balances
+// to it, and the callback closes the old profile and this set. Balance
+// nonces of both profiles are snapshotted at queue time, as in
+// rotate_user_key, so a balance update in between aborts the callback.
+//
+// Side PDAs of the old owner (beneficiary, buckets, delegates, streams, ...)
+// are not moved.
+
+/// Guardians and the recovery in progress for one owner.
+/// PDA derived with seeds: ["guardian_set", pool_id, owner.key().as_ref()]
+#[account]
+pub struct GuardianSet {
+    /// Wallet that can be recovered (same as UserProfile.owner)
+    pub owner: Pubkey,
+
+    /// Pool the owner's privacy account belongs to
+    pub pool_id: u8,
+
+    /// Guardian wallets (first guardian_count entries are used)
+    pub guardians: [Pubkey; MAX_GUARDIANS],
+
+    /// Number of registered guardians
+    pub guardian_count: u8,
+
+    /// Approvals needed to start the challenge period
+    pub threshold: u8,
+
+    /// Seconds between reaching the threshold and execute_recovery
+    pub challenge_period_secs: i64,
+
+    /// Wallet the recovery moves the account to (default = none proposed)
+    pub proposed_owner: Pubkey,
+
+    /// x25519 key the new owner's privacy account must use
+    pub proposed_user_pubkey: [u8; 32],
+
+    /// Bitmask of guardians (by index) approving the proposal
+    pub approvals: u8,
+
+    /// Unix timestamp the threshold was reached (0 = not yet)
+    pub approved_at: i64,
+
+    /// Old profile balance nonces (USDC, TSLA, SPY, AAPL) at execute_recovery
+    pub source_nonces: [u128; 4],
+
+    /// New profile balance nonces at execute_recovery
+    pub target_nonces: [u128; 4],
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl GuardianSet {
+    /// Size of the GuardianSet account in bytes.
+    ///
+    /// Calculation:
+    /// - 8 bytes: Anchor discriminator
+    /// - 32 bytes: owner (Pubkey)
+    /// - 1 byte: pool_id (u8)
+    /// - 160 bytes: guardians ([Pubkey; MAX_GUARDIANS])
+    /// - 1 byte: guardian_count (u8)
+    /// - 1 byte: threshold (u8)
+    /// - 8 bytes: challenge_period_secs (i64)
+    /// - 32 bytes: proposed_owner (Pubkey)
+    /// - 32 bytes: proposed_user_pubkey ([u8; 32])
+    /// - 1 byte: approvals (u8)
+    /// - 8 bytes: approved_at (i64)
+    /// - 64 bytes: source_nonces ([u128; 4])
+    /// - 64 bytes: target_nonces ([u128; 4])
+    /// - 1 byte: bump (u8)
+    pub const SIZE: usize = 8 + // discriminator
+        32 +  // owner
+        1 +   // pool_id
+        32 * MAX_GUARDIANS + // guardians
+        1 +   // guardian_count
+        1 +   // threshold
+        8 +   // challenge_period_secs
+        32 +  // proposed_owner
+        32 +  // proposed_user_pubkey
+        1 +   // approvals
+        8 +   // approved_at
+        64 +  // source_nonces
+        64 +  // target_nonces
+        1; // bump
+
+    /// Index of `wallet` among the registered guardians
+    pub fn guardian_index(&self, wallet: &Pubkey) -> Option<usize> {
+        self.guardians[..self.guardian_count as usize]
+            .iter()
+            .position(|g| g == wallet)
+    }
+
+    /// Whether a recovery has been proposed
+    pub fn has_proposal(&self) -> bool {
+        self.proposed_owner != Pubkey::default()
+    }
+
+    /// Drop the current proposal and its approvals
+    pub fn clear_proposal(&mut self) {
+        self.proposed_owner = Pubkey::default();
+        self.proposed_user_pubkey = [0u8; 32];
+        self.approvals = 0;
+        self.approved_at = 0;
+    }
+}
@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+
+use super::OrderTicket;
+
+// =============================================================================
+// ORDER HISTORY
+// =============================================================================
+// Per-user ring buffer of settled orders. Written by calculate_payout_callback
+// so users have an auditable on-chain record of their past fills.
+//
+// Amounts stay encrypted: each entry keeps the original OrderTicket and the
+// payout ciphertext, both decryptable only by the user.
+
+/// Number of settled orders kept per user before the oldest is overwritten
+pub const ORDER_HISTORY_CAPACITY: usize = 16;
+
+/// A single settled order record.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct OrderHistoryEntry {
+    /// The order as it was placed (batch_id, encrypted pair/direction/amount, nonce)
+    pub ticket: OrderTicket,
+
+    /// Encrypted payout credited to the user's output asset balance
+    pub encrypted_payout: [u8; 32],
+
+    /// Nonce of the payout ciphertext
+    pub payout_nonce: u128,
+
+    /// Unix timestamp when the settlement callback ran
+    pub settled_at: i64,
+}
+
+impl OrderHistoryEntry {
+    /// Size in bytes: 128 + 32 + 16 + 8 = 184
+    pub const SIZE: usize = OrderTicket::SIZE + 32 + 16 + 8;
+}
+
+/// Ring buffer of a user's most recent settled orders.
+///
+/// PDA derived with seeds: ["order_history", pool_id, owner.key().as_ref()]
+#[account]
+pub struct OrderHistory {
+    /// The wallet that owns this history (same as UserProfile.owner)
+    pub owner: Pubkey,
+
+    /// Index of the slot the next entry will be written to
+    pub head: u8,
+
+    /// Number of populated slots (saturates at ORDER_HISTORY_CAPACITY)
+    pub len: u8,
+
+    /// Total number of settlements ever recorded (never wraps)
+    pub total_settled: u64,
+
+    /// Settled order records, oldest entry at `head` once the buffer is full
+    pub entries: [OrderHistoryEntry; ORDER_HISTORY_CAPACITY],
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl OrderHistory {
+    /// Size of the OrderHistory account in bytes.
+    ///
+    /// Calculation:
+    /// - 8 bytes: Anchor discriminator
+    /// - 32 bytes: owner (Pubkey)
+    /// - 1 byte: head (u8)
+    /// - 1 byte: len (u8)
+    /// - 8 bytes: total_settled (u64)
+    /// - 16 * 184 bytes: entries
+    /// - 1 byte: bump (u8)
+    pub const SIZE: usize = 8 + // discriminator
+        32 +  // owner
+        1 +   // head
+        1 +   // len
+        8 +   // total_settled
+        (ORDER_HISTORY_CAPACITY * OrderHistoryEntry::SIZE) + // entries: 16 × 184 = 2944
+        1; // bump
+
+    /// Append an entry, overwriting the oldest one when the buffer is full.
+    pub fn push(&mut self, entry: OrderHistoryEntry) {
+        self.entries[self.head as usize] = entry;
+        self.head = ((self.head as usize + 1) % ORDER_HISTORY_CAPACITY) as u8;
+        if (self.len as usize) < ORDER_HISTORY_CAPACITY {
+            self.len += 1;
+        }
+        self.total_settled += 1;
+    }
+}
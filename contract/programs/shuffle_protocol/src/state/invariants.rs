@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+
+// =============================================================================
+// INVARIANT MONITOR (check_invariants checkpoint)
+// =============================================================================
+// check_invariants reports cross-account inconsistencies as a bitmask of
+// INVARIANT_* flags instead of failing, so monitoring can run it after every
+// batch and alert on a non-zero report. Counters that must only grow are
+// compared against the values seen by the previous run, stored here.
+
+/// Exactly one accumulator slot must be active
+pub const INVARIANT_ACTIVE_SLOT: u16 = 1 << 0;
+/// Accumulator slot/bump must derive the accumulator's address
+pub const INVARIANT_ACCUMULATOR_PDA: u16 = 1 << 1;
+/// The active slot's batch_id must be above the other slot's
+pub const INVARIANT_BATCH_IDS: u16 = 1 << 2;
+/// Batch lifecycle must match BatchLog existence: no log for the active
+/// batch, an unexecuted log for a revealing or recovered batch
+pub const INVARIANT_BATCH_LOG: u16 = 1 << 3;
+/// Vault and reserve mints must match the mints recorded on Pool
+pub const INVARIANT_VAULT_MINT: u16 = 1 << 4;
+/// Batch IDs and Pool counters must not decrease between runs
+pub const INVARIANT_STATS: u16 = 1 << 5;
+
+/// Values seen by the last check_invariants run.
+/// PDA derived with seeds: ["invariant_monitor", pool_id]
+#[account]
+pub struct InvariantMonitor {
+    /// Highest accumulator batch_id seen
+    pub last_batch_id: u64,
+
+    /// Pool.total_batches_executed seen
+    pub last_total_batches_executed: u64,
+
+    /// Pool.total_fees_collected seen
+    pub last_total_fees_collected: u64,
+
+    /// Unix timestamp of the last run
+    pub last_checked_at: i64,
+
+    /// Number of runs that reported at least one violation
+    pub failed_runs: u64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl InvariantMonitor {
+    /// Size of the InvariantMonitor account in bytes.
+    ///
+    /// Calculation:
+    /// - 8 bytes: Anchor discriminator
+    /// - 8 bytes: last_batch_id (u64)
+    /// - 8 bytes: last_total_batches_executed (u64)
+    /// - 8 bytes: last_total_fees_collected (u64)
+    /// - 8 bytes: last_checked_at (i64)
+    /// - 8 bytes: failed_runs (u64)
+    /// - 1 byte: bump (u8)
+    pub const SIZE: usize = 8 + // discriminator
+        8 +   // last_batch_id
+        8 +   // last_total_batches_executed
+        8 +   // last_total_fees_collected
+        8 +   // last_checked_at
+        8 +   // failed_runs
+        1; // bump
+}
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+
+// =============================================================================
+// KEY ROTATION (x25519 key recovery)
+// =============================================================================
+// Side PDA of a UserProfile holding the rotation queued by rotate_user_key.
+// The rotate_user_key circuit re-encrypts all four balances under the new
+// key; its callback swaps UserProfile.user_pubkey, credits and nonces in one
+// write.
+//
+// The balance nonces are snapshotted at queue time. Every balance update
+// replaces its nonce, so a callback finding different nonces knows its
+// re-encrypted balances are stale and aborts instead of overwriting them.
+
+/// Key rotation state of one owner.
+/// PDA derived with seeds: ["key_rotation", pool_id, owner.key().as_ref()]
+#[account]
+pub struct KeyRotation {
+    /// Wallet the profile belongs to (same as UserProfile.owner)
+    pub owner: Pubkey,
+
+    /// x25519 public key the queued rotation re-encrypts for
+    pub pending_user_pubkey: [u8; 32],
+
+    /// Balance nonces (USDC, TSLA, SPY, AAPL) when the rotation was queued
+    pub source_nonces: [u128; 4],
+
+    /// Number of completed rotations
+    pub rotation_count: u64,
+
+    /// Unix timestamp of the last completed rotation (0 = never)
+    pub rotated_at: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl KeyRotation {
+    /// Size of the KeyRotation account in bytes.
+    ///
+    /// Calculation:
+    /// - 8 bytes: Anchor discriminator
+    /// - 32 bytes: owner (Pubkey)
+    /// - 32 bytes: pending_user_pubkey ([u8; 32])
+    /// - 64 bytes: source_nonces ([u128; 4])
+    /// - 8 bytes: rotation_count (u64)
+    /// - 8 bytes: rotated_at (i64)
+    /// - 1 byte: bump (u8)
+    pub const SIZE: usize = 8 + // discriminator
+        32 +  // owner
+        32 +  // pending_user_pubkey
+        64 +  // source_nonces
+        8 +   // rotation_count
+        8 +   // rotated_at
+        1; // bump
+}
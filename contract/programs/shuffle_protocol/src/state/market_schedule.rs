@@ -0,0 +1,98 @@
+use anchor_lang::prelude::*;
+
+use super::PAIR_ASSETS;
+
+// =============================================================================
+// MARKET SCHEDULE (Trading hours of the tokenized equities)
+// =============================================================================
+// Tokenized stocks track an underlying that only trades during exchange
+// hours. Outside them the oracle keeps publishing the last close, so a batch
+// settled on a weekend would fill at a stale price. execute_batch halts every
+// pair touching an equity asset while the market is closed; USDC deposits,
+// withdrawals and transfers are unaffected.
+//
+// Enforcement is switched by Pool.market_hours_enforced rather than a flag
+// here, so a keeper cannot skip the schedule by leaving the account out of
+// execute_batch.
+//
+// Times are seconds after UTC midnight, so the authority moves them across
+// daylight-saving changes. Holidays are UTC day numbers (unix_timestamp /
+// 86400); an early close is entered as a holiday or by moving close_secs for
+// the day.
+
+/// Most holidays the schedule holds (a year of exchange holidays fits)
+pub const MAX_MARKET_HOLIDAYS: usize = 16;
+
+/// Seconds in a day
+pub const SECONDS_PER_DAY: i64 = 86_400;
+
+/// Trading hours of the equity assets.
+/// PDA derived with seeds: ["market_schedule", pool_id]
+#[account]
+pub struct MarketSchedule {
+    /// Market open, seconds after UTC midnight
+    pub open_secs: u32,
+
+    /// Market close, seconds after UTC midnight (> open_secs)
+    pub close_secs: u32,
+
+    /// Days the market opens (bit 0 = Monday ... bit 6 = Sunday)
+    pub trading_days: u8,
+
+    /// Closed UTC days (unix_timestamp / 86400); unused slots are 0
+    pub holidays: [u32; MAX_MARKET_HOLIDAYS],
+
+    /// Assets that follow the schedule (bit N = asset_id N)
+    pub equity_assets: u8,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl MarketSchedule {
+    /// Size of the MarketSchedule account in bytes.
+    ///
+    /// Calculation:
+    /// - 8 bytes: Anchor discriminator
+    /// - 4 bytes: open_secs (u32)
+    /// - 4 bytes: close_secs (u32)
+    /// - 1 byte: trading_days (u8)
+    /// - 16 * 4 bytes: holidays ([u32; 16])
+    /// - 1 byte: equity_assets (u8)
+    /// - 1 byte: bump (u8)
+    pub const SIZE: usize = 8 + // discriminator
+        4 +   // open_secs
+        4 +   // close_secs
+        1 +   // trading_days
+        (MAX_MARKET_HOLIDAYS * 4) + // holidays
+        1 +   // equity_assets
+        1; // bump
+
+    /// Whether the market is open at unix timestamp `now`
+    pub fn is_open(&self, now: i64) -> bool {
+        let day = now.div_euclid(SECONDS_PER_DAY);
+        let secs = now.rem_euclid(SECONDS_PER_DAY) as u32;
+        // 1970-01-01 was a Thursday (weekday 3 with Monday = 0)
+        let weekday = (day + 3).rem_euclid(7);
+        self.trading_days & (1 << weekday) != 0
+            && !self
+                .holidays
+                .iter()
+                .any(|holiday| *holiday != 0 && *holiday as i64 == day)
+            && secs >= self.open_secs
+            && secs < self.close_secs
+    }
+
+    /// Bitmask of pairs closed at `now` (bit N = pair_id N)
+    pub fn closed_pairs(&self, now: i64) -> u8 {
+        if self.is_open(now) {
+            return 0;
+        }
+        let follows = |asset: u8| self.equity_assets & (1 << asset) != 0;
+        PAIR_ASSETS
+            .iter()
+            .enumerate()
+            .filter(|(_, (base, quote))| follows(*base) || follows(*quote))
+            .fold(0, |mask, (pair_id, _)| mask | (1 << pair_id))
+    }
+}
@@ -7,10 +7,80 @@
 // Re-export all state structs for easy importing
 // Usage: `use crate::state::{Pool, UserProfile, BatchAccumulator, BatchLog};`
 
+mod account_closure;
+mod asset_policy;
+mod asset_registry;
+mod auction;
+mod basket;
 mod batch;
+mod beneficiary;
+mod buyback;
+mod circuit_registry;
+mod config;
+mod dca;
+mod delegate;
+mod diagnostics;
+mod event_cursor;
+mod execution_policy;
+mod executor;
+mod guardian;
+mod history;
+mod invariants;
+mod key_rotation;
+mod market_schedule;
+mod oracle_config;
+mod pair_registry;
+mod payment_stream;
+mod pending_operation;
 mod pool;
+mod rent_sponsor;
+mod risk_config;
+mod roles;
+mod savings_bucket;
+mod settlement;
+mod stats;
+mod transfer_memo;
+mod transfer_request;
 mod user;
+mod viewable_balance;
+mod viewer_grant;
+mod voucher;
 
+pub use account_closure::*;
+pub use asset_policy::*;
+pub use asset_registry::*;
+pub use auction::*;
+pub use basket::*;
 pub use batch::*;
+pub use beneficiary::*;
+pub use buyback::*;
+pub use circuit_registry::*;
+pub use config::*;
+pub use dca::*;
+pub use delegate::*;
+pub use diagnostics::*;
+pub use event_cursor::*;
+pub use execution_policy::*;
+pub use executor::*;
+pub use guardian::*;
+pub use history::*;
+pub use invariants::*;
+pub use key_rotation::*;
+pub use market_schedule::*;
+pub use oracle_config::*;
+pub use pair_registry::*;
+pub use payment_stream::*;
+pub use pending_operation::*;
 pub use pool::*;
+pub use rent_sponsor::*;
+pub use risk_config::*;
+pub use roles::*;
+pub use savings_bucket::*;
+pub use settlement::*;
+pub use stats::*;
+pub use transfer_memo::*;
+pub use transfer_request::*;
 pub use user::*;
+pub use viewable_balance::*;
+pub use viewer_grant::*;
+pub use voucher::*;
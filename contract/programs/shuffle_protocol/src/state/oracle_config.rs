@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{
+    DEFAULT_MAX_PRICE_AGE_SECS, DEFAULT_MAX_PRICE_CONF_BPS, DEFAULT_MAX_PRICE_DIVERGENCE_BPS,
+};
+
+// =============================================================================
+// ORACLE CONFIG (Price feeds execute_batch reads)
+// =============================================================================
+// Maps each asset to its price feeds and holds the limits execute_batch
+// applies to them. Feeds are rotated with set_oracle_feed and the limits
+// tuned with set_oracle_params, without a program upgrade.
+//
+// - feeds: Pyth feed ID of the asset (the 32-byte ID, stored as a Pubkey);
+//   execute_batch reads it from a fully verified PriceUpdateV2 account
+// - fallback_feeds: Switchboard On-Demand pull feed account of the asset,
+//   used when the Pyth update is unusable and as a cross-check otherwise
+//
+// An asset with neither (the default) is priced at MOCK_ORACLE_PRICES.
+
+/// Price feeds and limits of a pool.
+/// PDA derived with seeds: ["oracle_config", pool_id]
+#[account]
+pub struct OracleConfig {
+    /// Pyth feed ID per asset [USDC, TSLA, SPY, AAPL] (default = none)
+    pub feeds: [Pubkey; 4],
+
+    /// Switchboard pull feed per asset [USDC, TSLA, SPY, AAPL] (default = none)
+    pub fallback_feeds: [Pubkey; 4],
+
+    /// Oldest price execute_batch accepts, in seconds
+    pub max_price_age_secs: i64,
+
+    /// Widest confidence interval accepted, in bps of the price
+    pub max_price_conf_bps: u16,
+
+    /// Largest gap between the Pyth and Switchboard prices, in bps, before
+    /// execute_batch halts instead of pricing the batch
+    pub max_price_divergence_bps: u16,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl OracleConfig {
+    /// Size of the OracleConfig account in bytes.
+    ///
+    /// Calculation:
+    /// - 8 bytes: Anchor discriminator
+    /// - 128 bytes: feeds ([Pubkey; 4])
+    /// - 128 bytes: fallback_feeds ([Pubkey; 4])
+    /// - 8 bytes: max_price_age_secs (i64)
+    /// - 2 bytes: max_price_conf_bps (u16)
+    /// - 2 bytes: max_price_divergence_bps (u16)
+    /// - 1 byte: bump (u8)
+    pub const SIZE: usize = 8 + // discriminator
+        128 + // feeds
+        128 + // fallback_feeds
+        8 +   // max_price_age_secs
+        2 +   // max_price_conf_bps
+        2 +   // max_price_divergence_bps
+        1; // bump
+
+    /// Whether the account still holds the zeroed limits it was created with
+    /// (set_oracle_params never stores a zero age)
+    pub fn is_unset(&self) -> bool {
+        self.max_price_age_secs == 0
+    }
+
+    /// Default limits for a freshly created account
+    pub fn set_default_params(&mut self) {
+        self.max_price_age_secs = DEFAULT_MAX_PRICE_AGE_SECS;
+        self.max_price_conf_bps = DEFAULT_MAX_PRICE_CONF_BPS;
+        self.max_price_divergence_bps = DEFAULT_MAX_PRICE_DIVERGENCE_BPS;
+    }
+}
@@ -0,0 +1,127 @@
+use anchor_lang::prelude::*;
+
+use super::NUM_PAIRS;
+
+// =============================================================================
+// PAIR REGISTRY (Per-pair trading switch and fee)
+// =============================================================================
+// Which two assets a pair trades is fixed by the batch circuits, which
+// accumulate each pair's totals in a fixed slot; PAIR_ASSETS is the single
+// on-chain copy of that table. The registry adds what the authority may
+// change per pair:
+// - enabled: a disabled (halted) pair still accepts encrypted orders, since
+//   the pair of an order is not visible, but the reveal callback refunds
+//   every order in it in full, as it does for frozen assets
+// - fee_bps: execution fee of the pair, or PAIR_FEE_POOL_DEFAULT to follow
+//   Pool.execution_fee_bps
+// - max_price_deviation_bps: price band around the pair's last accepted
+//   execution price (Pool.last_execution_prices). A batch executing outside
+//   it waits for override_price_band before its swaps and settlement
+//   (0 = no band)
+//
+// Like the execution policy, the registry is snapshotted into the BatchLog
+// by execute_batch, so a change applies from the next batch executed.
+
+/// (base asset, quote asset) of each pair, in pair ID order
+pub const PAIR_ASSETS: [(u8, u8); NUM_PAIRS] = [
+    (1, 0), // TSLA/USDC
+    (2, 0), // SPY/USDC
+    (3, 0), // AAPL/USDC
+    (1, 2), // TSLA/SPY
+    (1, 3), // TSLA/AAPL
+    (2, 3), // SPY/AAPL
+];
+
+/// fee_bps value that defers to Pool.execution_fee_bps
+pub const PAIR_FEE_POOL_DEFAULT: u16 = u16::MAX;
+
+/// (base asset, quote asset) of a pair, or None for an unknown pair ID
+pub fn pair_assets(pair_id: u8) -> Option<(u8, u8)> {
+    PAIR_ASSETS.get(pair_id as usize).copied()
+}
+
+/// Trading configuration of a single pair.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct PairConfig {
+    /// Base asset (token A); USDC is never a base
+    pub base_asset: u8,
+    /// Quote asset (token B)
+    pub quote_asset: u8,
+    /// Whether the pair's orders execute
+    pub enabled: bool,
+    /// Execution fee in basis points (PAIR_FEE_POOL_DEFAULT = Pool's)
+    pub fee_bps: u16,
+    /// Largest move from the last accepted execution price, in bps (0 = no band)
+    pub max_price_deviation_bps: u16,
+}
+
+impl PairConfig {
+    /// Size of PairConfig in bytes: assets (2) + enabled (1) + fee_bps (2)
+    /// + max_price_deviation_bps (2)
+    pub const SIZE: usize = 1 + 1 + 1 + 2 + 2;
+}
+
+/// Per-pair trading configuration.
+/// PDA derived with seeds: ["pair_registry", pool_id]
+#[account]
+pub struct PairRegistry {
+    /// Configuration of each of the 6 pairs
+    pub pairs: [PairConfig; NUM_PAIRS],
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl PairRegistry {
+    /// Size of the PairRegistry account in bytes.
+    ///
+    /// Calculation:
+    /// - 8 bytes: Anchor discriminator
+    /// - 6 * 7 bytes: pairs (6 pairs × PairConfig::SIZE)
+    /// - 1 byte: bump (u8)
+    pub const SIZE: usize = 8 + // discriminator
+        (NUM_PAIRS * PairConfig::SIZE) + // pairs: 6 × 7
+        1; // bump
+
+    /// Whether the account still holds the zeroed entries it was created
+    /// with (USDC, asset 0, is never a base asset)
+    pub fn is_unset(&self) -> bool {
+        self.pairs[0].base_asset == 0
+    }
+
+    /// Every pair enabled at the pool's fee
+    pub fn default_pairs() -> [PairConfig; NUM_PAIRS] {
+        PAIR_ASSETS.map(|(base_asset, quote_asset)| PairConfig {
+            base_asset,
+            quote_asset,
+            enabled: true,
+            fee_bps: PAIR_FEE_POOL_DEFAULT,
+            max_price_deviation_bps: 0,
+        })
+    }
+
+    /// Bitmask of disabled pairs
+    pub fn halted_pairs(&self) -> u8 {
+        self.pairs
+            .iter()
+            .enumerate()
+            .filter(|(_, pair)| !pair.enabled)
+            .fold(0, |mask, (pair_id, _)| mask | 1 << pair_id)
+    }
+
+    /// Price band of each pair (0 = none)
+    pub fn price_bands(&self) -> [u16; NUM_PAIRS] {
+        self.pairs.map(|pair| pair.max_price_deviation_bps)
+    }
+
+    /// Execution fee of each pair, resolving PAIR_FEE_POOL_DEFAULT
+    pub fn fee_bps(&self, pool_fee_bps: u16) -> [u16; NUM_PAIRS] {
+        self.pairs.map(|pair| {
+            if pair.fee_bps == PAIR_FEE_POOL_DEFAULT {
+                pool_fee_bps
+            } else {
+                pair.fee_bps
+            }
+        })
+    }
+}
@@ -0,0 +1,114 @@
+use anchor_lang::prelude::*;
+
+// =============================================================================
+// PAYMENT STREAM
+// =============================================================================
+// Continuous payment from one privacy account to another, e.g. a salary.
+// The payer fixes an encrypted per-second rate and a [start_ts, end_ts]
+// window; anyone may crank advance_stream, which queues the stream_transfer
+// circuit to move rate * (now - last_advanced_ts) from payer to payee. The
+// rate stays encrypted, only the timing is public.
+//
+// Only one advance is tracked at a time: a new advance replaces an in-flight
+// one, whose callback is then rejected. The callback also rejects a result
+// computed against a balance that changed in the meantime (the next advance
+// simply covers the same seconds again). If the payer is short, nothing
+// moves and last_advanced_ts stays put, so the debt keeps accruing.
+
+/// Payer-to-payee stream of one asset.
+/// PDA derived with seeds: ["payment_stream", pool_id, payer.key().as_ref(), stream_id.to_le_bytes()]
+#[account]
+pub struct PaymentStream {
+    /// Payer wallet (funds the stream, receives the rent back)
+    pub payer: Pubkey,
+
+    /// Payee wallet
+    pub payee: Pubkey,
+
+    /// Payer-chosen ID (unique per payer)
+    pub stream_id: u64,
+
+    /// Pool both privacy accounts belong to
+    pub pool_id: u8,
+
+    /// Asset being streamed (0=USDC, 1=TSLA, 2=SPY, 3=AAPL)
+    pub asset_id: u8,
+
+    /// x25519 public key the rate was encrypted with
+    pub rate_pubkey: [u8; 32],
+
+    /// Encrypted amount per second (TransferRequest ciphertext)
+    pub encrypted_rate: [u8; 32],
+
+    /// Nonce of the encrypted rate
+    pub rate_nonce: u128,
+
+    /// Unix timestamp the stream starts accruing
+    pub start_ts: i64,
+
+    /// Unix timestamp the stream stops accruing
+    pub end_ts: i64,
+
+    /// Accrual is paid out up to this timestamp
+    pub last_advanced_ts: i64,
+
+    /// Computation account of the in-flight advance (default if none)
+    pub pending_computation: Pubkey,
+
+    /// Timestamp the in-flight advance pays up to
+    pub pending_until: i64,
+
+    /// Payer balance nonce the in-flight advance was computed against
+    pub pending_payer_nonce: u128,
+
+    /// Payee balance nonce the in-flight advance was computed against
+    pub pending_payee_nonce: u128,
+
+    /// Number of advances that moved funds
+    pub advances: u32,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl PaymentStream {
+    /// Size of the PaymentStream account in bytes.
+    ///
+    /// Calculation:
+    /// - 8 bytes: Anchor discriminator
+    /// - 32 bytes: payer (Pubkey)
+    /// - 32 bytes: payee (Pubkey)
+    /// - 8 bytes: stream_id (u64)
+    /// - 1 byte: pool_id (u8)
+    /// - 1 byte: asset_id (u8)
+    /// - 32 bytes: rate_pubkey
+    /// - 32 bytes: encrypted_rate
+    /// - 16 bytes: rate_nonce (u128)
+    /// - 8 bytes: start_ts (i64)
+    /// - 8 bytes: end_ts (i64)
+    /// - 8 bytes: last_advanced_ts (i64)
+    /// - 32 bytes: pending_computation (Pubkey)
+    /// - 8 bytes: pending_until (i64)
+    /// - 16 bytes: pending_payer_nonce (u128)
+    /// - 16 bytes: pending_payee_nonce (u128)
+    /// - 4 bytes: advances (u32)
+    /// - 1 byte: bump (u8)
+    pub const SIZE: usize = 8 + // discriminator
+        32 +  // payer
+        32 +  // payee
+        8 +   // stream_id
+        1 +   // pool_id
+        1 +   // asset_id
+        32 +  // rate_pubkey
+        32 +  // encrypted_rate
+        16 +  // rate_nonce
+        8 +   // start_ts
+        8 +   // end_ts
+        8 +   // last_advanced_ts
+        32 +  // pending_computation
+        8 +   // pending_until
+        16 +  // pending_payer_nonce
+        16 +  // pending_payee_nonce
+        4 +   // advances
+        1; // bump
+}
@@ -0,0 +1,101 @@
+use anchor_lang::prelude::*;
+
+// =============================================================================
+// PENDING OPERATION (Concurrent deposits and withdrawals)
+// =============================================================================
+// Context of one in-flight add_balance / sub_balance computation, keyed by
+// its computation_offset. It used to live in UserProfile.pending_asset_id and
+// pending_withdrawal_amount, so a second deposit or withdrawal queued before
+// the first callback overwrote it and the first callback updated the wrong
+// asset (or transferred the wrong amount).
+//
+// Operations on different assets now run concurrently. Two operations on the
+// same asset still both start from the balance they were queued against; the
+// callback compares that balance's nonce with the current one and rejects the
+// stale result (PendingOperationStale) instead of overwriting the other
+// operation's update. A stale deposit keeps its PendingOperation (its tokens
+// are already in the vault) and is re-queued with retry_deposit; a stale or
+// rejected withdrawal moved nothing and its owner cancels it with
+// cancel_withdrawal. The successful callback closes the account and returns
+// the rent to the owner.
+
+/// add_balance (or create_and_deposit) computation
+pub const PENDING_OP_DEPOSIT: u8 = 0;
+
+/// sub_balance (or delegated_withdraw) computation
+pub const PENDING_OP_WITHDRAWAL: u8 = 1;
+
+/// In-flight deposit or withdrawal.
+/// PDA derived with seeds: ["pending_operation", pool_id, owner.key().as_ref(), computation_offset.to_le_bytes()]
+#[account]
+pub struct PendingOperation {
+    /// Wallet of the UserProfile being updated (receives the rent back)
+    pub owner: Pubkey,
+
+    /// Computation account whose callback may complete the operation
+    /// (replaced by retry_deposit)
+    pub computation: Pubkey,
+
+    /// PENDING_OP_* value
+    pub kind: u8,
+
+    /// Asset being deposited or withdrawn (0=USDC, 1=TSLA, 2=SPY, 3=AAPL)
+    pub asset_id: u8,
+
+    /// Token amount (transferred to the vault for a deposit, from it for a
+    /// withdrawal)
+    pub amount: u64,
+
+    /// Nonce of the balance the computation was queued against
+    pub balance_nonce: u128,
+
+    /// Encrypted amount submitted by the owner (re-queued by retry_deposit)
+    pub encrypted_amount: [u8; 32],
+
+    /// x25519 public key the amount is encrypted with
+    pub amount_pubkey: [u8; 32],
+
+    /// Nonce of the encrypted amount
+    pub amount_nonce: u128,
+
+    /// Unix timestamp the current computation was queued
+    pub queued_at: i64,
+
+    /// Nonce of the delegate allowance drawn on (delegated_withdraw only)
+    pub allowance_nonce: u128,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl PendingOperation {
+    /// Size of the PendingOperation account in bytes.
+    ///
+    /// Calculation:
+    /// - 8 bytes: Anchor discriminator
+    /// - 32 bytes: owner (Pubkey)
+    /// - 32 bytes: computation (Pubkey)
+    /// - 1 byte: kind (u8)
+    /// - 1 byte: asset_id (u8)
+    /// - 8 bytes: amount (u64)
+    /// - 16 bytes: balance_nonce (u128)
+    /// - 32 bytes: encrypted_amount ([u8; 32])
+    /// - 32 bytes: amount_pubkey ([u8; 32])
+    /// - 16 bytes: amount_nonce (u128)
+    /// - 8 bytes: queued_at (i64)
+    /// - 16 bytes: allowance_nonce (u128)
+    /// - 1 byte: bump (u8)
+    pub const SIZE: usize = 8 + // discriminator
+        32 +  // owner
+        32 +  // computation
+        1 +   // kind
+        1 +   // asset_id
+        8 +   // amount
+        16 +  // balance_nonce
+        32 +  // encrypted_amount
+        32 +  // amount_pubkey
+        16 +  // amount_nonce
+        8 +   // queued_at
+        16 +  // allowance_nonce
+        1; // bump
+}
@@ -1,5 +1,12 @@
 use anchor_lang::prelude::*;
 
+use super::PAIR_ASSETS;
+use crate::constants::{
+    BATCH_MIN_ACTIVE_PAIRS, BPS_DENOMINATOR, DEFAULT_BATCH_WINDOW_SECS, DEFAULT_MAX_SLIPPAGE_BPS,
+    DEFAULT_SETTLEMENT_DEADLINE_SECS, FORCE_SETTLE_GRACE_SECS, JUPITER_PROGRAM_ID,
+    MAX_ASSET_DECIMALS,
+};
+
 // =============================================================================
 // POOL ACCOUNT
 // =============================================================================
@@ -8,7 +15,7 @@ use anchor_lang::prelude::*;
 //
 
 /// Central state account for the Shuffle Protocol protocol.
-/// PDA derived with seeds: ["pool"]
+/// PDA derived with seeds: ["pool", pool_id]
 #[account]
 pub struct Pool {
     /// Admin authority that can update settings and pause the protocol.
@@ -48,6 +55,9 @@ pub struct Pool {
     /// Number of orders required to trigger batch execution (default: 8)
     pub execution_trigger_count: u8,
 
+    /// Number of pairs with activity required to trigger batch execution (default: 2)
+    pub min_active_pairs: u8,
+
     // =========================================================================
     // PROTOCOL PARAMETERS
     // =========================================================================
@@ -61,16 +71,262 @@ pub struct Pool {
     pub bump: u8,
 
     /// Emergency pause flag.
-    /// When true, most operations are blocked.
+    /// When true, most operations are blocked (see emergency_withdraw_enabled).
     pub paused: bool,
 
-    /// Total fees collected in USDC base units (for analytics).
+    /// Total execution fees swept to the treasury, valued in USDC base units
+    /// at the accruing batches' oracle prices (for analytics and the buyback
+    /// budget). Grows by fees_owed_usdc on every sweep_fees.
     pub total_fees_collected: u64,
 
     /// Total batches executed (for analytics).
     pub total_batches_executed: u64,
+
+    // =========================================================================
+    // EMERGENCY ASSET FREEZE
+    // =========================================================================
+    /// Bitmask of frozen assets (bit N = asset_id N).
+    /// Deposits, orders and withdrawals of a frozen asset are rejected.
+    pub frozen_assets: u8,
+
+    /// Earliest unix timestamp at which each asset may be unfrozen
+    /// (0 = no unfreeze requested).
+    pub unfreeze_available_at: [i64; 4],
+
+    // =========================================================================
+    // RESTRICTED ASSETS
+    // =========================================================================
+    /// Bitmask of assets with an AssetTransferPolicy (bit N = asset_id N).
+    /// Deposits and withdrawals of these assets must pass the policy account.
+    pub restricted_assets: u8,
+
+    // =========================================================================
+    // SETTLEMENT DEADLINE
+    // =========================================================================
+    /// Seconds after batch execution before unsettled payouts can be
+    /// forfeited to the treasury (forfeit_order).
+    pub settlement_deadline_secs: i64,
+
+    // =========================================================================
+    // MPC CALLBACK PRIORITY
+    // =========================================================================
+    /// Priority fee (micro-lamports per CU) requested for MPC callback
+    /// transactions queued by the order, batch and balance instructions.
+    pub callback_cu_price_micro: u64,
+
+    // =========================================================================
+    // EXECUTION MODE
+    // =========================================================================
+    /// How net surpluses are filled (EXECUTION_MODE_*):
+    /// - default: reserves absorb what they can, priced at max_slippage_bps
+    /// - reserve-only: reserves only, at oracle price minus reserve_spread_bps
+    /// - external-only: reserves unused, surplus routed through swap_program
+    pub execution_mode: u8,
+
+    /// Spread charged by the reserves on absorbed surplus, in basis points.
+    pub reserve_spread_bps: u16,
+
+    /// Max share of each reserve one batch may pay out, in basis points
+    /// (reserve-only mode).
+    pub max_reserve_utilization_bps: u16,
+
+    /// Max balance each reserve [USDC, TSLA, SPY, AAPL] may hold after
+    /// absorbing surplus (reserve-only mode, 0 = no limit).
+    pub max_reserve_inventory: [u64; 4],
+
+    /// Swap adapter surpluses are routed through (external-only mode).
+    pub swap_program: Pubkey,
+
+    /// Seconds a routed surplus may wait in the reserves for an opposite
+    /// surplus from a later batch before being flushed (0 = no netting).
+    pub netting_window_secs: i64,
+
+    /// Unused: keeper rewards are paid in USDC from the execution fees at
+    /// keeper_reward_bps. Kept so the account layout does not change.
+    pub keeper_reward_lamports: u64,
+
+    /// Second operator whose approve_batch is required before the swaps of
+    /// a large batch (default = approval disabled).
+    pub co_operator: Pubkey,
+
+    /// Revealed batch notional in USDC base units above which the
+    /// co-operator must approve the batch (0 = no threshold).
+    pub approval_threshold_usdc: u64,
+
+    /// Whether execute_batch and execute_swaps are restricted to executors
+    /// bonded in the ExecutorRegistry (false = permissionless / operator).
+    pub executor_gated: bool,
+
+    // =========================================================================
+    // EXECUTION FEES
+    // =========================================================================
+    /// Execution fees [USDC, TSLA, SPY, AAPL] accrued by execute_swaps and
+    /// still held in the deposit vaults, until sweep_fees sends them to
+    /// the treasury.
+    pub fees_owed: [u64; 4],
+
+    // =========================================================================
+    // BATCH RATE LIMIT
+    // =========================================================================
+    /// Max batches execute_batch may queue per window (0 = unlimited).
+    /// Bounds MPC/fee spend and keeps batches (privacy sets) from being
+    /// flushed too thin when activity is low.
+    pub max_batches_per_window: u16,
+
+    /// Length of the rate-limit window in seconds. A window starts with the
+    /// first batch queued after the previous one elapsed.
+    pub batch_window_secs: i64,
+
+    /// Seconds after the previous batch was queued (i.e. since the current
+    /// batch opened) after which a batch may execute despite the limit
+    /// (0 = no override).
+    pub max_batch_wait_secs: i64,
+
+    /// Unix timestamp the current window started
+    pub batch_window_started_at: i64,
+
+    /// Batches queued in the current window
+    pub batches_in_window: u16,
+
+    /// Unix timestamp execute_batch last queued a new batch
+    pub last_batch_queued_at: i64,
+
+    // =========================================================================
+    // SLIPPAGE
+    // =========================================================================
+    /// Worst slippage accepted on external swaps, in basis points. Default
+    /// mode prices reserve fills at it; routed surplus must return at least
+    /// the oracle value minus it (BatchLog.route_min_out).
+    pub max_slippage_bps: u16,
+
+    // =========================================================================
+    // PRIVACY FLOOR
+    // =========================================================================
+    /// Minimum distinct wallets in a batch before its totals are revealed
+    /// (0 = off). Unlike execution_trigger_count the operator cannot bypass
+    /// it; a batch that never reaches it is refunded with flush_batch.
+    pub min_unique_users: u8,
+
+    // =========================================================================
+    // ASSET DECIMALS
+    // =========================================================================
+    /// Decimals of each asset's mint [USDC, TSLA, SPY, AAPL], read in
+    /// initialize. Oracle prices are per whole token; unit_prices uses these
+    /// to convert between assets in base units.
+    pub asset_decimals: [u8; 4],
+
+    // =========================================================================
+    // ASSET DELISTING
+    // =========================================================================
+    /// Bitmask of delisted assets (bit N = asset_id N). Unlike a freeze,
+    /// delisting only blocks new deposits and orders: withdrawals and
+    /// settlement of existing positions keep working.
+    pub delisted_assets: u8,
+
+    // =========================================================================
+    // POOL ID
+    // =========================================================================
+    /// Identifier of this pool. Several independent pools can be deployed
+    /// from the program; the pool, its vaults and reserves, batch
+    /// accumulators, batch logs and user accounts all seed their PDAs with it.
+    pub pool_id: u8,
+
+    // =========================================================================
+    // ORACLE
+    // =========================================================================
+    /// Whether the OracleConfig PDA exists. execute_batch then requires it,
+    /// so a keeper cannot fall back to mock prices by leaving it out.
+    pub oracle_configured: bool,
+
+    // =========================================================================
+    // MARKET HOURS
+    // =========================================================================
+    /// Whether execute_batch halts equity pairs outside the MarketSchedule
+    /// (the schedule account is then required)
+    pub market_hours_enforced: bool,
+
+    // =========================================================================
+    // PRICE BANDS
+    // =========================================================================
+    /// Last accepted execution price of each pair (EXECUTION_PRICE_SCALE,
+    /// 0 = none yet). Reference of the pair's price band, see
+    /// PairConfig.max_price_deviation_bps.
+    pub last_execution_prices: [u64; 6],
+
+    // =========================================================================
+    // AUTHORITY TRANSFER
+    // =========================================================================
+    /// Wallet proposed by propose_authority; becomes the authority once it
+    /// signs accept_authority (default = no transfer pending).
+    pub pending_authority: Pubkey,
+
+    // =========================================================================
+    // RISK LIMITS
+    // =========================================================================
+    /// Whether the RiskConfig PDA exists. execute_batch, sub_balance and
+    /// delegated_withdraw then require it, so its limits cannot be skipped.
+    pub risk_configured: bool,
+
+    // =========================================================================
+    // EMERGENCY WITHDRAW
+    // =========================================================================
+    /// When true, sub_balance stays available while the protocol is paused
+    /// (everything else the pause halts remains halted). Set with
+    /// set_emergency_withdraw.
+    pub emergency_withdraw_enabled: bool,
+
+    // =========================================================================
+    // ACCOUNT VERSION
+    // =========================================================================
+    /// Layout version (see Pool::VERSION). Stays the last field of the
+    /// versioned layout; fields added later go after it and migrate_pool
+    /// zero-extends old accounts to them.
+    pub version: u8,
+
+    // =========================================================================
+    // BOOTSTRAP
+    // =========================================================================
+    /// Deployment steps verified by bootstrap: one bit per entry of
+    /// BOOTSTRAP_COMP_DEFS, plus BOOTSTRAP_ACCUMULATOR_CREATED and
+    /// BOOTSTRAP_BATCH_STATE_READY per batch slot. Only ever set.
+    pub bootstrap_flags: u64,
+
+    /// USDC value of fees_owed, at the oracle prices of the batches that
+    /// accrued them. sweep_fees adds it to total_fees_collected and zeroes it.
+    pub fees_owed_usdc: u64,
+
+    /// Keeper reward in basis points of a batch's notional, paid in USDC out
+    /// of fees_owed by execute_swaps to the execute_batch caller and capped
+    /// at the fees the batch accrued (0 = no reward, see set_keeper_reward).
+    pub keeper_reward_bps: u16,
+
+    /// Whether the PairRegistry PDA exists (configure_pair, set_price_band).
+    /// execute_batch then requires it, so halts, fees and price bands
+    /// cannot be skipped by leaving it out.
+    pub pair_registry_configured: bool,
+
+    /// Whether the ExecutionPolicy PDA exists (set_pair_policy).
+    /// execute_batch then requires it, like the pair registry.
+    pub execution_policy_configured: bool,
+
+    /// Time after batch execution before force_settle may settle a user's
+    /// order (FORCE_SETTLE_GRACE_SECS by default, see set_force_settle_grace)
+    pub force_settle_grace_secs: i64,
 }
 
+/// Reserves absorb net surplus, priced at Pool.max_slippage_bps
+pub const EXECUTION_MODE_DEFAULT: u8 = 0;
+/// Reserves absorb net surplus at oracle price minus a spread, never routed out
+pub const EXECUTION_MODE_RESERVE_ONLY: u8 = 1;
+/// Reserves are never used; net surplus is routed to the external venue
+pub const EXECUTION_MODE_EXTERNAL_ONLY: u8 = 2;
+
+/// Pool.bootstrap_flags: batch accumulator slot 0 exists (<< 1 for slot 1)
+pub const BOOTSTRAP_ACCUMULATOR_CREATED: u64 = 1 << 32;
+/// Pool.bootstrap_flags: slot 0 holds the encrypted zeros of
+/// init_batch_state (<< 1 for slot 1)
+pub const BOOTSTRAP_BATCH_STATE_READY: u64 = 1 << 34;
+
 impl Pool {
     /// Size of the Pool account in bytes.
     /// Used when creating the account: space = Pool::SIZE
@@ -86,11 +342,52 @@ impl Pool {
     /// - 32 bytes: aapl_mint (Pubkey)
     /// - 8 bytes: current_batch_id (u64)
     /// - 1 byte: execution_trigger_count (u8)
+    /// - 1 byte: min_active_pairs (u8)
     /// - 2 bytes: execution_fee_bps (u16)
     /// - 1 byte: bump (u8)
     /// - 1 byte: paused (bool)
     /// - 8 bytes: total_fees_collected (u64)
     /// - 8 bytes: total_batches_executed (u64)
+    /// - 1 byte: frozen_assets (u8)
+    /// - 32 bytes: unfreeze_available_at (4 × i64)
+    /// - 1 byte: restricted_assets (u8)
+    /// - 8 bytes: settlement_deadline_secs (i64)
+    /// - 8 bytes: callback_cu_price_micro (u64)
+    /// - 1 byte: execution_mode (u8)
+    /// - 2 bytes: reserve_spread_bps (u16)
+    /// - 2 bytes: max_reserve_utilization_bps (u16)
+    /// - 32 bytes: max_reserve_inventory (4 × u64)
+    /// - 32 bytes: swap_program (Pubkey)
+    /// - 8 bytes: netting_window_secs (i64)
+    /// - 8 bytes: keeper_reward_lamports (u64)
+    /// - 32 bytes: co_operator (Pubkey)
+    /// - 8 bytes: approval_threshold_usdc (u64)
+    /// - 1 byte: executor_gated (bool)
+    /// - 32 bytes: fees_owed (4 × u64)
+    /// - 2 bytes: max_batches_per_window (u16)
+    /// - 8 bytes: batch_window_secs (i64)
+    /// - 8 bytes: max_batch_wait_secs (i64)
+    /// - 8 bytes: batch_window_started_at (i64)
+    /// - 2 bytes: batches_in_window (u16)
+    /// - 8 bytes: last_batch_queued_at (i64)
+    /// - 2 bytes: max_slippage_bps (u16)
+    /// - 1 byte: min_unique_users (u8)
+    /// - 4 bytes: asset_decimals (4 × u8)
+    /// - 1 byte: delisted_assets (u8)
+    /// - 1 byte: pool_id (u8)
+    /// - 1 byte: oracle_configured (bool)
+    /// - 1 byte: market_hours_enforced (bool)
+    /// - 48 bytes: last_execution_prices ([u64; 6])
+    /// - 32 bytes: pending_authority (Pubkey)
+    /// - 1 byte: risk_configured (bool)
+    /// - 1 byte: emergency_withdraw_enabled (bool)
+    /// - 1 byte: version (u8)
+    /// - 8 bytes: bootstrap_flags (u64)
+    /// - 8 bytes: fees_owed_usdc (u64)
+    /// - 2 bytes: keeper_reward_bps (u16)
+    /// - 1 byte: pair_registry_configured (bool)
+    /// - 1 byte: execution_policy_configured (bool)
+    /// - 8 bytes: force_settle_grace_secs (i64)
     pub const SIZE: usize = 8 + // discriminator
         32 +  // authority
         32 +  // operator
@@ -101,9 +398,257 @@ impl Pool {
         32 +  // aapl_mint
         8 +   // current_batch_id
         1 +   // execution_trigger_count
+        1 +   // min_active_pairs
         2 +   // execution_fee_bps
         1 +   // bump
         1 +   // paused
         8 +   // total_fees_collected
-        8; // total_batches_executed
+        8 +   // total_batches_executed
+        1 +   // frozen_assets
+        32 +  // unfreeze_available_at
+        1 +   // restricted_assets
+        8 +   // settlement_deadline_secs
+        8 +   // callback_cu_price_micro
+        1 +   // execution_mode
+        2 +   // reserve_spread_bps
+        2 +   // max_reserve_utilization_bps
+        32 +  // max_reserve_inventory
+        32 +  // swap_program
+        8 +   // netting_window_secs
+        8 +   // keeper_reward_lamports
+        32 +  // co_operator
+        8 +   // approval_threshold_usdc
+        1 +   // executor_gated
+        32 +  // fees_owed
+        2 +   // max_batches_per_window
+        8 +   // batch_window_secs
+        8 +   // max_batch_wait_secs
+        8 +   // batch_window_started_at
+        2 +   // batches_in_window
+        8 +   // last_batch_queued_at
+        2 +   // max_slippage_bps
+        1 +   // min_unique_users
+        4 +   // asset_decimals
+        1 +   // delisted_assets
+        1 +   // pool_id
+        1 +   // oracle_configured
+        1 +   // market_hours_enforced
+        48 +  // last_execution_prices
+        32 +  // pending_authority
+        1 +   // risk_configured
+        1 +   // emergency_withdraw_enabled
+        1 +   // version
+        8 +   // bootstrap_flags
+        8 +   // fees_owed_usdc
+        2 +   // keeper_reward_bps
+        1 +   // pair_registry_configured
+        1 +   // execution_policy_configured
+        8; // force_settle_grace_secs
+
+    /// Current layout version, written on creation and by migrate_pool.
+    /// 2 added bootstrap_flags, 3 added fees_owed_usdc, 4 added
+    /// keeper_reward_bps, 5 added pair_registry_configured and
+    /// execution_policy_configured, 6 added force_settle_grace_secs.
+    pub const VERSION: u8 = 6;
+
+    /// Byte offset of `version`: SIZE minus version and the fields added
+    /// after it (bootstrap_flags, fees_owed_usdc, keeper_reward_bps,
+    /// pair_registry_configured, execution_policy_configured,
+    /// force_settle_grace_secs).
+    /// Accounts created before versioning end right here (version 0),
+    /// except those still in the original layout (see LegacyPool).
+    pub const VERSION_OFFSET: usize = Self::SIZE - 1 - 8 - 8 - 2 - 1 - 1 - 8;
+
+    /// Whether every deployment step in `complete` (BOOTSTRAP_COMPLETE) has
+    /// been verified by bootstrap
+    pub fn is_bootstrapped(&self, complete: u64) -> bool {
+        self.bootstrap_flags & complete == complete
+    }
+
+    /// Get the token mint for a given asset ID (0=USDC, 1=TSLA, 2=SPY, 3=AAPL)
+    pub fn mint_for_asset(&self, asset_id: u8) -> Option<Pubkey> {
+        match asset_id {
+            0 => Some(self.usdc_mint),
+            1 => Some(self.tsla_mint),
+            2 => Some(self.spy_mint),
+            3 => Some(self.aapl_mint),
+            _ => None,
+        }
+    }
+
+    /// Asset ID of a pool mint (inverse of mint_for_asset)
+    pub fn asset_for_mint(&self, mint: &Pubkey) -> Option<u8> {
+        (0..4).find(|&asset_id| self.mint_for_asset(asset_id) == Some(*mint))
+    }
+
+    /// Price of one base unit of each asset, from per-whole-token `prices`
+    /// (USDC, 6 decimals), scaled by 10^MAX_ASSET_DECIMALS so mints with
+    /// fewer decimals keep full precision. Only ratios of these are
+    /// meaningful: `amount_in * unit_in / unit_out` converts base units of
+    /// one asset into base units of another.
+    pub fn unit_prices(&self, prices: &[u64; 4]) -> [u128; 4] {
+        let mut unit_prices = [0u128; 4];
+        for (asset, unit_price) in unit_prices.iter_mut().enumerate() {
+            let shift = MAX_ASSET_DECIMALS.saturating_sub(self.asset_decimals[asset]);
+            *unit_price = prices[asset] as u128 * 10u128.pow(shift as u32);
+        }
+        unit_prices
+    }
+
+    /// Whether deposits, orders and withdrawals of an asset are frozen
+    pub fn is_asset_frozen(&self, asset_id: u8) -> bool {
+        asset_id < 8 && self.frozen_assets & (1 << asset_id) != 0
+    }
+
+    /// Whether new deposits and orders of an asset are blocked (delisted)
+    pub fn is_asset_delisted(&self, asset_id: u8) -> bool {
+        asset_id < 8 && self.delisted_assets & (1 << asset_id) != 0
+    }
+
+    /// Bitmask of pairs that touch a delisted asset (bit N = pair_id N)
+    pub fn delisted_pairs(&self) -> u8 {
+        PAIR_ASSETS
+            .iter()
+            .enumerate()
+            .filter(|(_, (base, quote))| {
+                self.is_asset_delisted(*base) || self.is_asset_delisted(*quote)
+            })
+            .fold(0, |mask, (pair_id, _)| mask | (1 << pair_id))
+    }
+
+    /// Whether an asset has transfer restrictions (AssetTransferPolicy)
+    pub fn is_asset_restricted(&self, asset_id: u8) -> bool {
+        asset_id < 8 && self.restricted_assets & (1 << asset_id) != 0
+    }
+
+    /// Whether execute_batch may queue a new batch at `now`: under the
+    /// window's limit, or the current batch has waited past
+    /// max_batch_wait_secs (the deadline takes precedence over the limit)
+    pub fn batch_rate_allows(&self, now: i64) -> bool {
+        let window_elapsed = now
+            >= self
+                .batch_window_started_at
+                .saturating_add(self.batch_window_secs);
+        let deadline_passed = self.max_batch_wait_secs > 0
+            && now.saturating_sub(self.last_batch_queued_at) >= self.max_batch_wait_secs;
+        self.max_batches_per_window == 0
+            || window_elapsed
+            || self.batches_in_window < self.max_batches_per_window
+            || deadline_passed
+    }
+
+    /// Count a batch queued at `now` against the rate-limit window
+    pub fn record_batch_queued(&mut self, now: i64) {
+        if now
+            >= self
+                .batch_window_started_at
+                .saturating_add(self.batch_window_secs)
+        {
+            self.batch_window_started_at = now;
+            self.batches_in_window = 0;
+        }
+        self.batches_in_window = self.batches_in_window.saturating_add(1);
+        self.last_batch_queued_at = now;
+    }
+}
+
+/// Pool layout of the original deployment, read by migrate_pool. Later
+/// fields were not only appended (min_active_pairs sits between
+/// execution_trigger_count and execution_fee_bps), so it cannot be
+/// zero-extended and is rewritten field by field instead.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct LegacyPool {
+    pub authority: Pubkey,
+    pub operator: Pubkey,
+    pub treasury: Pubkey,
+    pub usdc_mint: Pubkey,
+    pub tsla_mint: Pubkey,
+    pub spy_mint: Pubkey,
+    pub aapl_mint: Pubkey,
+    pub current_batch_id: u64,
+    pub execution_trigger_count: u8,
+    pub execution_fee_bps: u16,
+    pub bump: u8,
+    pub paused: bool,
+    pub total_fees_collected: u64,
+    pub total_batches_executed: u64,
+}
+
+impl LegacyPool {
+    /// Size of the legacy account: discriminator, the seven keys and the
+    /// batch/fee parameters
+    pub const SIZE: usize = 8 + 7 * 32 + 8 + 1 + 2 + 1 + 1 + 8 + 8;
+
+    /// Mints in asset_id order [USDC, TSLA, SPY, AAPL]
+    pub fn mints(&self) -> [Pubkey; 4] {
+        [
+            self.usdc_mint,
+            self.tsla_mint,
+            self.spy_mint,
+            self.aapl_mint,
+        ]
+    }
+
+    /// Current layout of the same account. Settings the original layout did
+    /// not have get the defaults of initialize; `pool_id` and `bump` come
+    /// from the account's address and `asset_decimals` from its mints.
+    pub fn into_current(self, pool_id: u8, bump: u8, asset_decimals: [u8; 4]) -> Pool {
+        Pool {
+            authority: self.authority,
+            operator: self.operator,
+            treasury: self.treasury,
+            usdc_mint: self.usdc_mint,
+            tsla_mint: self.tsla_mint,
+            spy_mint: self.spy_mint,
+            aapl_mint: self.aapl_mint,
+            current_batch_id: self.current_batch_id,
+            execution_trigger_count: self.execution_trigger_count,
+            min_active_pairs: BATCH_MIN_ACTIVE_PAIRS,
+            execution_fee_bps: self.execution_fee_bps,
+            bump,
+            paused: self.paused,
+            total_fees_collected: self.total_fees_collected,
+            total_batches_executed: self.total_batches_executed,
+            frozen_assets: 0,
+            unfreeze_available_at: [0; 4],
+            restricted_assets: 0,
+            settlement_deadline_secs: DEFAULT_SETTLEMENT_DEADLINE_SECS,
+            callback_cu_price_micro: 0,
+            execution_mode: EXECUTION_MODE_DEFAULT,
+            reserve_spread_bps: 0,
+            max_reserve_utilization_bps: BPS_DENOMINATOR,
+            max_reserve_inventory: [0; 4],
+            swap_program: JUPITER_PROGRAM_ID,
+            netting_window_secs: 0,
+            keeper_reward_lamports: 0,
+            co_operator: Pubkey::default(),
+            approval_threshold_usdc: 0,
+            executor_gated: false,
+            fees_owed: [0; 4],
+            max_batches_per_window: 0,
+            batch_window_secs: DEFAULT_BATCH_WINDOW_SECS,
+            max_batch_wait_secs: 0,
+            batch_window_started_at: 0,
+            batches_in_window: 0,
+            last_batch_queued_at: 0,
+            max_slippage_bps: DEFAULT_MAX_SLIPPAGE_BPS,
+            min_unique_users: 0,
+            asset_decimals,
+            delisted_assets: 0,
+            pool_id,
+            oracle_configured: false,
+            market_hours_enforced: false,
+            last_execution_prices: [0; 6],
+            pending_authority: Pubkey::default(),
+            risk_configured: false,
+            emergency_withdraw_enabled: false,
+            version: Pool::VERSION,
+            bootstrap_flags: 0,
+            fees_owed_usdc: 0,
+            keeper_reward_bps: 0,
+            pair_registry_configured: false,
+            execution_policy_configured: false,
+            force_settle_grace_secs: FORCE_SETTLE_GRACE_SECS,
+        }
+    }
 }
@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+// =============================================================================
+// RENT SPONSOR (Onboarding subsidy)
+// =============================================================================
+// Lamport pool the protocol funds to cover UserProfile rent. When it is
+// passed to create_user_account with enough lamports above its own
+// rent-exempt minimum, the payer is reimbursed the account rent and the
+// profile is flagged rent_sponsored, so any path that later frees its rent
+// can return it to the pool rather than the owner.
+
+/// Rent sponsorship pool.
+/// PDA derived with seeds: ["rent_sponsor", pool_id]
+#[account]
+pub struct RentSponsor {
+    /// Number of UserProfiles whose rent was reimbursed
+    pub sponsored_accounts: u64,
+
+    /// Total lamports reimbursed
+    pub total_sponsored: u64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl RentSponsor {
+    /// Size of the RentSponsor account in bytes.
+    ///
+    /// Calculation:
+    /// - 8 bytes: Anchor discriminator
+    /// - 8 bytes: sponsored_accounts (u64)
+    /// - 8 bytes: total_sponsored (u64)
+    /// - 1 byte: bump (u8)
+    pub const SIZE: usize = 8 + // discriminator
+        8 +   // sponsored_accounts
+        8 +   // total_sponsored
+        1; // bump
+}
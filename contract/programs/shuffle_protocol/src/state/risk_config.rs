@@ -0,0 +1,117 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::WITHDRAWAL_WINDOW_SECS;
+
+// =============================================================================
+// RISK CONFIG (Global limits)
+// =============================================================================
+// Limits the risk role tunes with set_risk_config. The account is created on
+// first call; from then on (Pool.risk_configured) execute_batch, sub_balance
+// and delegated_withdraw require it, so a caller cannot skip a limit by
+// leaving the account out.
+//
+// - max_batch_notional_usdc: a batch revealed above it waits for
+//   approve_batch before its swaps, like Pool.approval_threshold_usdc (the
+//   lower of the two applies)
+// - max_daily_withdrawal: plaintext amount one wallet may withdraw per asset
+//   within WITHDRAWAL_WINDOW_SECS, tracked in its WithdrawalWindow. Counted
+//   when the withdrawal is queued, so an aborted withdrawal still counts.
+// - max_reserve_utilization_bps: share of each reserve one batch may pay
+//   out, in every execution mode (Pool.max_reserve_utilization_bps still
+//   applies in reserve-only mode; the lower of the two wins)
+
+/// Global risk limits of a pool.
+/// PDA derived with seeds: ["risk_config", pool_id]
+#[account]
+pub struct RiskConfig {
+    /// Revealed batch notional in USDC base units above which the batch
+    /// needs approve_batch (0 = no limit)
+    pub max_batch_notional_usdc: u64,
+
+    /// Per-wallet withdrawal cap per window [USDC, TSLA, SPY, AAPL] in base
+    /// units (0 = no cap)
+    pub max_daily_withdrawal: [u64; 4],
+
+    /// Max share of each reserve paid out per batch, in basis points
+    pub max_reserve_utilization_bps: u16,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl RiskConfig {
+    /// Size of the RiskConfig account in bytes.
+    ///
+    /// Calculation:
+    /// - 8 bytes: Anchor discriminator
+    /// - 8 bytes: max_batch_notional_usdc (u64)
+    /// - 32 bytes: max_daily_withdrawal (4 × u64)
+    /// - 2 bytes: max_reserve_utilization_bps (u16)
+    /// - 1 byte: bump (u8)
+    pub const SIZE: usize = 8 + // discriminator
+        8 +   // max_batch_notional_usdc
+        32 +  // max_daily_withdrawal
+        2 +   // max_reserve_utilization_bps
+        1; // bump
+
+    /// Approval threshold execute_batch snapshots: the lower non-zero of the
+    /// pool's threshold and the notional cap (0 = none)
+    pub fn approval_threshold(&self, pool_threshold_usdc: u64) -> u64 {
+        match (pool_threshold_usdc, self.max_batch_notional_usdc) {
+            (0, cap) => cap,
+            (threshold, 0) => threshold,
+            (threshold, cap) => threshold.min(cap),
+        }
+    }
+}
+
+/// Withdrawals of one wallet in the current window, created by the
+/// wallet's first capped withdrawal.
+/// PDA derived with seeds: ["withdrawal_window", pool_id, owner.key().as_ref()]
+#[account]
+pub struct WithdrawalWindow {
+    /// Wallet whose withdrawals are counted
+    pub owner: Pubkey,
+
+    /// Unix timestamp the current window started
+    pub window_started_at: i64,
+
+    /// Amount withdrawn per asset [USDC, TSLA, SPY, AAPL] in the window
+    pub withdrawn: [u64; 4],
+}
+
+impl WithdrawalWindow {
+    /// Size of the WithdrawalWindow account in bytes.
+    ///
+    /// Calculation:
+    /// - 8 bytes: Anchor discriminator
+    /// - 32 bytes: owner (Pubkey)
+    /// - 8 bytes: window_started_at (i64)
+    /// - 32 bytes: withdrawn (4 × u64)
+    pub const SIZE: usize = 8 + // discriminator
+        32 +  // owner
+        8 +   // window_started_at
+        32; // withdrawn
+
+    /// Count `amount` against `cap`, starting a new window when the current
+    /// one has elapsed. Returns false (and records nothing) if the cap would
+    /// be exceeded.
+    pub fn record(&mut self, asset_id: u8, amount: u64, cap: u64, now: i64) -> bool {
+        if now
+            >= self
+                .window_started_at
+                .saturating_add(WITHDRAWAL_WINDOW_SECS)
+        {
+            self.window_started_at = now;
+            self.withdrawn = [0; 4];
+        }
+        let withdrawn = &mut self.withdrawn[asset_id as usize];
+        match withdrawn.checked_add(amount) {
+            Some(total) if total <= cap => {
+                *withdrawn = total;
+                true
+            }
+            _ => false,
+        }
+    }
+}
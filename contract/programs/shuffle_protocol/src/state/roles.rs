@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+
+use super::Pool;
+use crate::constants::MAX_ROLE_MEMBERS;
+
+// =============================================================================
+// ROLES (Access control)
+// =============================================================================
+// Pool.authority and Pool.operator are single wallets. The Roles PDA lets
+// several wallets hold each role, so duties can be split across keys:
+// - admin: every authority-gated instruction, and role management
+// - operator: every operator-gated instruction (batch execution, routing)
+// - pauser: pause_protocol only, so a monitoring bot can halt the protocol
+//   without holding the admin key
+// - risk: risk limits (price bands, slippage, oracle limits, rate limit,
+//   privacy floor, asset freezes)
+//
+// Pool.authority stays an implicit admin and Pool.operator an implicit
+// operator; they cannot be revoked here (use propose_authority and
+// set_operator). Admins also hold the pauser and risk roles.
+
+/// Role grants of one pool, created with init_roles.
+/// PDA derived with seeds: ["roles", pool_id]
+#[account]
+pub struct Roles {
+    /// Pool the roles apply to
+    pub pool_id: u8,
+
+    /// Members of each role, indexed by ROLE_* (default = empty slot)
+    pub members: [[Pubkey; MAX_ROLE_MEMBERS]; ROLE_COUNT],
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+/// Authority-gated instructions and role management
+pub const ROLE_ADMIN: u8 = 0;
+/// Operator-gated instructions
+pub const ROLE_OPERATOR: u8 = 1;
+/// pause_protocol
+pub const ROLE_PAUSER: u8 = 2;
+/// Risk limit setters
+pub const ROLE_RISK: u8 = 3;
+/// Number of roles
+pub const ROLE_COUNT: usize = 4;
+
+impl Roles {
+    /// Size of the Roles account in bytes.
+    ///
+    /// Calculation:
+    /// - 8 bytes: Anchor discriminator
+    /// - 1 byte: pool_id (u8)
+    /// - 512 bytes: members (4 roles × 4 × Pubkey)
+    /// - 1 byte: bump (u8)
+    pub const SIZE: usize = 8 + // discriminator
+        1 +   // pool_id
+        32 * MAX_ROLE_MEMBERS * ROLE_COUNT + // members
+        1; // bump
+
+    /// Whether `key` was granted `role` (implicit members not included)
+    pub fn has_role(&self, role: u8, key: &Pubkey) -> bool {
+        *key != Pubkey::default()
+            && self
+                .members
+                .get(role as usize)
+                .is_some_and(|members| members.contains(key))
+    }
+
+    /// Pool authority or a granted admin
+    pub fn is_admin(&self, pool: &Pool, key: &Pubkey) -> bool {
+        *key == pool.authority || self.has_role(ROLE_ADMIN, key)
+    }
+
+    /// Pool operator or a granted operator
+    pub fn is_operator(&self, pool: &Pool, key: &Pubkey) -> bool {
+        *key == pool.operator || self.has_role(ROLE_OPERATOR, key)
+    }
+
+    /// Admin or a granted pauser
+    pub fn is_pauser(&self, pool: &Pool, key: &Pubkey) -> bool {
+        self.is_admin(pool, key) || self.has_role(ROLE_PAUSER, key)
+    }
+
+    /// Admin or a granted risk manager
+    pub fn is_risk_manager(&self, pool: &Pool, key: &Pubkey) -> bool {
+        self.is_admin(pool, key) || self.has_role(ROLE_RISK, key)
+    }
+}
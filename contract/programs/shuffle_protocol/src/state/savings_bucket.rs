@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+
+// =============================================================================
+// SAVINGS BUCKET
+// =============================================================================
+// Labeled partition of a user's encrypted USDC. The UserProfile balance is
+// the trading bucket; USDC moved into a SavingsBucket with move_savings is
+// out of reach of orders, withdrawals and transfers until it is moved back.
+//
+// A DCA schedule can name a bucket as its funding source (funding_bucket):
+// execute_dca then checks and deducts the order from the bucket instead of
+// the trading balance, so a recurring buy cannot eat into trading funds.
+// Fills and unfilled refunds settle into the trading balance as usual.
+//
+// The balance is encrypted for the owner's user_pubkey like the profile's
+// balances. close_user_account does not see buckets: move their balance
+// back before closing the profile.
+
+/// One labeled USDC bucket of a user.
+/// PDA derived with seeds: ["savings_bucket", pool_id, owner.key().as_ref(), &[bucket_id]]
+#[account]
+pub struct SavingsBucket {
+    /// The wallet that owns this bucket (same as UserProfile.owner)
+    pub owner: Pubkey,
+
+    /// Bucket identifier (chosen by the owner, allows several buckets)
+    pub bucket_id: u8,
+
+    /// Display label, UTF-8 padded with zeros (e.g. "savings")
+    pub label: [u8; 16],
+
+    /// Encrypted USDC balance (Enc<Shared> for the owner's user_pubkey)
+    pub encrypted_balance: [u8; 32],
+
+    /// Nonce of the encrypted balance
+    pub nonce: u128,
+
+    /// Unix timestamp when the bucket was created
+    pub created_at: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl SavingsBucket {
+    /// Size of the SavingsBucket account in bytes.
+    ///
+    /// Calculation:
+    /// - 8 bytes: Anchor discriminator
+    /// - 32 bytes: owner (Pubkey)
+    /// - 1 byte: bucket_id (u8)
+    /// - 16 bytes: label
+    /// - 32 bytes: encrypted_balance
+    /// - 16 bytes: nonce (u128)
+    /// - 8 bytes: created_at (i64)
+    /// - 1 byte: bump (u8)
+    pub const SIZE: usize = 8 + // discriminator
+        32 +  // owner
+        1 +   // bucket_id
+        16 +  // label
+        32 +  // encrypted_balance
+        16 +  // nonce
+        8 +   // created_at
+        1; // bump
+}
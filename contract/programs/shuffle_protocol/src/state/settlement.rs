@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+// =============================================================================
+// SETTLEMENT PREFERENCE (Settlement currency)
+// =============================================================================
+// Opt-in side PDA of a UserProfile. While it exists and is passed to
+// settle_order, settling an order whose output is not USDC stages a
+// follow-up order selling the payout for USDC in the next batch. The
+// follow-up is staged whether the encrypted flag is on or off (with a zero
+// amount when off), so only the user can tell which.
+
+/// Encrypted settlement currency preference of one owner.
+/// PDA derived with seeds: ["settlement_preference", pool_id, owner.key().as_ref()]
+#[account]
+pub struct SettlementPreference {
+    /// Wallet the preference belongs to (same as UserProfile.owner)
+    pub owner: Pubkey,
+
+    /// Encrypted flag (1 = convert payouts into USDC)
+    pub convert_to_usdc: [u8; 32],
+
+    /// Nonce used to encrypt the flag with the account's user_pubkey
+    pub nonce: u128,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl SettlementPreference {
+    /// Size of the SettlementPreference account in bytes.
+    ///
+    /// Calculation:
+    /// - 8 bytes: Anchor discriminator
+    /// - 32 bytes: owner (Pubkey)
+    /// - 32 bytes: convert_to_usdc (ciphertext)
+    /// - 16 bytes: nonce (u128)
+    /// - 1 byte: bump (u8)
+    pub const SIZE: usize = 8 + // discriminator
+        32 +  // owner
+        32 +  // convert_to_usdc
+        16 +  // nonce
+        1; // bump
+}
@@ -0,0 +1,116 @@
+use anchor_lang::prelude::*;
+
+use super::NUM_PAIRS;
+
+// =============================================================================
+// PROTOCOL STATS (Analytics)
+// =============================================================================
+// Cumulative counters for dashboards, so they can read one account instead
+// of replaying every BatchLog. reveal_batch_callback records volume, spread
+// revenue and reveal latency; execute_swaps records reserve flows, execution
+// fees and how long the batch waited for its swaps.
+//
+// Durations are summed so averages are total / count.
+
+/// Cumulative protocol statistics.
+///
+/// PDA derived with seeds: ["protocol_stats", pool_id]
+#[account]
+pub struct ProtocolStats {
+    /// Batches revealed by reveal_batch_callback
+    pub batches_revealed: u64,
+
+    /// Batches whose vault↔reserve swaps were executed
+    pub batches_swapped: u64,
+
+    /// Executed Token A volume per pair (filled part of total_a_in)
+    pub volume_a: [u64; NUM_PAIRS],
+
+    /// Executed Token B volume per pair (filled part of total_b_in)
+    pub volume_b: [u64; NUM_PAIRS],
+
+    /// Revealed notional of all batches, in USDC base units (BatchLog.notional_usdc)
+    pub volume_usdc: u64,
+
+    /// Protocol execution revenue valued in USDC base units at oracle
+    /// prices: the spread kept by the reserves on absorbed surplus plus the
+    /// execution fees withheld from payouts.
+    pub fees_collected_usdc: u64,
+
+    /// Tokens moved vault → reserve per asset [USDC, TSLA, SPY, AAPL]
+    pub reserve_inflow: [u64; 4],
+
+    /// Tokens moved reserve → vault per asset
+    pub reserve_outflow: [u64; 4],
+
+    /// Highest share of a reserve paid out by one batch, in basis points
+    pub peak_reserve_utilization_bps: [u16; 4],
+
+    /// Sum of execute_batch → reveal callback times, in seconds
+    pub total_reveal_secs: u64,
+
+    /// Longest execute_batch → reveal callback time, in seconds
+    pub max_reveal_secs: u64,
+
+    /// Sum of reveal callback → execute_swaps times, in seconds
+    pub total_swap_secs: u64,
+
+    /// Longest reveal callback → execute_swaps time, in seconds
+    pub max_swap_secs: u64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl ProtocolStats {
+    /// Size of the ProtocolStats account in bytes.
+    ///
+    /// Calculation:
+    /// - 8 bytes: Anchor discriminator
+    /// - 8 bytes: batches_revealed (u64)
+    /// - 8 bytes: batches_swapped (u64)
+    /// - 48 bytes: volume_a (6 × u64)
+    /// - 48 bytes: volume_b (6 × u64)
+    /// - 8 bytes: volume_usdc (u64)
+    /// - 8 bytes: fees_collected_usdc (u64)
+    /// - 32 bytes: reserve_inflow (4 × u64)
+    /// - 32 bytes: reserve_outflow (4 × u64)
+    /// - 8 bytes: peak_reserve_utilization_bps (4 × u16)
+    /// - 8 bytes: total_reveal_secs (u64)
+    /// - 8 bytes: max_reveal_secs (u64)
+    /// - 8 bytes: total_swap_secs (u64)
+    /// - 8 bytes: max_swap_secs (u64)
+    /// - 1 byte: bump (u8)
+    pub const SIZE: usize = 8 + // discriminator
+        8 +   // batches_revealed
+        8 +   // batches_swapped
+        48 +  // volume_a
+        48 +  // volume_b
+        8 +   // volume_usdc
+        8 +   // fees_collected_usdc
+        32 +  // reserve_inflow
+        32 +  // reserve_outflow
+        8 +   // peak_reserve_utilization_bps
+        8 +   // total_reveal_secs
+        8 +   // max_reveal_secs
+        8 +   // total_swap_secs
+        8 +   // max_swap_secs
+        1; // bump
+
+    /// Record a revealed batch
+    pub fn record_reveal(&mut self, reveal_secs: u64, notional_usdc: u64, fees_usdc: u64) {
+        self.batches_revealed += 1;
+        self.volume_usdc = self.volume_usdc.saturating_add(notional_usdc);
+        self.fees_collected_usdc = self.fees_collected_usdc.saturating_add(fees_usdc);
+        self.total_reveal_secs = self.total_reveal_secs.saturating_add(reveal_secs);
+        self.max_reveal_secs = self.max_reveal_secs.max(reveal_secs);
+    }
+
+    /// Record a batch whose swaps were executed
+    pub fn record_swaps(&mut self, swap_secs: u64, fees_usdc: u64) {
+        self.batches_swapped += 1;
+        self.fees_collected_usdc = self.fees_collected_usdc.saturating_add(fees_usdc);
+        self.total_swap_secs = self.total_swap_secs.saturating_add(swap_secs);
+        self.max_swap_secs = self.max_swap_secs.max(swap_secs);
+    }
+}
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_TRANSFER_MEMO_LEN;
+
+// =============================================================================
+// TRANSFER MEMO (Encrypted payment references)
+// =============================================================================
+// Opt-in side PDA of a sender. internal_transfer stages an encrypted memo
+// here (ciphertext for the recipient's x25519 key, opaque to the program)
+// together with the computation it belongs to; the transfer callback emits
+// it in TransferEvent and clears it. The account is reused by every memo
+// the sender attaches.
+//
+// A memo staged by a later transfer replaces one whose callback has not run
+// yet; the computation check keeps it from being emitted with the wrong
+// transfer.
+
+/// Memo staged for the sender's queued transfer.
+/// PDA derived with seeds: ["transfer_memo", pool_id, sender.key().as_ref()]
+#[account]
+pub struct TransferMemo {
+    /// Wallet that attaches the memos
+    pub owner: Pubkey,
+
+    /// Computation account of the transfer the memo belongs to
+    pub computation: Pubkey,
+
+    /// Encrypted memo (empty once emitted)
+    pub memo: Vec<u8>,
+}
+
+impl TransferMemo {
+    /// Size of the TransferMemo account in bytes.
+    ///
+    /// Calculation:
+    /// - 8 bytes: Anchor discriminator
+    /// - 32 bytes: owner (Pubkey)
+    /// - 32 bytes: computation (Pubkey)
+    /// - 4 + 128 bytes: memo (Vec<u8>, up to MAX_TRANSFER_MEMO_LEN)
+    pub const SIZE: usize = 8 + // discriminator
+        32 +  // owner
+        32 +  // computation
+        4 + MAX_TRANSFER_MEMO_LEN; // memo
+}
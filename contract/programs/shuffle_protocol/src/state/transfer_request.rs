@@ -0,0 +1,105 @@
+use anchor_lang::prelude::*;
+
+// =============================================================================
+// TRANSFER REQUEST (Two-step transfer)
+// =============================================================================
+// A transfer that only completes once the recipient claims it, so a mistyped
+// recipient does not lose the funds. request_transfer deducts the amount
+// from the sender and escrows it encrypted for the MXE (escrow_transfer
+// circuit); claim_transfer credits it to the recipient, or after expires_at
+// reclaim_transfer returns it to the sender (release_escrow circuit). The
+// release callback closes the request and returns its rent to the sender.
+//
+// If the sender was short of funds the escrow holds zero: the chain cannot
+// tell, and the recipient simply claims nothing.
+//
+// A callback that never arrives would leave the request ESCROWING or
+// RELEASING for good; reset_transfer_request recovers it (see there).
+
+/// Escrow created, waiting for the escrow_transfer callback
+pub const TRANSFER_STATUS_ESCROWING: u8 = 0;
+
+/// Escrowed amount can be claimed (or reclaimed after expiry)
+pub const TRANSFER_STATUS_OPEN: u8 = 1;
+
+/// Claim or reclaim queued, waiting for the release_escrow callback
+pub const TRANSFER_STATUS_RELEASING: u8 = 2;
+
+/// Pending two-step transfer.
+/// PDA derived with seeds: ["transfer_request", pool_id, sender.key().as_ref(), transfer_id.to_le_bytes()]
+#[account]
+pub struct TransferRequest {
+    /// Sender-chosen ID (unique per sender)
+    pub transfer_id: u64,
+
+    /// Pool both privacy accounts belong to
+    pub pool_id: u8,
+
+    /// Sender wallet (receives the rent back)
+    pub sender: Pubkey,
+
+    /// Recipient wallet allowed to claim
+    pub recipient: Pubkey,
+
+    /// Asset being transferred (0=USDC, 1=TSLA, 2=SPY, 3=AAPL)
+    pub asset_id: u8,
+
+    /// Escrowed amount (Enc<Mxe, UserBalance> ciphertext)
+    pub escrow_amount: [u8; 32],
+
+    /// MXE nonce of the escrowed amount
+    pub escrow_nonce: u128,
+
+    /// Unix timestamp the request was created
+    pub created_at: i64,
+
+    /// Unix timestamp after which the recipient can no longer claim
+    pub expires_at: i64,
+
+    /// TRANSFER_STATUS_* value
+    pub status: u8,
+
+    /// Computation the ESCROWING or RELEASING status waits on; only its
+    /// callback is accepted
+    pub pending_computation: Pubkey,
+
+    /// Unix timestamp that computation was queued
+    pub queued_at: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl TransferRequest {
+    /// Size of the TransferRequest account in bytes.
+    ///
+    /// Calculation:
+    /// - 8 bytes: Anchor discriminator
+    /// - 8 bytes: transfer_id (u64)
+    /// - 1 byte: pool_id (u8)
+    /// - 32 bytes: sender (Pubkey)
+    /// - 32 bytes: recipient (Pubkey)
+    /// - 1 byte: asset_id (u8)
+    /// - 32 bytes: escrow_amount (ciphertext)
+    /// - 16 bytes: escrow_nonce (u128)
+    /// - 8 bytes: created_at (i64)
+    /// - 8 bytes: expires_at (i64)
+    /// - 1 byte: status (u8)
+    /// - 32 bytes: pending_computation (Pubkey)
+    /// - 8 bytes: queued_at (i64)
+    /// - 1 byte: bump (u8)
+    pub const SIZE: usize = 8 + // discriminator
+        8 +   // transfer_id
+        1 +   // pool_id
+        32 +  // sender
+        32 +  // recipient
+        1 +   // asset_id
+        32 +  // escrow_amount
+        16 +  // escrow_nonce
+        8 +   // created_at
+        8 +   // expires_at
+        1 +   // status
+        32 +  // pending_computation
+        8 +   // queued_at
+        1; // bump
+}
@@ -8,6 +8,11 @@ use anchor_lang::prelude::*;
 //
 // Assets: USDC, TSLA, SPY, AAPL (4 assets → 6 trading pairs)
 //
+// Layout: UserProfile only holds what every order or balance operation
+// touches. Opt-in features live in side PDAs paid for by the users who use
+// them (BeneficiaryConfig, Delegate, DcaSchedule, OrderHistory,
+// SettlementPreference). Accounts created with the older, larger layout are
+// rewritten by migrate_user_account.
 
 /// An embedded order record stored in UserProfile.
 /// Replaces the separate Order PDA accounts from the previous architecture.
@@ -27,18 +32,28 @@ pub struct OrderTicket {
 
     /// Nonce used for encryption (needed for user to decrypt order)
     pub order_nonce: u128,
+
+    /// Last batch the order may execute in (0 = this batch only).
+    /// If the order's pair doesn't execute at all, settle_order rolls it
+    /// over into the next batch while batch_id < valid_until_batch.
+    pub valid_until_batch: u64,
 }
 
 impl OrderTicket {
-    /// Size in bytes: 8 + 32 + 32 + 32 + 16 = 120
-    pub const SIZE: usize = 8 + 32 + 32 + 32 + 16;
+    /// Size in bytes: 8 + 32 + 32 + 32 + 16 + 8 = 128
+    pub const SIZE: usize = 8 + 32 + 32 + 32 + 16 + 8;
+
+    /// Whether the order may still be placed into `batch_id`
+    pub fn is_valid_for(&self, batch_id: u64) -> bool {
+        self.valid_until_batch == 0 || batch_id <= self.valid_until_batch
+    }
 }
 
 /// Per-user account that stores encrypted balances for all 4 assets.
 /// The balances are encrypted using Arcium MPC, so on-chain observers
 /// cannot see actual amounts.
 ///
-/// PDA derived with seeds: ["user", user_wallet.key().as_ref()]
+/// PDA derived with seeds: ["user", pool_id, user_wallet.key().as_ref()]
 #[account]
 pub struct UserProfile {
     /// The wallet that owns this profile.
@@ -63,29 +78,25 @@ pub struct UserProfile {
     /// Encrypted AAPL (tokenized Apple) balance.
     pub aapl_credit: [u8; 32],
 
-    // =========================================================================
-    // VIEWABLE BALANCES (re-encrypted for frontend display)
-    // =========================================================================
-    // These are encrypted with a shared key that the frontend can decrypt
-    // for UI display, while keeping on-chain values hidden.
-    pub usdc_viewable: [u8; 32],
-    pub tsla_viewable: [u8; 32],
-    pub spy_viewable: [u8; 32],
-    pub aapl_viewable: [u8; 32],
-
     /// Current pending order awaiting settlement.
     /// Only one order per user at a time. Must settle before placing new order.
     /// None means no pending order.
     pub pending_order: Option<OrderTicket>,
 
     /// Asset ID for pending MPC operation (0=USDC, 1=TSLA, 2=SPY, 3=AAPL).
-    /// Set during add_balance/sub_balance, read in callback to update correct balance.
+    /// Set when an order or transfer is queued, read in callback to update
+    /// the correct balance. Deposits and withdrawals carry their asset in a
+    /// PendingOperation instead.
     pub pending_asset_id: u8,
 
-    /// Pending withdrawal amount (in token units).
-    /// Set during sub_balance, used by callback for deferred token transfer.
+    /// Unused: withdrawals carry their amount in a PendingOperation.
+    /// Kept so the account layout does not change.
     pub pending_withdrawal_amount: u64,
 
+    /// Source asset ID of the order being settled.
+    /// Set during settle_order, read in callback to credit any unfilled refund.
+    pub pending_refund_asset_id: u8,
+
     // =========================================================================
     // PER-ASSET NONCES - Each asset tracks its own encryption nonce
     // =========================================================================
@@ -104,8 +115,57 @@ pub struct UserProfile {
     /// Total USDC claimed from faucet (tracked to enforce per-user limit).
     pub total_faucet_claimed: u64,
 
+    /// Unix timestamp of the owner's last signed action.
+    /// Drives the beneficiary inactivity timer (see BeneficiaryConfig).
+    pub last_active_at: i64,
+
+    /// Order staged by settle_and_place_order, or a good-til-batch order
+    /// rolled over at settlement (batch_id unset).
+    /// Placed into the then-current batch by place_staged_order once the
+    /// pending order has settled. Cleared by place_order.
+    pub staged_order: Option<OrderTicket>,
+
+    /// Source asset ID of the staged order.
+    pub staged_asset_id: u8,
+
+    /// Whether the order being settled rolls over into the next batch.
+    /// Set during settle_order, read in callback to stage the order again.
+    pub pending_rollover: bool,
+
+    /// Whether the order being settled stages a USDC conversion.
+    /// Set during settle_order, read in callback to stage the follow-up order.
+    pub pending_conversion: bool,
+
+    /// Whether the account rent was reimbursed by the RentSponsor pool.
+    /// Rent freed from a sponsored account belongs back in the pool.
+    pub rent_sponsored: bool,
+
     /// PDA bump seed.
     pub bump: u8,
+
+    /// Layout version (see UserProfile::VERSION). Fields added later go after
+    /// it and migrate_user_profile zero-extends old accounts to them.
+    pub version: u8,
+
+    // =========================================================================
+    // IN-FLIGHT BALANCE NONCES (version 2)
+    // =========================================================================
+    // Nonce of each balance an order computation read when it was queued.
+    // Deposits and withdrawals run concurrently with orders, so the callback
+    // discards results computed against a balance that has since been
+    // replaced (PendingOperationStale).
+    /// Source balance (or savings bucket) read by the pending order's
+    /// accumulate computation
+    pub pending_order_nonce: u128,
+
+    /// Source balance read by the basket order's accumulate computation
+    pub pending_basket_nonce: u128,
+
+    /// Output balance read by the in-flight settlement
+    pub pending_payout_nonce: u128,
+
+    /// Source balance read by the in-flight settlement (refund side)
+    pub pending_refund_nonce: u128,
 }
 
 impl UserProfile {
@@ -123,20 +183,36 @@ impl UserProfile {
         32 +  // tsla_credit
         32 +  // spy_credit
         32 +  // aapl_credit
-        32 +  // usdc_viewable
-        32 +  // tsla_viewable
-        32 +  // spy_viewable
-        32 +  // aapl_viewable
         1 + OrderTicket::SIZE + // pending_order (Option)
         1 +   // pending_asset_id
         8 +   // pending_withdrawal_amount
+        1 +   // pending_refund_asset_id
         16 +  // usdc_nonce (u128)
         16 +  // tsla_nonce (u128)
         16 +  // spy_nonce (u128)
         16 +  // aapl_nonce (u128)
         8 +   // order_count
         8 +   // total_faucet_claimed
-        1; // bump
+        8 +   // last_active_at
+        1 + OrderTicket::SIZE + // staged_order (Option)
+        1 +   // staged_asset_id
+        1 +   // pending_rollover
+        1 +   // pending_conversion
+        1 +   // rent_sponsored
+        1 +   // bump
+        1 +   // version
+        16 +  // pending_order_nonce (u128)
+        16 +  // pending_basket_nonce (u128)
+        16 +  // pending_payout_nonce (u128)
+        16; // pending_refund_nonce (u128)
+
+    /// Current layout version, written on creation and by migrate_user_profile.
+    /// 2: in-flight balance nonces
+    pub const VERSION: u8 = 2;
+
+    /// Byte offset of `version`. Accounts created before versioning end
+    /// right here (version 0).
+    pub const VERSION_OFFSET: usize = Self::SIZE - 1 - 16 * 4;
 
     /// Get the encrypted balance for a given asset ID
     pub fn get_credit(&self, asset_id: u8) -> [u8; 32] {
@@ -181,6 +257,101 @@ impl UserProfile {
             _ => self.usdc_nonce = nonce,
         }
     }
+
+    /// Seed the in-flight balance nonces of an account migrated from an
+    /// older layout: an order or settlement computation still in flight read
+    /// the balances that are current now (a basket's source asset is not
+    /// known here, so an in-flight basket is rejected as stale)
+    pub fn seed_pending_nonces(&mut self) {
+        self.pending_order_nonce = self.get_nonce(self.pending_asset_id);
+        self.pending_payout_nonce = self.get_nonce(self.pending_asset_id);
+        self.pending_refund_nonce = self.get_nonce(self.pending_refund_asset_id);
+    }
+}
+
+/// admin_clear_pending reason: an MPC callback failed after its queue
+/// instruction set the pending state
+pub const CLEAR_PENDING_REASON_FAILED_CALLBACK: u8 = 0;
+/// admin_clear_pending reason: the pending order's batch was discarded or
+/// never received it
+pub const CLEAR_PENDING_REASON_BATCH_MISMATCH: u8 = 1;
+/// admin_clear_pending reason: any other support case
+pub const CLEAR_PENDING_REASON_OTHER: u8 = 2;
+
+/// UserProfile layout before the size audit, read by migrate_user_account.
+/// It carried four viewable balances that were never written and the
+/// settlement preference inline (now a side PDA, see SettlementPreference).
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct LegacyUserProfile {
+    pub owner: Pubkey,
+    pub user_pubkey: [u8; 32],
+    pub usdc_credit: [u8; 32],
+    pub tsla_credit: [u8; 32],
+    pub spy_credit: [u8; 32],
+    pub aapl_credit: [u8; 32],
+    pub viewable: [[u8; 32]; 4],
+    pub pending_order: Option<OrderTicket>,
+    pub pending_asset_id: u8,
+    pub pending_withdrawal_amount: u64,
+    pub pending_refund_asset_id: u8,
+    pub usdc_nonce: u128,
+    pub tsla_nonce: u128,
+    pub spy_nonce: u128,
+    pub aapl_nonce: u128,
+    pub order_count: u64,
+    pub total_faucet_claimed: u64,
+    pub last_active_at: i64,
+    pub staged_order: Option<OrderTicket>,
+    pub staged_asset_id: u8,
+    pub pending_rollover: bool,
+    /// Inline preference (encrypted flag, nonce)
+    pub settlement_preference: Option<([u8; 32], u128)>,
+    pub pending_conversion: bool,
+    pub bump: u8,
+}
+
+impl LegacyUserProfile {
+    /// Size of the legacy account: UserProfile::SIZE plus the viewable
+    /// balances (4 × 32) and the inline preference (1 + 48), minus
+    /// rent_sponsored, version and the in-flight balance nonces
+    pub const SIZE: usize = UserProfile::SIZE + 4 * 32 + (1 + 32 + 16) - 2 - 16 * 4;
+
+    /// Current layout of the same account. An inline settlement preference
+    /// is not carried over; the owner sets it again on the side PDA.
+    pub fn into_current(self) -> UserProfile {
+        let mut profile = UserProfile {
+            owner: self.owner,
+            user_pubkey: self.user_pubkey,
+            usdc_credit: self.usdc_credit,
+            tsla_credit: self.tsla_credit,
+            spy_credit: self.spy_credit,
+            aapl_credit: self.aapl_credit,
+            pending_order: self.pending_order,
+            pending_asset_id: self.pending_asset_id,
+            pending_withdrawal_amount: self.pending_withdrawal_amount,
+            pending_refund_asset_id: self.pending_refund_asset_id,
+            usdc_nonce: self.usdc_nonce,
+            tsla_nonce: self.tsla_nonce,
+            spy_nonce: self.spy_nonce,
+            aapl_nonce: self.aapl_nonce,
+            order_count: self.order_count,
+            total_faucet_claimed: self.total_faucet_claimed,
+            last_active_at: self.last_active_at,
+            staged_order: self.staged_order,
+            staged_asset_id: self.staged_asset_id,
+            pending_rollover: self.pending_rollover,
+            pending_conversion: self.pending_conversion,
+            rent_sponsored: false,
+            bump: self.bump,
+            version: UserProfile::VERSION,
+            pending_order_nonce: 0,
+            pending_basket_nonce: 0,
+            pending_payout_nonce: 0,
+            pending_refund_nonce: 0,
+        };
+        profile.seed_pending_nonces();
+        profile
+    }
 }
 
 // Keep the old name as a type alias for backward compatibility during migration
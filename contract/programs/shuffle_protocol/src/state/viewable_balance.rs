@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+
+// =============================================================================
+// VIEWABLE BALANCE (View keys)
+// =============================================================================
+// Opt-in side PDA of a UserProfile. The owner designates an x25519 viewing
+// key (a frontend session key or an auditor's key); refresh_viewable_balance
+// re-encrypts one asset's credit under it through the reencrypt_balance
+// circuit. The copy is a snapshot: it goes stale with every balance change
+// until refreshed again, and it grants no spending rights.
+//
+// Replacing the viewing key zeroes every copy, and a refresh queued under
+// the old key is dropped by its callback, so the stored copies are always
+// readable with the current key.
+
+/// Balances of one owner re-encrypted under a viewing key.
+/// PDA derived with seeds: ["viewable_balance", pool_id, owner.key().as_ref()]
+#[account]
+pub struct ViewableBalance {
+    /// Wallet the balances belong to (same as UserProfile.owner)
+    pub owner: Pubkey,
+
+    /// x25519 public key the balances are re-encrypted for
+    pub viewing_pubkey: [u8; 32],
+
+    /// Re-encrypted balances (USDC, TSLA, SPY, AAPL)
+    pub balances: [[u8; 32]; 4],
+
+    /// Nonces of the re-encrypted balances
+    pub nonces: [u128; 4],
+
+    /// Unix timestamp of each balance's last refresh (0 = never)
+    pub refreshed_at: [i64; 4],
+
+    /// Asset ID of the refresh currently queued in MPC
+    pub pending_asset_id: u8,
+
+    /// Viewing key the queued refresh encrypts for
+    pub pending_viewing_pubkey: [u8; 32],
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl ViewableBalance {
+    /// Size of the ViewableBalance account in bytes.
+    ///
+    /// Calculation:
+    /// - 8 bytes: Anchor discriminator
+    /// - 32 bytes: owner (Pubkey)
+    /// - 32 bytes: viewing_pubkey ([u8; 32])
+    /// - 128 bytes: balances ([[u8; 32]; 4])
+    /// - 64 bytes: nonces ([u128; 4])
+    /// - 32 bytes: refreshed_at ([i64; 4])
+    /// - 1 byte: pending_asset_id (u8)
+    /// - 32 bytes: pending_viewing_pubkey ([u8; 32])
+    /// - 1 byte: bump (u8)
+    pub const SIZE: usize = 8 + // discriminator
+        32 +  // owner
+        32 +  // viewing_pubkey
+        128 + // balances
+        64 +  // nonces
+        32 +  // refreshed_at
+        1 +   // pending_asset_id
+        32 +  // pending_viewing_pubkey
+        1; // bump
+}
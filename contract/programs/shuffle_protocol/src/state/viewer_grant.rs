@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+
+// =============================================================================
+// VIEWER GRANT (Auditor disclosure)
+// =============================================================================
+// Selective disclosure for compliance. add_viewer re-encrypts all four of
+// the owner's balances for an auditor's x25519 key through the
+// disclose_balances circuit and stores them in a grant per (owner, viewer),
+// so an owner can disclose to several auditors at once. Unlike
+// ViewableBalance (one frontend key, refreshed per asset) a grant is a
+// point-in-time statement of every balance; calling add_viewer again
+// refreshes it and remove_viewer revokes it.
+//
+// Revoking only stops future disclosures: the auditor keeps whatever it
+// already decrypted.
+
+/// Balances of one owner disclosed to one viewer.
+/// PDA derived with seeds: ["viewer_grant", pool_id, owner.key().as_ref(), viewer_pubkey]
+#[account]
+pub struct ViewerGrant {
+    /// Wallet the balances belong to (same as UserProfile.owner)
+    pub owner: Pubkey,
+
+    /// x25519 public key of the auditor
+    pub viewer_pubkey: [u8; 32],
+
+    /// Disclosed balances (USDC, TSLA, SPY, AAPL)
+    pub balances: [[u8; 32]; 4],
+
+    /// Nonces of the disclosed balances
+    pub nonces: [u128; 4],
+
+    /// Unix timestamp the grant was created
+    pub granted_at: i64,
+
+    /// Unix timestamp of the last disclosure (0 = still queued)
+    pub disclosed_at: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl ViewerGrant {
+    /// Size of the ViewerGrant account in bytes.
+    ///
+    /// Calculation:
+    /// - 8 bytes: Anchor discriminator
+    /// - 32 bytes: owner (Pubkey)
+    /// - 32 bytes: viewer_pubkey ([u8; 32])
+    /// - 128 bytes: balances ([[u8; 32]; 4])
+    /// - 64 bytes: nonces ([u128; 4])
+    /// - 8 bytes: granted_at (i64)
+    /// - 8 bytes: disclosed_at (i64)
+    /// - 1 byte: bump (u8)
+    pub const SIZE: usize = 8 + // discriminator
+        32 +  // owner
+        32 +  // viewer_pubkey
+        128 + // balances
+        64 +  // nonces
+        8 +   // granted_at
+        8 +   // disclosed_at
+        1; // bump
+}
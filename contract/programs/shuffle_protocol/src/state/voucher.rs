@@ -0,0 +1,103 @@
+use anchor_lang::prelude::*;
+
+// =============================================================================
+// VOUCHER (Claim-link deposit)
+// =============================================================================
+// An encrypted amount anyone can claim by presenting the voucher secret, for
+// sending funds to someone who has no privacy account yet. The secret is an
+// ed25519 keypair generated by the funder and shared as a link; its public
+// key (claim_key) seeds the PDA. claim_voucher requires a signature from the
+// claim key alongside the claimer's wallet, so a claim seen in flight cannot
+// be redirected to another wallet.
+//
+// create_voucher deducts the amount from the funder and escrows it for the
+// MXE (fund_voucher circuit); claim_voucher credits it to the claimer's
+// UserProfile, which can be created in the same transaction. After
+// expires_at only the funder can reclaim_voucher. The redeem_voucher
+// callback closes the voucher and returns its rent to the funder.
+//
+// As with two-step transfers, a funder short of funds escrows zero, and
+// reset_voucher recovers a voucher whose callback never arrived.
+
+/// Voucher created, waiting for the fund_voucher callback
+pub const VOUCHER_STATUS_FUNDING: u8 = 0;
+
+/// Escrowed amount can be claimed (or reclaimed after expiry)
+pub const VOUCHER_STATUS_OPEN: u8 = 1;
+
+/// Claim or reclaim queued, waiting for the redeem_voucher callback
+pub const VOUCHER_STATUS_REDEEMING: u8 = 2;
+
+/// Claim-link voucher.
+/// PDA derived with seeds: ["voucher", pool_id, claim_key.as_ref()]
+#[account]
+pub struct Voucher {
+    /// Public key of the voucher secret (signs the claim)
+    pub claim_key: Pubkey,
+
+    /// Pool the funder's privacy account belongs to
+    pub pool_id: u8,
+
+    /// Funder wallet (receives the rent back, may reclaim after expiry)
+    pub funder: Pubkey,
+
+    /// Asset held by the voucher (0=USDC, 1=TSLA, 2=SPY, 3=AAPL)
+    pub asset_id: u8,
+
+    /// Escrowed amount (Enc<Mxe, UserBalance> ciphertext)
+    pub escrow_amount: [u8; 32],
+
+    /// MXE nonce of the escrowed amount
+    pub escrow_nonce: u128,
+
+    /// Unix timestamp the voucher was created
+    pub created_at: i64,
+
+    /// Unix timestamp after which the voucher can no longer be claimed
+    pub expires_at: i64,
+
+    /// VOUCHER_STATUS_* value
+    pub status: u8,
+
+    /// Computation the FUNDING or REDEEMING status waits on; only its
+    /// callback is accepted
+    pub pending_computation: Pubkey,
+
+    /// Unix timestamp that computation was queued
+    pub queued_at: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl Voucher {
+    /// Size of the Voucher account in bytes.
+    ///
+    /// Calculation:
+    /// - 8 bytes: Anchor discriminator
+    /// - 32 bytes: claim_key (Pubkey)
+    /// - 1 byte: pool_id (u8)
+    /// - 32 bytes: funder (Pubkey)
+    /// - 1 byte: asset_id (u8)
+    /// - 32 bytes: escrow_amount (ciphertext)
+    /// - 16 bytes: escrow_nonce (u128)
+    /// - 8 bytes: created_at (i64)
+    /// - 8 bytes: expires_at (i64)
+    /// - 1 byte: status (u8)
+    /// - 32 bytes: pending_computation (Pubkey)
+    /// - 8 bytes: queued_at (i64)
+    /// - 1 byte: bump (u8)
+    pub const SIZE: usize = 8 + // discriminator
+        32 +  // claim_key
+        1 +   // pool_id
+        32 +  // funder
+        1 +   // asset_id
+        32 +  // escrow_amount
+        16 +  // escrow_nonce
+        8 +   // created_at
+        8 +   // expires_at
+        1 +   // status
+        32 +  // pending_computation
+        8 +   // queued_at
+        1; // bump
+}
@@ -52,6 +52,17 @@ async function retry(fn, maxRetries = 5, delayMs = 1500) {
   }
 }
 
+// Circuits in Pool.bootstrap_flags bit order (BOOTSTRAP_COMP_DEFS)
+const BOOTSTRAP_CIRCUITS = [
+  'add_balance', 'credit_balance', 'sub_balance', 'transfer', 'transfer_multi',
+  'transfer_batch', 'escrow_transfer', 'release_escrow', 'accumulate_order',
+  'init_batch_state', 'reveal_batch', 'calculate_payout', 'init_auction_state',
+  'submit_auction_bid', 'reveal_auction', 'sweep_balance', 'reencrypt_balance',
+  'rotate_user_key', 'check_balances_zero', 'disclose_balances', 'accumulate_basket',
+  'accumulate_order_usd', 'accumulate_order_delegated', 'sub_balance_delegated',
+  'move_savings', 'stream_transfer', 'fund_voucher', 'redeem_voucher', 'recover_account',
+];
+
 async function initCompDef(program, owner, provider, circuitName, methodName) {
   const baseSeedCompDefAcc = getArciumAccountBaseSeed('ComputationDefinitionAccount');
   const offset = getCompDefAccOffset(circuitName);
@@ -176,6 +187,31 @@ async function main() {
     console.log('✓ batch accumulator already exists');
   }
 
+  // Every event-emitting instruction (bootstrap included) writes the cursor.
+  const [eventCursorPDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from('event_cursor'), Buffer.from([0])],
+    program.programId,
+  );
+  const eventCursorInfo = await connection.getAccountInfo(eventCursorPDA);
+  if (!eventCursorInfo) {
+    console.log('Initializing event cursor...');
+    await retry(async () => {
+      await program.methods
+        .initEventCursor()
+        .accountsPartial({
+          payer: owner.publicKey,
+          pool: poolPDA,
+          eventCursor: eventCursorPDA,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([owner])
+        .rpc({ commitment: 'confirmed' });
+    });
+    console.log('✓ event cursor initialized');
+  } else {
+    console.log('✓ event cursor already exists');
+  }
+
   console.log('Initializing computation definitions...');
   await initCompDef(program, owner, provider, 'add_balance', 'initAddBalanceCompDef');
   await initCompDef(program, owner, provider, 'sub_balance', 'initSubBalanceCompDef');
@@ -240,6 +276,47 @@ async function main() {
     console.log('! batch state init skipped:', e.message || String(e));
   }
 
+  // Record what is in place on the Pool (Pool.bootstrap_flags).
+  // Order must match BOOTSTRAP_COMP_DEFS in the program.
+  try {
+    const compDefAccounts = BOOTSTRAP_CIRCUITS.map((name) => ({
+      pubkey: getCompDefAccAddress(
+        program.programId,
+        Buffer.from(getCompDefAccOffset(name)).readUInt32LE(),
+      ),
+      isSigner: false,
+      isWritable: false,
+    }));
+    const slotPDAs = [];
+    for (const slot of [0, 1]) {
+      const [pda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('batch_accumulator'), Buffer.from([0]), Buffer.from([slot])],
+        program.programId,
+      );
+      slotPDAs.push((await connection.getAccountInfo(pda)) ? pda : null);
+    }
+    for (let first = 0; first < compDefAccounts.length; first += 10) {
+      await retry(async () => {
+        await program.methods
+          .bootstrap(first)
+          .accountsPartial({
+            caller: owner.publicKey,
+            pool: poolPDA,
+            batchAccumulator0: slotPDAs[0],
+            batchAccumulator1: slotPDAs[1],
+            arciumProgram: getArciumProgramId(),
+          })
+          .remainingAccounts(compDefAccounts.slice(first, first + 10))
+          .signers([owner])
+          .rpc({ commitment: 'confirmed' });
+      });
+    }
+    const pool = await program.account.pool.fetch(poolPDA);
+    console.log(`✓ bootstrap flags recorded: 0x${pool.bootstrapFlags.toString(16)}`);
+  } catch (e) {
+    console.log('! bootstrap recording skipped:', e.message || String(e));
+  }
+
   console.log('Done. Devnet program bootstrap complete.');
 }
 
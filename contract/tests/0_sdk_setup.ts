@@ -139,6 +139,7 @@ describe("SDK Localnet Setup", function() {
   // PDAs
   let poolPDA: PublicKey;
   let batchAccumulatorPDA: PublicKey;
+  let nextBatchAccumulatorPDA: PublicKey;
 
   before(async function() {
     console.log("\n======================================================================");
@@ -149,11 +150,16 @@ describe("SDK Localnet Setup", function() {
 
     // Calculate PDAs
     [poolPDA] = PublicKey.findProgramAddressSync(
-      [Buffer.from("pool")],
+      [Buffer.from("pool"), Buffer.from([0])],
       program.programId
     );
+    // Double-buffered accumulators: slot 0 starts active, slot 1 idle
     [batchAccumulatorPDA] = PublicKey.findProgramAddressSync(
-      [Buffer.from("batch_accumulator")],
+      [Buffer.from("batch_accumulator"), Buffer.from([0]), Buffer.from([0])],
+      program.programId
+    );
+    [nextBatchAccumulatorPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("batch_accumulator"), Buffer.from([0]), Buffer.from([1])],
       program.programId
     );
 
@@ -191,21 +197,21 @@ describe("SDK Localnet Setup", function() {
     console.log(`  ✓ AAPL: ${aaplMint.toBase58()}`);
 
     // Derive vault PDAs
-    const [vaultUsdcPDA] = PublicKey.findProgramAddressSync([Buffer.from("vault"), Buffer.from("usdc")], program.programId);
-    const [vaultTslaPDA] = PublicKey.findProgramAddressSync([Buffer.from("vault"), Buffer.from("tsla")], program.programId);
-    const [vaultSpyPDA] = PublicKey.findProgramAddressSync([Buffer.from("vault"), Buffer.from("spy")], program.programId);
-    const [vaultAaplPDA] = PublicKey.findProgramAddressSync([Buffer.from("vault"), Buffer.from("aapl")], program.programId);
+    const [vaultUsdcPDA] = PublicKey.findProgramAddressSync([Buffer.from("vault"), Buffer.from([0]), Buffer.from("usdc")], program.programId);
+    const [vaultTslaPDA] = PublicKey.findProgramAddressSync([Buffer.from("vault"), Buffer.from([0]), Buffer.from("tsla")], program.programId);
+    const [vaultSpyPDA] = PublicKey.findProgramAddressSync([Buffer.from("vault"), Buffer.from([0]), Buffer.from("spy")], program.programId);
+    const [vaultAaplPDA] = PublicKey.findProgramAddressSync([Buffer.from("vault"), Buffer.from([0]), Buffer.from("aapl")], program.programId);
 
     // Derive reserve PDAs
-    const [reserveUsdcPDA] = PublicKey.findProgramAddressSync([Buffer.from("reserve"), Buffer.from("usdc")], program.programId);
-    const [reserveTslaPDA] = PublicKey.findProgramAddressSync([Buffer.from("reserve"), Buffer.from("tsla")], program.programId);
-    const [reserveSpyPDA] = PublicKey.findProgramAddressSync([Buffer.from("reserve"), Buffer.from("spy")], program.programId);
-    const [reserveAaplPDA] = PublicKey.findProgramAddressSync([Buffer.from("reserve"), Buffer.from("aapl")], program.programId);
+    const [reserveUsdcPDA] = PublicKey.findProgramAddressSync([Buffer.from("reserve"), Buffer.from([0]), Buffer.from("usdc")], program.programId);
+    const [reserveTslaPDA] = PublicKey.findProgramAddressSync([Buffer.from("reserve"), Buffer.from([0]), Buffer.from("tsla")], program.programId);
+    const [reserveSpyPDA] = PublicKey.findProgramAddressSync([Buffer.from("reserve"), Buffer.from([0]), Buffer.from("spy")], program.programId);
+    const [reserveAaplPDA] = PublicKey.findProgramAddressSync([Buffer.from("reserve"), Buffer.from([0]), Buffer.from("aapl")], program.programId);
 
     console.log("\n  Initializing pool...");
     await retryWithBackoff(async () => {
       await program.methods
-        .initialize(50, 8) // feeRate, minOrdersToExecute
+        .initialize(0, 50, 8) // poolId, feeRate, minOrdersToExecute
         .accountsPartial({
           payer: owner.publicKey,
           authority: owner.publicKey,
@@ -251,20 +257,77 @@ describe("SDK Localnet Setup", function() {
       return;
     }
 
-    console.log("\n  Initializing BatchAccumulator...");
+    console.log("\n  Initializing BatchAccumulator slots...");
+    for (const [slot, pda] of [batchAccumulatorPDA, nextBatchAccumulatorPDA].entries()) {
+      await retryWithBackoff(async () => {
+        await program.methods
+          .initBatchAccumulator(slot)
+          .accountsPartial({
+            pool: poolPDA,
+            payer: owner.publicKey,
+            batchAccumulator: pda,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([owner])
+          .rpc({ commitment: "confirmed" });
+      });
+    }
+
+    console.log(`  ✓ BatchAccumulator slots at ${batchAccumulatorPDA.toBase58()}, ${nextBatchAccumulatorPDA.toBase58()}`);
+  });
+
+  it("Initializes EventCursor", async function() {
+    const [eventCursorPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("event_cursor"), Buffer.from([0])],
+      program.programId
+    );
+    const accInfo = await connection.getAccountInfo(eventCursorPDA);
+    if (accInfo) {
+      console.log("  ✓ EventCursor already exists");
+      return;
+    }
+
     await retryWithBackoff(async () => {
       await program.methods
-        .initBatchAccumulator()
+        .initEventCursor()
         .accountsPartial({
           payer: owner.publicKey,
-          batchAccumulator: batchAccumulatorPDA,
+          pool: poolPDA,
+          eventCursor: eventCursorPDA,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([owner])
+        .rpc({ commitment: "confirmed" });
+    });
+
+    console.log(`  ✓ EventCursor at ${eventCursorPDA.toBase58()}`);
+  });
+
+  it("Initializes ProtocolStats", async function() {
+    const [protocolStatsPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("protocol_stats"), Buffer.from([0])],
+      program.programId
+    );
+    const accInfo = await connection.getAccountInfo(protocolStatsPDA);
+    if (accInfo) {
+      console.log("  ✓ ProtocolStats already exists");
+      return;
+    }
+
+    await retryWithBackoff(async () => {
+      await program.methods
+        .initProtocolStats()
+        .accountsPartial({
+          payer: owner.publicKey,
+          pool: poolPDA,
+          protocolStats: protocolStatsPDA,
           systemProgram: SystemProgram.programId,
         })
         .signers([owner])
         .rpc({ commitment: "confirmed" });
     });
 
-    console.log(`  ✓ BatchAccumulator at ${batchAccumulatorPDA.toBase58()}`);
+    console.log(`  ✓ ProtocolStats at ${protocolStatsPDA.toBase58()}`);
   });
 
   it("Initializes computation definitions", async function() {
@@ -298,6 +361,7 @@ describe("SDK Localnet Setup", function() {
       await program.methods
         .initBatchState(initBatchStateOffset)
         .accountsPartial({
+          pool: poolPDA,
           payer: owner.publicKey,
           batchAccumulator: batchAccumulatorPDA,
           mxeAccount: getMXEAccAddress(program.programId),
@@ -411,7 +475,7 @@ describe("SDK Localnet Setup", function() {
 
       // 4. Derive user account PDA
       const [userAccountPDA] = PublicKey.findProgramAddressSync(
-        [Buffer.from("user"), userKeypair.publicKey.toBuffer()],
+        [Buffer.from("user"), Buffer.from([0]), userKeypair.publicKey.toBuffer()],
         program.programId
       );
 
@@ -433,6 +497,7 @@ describe("SDK Localnet Setup", function() {
             new anchor.BN(deserializeLE(initialNonce).toString())
           )
           .accountsPartial({
+            pool: poolPDA,
             payer: owner.publicKey,
             owner: userKeypair.publicKey,
             userAccount: userAccountPDA,
@@ -458,7 +523,7 @@ describe("SDK Localnet Setup", function() {
 
       // 7. Deposit USDC into privacy account
       const [vaultUsdcPDA] = PublicKey.findProgramAddressSync(
-        [Buffer.from("vault"), Buffer.from("usdc")],
+        [Buffer.from("vault"), Buffer.from([0]), Buffer.from("usdc")],
         program.programId
       );
 
@@ -555,7 +620,8 @@ describe("SDK Localnet Setup", function() {
             Array.from(encryptedOrder[2]),
             Array.from(user.pubKey),
             new anchor.BN(deserializeLE(orderNonce).toString()),
-            0 // USDC - users are selling USDC to buy TSLA/SPY
+            0, // USDC - users are selling USDC to buy TSLA/SPY
+            new anchor.BN(0) // valid_until_batch: no rollover
           )
           .accountsPartial({
             payer: user.keypair.publicKey,
@@ -150,6 +150,7 @@ let spyMint: PublicKey;
 let aaplMint: PublicKey;
 let poolPDA: PublicKey;
 let batchAccumulatorPDA: PublicKey;
+let nextBatchAccumulatorPDA: PublicKey;
 let mxePublicKey: Uint8Array;
 
 interface TestUser {
@@ -265,12 +266,17 @@ describe.skip("Full Flow Integration Test", () => {
     // STEP 0A: Get PDAs
     // =========================================================================
     [poolPDA] = PublicKey.findProgramAddressSync(
-      [Buffer.from("pool")],
+      [Buffer.from("pool"), Buffer.from([0])],
       program.programId
     );
 
+    // Double-buffered accumulators: slot 0 starts active, slot 1 idle
     [batchAccumulatorPDA] = PublicKey.findProgramAddressSync(
-      [Buffer.from("batch_accumulator")],
+      [Buffer.from("batch_accumulator"), Buffer.from([0]), Buffer.from([0])],
+      program.programId
+    );
+    [nextBatchAccumulatorPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("batch_accumulator"), Buffer.from([0]), Buffer.from([1])],
       program.programId
     );
 
@@ -304,43 +310,43 @@ describe.skip("Full Flow Integration Test", () => {
 
       // Derive vault PDAs
       const [vaultUsdcPDA] = PublicKey.findProgramAddressSync(
-        [Buffer.from("vault"), Buffer.from("usdc")],
+        [Buffer.from("vault"), Buffer.from([0]), Buffer.from("usdc")],
         program.programId
       );
       const [vaultTslaPDA] = PublicKey.findProgramAddressSync(
-        [Buffer.from("vault"), Buffer.from("tsla")],
+        [Buffer.from("vault"), Buffer.from([0]), Buffer.from("tsla")],
         program.programId
       );
       const [vaultSpyPDA] = PublicKey.findProgramAddressSync(
-        [Buffer.from("vault"), Buffer.from("spy")],
+        [Buffer.from("vault"), Buffer.from([0]), Buffer.from("spy")],
         program.programId
       );
       const [vaultAaplPDA] = PublicKey.findProgramAddressSync(
-        [Buffer.from("vault"), Buffer.from("aapl")],
+        [Buffer.from("vault"), Buffer.from([0]), Buffer.from("aapl")],
         program.programId
       );
 
       // Derive reserve PDAs
       const [reserveUsdcPDA] = PublicKey.findProgramAddressSync(
-        [Buffer.from("reserve"), Buffer.from("usdc")],
+        [Buffer.from("reserve"), Buffer.from([0]), Buffer.from("usdc")],
         program.programId
       );
       const [reserveTslaPDA] = PublicKey.findProgramAddressSync(
-        [Buffer.from("reserve"), Buffer.from("tsla")],
+        [Buffer.from("reserve"), Buffer.from([0]), Buffer.from("tsla")],
         program.programId
       );
       const [reserveSpyPDA] = PublicKey.findProgramAddressSync(
-        [Buffer.from("reserve"), Buffer.from("spy")],
+        [Buffer.from("reserve"), Buffer.from([0]), Buffer.from("spy")],
         program.programId
       );
       const [reserveAaplPDA] = PublicKey.findProgramAddressSync(
-        [Buffer.from("reserve"), Buffer.from("aapl")],
+        [Buffer.from("reserve"), Buffer.from([0]), Buffer.from("aapl")],
         program.programId
       );
 
       // Derive faucet vault PDA
       const [faucetVaultPDA] = PublicKey.findProgramAddressSync(
-        [Buffer.from("faucet_usdc")],
+        [Buffer.from("faucet_usdc"), Buffer.from([0])],
         program.programId
       );
 
@@ -348,7 +354,7 @@ describe.skip("Full Flow Integration Test", () => {
       console.log("  Initializing protocol...");
       await retryWithBackoff(async () => {
         await program.methods
-          .initialize(50, 8)
+          .initialize(0, 50, 8)
           .accountsPartial({
             payer: owner.publicKey,
             authority: owner.publicKey,
@@ -402,21 +408,64 @@ describe.skip("Full Flow Integration Test", () => {
       const batch = await program.account.batchAccumulator.fetch(batchAccumulatorPDA);
       console.log(`BatchAccumulator exists: batch_id=${batch.batchId.toString()}, order_count=${batch.orderCount}`);
     } else {
-      console.log("  Initializing BatchAccumulator...");
+      console.log("  Initializing BatchAccumulator slots...");
+      for (const [slot, pda] of [batchAccumulatorPDA, nextBatchAccumulatorPDA].entries()) {
+        await retryWithBackoff(async () => {
+          await program.methods
+            .initBatchAccumulator(slot)
+            .accountsPartial({
+              pool: poolPDA,
+              payer: owner.publicKey,
+              batchAccumulator: pda,
+              systemProgram: SystemProgram.programId,
+            })
+            .signers([owner])
+            .rpc({ commitment: "confirmed" });
+        });
+      }
+      
+      console.log("  ✓ BatchAccumulator initialized");
+      await new Promise((resolve) => setTimeout(resolve, 1000));
+    }
+
+    const [eventCursorPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("event_cursor"), Buffer.from([0])],
+      program.programId
+    );
+    if (!(await retryWithBackoff(() => connection.getAccountInfo(eventCursorPDA)))) {
       await retryWithBackoff(async () => {
         await program.methods
-          .initBatchAccumulator()
+          .initEventCursor()
           .accountsPartial({
             payer: owner.publicKey,
-            batchAccumulator: batchAccumulatorPDA,
+            pool: poolPDA,
+            eventCursor: eventCursorPDA,
             systemProgram: SystemProgram.programId,
           })
           .signers([owner])
           .rpc({ commitment: "confirmed" });
       });
-      
-      console.log("  ✓ BatchAccumulator initialized");
-      await new Promise((resolve) => setTimeout(resolve, 1000));
+      console.log("  ✓ EventCursor initialized");
+    }
+
+    const [protocolStatsPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("protocol_stats"), Buffer.from([0])],
+      program.programId
+    );
+    if (!(await retryWithBackoff(() => connection.getAccountInfo(protocolStatsPDA)))) {
+      await retryWithBackoff(async () => {
+        await program.methods
+          .initProtocolStats()
+          .accountsPartial({
+            payer: owner.publicKey,
+            pool: poolPDA,
+            protocolStats: protocolStatsPDA,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([owner])
+          .rpc({ commitment: "confirmed" });
+      });
+      console.log("  ✓ ProtocolStats initialized");
     }
 
     // =========================================================================
@@ -450,6 +499,7 @@ describe.skip("Full Flow Integration Test", () => {
       await program.methods
         .initBatchState(initBatchStateOffset)
         .accountsPartial({
+          pool: poolPDA,
           payer: owner.publicKey,
           batchAccumulator: batchAccumulatorPDA,
           mxeAccount: getMXEAccAddress(program.programId),
@@ -531,7 +581,7 @@ describe.skip("Full Flow Integration Test", () => {
 
       // Get user account PDA
       const [accountPDA] = PublicKey.findProgramAddressSync(
-        [Buffer.from("user"), keypair.publicKey.toBuffer()],
+        [Buffer.from("user"), Buffer.from([0]), keypair.publicKey.toBuffer()],
         program.programId
       );
 
@@ -552,6 +602,7 @@ describe.skip("Full Flow Integration Test", () => {
           new anchor.BN(deserializeLE(initialNonce).toString())
         )
         .accountsPartial({
+          pool: poolPDA,
           payer: owner.publicKey,
           owner: keypair.publicKey,
           userAccount: accountPDA,
@@ -674,9 +725,12 @@ describe.skip("Full Flow Integration Test", () => {
         computationOffset,
         Array.from(encryptedAmount[0]),
         Array.from(alice.pubKey),
-        new anchor.BN(deserializeLE(transferNonce).toString())
+        new anchor.BN(deserializeLE(transferNonce).toString()),
+        0, // USDC
+        null // no memo
       )
       .accountsPartial({
+        pool: poolPDA,
         payer: owner.publicKey,
         sender: alice.keypair.publicKey,
         senderAccount: alice.accountPDA,
@@ -795,7 +849,8 @@ describe.skip("Full Flow Integration Test", () => {
           Array.from(encryptedOrder[2]),
           Array.from(user.pubKey),
           new anchor.BN(deserializeLE(orderNonce).toString()),
-          0 // USDC - users are selling USDC to buy TSLA/SPY
+          0, // USDC - users are selling USDC to buy TSLA/SPY
+          new anchor.BN(0) // valid_until_batch: no rollover
         )
         .accountsPartial({
           payer: user.keypair.publicKey,
@@ -864,7 +919,7 @@ describe.skip("Full Flow Integration Test", () => {
     console.log("DEBUG: BatchAccumulator mxe_nonce before execute:", batch.mxeNonce.toString());
 
     const [batchLogPDA] = PublicKey.findProgramAddressSync(
-      [Buffer.from("batch_log"), Buffer.from(new anchor.BN(batchId).toArray("le", 8))],
+      [Buffer.from("batch_log"), Buffer.from([0]), Buffer.from(new anchor.BN(batchId).toArray("le", 8))],
       program.programId
     );
 
@@ -872,37 +927,37 @@ describe.skip("Full Flow Integration Test", () => {
 
     // Derive vault PDAs for execute_batch
     const [vaultUsdcPDA] = PublicKey.findProgramAddressSync(
-      [Buffer.from("vault"), Buffer.from("usdc")],
+      [Buffer.from("vault"), Buffer.from([0]), Buffer.from("usdc")],
       program.programId
     );
     const [vaultTslaPDA] = PublicKey.findProgramAddressSync(
-      [Buffer.from("vault"), Buffer.from("tsla")],
+      [Buffer.from("vault"), Buffer.from([0]), Buffer.from("tsla")],
       program.programId
     );
     const [vaultSpyPDA] = PublicKey.findProgramAddressSync(
-      [Buffer.from("vault"), Buffer.from("spy")],
+      [Buffer.from("vault"), Buffer.from([0]), Buffer.from("spy")],
       program.programId
     );
     const [vaultAaplPDA] = PublicKey.findProgramAddressSync(
-      [Buffer.from("vault"), Buffer.from("aapl")],
+      [Buffer.from("vault"), Buffer.from([0]), Buffer.from("aapl")],
       program.programId
     );
 
     // Derive reserve PDAs for execute_batch
     const [reserveUsdcPDA] = PublicKey.findProgramAddressSync(
-      [Buffer.from("reserve"), Buffer.from("usdc")],
+      [Buffer.from("reserve"), Buffer.from([0]), Buffer.from("usdc")],
       program.programId
     );
     const [reserveTslaPDA] = PublicKey.findProgramAddressSync(
-      [Buffer.from("reserve"), Buffer.from("tsla")],
+      [Buffer.from("reserve"), Buffer.from([0]), Buffer.from("tsla")],
       program.programId
     );
     const [reserveSpyPDA] = PublicKey.findProgramAddressSync(
-      [Buffer.from("reserve"), Buffer.from("spy")],
+      [Buffer.from("reserve"), Buffer.from([0]), Buffer.from("spy")],
       program.programId
     );
     const [reserveAaplPDA] = PublicKey.findProgramAddressSync(
-      [Buffer.from("reserve"), Buffer.from("aapl")],
+      [Buffer.from("reserve"), Buffer.from([0]), Buffer.from("aapl")],
       program.programId
     );
 
@@ -913,6 +968,7 @@ describe.skip("Full Flow Integration Test", () => {
         caller: owner.publicKey,
         pool: poolPDA,
         batchAccumulator: batchAccumulatorPDA,
+        nextAccumulator: nextBatchAccumulatorPDA,
         batchLog: batchLogPDA,
         // Vault accounts
         vaultUsdc: vaultUsdcPDA,
@@ -1107,7 +1163,7 @@ describe.skip("Full Flow Integration Test", () => {
 
       const batchId = account.pendingOrder.batchId.toNumber();
       const [batchLogPDA] = PublicKey.findProgramAddressSync(
-        [Buffer.from("batch_log"), Buffer.from(new anchor.BN(batchId).toArray("le", 8))],
+        [Buffer.from("batch_log"), Buffer.from([0]), Buffer.from(new anchor.BN(batchId).toArray("le", 8))],
         program.programId
       );
 
@@ -1263,7 +1319,7 @@ async function depositToUser(
   // Get vault PDA using asset seed (usdc, tsla, spy, aapl)
   const vaultSeeds = ["usdc", "tsla", "spy", "aapl"];
   const [vaultPDA] = PublicKey.findProgramAddressSync(
-    [Buffer.from("vault"), Buffer.from(vaultSeeds[assetId])],
+    [Buffer.from("vault"), Buffer.from([0]), Buffer.from(vaultSeeds[assetId])],
     program.programId
   );
 
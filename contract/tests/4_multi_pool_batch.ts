@@ -0,0 +1,943 @@
+/**
+ * Second Pool Batch Lifecycle
+ *
+ * Runs pool_id 1 next to the pool 0 that 0_sdk_setup creates,
+ * asserting the outcome of each step:
+ * 1. A second pool initializes with its own vaults, reserves and faucet vault
+ * 2. The privacy floor counts a basket plus a regular order from one wallet
+ *    as a single wallet
+ * 3. A USD order is sized at the configured Pyth price, not the mock price
+ * 4. The batch executes and accrues its fees
+ * 5. force_settle is rejected within the grace period and, once the grace
+ *    period is lowered and has passed, credits the whole payout and pays
+ *    its bounty out of the fees owed
+ * 6. sweep_fees moves exactly the fees still owed
+ *
+ * Expects a fresh localnet with the Pyth price update fixture loaded
+ * (see [[test.validator.account]] in Anchor.toml).
+ *
+ * Run with: npx ts-mocha -p ./tsconfig.json -t 1000000 'tests/4_multi_pool_batch.ts'
+ */
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { Keypair, PublicKey, SystemProgram } from "@solana/web3.js";
+import { ShuffleProtocol } from "../target/types/shuffle_protocol";
+import { randomBytes } from "crypto";
+import {
+  awaitComputationFinalization,
+  getArciumEnv,
+  getCompDefAccOffset,
+  getArciumAccountBaseSeed,
+  getArciumProgramId,
+  RescueCipher,
+  deserializeLE,
+  getMXEPublicKey,
+  getMXEAccAddress,
+  getMempoolAccAddress,
+  getCompDefAccAddress,
+  getExecutingPoolAccAddress,
+  getComputationAccAddress,
+  getClusterAccAddress,
+  getLookupTableAddress,
+  getArciumProgram,
+  x25519,
+} from "@arcium-hq/client";
+import {
+  createMint,
+  mintTo,
+  getAccount,
+  TOKEN_PROGRAM_ID,
+  createAccount,
+} from "@solana/spl-token";
+import * as fs from "fs";
+import * as os from "os";
+import { expect } from "chai";
+
+process.env.ARCIUM_CLUSTER_OFFSET = process.env.ARCIUM_CLUSTER_OFFSET ?? "1234";
+
+// =============================================================================
+// CONSTANTS
+// =============================================================================
+const POOL_ID = 1;
+const EXECUTION_FEE_BPS = 50;
+const TRIGGER_COUNT = 8;
+
+// Mirrors FORCE_SETTLE_GRACE_SECS and FORCE_SETTLE_BOUNTY_SHARE_BPS
+const FORCE_SETTLE_GRACE_SECS = 604_800;
+const FORCE_SETTLE_BOUNTY_SHARE_BPS = 2_000;
+// Grace period set for the bounty test, so it runs on a fresh localnet
+const TEST_FORCE_SETTLE_GRACE_SECS = 2;
+
+// Pyth PriceUpdateV2 fixture: TSLA at 200 USD (price 20_000_000_000, expo -8),
+// published 2023-11-14. The mock TSLA price is 250 USD.
+const PYTH_FIXTURE = "tests/fixtures/pyth_tsla_price_update.json";
+const FIXTURE_TSLA_PRICE = 200_000_000;
+const PRICE_FEED_ID_OFFSET = 8 + 32 + 1;
+
+const ASSET_SEEDS = ["usdc", "tsla", "spy", "aapl"];
+
+// =============================================================================
+// HELPER: Retry with exponential backoff for blockhash errors
+// =============================================================================
+async function retryWithBackoff<T>(
+  fn: () => Promise<T>,
+  maxRetries: number = 5,
+  delayMs: number = 2000
+): Promise<T> {
+  for (let attempt = 1; attempt <= maxRetries; attempt++) {
+    try {
+      return await fn();
+    } catch (e: any) {
+      const isBlockhashError = e.message?.includes("Blockhash not found") ||
+                               e.message?.includes("blockhash");
+      if (attempt === maxRetries || !isBlockhashError) {
+        throw e;
+      }
+      console.log(`  ⏳ Blockhash error, retrying in ${delayMs}ms (attempt ${attempt}/${maxRetries})`);
+      await new Promise(resolve => setTimeout(resolve, delayMs));
+      delayMs *= 1.5;
+    }
+  }
+  throw new Error("Max retries exceeded");
+}
+
+// =============================================================================
+// HELPER: Initialize Computation Definition
+// =============================================================================
+async function initCompDef(
+  program: Program<ShuffleProtocol>,
+  owner: Keypair,
+  provider: anchor.AnchorProvider,
+  circuitName: string,
+  methodName: string
+): Promise<void> {
+  const baseSeedCompDefAcc = getArciumAccountBaseSeed("ComputationDefinitionAccount");
+  const offset = getCompDefAccOffset(circuitName);
+
+  const compDefPDA = PublicKey.findProgramAddressSync(
+    [baseSeedCompDefAcc, program.programId.toBuffer(), offset],
+    getArciumProgramId()
+  )[0];
+
+  const existingAccount = await retryWithBackoff(() => provider.connection.getAccountInfo(compDefPDA));
+  if (existingAccount) {
+    console.log(`  ✓ ${circuitName} comp def already exists`);
+    return;
+  }
+
+  console.log(`  Initializing ${circuitName} comp def...`);
+
+  const arciumProgram = getArciumProgram(provider);
+  const mxeAccount = getMXEAccAddress(program.programId);
+  const mxeAcc = await arciumProgram.account.mxeAccount.fetch(mxeAccount);
+  const lutAddress = getLookupTableAddress(
+    program.programId,
+    mxeAcc.lutOffsetSlot
+  );
+
+  await retryWithBackoff(async () => {
+    await (program.methods as any)[methodName]()
+      .accounts({
+        compDefAccount: compDefPDA,
+        payer: owner.publicKey,
+        mxeAccount,
+        addressLookupTable: lutAddress,
+      })
+      .signers([owner])
+      .rpc({ commitment: "confirmed" });
+  });
+
+  await new Promise((resolve) => setTimeout(resolve, 1500));
+  console.log(`  ✓ ${circuitName} comp def initialized`);
+  await new Promise((resolve) => setTimeout(resolve, 2000));
+}
+
+// Helper to read keypair
+function readKpJson(path: string): Keypair {
+  const data = JSON.parse(fs.readFileSync(path, "utf-8"));
+  return Keypair.fromSecretKey(Uint8Array.from(data));
+}
+
+// Wait for an MPC computation, retrying on timeout
+async function awaitComputationWithTimeout(
+  provider: anchor.AnchorProvider,
+  computationOffset: anchor.BN,
+  programId: PublicKey,
+  timeoutMs: number = 60000,
+  maxRetries: number = 3
+): Promise<string> {
+  for (let attempt = 1; attempt <= maxRetries; attempt++) {
+    try {
+      return await Promise.race([
+        awaitComputationFinalization(provider, computationOffset, programId, "confirmed"),
+        new Promise<never>((_, reject) => {
+          setTimeout(() => reject(new Error("MPC timeout")), timeoutMs);
+        }),
+      ]);
+    } catch (error: any) {
+      if (error.message.includes("timeout") && attempt < maxRetries) {
+        console.log(`[WARN] MPC attempt ${attempt} timed out, retrying...`);
+        await new Promise(resolve => setTimeout(resolve, 2000));
+        continue;
+      }
+      throw error;
+    }
+  }
+  throw new Error("MPC computation failed after all retries");
+}
+
+// Expect `fn` to fail with the Anchor error `code`
+async function expectAnchorError(fn: () => Promise<unknown>, code: string): Promise<void> {
+  let failed = false;
+  try {
+    await fn();
+  } catch (e: any) {
+    failed = true;
+    const actual = e.error?.errorCode?.code ?? e.message;
+    expect(actual).to.include(code);
+  }
+  expect(failed, `expected ${code}`).to.be.true;
+}
+
+// =============================================================================
+// TEST STATE
+// =============================================================================
+interface Trader {
+  name: string;
+  keypair: Keypair;
+  pubKey: Uint8Array;
+  cipher: RescueCipher;
+  accountPDA: PublicKey;
+}
+
+describe("Second Pool Batch Lifecycle", function() {
+  anchor.setProvider(anchor.AnchorProvider.env());
+  const program = anchor.workspace.ShuffleProtocol as Program<ShuffleProtocol>;
+  const provider = anchor.getProvider() as anchor.AnchorProvider;
+  const connection = provider.connection;
+  const arciumEnv = getArciumEnv();
+  const clusterAccount = getClusterAccAddress(arciumEnv.arciumClusterOffset);
+  const owner = readKpJson(`${os.homedir()}/.config/solana/id.json`);
+
+  // Pool 1 PDAs
+  const pda = (...seeds: Buffer[]) =>
+    PublicKey.findProgramAddressSync(seeds, program.programId)[0];
+  const poolSeed = Buffer.from([POOL_ID]);
+  const poolPDA = pda(Buffer.from("pool"), poolSeed);
+  const vaults = ASSET_SEEDS.map(asset => pda(Buffer.from("vault"), poolSeed, Buffer.from(asset)));
+  const reserves = ASSET_SEEDS.map(asset => pda(Buffer.from("reserve"), poolSeed, Buffer.from(asset)));
+  const faucetVaultPDA = pda(Buffer.from("faucet_usdc"), poolSeed);
+  const batchAccumulatorPDA = pda(Buffer.from("batch_accumulator"), poolSeed, Buffer.from([0]));
+  const nextBatchAccumulatorPDA = pda(Buffer.from("batch_accumulator"), poolSeed, Buffer.from([1]));
+  const eventCursorPDA = pda(Buffer.from("event_cursor"), poolSeed);
+  const protocolStatsPDA = pda(Buffer.from("protocol_stats"), poolSeed);
+  const rolesPDA = pda(Buffer.from("roles"), poolSeed);
+  const oracleConfigPDA = pda(Buffer.from("oracle_config"), poolSeed);
+  const batchLogPDA = (batchId: anchor.BN) =>
+    pda(Buffer.from("batch_log"), poolSeed, Buffer.from(batchId.toArray("le", 8)));
+
+  const mints: PublicKey[] = [];
+  let mxePublicKey: Uint8Array;
+  let priceUpdate: PublicKey;
+  let tslaFeedId: PublicKey;
+  let executedBatchId: anchor.BN;
+
+  // alice: regular order + basket; bob: USD order
+  let alice: Trader;
+  let bob: Trader;
+
+  // force_settle caller and its TSLA account
+  let cranker: Keypair;
+  let bountyAccount: PublicKey;
+
+  // Arcium accounts shared by every queued computation
+  const arciumAccounts = (computationOffset: anchor.BN, circuitName: string) => ({
+    computationAccount: getComputationAccAddress(arciumEnv.arciumClusterOffset, computationOffset),
+    clusterAccount,
+    mxeAccount: getMXEAccAddress(program.programId),
+    mempoolAccount: getMempoolAccAddress(arciumEnv.arciumClusterOffset),
+    executingPool: getExecutingPoolAccAddress(arciumEnv.arciumClusterOffset),
+    compDefAccount: getCompDefAccAddress(
+      program.programId,
+      Buffer.from(getCompDefAccOffset(circuitName)).readUInt32LE()
+    ),
+  });
+
+  async function createTrader(name: string): Promise<Trader> {
+    const keypair = Keypair.generate();
+    const airdropSig = await connection.requestAirdrop(keypair.publicKey, 2_000_000_000);
+    await connection.confirmTransaction(airdropSig, "confirmed");
+
+    const privKey = x25519.utils.randomSecretKey();
+    const pubKey = x25519.getPublicKey(privKey);
+    const cipher = new RescueCipher(x25519.getSharedSecret(privKey, mxePublicKey));
+    const accountPDA = pda(Buffer.from("user"), poolSeed, keypair.publicKey.toBuffer());
+
+    const initialNonce = randomBytes(16);
+    const encryptedZero = Array.from(cipher.encrypt([BigInt(0)], initialNonce)[0]);
+    await program.methods
+      .createUserAccount(
+        Array.from(pubKey),
+        [encryptedZero, encryptedZero, encryptedZero, encryptedZero],
+        new anchor.BN(deserializeLE(initialNonce).toString())
+      )
+      .accountsPartial({
+        pool: poolPDA,
+        payer: owner.publicKey,
+        owner: keypair.publicKey,
+        userAccount: accountPDA,
+        rentSponsor: null,
+      })
+      .signers([owner, keypair])
+      .rpc({ commitment: "confirmed" });
+
+    return { name, keypair, pubKey, cipher, accountPDA };
+  }
+
+  async function deposit(trader: Trader, assetId: number, amount: number): Promise<void> {
+    const userTokenAccount = await createAccount(connection, owner, mints[assetId], trader.keypair.publicKey);
+    await mintTo(connection, owner, mints[assetId], userTokenAccount, owner, amount);
+
+    const depositNonce = randomBytes(16);
+    const encryptedAmount = trader.cipher.encrypt([BigInt(amount)], depositNonce);
+    const computationOffset = new anchor.BN(randomBytes(8), "hex");
+
+    await program.methods
+      .addBalance(
+        computationOffset,
+        Array.from(encryptedAmount[0]),
+        Array.from(trader.pubKey),
+        new anchor.BN(deserializeLE(depositNonce).toString()),
+        new anchor.BN(amount),
+        assetId
+      )
+      .accountsPartial({
+        payer: owner.publicKey,
+        user: trader.keypair.publicKey,
+        pool: poolPDA,
+        userAccount: trader.accountPDA,
+        pendingOperation: pda(
+          Buffer.from("pending_operation"),
+          poolSeed,
+          trader.keypair.publicKey.toBuffer(),
+          Buffer.from(computationOffset.toArray("le", 8))
+        ),
+        mint: mints[assetId],
+        userTokenAccount,
+        vault: vaults[assetId],
+        assetPolicy: null,
+        allowlistEntry: null,
+        ...arciumAccounts(computationOffset, "add_balance"),
+      })
+      .signers([owner, trader.keypair])
+      .rpc({ skipPreflight: true, commitment: "confirmed" });
+
+    await awaitComputationWithTimeout(provider, computationOffset, program.programId);
+  }
+
+  async function placeOrder(trader: Trader, pairId: number, direction: number, amount: number, sourceAssetId: number): Promise<void> {
+    const orderNonce = randomBytes(16);
+    const encryptedOrder = trader.cipher.encrypt(
+      [BigInt(pairId), BigInt(direction), BigInt(amount)],
+      orderNonce
+    );
+    const computationOffset = new anchor.BN(randomBytes(8), "hex");
+
+    await program.methods
+      .placeOrder(
+        computationOffset,
+        Array.from(encryptedOrder[0]),
+        Array.from(encryptedOrder[1]),
+        Array.from(encryptedOrder[2]),
+        Array.from(trader.pubKey),
+        new anchor.BN(deserializeLE(orderNonce).toString()),
+        sourceAssetId,
+        new anchor.BN(0)
+      )
+      .accountsPartial({
+        payer: trader.keypair.publicKey,
+        user: trader.keypair.publicKey,
+        userAccount: trader.accountPDA,
+        delegate: null,
+        pool: poolPDA,
+        batchAccumulator: batchAccumulatorPDA,
+        ...arciumAccounts(computationOffset, "accumulate_order"),
+      })
+      .signers([trader.keypair])
+      .rpc({ skipPreflight: true, commitment: "confirmed" });
+
+    await awaitComputationWithTimeout(provider, computationOffset, program.programId);
+  }
+
+  async function clusterTime(): Promise<number> {
+    const slot = await connection.getSlot("confirmed");
+    return (await connection.getBlockTime(slot))!;
+  }
+
+  const forceSettle = (computationOffset: anchor.BN) => program.methods
+    .forceSettle(computationOffset, 0, 1)
+    .accountsPartial({
+      payer: cranker.publicKey,
+      pool: poolPDA,
+      userAccount: alice.accountPDA,
+      batchLog: batchLogPDA(executedBatchId),
+      orderHistory: pda(Buffer.from("order_history"), poolSeed, alice.keypair.publicKey.toBuffer()),
+      payoutVault: vaults[1],
+      bountyTokenAccount: bountyAccount,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      ...arciumAccounts(computationOffset, "calculate_payout"),
+    })
+    .signers([cranker]);
+
+  // Accounts for execute_batch on slot 0; the oracle accounts are passed once
+  // Pool.oracle_configured is set
+  const executeBatchAccounts = (computationOffset: anchor.BN, batchId: anchor.BN, withOracle: boolean) => ({
+    payer: owner.publicKey,
+    batchAccumulator: batchAccumulatorPDA,
+    nextAccumulator: nextBatchAccumulatorPDA,
+    batchLog: batchLogPDA(batchId),
+    pool: poolPDA,
+    roles: rolesPDA,
+    reserveUsdc: reserves[0],
+    reserveTsla: reserves[1],
+    reserveSpy: reserves[2],
+    reserveAapl: reserves[3],
+    executionPolicy: null,
+    pairRegistry: null,
+    marketSchedule: null,
+    executorRegistry: null,
+    circuitRegistry: null,
+    diagnostics: null,
+    oracleConfig: withOracle ? oracleConfigPDA : null,
+    riskConfig: null,
+    priceUpdateUsdc: null,
+    priceUpdateTsla: withOracle ? priceUpdate : null,
+    priceUpdateSpy: null,
+    priceUpdateAapl: null,
+    fallbackFeedUsdc: null,
+    fallbackFeedTsla: null,
+    fallbackFeedSpy: null,
+    fallbackFeedAapl: null,
+    eventCursor: eventCursorPDA,
+    ...arciumAccounts(computationOffset, "reveal_batch"),
+  });
+
+  before(async function() {
+    console.log("\n" + "=".repeat(70));
+    console.log("SECOND POOL BATCH LIFECYCLE");
+    console.log("=".repeat(70));
+
+    console.log("Checking computation definitions...");
+    await initCompDef(program, owner, provider, "add_balance", "initAddBalanceCompDef");
+    await initCompDef(program, owner, provider, "accumulate_order", "initAccumulateOrderCompDef");
+    await initCompDef(program, owner, provider, "accumulate_order_usd", "initAccumulateOrderUsdCompDef");
+    await initCompDef(program, owner, provider, "accumulate_basket", "initAccumulateBasketCompDef");
+    await initCompDef(program, owner, provider, "init_batch_state", "initInitBatchStateCompDef");
+    await initCompDef(program, owner, provider, "reveal_batch", "initRevealBatchCompDef");
+    await initCompDef(program, owner, provider, "calculate_payout", "initCalculatePayoutCompDef");
+
+    mxePublicKey = await getMXEPublicKey(provider, program.programId);
+
+    const fixture = JSON.parse(fs.readFileSync(PYTH_FIXTURE, "utf-8"));
+    priceUpdate = new PublicKey(fixture.pubkey);
+    const fixtureData = Buffer.from(fixture.account.data[0], "base64");
+    tslaFeedId = new PublicKey(fixtureData.subarray(PRICE_FEED_ID_OFFSET, PRICE_FEED_ID_OFFSET + 32));
+  });
+
+  // =============================================================================
+  // STEP 1: INITIALIZE POOL 1
+  // =============================================================================
+  it("Initializes a second pool alongside pool 0", async function() {
+    const pool0PDA = pda(Buffer.from("pool"), Buffer.from([0]));
+    const pool0Before = await connection.getAccountInfo(pool0PDA);
+
+    for (let i = 0; i < 4; i++) {
+      mints.push(await retryWithBackoff(() => createMint(connection, owner, owner.publicKey, null, 6)));
+    }
+
+    await retryWithBackoff(async () => {
+      await program.methods
+        .initialize(POOL_ID, EXECUTION_FEE_BPS, TRIGGER_COUNT)
+        .accountsPartial({
+          payer: owner.publicKey,
+          authority: owner.publicKey,
+          operator: owner.publicKey,
+          treasury: owner.publicKey,
+          pool: poolPDA,
+          usdcMint: mints[0],
+          tslaMint: mints[1],
+          spyMint: mints[2],
+          aaplMint: mints[3],
+          vaultUsdc: vaults[0],
+          vaultTsla: vaults[1],
+          vaultSpy: vaults[2],
+          vaultAapl: vaults[3],
+          reserveUsdc: reserves[0],
+          reserveTsla: reserves[1],
+          reserveSpy: reserves[2],
+          reserveAapl: reserves[3],
+          faucetVault: faucetVaultPDA,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([owner])
+        .rpc({ commitment: "confirmed" });
+    });
+    console.log("  ✓ Pool 1 initialized");
+
+    const pool = await program.account.pool.fetch(poolPDA);
+    expect(pool.poolId).to.equal(POOL_ID);
+    expect(pool.usdcMint.equals(mints[0])).to.be.true;
+    expect(pool.minUniqueUsers).to.equal(0);
+
+    // Every token account is pool 1's own, owned by the pool 1 PDA
+    for (const [i, vault] of vaults.entries()) {
+      const account = await getAccount(connection, vault);
+      expect(account.mint.equals(mints[i])).to.be.true;
+      expect(account.owner.equals(poolPDA)).to.be.true;
+    }
+    const faucet = await getAccount(connection, faucetVaultPDA);
+    expect(faucet.mint.equals(mints[0])).to.be.true;
+    expect(faucet.owner.equals(poolPDA)).to.be.true;
+
+    // Pool 0 and its faucet vault are untouched
+    if (pool0Before) {
+      const pool0 = await program.account.pool.fetch(pool0PDA);
+      expect(pool0.poolId).to.equal(0);
+      expect(pool0.usdcMint.equals(mints[0])).to.be.false;
+      const pool0Faucet = await getAccount(connection, pda(Buffer.from("faucet_usdc"), Buffer.from([0])));
+      expect(pool0Faucet.mint.equals(pool0.usdcMint)).to.be.true;
+      expect(faucetVaultPDA.equals(pool0Faucet.address)).to.be.false;
+    }
+
+    // Liquidity and per-pool infrastructure
+    const INITIAL_RESERVE_AMOUNT = 100_000_000_000;
+    for (const [i, reserve] of reserves.entries()) {
+      await retryWithBackoff(() => mintTo(connection, owner, mints[i], reserve, owner, INITIAL_RESERVE_AMOUNT));
+    }
+
+    for (const [slot, batchAccumulator] of [batchAccumulatorPDA, nextBatchAccumulatorPDA].entries()) {
+      await program.methods
+        .initBatchAccumulator(slot)
+        .accountsPartial({
+          pool: poolPDA,
+          payer: owner.publicKey,
+          batchAccumulator,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([owner])
+        .rpc({ commitment: "confirmed" });
+    }
+    await program.methods
+      .initEventCursor()
+      .accountsPartial({
+        payer: owner.publicKey,
+        pool: poolPDA,
+        eventCursor: eventCursorPDA,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([owner])
+      .rpc({ commitment: "confirmed" });
+    await program.methods
+      .initProtocolStats()
+      .accountsPartial({
+        payer: owner.publicKey,
+        pool: poolPDA,
+        protocolStats: protocolStatsPDA,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([owner])
+      .rpc({ commitment: "confirmed" });
+    await program.methods
+      .initRoles()
+      .accountsPartial({
+        authority: owner.publicKey,
+        pool: poolPDA,
+        roles: rolesPDA,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([owner])
+      .rpc({ commitment: "confirmed" });
+
+    const initBatchStateOffset = new anchor.BN(randomBytes(8), "hex");
+    await program.methods
+      .initBatchState(initBatchStateOffset)
+      .accountsPartial({
+        pool: poolPDA,
+        payer: owner.publicKey,
+        batchAccumulator: batchAccumulatorPDA,
+        ...arciumAccounts(initBatchStateOffset, "init_batch_state"),
+      })
+      .signers([owner])
+      .rpc({ commitment: "confirmed" });
+    await awaitComputationWithTimeout(provider, initBatchStateOffset, program.programId);
+    console.log("  ✓ Pool 1 batch infrastructure initialized");
+  });
+
+  // =============================================================================
+  // STEP 2: PRIVACY FLOOR
+  // =============================================================================
+  it("Counts a basket plus a regular order from one wallet as one wallet", async function() {
+    await program.methods
+      .setPrivacyFloor(2)
+      .accountsPartial({ authority: owner.publicKey, pool: poolPDA, roles: rolesPDA })
+      .signers([owner])
+      .rpc({ commitment: "confirmed" });
+
+    alice = await createTrader("Alice");
+    await deposit(alice, 0, 10_000_000);
+
+    // Regular order: buy TSLA with 1 USDC
+    await placeOrder(alice, 0, 1, 1_000_000, 0);
+
+    // Basket: 1 USDC split 50/30/20 into TSLA, SPY and AAPL
+    const basketNonce = randomBytes(16);
+    const basket = alice.cipher.encrypt(
+      [0, 1, 2, 1, 1, 1, 5_000, 3_000, 2_000, 1_000_000].map(BigInt),
+      basketNonce
+    );
+    const basketOffset = new anchor.BN(randomBytes(8), "hex");
+    await program.methods
+      .placeBasketOrder(
+        basketOffset,
+        basket.slice(0, 3).map(c => Array.from(c)),
+        basket.slice(3, 6).map(c => Array.from(c)),
+        basket.slice(6, 9).map(c => Array.from(c)),
+        Array.from(basket[9]),
+        Array.from(alice.pubKey),
+        new anchor.BN(deserializeLE(basketNonce).toString()),
+        0
+      )
+      .accountsPartial({
+        payer: alice.keypair.publicKey,
+        user: alice.keypair.publicKey,
+        pool: poolPDA,
+        userAccount: alice.accountPDA,
+        delegate: null,
+        basketOrder: pda(Buffer.from("basket_order"), poolSeed, alice.keypair.publicKey.toBuffer()),
+        batchAccumulator: batchAccumulatorPDA,
+        ...arciumAccounts(basketOffset, "accumulate_basket"),
+      })
+      .signers([alice.keypair])
+      .rpc({ skipPreflight: true, commitment: "confirmed" });
+    await awaitComputationWithTimeout(provider, basketOffset, program.programId);
+
+    const batch = await program.account.batchAccumulator.fetch(batchAccumulatorPDA, "confirmed");
+    expect(batch.orderCount).to.equal(2);
+    expect(batch.basketCount).to.equal(1);
+
+    // Two orders but one wallet: even the operator cannot reveal the batch
+    const computationOffset = new anchor.BN(randomBytes(8), "hex");
+    await expectAnchorError(
+      () => program.methods
+        .executeBatch(computationOffset)
+        .accountsPartial(executeBatchAccounts(computationOffset, batch.batchId, false))
+        .signers([owner])
+        .rpc({ commitment: "confirmed" }),
+      "PrivacyFloorNotMet"
+    );
+    console.log("  ✓ Batch with one wallet rejected by the privacy floor");
+  });
+
+  // =============================================================================
+  // STEP 3: USD ORDER PRICED BY THE ORACLE
+  // =============================================================================
+  it("Sizes a USD order at the configured Pyth price", async function() {
+    expect(await connection.getAccountInfo(priceUpdate), `${PYTH_FIXTURE} not loaded`).to.not.be.null;
+
+    await program.methods
+      .setOracleFeed(1, tslaFeedId, PublicKey.default)
+      .accountsPartial({
+        authority: owner.publicKey,
+        pool: poolPDA,
+        roles: rolesPDA,
+        oracleConfig: oracleConfigPDA,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([owner])
+      .rpc({ commitment: "confirmed" });
+    // The fixture is years old, so the max age is wide open
+    await program.methods
+      .setOracleParams(new anchor.BN(1_000_000_000), 100, 10_000)
+      .accountsPartial({
+        authority: owner.publicKey,
+        pool: poolPDA,
+        roles: rolesPDA,
+        oracleConfig: oracleConfigPDA,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([owner])
+      .rpc({ commitment: "confirmed" });
+    expect((await program.account.pool.fetch(poolPDA)).oracleConfigured).to.be.true;
+
+    bob = await createTrader("Bob");
+    await deposit(bob, 1, 2_000_000);
+
+    // Sell 100 USDC worth of TSLA
+    const notional = 100_000_000;
+    const orderNonce = randomBytes(16);
+    const encryptedOrder = bob.cipher.encrypt([BigInt(0), BigInt(0), BigInt(notional)], orderNonce);
+    const usdOrderAccounts = (computationOffset: anchor.BN, priceUpdateAccount: PublicKey | null) => ({
+      payer: bob.keypair.publicKey,
+      user: bob.keypair.publicKey,
+      userAccount: bob.accountPDA,
+      delegate: null,
+      pool: poolPDA,
+      batchAccumulator: batchAccumulatorPDA,
+      oracleConfig: oracleConfigPDA,
+      priceUpdate: priceUpdateAccount,
+      fallbackFeed: null,
+      ...arciumAccounts(computationOffset, "accumulate_order_usd"),
+    });
+    const placeOrderUsd = (computationOffset: anchor.BN) => program.methods
+      .placeOrderUsd(
+        computationOffset,
+        Array.from(encryptedOrder[0]),
+        Array.from(encryptedOrder[1]),
+        Array.from(encryptedOrder[2]),
+        Array.from(bob.pubKey),
+        new anchor.BN(deserializeLE(orderNonce).toString()),
+        1,
+        new anchor.BN(0)
+      );
+
+    // With a TSLA feed configured the mock price is no longer an option
+    const rejectedOffset = new anchor.BN(randomBytes(8), "hex");
+    await expectAnchorError(
+      () => placeOrderUsd(rejectedOffset)
+        .accountsPartial(usdOrderAccounts(rejectedOffset, null))
+        .signers([bob.keypair])
+        .rpc({ commitment: "confirmed" }),
+      "PriceUpdateMissing"
+    );
+
+    const computationOffset = new anchor.BN(randomBytes(8), "hex");
+    await placeOrderUsd(computationOffset)
+      .accountsPartial(usdOrderAccounts(computationOffset, priceUpdate))
+      .signers([bob.keypair])
+      .rpc({ skipPreflight: true, commitment: "confirmed" });
+    await awaitComputationWithTimeout(provider, computationOffset, program.programId);
+
+    // The callback re-encrypts the sized order for the user
+    const account = await program.account.userProfile.fetch(bob.accountPDA, "confirmed");
+    const ticket = account.pendingOrder!;
+    const sized = bob.cipher.decrypt(
+      [Array.from(ticket.pairId), Array.from(ticket.direction), Array.from(ticket.encryptedAmount)],
+      new Uint8Array(new anchor.BN(ticket.orderNonce.toString()).toArray("le", 16))
+    );
+    const expectedAmount = notional * 1_000_000 / FIXTURE_TSLA_PRICE;
+    console.log(`  Sized amount: ${sized[2]} (expected ${expectedAmount}, mock price gives 400000)`);
+    expect(Number(sized[0])).to.equal(0);
+    expect(Number(sized[1])).to.equal(0);
+    expect(Number(sized[2])).to.equal(expectedAmount);
+  });
+
+  // =============================================================================
+  // STEP 4: EXECUTE
+  // =============================================================================
+  it("Executes the batch and accrues its fees", async function() {
+    const batch = await program.account.batchAccumulator.fetch(batchAccumulatorPDA);
+    expect(batch.orderCount).to.equal(3);
+    executedBatchId = batch.batchId;
+
+    const computationOffset = new anchor.BN(randomBytes(8), "hex");
+    await program.methods
+      .executeBatch(computationOffset)
+      .accountsPartial(executeBatchAccounts(computationOffset, executedBatchId, true))
+      .signers([owner])
+      .rpc({ skipPreflight: true, commitment: "confirmed" });
+    await awaitComputationWithTimeout(provider, computationOffset, program.programId, 90000);
+
+    const batchLog = await program.account.batchLog.fetch(batchLogPDA(executedBatchId), "confirmed");
+    expect(batchLog.batchId.eq(executedBatchId)).to.be.true;
+    expect(batchLog.oraclePrices[1].toNumber()).to.equal(FIXTURE_TSLA_PRICE);
+
+    const poolBefore = await program.account.pool.fetch(poolPDA);
+    await program.methods
+      .executeSwaps(executedBatchId)
+      .accountsPartial({
+        payer: owner.publicKey,
+        operator: owner.publicKey,
+        pool: poolPDA,
+        roles: rolesPDA,
+        batchLog: batchLogPDA(executedBatchId),
+        executorRegistry: null,
+        vaultUsdc: vaults[0],
+        vaultTsla: vaults[1],
+        vaultSpy: vaults[2],
+        vaultAapl: vaults[3],
+        reserveUsdc: reserves[0],
+        reserveTsla: reserves[1],
+        reserveSpy: reserves[2],
+        reserveAapl: reserves[3],
+        diagnostics: null,
+        protocolStats: protocolStatsPDA,
+        eventCursor: eventCursorPDA,
+        keeperUsdc: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([owner])
+      .rpc({ commitment: "confirmed" });
+
+    const swappedLog = await program.account.batchLog.fetch(batchLogPDA(executedBatchId), "confirmed");
+    expect(swappedLog.swapsExecuted).to.be.true;
+
+    // The batch's fees, valued in USDC at its oracle prices (all mints have
+    // 6 decimals, so the unit prices reduce to the oracle prices)
+    const pool = await program.account.pool.fetch(poolPDA, "confirmed");
+    const prices = swappedLog.oraclePrices.map(price => BigInt(price.toString()));
+    const batchFees = pool.feesOwed.map((fee, i) => BigInt(fee.toString()) - BigInt(poolBefore.feesOwed[i].toString()));
+    const expectedFeesUsdc = batchFees.reduce((sum, fee, i) => sum + fee * prices[i] / prices[0], BigInt(0));
+    expect(batchFees.some(fee => fee > BigInt(0))).to.be.true;
+    expect(swappedLog.feesUsdc.toString()).to.equal(expectedFeesUsdc.toString());
+    expect(pool.feesOwedUsdc.sub(poolBefore.feesOwedUsdc).toString()).to.equal(expectedFeesUsdc.toString());
+    console.log(`  Fees owed: [${pool.feesOwed.join(", ")}] = ${pool.feesOwedUsdc} USDC base units`);
+  });
+
+  // =============================================================================
+  // STEP 5: FORCE SETTLE
+  // =============================================================================
+  it("Rejects force_settle within the grace period", async function() {
+    cranker = Keypair.generate();
+    const airdropSig = await connection.requestAirdrop(cranker.publicKey, 2_000_000_000);
+    await connection.confirmTransaction(airdropSig, "confirmed");
+
+    // Alice bought TSLA on pair 0, so the payout and bounty are TSLA
+    bountyAccount = await createAccount(connection, owner, mints[1], cranker.publicKey);
+
+    const pool = await program.account.pool.fetch(poolPDA);
+    expect(pool.forceSettleGraceSecs.toNumber()).to.equal(FORCE_SETTLE_GRACE_SECS);
+    const batchLog = await program.account.batchLog.fetch(batchLogPDA(executedBatchId));
+    const graceEnd = batchLog.executedAt.toNumber() + FORCE_SETTLE_GRACE_SECS;
+    const now = await clusterTime();
+    expect(now).to.be.lessThan(graceEnd);
+
+    const computationOffset = new anchor.BN(randomBytes(8), "hex");
+    await expectAnchorError(
+      () => forceSettle(computationOffset).rpc({ commitment: "confirmed" }),
+      "SettlementGracePeriodActive"
+    );
+    expect((await getAccount(connection, bountyAccount, "confirmed")).amount.toString()).to.equal("0");
+    const account = await program.account.userProfile.fetch(alice.accountPDA, "confirmed");
+    expect(account.pendingOrder).to.not.be.null;
+    console.log(`  ✓ force_settle rejected within the grace period (ends at ${graceEnd}, now ${now})`);
+  });
+
+  it("Credits the payout and pays the bounty out of the fees owed", async function() {
+    await program.methods
+      .setForceSettleGrace(new anchor.BN(TEST_FORCE_SETTLE_GRACE_SECS))
+      .accountsPartial({ authority: owner.publicKey, pool: poolPDA, roles: rolesPDA })
+      .signers([owner])
+      .rpc({ commitment: "confirmed" });
+
+    const batchLog = await program.account.batchLog.fetch(batchLogPDA(executedBatchId), "confirmed");
+    const graceEnd = batchLog.executedAt.toNumber() + TEST_FORCE_SETTLE_GRACE_SECS;
+    while ((await clusterTime()) < graceEnd) {
+      await new Promise((resolve) => setTimeout(resolve, 1000));
+    }
+
+    const poolBefore = await program.account.pool.fetch(poolPDA, "confirmed");
+    const accountBefore = await program.account.userProfile.fetch(alice.accountPDA, "confirmed");
+    const tslaBefore = alice.cipher.decrypt(
+      [Array.from(accountBefore.tslaCredit) as number[]],
+      new Uint8Array(new anchor.BN(accountBefore.tslaNonce.toString()).toArray("le", 16))
+    )[0];
+
+    const settlementEvent = new Promise<any>((resolve) => {
+      const listenerId = program.addEventListener("settlementEvent", (event) => {
+        if (event.user.equals(alice.keypair.publicKey)) {
+          program.removeEventListener(listenerId);
+          resolve(event);
+        }
+      });
+    });
+    const bountyEvent = new Promise<any>((resolve) => {
+      const listenerId = program.addEventListener("settlementBountyPaidEvent", (event) => {
+        if (event.user.equals(alice.keypair.publicKey)) {
+          program.removeEventListener(listenerId);
+          resolve(event);
+        }
+      });
+    });
+
+    const computationOffset = new anchor.BN(randomBytes(8), "hex");
+    await forceSettle(computationOffset).rpc({ skipPreflight: true, commitment: "confirmed" });
+    await awaitComputationWithTimeout(provider, computationOffset, program.programId);
+
+    // The whole payout is added to Alice's existing TSLA balance
+    const payout = BigInt((await settlementEvent).revealedPayout.toString());
+    expect(payout > BigInt(0)).to.be.true;
+    const account = await program.account.userProfile.fetch(alice.accountPDA, "confirmed");
+    expect(account.pendingOrder).to.be.null;
+    const tslaAfter = alice.cipher.decrypt(
+      [Array.from(account.tslaCredit) as number[]],
+      new Uint8Array(new anchor.BN(account.tslaNonce.toString()).toArray("le", 16))
+    )[0];
+    expect(tslaAfter.toString()).to.equal((tslaBefore + payout).toString());
+
+    // The bounty is the cranker's share of the pair's fee on the payout,
+    // taken from the TSLA fees owed
+    const feeBps = BigInt(batchLog.pairFeeBps[0]);
+    const feesOwedBefore = BigInt(poolBefore.feesOwed[1].toString());
+    let expectedBounty = payout * feeBps * BigInt(FORCE_SETTLE_BOUNTY_SHARE_BPS) / BigInt(100_000_000);
+    if (expectedBounty > feesOwedBefore) {
+      expectedBounty = feesOwedBefore;
+    }
+    expect(expectedBounty > BigInt(0)).to.be.true;
+    const paid = await bountyEvent;
+    expect(paid.recipient.equals(cranker.publicKey)).to.be.true;
+    expect(paid.assetId).to.equal(1);
+    expect(paid.amount.toString()).to.equal(expectedBounty.toString());
+    expect((await getAccount(connection, bountyAccount, "confirmed")).amount.toString())
+      .to.equal(expectedBounty.toString());
+    const pool = await program.account.pool.fetch(poolPDA, "confirmed");
+    expect(pool.feesOwed[1].toString()).to.equal((feesOwedBefore - expectedBounty).toString());
+    console.log(`  ✓ Alice credited ${payout} TSLA base units; cranker paid ${expectedBounty} from the fees owed`);
+  });
+
+  // =============================================================================
+  // STEP 6: SWEEP FEES
+  // =============================================================================
+  it("Sweeps exactly the fees owed", async function() {
+    // Sweep to the treasury (the owner), after force_settle's bounty
+    const pool = await program.account.pool.fetch(poolPDA, "confirmed");
+    expect(pool.feesOwed.some(fee => !fee.isZero())).to.be.true;
+    const treasury: PublicKey[] = [];
+    for (const mint of mints) {
+      treasury.push(await createAccount(connection, owner, mint, owner.publicKey));
+    }
+    const treasuryBefore = await Promise.all(treasury.map(account => getAccount(connection, account)));
+
+    await program.methods
+      .sweepFees()
+      .accountsPartial({
+        caller: owner.publicKey,
+        pool: poolPDA,
+        vaultUsdc: vaults[0],
+        vaultTsla: vaults[1],
+        vaultSpy: vaults[2],
+        vaultAapl: vaults[3],
+        treasuryUsdc: treasury[0],
+        treasuryTsla: treasury[1],
+        treasurySpy: treasury[2],
+        treasuryAapl: treasury[3],
+        eventCursor: eventCursorPDA,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([owner])
+      .rpc({ commitment: "confirmed" });
+
+    const treasuryAfter = await Promise.all(treasury.map(account => getAccount(connection, account, "confirmed")));
+    for (let i = 0; i < 4; i++) {
+      expect((treasuryAfter[i].amount - treasuryBefore[i].amount).toString()).to.equal(pool.feesOwed[i].toString());
+    }
+    const swept = await program.account.pool.fetch(poolPDA, "confirmed");
+    expect(swept.feesOwed.every(fee => fee.isZero())).to.be.true;
+    expect(swept.feesOwedUsdc.isZero()).to.be.true;
+    expect(swept.totalFeesCollected.toString()).to.equal(
+      pool.totalFeesCollected.add(pool.feesOwedUsdc).toString()
+    );
+    console.log("  ✓ Treasury received the fees owed; total_fees_collected advanced by their USDC value");
+  });
+});
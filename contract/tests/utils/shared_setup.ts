@@ -50,7 +50,7 @@ export async function ensureShuffleProtocolPool(
 
   const authority = (provider.wallet as anchor.Wallet).payer;
   const [poolPda] = PublicKey.findProgramAddressSync(
-    [Buffer.from("pool")],
+    [Buffer.from("pool"), Buffer.from([0])],
     shuffleProtocol.programId
   );
 
@@ -75,48 +75,48 @@ export async function ensureShuffleProtocolPool(
     aaplMint = await createMint(provider.connection, authority, authority.publicKey, null, 6);
 
     const [vaultUsdc] = PublicKey.findProgramAddressSync(
-      [Buffer.from("vault"), Buffer.from("usdc")],
+      [Buffer.from("vault"), Buffer.from([0]), Buffer.from("usdc")],
       shuffleProtocol.programId
     );
     const [vaultTsla] = PublicKey.findProgramAddressSync(
-      [Buffer.from("vault"), Buffer.from("tsla")],
+      [Buffer.from("vault"), Buffer.from([0]), Buffer.from("tsla")],
       shuffleProtocol.programId
     );
     const [vaultSpy] = PublicKey.findProgramAddressSync(
-      [Buffer.from("vault"), Buffer.from("spy")],
+      [Buffer.from("vault"), Buffer.from([0]), Buffer.from("spy")],
       shuffleProtocol.programId
     );
     const [vaultAapl] = PublicKey.findProgramAddressSync(
-      [Buffer.from("vault"), Buffer.from("aapl")],
+      [Buffer.from("vault"), Buffer.from([0]), Buffer.from("aapl")],
       shuffleProtocol.programId
     );
 
     // Derive reserve PDAs
     const [reserveUsdc] = PublicKey.findProgramAddressSync(
-      [Buffer.from("reserve"), Buffer.from("usdc")],
+      [Buffer.from("reserve"), Buffer.from([0]), Buffer.from("usdc")],
       shuffleProtocol.programId
     );
     const [reserveTsla] = PublicKey.findProgramAddressSync(
-      [Buffer.from("reserve"), Buffer.from("tsla")],
+      [Buffer.from("reserve"), Buffer.from([0]), Buffer.from("tsla")],
       shuffleProtocol.programId
     );
     const [reserveSpy] = PublicKey.findProgramAddressSync(
-      [Buffer.from("reserve"), Buffer.from("spy")],
+      [Buffer.from("reserve"), Buffer.from([0]), Buffer.from("spy")],
       shuffleProtocol.programId
     );
     const [reserveAapl] = PublicKey.findProgramAddressSync(
-      [Buffer.from("reserve"), Buffer.from("aapl")],
+      [Buffer.from("reserve"), Buffer.from([0]), Buffer.from("aapl")],
       shuffleProtocol.programId
     );
 
     // Derive faucet vault PDA (for devnet USDC faucet)
     const [faucetVault] = PublicKey.findProgramAddressSync(
-      [Buffer.from("faucet_usdc")],
+      [Buffer.from("faucet_usdc"), Buffer.from([0])],
       shuffleProtocol.programId
     );
 
     await shuffleProtocol.methods
-      .initialize(50, 8)
+      .initialize(0, 50, 8)
       .accountsStrict({
         payer: authority.publicKey,
         authority: authority.publicKey,
@@ -1,10 +1,13 @@
 import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
-import { PublicKey, SystemProgram, Transaction } from "@solana/web3.js";
+import { Keypair, PublicKey, SystemProgram, Transaction } from "@solana/web3.js";
 import {
   TOKEN_PROGRAM_ID,
   getAssociatedTokenAddressSync,
   getAccount,
+  getMint,
+  getTransferFeeConfig,
+  calculateEpochFee,
   TokenAccountNotFoundError,
   createAssociatedTokenAccountInstruction,
 } from "@solana/spl-token";
@@ -29,6 +32,20 @@ import {
   getBatchLogPDA,
   getVaultPDA,
   getFaucetVaultPDA,
+  getSettlementPreferencePDA,
+  getTransferMemoPDA,
+  getRentSponsorPDA,
+  getAccountClosurePDA,
+  getPendingOperationPDA,
+  getSavingsBucketPDA,
+  getPaymentStreamPDA,
+  getVoucherPDA,
+  getGuardianSetPDA,
+  getExecutorRegistryPDA,
+  getRiskConfigPDA,
+  getWithdrawalWindowPDA,
+  getCircuitRegistryPDA,
+  getOracleConfigPDA,
 } from "./pda";
 import {
   fetchMXEPublicKey,
@@ -55,6 +72,7 @@ export class ShuffleClient {
   private connection: anchor.web3.Connection;
   private wallet: anchor.Wallet;
   private programId: PublicKey;
+  private poolId: number;
   private provider: anchor.AnchorProvider;
   private program: any;
   private mxePublicKey!: Uint8Array;
@@ -63,7 +81,7 @@ export class ShuffleClient {
 
   // Derived PDAs
   private poolPDA!: PublicKey;
-  private batchAccumulatorPDA!: PublicKey;
+  private batchAccumulatorPDAs!: PublicKey[];
 
   // Encryption state (set via initEncryption)
   private cipher: RescueCipher | null = null;
@@ -73,6 +91,7 @@ export class ShuffleClient {
     this.connection = config.connection;
     this.wallet = config.wallet;
     this.programId = config.programId || PROGRAM_ID;
+    this.poolId = config.poolId ?? 0;
     this.clusterOffset = config.clusterOffset ?? 0; // Default to 0 for localnet
     this.provider = new anchor.AnchorProvider(this.connection, this.wallet, {
       commitment: "confirmed",
@@ -92,8 +111,10 @@ export class ShuffleClient {
 
   private async initialize(): Promise<void> {
     this.clusterAccount = getClusterAccAddress(this.clusterOffset);
-    [this.poolPDA] = getPoolPDA(this.programId);
-    [this.batchAccumulatorPDA] = getBatchAccumulatorPDA(this.programId);
+    [this.poolPDA] = getPoolPDA(this.programId, this.poolId);
+    this.batchAccumulatorPDAs = [0, 1].map(
+      (slot) => getBatchAccumulatorPDA(this.programId, slot, this.poolId)[0]
+    );
     this.mxePublicKey = await fetchMXEPublicKey(this.provider, this.programId);
   }
 
@@ -144,7 +165,7 @@ export class ShuffleClient {
     const pubkey = encryptionPublicKey || this._requireEncryption().pubkey;
     const enc = this.cipher || createCipher(generateEncryptionKeypair().privateKey, this.mxePublicKey);
     const owner = this.wallet.publicKey;
-    const [userAccountPDA] = getUserAccountPDA(this.programId, owner);
+    const [userAccountPDA] = getUserAccountPDA(this.programId, owner, this.poolId);
 
     const initialNonce = randomBytes(16);
     const encryptedZero = enc.encrypt([BigInt(0)], initialNonce);
@@ -155,6 +176,10 @@ export class ShuffleClient {
       Array.from(encryptedZero[0]),
     ];
 
+    // The rent sponsor pool reimburses the rent when it is funded
+    const [rentSponsorPDA] = getRentSponsorPDA(this.programId, this.poolId);
+    const sponsored = (await this.connection.getAccountInfo(rentSponsorPDA)) !== null;
+
     const sig = await this.program.methods
       .createUserAccount(
         Array.from(pubkey),
@@ -162,9 +187,11 @@ export class ShuffleClient {
         nonceToBN(initialNonce)
       )
       .accounts({
+        pool: this.poolPDA,
         payer: owner,
         owner: owner,
         userAccount: userAccountPDA,
+        rentSponsor: sponsored ? rentSponsorPDA : null,
         systemProgram: SystemProgram.programId,
       })
       .rpc({ commitment: "confirmed" });
@@ -172,10 +199,81 @@ export class ShuffleClient {
     return sig;
   }
 
+  /**
+   * Create the user privacy account and make its first deposit in one
+   * transaction. Uses internal encryption.
+   */
+  async createAndDeposit(assetId: AssetId, amount: number): Promise<string> {
+    const { cipher: enc, pubkey } = this._requireEncryption();
+    const owner = this.wallet.publicKey;
+    const [userAccountPDA] = getUserAccountPDA(this.programId, owner, this.poolId);
+    const assetSeed = VAULT_ASSET_SEEDS[assetId];
+    const [vaultPDA] = getVaultPDA(this.programId, assetSeed, this.poolId);
+
+    const initialNonce = randomBytes(16);
+    const encryptedZero = Array.from(enc.encrypt([BigInt(0)], initialNonce)[0]);
+    const initialBalances: number[][] = [encryptedZero, encryptedZero, encryptedZero, encryptedZero];
+
+    const pool = await (this.program.account as any).pool.fetch(this.poolPDA);
+    const mints = [pool.usdcMint, pool.tslaMint, pool.spyMint, pool.aaplMint];
+    const mint = mints[assetId];
+
+    // Credit the amount net of any Token-2022 transfer fee
+    const mintInfo = await this.connection.getAccountInfo(mint);
+    const tokenProgram = mintInfo?.owner ?? TOKEN_PROGRAM_ID;
+    let credited = BigInt(amount);
+    if (!tokenProgram.equals(TOKEN_PROGRAM_ID)) {
+      const mintState = await getMint(this.connection, mint, "confirmed", tokenProgram);
+      const feeConfig = getTransferFeeConfig(mintState);
+      if (feeConfig) {
+        const epoch = BigInt((await this.connection.getEpochInfo()).epoch);
+        credited -= calculateEpochFee(feeConfig, epoch, credited);
+      }
+    }
+    const userTokenAccount = getAssociatedTokenAddressSync(mint, owner, false, tokenProgram);
+
+    // The deposit must not reuse the initial balances' nonce
+    const nonce = randomBytes(16);
+    const encrypted = encryptValue(enc, credited, nonce);
+    const computationOffset = this._generateComputationOffset();
+
+    const [rentSponsorPDA] = getRentSponsorPDA(this.programId, this.poolId);
+    const sponsored = (await this.connection.getAccountInfo(rentSponsorPDA)) !== null;
+
+    const sig = await this.program.methods
+      .createAndDeposit(
+        computationOffset,
+        Array.from(pubkey),
+        initialBalances,
+        nonceToBN(initialNonce),
+        Array.from(encrypted.ciphertext),
+        nonceToBN(nonce),
+        new anchor.BN(amount),
+        assetId
+      )
+      .accountsPartial({
+        payer: owner,
+        user: owner,
+        pool: this.poolPDA,
+        userAccount: userAccountPDA,
+        pendingOperation: getPendingOperationPDA(this.programId, owner, computationOffset, this.poolId)[0],
+        rentSponsor: sponsored ? rentSponsorPDA : null,
+        userTokenAccount,
+        vault: vaultPDA,
+        mint,
+        tokenProgram,
+        ...this._getArciumAccounts("add_balance", computationOffset),
+      })
+      .rpc({ skipPreflight: true, commitment: "confirmed" });
+
+    await this._awaitComputation(computationOffset);
+    return sig;
+  }
+
   /** Fetch UserProfile data for an owner */
   async fetchUserAccount(owner?: PublicKey): Promise<any> {
     const target = owner || this.wallet.publicKey;
-    const [userAccountPDA] = getUserAccountPDA(this.programId, target);
+    const [userAccountPDA] = getUserAccountPDA(this.programId, target, this.poolId);
     return (this.program.account as any).userProfile.fetch(userAccountPDA);
   }
 
@@ -189,6 +287,34 @@ export class ShuffleClient {
     }
   }
 
+  /**
+   * Close the user privacy account and reclaim its rent.
+   * Only succeeds once MPC confirms every balance is zero.
+   */
+  async closeUserAccount(): Promise<string> {
+    const owner = this.wallet.publicKey;
+    const [userAccountPDA] = getUserAccountPDA(this.programId, owner, this.poolId);
+    const [accountClosurePDA] = getAccountClosurePDA(this.programId, owner, this.poolId);
+    const account = await this.fetchUserAccount(owner);
+    const computationOffset = this._generateComputationOffset();
+
+    const sig = await this.program.methods
+      .closeUserAccount(computationOffset)
+      .accountsPartial({
+        payer: owner,
+        user: owner,
+        pool: this.poolPDA,
+        userAccount: userAccountPDA,
+        accountClosure: accountClosurePDA,
+        rentSponsor: account.rentSponsored ? getRentSponsorPDA(this.programId, this.poolId)[0] : null,
+        ...this._getArciumAccounts("check_balances_zero", computationOffset),
+      })
+      .rpc({ skipPreflight: true, commitment: "confirmed" });
+
+    await this._awaitComputation(computationOffset);
+    return sig;
+  }
+
   // =========================================================================
   // DEVNET / FAUCET METHODS
   // =========================================================================
@@ -199,8 +325,8 @@ export class ShuffleClient {
    */
   async faucet(amount: number): Promise<string> {
     const owner = this.wallet.publicKey;
-    const [userAccountPDA] = getUserAccountPDA(this.programId, owner);
-    const [faucetVaultPDA] = getFaucetVaultPDA(this.programId);
+    const [userAccountPDA] = getUserAccountPDA(this.programId, owner, this.poolId);
+    const [faucetVaultPDA] = getFaucetVaultPDA(this.programId, this.poolId);
 
     // Fetch pool to find the USDC mint
     const pool = await (this.program.account as any).pool.fetch(this.poolPDA);
@@ -231,6 +357,7 @@ export class ShuffleClient {
         user: owner,
         userAccount: userAccountPDA,
         userUsdcAccount,
+        usdcMint,
         pool: this.poolPDA,
         faucetVault: faucetVaultPDA,
         tokenProgram: TOKEN_PROGRAM_ID,
@@ -254,21 +381,34 @@ export class ShuffleClient {
     const enc = cipher || this._requireEncryption().cipher;
     const pubkey = encryptionPublicKey || this._requireEncryption().pubkey;
     const owner = this.wallet.publicKey;
-    const [userAccountPDA] = getUserAccountPDA(this.programId, owner);
+    const [userAccountPDA] = getUserAccountPDA(this.programId, owner, this.poolId);
     const assetSeed = VAULT_ASSET_SEEDS[assetId];
-    const [vaultPDA] = getVaultPDA(this.programId, assetSeed);
+    const [vaultPDA] = getVaultPDA(this.programId, assetSeed, this.poolId);
 
     // Get the pool to find the correct mint
     const pool = await (this.program.account as any).pool.fetch(this.poolPDA);
     const mints = [pool.usdcMint, pool.tslaMint, pool.spyMint, pool.aaplMint];
     const mint = mints[assetId];
 
+    // Token-2022 mints: use their program, and credit the amount net of
+    // any transfer fee (what the vault actually receives)
+    const mintInfo = await this.connection.getAccountInfo(mint);
+    const tokenProgram = mintInfo?.owner ?? TOKEN_PROGRAM_ID;
+    let credited = BigInt(amount);
+    if (!tokenProgram.equals(TOKEN_PROGRAM_ID)) {
+      const mintState = await getMint(this.connection, mint, "confirmed", tokenProgram);
+      const feeConfig = getTransferFeeConfig(mintState);
+      if (feeConfig) {
+        const epoch = BigInt((await this.connection.getEpochInfo()).epoch);
+        credited -= calculateEpochFee(feeConfig, epoch, credited);
+      }
+    }
+
     // Find user's token account for this mint
-    const { getAssociatedTokenAddress } = await import("@solana/spl-token");
-    const userTokenAccount = await getAssociatedTokenAddress(mint, owner);
+    const userTokenAccount = getAssociatedTokenAddressSync(mint, owner, false, tokenProgram);
 
     const nonce = randomBytes(16);
-    const encrypted = encryptValue(enc, BigInt(amount), nonce);
+    const encrypted = encryptValue(enc, credited, nonce);
     const computationOffset = this._generateComputationOffset();
 
     const sig = await this.program.methods
@@ -285,9 +425,11 @@ export class ShuffleClient {
         user: owner,
         pool: this.poolPDA,
         userAccount: userAccountPDA,
+        pendingOperation: getPendingOperationPDA(this.programId, owner, computationOffset, this.poolId)[0],
         userTokenAccount,
         vault: vaultPDA,
-        tokenProgram: TOKEN_PROGRAM_ID,
+        mint,
+        tokenProgram,
         ...this._getArciumAccounts("add_balance", computationOffset),
       })
       .rpc({ skipPreflight: true, commitment: "confirmed" });
@@ -296,6 +438,49 @@ export class ShuffleClient {
     return sig;
   }
 
+  /**
+   * Deposit tokens from this wallet into another user's privacy account
+   * (deposit_for). The credit is encrypted under the recipient's key; the
+   * recipient must already have a privacy account in this pool.
+   */
+  async depositFor(recipient: PublicKey, assetId: AssetId, amount: number): Promise<string> {
+    const depositor = this.wallet.publicKey;
+    const [recipientAccountPDA] = getUserAccountPDA(this.programId, recipient, this.poolId);
+    const assetSeed = VAULT_ASSET_SEEDS[assetId];
+    const [vaultPDA] = getVaultPDA(this.programId, assetSeed, this.poolId);
+
+    const pool = await (this.program.account as any).pool.fetch(this.poolPDA);
+    const mints = [pool.usdcMint, pool.tslaMint, pool.spyMint, pool.aaplMint];
+    const mint = mints[assetId];
+    const mintInfo = await this.connection.getAccountInfo(mint);
+    const tokenProgram = mintInfo?.owner ?? TOKEN_PROGRAM_ID;
+    const depositorTokenAccount = getAssociatedTokenAddressSync(
+      mint,
+      depositor,
+      false,
+      tokenProgram
+    );
+    const computationOffset = this._generateComputationOffset();
+
+    const sig = await this.program.methods
+      .depositFor(computationOffset, new anchor.BN(amount), assetId)
+      .accountsPartial({
+        payer: depositor,
+        depositor,
+        pool: this.poolPDA,
+        recipientAccount: recipientAccountPDA,
+        depositorTokenAccount,
+        vault: vaultPDA,
+        mint,
+        tokenProgram,
+        ...this._getArciumAccounts("credit_balance", computationOffset),
+      })
+      .rpc({ skipPreflight: true, commitment: "confirmed" });
+
+    await this._awaitComputation(computationOffset);
+    return sig;
+  }
+
   /** Withdraw tokens from the protocol (sub_balance). Uses internal encryption if params omitted. */
   async withdraw(
     assetId: AssetId,
@@ -306,9 +491,9 @@ export class ShuffleClient {
     const enc = cipher || this._requireEncryption().cipher;
     const pubkey = encryptionPublicKey || this._requireEncryption().pubkey;
     const owner = this.wallet.publicKey;
-    const [userAccountPDA] = getUserAccountPDA(this.programId, owner);
+    const [userAccountPDA] = getUserAccountPDA(this.programId, owner, this.poolId);
     const assetSeed = VAULT_ASSET_SEEDS[assetId];
-    const [vaultPDA] = getVaultPDA(this.programId, assetSeed);
+    const [vaultPDA] = getVaultPDA(this.programId, assetSeed, this.poolId);
 
     const pool = await (this.program.account as any).pool.fetch(this.poolPDA);
     const mints = [pool.usdcMint, pool.tslaMint, pool.spyMint, pool.aaplMint];
@@ -335,9 +520,16 @@ export class ShuffleClient {
         user: owner,
         pool: this.poolPDA,
         userAccount: userAccountPDA,
+        pendingOperation: getPendingOperationPDA(this.programId, owner, computationOffset, this.poolId)[0],
         recipientTokenAccount,
         vault: vaultPDA,
+        mint,
         tokenProgram: TOKEN_PROGRAM_ID,
+        // Daily withdrawal caps apply once the pool has a RiskConfig
+        riskConfig: pool.riskConfigured ? getRiskConfigPDA(this.programId, this.poolId)[0] : null,
+        withdrawalWindow: pool.riskConfigured
+          ? getWithdrawalWindowPDA(this.programId, owner, this.poolId)[0]
+          : null,
         ...this._getArciumAccounts("sub_balance", computationOffset),
       })
       .rpc({ skipPreflight: true, commitment: "confirmed" });
@@ -346,6 +538,52 @@ export class ShuffleClient {
     return sig;
   }
 
+  /**
+   * Re-queue a deposit that was never credited (its callback failed or was
+   * rejected as stale).
+   * @param pendingOffset Computation offset the deposit was first queued with
+   */
+  async retryDeposit(pendingOffset: anchor.BN): Promise<string> {
+    const owner = this.wallet.publicKey;
+    const [userAccountPDA] = getUserAccountPDA(this.programId, owner, this.poolId);
+    const computationOffset = this._generateComputationOffset();
+
+    const sig = await this.program.methods
+      .retryDeposit(computationOffset, pendingOffset)
+      .accountsPartial({
+        payer: owner,
+        user: owner,
+        pool: this.poolPDA,
+        userAccount: userAccountPDA,
+        pendingOperation: getPendingOperationPDA(this.programId, owner, pendingOffset, this.poolId)[0],
+        ...this._getArciumAccounts("add_balance", computationOffset),
+      })
+      .rpc({ skipPreflight: true, commitment: "confirmed" });
+
+    await this._awaitComputation(computationOffset);
+    return sig;
+  }
+
+  /**
+   * Cancel a withdrawal whose callback never arrived and reclaim its rent.
+   * Succeeds once its computation account is gone or the cancel timeout passed.
+   * @param pendingOffset Computation offset the withdrawal was queued with
+   */
+  async cancelWithdrawal(pendingOffset: anchor.BN): Promise<string> {
+    const owner = this.wallet.publicKey;
+    const [pendingOperationPDA] = getPendingOperationPDA(this.programId, owner, pendingOffset, this.poolId);
+    const pending = await (this.program.account as any).pendingOperation.fetch(pendingOperationPDA);
+    return this.program.methods
+      .cancelWithdrawal(pendingOffset)
+      .accountsPartial({
+        user: owner,
+        pool: this.poolPDA,
+        pendingOperation: pendingOperationPDA,
+        computationAccount: pending.computation,
+      })
+      .rpc({ commitment: "confirmed" });
+  }
+
   /** Decrypt all 4 asset balances from on-chain account. Uses internal cipher if param omitted. */
   async getBalance(cipher?: RescueCipher, owner?: PublicKey): Promise<UserBalance> {
     const enc = cipher || this._requireEncryption().cipher;
@@ -401,18 +639,24 @@ export class ShuffleClient {
     return { usdc, tsla, spy, aapl };
   }
 
-  /** Internal P2P transfer (USDC only). Uses internal encryption if params omitted. */
+  /**
+   * Internal P2P transfer of one asset. Uses internal encryption if params omitted.
+   * `memo` is a 64-128 byte ciphertext the caller encrypted to the recipient's
+   * x25519 key; it is emitted in the TransferEvent.
+   */
   async transfer(
     recipientPubkey: PublicKey,
     amount: number,
+    assetId: AssetId = AssetId.USDC,
     cipher?: RescueCipher,
-    encryptionPublicKey?: Uint8Array
+    encryptionPublicKey?: Uint8Array,
+    memo?: Uint8Array
   ): Promise<string> {
     const enc = cipher || this._requireEncryption().cipher;
     const pubkey = encryptionPublicKey || this._requireEncryption().pubkey;
     const sender = this.wallet.publicKey;
-    const [senderAccountPDA] = getUserAccountPDA(this.programId, sender);
-    const [recipientAccountPDA] = getUserAccountPDA(this.programId, recipientPubkey);
+    const [senderAccountPDA] = getUserAccountPDA(this.programId, sender, this.poolId);
+    const [recipientAccountPDA] = getUserAccountPDA(this.programId, recipientPubkey, this.poolId);
 
     const nonce = randomBytes(16);
     const encrypted = encryptValue(enc, BigInt(amount), nonce);
@@ -423,13 +667,17 @@ export class ShuffleClient {
         computationOffset,
         Array.from(encrypted.ciphertext),
         Array.from(pubkey),
-        nonceToBN(nonce)
+        nonceToBN(nonce),
+        assetId,
+        memo ? Buffer.from(memo) : null
       )
       .accountsPartial({
+        pool: this.poolPDA,
         payer: sender,
         sender: sender,
         senderAccount: senderAccountPDA,
         recipientAccount: recipientAccountPDA,
+        transferMemo: memo ? getTransferMemoPDA(this.programId, sender, this.poolId)[0] : null,
         ...this._getArciumAccounts("transfer", computationOffset),
       })
       .rpc({ skipPreflight: true, commitment: "confirmed" });
@@ -438,24 +686,492 @@ export class ShuffleClient {
     return sig;
   }
 
+  /**
+   * Internal P2P transfer of all four assets in one MPC call.
+   * `amounts` is indexed by AssetId; either every amount moves or none does.
+   */
+  async transferMulti(
+    recipientPubkey: PublicKey,
+    amounts: [number, number, number, number],
+    cipher?: RescueCipher,
+    encryptionPublicKey?: Uint8Array
+  ): Promise<string> {
+    const enc = cipher || this._requireEncryption().cipher;
+    const pubkey = encryptionPublicKey || this._requireEncryption().pubkey;
+    const sender = this.wallet.publicKey;
+    const [senderAccountPDA] = getUserAccountPDA(this.programId, sender, this.poolId);
+    const [recipientAccountPDA] = getUserAccountPDA(this.programId, recipientPubkey, this.poolId);
+
+    const nonce = randomBytes(16);
+    const encrypted = enc.encrypt(
+      amounts.map((amount) => BigInt(amount)),
+      nonce
+    );
+    const computationOffset = this._generateComputationOffset();
+
+    const sig = await this.program.methods
+      .internalTransferMulti(
+        computationOffset,
+        encrypted.map((ciphertext) => Array.from(ciphertext)),
+        Array.from(pubkey),
+        nonceToBN(nonce)
+      )
+      .accountsPartial({
+        pool: this.poolPDA,
+        payer: sender,
+        sender: sender,
+        senderAccount: senderAccountPDA,
+        recipientAccount: recipientAccountPDA,
+        ...this._getArciumAccounts("transfer_multi", computationOffset),
+      })
+      .rpc({ skipPreflight: true, commitment: "confirmed" });
+
+    await this._awaitComputation(computationOffset);
+    return sig;
+  }
+
+  /**
+   * Pay one asset to up to four recipients in one MPC call (payroll).
+   * `amounts[i]` goes to `recipients[i]`; either every payout moves or none does.
+   */
+  async transferBatch(
+    recipients: PublicKey[],
+    amounts: number[],
+    assetId: AssetId = AssetId.USDC,
+    cipher?: RescueCipher,
+    encryptionPublicKey?: Uint8Array
+  ): Promise<string> {
+    if (recipients.length === 0 || recipients.length > 4 || amounts.length !== recipients.length) {
+      throw new Error("transferBatch takes 1-4 recipients, one amount each");
+    }
+    const enc = cipher || this._requireEncryption().cipher;
+    const pubkey = encryptionPublicKey || this._requireEncryption().pubkey;
+    const sender = this.wallet.publicKey;
+    const [senderAccountPDA] = getUserAccountPDA(this.programId, sender, this.poolId);
+
+    // The circuit has four slots; unused ones are zero
+    const slots = [0, 1, 2, 3].map((i) => BigInt(amounts[i] ?? 0));
+    const nonce = randomBytes(16);
+    const encrypted = enc.encrypt(slots, nonce);
+    const computationOffset = this._generateComputationOffset();
+
+    const sig = await this.program.methods
+      .transferBatch(
+        computationOffset,
+        assetId,
+        encrypted.map((ciphertext) => Array.from(ciphertext)),
+        Array.from(pubkey),
+        nonceToBN(nonce)
+      )
+      .accountsPartial({
+        pool: this.poolPDA,
+        payer: sender,
+        sender: sender,
+        senderAccount: senderAccountPDA,
+        ...this._getArciumAccounts("transfer_batch", computationOffset),
+      })
+      .remainingAccounts(
+        recipients.map((recipient) => ({
+          pubkey: getUserAccountPDA(this.programId, recipient, this.poolId)[0],
+          isSigner: false,
+          isWritable: true,
+        }))
+      )
+      .rpc({ skipPreflight: true, commitment: "confirmed" });
+
+    await this._awaitComputation(computationOffset);
+    return sig;
+  }
+
+  // =========================================================================
+  // SAVINGS BUCKET METHODS
+  // =========================================================================
+
+  /**
+   * Create a labeled savings bucket for USDC (label up to 16 UTF-8 bytes).
+   * Uses internal encryption for the initial zero balance.
+   */
+  async createSavingsBucket(bucketId: number, label: string): Promise<string> {
+    const { cipher: enc } = this._requireEncryption();
+    const owner = this.wallet.publicKey;
+    const [userAccountPDA] = getUserAccountPDA(this.programId, owner, this.poolId);
+    const [savingsBucketPDA] = getSavingsBucketPDA(this.programId, owner, bucketId, this.poolId);
+
+    const labelBytes = Buffer.alloc(16);
+    Buffer.from(label, "utf8").copy(labelBytes, 0, 0, 16);
+    const initialNonce = randomBytes(16);
+    const encryptedZero = enc.encrypt([BigInt(0)], initialNonce);
+
+    return this.program.methods
+      .createSavingsBucket(
+        bucketId,
+        Array.from(labelBytes),
+        Array.from(encryptedZero[0]),
+        nonceToBN(initialNonce)
+      )
+      .accountsPartial({
+        user: owner,
+        pool: this.poolPDA,
+        userAccount: userAccountPDA,
+        savingsBucket: savingsBucketPDA,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc({ commitment: "confirmed" });
+  }
+
+  /**
+   * Move USDC between the trading balance and a savings bucket.
+   * `toBucket` = true moves into the bucket, false back to trading.
+   */
+  async moveSavings(
+    bucketId: number,
+    amount: number,
+    toBucket: boolean,
+    cipher?: RescueCipher,
+    encryptionPublicKey?: Uint8Array
+  ): Promise<string> {
+    const enc = cipher || this._requireEncryption().cipher;
+    const pubkey = encryptionPublicKey || this._requireEncryption().pubkey;
+    const owner = this.wallet.publicKey;
+    const [userAccountPDA] = getUserAccountPDA(this.programId, owner, this.poolId);
+    const [savingsBucketPDA] = getSavingsBucketPDA(this.programId, owner, bucketId, this.poolId);
+
+    const nonce = randomBytes(16);
+    const encrypted = encryptValue(enc, BigInt(amount), nonce);
+    const computationOffset = this._generateComputationOffset();
+
+    const sig = await this.program.methods
+      .moveSavings(
+        computationOffset,
+        Array.from(encrypted.ciphertext),
+        Array.from(pubkey),
+        nonceToBN(nonce),
+        toBucket
+      )
+      .accountsPartial({
+        payer: owner,
+        user: owner,
+        pool: this.poolPDA,
+        userAccount: userAccountPDA,
+        savingsBucket: savingsBucketPDA,
+        ...this._getArciumAccounts("move_savings", computationOffset),
+      })
+      .rpc({ skipPreflight: true, commitment: "confirmed" });
+
+    await this._awaitComputation(computationOffset);
+    return sig;
+  }
+
+  // =========================================================================
+  // PAYMENT STREAM METHODS
+  // =========================================================================
+
+  /**
+   * Open a payment stream paying `ratePerSecond` (base units) of an asset to
+   * `payee` between `startTs` and `endTs` (unix seconds).
+   * Uses internal encryption if params omitted.
+   */
+  async createStream(
+    streamId: number,
+    payee: PublicKey,
+    assetId: AssetId,
+    ratePerSecond: number,
+    startTs: number,
+    endTs: number,
+    cipher?: RescueCipher,
+    encryptionPublicKey?: Uint8Array
+  ): Promise<string> {
+    const enc = cipher || this._requireEncryption().cipher;
+    const pubkey = encryptionPublicKey || this._requireEncryption().pubkey;
+    const payer = this.wallet.publicKey;
+    const [payerAccountPDA] = getUserAccountPDA(this.programId, payer, this.poolId);
+    const [payeeAccountPDA] = getUserAccountPDA(this.programId, payee, this.poolId);
+    const [paymentStreamPDA] = getPaymentStreamPDA(this.programId, payer, streamId, this.poolId);
+
+    const nonce = randomBytes(16);
+    const encrypted = encryptValue(enc, BigInt(ratePerSecond), nonce);
+
+    return this.program.methods
+      .createStream(
+        new anchor.BN(streamId),
+        assetId,
+        Array.from(encrypted.ciphertext),
+        Array.from(pubkey),
+        nonceToBN(nonce),
+        new anchor.BN(startTs),
+        new anchor.BN(endTs)
+      )
+      .accountsPartial({
+        payer,
+        pool: this.poolPDA,
+        payerAccount: payerAccountPDA,
+        payeeAccount: payeeAccountPDA,
+        paymentStream: paymentStreamPDA,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc({ commitment: "confirmed" });
+  }
+
+  /**
+   * Pay out what a stream has accrued since its last advance.
+   * Permissionless: any wallet can crank any stream.
+   */
+  async advanceStream(streamPayer: PublicKey, streamId: number): Promise<string> {
+    const [paymentStreamPDA] = getPaymentStreamPDA(this.programId, streamPayer, streamId, this.poolId);
+    const stream = await (this.program.account as any).paymentStream.fetch(paymentStreamPDA);
+    const [payerAccountPDA] = getUserAccountPDA(this.programId, stream.payer, this.poolId);
+    const [payeeAccountPDA] = getUserAccountPDA(this.programId, stream.payee, this.poolId);
+    const computationOffset = this._generateComputationOffset();
+
+    const sig = await this.program.methods
+      .advanceStream(computationOffset)
+      .accountsPartial({
+        payer: this.wallet.publicKey,
+        pool: this.poolPDA,
+        paymentStream: paymentStreamPDA,
+        payerAccount: payerAccountPDA,
+        payeeAccount: payeeAccountPDA,
+        ...this._getArciumAccounts("stream_transfer", computationOffset),
+      })
+      .rpc({ skipPreflight: true, commitment: "confirmed" });
+
+    await this._awaitComputation(computationOffset);
+    return sig;
+  }
+
+  /** Cancel one of the wallet's payment streams and reclaim its rent. */
+  async cancelStream(streamId: number): Promise<string> {
+    const payer = this.wallet.publicKey;
+    const [paymentStreamPDA] = getPaymentStreamPDA(this.programId, payer, streamId, this.poolId);
+
+    return this.program.methods
+      .cancelStream()
+      .accountsPartial({ payer, paymentStream: paymentStreamPDA })
+      .rpc({ commitment: "confirmed" });
+  }
+
+  // =========================================================================
+  // VOUCHER METHODS
+  // =========================================================================
+
+  /**
+   * Escrow `amount` of an asset behind a fresh voucher secret, claimable
+   * for `expirySecs`. Returns the secret (64-byte ed25519 secret key) to
+   * share with the recipient, e.g. as a link. Uses internal encryption.
+   */
+  async createVoucher(
+    assetId: AssetId,
+    amount: number,
+    expirySecs: number
+  ): Promise<{ signature: string; voucherSecret: Uint8Array }> {
+    const { cipher: enc } = this._requireEncryption();
+    const funder = this.wallet.publicKey;
+    const [funderAccountPDA] = getUserAccountPDA(this.programId, funder, this.poolId);
+    const claimKey = Keypair.generate();
+    const [voucherPDA] = getVoucherPDA(this.programId, claimKey.publicKey, this.poolId);
+
+    const nonce = randomBytes(16);
+    const encrypted = encryptValue(enc, BigInt(amount), nonce);
+    const computationOffset = this._generateComputationOffset();
+
+    const signature = await this.program.methods
+      .createVoucher(
+        computationOffset,
+        assetId,
+        Array.from(encrypted.ciphertext),
+        nonceToBN(nonce),
+        new anchor.BN(expirySecs)
+      )
+      .accountsPartial({
+        payer: funder,
+        funder,
+        pool: this.poolPDA,
+        funderAccount: funderAccountPDA,
+        claimKey: claimKey.publicKey,
+        voucher: voucherPDA,
+        ...this._getArciumAccounts("fund_voucher", computationOffset),
+      })
+      .rpc({ skipPreflight: true, commitment: "confirmed" });
+
+    await this._awaitComputation(computationOffset);
+    return { signature, voucherSecret: claimKey.secretKey };
+  }
+
+  /**
+   * Claim a voucher into this wallet's privacy account, creating the
+   * account first if it does not exist yet.
+   */
+  async claimVoucher(voucherSecret: Uint8Array): Promise<string> {
+    if (!(await this.accountExists())) {
+      await this.createUserAccount();
+    }
+    const claimer = this.wallet.publicKey;
+    const claimKey = Keypair.fromSecretKey(voucherSecret);
+    const [claimerAccountPDA] = getUserAccountPDA(this.programId, claimer, this.poolId);
+    const [voucherPDA] = getVoucherPDA(this.programId, claimKey.publicKey, this.poolId);
+    const computationOffset = this._generateComputationOffset();
+
+    const sig = await this.program.methods
+      .claimVoucher(computationOffset)
+      .accountsPartial({
+        payer: claimer,
+        claimer,
+        claimKey: claimKey.publicKey,
+        pool: this.poolPDA,
+        claimerAccount: claimerAccountPDA,
+        voucher: voucherPDA,
+        ...this._getArciumAccounts("redeem_voucher", computationOffset),
+      })
+      .signers([claimKey])
+      .rpc({ skipPreflight: true, commitment: "confirmed" });
+
+    await this._awaitComputation(computationOffset);
+    return sig;
+  }
+
+  /** Take back an expired, unclaimed voucher this wallet funded. */
+  async reclaimVoucher(claimKey: PublicKey): Promise<string> {
+    const funder = this.wallet.publicKey;
+    const [funderAccountPDA] = getUserAccountPDA(this.programId, funder, this.poolId);
+    const [voucherPDA] = getVoucherPDA(this.programId, claimKey, this.poolId);
+    const computationOffset = this._generateComputationOffset();
+
+    const sig = await this.program.methods
+      .reclaimVoucher(computationOffset)
+      .accountsPartial({
+        payer: funder,
+        funder,
+        pool: this.poolPDA,
+        funderAccount: funderAccountPDA,
+        voucher: voucherPDA,
+        ...this._getArciumAccounts("redeem_voucher", computationOffset),
+      })
+      .rpc({ skipPreflight: true, commitment: "confirmed" });
+
+    await this._awaitComputation(computationOffset);
+    return sig;
+  }
+
+  // =========================================================================
+  // GUARDIAN RECOVERY METHODS
+  // =========================================================================
+
+  /**
+   * Register the guardians that can move this wallet's account to a new
+   * wallet once `threshold` of them approve and `challengePeriodSecs` pass.
+   */
+  async setGuardians(
+    guardians: PublicKey[],
+    threshold: number,
+    challengePeriodSecs: number
+  ): Promise<string> {
+    const user = this.wallet.publicKey;
+    const [userAccountPDA] = getUserAccountPDA(this.programId, user, this.poolId);
+    const [guardianSetPDA] = getGuardianSetPDA(this.programId, user, this.poolId);
+
+    return this.program.methods
+      .setGuardians(guardians, threshold, new anchor.BN(challengePeriodSecs))
+      .accountsPartial({
+        user,
+        pool: this.poolPDA,
+        userAccount: userAccountPDA,
+        guardianSet: guardianSetPDA,
+      })
+      .rpc({ commitment: "confirmed" });
+  }
+
+  /**
+   * As a guardian of `owner`, approve moving their account to `newOwner`,
+   * whose privacy account must use `newUserPubkey`.
+   */
+  async approveRecovery(
+    owner: PublicKey,
+    newOwner: PublicKey,
+    newUserPubkey: Uint8Array
+  ): Promise<string> {
+    const [guardianSetPDA] = getGuardianSetPDA(this.programId, owner, this.poolId);
+
+    return this.program.methods
+      .approveRecovery(newOwner, Array.from(newUserPubkey))
+      .accountsPartial({
+        guardian: this.wallet.publicKey,
+        guardianSet: guardianSetPDA,
+      })
+      .rpc({ commitment: "confirmed" });
+  }
+
+  /** Reject the recovery in progress on this wallet's account. */
+  async cancelRecovery(): Promise<string> {
+    const user = this.wallet.publicKey;
+    const [userAccountPDA] = getUserAccountPDA(this.programId, user, this.poolId);
+    const [guardianSetPDA] = getGuardianSetPDA(this.programId, user, this.poolId);
+
+    return this.program.methods
+      .cancelRecovery()
+      .accountsPartial({
+        user,
+        pool: this.poolPDA,
+        userAccount: userAccountPDA,
+        guardianSet: guardianSetPDA,
+      })
+      .rpc({ commitment: "confirmed" });
+  }
+
+  /**
+   * Move an approved recovery of `previousOwner`'s account into this
+   * wallet's privacy account, creating it first with the internal
+   * encryption key (which must be the approved one) if needed.
+   */
+  async executeRecovery(previousOwner: PublicKey): Promise<string> {
+    if (!(await this.accountExists())) {
+      await this.createUserAccount();
+    }
+    const newOwner = this.wallet.publicKey;
+    const [oldAccountPDA] = getUserAccountPDA(this.programId, previousOwner, this.poolId);
+    const [newAccountPDA] = getUserAccountPDA(this.programId, newOwner, this.poolId);
+    const [guardianSetPDA] = getGuardianSetPDA(this.programId, previousOwner, this.poolId);
+    const oldAccount = await this.fetchUserAccount(previousOwner);
+    const computationOffset = this._generateComputationOffset();
+
+    const sig = await this.program.methods
+      .executeRecovery(computationOffset)
+      .accountsPartial({
+        payer: newOwner,
+        newOwner,
+        guardianSet: guardianSetPDA,
+        pool: this.poolPDA,
+        oldAccount: oldAccountPDA,
+        newAccount: newAccountPDA,
+        rentSponsor: oldAccount.rentSponsored ? getRentSponsorPDA(this.programId, this.poolId)[0] : null,
+        ...this._getArciumAccounts("recover_account", computationOffset),
+      })
+      .rpc({ skipPreflight: true, commitment: "confirmed" });
+
+    await this._awaitComputation(computationOffset);
+    return sig;
+  }
+
   // =========================================================================
   // ORDER METHODS
   // =========================================================================
 
   /**
    * Initialize batch state with encrypted zeros.
-   * This must be called before the first order of each new batch.
-   * After batch execution, the batch state needs to be re-initialized for the next batch.
+   * Needed once per accumulator slot before its first order (or after a
+   * reset whose re-encryption never landed). Only the pool authority can
+   * call it. Targets the active accumulator slot unless `slot` is given.
    */
-  async initBatchState(): Promise<string> {
+  async initBatchState(slot?: number): Promise<string> {
     const owner = this.wallet.publicKey;
     const computationOffset = this._generateComputationOffset();
+    const targetSlot = slot ?? (await this.getBatchInfo()).slot;
 
     const sig = await this.program.methods
       .initBatchState(computationOffset)
       .accountsPartial({
+        pool: this.poolPDA,
         payer: owner,
-        batchAccumulator: this.batchAccumulatorPDA,
+        batchAccumulator: this.batchAccumulatorPDAs[targetSlot],
         ...this._getArciumAccounts("init_batch_state", computationOffset),
       })
       .rpc({ skipPreflight: true, commitment: "confirmed" });
@@ -468,6 +1184,7 @@ export class ShuffleClient {
    * Place an encrypted order in the current batch.
    * Automatically initializes batch state if needed (first order of a new batch).
    * Uses internal encryption if params omitted.
+   * `validUntilBatch` lets an unexecuted order roll over up to that batch (0 = no rollover).
    */
   async placeOrder(
     pairId: PairId,
@@ -475,21 +1192,18 @@ export class ShuffleClient {
     amount: number,
     sourceAssetId: AssetId,
     cipher?: RescueCipher,
-    encryptionPublicKey?: Uint8Array
+    encryptionPublicKey?: Uint8Array,
+    validUntilBatch: number = 0
   ): Promise<string> {
     // Lazy check: If mxe_nonce is 0, batch state needs initialization
     // (mxe_nonce is set by init_batch_state callback, 0 means not yet initialized)
     const batchInfo = await this.getBatchInfo();
-    if (batchInfo.mxeNonce === "0") {
-      console.log("[SDK] Initializing batch state for new batch...");
-      await this.initBatchState();
-      console.log("[SDK] Batch state initialized");
-    }
+    await this._ensureBatchState(batchInfo.mxeNonce);
 
     const enc = cipher || this._requireEncryption().cipher;
     const pubkey = encryptionPublicKey || this._requireEncryption().pubkey;
     const owner = this.wallet.publicKey;
-    const [userAccountPDA] = getUserAccountPDA(this.programId, owner);
+    const [userAccountPDA] = getUserAccountPDA(this.programId, owner, this.poolId);
 
     const orderNonce = randomBytes(16);
     // Encrypt OrderInput struct fields together in a single call
@@ -508,13 +1222,15 @@ export class ShuffleClient {
         Array.from(encryptedOrderInput[2]),
         Array.from(pubkey),
         nonceToBN(orderNonce),
-        sourceAssetId
+        sourceAssetId,
+        new anchor.BN(validUntilBatch)
       )
       .accountsPartial({
+        pool: this.poolPDA,
         payer: owner,
         user: owner,
         userAccount: userAccountPDA,
-        batchAccumulator: this.batchAccumulatorPDA,
+        batchAccumulator: this.batchAccumulatorPDAs[batchInfo.slot],
         ...this._getArciumAccounts("accumulate_order", computationOffset),
       })
       .rpc({ skipPreflight: true, commitment: "confirmed" });
@@ -523,6 +1239,79 @@ export class ShuffleClient {
     return sig;
   }
 
+  /**
+   * Place an order sized in USD. `notional` is a USDC amount (6 decimals);
+   * MPC converts it to source asset units at the oracle price, and the
+   * pending order then holds the converted amount.
+   *
+   * When the pool has an oracle configured, `priceUpdate` must be a posted
+   * Pyth update for the source asset if it has a Pyth feed; its Switchboard
+   * fallback feed is taken from the OracleConfig.
+   */
+  async placeOrderUsd(
+    pairId: PairId,
+    direction: Direction,
+    notional: number,
+    sourceAssetId: AssetId,
+    cipher?: RescueCipher,
+    encryptionPublicKey?: Uint8Array,
+    validUntilBatch: number = 0,
+    priceUpdate?: PublicKey
+  ): Promise<string> {
+    const batchInfo = await this.getBatchInfo();
+    await this._ensureBatchState(batchInfo.mxeNonce);
+
+    const enc = cipher || this._requireEncryption().cipher;
+    const pubkey = encryptionPublicKey || this._requireEncryption().pubkey;
+    const owner = this.wallet.publicKey;
+    const [userAccountPDA] = getUserAccountPDA(this.programId, owner, this.poolId);
+
+    const orderNonce = randomBytes(16);
+    // Same OrderInput layout as placeOrder, with the notional as amount
+    const encryptedOrderInput = enc.encrypt(
+      [BigInt(pairId), BigInt(direction), BigInt(notional)],
+      orderNonce
+    );
+    const computationOffset = this._generateComputationOffset();
+
+    // Priced the way execute_batch prices the batch
+    const pool = await (this.program.account as any).pool.fetch(this.poolPDA);
+    const [oracleConfigPDA] = getOracleConfigPDA(this.programId, this.poolId);
+    let fallbackFeed: PublicKey | null = null;
+    if (pool.oracleConfigured) {
+      const oracle = await (this.program.account as any).oracleConfig.fetch(oracleConfigPDA);
+      const feed = oracle.fallbackFeeds[sourceAssetId] as PublicKey;
+      fallbackFeed = feed.equals(PublicKey.default) ? null : feed;
+    }
+
+    const sig = await this.program.methods
+      .placeOrderUsd(
+        computationOffset,
+        Array.from(encryptedOrderInput[0]),
+        Array.from(encryptedOrderInput[1]),
+        Array.from(encryptedOrderInput[2]),
+        Array.from(pubkey),
+        nonceToBN(orderNonce),
+        sourceAssetId,
+        new anchor.BN(validUntilBatch)
+      )
+      .accountsPartial({
+        pool: this.poolPDA,
+        payer: owner,
+        user: owner,
+        userAccount: userAccountPDA,
+        batchAccumulator: this.batchAccumulatorPDAs[batchInfo.slot],
+        oracleConfig: pool.oracleConfigured ? oracleConfigPDA : null,
+        priceUpdate: priceUpdate ?? null,
+        fallbackFeed,
+        ...this._getArciumAccounts("accumulate_order_usd", computationOffset),
+      })
+      .rpc({ skipPreflight: true, commitment: "confirmed" });
+
+    await this._awaitComputation(computationOffset);
+    return sig;
+  }
+
   /** Get current pending order info, or null */
   async getPendingOrder(owner?: PublicKey): Promise<OrderInfo | null> {
     const account = await this.fetchUserAccount(owner);
@@ -534,6 +1323,7 @@ export class ShuffleClient {
       pairId: Array.from(order.pairId),
       direction: Array.from(order.direction),
       encryptedAmount: Array.from(order.encryptedAmount),
+      validUntilBatch: order.validUntilBatch.toNumber(),
     };
   }
 
@@ -597,17 +1387,33 @@ export class ShuffleClient {
     }
 
     const batchId = batch.batchId;
-    const [batchLogPDA] = getBatchLogPDA(this.programId, batchId);
+    const [batchLogPDA] = getBatchLogPDA(this.programId, batchId, this.poolId);
 
     const computationOffset = this._generateComputationOffset();
     const owner = this.wallet.publicKey;
 
+    // Registry-gated execution checks the caller's bond in the registry
+    const [executorRegistryPDA] = getExecutorRegistryPDA(this.programId, this.poolId);
+    const hasRegistry = (await this.connection.getAccountInfo(executorRegistryPDA)) !== null;
+    // Stamps the reveal circuit version into the BatchLog when registered
+    const [circuitRegistryPDA] = getCircuitRegistryPDA(this.programId, this.poolId);
+    const hasCircuitRegistry =
+      (await this.connection.getAccountInfo(circuitRegistryPDA)) !== null;
+    const pool = await (this.program.account as any).pool.fetch(this.poolPDA);
+
     const sig = await this.program.methods
       .executeBatch(computationOffset)
       .accountsPartial({
+        pool: this.poolPDA,
         payer: owner,
-        batchAccumulator: this.batchAccumulatorPDA,
+        batchAccumulator: this.batchAccumulatorPDAs[batch.slot],
+        nextAccumulator: this.batchAccumulatorPDAs[1 - batch.slot],
         batchLog: batchLogPDA,
+        executorRegistry: hasRegistry ? executorRegistryPDA : null,
+        circuitRegistry: hasCircuitRegistry ? circuitRegistryPDA : null,
+        // Only needed by granted operators flushing below the trigger count
+        roles: null,
+        riskConfig: pool.riskConfigured ? getRiskConfigPDA(this.programId, this.poolId)[0] : null,
         // Arcium accounts
         ...this._getArciumAccounts("reveal_batch", computationOffset),
       })
@@ -631,13 +1437,15 @@ export class ShuffleClient {
   ): Promise<string> {
     const pubkey = encryptionPublicKey || this._requireEncryption().pubkey;
     const owner = this.wallet.publicKey;
-    const [userAccountPDA] = getUserAccountPDA(this.programId, owner);
+    const [userAccountPDA] = getUserAccountPDA(this.programId, owner, this.poolId);
 
     const account = await this.fetchUserAccount();
     if (!account.pendingOrder) throw new Error("No pending order to settle");
 
     const batchId = account.pendingOrder.batchId.toNumber();
-    const [batchLogPDA] = getBatchLogPDA(this.programId, batchId);
+    const [batchLogPDA] = getBatchLogPDA(this.programId, batchId, this.poolId);
+    const [preferencePDA] = getSettlementPreferencePDA(this.programId, owner, this.poolId);
+    const hasPreference = (await this.connection.getAccountInfo(preferencePDA)) !== null;
 
     const settlementNonce = randomBytes(16);
     const computationOffset = this._generateComputationOffset();
@@ -654,7 +1462,9 @@ export class ShuffleClient {
         payer: owner,
         user: owner,
         userAccount: userAccountPDA,
+        pool: this.poolPDA,
         batchLog: batchLogPDA,
+        settlementPreference: hasPreference ? preferencePDA : null,
         ...this._getArciumAccounts("calculate_payout", computationOffset),
       })
       .rpc({ skipPreflight: true, commitment: "confirmed" });
@@ -663,25 +1473,70 @@ export class ShuffleClient {
     return sig;
   }
 
+  /**
+   * Set the settlement currency preference. With `convertToUsdc` true, non-USDC
+   * payouts are sold for USDC in the next batch through a staged follow-up
+   * order; `null` clears the preference and reclaims its rent. The flag is
+   * encrypted, so the chain only shows that a preference exists.
+   */
+  async setSettlementPreference(
+    convertToUsdc: boolean | null,
+    cipher?: RescueCipher
+  ): Promise<string> {
+    const owner = this.wallet.publicKey;
+    const [userAccountPDA] = getUserAccountPDA(this.programId, owner, this.poolId);
+    const [preferencePDA] = getSettlementPreferencePDA(this.programId, owner, this.poolId);
+
+    if (convertToUsdc === null) {
+      return this.program.methods
+        .clearSettlementPreference()
+        .accountsPartial({
+          pool: this.poolPDA,
+          user: owner,
+          userAccount: userAccountPDA,
+          settlementPreference: preferencePDA,
+        })
+        .rpc({ commitment: "confirmed" });
+    }
+
+    const enc = cipher || this._requireEncryption().cipher;
+    const nonce = randomBytes(16);
+    const [encryptedFlag] = enc.encrypt([BigInt(convertToUsdc ? 1 : 0)], nonce);
+
+    return this.program.methods
+      .setSettlementPreference(Array.from(encryptedFlag), nonceToBN(nonce))
+      .accountsPartial({
+        pool: this.poolPDA,
+        user: owner,
+        userAccount: userAccountPDA,
+        settlementPreference: preferencePDA,
+      })
+      .rpc({ commitment: "confirmed" });
+  }
+
   // =========================================================================
   // QUERY METHODS
   // =========================================================================
 
-  /** Fetch current batch accumulator state */
+  /** Fetch the batch accumulator slot currently receiving orders */
   async getBatchInfo(): Promise<BatchInfo> {
-    const batch = await (this.program.account as any).batchAccumulator.fetch(
-      this.batchAccumulatorPDA
+    const slots = await (this.program.account as any).batchAccumulator.fetchMultiple(
+      this.batchAccumulatorPDAs
     );
+    const slot = slots.findIndex((batch: any) => batch?.active);
+    if (slot < 0) throw new Error("No active batch accumulator");
+    const batch = slots[slot];
     return {
       batchId: batch.batchId.toNumber(),
       orderCount: batch.orderCount,
       mxeNonce: batch.mxeNonce.toString(),
+      slot,
     };
   }
 
   /** Fetch historical batch log */
   async getBatchLog(batchId: number): Promise<BatchResult> {
-    const [batchLogPDA] = getBatchLogPDA(this.programId, batchId);
+    const [batchLogPDA] = getBatchLogPDA(this.programId, batchId, this.poolId);
     const log = await (this.program.account as any).batchLog.fetch(batchLogPDA);
 
     // Note: Anchor uses camelCase field names (converted from Rust's snake_case)
@@ -690,11 +1545,22 @@ export class ShuffleClient {
       totalBIn: r.totalBIn ?? r.total_b_in,
       finalPoolA: r.finalPoolA ?? r.final_pool_a,
       finalPoolB: r.finalPoolB ?? r.final_pool_b,
+      executionPrice: r.executionPrice ?? r.execution_price,
     }));
 
     return {
       batchId: log.batchId?.toNumber() ?? log.batch_id?.toNumber(),
       results,
+      oraclePrices: log.oraclePrices ?? log.oracle_prices,
+      oracleSlot: log.oracleSlot ?? log.oracle_slot,
+      oracleConfidence: log.oracleConfidence ?? log.oracle_confidence,
+      executionFeeBps: log.executionFeeBps ?? log.execution_fee_bps ?? 0,
+      maxSlippageBps: log.maxSlippageBps ?? log.max_slippage_bps ?? 0,
+      routeMinOut: log.routeMinOut ?? log.route_min_out,
+      shortfallPairs: log.shortfallPairs ?? log.shortfall_pairs ?? 0,
+      swapExecuted: log.swapExecuted ?? log.swap_executed,
+      haltedPairs: log.haltedPairs ?? log.halted_pairs ?? 0,
+      pairFeeBps: log.pairFeeBps ?? log.pair_fee_bps,
     };
   }
 
@@ -727,7 +1593,8 @@ export class ShuffleClient {
 
   /**
    * Estimate payout for a pending order after batch execution.
-   * Uses client-side calculation: payout = (orderAmount / totalInput) * finalPoolOutput
+   * Uses client-side calculation: payout = (orderAmount / totalInput) * finalPoolOutput,
+   * where finalPoolOutput is net of the batch's execution fee
    * 
    * @param cipher - Optional cipher (uses internal if omitted)
    * @param owner - Optional owner pubkey (uses wallet if omitted)
@@ -759,9 +1626,12 @@ export class ShuffleClient {
       ? BigInt(pairResult.totalAIn.toString())
       : BigInt(pairResult.totalBIn.toString());
     
-    const finalPoolOutput = order.direction === 0
+    const grossPoolOutput = order.direction === 0
       ? BigInt(pairResult.finalPoolB.toString())
       : BigInt(pairResult.finalPoolA.toString());
+    // Same rounding as BatchLog::execution_fee on-chain
+    const finalPoolOutput =
+      grossPoolOutput - (grossPoolOutput * BigInt(batchLog.executionFeeBps)) / 10000n;
 
     // Prevent division by zero
     if (totalInput === 0n) {
@@ -842,6 +1712,21 @@ export class ShuffleClient {
   /**
    * Helper to determine output asset ID based on pair and direction
    */
+  /**
+   * Initialize the active slot's batch state if it has none yet. Only the
+   * pool authority may do so; anyone else gets an error instead.
+   */
+  private async _ensureBatchState(mxeNonce: string): Promise<void> {
+    if (mxeNonce !== "0") return;
+    const pool = await (this.program.account as any).pool.fetch(this.poolPDA);
+    if (!pool.authority.equals(this.wallet.publicKey)) {
+      throw new Error("Batch state not initialized: the pool authority must call initBatchState");
+    }
+    console.log("[SDK] Initializing batch state for new batch...");
+    await this.initBatchState();
+    console.log("[SDK] Batch state initialized");
+  }
+
   private _getOutputAssetId(pairId: number, direction: number): AssetId {
     // Pair mapping (from constants):
     // TSLA_USDC = 0, SPY_USDC = 1, AAPL_USDC = 2
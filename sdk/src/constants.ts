@@ -34,9 +34,31 @@ export const NUM_ASSETS = 4;
 export const POOL_SEED = "pool";
 export const USER_SEED = "user";
 export const BATCH_ACCUMULATOR_SEED = "batch_accumulator";
+/** Batch accumulators are double-buffered: slots 0 and 1 alternate */
+export const BATCH_ACCUMULATOR_SLOTS = 2;
 export const BATCH_LOG_SEED = "batch_log";
 export const VAULT_SEED = "vault";
 export const FAUCET_USDC_SEED = "faucet_usdc";
+export const SETTLEMENT_PREFERENCE_SEED = "settlement_preference";
+export const VIEWABLE_BALANCE_SEED = "viewable_balance";
+export const VIEWER_GRANT_SEED = "viewer_grant";
+export const TRANSFER_REQUEST_SEED = "transfer_request";
+export const TRANSFER_MEMO_SEED = "transfer_memo";
+export const KEY_ROTATION_SEED = "key_rotation";
+export const ACCOUNT_CLOSURE_SEED = "account_closure";
+export const PENDING_OPERATION_SEED = "pending_operation";
+export const SAVINGS_BUCKET_SEED = "savings_bucket";
+export const PAYMENT_STREAM_SEED = "payment_stream";
+export const VOUCHER_SEED = "voucher";
+export const GUARDIAN_SET_SEED = "guardian_set";
+export const RENT_SPONSOR_SEED = "rent_sponsor";
+export const EXECUTOR_REGISTRY_SEED = "executor_registry";
+export const EVENT_CURSOR_SEED = "event_cursor";
+export const PROTOCOL_STATS_SEED = "protocol_stats";
+export const RISK_CONFIG_SEED = "risk_config";
+export const WITHDRAWAL_WINDOW_SEED = "withdrawal_window";
+export const CIRCUIT_REGISTRY_SEED = "circuit_registry";
+export const ORACLE_CONFIG_SEED = "oracle_config";
 
 // Per-asset vault sub-seeds
 export const VAULT_ASSET_SEEDS: Record<AssetId, string> = {
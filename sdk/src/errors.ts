@@ -1,39 +1,219 @@
-/** Maps Anchor error codes (6000+) to readable names and messages */
-export const ERROR_MAP: Record<number, { name: string; message: string }> = {
-  6000: { name: "ProtocolPaused", message: "Protocol is paused" },
-  6001: { name: "Unauthorized", message: "Unauthorized" },
-  6002: { name: "InvalidAmount", message: "Invalid amount" },
-  6003: { name: "InvalidAsset", message: "Invalid asset" },
-  6004: { name: "InvalidAssetId", message: "Invalid asset ID (must be 0-3 for USDC, TSLA, SPY, AAPL)" },
-  6005: { name: "InvalidPairId", message: "Invalid pair ID (must be 0-5)" },
-  6006: { name: "InvalidMint", message: "Invalid token mint" },
-  6007: { name: "InvalidOwner", message: "Invalid token account owner" },
-  6008: { name: "FeeTooHigh", message: "Fee too high (max 10%)" },
-  6009: { name: "PendingOrderExists", message: "User has a pending order - settle before placing a new one" },
-  6010: { name: "NoPendingOrder", message: "No pending order to settle" },
-  6011: { name: "BatchNotFinalized", message: "Batch not yet executed" },
-  6012: { name: "BatchIdMismatch", message: "Batch ID mismatch" },
-  6013: { name: "InvalidBatchId", message: "Invalid batch ID - doesn't match BatchLog" },
-  6014: { name: "SwapsAlreadyExecuted", message: "Swaps already executed for this batch" },
-  6015: { name: "InsufficientBalance", message: "Insufficient balance" },
-  6016: { name: "MinOutputNotMet", message: "Minimum output not met" },
-  6017: { name: "DivisionByZero", message: "Division by zero in settlement - no input for this pair" },
-  6018: { name: "AbortedComputation", message: "The computation was aborted" },
-  6019: { name: "ComputationFailed", message: "MPC computation failed" },
-  6020: { name: "ClusterNotSet", message: "Cluster not set" },
-  6021: { name: "RecipientAccountNotFound", message: "Recipient account not found - they must create a privacy account first" },
-  6022: { name: "FaucetLimitExceeded", message: "Faucet limit exceeded - you can only claim up to 1000 USDC total" },
+/** Protocol area an error belongs to (mirrors ErrorDomain in errors.rs) */
+export type ErrorDomain =
+  | "protocolState"
+  | "authorization"
+  | "inputValidation"
+  | "orderBatch"
+  | "dca"
+  | "balance"
+  | "swapExecution"
+  | "mpc"
+  | "transfer"
+  | "reserveAuction"
+  | "beneficiary"
+  | "delegation"
+  | "buyback"
+  | "faucet"
+  | "executor"
+  | "oracle";
+
+/** Classification of an error code, as carried by ErrorInfo in failure events */
+export interface ErrorInfo {
+  code: number;
+  domain: ErrorDomain;
+  /** Re-sending the same request later can succeed */
+  retryable: boolean;
+}
+
+/** Maps Anchor error codes (6000+) to readable names, messages and classification */
+export const ERROR_MAP: Record<
+  number,
+  { name: string; message: string; domain: ErrorDomain; retryable: boolean }
+> = {
+  6000: { name: "ProtocolPaused", message: "Protocol is paused", domain: "protocolState", retryable: true },
+  6001: { name: "AssetFrozen", message: "Asset is frozen", domain: "protocolState", retryable: true },
+  6002: { name: "AssetNotFrozen", message: "Asset is not frozen", domain: "protocolState", retryable: false },
+  6003: { name: "AssetDelisted", message: "Asset is delisted", domain: "protocolState", retryable: false },
+  6004: { name: "UnfreezeNotRequested", message: "Unfreeze not requested", domain: "protocolState", retryable: false },
+  6005: { name: "UnfreezeTimelockActive", message: "Unfreeze timelock has not elapsed", domain: "protocolState", retryable: true },
+  6006: { name: "Unauthorized", message: "Unauthorized", domain: "authorization", retryable: false },
+  6007: { name: "InvalidAmount", message: "Invalid amount", domain: "inputValidation", retryable: false },
+  6008: { name: "InvalidAsset", message: "Invalid asset", domain: "inputValidation", retryable: false },
+  6009: { name: "InvalidAssetId", message: "Invalid asset ID (must be 0-3 for USDC, TSLA, SPY, AAPL)", domain: "inputValidation", retryable: false },
+  6010: { name: "InvalidPairId", message: "Invalid pair ID (must be 0-5)", domain: "inputValidation", retryable: false },
+  6011: { name: "AssetAlreadyRegistered", message: "Asset is already registered", domain: "inputValidation", retryable: false },
+  6012: { name: "AssetRegistryFull", message: "Asset registry is full", domain: "inputValidation", retryable: false },
+  6013: { name: "InvalidAssetSymbol", message: "Invalid asset symbol", domain: "inputValidation", retryable: false },
+  6014: { name: "InvalidMint", message: "Invalid token mint", domain: "inputValidation", retryable: false },
+  6015: { name: "InvalidOwner", message: "Invalid token account owner", domain: "inputValidation", retryable: false },
+  6016: { name: "InvalidAssetPolicy", message: "Invalid asset transfer policy", domain: "inputValidation", retryable: false },
+  6017: { name: "NotAllowlisted", message: "Wallet is not on the issuer allowlist", domain: "inputValidation", retryable: false },
+  6018: { name: "VaultHasDelegate", message: "Vault has a delegate set", domain: "inputValidation", retryable: false },
+  6019: { name: "VaultHasCloseAuthority", message: "Vault has a close authority set", domain: "inputValidation", retryable: false },
+  6020: { name: "FeeTooHigh", message: "Fee too high (max 10%)", domain: "inputValidation", retryable: false },
+  6021: { name: "NoFeesOwed", message: "No execution fees owed", domain: "inputValidation", retryable: false },
+  6022: { name: "InvalidBatchConfig", message: "Invalid batch config", domain: "inputValidation", retryable: false },
+  6023: { name: "InvalidKeeperReward", message: "Keeper reward too high", domain: "inputValidation", retryable: false },
+  6024: { name: "InvalidApprovalConfig", message: "Invalid batch approval config", domain: "inputValidation", retryable: false },
+  6025: { name: "InvalidPoolAddress", message: "Invalid operator or treasury address", domain: "inputValidation", retryable: false },
+  6026: { name: "InvalidRole", message: "Invalid role or role member", domain: "inputValidation", retryable: false },
+  6027: { name: "RoleFull", message: "Role has no free member slot", domain: "inputValidation", retryable: false },
+  6028: { name: "InvalidRiskConfig", message: "Invalid risk config", domain: "inputValidation", retryable: false },
+  6029: { name: "RiskConfigMissing", message: "Risk config not passed", domain: "inputValidation", retryable: false },
+  6030: { name: "WithdrawalCapExceeded", message: "Daily withdrawal cap exceeded", domain: "inputValidation", retryable: true },
+  6031: { name: "UnsupportedConfigVersion", message: "Unsupported config version", domain: "inputValidation", retryable: false },
+  6032: { name: "AccountAlreadyMigrated", message: "Account is already migrated", domain: "inputValidation", retryable: false },
+  6033: { name: "UnsupportedAccountLayout", message: "Unsupported account layout", domain: "inputValidation", retryable: false },
+  6034: { name: "InvalidViewingKey", message: "Invalid viewing key", domain: "inputValidation", retryable: false },
+  6035: { name: "InvalidUserKey", message: "Invalid user key", domain: "inputValidation", retryable: false },
+  6036: { name: "DepositNonceReused", message: "Deposit nonce must differ from the initial balance nonce", domain: "inputValidation", retryable: false },
+  6037: { name: "InvalidPendingOperation", message: "Invalid pending operation", domain: "inputValidation", retryable: false },
+  6038: { name: "PendingOrderExists", message: "User has a pending order - settle before placing a new one", domain: "orderBatch", retryable: true },
+  6039: { name: "NoPendingOrder", message: "No pending order to settle", domain: "orderBatch", retryable: false },
+  6040: { name: "NoStagedOrder", message: "No staged order to place", domain: "orderBatch", retryable: false },
+  6041: { name: "InvalidOrderValidity", message: "Order validity ends before the current batch", domain: "orderBatch", retryable: false },
+  6042: { name: "BasketOrderActive", message: "A basket order is already in progress", domain: "orderBatch", retryable: true },
+  6043: { name: "NoBasketOrder", message: "No basket order to settle", domain: "orderBatch", retryable: false },
+  6044: { name: "InvalidBasketLeg", message: "Invalid basket leg", domain: "orderBatch", retryable: false },
+  6045: { name: "BasketRejected", message: "Basket order rejected", domain: "orderBatch", retryable: false },
+  6046: { name: "InvalidOrderRoute", message: "Settlement route does not match the order", domain: "orderBatch", retryable: false },
+  6047: { name: "OrderRouteOutOfRange", message: "Order pair or direction out of range", domain: "orderBatch", retryable: false },
+  6048: { name: "SettlementGracePeriodActive", message: "Force settlement grace period has not elapsed", domain: "orderBatch", retryable: true },
+  6049: { name: "SettlementDeadlineNotReached", message: "Settlement deadline has not passed", domain: "orderBatch", retryable: true },
+  6050: { name: "InvalidSettlementDeadline", message: "Settlement deadline too short", domain: "orderBatch", retryable: false },
+  6051: { name: "InvalidForceSettleGrace", message: "Invalid force settlement grace period", domain: "orderBatch", retryable: false },
+  6052: { name: "BatchLogInUse", message: "Batch log still in use", domain: "orderBatch", retryable: true },
+  6053: { name: "ExternalRoutePending", message: "External route pending for this pair", domain: "orderBatch", retryable: true },
+  6054: { name: "InvalidVault", message: "Invalid vault account", domain: "orderBatch", retryable: false },
+  6055: { name: "BatchNotFinalized", message: "Batch not yet executed", domain: "orderBatch", retryable: true },
+  6056: { name: "BatchApprovalRequired", message: "Batch requires co-operator approval", domain: "orderBatch", retryable: true },
+  6057: { name: "PriceBandBreached", message: "Batch price outside the pair's price band", domain: "orderBatch", retryable: true },
+  6058: { name: "PriceBandNotBreached", message: "Batch is within its price bands", domain: "orderBatch", retryable: false },
+  6059: { name: "RevealRetriesExhausted", message: "Batch reveal retries exhausted", domain: "orderBatch", retryable: false },
+  6060: { name: "BatchIdMismatch", message: "Batch ID mismatch", domain: "orderBatch", retryable: false },
+  6061: { name: "InvalidBatchId", message: "Invalid batch ID - doesn't match BatchLog", domain: "orderBatch", retryable: false },
+  6062: { name: "BatchRevealInProgress", message: "Batch reveal in progress - orders are closed", domain: "orderBatch", retryable: true },
+  6063: { name: "BatchAlreadyExecuted", message: "Batch already executed", domain: "orderBatch", retryable: false },
+  6064: { name: "BatchStateAlreadyInitialized", message: "Batch state already initialized", domain: "orderBatch", retryable: false },
+  6065: { name: "BatchNotRevealing", message: "No batch reveal in progress", domain: "orderBatch", retryable: false },
+  6066: { name: "BatchRevealNotStale", message: "Batch reveal is not stale yet", domain: "orderBatch", retryable: true },
+  6067: { name: "SwapsAlreadyExecuted", message: "Swaps already executed for this batch", domain: "orderBatch", retryable: false },
+  6068: { name: "SwapsNotExecuted", message: "Swaps not yet executed for this batch", domain: "orderBatch", retryable: true },
+  6069: { name: "InvalidBatchSlot", message: "Wrong batch accumulator slot", domain: "orderBatch", retryable: false },
+  6070: { name: "BatchSlotBusy", message: "Next batch accumulator slot is not free yet", domain: "orderBatch", retryable: true },
+  6071: { name: "BatchNotReady", message: "Batch does not have enough orders to execute", domain: "orderBatch", retryable: true },
+  6072: { name: "BatchRateLimited", message: "Batch execution rate limit reached", domain: "orderBatch", retryable: true },
+  6073: { name: "PrivacyFloorNotMet", message: "Batch is below the privacy floor", domain: "orderBatch", retryable: true },
+  6074: { name: "InvalidResetReason", message: "Invalid batch reset reason", domain: "orderBatch", retryable: false },
+  6075: { name: "InvalidClearReason", message: "Invalid clear pending reason", domain: "orderBatch", retryable: false },
+  6076: { name: "MarketClosed", message: "Market is closed", domain: "orderBatch", retryable: true },
+  6077: { name: "InvalidMarketSchedule", message: "Invalid market schedule", domain: "orderBatch", retryable: false },
+  6078: { name: "MarketScheduleMissing", message: "Market schedule not passed", domain: "orderBatch", retryable: false },
+  6079: { name: "PairRegistryMissing", message: "Pair registry not passed", domain: "orderBatch", retryable: false },
+  6080: { name: "ExecutionPolicyMissing", message: "Execution policy not passed", domain: "orderBatch", retryable: false },
+  6081: { name: "InvalidDcaInterval", message: "DCA interval too short", domain: "dca", retryable: false },
+  6082: { name: "DcaNotDue", message: "DCA execution not due yet", domain: "dca", retryable: true },
+  6083: { name: "DcaCompleted", message: "DCA schedule completed", domain: "dca", retryable: false },
+  6084: { name: "DcaPaused", message: "DCA schedule is paused", domain: "dca", retryable: false },
+  6085: { name: "DcaNotPaused", message: "DCA schedule is not paused", domain: "dca", retryable: false },
+  6086: { name: "InsufficientBalance", message: "Insufficient balance", domain: "balance", retryable: false },
+  6087: { name: "InsufficientSponsorFunds", message: "Insufficient rent sponsor funds", domain: "balance", retryable: false },
+  6088: { name: "UserAccountNotEmpty", message: "User account balances are not zero", domain: "balance", retryable: false },
+  6089: { name: "RentSponsorMissing", message: "Rent sponsor not passed", domain: "balance", retryable: false },
+  6090: { name: "InvalidSavingsBucket", message: "Invalid savings bucket", domain: "balance", retryable: false },
+  6091: { name: "MinOutputNotMet", message: "Minimum output not met", domain: "swapExecution", retryable: true },
+  6092: { name: "InvalidSwapRoute", message: "Invalid external swap route", domain: "swapExecution", retryable: false },
+  6093: { name: "SlippageTooHigh", message: "Swap slippage too high", domain: "swapExecution", retryable: false },
+  6094: { name: "DivisionByZero", message: "Division by zero in settlement - no input for this pair", domain: "swapExecution", retryable: false },
+  6095: { name: "MathOverflow", message: "Arithmetic overflow", domain: "swapExecution", retryable: false },
+  6096: { name: "InvalidExecutionConfig", message: "Invalid execution mode config", domain: "swapExecution", retryable: false },
+  6097: { name: "NettingWindowOpen", message: "Netting window still open", domain: "swapExecution", retryable: true },
+  6098: { name: "NettingBufferEmpty", message: "Netting buffer is empty", domain: "swapExecution", retryable: false },
+  6099: { name: "PriceUpdateMissing", message: "Price update account missing", domain: "oracle", retryable: false },
+  6100: { name: "OracleConfigMissing", message: "Oracle config not passed", domain: "oracle", retryable: false },
+  6101: { name: "InvalidPriceUpdate", message: "Invalid price update account", domain: "oracle", retryable: false },
+  6102: { name: "PriceFeedMismatch", message: "Price feed mismatch", domain: "oracle", retryable: false },
+  6103: { name: "PriceTooStale", message: "Oracle price too stale", domain: "oracle", retryable: true },
+  6104: { name: "PriceConfidenceTooWide", message: "Oracle price confidence too wide", domain: "oracle", retryable: true },
+  6105: { name: "PriceDivergence", message: "Oracle prices diverge", domain: "oracle", retryable: true },
+  6106: { name: "InvalidOracleConfig", message: "Invalid oracle config", domain: "oracle", retryable: false },
+  6107: { name: "AbortedComputation", message: "The computation was aborted", domain: "mpc", retryable: true },
+  6108: { name: "ComputationFailed", message: "MPC computation failed", domain: "mpc", retryable: true },
+  6109: { name: "ClusterNotSet", message: "Cluster not set", domain: "mpc", retryable: false },
+  6110: { name: "InvalidCallbackPriorityFee", message: "Callback priority fee too high", domain: "mpc", retryable: false },
+  6111: { name: "ViewingKeyChanged", message: "Viewing key changed during refresh", domain: "mpc", retryable: false },
+  6112: { name: "KeyRotationStale", message: "Balances changed during key rotation", domain: "mpc", retryable: true },
+  6113: { name: "AccountClosureStale", message: "Balances changed during account closure", domain: "mpc", retryable: true },
+  6114: { name: "RecoveryStale", message: "Balances changed during account recovery", domain: "mpc", retryable: true },
+  6115: { name: "PendingOperationStale", message: "Balance changed while the operation was pending", domain: "mpc", retryable: true },
+  6116: { name: "WithdrawalCancelTimeoutActive", message: "Withdrawal cannot be cancelled yet", domain: "mpc", retryable: true },
+  6117: { name: "InvalidCircuitHash", message: "Invalid circuit hash", domain: "mpc", retryable: false },
+  6118: { name: "CircuitRegistryFull", message: "Circuit registry is full", domain: "mpc", retryable: false },
+  6119: { name: "InvalidCompDefAccount", message: "Comp def account does not match", domain: "mpc", retryable: false },
+  6120: { name: "RecipientAccountNotFound", message: "Recipient account not found - they must create a privacy account first", domain: "transfer", retryable: false },
+  6121: { name: "InvalidTransferRequest", message: "Invalid transfer request", domain: "transfer", retryable: false },
+  6122: { name: "TransferRequestNotOpen", message: "Transfer request is not open", domain: "transfer", retryable: false },
+  6123: { name: "TransferRequestExpired", message: "Transfer request expired", domain: "transfer", retryable: false },
+  6124: { name: "TransferRequestNotExpired", message: "Transfer request has not expired", domain: "transfer", retryable: false },
+  6125: { name: "TransferRequestNotPending", message: "Transfer request has no computation in flight", domain: "transfer", retryable: false },
+  6126: { name: "TransferResetTimeoutActive", message: "Transfer request cannot be reset yet", domain: "transfer", retryable: true },
+  6127: { name: "InvalidTransferMemo", message: "Invalid transfer memo", domain: "transfer", retryable: false },
+  6128: { name: "TransferMemoMissing", message: "Transfer memo account not passed", domain: "transfer", retryable: false },
+  6129: { name: "InvalidBatchRecipients", message: "Invalid batch transfer recipients", domain: "transfer", retryable: false },
+  6130: { name: "InvalidPaymentStream", message: "Invalid payment stream", domain: "transfer", retryable: false },
+  6131: { name: "StreamNothingAccrued", message: "Nothing accrued on the stream yet", domain: "transfer", retryable: true },
+  6132: { name: "InvalidVoucher", message: "Invalid voucher", domain: "transfer", retryable: false },
+  6133: { name: "VoucherNotOpen", message: "Voucher is not open", domain: "transfer", retryable: false },
+  6134: { name: "VoucherExpired", message: "Voucher expired", domain: "transfer", retryable: false },
+  6135: { name: "VoucherNotExpired", message: "Voucher has not expired", domain: "transfer", retryable: false },
+  6136: { name: "VoucherNotPending", message: "Voucher has no computation in flight", domain: "transfer", retryable: false },
+  6137: { name: "VoucherResetTimeoutActive", message: "Voucher cannot be reset yet", domain: "transfer", retryable: true },
+  6138: { name: "InvalidAuctionStatus", message: "Invalid auction status", domain: "reserveAuction", retryable: false },
+  6139: { name: "AuctionBiddingClosed", message: "Auction bidding has closed", domain: "reserveAuction", retryable: false },
+  6140: { name: "AuctionStillOpen", message: "Auction bidding is still open", domain: "reserveAuction", retryable: true },
+  6141: { name: "AuctionFull", message: "Auction bid limit reached", domain: "reserveAuction", retryable: false },
+  6142: { name: "DuplicateBid", message: "Bidder already placed a bid", domain: "reserveAuction", retryable: false },
+  6143: { name: "InvalidReserve", message: "Invalid reserve account", domain: "reserveAuction", retryable: false },
+  6144: { name: "InvalidInactivityPeriod", message: "Inactivity period too short", domain: "beneficiary", retryable: false },
+  6145: { name: "InvalidBeneficiary", message: "Invalid beneficiary", domain: "beneficiary", retryable: false },
+  6146: { name: "OwnerStillActive", message: "Owner is still active", domain: "beneficiary", retryable: true },
+  6147: { name: "InvalidGuardians", message: "Invalid guardian configuration", domain: "beneficiary", retryable: false },
+  6148: { name: "InvalidRecovery", message: "Invalid recovery", domain: "beneficiary", retryable: false },
+  6149: { name: "RecoveryAlreadyApproved", message: "Another recovery is already approved", domain: "beneficiary", retryable: false },
+  6150: { name: "RecoveryNotApproved", message: "Recovery not approved", domain: "beneficiary", retryable: false },
+  6151: { name: "RecoveryChallengeActive", message: "Recovery challenge period active", domain: "beneficiary", retryable: true },
+  6152: { name: "InvalidDelegate", message: "Invalid delegate", domain: "delegation", retryable: false },
+  6153: { name: "InvalidDelegateExpiry", message: "Invalid delegate expiry", domain: "delegation", retryable: false },
+  6154: { name: "DelegateExpired", message: "Delegate has expired", domain: "delegation", retryable: false },
+  6155: { name: "DelegateOrderLimitReached", message: "Delegate order limit reached for this batch", domain: "delegation", retryable: true },
+  6156: { name: "DelegateAllowanceRequired", message: "Delegate must place orders for this asset against its allowance", domain: "delegation", retryable: false },
+  6157: { name: "NoDelegateAllowance", message: "No delegate allowance for this asset", domain: "delegation", retryable: false },
+  6158: { name: "DelegateAllowanceExceeded", message: "Amount exceeds the delegate allowance", domain: "delegation", retryable: false },
+  6159: { name: "BuybackDisabled", message: "Buyback is disabled", domain: "buyback", retryable: false },
+  6160: { name: "BuybackTooSoon", message: "Buyback interval has not elapsed", domain: "buyback", retryable: true },
+  6161: { name: "BuybackBudgetExceeded", message: "Buyback amount exceeds cap or available fee budget", domain: "buyback", retryable: false },
+  6162: { name: "InvalidSwapProgram", message: "Invalid swap program", domain: "buyback", retryable: false },
+  6163: { name: "ExecutorNotRegistered", message: "Executor is not registered", domain: "executor", retryable: false },
+  6164: { name: "ExecutorAlreadyRegistered", message: "Executor already registered", domain: "executor", retryable: false },
+  6165: { name: "ExecutorBondTooLow", message: "Executor bond too low", domain: "executor", retryable: false },
+  6166: { name: "ExecutorRegistryFull", message: "Executor registry is full", domain: "executor", retryable: false },
+  6167: { name: "ExecutorRegistryMissing", message: "Executor registry not initialized", domain: "executor", retryable: false },
+  6168: { name: "FaucetLimitExceeded", message: "Faucet limit exceeded - you can only claim up to 1000 USDC total", domain: "faucet", retryable: false },
 };
 
 export class ShuffleError extends Error {
   code: number;
   errorName: string;
+  domain?: ErrorDomain;
+  retryable: boolean;
 
   constructor(code: number) {
-    const info = ERROR_MAP[code] || { name: "Unknown", message: `Unknown error code: ${code}` };
-    super(info.message);
+    const info = ERROR_MAP[code];
+    super(info ? info.message : `Unknown error code: ${code}`);
     this.code = code;
-    this.errorName = info.name;
+    this.errorName = info ? info.name : "Unknown";
+    this.domain = info?.domain;
+    this.retryable = info?.retryable ?? false;
     this.name = "ShuffleError";
   }
 }
@@ -46,3 +226,8 @@ export function parseError(error: any): ShuffleError | Error {
   }
   return error instanceof Error ? error : new Error(String(error));
 }
+
+/** Build a ShuffleError from the ErrorInfo of a failure event */
+export function errorFromEvent(error: { code: number }): ShuffleError {
+  return new ShuffleError(error.code);
+}
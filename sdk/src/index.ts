@@ -41,6 +41,26 @@ export {
   getBatchLogPDA,
   getVaultPDA,
   getFaucetVaultPDA,
+  getSettlementPreferencePDA,
+  getViewableBalancePDA,
+  getViewerGrantPDA,
+  getTransferRequestPDA,
+  getTransferMemoPDA,
+  getKeyRotationPDA,
+  getAccountClosurePDA,
+  getPendingOperationPDA,
+  getSavingsBucketPDA,
+  getPaymentStreamPDA,
+  getVoucherPDA,
+  getGuardianSetPDA,
+  getRentSponsorPDA,
+  getExecutorRegistryPDA,
+  getEventCursorPDA,
+  getProtocolStatsPDA,
+  getRiskConfigPDA,
+  getWithdrawalWindowPDA,
+  getCircuitRegistryPDA,
+  getOracleConfigPDA,
 } from "./pda";
 
 // Encryption helpers
@@ -55,4 +75,5 @@ export {
 export type { EncryptionKeypair, EncryptedValue } from "./encryption";
 
 // Errors
-export { ShuffleError, parseError, ERROR_MAP } from "./errors";
+export { ShuffleError, parseError, errorFromEvent, ERROR_MAP } from "./errors";
+export type { ErrorDomain, ErrorInfo } from "./errors";
@@ -7,58 +7,311 @@ import {
   BATCH_LOG_SEED,
   VAULT_SEED,
   FAUCET_USDC_SEED,
+  SETTLEMENT_PREFERENCE_SEED,
+  VIEWABLE_BALANCE_SEED,
+  VIEWER_GRANT_SEED,
+  TRANSFER_REQUEST_SEED,
+  TRANSFER_MEMO_SEED,
+  KEY_ROTATION_SEED,
+  ACCOUNT_CLOSURE_SEED,
+  PENDING_OPERATION_SEED,
+  SAVINGS_BUCKET_SEED,
+  PAYMENT_STREAM_SEED,
+  VOUCHER_SEED,
+  GUARDIAN_SET_SEED,
+  RENT_SPONSOR_SEED,
+  EXECUTOR_REGISTRY_SEED,
+  EVENT_CURSOR_SEED,
+  PROTOCOL_STATS_SEED,
+  RISK_CONFIG_SEED,
+  WITHDRAWAL_WINDOW_SEED,
+  CIRCUIT_REGISTRY_SEED,
+  ORACLE_CONFIG_SEED,
 } from "./constants";
 
-export function getPoolPDA(programId: PublicKey): [PublicKey, number] {
+export function getPoolPDA(
+  programId: PublicKey,
+  poolId: number = 0
+): [PublicKey, number] {
   return PublicKey.findProgramAddressSync(
-    [Buffer.from(POOL_SEED)],
+    [Buffer.from(POOL_SEED), Buffer.from([poolId])],
     programId
   );
 }
 
 export function getUserAccountPDA(
   programId: PublicKey,
-  owner: PublicKey
+  owner: PublicKey,
+  poolId: number = 0
 ): [PublicKey, number] {
   return PublicKey.findProgramAddressSync(
-    [Buffer.from(USER_SEED), owner.toBuffer()],
+    [Buffer.from(USER_SEED), Buffer.from([poolId]), owner.toBuffer()],
     programId
   );
 }
 
 export function getBatchAccumulatorPDA(
-  programId: PublicKey
+  programId: PublicKey,
+  slot: number = 0,
+  poolId: number = 0
 ): [PublicKey, number] {
   return PublicKey.findProgramAddressSync(
-    [Buffer.from(BATCH_ACCUMULATOR_SEED)],
+    [Buffer.from(BATCH_ACCUMULATOR_SEED), Buffer.from([poolId]), Buffer.from([slot])],
     programId
   );
 }
 
 export function getBatchLogPDA(
   programId: PublicKey,
-  batchId: number | anchor.BN
+  batchId: number | anchor.BN,
+  poolId: number = 0
 ): [PublicKey, number] {
   const bn = typeof batchId === "number" ? new anchor.BN(batchId) : batchId;
   return PublicKey.findProgramAddressSync(
-    [Buffer.from(BATCH_LOG_SEED), Buffer.from(bn.toArray("le", 8))],
+    [Buffer.from(BATCH_LOG_SEED), Buffer.from([poolId]), Buffer.from(bn.toArray("le", 8))],
     programId
   );
 }
 
 export function getVaultPDA(
   programId: PublicKey,
-  assetSeed: string
+  assetSeed: string,
+  poolId: number = 0
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from(VAULT_SEED), Buffer.from([poolId]), Buffer.from(assetSeed)],
+    programId
+  );
+}
+
+export function getFaucetVaultPDA(
+  programId: PublicKey,
+  poolId: number = 0
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from(FAUCET_USDC_SEED), Buffer.from([poolId])],
+    programId
+  );
+}
+
+export function getSettlementPreferencePDA(
+  programId: PublicKey,
+  owner: PublicKey,
+  poolId: number = 0
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from(SETTLEMENT_PREFERENCE_SEED), Buffer.from([poolId]), owner.toBuffer()],
+    programId
+  );
+}
+
+export function getViewableBalancePDA(
+  programId: PublicKey,
+  owner: PublicKey,
+  poolId: number = 0
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from(VIEWABLE_BALANCE_SEED), Buffer.from([poolId]), owner.toBuffer()],
+    programId
+  );
+}
+
+export function getViewerGrantPDA(
+  programId: PublicKey,
+  owner: PublicKey,
+  viewerPubkey: Uint8Array,
+  poolId: number = 0
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from(VIEWER_GRANT_SEED), Buffer.from([poolId]), owner.toBuffer(), Buffer.from(viewerPubkey)],
+    programId
+  );
+}
+
+export function getTransferRequestPDA(
+  programId: PublicKey,
+  sender: PublicKey,
+  transferId: number | anchor.BN,
+  poolId: number = 0
+): [PublicKey, number] {
+  const bn = typeof transferId === "number" ? new anchor.BN(transferId) : transferId;
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from(TRANSFER_REQUEST_SEED), Buffer.from([poolId]), sender.toBuffer(), Buffer.from(bn.toArray("le", 8))],
+    programId
+  );
+}
+
+export function getTransferMemoPDA(
+  programId: PublicKey,
+  sender: PublicKey,
+  poolId: number = 0
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from(TRANSFER_MEMO_SEED), Buffer.from([poolId]), sender.toBuffer()],
+    programId
+  );
+}
+
+export function getKeyRotationPDA(
+  programId: PublicKey,
+  owner: PublicKey,
+  poolId: number = 0
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from(KEY_ROTATION_SEED), Buffer.from([poolId]), owner.toBuffer()],
+    programId
+  );
+}
+
+export function getAccountClosurePDA(
+  programId: PublicKey,
+  owner: PublicKey,
+  poolId: number = 0
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from(ACCOUNT_CLOSURE_SEED), Buffer.from([poolId]), owner.toBuffer()],
+    programId
+  );
+}
+
+export function getPendingOperationPDA(
+  programId: PublicKey,
+  owner: PublicKey,
+  computationOffset: number | anchor.BN,
+  poolId: number = 0
+): [PublicKey, number] {
+  const bn =
+    typeof computationOffset === "number" ? new anchor.BN(computationOffset) : computationOffset;
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from(PENDING_OPERATION_SEED), Buffer.from([poolId]), owner.toBuffer(), Buffer.from(bn.toArray("le", 8))],
+    programId
+  );
+}
+
+export function getSavingsBucketPDA(
+  programId: PublicKey,
+  owner: PublicKey,
+  bucketId: number,
+  poolId: number = 0
 ): [PublicKey, number] {
   return PublicKey.findProgramAddressSync(
-    [Buffer.from(VAULT_SEED), Buffer.from(assetSeed)],
+    [Buffer.from(SAVINGS_BUCKET_SEED), Buffer.from([poolId]), owner.toBuffer(), Buffer.from([bucketId])],
     programId
   );
 }
 
-export function getFaucetVaultPDA(programId: PublicKey): [PublicKey, number] {
+export function getPaymentStreamPDA(
+  programId: PublicKey,
+  payer: PublicKey,
+  streamId: number | anchor.BN,
+  poolId: number = 0
+): [PublicKey, number] {
+  const bn = typeof streamId === "number" ? new anchor.BN(streamId) : streamId;
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from(PAYMENT_STREAM_SEED), Buffer.from([poolId]), payer.toBuffer(), Buffer.from(bn.toArray("le", 8))],
+    programId
+  );
+}
+
+export function getVoucherPDA(
+  programId: PublicKey,
+  claimKey: PublicKey,
+  poolId: number = 0
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from(VOUCHER_SEED), Buffer.from([poolId]), claimKey.toBuffer()],
+    programId
+  );
+}
+
+export function getGuardianSetPDA(
+  programId: PublicKey,
+  owner: PublicKey,
+  poolId: number = 0
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from(GUARDIAN_SET_SEED), Buffer.from([poolId]), owner.toBuffer()],
+    programId
+  );
+}
+
+export function getRentSponsorPDA(
+  programId: PublicKey,
+  poolId: number = 0
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from(RENT_SPONSOR_SEED), Buffer.from([poolId])],
+    programId
+  );
+}
+
+export function getExecutorRegistryPDA(
+  programId: PublicKey,
+  poolId: number = 0
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from(EXECUTOR_REGISTRY_SEED), Buffer.from([poolId])],
+    programId
+  );
+}
+
+export function getEventCursorPDA(
+  programId: PublicKey,
+  poolId: number = 0
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from(EVENT_CURSOR_SEED), Buffer.from([poolId])],
+    programId
+  );
+}
+
+export function getProtocolStatsPDA(
+  programId: PublicKey,
+  poolId: number = 0
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from(PROTOCOL_STATS_SEED), Buffer.from([poolId])],
+    programId
+  );
+}
+
+export function getRiskConfigPDA(
+  programId: PublicKey,
+  poolId: number = 0
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from(RISK_CONFIG_SEED), Buffer.from([poolId])],
+    programId
+  );
+}
+
+export function getWithdrawalWindowPDA(
+  programId: PublicKey,
+  owner: PublicKey,
+  poolId: number = 0
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from(WITHDRAWAL_WINDOW_SEED), Buffer.from([poolId]), owner.toBuffer()],
+    programId
+  );
+}
+
+export function getCircuitRegistryPDA(
+  programId: PublicKey,
+  poolId: number = 0
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from(CIRCUIT_REGISTRY_SEED), Buffer.from([poolId])],
+    programId
+  );
+}
+
+export function getOracleConfigPDA(
+  programId: PublicKey,
+  poolId: number = 0
+): [PublicKey, number] {
   return PublicKey.findProgramAddressSync(
-    [Buffer.from(FAUCET_USDC_SEED)],
+    [Buffer.from(ORACLE_CONFIG_SEED), Buffer.from([poolId])],
     programId
   );
 }
@@ -16,6 +16,7 @@ export interface OrderInfo {
   pairId: number[];  // encrypted [u8; 32]
   direction: number[]; // encrypted [u8; 32]
   encryptedAmount: number[]; // encrypted [u8; 32]
+  validUntilBatch: number; // 0 = no rollover
 }
 
 /** Decrypted order info - user-readable after decryption */
@@ -32,6 +33,8 @@ export interface BatchInfo {
   orderCount: number;
   /** MXE nonce - 0 means batch state needs initialization */
   mxeNonce: string;
+  /** Accumulator slot (0 or 1) currently receiving orders */
+  slot: number;
 }
 
 /** Per-pair result from a batch execution */
@@ -40,12 +43,34 @@ export interface PairResult {
   totalBIn: anchor.BN;
   finalPoolA: anchor.BN;
   finalPoolB: anchor.BN;
+  /** Netting price in quote units per base unit, scaled by 1e6 (0 = not executed) */
+  executionPrice: anchor.BN;
 }
 
 /** Full batch log with results for all 6 pairs */
 export interface BatchResult {
   batchId: number;
   results: PairResult[];
+  /** Oracle prices [USDC, TSLA, SPY, AAPL] in USDC base units at execution */
+  oraclePrices: anchor.BN[];
+  /** Slot the oracle prices were read in */
+  oracleSlot: anchor.BN;
+  /** Confidence interval of each oracle price (0 = exact) */
+  oracleConfidence: anchor.BN[];
+  /** Pool execution fee when the batch was executed, in basis points */
+  executionFeeBps: number;
+  /** Worst slippage accepted on external swaps, in basis points */
+  maxSlippageBps: number;
+  /** Minimum output each pair's routed surplus had to return */
+  routeMinOut: anchor.BN[];
+  /** Bitmask of pairs partially filled because a reserve fell short */
+  shortfallPairs: number;
+  /** Pairs whose vault↔reserve transfers are done */
+  swapExecuted: boolean[];
+  /** Bitmask of pairs halted when the batch was executed (orders refunded) */
+  haltedPairs: number;
+  /** Execution fee withheld from each pair's output pools, in basis points */
+  pairFeeBps: number[];
 }
 
 /** SDK constructor configuration */
@@ -55,6 +80,8 @@ export interface ShuffleConfig {
   programId?: PublicKey;
   /** Arcium cluster offset (default: 0 for localnet) */
   clusterOffset?: number;
+  /** Pool to trade in, as passed to initialize (default: 0) */
+  poolId?: number;
 }
 
 /** Estimated payout for a pending order after batch execution */
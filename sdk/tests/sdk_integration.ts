@@ -163,7 +163,7 @@ async function main() {
   try {
     // Get pool to find USDC mint
     const poolPDA = PublicKey.findProgramAddressSync(
-      [Buffer.from("pool")],
+      [Buffer.from("pool"), Buffer.from([0])],
       LOCALNET_PROGRAM_ID
     )[0];
     
@@ -431,7 +431,7 @@ async function main() {
     
     // Get pool to find USDC mint
     const poolPDA = PublicKey.findProgramAddressSync(
-      [Buffer.from("pool")],
+      [Buffer.from("pool"), Buffer.from([0])],
       LOCALNET_PROGRAM_ID
     )[0];
     